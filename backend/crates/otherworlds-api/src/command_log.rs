@@ -0,0 +1,611 @@
+//! Command log — the run-scoped commands a campaign run can record and replay.
+//!
+//! `RunScope::commit` records every command executed on behalf of a run as a
+//! `CommandRecorded` event carrying the command's type name and its
+//! serialized form. `RunCommand` decodes those records back into commands and
+//! re-executes them through the same handlers and orchestrations the routes
+//! use. See ADR-0036.
+
+use std::sync::Mutex;
+
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use uuid::Uuid;
+
+use otherworlds_core::clock::Clock;
+use otherworlds_core::command::Command;
+use otherworlds_core::error::DomainError;
+use otherworlds_core::repository::EventRepository;
+use otherworlds_core::rng::DeterministicRng;
+use otherworlds_session::domain::events::CommandRecorded;
+
+use otherworlds_character::application::command_handlers as character_handlers;
+use otherworlds_character::domain::commands as character_commands;
+use otherworlds_inventory::application::command_handlers as inventory_handlers;
+use otherworlds_inventory::domain::commands as inventory_commands;
+use otherworlds_narrative::application::command_handlers as narrative_handlers;
+use otherworlds_narrative::domain::commands as narrative_commands;
+use otherworlds_rules::application::command_handlers as rules_handlers;
+use otherworlds_rules::application::encounter_handlers;
+use otherworlds_rules::domain::commands as rules_commands;
+use otherworlds_session::application::command_handlers as session_handlers;
+use otherworlds_session::domain::commands as session_commands;
+use otherworlds_world_state::application::command_handlers as world_state_handlers;
+use otherworlds_world_state::domain::commands as world_state_commands;
+
+use crate::orchestration::branch::orchestrate_branch_timeline;
+use crate::orchestration::encounter::orchestrate_start_encounter;
+use crate::orchestration::play::{
+    ResolveAction, ResolveOpposedAction, orchestrate_resolve_action,
+    orchestrate_resolve_opposed_action,
+};
+
+/// A command that can be executed on behalf of a campaign run.
+///
+/// Serializes as the wrapped command, exactly as it was recorded.
+#[derive(Debug, Clone, Serialize)]
+#[serde(untagged)]
+pub enum RunCommand {
+    /// `narrative.advance_beat`
+    AdvanceBeat(narrative_commands::AdvanceBeat),
+    /// `narrative.present_choice`
+    PresentChoice(narrative_commands::PresentChoice),
+    /// `narrative.enter_scene`
+    EnterScene(narrative_commands::EnterScene),
+    /// `narrative.select_choice`
+    SelectChoice(narrative_commands::SelectChoice),
+    /// `narrative.archive_session`
+    ArchiveSession(narrative_commands::ArchiveSession),
+    /// `rules.declare_intent`
+    DeclareIntent(rules_commands::DeclareIntent),
+    /// `rules.declare_opposed_intent`
+    DeclareOpposedIntent(rules_commands::DeclareOpposedIntent),
+    /// `rules.resolve_check`
+    ResolveCheck(rules_commands::ResolveCheck),
+    /// `rules.produce_effects`
+    ProduceEffects(rules_commands::ProduceEffects),
+    /// `rules.archive_resolution`
+    ArchiveResolution(rules_commands::ArchiveResolution),
+    /// `rules.start_encounter`
+    StartEncounter(rules_commands::StartEncounter),
+    /// `rules.end_turn`
+    EndTurn(rules_commands::EndTurn),
+    /// `rules.delay_turn`
+    DelayTurn(rules_commands::DelayTurn),
+    /// `rules.ready_action`
+    ReadyAction(rules_commands::ReadyAction),
+    /// `rules.trigger_readied_action`
+    TriggerReadiedAction(rules_commands::TriggerReadiedAction),
+    /// `rules.link_resolution`
+    LinkResolution(rules_commands::LinkResolution),
+    /// `rules.end_encounter`
+    EndEncounter(rules_commands::EndEncounter),
+    /// `character.create_character`
+    CreateCharacter(character_commands::CreateCharacter),
+    /// `character.modify_attribute`
+    ModifyAttribute(character_commands::ModifyAttribute),
+    /// `character.award_experience`
+    AwardExperience(character_commands::AwardExperience),
+    /// `character.grant_proficiency`
+    GrantProficiency(character_commands::GrantProficiency),
+    /// `character.archive_character`
+    ArchiveCharacter(character_commands::ArchiveCharacter),
+    /// `inventory.add_item`
+    AddItem(inventory_commands::AddItem),
+    /// `inventory.remove_item`
+    RemoveItem(inventory_commands::RemoveItem),
+    /// `inventory.equip_item`
+    EquipItem(inventory_commands::EquipItem),
+    /// `inventory.archive_inventory`
+    ArchiveInventory(inventory_commands::ArchiveInventory),
+    /// `world_state.apply_effect`
+    ApplyEffect(world_state_commands::ApplyEffect),
+    /// `world_state.assert_fact`
+    AssertFact(world_state_commands::AssertFact),
+    /// `world_state.retract_fact`
+    RetractFact(world_state_commands::RetractFact),
+    /// `world_state.set_flag`
+    SetFlag(world_state_commands::SetFlag),
+    /// `world_state.update_disposition`
+    UpdateDisposition(world_state_commands::UpdateDisposition),
+    /// `world_state.seed_dispositions`
+    SeedDispositions(world_state_commands::SeedDispositions),
+    /// `world_state.join_faction`
+    JoinFaction(world_state_commands::JoinFaction),
+    /// `world_state.seed_locations`
+    SeedLocations(world_state_commands::SeedLocations),
+    /// `world_state.set_connection_lock`
+    SetConnectionLock(world_state_commands::SetConnectionLock),
+    /// `world_state.move_party`
+    MoveParty(world_state_commands::MoveParty),
+    /// `world_state.move_npc`
+    MoveNpc(world_state_commands::MoveNpc),
+    /// `world_state.set_calendar`
+    SetCalendar(world_state_commands::SetCalendar),
+    /// `world_state.schedule_event`
+    ScheduleEvent(world_state_commands::ScheduleEvent),
+    /// `world_state.advance_time`
+    AdvanceTime(world_state_commands::AdvanceTime),
+    /// `world_state.archive_world_snapshot`
+    ArchiveWorldSnapshot(world_state_commands::ArchiveWorldSnapshot),
+    /// `session.create_checkpoint`
+    CreateCheckpoint(session_commands::CreateCheckpoint),
+    /// `session.branch_timeline`
+    BranchTimeline(session_commands::BranchTimeline),
+    /// `session.archive_campaign_run`
+    ArchiveCampaignRun(session_commands::ArchiveCampaignRun),
+    /// `play.resolve_action`
+    ResolveAction(ResolveAction),
+    /// `play.resolve_opposed_action`
+    ResolveOpposedAction(ResolveOpposedAction),
+}
+
+fn decode<T: DeserializeOwned>(record: &CommandRecorded) -> Result<T, DomainError> {
+    serde_json::from_value(record.command.clone()).map_err(|e| {
+        DomainError::Validation(format!(
+            "recorded {} command is malformed: {e}",
+            record.command_type
+        ))
+    })
+}
+
+impl RunCommand {
+    fn as_command(&self) -> &dyn Command {
+        match self {
+            Self::AdvanceBeat(c) => c,
+            Self::PresentChoice(c) => c,
+            Self::EnterScene(c) => c,
+            Self::SelectChoice(c) => c,
+            Self::ArchiveSession(c) => c,
+            Self::DeclareIntent(c) => c,
+            Self::DeclareOpposedIntent(c) => c,
+            Self::ResolveCheck(c) => c,
+            Self::ProduceEffects(c) => c,
+            Self::ArchiveResolution(c) => c,
+            Self::StartEncounter(c) => c,
+            Self::EndTurn(c) => c,
+            Self::DelayTurn(c) => c,
+            Self::ReadyAction(c) => c,
+            Self::TriggerReadiedAction(c) => c,
+            Self::LinkResolution(c) => c,
+            Self::EndEncounter(c) => c,
+            Self::CreateCharacter(c) => c,
+            Self::ModifyAttribute(c) => c,
+            Self::AwardExperience(c) => c,
+            Self::GrantProficiency(c) => c,
+            Self::ArchiveCharacter(c) => c,
+            Self::AddItem(c) => c,
+            Self::RemoveItem(c) => c,
+            Self::EquipItem(c) => c,
+            Self::ArchiveInventory(c) => c,
+            Self::ApplyEffect(c) => c,
+            Self::AssertFact(c) => c,
+            Self::RetractFact(c) => c,
+            Self::SetFlag(c) => c,
+            Self::UpdateDisposition(c) => c,
+            Self::SeedDispositions(c) => c,
+            Self::JoinFaction(c) => c,
+            Self::SeedLocations(c) => c,
+            Self::SetConnectionLock(c) => c,
+            Self::MoveParty(c) => c,
+            Self::MoveNpc(c) => c,
+            Self::SetCalendar(c) => c,
+            Self::ScheduleEvent(c) => c,
+            Self::AdvanceTime(c) => c,
+            Self::ArchiveWorldSnapshot(c) => c,
+            Self::CreateCheckpoint(c) => c,
+            Self::BranchTimeline(c) => c,
+            Self::ArchiveCampaignRun(c) => c,
+            Self::ResolveAction(c) => c,
+            Self::ResolveOpposedAction(c) => c,
+        }
+    }
+
+    /// Decodes a recorded command.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::Validation` if the command type is not a
+    /// run-scoped command or the recorded command does not match its type.
+    pub fn from_record(record: &CommandRecorded) -> Result<Self, DomainError> {
+        Ok(match record.command_type.as_str() {
+            "narrative.advance_beat" => Self::AdvanceBeat(decode(record)?),
+            "narrative.present_choice" => Self::PresentChoice(decode(record)?),
+            "narrative.enter_scene" => Self::EnterScene(decode(record)?),
+            "narrative.select_choice" => Self::SelectChoice(decode(record)?),
+            "narrative.archive_session" => Self::ArchiveSession(decode(record)?),
+            "rules.declare_intent" => Self::DeclareIntent(decode(record)?),
+            "rules.declare_opposed_intent" => Self::DeclareOpposedIntent(decode(record)?),
+            "rules.resolve_check" => Self::ResolveCheck(decode(record)?),
+            "rules.produce_effects" => Self::ProduceEffects(decode(record)?),
+            "rules.archive_resolution" => Self::ArchiveResolution(decode(record)?),
+            "rules.start_encounter" => Self::StartEncounter(decode(record)?),
+            "rules.end_turn" => Self::EndTurn(decode(record)?),
+            "rules.delay_turn" => Self::DelayTurn(decode(record)?),
+            "rules.ready_action" => Self::ReadyAction(decode(record)?),
+            "rules.trigger_readied_action" => Self::TriggerReadiedAction(decode(record)?),
+            "rules.link_resolution" => Self::LinkResolution(decode(record)?),
+            "rules.end_encounter" => Self::EndEncounter(decode(record)?),
+            "character.create_character" => Self::CreateCharacter(decode(record)?),
+            "character.modify_attribute" => Self::ModifyAttribute(decode(record)?),
+            "character.award_experience" => Self::AwardExperience(decode(record)?),
+            "character.grant_proficiency" => Self::GrantProficiency(decode(record)?),
+            "character.archive_character" => Self::ArchiveCharacter(decode(record)?),
+            "inventory.add_item" => Self::AddItem(decode(record)?),
+            "inventory.remove_item" => Self::RemoveItem(decode(record)?),
+            "inventory.equip_item" => Self::EquipItem(decode(record)?),
+            "inventory.archive_inventory" => Self::ArchiveInventory(decode(record)?),
+            "world_state.apply_effect" => Self::ApplyEffect(decode(record)?),
+            "world_state.assert_fact" => Self::AssertFact(decode(record)?),
+            "world_state.retract_fact" => Self::RetractFact(decode(record)?),
+            "world_state.set_flag" => Self::SetFlag(decode(record)?),
+            "world_state.update_disposition" => Self::UpdateDisposition(decode(record)?),
+            "world_state.seed_dispositions" => Self::SeedDispositions(decode(record)?),
+            "world_state.join_faction" => Self::JoinFaction(decode(record)?),
+            "world_state.seed_locations" => Self::SeedLocations(decode(record)?),
+            "world_state.set_connection_lock" => Self::SetConnectionLock(decode(record)?),
+            "world_state.move_party" => Self::MoveParty(decode(record)?),
+            "world_state.move_npc" => Self::MoveNpc(decode(record)?),
+            "world_state.set_calendar" => Self::SetCalendar(decode(record)?),
+            "world_state.schedule_event" => Self::ScheduleEvent(decode(record)?),
+            "world_state.advance_time" => Self::AdvanceTime(decode(record)?),
+            "world_state.archive_world_snapshot" => Self::ArchiveWorldSnapshot(decode(record)?),
+            "session.create_checkpoint" => Self::CreateCheckpoint(decode(record)?),
+            "session.branch_timeline" => Self::BranchTimeline(decode(record)?),
+            "session.archive_campaign_run" => Self::ArchiveCampaignRun(decode(record)?),
+            "play.resolve_action" => Self::ResolveAction(decode(record)?),
+            "play.resolve_opposed_action" => Self::ResolveOpposedAction(decode(record)?),
+            other => {
+                return Err(DomainError::Validation(format!(
+                    "unknown recorded command type: {other}"
+                )));
+            }
+        })
+    }
+
+    /// Executes the command on behalf of `run_id` through the handler or
+    /// orchestration its route uses, discarding the handler's result.
+    ///
+    /// # Errors
+    ///
+    /// Returns any error the handler returns.
+    #[allow(clippy::too_many_lines)]
+    pub async fn execute(
+        &self,
+        run_id: Uuid,
+        clock: &(dyn Clock + Send + Sync),
+        rng: &Mutex<dyn DeterministicRng + Send>,
+        repo: &dyn EventRepository,
+    ) -> Result<(), DomainError> {
+        match self {
+            Self::AdvanceBeat(c) => {
+                narrative_handlers::handle_advance_beat(c, clock, rng, repo).await?;
+            }
+            Self::PresentChoice(c) => {
+                narrative_handlers::handle_present_choice(c, clock, rng, repo).await?;
+            }
+            Self::EnterScene(c) => {
+                narrative_handlers::handle_enter_scene(c, clock, rng, repo).await?;
+            }
+            Self::SelectChoice(c) => {
+                narrative_handlers::handle_select_choice(c, clock, rng, repo).await?;
+            }
+            Self::ArchiveSession(c) => {
+                narrative_handlers::handle_archive_session(c, clock, rng, repo).await?;
+            }
+            Self::DeclareIntent(c) => {
+                rules_handlers::handle_declare_intent(c, clock, rng, repo).await?;
+            }
+            Self::DeclareOpposedIntent(c) => {
+                rules_handlers::handle_declare_opposed_intent(c, clock, rng, repo).await?;
+            }
+            Self::ResolveCheck(c) => {
+                rules_handlers::handle_resolve_check(c, clock, rng, repo).await?;
+            }
+            Self::ProduceEffects(c) => {
+                rules_handlers::handle_produce_effects(c, clock, rng, repo).await?;
+            }
+            Self::ArchiveResolution(c) => {
+                rules_handlers::handle_archive_resolution(c, clock, rng, repo).await?;
+            }
+            Self::StartEncounter(c) => {
+                orchestrate_start_encounter(c, Some(run_id), clock, rng, repo).await?;
+            }
+            Self::EndTurn(c) => {
+                encounter_handlers::handle_end_turn(c, clock, rng, repo).await?;
+            }
+            Self::DelayTurn(c) => {
+                encounter_handlers::handle_delay_turn(c, clock, rng, repo).await?;
+            }
+            Self::ReadyAction(c) => {
+                encounter_handlers::handle_ready_action(c, clock, rng, repo).await?;
+            }
+            Self::TriggerReadiedAction(c) => {
+                encounter_handlers::handle_trigger_readied_action(c, clock, rng, repo).await?;
+            }
+            Self::LinkResolution(c) => {
+                encounter_handlers::handle_link_resolution(c, clock, rng, repo).await?;
+            }
+            Self::EndEncounter(c) => {
+                encounter_handlers::handle_end_encounter(c, clock, rng, repo).await?;
+            }
+            Self::CreateCharacter(c) => {
+                character_handlers::handle_create_character(c, clock, rng, repo).await?;
+            }
+            Self::ModifyAttribute(c) => {
+                character_handlers::handle_modify_attribute(c, clock, rng, repo).await?;
+            }
+            Self::AwardExperience(c) => {
+                character_handlers::handle_award_experience(c, clock, rng, repo).await?;
+            }
+            Self::GrantProficiency(c) => {
+                character_handlers::handle_grant_proficiency(c, clock, rng, repo).await?;
+            }
+            Self::ArchiveCharacter(c) => {
+                character_handlers::handle_archive_character(c, clock, rng, repo).await?;
+            }
+            Self::AddItem(c) => {
+                inventory_handlers::handle_add_item(c, clock, rng, repo).await?;
+            }
+            Self::RemoveItem(c) => {
+                inventory_handlers::handle_remove_item(c, clock, rng, repo).await?;
+            }
+            Self::EquipItem(c) => {
+                inventory_handlers::handle_equip_item(c, clock, rng, repo).await?;
+            }
+            Self::ArchiveInventory(c) => {
+                inventory_handlers::handle_archive_inventory(c, clock, rng, repo).await?;
+            }
+            Self::ApplyEffect(c) => {
+                world_state_handlers::handle_apply_effect(c, clock, rng, repo).await?;
+            }
+            Self::AssertFact(c) => {
+                world_state_handlers::handle_assert_fact(c, clock, rng, repo).await?;
+            }
+            Self::RetractFact(c) => {
+                world_state_handlers::handle_retract_fact(c, clock, rng, repo).await?;
+            }
+            Self::SetFlag(c) => {
+                world_state_handlers::handle_set_flag(c, clock, rng, repo).await?;
+            }
+            Self::UpdateDisposition(c) => {
+                world_state_handlers::handle_update_disposition(c, clock, rng, repo).await?;
+            }
+            Self::SeedDispositions(c) => {
+                world_state_handlers::handle_seed_dispositions(c, clock, rng, repo).await?;
+            }
+            Self::JoinFaction(c) => {
+                world_state_handlers::handle_join_faction(c, clock, rng, repo).await?;
+            }
+            Self::SeedLocations(c) => {
+                world_state_handlers::handle_seed_locations(c, clock, rng, repo).await?;
+            }
+            Self::SetConnectionLock(c) => {
+                world_state_handlers::handle_set_connection_lock(c, clock, rng, repo).await?;
+            }
+            Self::MoveParty(c) => {
+                world_state_handlers::handle_move_party(c, clock, rng, repo).await?;
+            }
+            Self::MoveNpc(c) => {
+                world_state_handlers::handle_move_npc(c, clock, rng, repo).await?;
+            }
+            Self::SetCalendar(c) => {
+                world_state_handlers::handle_set_calendar(c, clock, rng, repo).await?;
+            }
+            Self::ScheduleEvent(c) => {
+                world_state_handlers::handle_schedule_event(c, clock, rng, repo).await?;
+            }
+            Self::AdvanceTime(c) => {
+                world_state_handlers::handle_advance_time(c, clock, rng, repo).await?;
+            }
+            Self::ArchiveWorldSnapshot(c) => {
+                world_state_handlers::handle_archive_world_snapshot(c, clock, rng, repo).await?;
+            }
+            Self::CreateCheckpoint(c) => {
+                session_handlers::handle_create_checkpoint(c, clock, rng, repo).await?;
+            }
+            Self::BranchTimeline(c) => {
+                orchestrate_branch_timeline(
+                    c.source_run_id,
+                    c.from_checkpoint_id,
+                    c.correlation_id,
+                    clock,
+                    rng,
+                    repo,
+                )
+                .await?;
+            }
+            Self::ArchiveCampaignRun(c) => {
+                session_handlers::handle_archive_campaign_run(c, clock, rng, repo).await?;
+            }
+            Self::ResolveAction(c) => {
+                orchestrate_resolve_action(c, clock, rng, repo).await?;
+            }
+            Self::ResolveOpposedAction(c) => {
+                orchestrate_resolve_opposed_action(c, clock, rng, repo).await?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Command for RunCommand {
+    fn command_type(&self) -> &'static str {
+        self.as_command().command_type()
+    }
+
+    fn correlation_id(&self) -> Uuid {
+        self.as_command().correlation_id()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use otherworlds_session::domain::events::CommitSpan;
+    use otherworlds_world_state::domain::events::FactValue;
+
+    fn record(command_type: &str, command: serde_json::Value) -> CommandRecorded {
+        CommandRecorded {
+            run_id: Uuid::new_v4(),
+            command_type: command_type.to_owned(),
+            command,
+            commit: CommitSpan { offset: 0, len: 2 },
+        }
+    }
+
+    #[test]
+    fn test_from_record_decodes_known_command() {
+        // Arrange
+        let character_id = Uuid::new_v4();
+        let record = record(
+            "character.award_experience",
+            serde_json::json!({
+                "correlation_id": Uuid::new_v4(),
+                "character_id": character_id,
+                "amount": 50
+            }),
+        );
+
+        // Act
+        let command = RunCommand::from_record(&record).unwrap();
+
+        // Assert
+        match command {
+            RunCommand::AwardExperience(c) => {
+                assert_eq!(c.character_id, character_id);
+                assert_eq!(c.amount, 50);
+            }
+            other => panic!("expected AwardExperience, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_from_record_decodes_typed_fact_assertion() {
+        // Arrange
+        let world_id = Uuid::new_v4();
+        let record = record(
+            "world_state.assert_fact",
+            serde_json::json!({
+                "correlation_id": Uuid::new_v4(),
+                "world_id": world_id,
+                "key": "gold_owed",
+                "value": { "type": "number", "value": 40 }
+            }),
+        );
+
+        // Act
+        let command = RunCommand::from_record(&record).unwrap();
+
+        // Assert
+        match command {
+            RunCommand::AssertFact(c) => {
+                assert_eq!(c.world_id, world_id);
+                assert_eq!(c.key, "gold_owed");
+                assert_eq!(c.value, FactValue::Number(40));
+            }
+            other => panic!("expected AssertFact, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_from_record_decodes_update_disposition_recorded_without_delta() {
+        // Arrange
+        let record = record(
+            "world_state.update_disposition",
+            serde_json::json!({
+                "correlation_id": Uuid::new_v4(),
+                "world_id": Uuid::new_v4(),
+                "entity_id": Uuid::new_v4()
+            }),
+        );
+
+        // Act
+        let command = RunCommand::from_record(&record).unwrap();
+
+        // Assert
+        match command {
+            RunCommand::UpdateDisposition(c) => assert_eq!(c.delta, 0),
+            other => panic!("expected UpdateDisposition, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_from_record_decodes_seed_locations_without_connections() {
+        // Arrange
+        let record = record(
+            "world_state.seed_locations",
+            serde_json::json!({
+                "correlation_id": Uuid::new_v4(),
+                "world_id": Uuid::new_v4(),
+                "locations": [{ "location_id": Uuid::new_v4(), "name": "Quay" }]
+            }),
+        );
+
+        // Act
+        let command = RunCommand::from_record(&record).unwrap();
+
+        // Assert
+        match command {
+            RunCommand::SeedLocations(c) => {
+                assert_eq!(c.locations.len(), 1);
+                assert!(c.locations[0].npcs.is_empty());
+                assert!(c.connections.is_empty());
+                assert_eq!(c.party_location, None);
+            }
+            other => panic!("expected SeedLocations, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_from_record_decodes_calendar_without_months() {
+        // Arrange
+        let record = record(
+            "world_state.set_calendar",
+            serde_json::json!({
+                "correlation_id": Uuid::new_v4(),
+                "world_id": Uuid::new_v4(),
+                "calendar": { "hours_per_day": 20 }
+            }),
+        );
+
+        // Act
+        let command = RunCommand::from_record(&record).unwrap();
+
+        // Assert
+        match command {
+            RunCommand::SetCalendar(c) => {
+                assert_eq!(c.calendar.hours_per_day, 20);
+                assert!(c.calendar.months.is_empty());
+            }
+            other => panic!("expected SetCalendar, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_from_record_rejects_unknown_command_type() {
+        // Arrange
+        let record = record("content.ingest_campaign", serde_json::json!({}));
+
+        // Act
+        let result = RunCommand::from_record(&record);
+
+        // Assert
+        assert!(matches!(result, Err(DomainError::Validation(_))));
+    }
+
+    #[test]
+    fn test_from_record_rejects_malformed_command() {
+        // Arrange
+        let record = record(
+            "character.award_experience",
+            serde_json::json!({ "amount": 50 }),
+        );
+
+        // Act
+        let result = RunCommand::from_record(&record);
+
+        // Assert
+        assert!(matches!(result, Err(DomainError::Validation(_))));
+    }
+}
@@ -1,6 +1,10 @@
 //! Otherworlds RPG — API library for shared types and router construction.
 
+pub mod command_log;
 pub mod error;
 pub mod orchestration;
+pub mod projections;
+pub mod replay;
 pub mod routes;
+pub mod run_scope;
 pub mod state;
@@ -2,6 +2,7 @@
 
 use std::net::SocketAddr;
 use std::sync::{Arc, Mutex};
+use std::time::Duration;
 
 use axum::Router;
 use sqlx::postgres::PgPoolOptions;
@@ -14,7 +15,10 @@ use tracing_subscriber::util::SubscriberInitExt;
 use opentelemetry::trace::TracerProvider;
 
 use otherworlds_api::error::AppError;
-use otherworlds_api::{routes, state};
+use otherworlds_api::{projections, routes, state};
+use otherworlds_event_store::memory_event_repository::InMemoryEventRepository;
+use otherworlds_event_store::memory_read_model_store::InMemoryReadModelStore;
+use otherworlds_event_store::sqlite_event_repository::SqliteEventRepository;
 
 #[tokio::main]
 async fn main() -> Result<(), AppError> {
@@ -52,8 +56,6 @@ async fn main() -> Result<(), AppError> {
     tracing::info!("Starting Otherworlds RPG API server");
 
     // Read configuration from environment.
-    let database_url = std::env::var("DATABASE_URL")
-        .map_err(|_| AppError::Config("DATABASE_URL environment variable must be set".into()))?;
     let host = std::env::var("HOST")
         .map_err(|_| AppError::Config("HOST environment variable must be set".into()))?;
     let port: u16 = std::env::var("PORT")
@@ -61,26 +63,36 @@ async fn main() -> Result<(), AppError> {
         .parse()
         .map_err(|e| AppError::Config(format!("PORT must be a valid u16: {e}")))?;
 
+    // Snapshot every N events per aggregate (0 disables snapshotting).
+    let snapshot_every: i64 = env_or("SNAPSHOT_EVERY_N_EVENTS", 100)?;
+
+    // Poll the global event log for projection updates every N milliseconds
+    // (0 disables the background runner; queries still catch up on read).
+    let projection_poll_ms: u64 = env_or("PROJECTION_POLL_INTERVAL_MS", 1_000)?;
+
     // Validate HOST:PORT combination early.
     let addr: SocketAddr = format!("{host}:{port}")
         .parse()
         .map_err(|e| AppError::Config(format!("invalid HOST:PORT combination: {e}")))?;
 
-    // Create database connection pool.
-    let pool = PgPoolOptions::new()
-        .max_connections(10)
-        .connect(&database_url)
-        .await?;
-
     // Build application state with injected Clock, RNG, and EventRepository.
     let clock: Arc<dyn otherworlds_core::clock::Clock + Send + Sync> =
         Arc::new(otherworlds_core::clock::SystemClock);
+    // The process RNG serves only commands outside any campaign run; those
+    // are not recorded or replayable. Run commands draw from their run's
+    // seeded RNG instead (see `run_scope`).
     let rng: Arc<Mutex<dyn otherworlds_core::rng::DeterministicRng + Send>> =
         Arc::new(Mutex::new(otherworlds_core::rng::StdRng));
-    let event_repository: Arc<dyn otherworlds_core::repository::EventRepository> = Arc::new(
-        otherworlds_event_store::pg_event_repository::PgEventRepository::new(pool.clone()),
-    );
-    let app_state = state::AppState::new(pool, clock, rng, event_repository);
+    let snapshot_policy = otherworlds_core::repository::SnapshotPolicy::every(snapshot_every);
+    let (pool, event_repository, read_models) = build_stores(snapshot_policy).await?;
+    if projection_poll_ms > 0 {
+        tokio::spawn(run_projections(
+            event_repository.clone(),
+            read_models.clone(),
+            Duration::from_millis(projection_poll_ms),
+        ));
+    }
+    let app_state = state::AppState::new(pool, clock, rng, event_repository, read_models);
 
     // Build CORS layer.
     let cors = if std::env::var("CORS_PERMISSIVE").is_ok_and(|v| v == "true") {
@@ -96,12 +108,14 @@ async fn main() -> Result<(), AppError> {
         .merge(routes::health::router())
         .nest("/api/v1/narrative", routes::narrative::router())
         .nest("/api/v1/rules", routes::rules::router())
+        .nest("/api/v1/encounters", routes::encounter::router())
         .nest("/api/v1/world", routes::world_state::router())
         .nest("/api/v1/characters", routes::character::router())
         .nest("/api/v1/inventory", routes::inventory::router())
         .nest("/api/v1/sessions", routes::session::router())
         .nest("/api/v1/content", routes::content::router())
         .nest("/api/v1/play", routes::play::router())
+        .nest("/api/v1/admin", routes::admin::router())
         .layer(TraceLayer::new_for_http())
         .layer(cors)
         .with_state(app_state);
@@ -115,3 +129,124 @@ async fn main() -> Result<(), AppError> {
 
     Ok(())
 }
+
+/// Builds the event repository and read-model store selected by
+/// `EVENT_STORE`.
+///
+/// `postgres` (the default) connects to `DATABASE_URL`. `sqlite` opens the
+/// `SQLite` database at `DATABASE_URL` (e.g. `sqlite://otherworlds.db`) and
+/// applies its migrations. `memory` keeps everything in process, mirroring
+/// the event log to `EVENT_STORE_FILE` when it is set. The `sqlite` and
+/// `memory` backends keep read models in memory and rebuild them from the
+/// log on startup.
+async fn build_stores(
+    snapshot_policy: otherworlds_core::repository::SnapshotPolicy,
+) -> Result<
+    (
+        Option<sqlx::PgPool>,
+        Arc<dyn otherworlds_core::repository::EventRepository>,
+        Arc<dyn otherworlds_core::projection::ReadModelStore>,
+    ),
+    AppError,
+> {
+    let backend = std::env::var("EVENT_STORE").unwrap_or_else(|_| "postgres".into());
+    match backend.as_str() {
+        "postgres" => {
+            let database_url = std::env::var("DATABASE_URL").map_err(|_| {
+                AppError::Config("DATABASE_URL environment variable must be set".into())
+            })?;
+
+            // Create database connection pool.
+            let pool = PgPoolOptions::new()
+                .max_connections(10)
+                .connect(&database_url)
+                .await?;
+
+            tracing::info!(
+                every_n_events = snapshot_policy.every_n_events(),
+                "Using PostgreSQL event store with aggregate snapshot policy"
+            );
+            let event_repository = Arc::new(
+                otherworlds_event_store::pg_event_repository::PgEventRepository::with_snapshots(
+                    pool.clone(),
+                    snapshot_policy,
+                ),
+            );
+            let read_models = Arc::new(
+                otherworlds_event_store::pg_read_model_store::PgReadModelStore::new(pool.clone()),
+            );
+            Ok((Some(pool), event_repository, read_models))
+        }
+        "sqlite" => {
+            let database_url = std::env::var("DATABASE_URL").map_err(|_| {
+                AppError::Config("DATABASE_URL environment variable must be set".into())
+            })?;
+            tracing::info!("Using SQLite event store");
+            let event_repository = SqliteEventRepository::connect(&database_url)
+                .await
+                .map_err(|e| AppError::Config(e.to_string()))?;
+            let read_models = InMemoryReadModelStore::new();
+            Ok((None, Arc::new(event_repository), Arc::new(read_models)))
+        }
+        "memory" => {
+            let event_repository = if let Ok(path) = std::env::var("EVENT_STORE_FILE") {
+                tracing::info!(%path, "Using in-memory event store persisted to file");
+                InMemoryEventRepository::open(path).map_err(|e| AppError::Config(e.to_string()))?
+            } else {
+                tracing::warn!("Using in-memory event store; events are lost on exit");
+                InMemoryEventRepository::new()
+            };
+            let read_models = InMemoryReadModelStore::new();
+            Ok((None, Arc::new(event_repository), Arc::new(read_models)))
+        }
+        other => Err(AppError::Config(format!(
+            "EVENT_STORE must be \"postgres\", \"sqlite\", or \"memory\", got \"{other}\""
+        ))),
+    }
+}
+
+/// Keeps every projection caught up with the global event log.
+///
+/// Failures are logged and retried on the next tick; query handlers catch up
+/// on read, so a stalled runner only costs latency.
+async fn run_projections(
+    event_repository: Arc<dyn otherworlds_core::repository::EventRepository>,
+    read_models: Arc<dyn otherworlds_core::projection::ReadModelStore>,
+    interval: Duration,
+) {
+    let runner = otherworlds_core::projection::ProjectionRunner::default();
+    let projections = projections::all();
+    let mut ticker = tokio::time::interval(interval);
+    loop {
+        ticker.tick().await;
+        for projection in &projections {
+            if let Err(err) = runner
+                .catch_up(projection.as_ref(), &*event_repository, &*read_models)
+                .await
+            {
+                tracing::warn!(
+                    projection = projection.name(),
+                    "projection catch-up failed: {err}"
+                );
+            }
+        }
+    }
+}
+
+/// Parses an optional numeric environment variable, falling back to `default`
+/// when it is unset.
+fn env_or<T>(name: &str, default: T) -> Result<T, AppError>
+where
+    T: std::str::FromStr,
+    T::Err: std::fmt::Display,
+{
+    match std::env::var(name) {
+        Ok(value) => value.parse().map_err(|e| {
+            AppError::Config(format!(
+                "{name} must be a valid {}: {e}",
+                std::any::type_name::<T>()
+            ))
+        }),
+        Err(_) => Ok(default),
+    }
+}
@@ -1,3 +1,8 @@
 //! Orchestration — cross-context coordination that the API composition root provides.
 
+pub mod actor;
 pub mod branch;
+pub mod effects;
+pub mod encounter;
+pub mod play;
+pub mod world_seed;
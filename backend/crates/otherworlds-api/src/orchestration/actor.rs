@@ -0,0 +1,103 @@
+//! Actor sheets — gathers a character's attributes, proficiencies and
+//! equipped-item bonuses from the Character and Inventory contexts, so the
+//! Rules context can derive a check's modifier from them. See ADR-0042.
+
+use otherworlds_character::application::query_handlers as character_queries;
+use otherworlds_core::error::DomainError;
+use otherworlds_core::repository::EventRepository;
+use otherworlds_inventory::application::query_handlers as inventory_queries;
+use otherworlds_rules::domain::modifiers::{ActorSheet, EquipmentBonus};
+use uuid::Uuid;
+
+/// Checks that a request either names the acting character or supplies a
+/// modifier, but not both, and returns the modifier to put on the command
+/// (zero when it will be derived).
+///
+/// # Errors
+///
+/// Returns `DomainError::Validation` if both or neither are given, or if an
+/// inventory is named without a character.
+pub fn supplied_modifier(
+    character_id: Option<Uuid>,
+    inventory_id: Option<Uuid>,
+    modifier: Option<i32>,
+) -> Result<i32, DomainError> {
+    match (character_id, modifier) {
+        (Some(_), Some(_)) => Err(DomainError::Validation(
+            "modifier is derived from the character sheet; omit it when character_id is given"
+                .into(),
+        )),
+        (None, None) => Err(DomainError::Validation(
+            "modifier is required unless character_id is given".into(),
+        )),
+        (None, Some(_)) if inventory_id.is_some() => Err(DomainError::Validation(
+            "inventory_id requires character_id".into(),
+        )),
+        (_, modifier) => Ok(modifier.unwrap_or(0)),
+    }
+}
+
+/// Loads the sheet of `character_id`, with the bonuses of the items
+/// equipped in `inventory_id`. Returns `None` when no character is named.
+///
+/// Both are read straight from their event streams, so replaying a run
+/// reads them as they stood when the command first ran.
+///
+/// # Errors
+///
+/// Returns `DomainError::AggregateNotFound` if the character or inventory
+/// does not exist and `DomainError::Validation` if either is archived.
+pub async fn load_actor_sheet(
+    character_id: Option<Uuid>,
+    inventory_id: Option<Uuid>,
+    repo: &dyn EventRepository,
+) -> Result<Option<ActorSheet>, DomainError> {
+    let Some(character_id) = character_id else {
+        return Ok(None);
+    };
+    let character = character_queries::get_character_sheet(character_id, repo).await?;
+    let equipment = match inventory_id {
+        Some(inventory_id) => inventory_queries::get_equipped_items(inventory_id, repo)
+            .await?
+            .into_iter()
+            .map(|item| EquipmentBonus {
+                item_id: item.item_id,
+                bonuses: item.bonuses,
+            })
+            .collect(),
+        None => Vec::new(),
+    };
+    Ok(Some(ActorSheet {
+        character_id,
+        attributes: character.attributes,
+        proficiencies: character.proficiencies,
+        equipment,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_supplied_modifier_requires_exactly_one_source() {
+        let character_id = Some(Uuid::new_v4());
+        let inventory_id = Some(Uuid::new_v4());
+
+        assert_eq!(supplied_modifier(None, None, Some(3)).unwrap(), 3);
+        assert_eq!(
+            supplied_modifier(character_id, inventory_id, None).unwrap(),
+            0
+        );
+        for (character_id, inventory_id, modifier) in [
+            (character_id, None, Some(3)),
+            (None, None, None),
+            (None, inventory_id, Some(3)),
+        ] {
+            assert!(matches!(
+                supplied_modifier(character_id, inventory_id, modifier),
+                Err(DomainError::Validation(_))
+            ));
+        }
+    }
+}
@@ -7,6 +7,7 @@ use otherworlds_core::clock::Clock;
 use otherworlds_core::error::DomainError;
 use otherworlds_core::repository::EventRepository;
 use otherworlds_core::rng::DeterministicRng;
+use otherworlds_core::unit_of_work::UnitOfWork;
 use tracing::{info, instrument};
 use uuid::Uuid;
 
@@ -34,9 +35,13 @@ pub struct BranchResult {
 ///    new IDs, and persist as a new aggregate.
 /// 4. Register each cloned aggregate with the branched run.
 ///
+/// Every step stages its events in one unit of work that is committed at the
+/// end, so a failure part-way through leaves no half-built branch behind.
+///
 /// # Errors
 ///
-/// Returns `DomainError` if any step fails (source not found, archived, etc.).
+/// Returns `DomainError` if any step fails (source not found, archived, etc.)
+/// or if another writer touched one of the affected streams before commit.
 #[instrument(skip(clock, rng, repo), fields(source_run_id = %source_run_id, from_checkpoint_id = %from_checkpoint_id))]
 pub async fn orchestrate_branch_timeline(
     source_run_id: Uuid,
@@ -46,6 +51,8 @@ pub async fn orchestrate_branch_timeline(
     rng: &Mutex<dyn DeterministicRng + Send>,
     repo: &dyn EventRepository,
 ) -> Result<BranchResult, DomainError> {
+    let uow = UnitOfWork::new(repo);
+
     // Step 1: Branch the session context.
     let branch_command = BranchTimeline {
         correlation_id,
@@ -54,7 +61,7 @@ pub async fn orchestrate_branch_timeline(
     };
 
     let session_result =
-        session_handlers::handle_branch_timeline(&branch_command, clock, rng, repo).await?;
+        session_handlers::handle_branch_timeline(&branch_command, clock, rng, &uow).await?;
     let branch_run_id = session_result.aggregate_id;
 
     let mut all_event_ids: Vec<Uuid> = session_result
@@ -66,14 +73,14 @@ pub async fn orchestrate_branch_timeline(
     info!(branch_run_id = %branch_run_id, "session branch created, cloning cross-context aggregates");
 
     // Step 2: Load the source run to get registered_aggregates.
-    let source_events = repo.load_events(source_run_id).await?;
+    let source_events = uow.load_events(source_run_id).await?;
     let source_run = session_handlers::reconstitute(source_run_id, &source_events)?;
 
     let mut cloned_aggregates = Vec::new();
 
     // Step 3: For each registered aggregate, clone its events.
     for (context_name, source_aggregate_id) in source_run.registered_aggregates() {
-        let source_agg_events = repo.load_events(*source_aggregate_id).await?;
+        let source_agg_events = uow.load_events(*source_aggregate_id).await?;
         if source_agg_events.is_empty() {
             continue;
         }
@@ -109,7 +116,7 @@ pub async fn orchestrate_branch_timeline(
         );
 
         all_event_ids.extend(cloned_events.iter().map(|e| e.event_id));
-        repo.append_events(new_aggregate_id, 0, &cloned_events)
+        uow.append_events(new_aggregate_id, 0, &cloned_events)
             .await?;
 
         // Step 4: Register the cloned aggregate with the branched run.
@@ -121,13 +128,17 @@ pub async fn orchestrate_branch_timeline(
         };
 
         let register_result =
-            session_handlers::handle_register_aggregate(&register_command, clock, rng, repo)
+            session_handlers::handle_register_aggregate(&register_command, clock, rng, &uow)
                 .await?;
         all_event_ids.extend(register_result.stored_events.iter().map(|e| e.event_id));
 
         cloned_aggregates.push((context_name.clone(), new_aggregate_id));
     }
 
+    uow.commit().await?;
+
+    info!(branch_run_id = %branch_run_id, "branch committed");
+
     Ok(BranchResult {
         branch_run_id,
         event_ids: all_event_ids,
@@ -174,6 +185,7 @@ mod tests {
                     CampaignRunStarted {
                         run_id,
                         campaign_id,
+                        seed: 42,
                     },
                 ))
                 .unwrap(),
@@ -181,6 +193,7 @@ mod tests {
                 correlation_id: Uuid::new_v4(),
                 causation_id: Uuid::new_v4(),
                 occurred_at: fixed_now,
+                schema_version: 2,
             },
             StoredEvent {
                 event_id: Uuid::new_v4(),
@@ -197,6 +210,7 @@ mod tests {
                 correlation_id: Uuid::new_v4(),
                 causation_id: Uuid::new_v4(),
                 occurred_at: fixed_now,
+                schema_version: 1,
             },
         ]
     }
@@ -224,6 +238,7 @@ mod tests {
             correlation_id: Uuid::new_v4(),
             causation_id: Uuid::new_v4(),
             occurred_at: fixed_now,
+            schema_version: 1,
         }
     }
 
@@ -245,6 +260,7 @@ mod tests {
                     correlation_id: Uuid::new_v4(),
                     causation_id: Uuid::new_v4(),
                     occurred_at: fixed_now,
+                    schema_version: 1,
                 }
             })
             .collect()
@@ -334,10 +350,11 @@ mod tests {
         // Verify events were appended for the cloned aggregate
         let appended = repo.appended_events();
 
-        // appended[0] = session branch events (3)
+        // appended[0] = session branch events (3) + register aggregate event (1)
         // appended[1] = cloned narrative events (2)
-        // appended[2] = register aggregate event (1)
-        assert!(appended.len() >= 3);
+        assert_eq!(appended.len(), 2);
+        assert_eq!(appended[0].0, branch.branch_run_id);
+        assert_eq!(appended[0].2.len(), 4);
 
         // Check cloned narrative events
         let (cloned_agg_id, expected_version, cloned_events) = &appended[1];
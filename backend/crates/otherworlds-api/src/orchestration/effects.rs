@@ -0,0 +1,446 @@
+//! Effect routing — dispatches the effects a resolution produced to the
+//! contexts that own them. See ADR-0046.
+//!
+//! | Effect              | Context     | Command                              |
+//! |---------------------|-------------|--------------------------------------|
+//! | `damage`, `heal`    | Character   | `ModifyAttribute` on `hit_points`    |
+//! | `award_xp`          | Character   | `AwardExperience`                    |
+//! | `grant_item`        | Inventory   | `AddItem` (the target is the inventory) |
+//! | `set_flag`          | World State | `SetFlag`                            |
+//! | `shift_disposition` | World State | `UpdateDisposition`                  |
+//! | `apply_status`, `custom` | World State | `ApplyEffect` as a world fact   |
+//!
+//! Every effect is routed before any command runs, so an effect that cannot
+//! be routed rejects the turn before a single routed event is appended.
+//! Routed commands carry the turn's correlation ID and name the
+//! `EffectsProduced` event as their cause.
+
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+
+use uuid::Uuid;
+
+use otherworlds_character::application::command_handlers as character_handlers;
+use otherworlds_character::application::query_handlers as character_queries;
+use otherworlds_character::domain::commands as character_commands;
+use otherworlds_core::clock::Clock;
+use otherworlds_core::error::DomainError;
+use otherworlds_core::repository::{EventRepository, StoredEvent};
+use otherworlds_core::rng::DeterministicRng;
+use otherworlds_inventory::application::command_handlers as inventory_handlers;
+use otherworlds_inventory::domain::commands as inventory_commands;
+use otherworlds_rules::domain::effects::{Damage, Effect, Heal};
+use otherworlds_rules::domain::events::{ResolvedEffect, RulesEventKind};
+use otherworlds_world_state::application::command_handlers as world_state_handlers;
+use otherworlds_world_state::domain::commands as world_state_commands;
+
+/// The character attribute damage and healing change.
+pub const HIT_POINTS: &str = "hit_points";
+
+/// The character attribute healing is capped at, when the sheet has it.
+pub const MAX_HIT_POINTS: &str = "max_hit_points";
+
+/// A command for the context that owns an effect.
+#[derive(Debug, Clone)]
+pub enum EffectCommand {
+    /// Sets a character's hit points after damage or healing.
+    ModifyAttribute(character_commands::ModifyAttribute),
+    /// Awards a character experience.
+    AwardExperience(character_commands::AwardExperience),
+    /// Adds an item to an inventory.
+    AddItem(inventory_commands::AddItem),
+    /// Sets a world flag.
+    SetFlag(world_state_commands::SetFlag),
+    /// Shifts an entity's disposition.
+    UpdateDisposition(world_state_commands::UpdateDisposition),
+    /// Records an effect no context models yet as a world fact.
+    ApplyEffect(world_state_commands::ApplyEffect),
+}
+
+/// The events routed effects produced, by the context that recorded them.
+#[derive(Debug, Default)]
+pub struct DispatchedEvents {
+    /// Events recorded by characters.
+    pub character: Vec<StoredEvent>,
+    /// Events recorded by inventories.
+    pub inventory: Vec<StoredEvent>,
+    /// Events recorded by the world snapshot.
+    pub world_state: Vec<StoredEvent>,
+}
+
+/// A character's hit points as earlier effects of the turn left them.
+#[derive(Debug, Clone, Copy)]
+struct HitPoints {
+    current: i32,
+    max: Option<i32>,
+}
+
+/// Routes every effect carried by `effects_events` to a command for the
+/// context that owns it, without running any of them.
+///
+/// Damage and healing read the target's hit points from its sheet and
+/// accumulate over the turn, so two hits on one character both count.
+///
+/// # Errors
+///
+/// Returns `DomainError::Validation` if an `EffectsProduced` payload holds an
+/// effect outside the catalogue, an effect lacks the target it needs, or a
+/// damaged or healed character has no `hit_points` attribute.
+/// Returns `DomainError::AggregateNotFound` if a damaged or healed character
+/// does not exist.
+pub async fn route_effects(
+    effects_events: &[StoredEvent],
+    world_id: Uuid,
+    correlation_id: Uuid,
+    repo: &dyn EventRepository,
+) -> Result<Vec<EffectCommand>, DomainError> {
+    let mut hit_points: BTreeMap<Uuid, HitPoints> = BTreeMap::new();
+    let mut commands = Vec::new();
+    for stored in effects_events {
+        if stored.event_type != "rules.effects_produced" {
+            continue;
+        }
+        let RulesEventKind::EffectsProduced(produced) =
+            serde_json::from_value::<RulesEventKind>(stored.payload.clone())
+                .map_err(|e| DomainError::Validation(format!("cannot route effects: {e}")))?
+        else {
+            continue;
+        };
+        let causation_id = Some(stored.event_id);
+        for effect in &produced.effects {
+            let command = match &effect.effect {
+                Effect::Damage(Damage { amount, .. }) | Effect::Heal(Heal { amount }) => {
+                    let character_id = target(effect)?;
+                    let amount = signed(*amount)?;
+                    let change = if matches!(effect.effect, Effect::Damage(_)) {
+                        -amount
+                    } else {
+                        amount
+                    };
+                    let new_value =
+                        change_hit_points(character_id, change, &mut hit_points, repo).await?;
+                    EffectCommand::ModifyAttribute(character_commands::ModifyAttribute {
+                        correlation_id,
+                        causation_id,
+                        character_id,
+                        attribute: HIT_POINTS.to_owned(),
+                        new_value,
+                    })
+                }
+                Effect::AwardXp(award) => {
+                    EffectCommand::AwardExperience(character_commands::AwardExperience {
+                        correlation_id,
+                        causation_id,
+                        character_id: target(effect)?,
+                        amount: award.amount,
+                    })
+                }
+                Effect::GrantItem(grant) => EffectCommand::AddItem(inventory_commands::AddItem {
+                    correlation_id,
+                    causation_id,
+                    inventory_id: target(effect)?,
+                    item_id: grant.item_id,
+                }),
+                Effect::SetFlag(flag) => EffectCommand::SetFlag(world_state_commands::SetFlag {
+                    correlation_id,
+                    causation_id,
+                    world_id,
+                    flag_key: flag.flag.clone(),
+                    value: flag.value,
+                }),
+                Effect::ShiftDisposition(shift) => {
+                    EffectCommand::UpdateDisposition(world_state_commands::UpdateDisposition {
+                        correlation_id,
+                        causation_id,
+                        world_id,
+                        entity_id: target(effect)?,
+                        delta: shift.delta,
+                    })
+                }
+                Effect::ApplyStatus(_) | Effect::Custom(_) => {
+                    EffectCommand::ApplyEffect(world_state_commands::ApplyEffect {
+                        correlation_id,
+                        causation_id,
+                        world_id,
+                        fact_key: fact_key(effect),
+                    })
+                }
+            };
+            commands.push(command);
+        }
+    }
+    Ok(commands)
+}
+
+/// Runs routed commands in order against their contexts' handlers.
+///
+/// # Errors
+///
+/// Returns the first error a handler returns.
+pub async fn dispatch_effects(
+    commands: &[EffectCommand],
+    clock: &dyn Clock,
+    rng: &Mutex<dyn DeterministicRng + Send>,
+    repo: &dyn EventRepository,
+) -> Result<DispatchedEvents, DomainError> {
+    let mut dispatched = DispatchedEvents::default();
+    for command in commands {
+        match command {
+            EffectCommand::ModifyAttribute(command) => dispatched.character.extend(
+                character_handlers::handle_modify_attribute(command, clock, rng, repo).await?,
+            ),
+            EffectCommand::AwardExperience(command) => dispatched.character.extend(
+                character_handlers::handle_award_experience(command, clock, rng, repo).await?,
+            ),
+            EffectCommand::AddItem(command) => dispatched.inventory.extend(
+                inventory_handlers::handle_add_item(command, clock, rng, repo)
+                    .await?
+                    .stored_events,
+            ),
+            EffectCommand::SetFlag(command) => dispatched
+                .world_state
+                .extend(world_state_handlers::handle_set_flag(command, clock, rng, repo).await?),
+            EffectCommand::UpdateDisposition(command) => dispatched.world_state.extend(
+                world_state_handlers::handle_update_disposition(command, clock, rng, repo).await?,
+            ),
+            EffectCommand::ApplyEffect(command) => dispatched.world_state.extend(
+                world_state_handlers::handle_apply_effect(command, clock, rng, repo).await?,
+            ),
+        }
+    }
+    Ok(dispatched)
+}
+
+fn target(effect: &ResolvedEffect) -> Result<Uuid, DomainError> {
+    effect.target_id.ok_or_else(|| {
+        DomainError::Validation(format!(
+            "{} effect has no target to route to",
+            effect.effect.effect_type()
+        ))
+    })
+}
+
+fn signed(amount: u32) -> Result<i32, DomainError> {
+    i32::try_from(amount)
+        .map_err(|_| DomainError::Validation(format!("effect amount {amount} is too large")))
+}
+
+/// Returns the world fact recording an effect no context models yet.
+fn fact_key(effect: &ResolvedEffect) -> String {
+    match effect.target_id {
+        Some(target_id) => format!(
+            "{}:{target_id}:{}",
+            effect.effect.effect_type(),
+            effect.effect.payload()
+        ),
+        None => format!(
+            "{}:{}",
+            effect.effect.effect_type(),
+            effect.effect.payload()
+        ),
+    }
+}
+
+/// Applies `change` to a character's hit points, reading them from its sheet
+/// the first time, and returns the new value. Hit points never drop below
+/// zero and healing never raises them above `max_hit_points`.
+async fn change_hit_points(
+    character_id: Uuid,
+    change: i32,
+    hit_points: &mut BTreeMap<Uuid, HitPoints>,
+    repo: &dyn EventRepository,
+) -> Result<i32, DomainError> {
+    let current = if let Some(current) = hit_points.get(&character_id) {
+        *current
+    } else {
+        let sheet = character_queries::get_character_sheet(character_id, repo).await?;
+        let current = *sheet.attributes.get(HIT_POINTS).ok_or_else(|| {
+            DomainError::Validation(format!(
+                "character {character_id} has no {HIT_POINTS} attribute"
+            ))
+        })?;
+        HitPoints {
+            current,
+            max: sheet.attributes.get(MAX_HIT_POINTS).copied(),
+        }
+    };
+    let mut new_value = current.current.saturating_add(change).max(0);
+    if change > 0
+        && let Some(max) = current.max
+    {
+        new_value = new_value.min(max.max(current.current));
+    }
+    hit_points.insert(
+        character_id,
+        HitPoints {
+            current: new_value,
+            ..current
+        },
+    );
+    Ok(new_value)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use otherworlds_rules::domain::effects::{ApplyStatus, GrantItem, SetFlag};
+    use otherworlds_rules::domain::events::EffectsProduced;
+    use otherworlds_test_support::EmptyEventRepository;
+
+    use super::*;
+
+    fn effects_produced(payload: serde_json::Value) -> StoredEvent {
+        StoredEvent {
+            event_id: Uuid::new_v4(),
+            aggregate_id: Uuid::new_v4(),
+            event_type: "rules.effects_produced".to_owned(),
+            payload,
+            sequence_number: 3,
+            correlation_id: Uuid::new_v4(),
+            causation_id: Uuid::new_v4(),
+            occurred_at: Utc::now(),
+            schema_version: 3,
+        }
+    }
+
+    fn produced(effects: Vec<ResolvedEffect>) -> StoredEvent {
+        effects_produced(
+            serde_json::to_value(RulesEventKind::EffectsProduced(EffectsProduced {
+                resolution_id: Uuid::new_v4(),
+                effects,
+                tier: None,
+            }))
+            .unwrap(),
+        )
+    }
+
+    #[tokio::test]
+    async fn test_route_effects_sends_each_effect_to_its_owning_context() {
+        // Arrange
+        let world_id = Uuid::new_v4();
+        let correlation_id = Uuid::new_v4();
+        let inventory_id = Uuid::new_v4();
+        let item_id = Uuid::new_v4();
+        let event = produced(vec![
+            ResolvedEffect {
+                target_id: Some(inventory_id),
+                effect: Effect::GrantItem(GrantItem { item_id }),
+            },
+            ResolvedEffect {
+                target_id: None,
+                effect: Effect::SetFlag(SetFlag {
+                    flag: "gate_open".to_owned(),
+                    value: true,
+                }),
+            },
+            ResolvedEffect {
+                target_id: None,
+                effect: Effect::ApplyStatus(ApplyStatus {
+                    status: "blessed".to_owned(),
+                    duration_rounds: None,
+                }),
+            },
+        ]);
+
+        // Act
+        let commands = route_effects(
+            std::slice::from_ref(&event),
+            world_id,
+            correlation_id,
+            &EmptyEventRepository,
+        )
+        .await
+        .unwrap();
+
+        // Assert
+        assert_eq!(commands.len(), 3);
+        let EffectCommand::AddItem(add_item) = &commands[0] else {
+            panic!("expected AddItem, got {:?}", commands[0]);
+        };
+        assert_eq!(add_item.inventory_id, inventory_id);
+        assert_eq!(add_item.item_id, item_id);
+        assert_eq!(add_item.correlation_id, correlation_id);
+        assert_eq!(add_item.causation_id, Some(event.event_id));
+        let EffectCommand::SetFlag(set_flag) = &commands[1] else {
+            panic!("expected SetFlag, got {:?}", commands[1]);
+        };
+        assert_eq!(set_flag.world_id, world_id);
+        assert_eq!(set_flag.flag_key, "gate_open");
+        let EffectCommand::ApplyEffect(apply_effect) = &commands[2] else {
+            panic!("expected ApplyEffect, got {:?}", commands[2]);
+        };
+        assert!(apply_effect.fact_key.starts_with("apply_status:"));
+    }
+
+    #[tokio::test]
+    async fn test_route_effects_rejects_unknown_effect_types() {
+        // Arrange
+        let event = effects_produced(serde_json::json!({
+            "EffectsProduced": {
+                "resolution_id": Uuid::new_v4(),
+                "effects": [{ "target_id": null, "effect_type": "reveal", "payload": {} }],
+                "tier": null
+            }
+        }));
+
+        // Act
+        let result = route_effects(
+            &[event],
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            &EmptyEventRepository,
+        )
+        .await;
+
+        // Assert
+        assert!(matches!(result, Err(DomainError::Validation(_))));
+    }
+
+    #[tokio::test]
+    async fn test_route_effects_rejects_untargeted_item_grants() {
+        // Arrange
+        let event = produced(vec![ResolvedEffect {
+            target_id: None,
+            effect: Effect::GrantItem(GrantItem {
+                item_id: Uuid::new_v4(),
+            }),
+        }]);
+
+        // Act
+        let result = route_effects(
+            &[event],
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            &EmptyEventRepository,
+        )
+        .await;
+
+        // Assert
+        assert!(matches!(result, Err(DomainError::Validation(m)) if m.contains("grant_item")));
+    }
+
+    #[tokio::test]
+    async fn test_route_effects_rejects_damage_to_missing_characters() {
+        // Arrange
+        let character_id = Uuid::new_v4();
+        let event = produced(vec![ResolvedEffect {
+            target_id: Some(character_id),
+            effect: Effect::Damage(Damage {
+                amount: 3,
+                damage_type: None,
+            }),
+        }]);
+
+        // Act
+        let result = route_effects(
+            &[event],
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            &EmptyEventRepository,
+        )
+        .await;
+
+        // Assert
+        assert!(matches!(result, Err(DomainError::AggregateNotFound(id)) if id == character_id));
+    }
+}
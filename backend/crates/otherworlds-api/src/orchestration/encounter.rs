@@ -0,0 +1,102 @@
+//! Encounter orchestration — starts a combat encounter and registers it with
+//! its campaign run so branching the run carries the encounter along.
+
+use std::sync::Mutex;
+
+use tracing::{info, instrument};
+use uuid::Uuid;
+
+use otherworlds_core::clock::Clock;
+use otherworlds_core::error::DomainError;
+use otherworlds_core::repository::{EventRepository, StoredEvent};
+use otherworlds_core::rng::DeterministicRng;
+use otherworlds_rules::application::encounter_handlers;
+use otherworlds_rules::domain::commands::StartEncounter;
+use otherworlds_session::application::command_handlers as session_handlers;
+use otherworlds_session::domain::commands::RegisterAggregate;
+
+/// Context name an encounter is registered under on its campaign run.
+///
+/// A run tracks one aggregate per context, so starting a new encounter
+/// replaces the registration of the previous one.
+pub const ENCOUNTER_CONTEXT: &str = "encounter";
+
+/// Starts the encounter and, when the command belongs to a campaign run,
+/// registers it with that run. Both land in `repo`, which for a run is the
+/// run scope's unit of work, so they commit together.
+///
+/// # Errors
+///
+/// Returns `DomainError` if starting the encounter or registering it fails.
+#[instrument(skip(command, clock, rng, repo), fields(encounter_id = %command.encounter_id, correlation_id = %command.correlation_id))]
+pub async fn orchestrate_start_encounter(
+    command: &StartEncounter,
+    run_id: Option<Uuid>,
+    clock: &dyn Clock,
+    rng: &Mutex<dyn DeterministicRng + Send>,
+    repo: &dyn EventRepository,
+) -> Result<Vec<StoredEvent>, DomainError> {
+    let mut stored_events =
+        encounter_handlers::handle_start_encounter(command, clock, rng, repo).await?;
+
+    if let Some(run_id) = run_id {
+        let register = RegisterAggregate {
+            correlation_id: command.correlation_id,
+            run_id,
+            context_name: ENCOUNTER_CONTEXT.to_owned(),
+            aggregate_id: command.encounter_id,
+        };
+        let result =
+            session_handlers::handle_register_aggregate(&register, clock, rng, repo).await?;
+        info!(run_id = %run_id, "encounter registered with campaign run");
+        stored_events.extend(result.stored_events);
+    }
+
+    Ok(stored_events)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use chrono::{TimeZone, Utc};
+    use otherworlds_core::rng::DeterministicRng;
+    use otherworlds_rules::domain::encounter::EncounterParticipant;
+    use otherworlds_test_support::{FixedClock, RecordingEventRepository, SequenceRng};
+    use uuid::Uuid;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn test_orchestrate_start_encounter_without_run_only_starts_encounter() {
+        // Arrange
+        let command = StartEncounter {
+            correlation_id: Uuid::new_v4(),
+            encounter_id: Uuid::new_v4(),
+            participants: vec![EncounterParticipant {
+                participant_id: Uuid::new_v4(),
+                name: "Hero".to_owned(),
+                initiative_modifier: 0,
+            }],
+        };
+        let clock = FixedClock(Utc.with_ymd_and_hms(2026, 1, 15, 10, 0, 0).unwrap());
+        let rng: Mutex<SequenceRng> =
+            Mutex::new(SequenceRng::new(vec![10, 1, 2, 3, 4, 5, 6, 7, 8]));
+        let rng_ref: &Mutex<dyn DeterministicRng + Send> = &rng;
+        let repo = RecordingEventRepository::new(Ok(Vec::new()));
+
+        // Act
+        let events = orchestrate_start_encounter(&command, None, &clock, rng_ref, &repo)
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(events.len(), 2);
+        assert!(
+            events
+                .iter()
+                .all(|e| e.aggregate_id == command.encounter_id)
+        );
+        assert_eq!(repo.appended_events().len(), 1);
+    }
+}
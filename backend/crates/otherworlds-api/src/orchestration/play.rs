@@ -0,0 +1,401 @@
+//! Play orchestration — coordinates one turn of the play loop across contexts.
+//!
+//! Intent → Check → Effects → Owning contexts → Narrative. See ADR-0014 and
+//! ADR-0046.
+
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+use tracing::{info, instrument};
+use uuid::Uuid;
+
+use otherworlds_content::application::query_handlers as content_queries;
+use otherworlds_core::clock::Clock;
+use otherworlds_core::command::Command;
+use otherworlds_core::error::DomainError;
+use otherworlds_core::repository::{EventRepository, StoredEvent};
+use otherworlds_core::rng::DeterministicRng;
+use otherworlds_core::unit_of_work::UnitOfWork;
+use otherworlds_narrative::application::command_handlers as narrative_handlers;
+use otherworlds_narrative::domain::commands as narrative_commands;
+use otherworlds_rules::application::command_handlers as rules_handlers;
+use otherworlds_rules::domain::commands as rules_commands;
+use otherworlds_rules::domain::effects::CustomEffectSchema;
+use otherworlds_rules::domain::events::{OpposedParticipant, RollMode, TieBreak};
+use otherworlds_rules::domain::outcome_table::OutcomeTable;
+use otherworlds_rules::domain::ruleset::RulesetId;
+
+use crate::orchestration::{actor, effects};
+
+/// Command to resolve one player action through the full play loop.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResolveAction {
+    /// The correlation ID threading all events together.
+    pub correlation_id: Uuid,
+    /// The narrative session to advance.
+    pub session_id: Uuid,
+    /// The world snapshot to apply effects to.
+    pub world_id: Uuid,
+    /// Optional campaign whose front-matter ruleset judges the check; d20
+    /// when absent.
+    #[serde(default)]
+    pub campaign_id: Option<Uuid>,
+    /// The type of action (e.g., "`skill_check`", "`attack`", "`save`").
+    pub action_type: String,
+    /// Optional skill being used.
+    pub skill: Option<String>,
+    /// Optional target of the action.
+    pub target_id: Option<Uuid>,
+    /// The difficulty class to beat.
+    pub difficulty_class: i32,
+    /// The modifier applied to the roll; zero when `character_id` is given.
+    #[serde(default)]
+    pub modifier: i32,
+    /// Optional character making the check; the modifier is then derived
+    /// from its sheet for `skill`.
+    #[serde(default)]
+    pub character_id: Option<Uuid>,
+    /// Optional inventory of that character, whose equipped items add
+    /// their bonuses.
+    #[serde(default)]
+    pub inventory_id: Option<Uuid>,
+    /// Optional dice expression to roll; the ruleset's default roll when
+    /// absent.
+    #[serde(default)]
+    pub roll_expression: Option<String>,
+    /// How many times the expression is rolled and which roll counts
+    /// (e.g., "`advantage`", `{"keep_best": 3}`); rolled once when absent.
+    #[serde(default)]
+    pub roll_mode: RollMode,
+    /// Effects produced whatever the check's outcome.
+    #[serde(default)]
+    pub effects: Vec<rules_commands::EffectSpec>,
+    /// Effects keyed by the check's outcome, instead of `effects`.
+    #[serde(default)]
+    pub outcome_effects: Option<OutcomeTable<rules_commands::EffectSpec>>,
+}
+
+impl Command for ResolveAction {
+    fn command_type(&self) -> &'static str {
+        "play.resolve_action"
+    }
+
+    fn correlation_id(&self) -> Uuid {
+        self.correlation_id
+    }
+}
+
+/// Command to resolve one contested action, where two participants roll
+/// against each other, through the full play loop.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResolveOpposedAction {
+    /// The correlation ID threading all events together.
+    pub correlation_id: Uuid,
+    /// The narrative session to advance.
+    pub session_id: Uuid,
+    /// The world snapshot to apply effects to.
+    pub world_id: Uuid,
+    /// Optional campaign whose front-matter ruleset the rolls are made
+    /// under; d20 when absent.
+    #[serde(default)]
+    pub campaign_id: Option<Uuid>,
+    /// The type of action (e.g., "`grapple`", "`contest`").
+    pub action_type: String,
+    /// Optional skill being contested.
+    pub skill: Option<String>,
+    /// The participant initiating the contest.
+    pub actor: OpposedParticipant,
+    /// The participant resisting it.
+    pub opponent: OpposedParticipant,
+    /// Optional dice expression both participants roll; the ruleset's
+    /// default roll when absent.
+    #[serde(default)]
+    pub roll_expression: Option<String>,
+    /// Who wins when both totals are equal; the defender when absent.
+    #[serde(default)]
+    pub tie_break: TieBreak,
+    /// Effects produced whatever the contest's outcome.
+    #[serde(default)]
+    pub effects: Vec<rules_commands::EffectSpec>,
+    /// Effects keyed by the contest's outcome, instead of `effects`.
+    #[serde(default)]
+    pub outcome_effects: Option<OutcomeTable<rules_commands::EffectSpec>>,
+}
+
+impl Command for ResolveOpposedAction {
+    fn command_type(&self) -> &'static str {
+        "play.resolve_opposed_action"
+    }
+
+    fn correlation_id(&self) -> Uuid {
+        self.correlation_id
+    }
+}
+
+/// Result of a resolved action, grouping event IDs by play-loop phase.
+#[derive(Debug)]
+pub struct PlayResult {
+    /// The resolution aggregate ID (rules context).
+    pub resolution_id: Uuid,
+    /// Event IDs from declaring intent.
+    pub intent_event_ids: Vec<Uuid>,
+    /// Event IDs from resolving the check.
+    pub check_event_ids: Vec<Uuid>,
+    /// Event IDs from producing effects.
+    pub effects_event_ids: Vec<Uuid>,
+    /// Event IDs from applying effects to characters.
+    pub character_event_ids: Vec<Uuid>,
+    /// Event IDs from applying effects to inventories.
+    pub inventory_event_ids: Vec<Uuid>,
+    /// Event IDs from applying effects to world state.
+    pub world_state_event_ids: Vec<Uuid>,
+    /// Event IDs from advancing the narrative beat.
+    pub narrative_event_ids: Vec<Uuid>,
+}
+
+fn collect_event_ids(events: &[StoredEvent]) -> Vec<Uuid> {
+    events.iter().map(|e| e.event_id).collect()
+}
+
+/// The rules a campaign's front matter sets for its checks and effects.
+#[derive(Debug, Clone, Default)]
+pub struct CampaignRules {
+    /// The ruleset checks are judged by.
+    pub ruleset: RulesetId,
+    /// The custom effects the campaign declares.
+    pub custom_effects: Vec<CustomEffectSchema>,
+}
+
+/// Loads the rules of `campaign_id` — the d20 ruleset and no custom effects
+/// when no campaign is given, or its front matter names none.
+///
+/// # Errors
+///
+/// Returns `DomainError::AggregateNotFound` if the campaign has not been
+/// compiled and `DomainError::Validation` if it names an unknown ruleset.
+pub async fn load_campaign_rules(
+    campaign_id: Option<Uuid>,
+    repo: &dyn EventRepository,
+) -> Result<CampaignRules, DomainError> {
+    let Some(campaign_id) = campaign_id else {
+        return Ok(CampaignRules::default());
+    };
+    let campaign = content_queries::get_compiled_campaign(campaign_id, repo).await?;
+    let ruleset = campaign
+        .ruleset
+        .as_deref()
+        .map_or(Ok(RulesetId::default()), str::parse)?;
+    let custom_effects = campaign
+        .custom_effects
+        .into_iter()
+        .map(|effect| CustomEffectSchema {
+            name: effect.name,
+            version: effect.version,
+            schema: effect.schema,
+        })
+        .collect();
+    Ok(CampaignRules {
+        ruleset,
+        custom_effects,
+    })
+}
+
+/// The parts of a turn shared by every kind of action once its intent has
+/// been declared.
+struct Turn<'a> {
+    correlation_id: Uuid,
+    resolution_id: Uuid,
+    session_id: Uuid,
+    world_id: Uuid,
+    effects: &'a [rules_commands::EffectSpec],
+    outcome_effects: Option<&'a OutcomeTable<rules_commands::EffectSpec>>,
+    custom_effects: Vec<CustomEffectSchema>,
+}
+
+/// Draws the resolution and intent IDs for a turn from `rng`.
+fn draw_turn_ids(rng: &Mutex<dyn DeterministicRng + Send>) -> Result<(Uuid, Uuid), DomainError> {
+    let mut rng = rng
+        .lock()
+        .map_err(|e| DomainError::Infrastructure(format!("RNG mutex poisoned: {e}")))?;
+    Ok((rng.next_uuid(), rng.next_uuid()))
+}
+
+/// Runs the rest of the play loop after the intent is declared — resolve
+/// the check, produce effects, dispatch them to their owning contexts,
+/// advance the beat — then commits the unit of work.
+async fn complete_turn(
+    turn: Turn<'_>,
+    intent_events: Vec<StoredEvent>,
+    clock: &dyn Clock,
+    rng: &Mutex<dyn DeterministicRng + Send>,
+    uow: UnitOfWork<'_>,
+) -> Result<PlayResult, DomainError> {
+    let Turn {
+        correlation_id,
+        resolution_id,
+        ..
+    } = turn;
+
+    // Step 2: Resolve check (rules context)
+    let resolve_check_cmd = rules_commands::ResolveCheck {
+        correlation_id,
+        resolution_id,
+    };
+    let check_events =
+        rules_handlers::handle_resolve_check(&resolve_check_cmd, clock, rng, &uow).await?;
+
+    // Step 3: Produce effects (rules context)
+    let produce_effects_cmd = rules_commands::ProduceEffects {
+        correlation_id,
+        resolution_id,
+        effects: turn.effects.to_vec(),
+        outcome_effects: turn.outcome_effects.cloned(),
+        custom_effects: turn.custom_effects,
+    };
+    let effects_events =
+        rules_handlers::handle_produce_effects(&produce_effects_cmd, clock, rng, &uow).await?;
+
+    // Step 4: Route every effect to its owning context before dispatching any
+    let effect_commands =
+        effects::route_effects(&effects_events, turn.world_id, correlation_id, &uow).await?;
+    let dispatched = effects::dispatch_effects(&effect_commands, clock, rng, &uow).await?;
+
+    // Step 5: Advance narrative beat
+    let advance_beat_cmd = narrative_commands::AdvanceBeat {
+        correlation_id,
+        session_id: turn.session_id,
+    };
+    let narrative_events =
+        narrative_handlers::handle_advance_beat(&advance_beat_cmd, clock, rng, &uow).await?;
+
+    uow.commit().await?;
+
+    info!(%correlation_id, "play loop committed");
+
+    Ok(PlayResult {
+        resolution_id,
+        intent_event_ids: collect_event_ids(&intent_events),
+        check_event_ids: collect_event_ids(&check_events),
+        effects_event_ids: collect_event_ids(&effects_events),
+        character_event_ids: collect_event_ids(&dispatched.character),
+        inventory_event_ids: collect_event_ids(&dispatched.inventory),
+        world_state_event_ids: collect_event_ids(&dispatched.world_state),
+        narrative_event_ids: collect_event_ids(&narrative_events),
+    })
+}
+
+/// Orchestrates the full play loop:
+/// 1. Rules: declare intent under the campaign's ruleset, with the modifier
+///    derived from the acting character's sheet when one is named
+/// 2. Rules: resolve check (roll the intent's dice expression)
+/// 3. Rules: produce effects
+/// 4. Character, Inventory, World State: dispatch each effect to the context
+///    that owns it (see [`effects`])
+/// 5. Narrative: advance the beat
+///
+/// The resolution and intent IDs are drawn from `rng`, so replaying the
+/// command against the same RNG reproduces them. All steps run inside one
+/// unit of work, so the events of every context are committed together or
+/// not at all.
+///
+/// # Errors
+///
+/// Returns `DomainError` if any step fails or if another writer touched one
+/// of the affected streams before commit.
+#[instrument(skip(command, clock, rng, repo), fields(session_id = %command.session_id, world_id = %command.world_id))]
+pub async fn orchestrate_resolve_action(
+    command: &ResolveAction,
+    clock: &dyn Clock,
+    rng: &Mutex<dyn DeterministicRng + Send>,
+    repo: &dyn EventRepository,
+) -> Result<PlayResult, DomainError> {
+    let correlation_id = command.correlation_id;
+    let (resolution_id, intent_id) = draw_turn_ids(rng)?;
+
+    info!(%correlation_id, %resolution_id, "orchestrating play loop");
+
+    let rules = load_campaign_rules(command.campaign_id, repo).await?;
+    let actor = actor::load_actor_sheet(command.character_id, command.inventory_id, repo).await?;
+    let uow = UnitOfWork::new(repo);
+
+    // Step 1: Declare intent (rules context)
+    let declare_intent_cmd = rules_commands::DeclareIntent {
+        correlation_id,
+        resolution_id,
+        intent_id,
+        action_type: command.action_type.clone(),
+        skill: command.skill.clone(),
+        target_id: command.target_id,
+        difficulty_class: command.difficulty_class,
+        modifier: command.modifier,
+        actor,
+        roll_expression: command.roll_expression.clone(),
+        roll_mode: command.roll_mode,
+        ruleset: rules.ruleset,
+    };
+    let intent_events =
+        rules_handlers::handle_declare_intent(&declare_intent_cmd, clock, rng, &uow).await?;
+
+    let turn = Turn {
+        correlation_id,
+        resolution_id,
+        session_id: command.session_id,
+        world_id: command.world_id,
+        effects: &command.effects,
+        outcome_effects: command.outcome_effects.as_ref(),
+        custom_effects: rules.custom_effects,
+    };
+    complete_turn(turn, intent_events, clock, rng, uow).await
+}
+
+/// Orchestrates the play loop for a contested action. Identical to
+/// [`orchestrate_resolve_action`] except that step 1 declares an opposed
+/// intent, so step 2 rolls for both participants and compares their totals.
+///
+/// # Errors
+///
+/// Returns `DomainError` if any step fails or if another writer touched one
+/// of the affected streams before commit.
+#[instrument(skip(command, clock, rng, repo), fields(session_id = %command.session_id, world_id = %command.world_id))]
+pub async fn orchestrate_resolve_opposed_action(
+    command: &ResolveOpposedAction,
+    clock: &dyn Clock,
+    rng: &Mutex<dyn DeterministicRng + Send>,
+    repo: &dyn EventRepository,
+) -> Result<PlayResult, DomainError> {
+    let correlation_id = command.correlation_id;
+    let (resolution_id, intent_id) = draw_turn_ids(rng)?;
+
+    info!(%correlation_id, %resolution_id, "orchestrating opposed play loop");
+
+    let rules = load_campaign_rules(command.campaign_id, repo).await?;
+    let uow = UnitOfWork::new(repo);
+
+    // Step 1: Declare the opposed intent (rules context)
+    let declare_intent_cmd = rules_commands::DeclareOpposedIntent {
+        correlation_id,
+        resolution_id,
+        intent_id,
+        action_type: command.action_type.clone(),
+        skill: command.skill.clone(),
+        actor: command.actor,
+        opponent: command.opponent,
+        roll_expression: command.roll_expression.clone(),
+        ruleset: rules.ruleset,
+        tie_break: command.tie_break,
+    };
+    let intent_events =
+        rules_handlers::handle_declare_opposed_intent(&declare_intent_cmd, clock, rng, &uow)
+            .await?;
+
+    let turn = Turn {
+        correlation_id,
+        resolution_id,
+        session_id: command.session_id,
+        world_id: command.world_id,
+        effects: &command.effects,
+        outcome_effects: command.outcome_effects.as_ref(),
+        custom_effects: rules.custom_effects,
+    };
+    complete_turn(turn, intent_events, clock, rng, uow).await
+}
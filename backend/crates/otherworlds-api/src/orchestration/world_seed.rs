@@ -0,0 +1,403 @@
+//! World seeding — gives a world the starting dispositions and faction
+//! memberships of a campaign's NPCs, and the campaign's locations.
+//!
+//! Content knows NPCs, factions and locations by their names in the campaign
+//! source; the World State context knows them by UUID. Each is given a UUID
+//! derived from the campaign and its name (see
+//! `campaign_model::npc_entity_id`), so every world seeded from the same
+//! campaign agrees on who is who and where. See ADR-0048 and ADR-0049.
+//! Conditions name NPCs and factions the same way (see ADR-0051).
+
+use std::collections::BTreeMap;
+
+use otherworlds_content::application::query_handlers as content_queries;
+use otherworlds_content::domain::campaign_model::{
+    CompiledCampaign, faction_entity_id, location_entity_id, npc_entity_id,
+};
+use otherworlds_core::error::DomainError;
+use otherworlds_core::repository::EventRepository;
+use otherworlds_world_state::domain::commands::{
+    ConnectionSeed, DispositionSeed, LocationSeed, SeedDispositions, SeedLocations,
+};
+use otherworlds_world_state::domain::disposition::parse_disposition;
+use uuid::Uuid;
+
+/// The seeding commands for a world, with the entity IDs they assigned.
+#[derive(Debug)]
+pub struct WorldSeed {
+    /// The command seeding every NPC and faction of the campaign.
+    pub dispositions: SeedDispositions,
+    /// The command seeding every location of the campaign.
+    pub locations: SeedLocations,
+    /// World entity IDs of the campaign's NPCs, by NPC ID.
+    pub npc_entity_ids: BTreeMap<String, Uuid>,
+    /// World entity IDs of the campaign's factions, by faction name.
+    pub faction_entity_ids: BTreeMap<String, Uuid>,
+    /// World location IDs of the campaign's locations, by location ID.
+    pub location_entity_ids: BTreeMap<String, Uuid>,
+}
+
+/// Builds the seeding commands for `world_id` from a compiled campaign.
+///
+/// NPCs start at the score their `disposition` gives, or neutral when it is
+/// absent, and join the factions they name. Factions start neutral. NPCs
+/// start at the location that lists them, and the party at the campaign's
+/// start location.
+///
+/// # Errors
+///
+/// Returns `DomainError::Validation` if an NPC's disposition is neither a
+/// band name nor a score in range.
+pub fn world_seed(
+    world_id: Uuid,
+    campaign_id: Uuid,
+    campaign: &CompiledCampaign,
+    correlation_id: Uuid,
+) -> Result<WorldSeed, DomainError> {
+    let mut npcs: Vec<_> = campaign.npcs.values().collect();
+    npcs.sort_by(|a, b| a.id.cmp(&b.id));
+
+    let mut seeds = Vec::new();
+    let mut npc_entity_ids = BTreeMap::new();
+    let mut faction_entity_ids = BTreeMap::new();
+    for npc in npcs {
+        let score = match &npc.disposition {
+            Some(disposition) => parse_disposition(disposition)
+                .map_err(|e| DomainError::Validation(format!("NPC '{}': {e}", npc.id)))?,
+            None => 0,
+        };
+        let factions = npc
+            .factions
+            .iter()
+            .map(|faction| {
+                *faction_entity_ids
+                    .entry(faction.clone())
+                    .or_insert_with(|| faction_entity_id(campaign_id, faction))
+            })
+            .collect();
+        let entity_id = npc_entity_id(campaign_id, &npc.id);
+        npc_entity_ids.insert(npc.id.clone(), entity_id);
+        seeds.push(DispositionSeed {
+            entity_id,
+            score,
+            factions,
+        });
+    }
+    seeds.extend(
+        faction_entity_ids
+            .values()
+            .map(|entity_id| DispositionSeed {
+                entity_id: *entity_id,
+                score: 0,
+                factions: Vec::new(),
+            }),
+    );
+
+    let (locations, location_entity_ids) =
+        location_seed(world_id, campaign_id, campaign, correlation_id);
+    Ok(WorldSeed {
+        dispositions: SeedDispositions {
+            correlation_id,
+            world_id,
+            seeds,
+        },
+        locations,
+        npc_entity_ids,
+        faction_entity_ids,
+        location_entity_ids,
+    })
+}
+
+/// Builds the command seeding the campaign's locations, with the location
+/// IDs it assigned. An exit listed from both ends becomes one connection.
+fn location_seed(
+    world_id: Uuid,
+    campaign_id: Uuid,
+    campaign: &CompiledCampaign,
+    correlation_id: Uuid,
+) -> (SeedLocations, BTreeMap<String, Uuid>) {
+    let location_entity_ids: BTreeMap<String, Uuid> = campaign
+        .locations
+        .keys()
+        .map(|id| (id.clone(), location_entity_id(campaign_id, id)))
+        .collect();
+
+    let mut locations = Vec::new();
+    let mut connections = BTreeMap::new();
+    for (id, location_id) in &location_entity_ids {
+        let location = &campaign.locations[id];
+        locations.push(LocationSeed {
+            location_id: *location_id,
+            name: location.name.clone(),
+            npcs: location
+                .npc_refs
+                .iter()
+                .map(|npc_id| npc_entity_id(campaign_id, npc_id))
+                .collect(),
+        });
+        for exit in &location.exits {
+            let target = &exit.target_location_id;
+            let ends = if id < target {
+                (id, target)
+            } else {
+                (target, id)
+            };
+            connections.entry(ends).or_insert(exit.locked);
+        }
+    }
+
+    let command = SeedLocations {
+        correlation_id,
+        world_id,
+        locations,
+        connections: connections
+            .into_iter()
+            .map(|((id, other_id), locked)| ConnectionSeed {
+                location_id: location_entity_id(campaign_id, id),
+                other_location_id: location_entity_id(campaign_id, other_id),
+                locked,
+            })
+            .collect(),
+        party_location: campaign
+            .start_location
+            .as_deref()
+            .map(|id| location_entity_id(campaign_id, id)),
+    };
+    (command, location_entity_ids)
+}
+
+/// Loads `campaign_id`'s compiled form and builds the seeding commands for
+/// `world_id` from it.
+///
+/// # Errors
+///
+/// Returns `DomainError::AggregateNotFound` if the campaign does not exist,
+/// and `DomainError::Validation` if it has not been compiled or an NPC's
+/// disposition is invalid.
+pub async fn load_world_seed(
+    world_id: Uuid,
+    campaign_id: Uuid,
+    correlation_id: Uuid,
+    repo: &dyn EventRepository,
+) -> Result<WorldSeed, DomainError> {
+    let campaign = content_queries::get_compiled_campaign(campaign_id, repo).await?;
+    world_seed(world_id, campaign_id, &campaign, correlation_id)
+}
+
+/// Returns the world entity IDs of a campaign's NPCs, by NPC ID, and of its
+/// factions, by faction name. A faction named like an NPC resolves to the NPC.
+#[must_use]
+pub fn campaign_entity_ids(
+    campaign_id: Uuid,
+    campaign: &CompiledCampaign,
+) -> BTreeMap<String, Uuid> {
+    let mut entity_ids: BTreeMap<String, Uuid> = campaign
+        .npcs
+        .values()
+        .flat_map(|npc| &npc.factions)
+        .map(|faction| (faction.clone(), faction_entity_id(campaign_id, faction)))
+        .collect();
+    entity_ids.extend(
+        campaign
+            .npcs
+            .keys()
+            .map(|id| (id.clone(), npc_entity_id(campaign_id, id))),
+    );
+    entity_ids
+}
+
+/// Loads `campaign_id`'s compiled form and returns the world entity IDs of
+/// its NPCs and factions by name.
+///
+/// # Errors
+///
+/// Returns `DomainError::AggregateNotFound` if the campaign does not exist,
+/// and `DomainError::Validation` if it has not been compiled.
+pub async fn load_campaign_entity_ids(
+    campaign_id: Uuid,
+    repo: &dyn EventRepository,
+) -> Result<BTreeMap<String, Uuid>, DomainError> {
+    let campaign = content_queries::get_compiled_campaign(campaign_id, repo).await?;
+    Ok(campaign_entity_ids(campaign_id, &campaign))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use otherworlds_content::domain::campaign_model::{
+        CompiledExit, CompiledLocation, CompiledNpc,
+    };
+
+    use super::*;
+
+    fn campaign(npcs: &[(&str, Option<&str>, &[&str])]) -> CompiledCampaign {
+        CompiledCampaign {
+            title: "Harbour".to_owned(),
+            description: None,
+            min_engine_version: None,
+            ruleset: None,
+            custom_effects: Vec::new(),
+            scenes: HashMap::new(),
+            npcs: npcs
+                .iter()
+                .map(|(id, disposition, factions)| {
+                    let npc = CompiledNpc {
+                        id: (*id).to_owned(),
+                        name: (*id).to_owned(),
+                        disposition: disposition.map(str::to_owned),
+                        factions: factions.iter().map(|f| (*f).to_owned()).collect(),
+                    };
+                    ((*id).to_owned(), npc)
+                })
+                .collect(),
+            locations: HashMap::new(),
+            start_location: None,
+        }
+    }
+
+    #[test]
+    fn test_world_seed_seeds_npcs_then_their_factions() {
+        // Arrange
+        let world_id = Uuid::new_v4();
+        let campaign_id = Uuid::new_v4();
+        let campaign = campaign(&[
+            ("sergeant", Some("friendly"), &["city_watch"]),
+            ("captain", Some("-30"), &["city_watch", "temple"]),
+            ("beggar", None, &[]),
+        ]);
+
+        // Act
+        let seed = world_seed(world_id, campaign_id, &campaign, Uuid::new_v4()).unwrap();
+
+        // Assert
+        let watch_id = faction_entity_id(campaign_id, "city_watch");
+        let captain_id = npc_entity_id(campaign_id, "captain");
+        assert_eq!(seed.dispositions.world_id, world_id);
+        assert_eq!(seed.npc_entity_ids["captain"], captain_id);
+        assert_eq!(seed.faction_entity_ids["city_watch"], watch_id);
+        let scores: Vec<(Uuid, i32, usize)> = seed
+            .dispositions
+            .seeds
+            .iter()
+            .map(|s| (s.entity_id, s.score, s.factions.len()))
+            .collect();
+        assert_eq!(
+            scores,
+            vec![
+                (npc_entity_id(campaign_id, "beggar"), 0, 0),
+                (captain_id, -30, 2),
+                (npc_entity_id(campaign_id, "sergeant"), 40, 1),
+                (watch_id, 0, 0),
+                (faction_entity_id(campaign_id, "temple"), 0, 0),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_world_seed_rejects_unknown_disposition() {
+        // Arrange
+        let campaign = campaign(&[("smuggler", Some("shifty"), &[])]);
+
+        // Act
+        let result = world_seed(Uuid::new_v4(), Uuid::new_v4(), &campaign, Uuid::new_v4());
+
+        // Assert
+        assert!(matches!(
+            result,
+            Err(DomainError::Validation(m)) if m.contains("smuggler") && m.contains("shifty")
+        ));
+    }
+
+    #[test]
+    fn test_world_seed_connects_each_exit_once_and_starts_the_party() {
+        // Arrange
+        let campaign_id = Uuid::new_v4();
+        let mut campaign = campaign(&[("guard", None, &[])]);
+        let exit = |target: &str, locked: bool| CompiledExit {
+            target_location_id: target.to_owned(),
+            locked,
+        };
+        for (id, exits, npc_refs) in [
+            (
+                "market",
+                vec![exit("vault", true), exit("dock", false)],
+                vec![],
+            ),
+            (
+                "vault",
+                vec![exit("market", true)],
+                vec!["guard".to_owned()],
+            ),
+            ("dock", vec![], vec![]),
+        ] {
+            let location = CompiledLocation {
+                id: id.to_owned(),
+                name: id.to_owned(),
+                exits,
+                npc_refs,
+            };
+            campaign.locations.insert(id.to_owned(), location);
+        }
+        campaign.start_location = Some("market".to_owned());
+
+        // Act
+        let seed = world_seed(Uuid::new_v4(), campaign_id, &campaign, Uuid::new_v4()).unwrap();
+
+        // Assert
+        let market_id = location_entity_id(campaign_id, "market");
+        let vault_id = location_entity_id(campaign_id, "vault");
+        assert_eq!(seed.location_entity_ids["market"], market_id);
+        assert_eq!(seed.locations.locations.len(), 3);
+        assert_eq!(seed.locations.party_location, Some(market_id));
+        let connections: Vec<(Uuid, Uuid, bool)> = seed
+            .locations
+            .connections
+            .iter()
+            .map(|c| (c.location_id, c.other_location_id, c.locked))
+            .collect();
+        assert_eq!(
+            connections,
+            vec![
+                (location_entity_id(campaign_id, "dock"), market_id, false),
+                (market_id, vault_id, true),
+            ]
+        );
+        let vault = seed
+            .locations
+            .locations
+            .iter()
+            .find(|l| l.location_id == vault_id)
+            .unwrap();
+        assert_eq!(vault.npcs, vec![npc_entity_id(campaign_id, "guard")]);
+    }
+
+    #[test]
+    fn test_campaign_entity_ids_names_npcs_and_factions() {
+        // Arrange
+        let campaign_id = Uuid::new_v4();
+        let campaign = campaign(&[
+            ("guard_captain", None, &["city_watch"]),
+            ("city_watch", None, &[]),
+            ("priest", None, &["temple"]),
+        ]);
+
+        // Act
+        let entity_ids = campaign_entity_ids(campaign_id, &campaign);
+
+        // Assert
+        assert_eq!(entity_ids.len(), 4);
+        assert_eq!(
+            entity_ids["guard_captain"],
+            npc_entity_id(campaign_id, "guard_captain")
+        );
+        assert_eq!(
+            entity_ids["temple"],
+            faction_entity_id(campaign_id, "temple")
+        );
+        assert_eq!(
+            entity_ids["city_watch"],
+            npc_entity_id(campaign_id, "city_watch")
+        );
+    }
+}
@@ -0,0 +1,63 @@
+//! Projections — the read models maintained by the API composition root.
+
+use std::sync::Arc;
+
+use otherworlds_core::projection::Projection;
+
+use otherworlds_character::application::projections::CharacterProjection;
+use otherworlds_content::application::projections::CampaignProjection;
+use otherworlds_inventory::application::projections::InventoryProjection;
+use otherworlds_narrative::application::projections::NarrativeSessionProjection;
+use otherworlds_rules::application::projections::{EncounterProjection, ResolutionProjection};
+use otherworlds_session::application::projections::CampaignRunProjection;
+use otherworlds_world_state::application::projections::{
+    WorldLocationProjection, WorldSnapshotProjection,
+};
+
+/// Returns every projection.
+#[must_use]
+pub fn all() -> Vec<Arc<dyn Projection>> {
+    vec![
+        Arc::new(NarrativeSessionProjection),
+        Arc::new(ResolutionProjection),
+        Arc::new(EncounterProjection),
+        Arc::new(WorldSnapshotProjection),
+        Arc::new(WorldLocationProjection),
+        Arc::new(CharacterProjection),
+        Arc::new(InventoryProjection),
+        Arc::new(CampaignRunProjection),
+        Arc::new(CampaignProjection),
+    ]
+}
+
+/// Looks up a projection by its checkpoint name.
+#[must_use]
+pub fn find(name: &str) -> Option<Arc<dyn Projection>> {
+    all()
+        .into_iter()
+        .find(|projection| projection.name() == name)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    #[test]
+    fn test_projection_names_are_unique() {
+        let projections = all();
+
+        let names: HashSet<&str> = projections.iter().map(|p| p.name()).collect();
+
+        assert_eq!(names.len(), projections.len());
+    }
+
+    #[test]
+    fn test_find_returns_named_projection() {
+        let projection = find("character.characters").unwrap();
+
+        assert_eq!(projection.name(), "character.characters");
+        assert!(find("unknown.projection").is_none());
+    }
+}
@@ -0,0 +1,481 @@
+//! Replay verifier — re-executes a campaign run's command log and checks that
+//! it reproduces the stored events.
+//!
+//! Each command recorded on the run is decoded and executed again, in order,
+//! through a fresh `RunScope` over an in-memory repository, with a
+//! `FixedClock` set to the time the command was recorded. The run's RNG is
+//! resumed from its seed and recorded position exactly as it was live, so a
+//! deterministic command reproduces its events byte for byte. The first
+//! replayed event that differs from the stored one is reported.
+//!
+//! Events the run did not produce itself (the run's genesis, or writes made
+//! outside the run to aggregates it touches) are copied from the stored log
+//! into the in-memory repository the first time a command loads the
+//! aggregate, up to the position the command originally committed at.
+//! See ADR-0036.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use async_trait::async_trait;
+use serde::Serialize;
+use tracing::{info, instrument};
+use uuid::Uuid;
+
+use otherworlds_core::clock::FixedClock;
+use otherworlds_core::error::DomainError;
+use otherworlds_core::repository::{EventRepository, PositionedEvent, StoredEvent};
+use otherworlds_core::rng::{DeterministicRng, SeededRng};
+use otherworlds_event_store::memory_event_repository::InMemoryEventRepository;
+use otherworlds_event_store::memory_read_model_store::InMemoryReadModelStore;
+use otherworlds_session::domain::events::{COMMAND_RECORDED_EVENT_TYPE, SessionEventKind};
+
+use crate::command_log::RunCommand;
+use crate::run_scope::RunScope;
+use crate::state::AppState;
+
+/// How a replay diverged from the stored events.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DivergenceKind {
+    /// The recorded command could not be decoded or was rejected on replay.
+    CommandRejected,
+    /// A replayed event differs from the stored event at its position.
+    EventMismatch,
+    /// The replay produced an event that was never stored.
+    UnexpectedEvent,
+    /// A stored event of the command was not reproduced by the replay.
+    MissingEvent,
+}
+
+/// The event type and payload of one side of a divergence.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ComparedEvent {
+    /// Event type name.
+    pub event_type: String,
+    /// Event payload.
+    pub payload: serde_json::Value,
+}
+
+impl From<&StoredEvent> for ComparedEvent {
+    fn from(event: &StoredEvent) -> Self {
+        Self {
+            event_type: event.event_type.clone(),
+            payload: event.payload.clone(),
+        }
+    }
+}
+
+/// The first point at which a replay diverged from the stored events.
+#[derive(Debug, Clone, Serialize)]
+pub struct Divergence {
+    /// Zero-based index of the command in the run's command log.
+    pub command_index: usize,
+    /// Type name of the diverging command.
+    pub command_type: String,
+    /// How the replay diverged.
+    pub kind: DivergenceKind,
+    /// Stream of the diverging event, if the divergence concerns an event.
+    pub aggregate_id: Option<Uuid>,
+    /// Sequence number of the diverging event within its stream.
+    pub sequence_number: Option<i64>,
+    /// The stored event, if one exists at that position.
+    pub expected: Option<ComparedEvent>,
+    /// The replayed event, if the replay produced one at that position.
+    pub actual: Option<ComparedEvent>,
+    /// Error message for a rejected command.
+    pub message: Option<String>,
+}
+
+/// Outcome of replaying a campaign run.
+#[derive(Debug, Clone, Serialize)]
+pub struct ReplayReport {
+    /// The replayed campaign run.
+    pub run_id: Uuid,
+    /// Number of commands replayed without divergence.
+    pub commands_replayed: usize,
+    /// Number of replayed events that matched their stored counterparts.
+    pub events_compared: usize,
+    /// The first divergence, or `None` if the replay matched.
+    pub divergence: Option<Divergence>,
+}
+
+/// A recorded command together with the stored events it committed.
+#[derive(Debug)]
+struct RecordedCommand {
+    occurred_at: chrono::DateTime<chrono::Utc>,
+    command_type: String,
+    /// The decoded command, or why it could not be decoded.
+    decoded: Result<RunCommand, String>,
+    /// Global position of the first event the command committed.
+    committed_from: i64,
+    /// Every stored event the command committed.
+    committed: Vec<StoredEvent>,
+}
+
+/// The stored streams of the source repository, loaded on first use so a
+/// replay reads only the run and the aggregates its commands touch.
+#[derive(Debug)]
+struct StoredLog {
+    source: Arc<dyn EventRepository>,
+    streams: Mutex<HashMap<Uuid, Arc<[PositionedEvent]>>>,
+}
+
+impl StoredLog {
+    fn new(source: Arc<dyn EventRepository>) -> Self {
+        Self {
+            source,
+            streams: Mutex::new(HashMap::new()),
+        }
+    }
+
+    fn poisoned(e: impl std::fmt::Display) -> DomainError {
+        DomainError::Infrastructure(format!("stored log mutex poisoned: {e}"))
+    }
+
+    /// Returns the stored stream of `aggregate_id`, with global positions.
+    async fn stream(&self, aggregate_id: Uuid) -> Result<Arc<[PositionedEvent]>, DomainError> {
+        if let Some(stream) = self
+            .streams
+            .lock()
+            .map_err(Self::poisoned)?
+            .get(&aggregate_id)
+        {
+            return Ok(stream.clone());
+        }
+        let stream: Arc<[PositionedEvent]> = self
+            .source
+            .load_positioned_events(aggregate_id)
+            .await?
+            .into();
+        Ok(self
+            .streams
+            .lock()
+            .map_err(Self::poisoned)?
+            .entry(aggregate_id)
+            .or_insert(stream)
+            .clone())
+    }
+
+    async fn stored_event(
+        &self,
+        aggregate_id: Uuid,
+        sequence_number: i64,
+    ) -> Result<Option<StoredEvent>, DomainError> {
+        Ok(self
+            .stream(aggregate_id)
+            .await?
+            .iter()
+            .map(|p| &p.event)
+            .find(|e| e.sequence_number == sequence_number)
+            .cloned())
+    }
+
+    /// Returns the recorded commands of `run_stream`, in order.
+    ///
+    /// A command's events are committed in one append, so they occupy a
+    /// contiguous range of the global log around its `CommandRecorded` event.
+    /// The record states where in that range it sits and how long the range
+    /// is, so only that range is read.
+    async fn recorded_commands(
+        &self,
+        run_stream: &[PositionedEvent],
+    ) -> Result<Vec<RecordedCommand>, DomainError> {
+        let mut commands = Vec::new();
+        for positioned in run_stream {
+            let event = &positioned.event;
+            if event.event_type != COMMAND_RECORDED_EVENT_TYPE {
+                continue;
+            }
+            let record = serde_json::from_value::<SessionEventKind>(event.payload.clone());
+            let (command_type, decoded, span) = match record {
+                Ok(SessionEventKind::CommandRecorded(record)) => {
+                    let decoded = RunCommand::from_record(&record).map_err(|e| e.to_string());
+                    (record.command_type, decoded, Some(record.commit))
+                }
+                _ => (
+                    String::new(),
+                    Err("command record payload is malformed".to_owned()),
+                    None,
+                ),
+            };
+
+            // A record that cannot be read stands alone; replay reports it.
+            let (from, len) = span.map_or((positioned.position, 1), |span| {
+                let offset = i64::try_from(span.offset).unwrap_or(i64::MAX);
+                let len = i64::try_from(span.len).unwrap_or(i64::MAX);
+                (positioned.position.saturating_sub(offset).max(1), len)
+            });
+            let committed = self.source.read_all_from(from, len).await?;
+
+            commands.push(RecordedCommand {
+                occurred_at: event.occurred_at,
+                command_type,
+                decoded,
+                committed_from: committed.first().map_or(from, |p| p.position),
+                committed: committed.into_iter().map(|p| p.event).collect(),
+            });
+        }
+        Ok(commands)
+    }
+}
+
+/// In-memory repository that the replay writes to, backfilled from the
+/// stored log with events the replay itself does not produce.
+#[derive(Debug)]
+struct ReplayRepository {
+    log: Arc<StoredLog>,
+    memory: InMemoryEventRepository,
+    /// Stored events at or after this position are never backfilled.
+    backfill_before: Mutex<i64>,
+    /// Events appended by the command currently being replayed.
+    replayed: Mutex<Vec<StoredEvent>>,
+}
+
+impl ReplayRepository {
+    fn new(log: Arc<StoredLog>) -> Self {
+        Self {
+            log,
+            memory: InMemoryEventRepository::new(),
+            backfill_before: Mutex::new(0),
+            replayed: Mutex::new(Vec::new()),
+        }
+    }
+
+    fn poisoned(e: impl std::fmt::Display) -> DomainError {
+        DomainError::Infrastructure(format!("replay repository mutex poisoned: {e}"))
+    }
+
+    /// Prepares the repository for the next command and clears the events
+    /// captured for the previous one.
+    fn begin_command(&self, committed_from: i64) -> Result<(), DomainError> {
+        *self.backfill_before.lock().map_err(Self::poisoned)? = committed_from;
+        self.replayed.lock().map_err(Self::poisoned)?.clear();
+        Ok(())
+    }
+
+    fn take_replayed(&self) -> Result<Vec<StoredEvent>, DomainError> {
+        Ok(std::mem::take(
+            &mut *self.replayed.lock().map_err(Self::poisoned)?,
+        ))
+    }
+
+    /// Copies stored events of `aggregate_id` that precede the current
+    /// command into memory, stopping at the first one that does not.
+    async fn backfill(&self, aggregate_id: Uuid) -> Result<(), DomainError> {
+        let stream = self.log.stream(aggregate_id).await?;
+        let backfill_before = *self.backfill_before.lock().map_err(Self::poisoned)?;
+        let version = self
+            .memory
+            .load_events(aggregate_id)
+            .await?
+            .last()
+            .map_or(0, |e| e.sequence_number);
+        let missing: Vec<StoredEvent> = stream
+            .iter()
+            .filter(|p| p.event.sequence_number > version)
+            .take_while(|p| p.position < backfill_before)
+            .map(|p| p.event.clone())
+            .collect();
+        if missing.is_empty() {
+            return Ok(());
+        }
+        self.memory
+            .append_events(aggregate_id, version, &missing)
+            .await
+    }
+}
+
+#[async_trait]
+impl EventRepository for ReplayRepository {
+    async fn load_events(&self, aggregate_id: Uuid) -> Result<Vec<StoredEvent>, DomainError> {
+        self.backfill(aggregate_id).await?;
+        self.memory.load_events(aggregate_id).await
+    }
+
+    async fn append_events(
+        &self,
+        aggregate_id: Uuid,
+        expected_version: i64,
+        events: &[StoredEvent],
+    ) -> Result<(), DomainError> {
+        self.backfill(aggregate_id).await?;
+        self.memory
+            .append_events(aggregate_id, expected_version, events)
+            .await?;
+        self.replayed
+            .lock()
+            .map_err(Self::poisoned)?
+            .extend_from_slice(events);
+        Ok(())
+    }
+
+    async fn list_aggregate_ids(&self, event_types: &[&str]) -> Result<Vec<Uuid>, DomainError> {
+        self.memory.list_aggregate_ids(event_types).await
+    }
+}
+
+/// Replays one recorded command, returning the events it appended.
+async fn replay_command(
+    run_id: Uuid,
+    command: &RunCommand,
+    recorded: &RecordedCommand,
+    repo: &Arc<ReplayRepository>,
+) -> Result<Vec<StoredEvent>, DomainError> {
+    repo.begin_command(recorded.committed_from)?;
+    // The process-wide RNG must never be drawn from by a run-scoped command;
+    // a fixed seed keeps the replay deterministic even if one does.
+    let rng: Arc<Mutex<dyn DeterministicRng + Send>> = Arc::new(Mutex::new(SeededRng::new(0)));
+    let state = AppState::new(
+        None,
+        Arc::new(FixedClock(recorded.occurred_at)),
+        rng,
+        repo.clone(),
+        Arc::new(InMemoryReadModelStore::new()),
+    );
+
+    let scope = RunScope::open(&state, Some(run_id)).await?;
+    command
+        .execute(run_id, state.clock.as_ref(), scope.rng(), scope.repo())
+        .await?;
+    scope.commit(command, state.clock.as_ref()).await?;
+    repo.take_replayed()
+}
+
+/// Compares the events a command replayed with those it originally
+/// committed, returning the first divergence, if any.
+async fn compare_events(
+    log: &StoredLog,
+    index: usize,
+    recorded: &RecordedCommand,
+    replayed: &[StoredEvent],
+) -> Result<Option<Divergence>, DomainError> {
+    let divergence = |kind, aggregate_id, sequence_number, expected, actual| Divergence {
+        command_index: index,
+        command_type: recorded.command_type.clone(),
+        kind,
+        aggregate_id: Some(aggregate_id),
+        sequence_number: Some(sequence_number),
+        expected,
+        actual,
+        message: None,
+    };
+
+    for event in replayed {
+        let actual = ComparedEvent::from(event);
+        let stored = log
+            .stored_event(event.aggregate_id, event.sequence_number)
+            .await?;
+        let Some(stored) = stored else {
+            return Ok(Some(divergence(
+                DivergenceKind::UnexpectedEvent,
+                event.aggregate_id,
+                event.sequence_number,
+                None,
+                Some(actual),
+            )));
+        };
+        let expected = ComparedEvent::from(&stored);
+        if expected != actual {
+            return Ok(Some(divergence(
+                DivergenceKind::EventMismatch,
+                event.aggregate_id,
+                event.sequence_number,
+                Some(expected),
+                Some(actual),
+            )));
+        }
+    }
+
+    let reproduced = |stored: &&StoredEvent| {
+        replayed.iter().any(|e| {
+            e.aggregate_id == stored.aggregate_id && e.sequence_number == stored.sequence_number
+        })
+    };
+    if let Some(missing) = recorded.committed.iter().find(|s| !reproduced(s)) {
+        return Ok(Some(divergence(
+            DivergenceKind::MissingEvent,
+            missing.aggregate_id,
+            missing.sequence_number,
+            Some(ComparedEvent::from(missing)),
+            None,
+        )));
+    }
+
+    Ok(None)
+}
+
+/// Replays the command log of `run_id` and reports the first point at which
+/// the replay diverged from the stored events.
+///
+/// Only the run's stream, the ranges of the global log its commands
+/// committed, and the streams those commands touch are read from `source`.
+/// This is still meant for administrative use rather than the request path.
+///
+/// # Errors
+///
+/// Returns `DomainError::AggregateNotFound` if the run has no events, and
+/// `DomainError::Infrastructure` if `source` cannot read its global log
+/// or a stored event needed for the comparison.
+/// Failures of the replayed commands themselves are reported as a
+/// divergence, not as an error.
+#[instrument(skip(source), fields(%run_id))]
+pub async fn verify_run(
+    run_id: Uuid,
+    source: Arc<dyn EventRepository>,
+) -> Result<ReplayReport, DomainError> {
+    let log = Arc::new(StoredLog::new(source));
+    let run_stream = log.stream(run_id).await?;
+    if run_stream.is_empty() {
+        return Err(DomainError::AggregateNotFound(run_id));
+    }
+
+    let commands = log.recorded_commands(&run_stream).await?;
+    let repo = Arc::new(ReplayRepository::new(log.clone()));
+    let mut report = ReplayReport {
+        run_id,
+        commands_replayed: 0,
+        events_compared: 0,
+        divergence: None,
+    };
+
+    for (index, recorded) in commands.iter().enumerate() {
+        let replayed = match &recorded.decoded {
+            Ok(command) => replay_command(run_id, command, recorded, &repo)
+                .await
+                .map_err(|e| e.to_string()),
+            Err(message) => Err(message.clone()),
+        };
+        let divergence = match replayed {
+            Ok(events) => {
+                let Some(divergence) = compare_events(&log, index, recorded, &events).await? else {
+                    report.commands_replayed += 1;
+                    report.events_compared += events.len();
+                    continue;
+                };
+                divergence
+            }
+            Err(message) => Divergence {
+                command_index: index,
+                command_type: recorded.command_type.clone(),
+                kind: DivergenceKind::CommandRejected,
+                aggregate_id: None,
+                sequence_number: None,
+                expected: None,
+                actual: None,
+                message: Some(message),
+            },
+        };
+        report.divergence = Some(divergence);
+        break;
+    }
+
+    info!(
+        commands_replayed = report.commands_replayed,
+        events_compared = report.events_compared,
+        diverged = report.divergence.is_some(),
+        "run replay finished"
+    );
+
+    Ok(report)
+}
@@ -0,0 +1,100 @@
+//! Administrative routes for operating read-model projections and verifying
+//! that campaign runs replay deterministically.
+
+use axum::extract::{Path, State};
+use axum::{
+    Json, Router,
+    routing::{get, post},
+};
+use otherworlds_core::error::DomainError;
+use otherworlds_core::projection::ProjectionRunner;
+use serde::Serialize;
+use tracing::{info, instrument};
+use uuid::Uuid;
+
+use crate::error::ApiError;
+use crate::projections;
+use crate::replay::{self, ReplayReport};
+use crate::state::AppState;
+
+/// Status of a single projection.
+#[derive(Debug, Serialize)]
+pub struct ProjectionStatus {
+    /// Checkpoint name of the projection.
+    pub name: &'static str,
+    /// Last global log position the projection has processed.
+    pub checkpoint: i64,
+}
+
+/// Response body returned after a projection is rebuilt.
+#[derive(Debug, Serialize)]
+pub struct RebuildResponse {
+    /// Checkpoint name of the rebuilt projection.
+    pub name: &'static str,
+    /// Number of events replayed into the projection.
+    pub events_processed: u64,
+}
+
+/// GET /projections
+#[instrument(skip(state))]
+async fn list_projections(
+    State(state): State<AppState>,
+) -> Result<Json<Vec<ProjectionStatus>>, ApiError> {
+    let mut statuses = Vec::new();
+    for projection in projections::all() {
+        let checkpoint = state.read_models.load_checkpoint(projection.name()).await?;
+        statuses.push(ProjectionStatus {
+            name: projection.name(),
+            checkpoint,
+        });
+    }
+    Ok(Json(statuses))
+}
+
+/// POST /projections/{name}/rebuild
+#[instrument(skip(state), fields(projection = %name))]
+async fn rebuild_projection(
+    State(state): State<AppState>,
+    Path(name): Path<String>,
+) -> Result<Json<RebuildResponse>, ApiError> {
+    let projection = projections::find(&name)
+        .ok_or_else(|| DomainError::Validation(format!("unknown projection: {name}")))?;
+
+    info!("rebuilding projection from scratch");
+
+    let events_processed = ProjectionRunner::default()
+        .rebuild(
+            projection.as_ref(),
+            &*state.event_repository,
+            &*state.read_models,
+        )
+        .await?;
+
+    info!(events_processed, "projection rebuilt");
+
+    Ok(Json(RebuildResponse {
+        name: projection.name(),
+        events_processed,
+    }))
+}
+
+/// POST /runs/{`run_id`}/replay
+///
+/// Replays the run's command log and reports the first divergence from the
+/// stored events, if any. See ADR-0036.
+#[instrument(skip(state), fields(%run_id))]
+async fn replay_run(
+    State(state): State<AppState>,
+    Path(run_id): Path<Uuid>,
+) -> Result<Json<ReplayReport>, ApiError> {
+    let report = replay::verify_run(run_id, state.event_repository.clone()).await?;
+    Ok(Json(report))
+}
+
+/// Returns the router for administrative endpoints.
+pub fn router() -> Router<AppState> {
+    Router::new()
+        .route("/projections", get(list_projections))
+        .route("/projections/{name}/rebuild", post(rebuild_projection))
+        .route("/runs/{run_id}/replay", post(replay_run))
+}
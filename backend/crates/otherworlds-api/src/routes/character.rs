@@ -1,6 +1,7 @@
 //! Routes for the Character Management bounded context.
 
 use axum::extract::{Path, State};
+use axum::http::HeaderMap;
 use axum::{
     Json, Router,
     routing::{get, post},
@@ -14,6 +15,7 @@ use otherworlds_character::application::{command_handlers, query_handlers};
 use otherworlds_character::domain::commands;
 
 use crate::error::ApiError;
+use crate::run_scope::RunScope;
 use crate::state::AppState;
 
 /// Request body for POST /create.
@@ -43,6 +45,15 @@ pub struct AwardExperienceRequest {
     pub amount: u32,
 }
 
+/// Request body for POST /grant-proficiency.
+#[derive(Debug, Deserialize)]
+pub struct GrantProficiencyRequest {
+    /// The character to grant the proficiency to.
+    pub character_id: Uuid,
+    /// The skill or attribute (e.g., "stealth").
+    pub skill: String,
+}
+
 /// Response body returned after a command is successfully handled.
 #[derive(Debug, Serialize)]
 pub struct CommandResponse {
@@ -51,9 +62,10 @@ pub struct CommandResponse {
 }
 
 /// POST /create
-#[instrument(skip(state, request), fields(name = %request.name))]
+#[instrument(skip(state, headers, request), fields(name = %request.name))]
 async fn create_character(
     State(state): State<AppState>,
+    headers: HeaderMap,
     Json(request): Json<CreateCharacterRequest>,
 ) -> Result<Json<CommandResponse>, ApiError> {
     let command = commands::CreateCharacter {
@@ -64,13 +76,15 @@ async fn create_character(
 
     info!(correlation_id = %command.correlation_id, "handling create_character command");
 
+    let scope = RunScope::from_headers(&state, &headers).await?;
     let stored_events = command_handlers::handle_create_character(
         &command,
         state.clock.as_ref(),
-        &state.rng,
-        &*state.event_repository,
+        scope.rng(),
+        scope.repo(),
     )
     .await?;
+    scope.commit(&command, state.clock.as_ref()).await?;
 
     let event_ids = stored_events.iter().map(|e| e.event_id).collect();
 
@@ -78,13 +92,15 @@ async fn create_character(
 }
 
 /// POST /modify-attribute
-#[instrument(skip(state, request), fields(character_id = %request.character_id))]
+#[instrument(skip(state, headers, request), fields(character_id = %request.character_id))]
 async fn modify_attribute(
     State(state): State<AppState>,
+    headers: HeaderMap,
     Json(request): Json<ModifyAttributeRequest>,
 ) -> Result<Json<CommandResponse>, ApiError> {
     let command = commands::ModifyAttribute {
         correlation_id: Uuid::new_v4(),
+        causation_id: None,
         character_id: request.character_id,
         attribute: request.attribute,
         new_value: request.new_value,
@@ -92,13 +108,15 @@ async fn modify_attribute(
 
     info!(correlation_id = %command.correlation_id, "handling modify_attribute command");
 
+    let scope = RunScope::from_headers(&state, &headers).await?;
     let stored_events = command_handlers::handle_modify_attribute(
         &command,
         state.clock.as_ref(),
-        &state.rng,
-        &*state.event_repository,
+        scope.rng(),
+        scope.repo(),
     )
     .await?;
+    scope.commit(&command, state.clock.as_ref()).await?;
 
     let event_ids = stored_events.iter().map(|e| e.event_id).collect();
 
@@ -106,26 +124,60 @@ async fn modify_attribute(
 }
 
 /// POST /award-experience
-#[instrument(skip(state, request), fields(character_id = %request.character_id))]
+#[instrument(skip(state, headers, request), fields(character_id = %request.character_id))]
 async fn award_experience(
     State(state): State<AppState>,
+    headers: HeaderMap,
     Json(request): Json<AwardExperienceRequest>,
 ) -> Result<Json<CommandResponse>, ApiError> {
     let command = commands::AwardExperience {
         correlation_id: Uuid::new_v4(),
+        causation_id: None,
         character_id: request.character_id,
         amount: request.amount,
     };
 
     info!(correlation_id = %command.correlation_id, "handling award_experience command");
 
+    let scope = RunScope::from_headers(&state, &headers).await?;
     let stored_events = command_handlers::handle_award_experience(
         &command,
         state.clock.as_ref(),
-        &state.rng,
-        &*state.event_repository,
+        scope.rng(),
+        scope.repo(),
+    )
+    .await?;
+    scope.commit(&command, state.clock.as_ref()).await?;
+
+    let event_ids = stored_events.iter().map(|e| e.event_id).collect();
+
+    Ok(Json(CommandResponse { event_ids }))
+}
+
+/// POST /grant-proficiency
+#[instrument(skip(state, headers, request), fields(character_id = %request.character_id))]
+async fn grant_proficiency(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Json(request): Json<GrantProficiencyRequest>,
+) -> Result<Json<CommandResponse>, ApiError> {
+    let command = commands::GrantProficiency {
+        correlation_id: Uuid::new_v4(),
+        character_id: request.character_id,
+        skill: request.skill,
+    };
+
+    info!(correlation_id = %command.correlation_id, "handling grant_proficiency command");
+
+    let scope = RunScope::from_headers(&state, &headers).await?;
+    let stored_events = command_handlers::handle_grant_proficiency(
+        &command,
+        state.clock.as_ref(),
+        scope.rng(),
+        scope.repo(),
     )
     .await?;
+    scope.commit(&command, state.clock.as_ref()).await?;
 
     let event_ids = stored_events.iter().map(|e| e.event_id).collect();
 
@@ -137,14 +189,16 @@ async fn award_experience(
 async fn list_characters(
     State(state): State<AppState>,
 ) -> Result<Json<Vec<CharacterSummary>>, ApiError> {
-    let summaries = query_handlers::list_characters(&*state.event_repository).await?;
+    let summaries =
+        query_handlers::list_characters(&*state.event_repository, &*state.read_models).await?;
     Ok(Json(summaries))
 }
 
 /// DELETE /{`character_id`}
-#[instrument(skip(state), fields(character_id = %id))]
+#[instrument(skip(state, headers), fields(character_id = %id))]
 async fn archive_character(
     State(state): State<AppState>,
+    headers: HeaderMap,
     Path(id): Path<Uuid>,
 ) -> Result<Json<CommandResponse>, ApiError> {
     let command = commands::ArchiveCharacter {
@@ -154,13 +208,15 @@ async fn archive_character(
 
     info!(correlation_id = %command.correlation_id, "handling archive_character command");
 
+    let scope = RunScope::from_headers(&state, &headers).await?;
     let stored_events = command_handlers::handle_archive_character(
         &command,
         state.clock.as_ref(),
-        &state.rng,
-        &*state.event_repository,
+        scope.rng(),
+        scope.repo(),
     )
     .await?;
+    scope.commit(&command, state.clock.as_ref()).await?;
 
     let event_ids = stored_events.iter().map(|e| e.event_id).collect();
 
@@ -173,7 +229,9 @@ async fn get_character(
     State(state): State<AppState>,
     Path(id): Path<Uuid>,
 ) -> Result<Json<CharacterView>, ApiError> {
-    let view = query_handlers::get_character_by_id(id, &*state.event_repository).await?;
+    let view =
+        query_handlers::get_character_by_id(id, &*state.event_repository, &*state.read_models)
+            .await?;
     Ok(Json(view))
 }
 
@@ -188,6 +246,7 @@ pub fn router() -> Router<AppState> {
         .route("/create", post(create_character))
         .route("/modify-attribute", post(modify_attribute))
         .route("/award-experience", post(award_experience))
+        .route("/grant-proficiency", post(grant_proficiency))
 }
 
 #[cfg(test)]
@@ -203,7 +262,8 @@ mod tests {
     use otherworlds_core::repository::StoredEvent;
     use otherworlds_core::rng::DeterministicRng;
     use otherworlds_test_support::{
-        EmptyEventRepository, FailingEventRepository, FixedClock, MockRng, RecordingEventRepository,
+        EmptyEventRepository, FailingEventRepository, FixedClock, InMemoryReadModelStore, MockRng,
+        RecordingEventRepository,
     };
     use serde_json::Value;
     use sqlx::PgPool;
@@ -214,7 +274,13 @@ mod tests {
         let pool = PgPool::connect_lazy("postgres://localhost/test").unwrap();
         let clock: Arc<dyn Clock + Send + Sync> = Arc::new(FixedClock(Utc::now()));
         let rng: Arc<Mutex<dyn DeterministicRng + Send>> = Arc::new(Mutex::new(MockRng));
-        AppState::new(pool, clock, rng, event_repository)
+        AppState::new(
+            Some(pool),
+            clock,
+            rng,
+            event_repository,
+            Arc::new(InMemoryReadModelStore::new()),
+        )
     }
 
     fn test_app_state() -> AppState {
@@ -236,6 +302,7 @@ mod tests {
             correlation_id: Uuid::new_v4(),
             causation_id: Uuid::new_v4(),
             occurred_at: fixed_now,
+            schema_version: 1,
         }
     }
 
@@ -349,6 +416,38 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_grant_proficiency_returns_200_with_event_ids() {
+        // Arrange
+        let character_id = Uuid::new_v4();
+        let existing = vec![character_created_stored_event(character_id)];
+        let repo = RecordingEventRepository::new(Ok(existing));
+        let app = router().with_state(app_state_with(Arc::new(repo)));
+        let body = serde_json::json!({
+            "character_id": character_id,
+            "skill": "stealth"
+        });
+
+        let request = Request::builder()
+            .method("POST")
+            .uri("/grant-proficiency")
+            .header("content-type", "application/json")
+            .body(Body::from(serde_json::to_vec(&body).unwrap()))
+            .unwrap();
+
+        // Act
+        let response = app.oneshot(request).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body_bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let json: Value = serde_json::from_slice(&body_bytes).unwrap();
+        assert_eq!(json["event_ids"].as_array().unwrap().len(), 1);
+    }
+
     #[tokio::test]
     async fn test_create_character_returns_422_for_missing_body() {
         // Arrange
@@ -566,6 +665,7 @@ mod tests {
             correlation_id: Uuid::new_v4(),
             causation_id: Uuid::new_v4(),
             occurred_at: fixed_now,
+            schema_version: 1,
         }];
         let repo = RecordingEventRepository::new(Ok(events));
         let app = router().with_state(app_state_with(Arc::new(repo)));
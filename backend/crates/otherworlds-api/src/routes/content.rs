@@ -51,7 +51,8 @@ pub struct CommandResponse {
 async fn list_campaigns(
     State(state): State<AppState>,
 ) -> Result<Json<Vec<CampaignSummary>>, ApiError> {
-    let summaries = query_handlers::list_campaigns(&*state.event_repository).await?;
+    let summaries =
+        query_handlers::list_campaigns(&*state.event_repository, &*state.read_models).await?;
     Ok(Json(summaries))
 }
 
@@ -61,7 +62,9 @@ async fn get_campaign(
     State(state): State<AppState>,
     Path(id): Path<Uuid>,
 ) -> Result<Json<CampaignView>, ApiError> {
-    let view = query_handlers::get_campaign_by_id(id, &*state.event_repository).await?;
+    let view =
+        query_handlers::get_campaign_by_id(id, &*state.event_repository, &*state.read_models)
+            .await?;
     Ok(Json(view))
 }
 
@@ -207,7 +210,8 @@ mod tests {
     use otherworlds_core::repository::{EventRepository, StoredEvent};
     use otherworlds_core::rng::DeterministicRng;
     use otherworlds_test_support::{
-        EmptyEventRepository, FailingEventRepository, FixedClock, MockRng,
+        EmptyEventRepository, FailingEventRepository, FixedClock, InMemoryReadModelStore, MockRng,
+        RecordingEventRepository,
     };
     use serde_json::Value;
     use sqlx::PgPool;
@@ -243,6 +247,7 @@ mod tests {
                     correlation_id: Uuid::new_v4(),
                     causation_id: Uuid::new_v4(),
                     occurred_at: fixed_now,
+                    schema_version: 1,
                 },
                 StoredEvent {
                     event_id: Uuid::new_v4(),
@@ -258,6 +263,7 @@ mod tests {
                     correlation_id: Uuid::new_v4(),
                     causation_id: Uuid::new_v4(),
                     occurred_at: fixed_now,
+                    schema_version: 1,
                 },
             ])
         }
@@ -306,6 +312,7 @@ mod tests {
                 correlation_id: Uuid::new_v4(),
                 causation_id: Uuid::new_v4(),
                 occurred_at: fixed_now,
+                schema_version: 1,
             }])
         }
 
@@ -352,6 +359,7 @@ mod tests {
                     correlation_id: Uuid::new_v4(),
                     causation_id: Uuid::new_v4(),
                     occurred_at: fixed_now,
+                    schema_version: 1,
                 },
                 StoredEvent {
                     event_id: Uuid::new_v4(),
@@ -367,6 +375,7 @@ mod tests {
                     correlation_id: Uuid::new_v4(),
                     causation_id: Uuid::new_v4(),
                     occurred_at: fixed_now,
+                    schema_version: 1,
                 },
             ])
         }
@@ -394,7 +403,13 @@ mod tests {
             Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap(),
         ));
         let rng: Arc<Mutex<dyn DeterministicRng + Send>> = Arc::new(Mutex::new(MockRng));
-        AppState::new(pool, clock, rng, event_repository)
+        AppState::new(
+            Some(pool),
+            clock,
+            rng,
+            event_repository,
+            Arc::new(InMemoryReadModelStore::new()),
+        )
     }
 
     fn test_app_state() -> AppState {
@@ -667,9 +682,12 @@ mod tests {
 
     #[tokio::test]
     async fn test_get_campaign_returns_200_with_json() {
-        // Arrange
-        let app = router().with_state(test_app_state());
+        // Arrange — queries read the projection, which is fed from the
+        // global log, so the mock's stream is recorded there.
         let campaign_id = Uuid::new_v4();
+        let events = MockEventRepository.load_events(campaign_id).await.unwrap();
+        let repo = RecordingEventRepository::new(Ok(events));
+        let app = router().with_state(app_state_with(Arc::new(repo)));
 
         let request = Request::builder()
             .method("GET")
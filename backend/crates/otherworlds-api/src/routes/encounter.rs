@@ -0,0 +1,446 @@
+//! Routes for combat encounters (Rules & Resolution bounded context).
+
+use axum::extract::{Path, State};
+use axum::http::HeaderMap;
+use axum::{
+    Json, Router,
+    routing::{get, post},
+};
+use serde::{Deserialize, Serialize};
+use tracing::{info, instrument};
+use uuid::Uuid;
+
+use otherworlds_rules::application::query_handlers::{EncounterSummary, EncounterView};
+use otherworlds_rules::application::{encounter_handlers, query_handlers};
+use otherworlds_rules::domain::commands;
+use otherworlds_rules::domain::encounter::EncounterParticipant;
+
+use crate::error::ApiError;
+use crate::orchestration::encounter::orchestrate_start_encounter;
+use crate::run_scope::RunScope;
+use crate::state::AppState;
+
+/// Extracts a correlation ID from the `X-Correlation-ID` header, falling back
+/// to a new v4 UUID if the header is absent or not a valid UUID.
+fn extract_correlation_id(headers: &HeaderMap) -> Uuid {
+    headers
+        .get("x-correlation-id")
+        .and_then(|v| v.to_str().ok())
+        .and_then(|s| Uuid::parse_str(s).ok())
+        .unwrap_or_else(Uuid::new_v4)
+}
+
+/// Request body for POST /start-encounter.
+#[derive(Debug, Deserialize)]
+pub struct StartEncounterRequest {
+    /// The encounter to start.
+    pub encounter_id: Uuid,
+    /// The participants, each rolling initiative in the order listed.
+    pub participants: Vec<EncounterParticipant>,
+}
+
+/// Request body for POST /end-turn.
+#[derive(Debug, Deserialize)]
+pub struct EndTurnRequest {
+    /// The encounter whose current turn ends.
+    pub encounter_id: Uuid,
+}
+
+/// Request body for POST /delay-turn.
+#[derive(Debug, Deserialize)]
+pub struct DelayTurnRequest {
+    /// The encounter whose acting participant delays.
+    pub encounter_id: Uuid,
+    /// The participant to act after; must not have acted yet this round.
+    pub after_participant_id: Uuid,
+}
+
+/// Request body for POST /ready-action.
+#[derive(Debug, Deserialize)]
+pub struct ReadyActionRequest {
+    /// The encounter whose acting participant readies an action.
+    pub encounter_id: Uuid,
+    /// The circumstance the readied action waits for.
+    pub trigger: String,
+}
+
+/// Request body for POST /trigger-readied-action.
+#[derive(Debug, Deserialize)]
+pub struct TriggerReadiedActionRequest {
+    /// The encounter the readied action belongs to.
+    pub encounter_id: Uuid,
+    /// The participant whose readied action fires.
+    pub participant_id: Uuid,
+}
+
+/// Request body for POST /link-resolution.
+#[derive(Debug, Deserialize)]
+pub struct LinkResolutionRequest {
+    /// The encounter the action is taken in.
+    pub encounter_id: Uuid,
+    /// The participant taking the action.
+    pub participant_id: Uuid,
+    /// The resolution recording the action's check.
+    pub resolution_id: Uuid,
+}
+
+/// Request body for POST /end-encounter.
+#[derive(Debug, Deserialize)]
+pub struct EndEncounterRequest {
+    /// The encounter to end.
+    pub encounter_id: Uuid,
+    /// Optional reason the encounter ended (e.g., "`enemies fled`").
+    #[serde(default)]
+    pub reason: Option<String>,
+}
+
+/// Response body returned after a command is successfully handled.
+#[derive(Debug, Serialize)]
+pub struct CommandResponse {
+    /// IDs of the domain events produced and persisted.
+    pub event_ids: Vec<Uuid>,
+}
+
+/// POST /start-encounter
+#[instrument(skip(state, headers, request), fields(encounter_id = %request.encounter_id))]
+async fn start_encounter(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Json(request): Json<StartEncounterRequest>,
+) -> Result<Json<CommandResponse>, ApiError> {
+    let command = commands::StartEncounter {
+        correlation_id: extract_correlation_id(&headers),
+        encounter_id: request.encounter_id,
+        participants: request.participants,
+    };
+
+    info!(correlation_id = %command.correlation_id, "handling start_encounter command");
+
+    let scope = RunScope::from_headers(&state, &headers).await?;
+    let stored_events = orchestrate_start_encounter(
+        &command,
+        scope.run_id(),
+        state.clock.as_ref(),
+        scope.rng(),
+        scope.repo(),
+    )
+    .await?;
+    scope.commit(&command, state.clock.as_ref()).await?;
+
+    let event_ids = stored_events.iter().map(|e| e.event_id).collect();
+
+    Ok(Json(CommandResponse { event_ids }))
+}
+
+/// POST /end-turn
+#[instrument(skip(state, headers, request), fields(encounter_id = %request.encounter_id))]
+async fn end_turn(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Json(request): Json<EndTurnRequest>,
+) -> Result<Json<CommandResponse>, ApiError> {
+    let command = commands::EndTurn {
+        correlation_id: extract_correlation_id(&headers),
+        encounter_id: request.encounter_id,
+    };
+
+    info!(correlation_id = %command.correlation_id, "handling end_turn command");
+
+    let scope = RunScope::for_target(&state, &headers, command.encounter_id).await?;
+    let stored_events = encounter_handlers::handle_end_turn(
+        &command,
+        state.clock.as_ref(),
+        scope.rng(),
+        scope.repo(),
+    )
+    .await?;
+    scope.commit(&command, state.clock.as_ref()).await?;
+
+    let event_ids = stored_events.iter().map(|e| e.event_id).collect();
+
+    Ok(Json(CommandResponse { event_ids }))
+}
+
+/// POST /delay-turn
+#[instrument(skip(state, headers, request), fields(encounter_id = %request.encounter_id))]
+async fn delay_turn(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Json(request): Json<DelayTurnRequest>,
+) -> Result<Json<CommandResponse>, ApiError> {
+    let command = commands::DelayTurn {
+        correlation_id: extract_correlation_id(&headers),
+        encounter_id: request.encounter_id,
+        after_participant_id: request.after_participant_id,
+    };
+
+    info!(correlation_id = %command.correlation_id, "handling delay_turn command");
+
+    let scope = RunScope::for_target(&state, &headers, command.encounter_id).await?;
+    let stored_events = encounter_handlers::handle_delay_turn(
+        &command,
+        state.clock.as_ref(),
+        scope.rng(),
+        scope.repo(),
+    )
+    .await?;
+    scope.commit(&command, state.clock.as_ref()).await?;
+
+    let event_ids = stored_events.iter().map(|e| e.event_id).collect();
+
+    Ok(Json(CommandResponse { event_ids }))
+}
+
+/// POST /ready-action
+#[instrument(skip(state, headers, request), fields(encounter_id = %request.encounter_id))]
+async fn ready_action(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Json(request): Json<ReadyActionRequest>,
+) -> Result<Json<CommandResponse>, ApiError> {
+    let command = commands::ReadyAction {
+        correlation_id: extract_correlation_id(&headers),
+        encounter_id: request.encounter_id,
+        trigger: request.trigger,
+    };
+
+    info!(correlation_id = %command.correlation_id, "handling ready_action command");
+
+    let scope = RunScope::for_target(&state, &headers, command.encounter_id).await?;
+    let stored_events = encounter_handlers::handle_ready_action(
+        &command,
+        state.clock.as_ref(),
+        scope.rng(),
+        scope.repo(),
+    )
+    .await?;
+    scope.commit(&command, state.clock.as_ref()).await?;
+
+    let event_ids = stored_events.iter().map(|e| e.event_id).collect();
+
+    Ok(Json(CommandResponse { event_ids }))
+}
+
+/// POST /trigger-readied-action
+#[instrument(skip(state, headers, request), fields(encounter_id = %request.encounter_id))]
+async fn trigger_readied_action(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Json(request): Json<TriggerReadiedActionRequest>,
+) -> Result<Json<CommandResponse>, ApiError> {
+    let command = commands::TriggerReadiedAction {
+        correlation_id: extract_correlation_id(&headers),
+        encounter_id: request.encounter_id,
+        participant_id: request.participant_id,
+    };
+
+    info!(correlation_id = %command.correlation_id, "handling trigger_readied_action command");
+
+    let scope = RunScope::for_target(&state, &headers, command.encounter_id).await?;
+    let stored_events = encounter_handlers::handle_trigger_readied_action(
+        &command,
+        state.clock.as_ref(),
+        scope.rng(),
+        scope.repo(),
+    )
+    .await?;
+    scope.commit(&command, state.clock.as_ref()).await?;
+
+    let event_ids = stored_events.iter().map(|e| e.event_id).collect();
+
+    Ok(Json(CommandResponse { event_ids }))
+}
+
+/// POST /link-resolution
+#[instrument(skip(state, headers, request), fields(encounter_id = %request.encounter_id))]
+async fn link_resolution(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Json(request): Json<LinkResolutionRequest>,
+) -> Result<Json<CommandResponse>, ApiError> {
+    let command = commands::LinkResolution {
+        correlation_id: extract_correlation_id(&headers),
+        encounter_id: request.encounter_id,
+        participant_id: request.participant_id,
+        resolution_id: request.resolution_id,
+    };
+
+    info!(correlation_id = %command.correlation_id, "handling link_resolution command");
+
+    let scope = RunScope::for_target(&state, &headers, command.encounter_id).await?;
+    let stored_events = encounter_handlers::handle_link_resolution(
+        &command,
+        state.clock.as_ref(),
+        scope.rng(),
+        scope.repo(),
+    )
+    .await?;
+    scope.commit(&command, state.clock.as_ref()).await?;
+
+    let event_ids = stored_events.iter().map(|e| e.event_id).collect();
+
+    Ok(Json(CommandResponse { event_ids }))
+}
+
+/// POST /end-encounter
+#[instrument(skip(state, headers, request), fields(encounter_id = %request.encounter_id))]
+async fn end_encounter(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Json(request): Json<EndEncounterRequest>,
+) -> Result<Json<CommandResponse>, ApiError> {
+    let command = commands::EndEncounter {
+        correlation_id: extract_correlation_id(&headers),
+        encounter_id: request.encounter_id,
+        reason: request.reason,
+    };
+
+    info!(correlation_id = %command.correlation_id, "handling end_encounter command");
+
+    let scope = RunScope::for_target(&state, &headers, command.encounter_id).await?;
+    let stored_events = encounter_handlers::handle_end_encounter(
+        &command,
+        state.clock.as_ref(),
+        scope.rng(),
+        scope.repo(),
+    )
+    .await?;
+    scope.commit(&command, state.clock.as_ref()).await?;
+
+    let event_ids = stored_events.iter().map(|e| e.event_id).collect();
+
+    Ok(Json(CommandResponse { event_ids }))
+}
+
+/// GET /
+#[instrument(skip(state))]
+async fn list_encounters(
+    State(state): State<AppState>,
+) -> Result<Json<Vec<EncounterSummary>>, ApiError> {
+    let summaries =
+        query_handlers::list_encounters(&*state.event_repository, &*state.read_models).await?;
+    Ok(Json(summaries))
+}
+
+/// GET /{`encounter_id`}
+#[instrument(skip(state), fields(encounter_id = %id))]
+async fn get_encounter(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<EncounterView>, ApiError> {
+    let view =
+        query_handlers::get_encounter_by_id(id, &*state.event_repository, &*state.read_models)
+            .await?;
+    Ok(Json(view))
+}
+
+/// Returns the router for combat encounter endpoints.
+pub fn router() -> Router<AppState> {
+    Router::new()
+        .route("/", get(list_encounters))
+        .route("/{encounter_id}", get(get_encounter))
+        .route("/start-encounter", post(start_encounter))
+        .route("/end-turn", post(end_turn))
+        .route("/delay-turn", post(delay_turn))
+        .route("/ready-action", post(ready_action))
+        .route("/trigger-readied-action", post(trigger_readied_action))
+        .route("/link-resolution", post(link_resolution))
+        .route("/end-encounter", post(end_encounter))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use axum::body::Body;
+    use axum::http::{Request, StatusCode};
+    use chrono::Utc;
+    use otherworlds_core::clock::Clock;
+    use otherworlds_core::repository::EventRepository;
+    use otherworlds_core::rng::DeterministicRng;
+    use otherworlds_test_support::{
+        EmptyEventRepository, FixedClock, InMemoryReadModelStore, SequenceRng,
+    };
+    use serde_json::Value;
+    use std::sync::{Arc, Mutex};
+    use tower::ServiceExt;
+
+    fn app_state_with(
+        event_repository: Arc<dyn EventRepository>,
+        rng: Arc<Mutex<dyn DeterministicRng + Send>>,
+    ) -> AppState {
+        let clock: Arc<dyn Clock + Send + Sync> = Arc::new(FixedClock(Utc::now()));
+        AppState::new(
+            None,
+            clock,
+            rng,
+            event_repository,
+            Arc::new(InMemoryReadModelStore::new()),
+        )
+    }
+
+    fn post(uri: &str, body: &Value) -> Request<Body> {
+        Request::builder()
+            .method("POST")
+            .uri(uri)
+            .header("content-type", "application/json")
+            .body(Body::from(serde_json::to_vec(body).unwrap()))
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_start_encounter_returns_200_with_event_ids() {
+        // Arrange — two initiative rolls, then two event IDs.
+        let rng: Arc<Mutex<dyn DeterministicRng + Send>> =
+            Arc::new(Mutex::new(SequenceRng::new((1..=10).collect())));
+        let app = router().with_state(app_state_with(Arc::new(EmptyEventRepository), rng));
+        let body = serde_json::json!({
+            "encounter_id": Uuid::new_v4(),
+            "participants": [
+                { "participant_id": Uuid::new_v4(), "name": "Hero", "initiative_modifier": 2 },
+                { "participant_id": Uuid::new_v4(), "name": "Goblin" }
+            ]
+        });
+
+        // Act
+        let response = app.oneshot(post("/start-encounter", &body)).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), StatusCode::OK);
+        let body_bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let json: Value = serde_json::from_slice(&body_bytes).unwrap();
+        assert_eq!(json["event_ids"].as_array().unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_start_encounter_returns_400_without_participants() {
+        // Arrange
+        let rng: Arc<Mutex<dyn DeterministicRng + Send>> =
+            Arc::new(Mutex::new(SequenceRng::new(vec![1])));
+        let app = router().with_state(app_state_with(Arc::new(EmptyEventRepository), rng));
+        let body = serde_json::json!({ "encounter_id": Uuid::new_v4(), "participants": [] });
+
+        // Act
+        let response = app.oneshot(post("/start-encounter", &body)).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_end_turn_returns_404_for_unknown_encounter() {
+        // Arrange
+        let rng: Arc<Mutex<dyn DeterministicRng + Send>> =
+            Arc::new(Mutex::new(SequenceRng::new(vec![1])));
+        let app = router().with_state(app_state_with(Arc::new(EmptyEventRepository), rng));
+        let body = serde_json::json!({ "encounter_id": Uuid::new_v4() });
+
+        // Act
+        let response = app.oneshot(post("/end-turn", &body)).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+}
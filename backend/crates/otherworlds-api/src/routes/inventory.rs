@@ -1,6 +1,9 @@
 //! Routes for the Inventory & Economy bounded context.
 
+use std::collections::BTreeMap;
+
 use axum::extract::{Path, State};
+use axum::http::HeaderMap;
 use axum::{
     Json, Router,
     routing::{get, post},
@@ -14,6 +17,7 @@ use otherworlds_inventory::application::{command_handlers, query_handlers};
 use otherworlds_inventory::domain::commands;
 
 use crate::error::ApiError;
+use crate::run_scope::RunScope;
 use crate::state::AppState;
 
 /// Request body for POST /add-item.
@@ -41,6 +45,10 @@ pub struct EquipItemRequest {
     pub inventory_id: Uuid,
     /// The item to equip.
     pub item_id: Uuid,
+    /// Check bonuses the item grants while equipped, keyed by skill or
+    /// attribute name (e.g., `{"stealth": 2}`).
+    #[serde(default)]
+    pub bonuses: BTreeMap<String, i32>,
 }
 
 /// Response body returned after a command is successfully handled.
@@ -57,7 +65,8 @@ pub struct CommandResponse {
 async fn list_inventories(
     State(state): State<AppState>,
 ) -> Result<Json<Vec<InventorySummary>>, ApiError> {
-    let summaries = query_handlers::list_inventories(&*state.event_repository).await?;
+    let summaries =
+        query_handlers::list_inventories(&*state.event_repository, &*state.read_models).await?;
     Ok(Json(summaries))
 }
 
@@ -67,31 +76,37 @@ async fn get_inventory(
     State(state): State<AppState>,
     Path(id): Path<Uuid>,
 ) -> Result<Json<InventoryView>, ApiError> {
-    let view = query_handlers::get_inventory_by_id(id, &*state.event_repository).await?;
+    let view =
+        query_handlers::get_inventory_by_id(id, &*state.event_repository, &*state.read_models)
+            .await?;
     Ok(Json(view))
 }
 
 /// POST /add-item
-#[instrument(skip(state, request), fields(inventory_id = %request.inventory_id))]
+#[instrument(skip(state, headers, request), fields(inventory_id = %request.inventory_id))]
 async fn add_item(
     State(state): State<AppState>,
+    headers: HeaderMap,
     Json(request): Json<AddItemRequest>,
 ) -> Result<Json<CommandResponse>, ApiError> {
     let command = commands::AddItem {
         correlation_id: Uuid::new_v4(),
+        causation_id: None,
         inventory_id: request.inventory_id,
         item_id: request.item_id,
     };
 
     info!(correlation_id = %command.correlation_id, "handling add_item command");
 
+    let scope = RunScope::from_headers(&state, &headers).await?;
     let result = command_handlers::handle_add_item(
         &command,
         state.clock.as_ref(),
-        &state.rng,
-        &*state.event_repository,
+        scope.rng(),
+        scope.repo(),
     )
     .await?;
+    scope.commit(&command, state.clock.as_ref()).await?;
 
     let event_ids = result.stored_events.iter().map(|e| e.event_id).collect();
 
@@ -102,9 +117,10 @@ async fn add_item(
 }
 
 /// POST /remove-item
-#[instrument(skip(state, request), fields(inventory_id = %request.inventory_id))]
+#[instrument(skip(state, headers, request), fields(inventory_id = %request.inventory_id))]
 async fn remove_item(
     State(state): State<AppState>,
+    headers: HeaderMap,
     Json(request): Json<RemoveItemRequest>,
 ) -> Result<Json<CommandResponse>, ApiError> {
     let command = commands::RemoveItem {
@@ -115,13 +131,15 @@ async fn remove_item(
 
     info!(correlation_id = %command.correlation_id, "handling remove_item command");
 
+    let scope = RunScope::from_headers(&state, &headers).await?;
     let result = command_handlers::handle_remove_item(
         &command,
         state.clock.as_ref(),
-        &state.rng,
-        &*state.event_repository,
+        scope.rng(),
+        scope.repo(),
     )
     .await?;
+    scope.commit(&command, state.clock.as_ref()).await?;
 
     let event_ids = result.stored_events.iter().map(|e| e.event_id).collect();
 
@@ -132,26 +150,30 @@ async fn remove_item(
 }
 
 /// POST /equip-item
-#[instrument(skip(state, request), fields(inventory_id = %request.inventory_id))]
+#[instrument(skip(state, headers, request), fields(inventory_id = %request.inventory_id))]
 async fn equip_item(
     State(state): State<AppState>,
+    headers: HeaderMap,
     Json(request): Json<EquipItemRequest>,
 ) -> Result<Json<CommandResponse>, ApiError> {
     let command = commands::EquipItem {
         correlation_id: Uuid::new_v4(),
         inventory_id: request.inventory_id,
         item_id: request.item_id,
+        bonuses: request.bonuses,
     };
 
     info!(correlation_id = %command.correlation_id, "handling equip_item command");
 
+    let scope = RunScope::from_headers(&state, &headers).await?;
     let result = command_handlers::handle_equip_item(
         &command,
         state.clock.as_ref(),
-        &state.rng,
-        &*state.event_repository,
+        scope.rng(),
+        scope.repo(),
     )
     .await?;
+    scope.commit(&command, state.clock.as_ref()).await?;
 
     let event_ids = result.stored_events.iter().map(|e| e.event_id).collect();
 
@@ -162,9 +184,10 @@ async fn equip_item(
 }
 
 /// DELETE /{`inventory_id`}
-#[instrument(skip(state), fields(inventory_id = %id))]
+#[instrument(skip(state, headers), fields(inventory_id = %id))]
 async fn archive_inventory(
     State(state): State<AppState>,
+    headers: HeaderMap,
     Path(id): Path<Uuid>,
 ) -> Result<Json<CommandResponse>, ApiError> {
     let command = commands::ArchiveInventory {
@@ -174,13 +197,15 @@ async fn archive_inventory(
 
     info!(correlation_id = %command.correlation_id, "handling archive_inventory command");
 
+    let scope = RunScope::from_headers(&state, &headers).await?;
     let result = command_handlers::handle_archive_inventory(
         &command,
         state.clock.as_ref(),
-        &state.rng,
-        &*state.event_repository,
+        scope.rng(),
+        scope.repo(),
     )
     .await?;
+    scope.commit(&command, state.clock.as_ref()).await?;
 
     let event_ids = result.stored_events.iter().map(|e| e.event_id).collect();
 
@@ -218,7 +243,8 @@ mod tests {
         ITEM_ADDED_EVENT_TYPE, InventoryEventKind, ItemAdded,
     };
     use otherworlds_test_support::{
-        EmptyEventRepository, FailingEventRepository, FixedClock, MockRng,
+        EmptyEventRepository, FailingEventRepository, FixedClock, InMemoryReadModelStore, MockRng,
+        RecordingEventRepository,
     };
     use serde_json::Value;
     use sqlx::PgPool;
@@ -254,6 +280,7 @@ mod tests {
                 correlation_id: Uuid::new_v4(),
                 causation_id: Uuid::new_v4(),
                 occurred_at: fixed_now,
+                schema_version: 1,
             }])
         }
 
@@ -280,7 +307,13 @@ mod tests {
             Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap(),
         ));
         let rng: Arc<Mutex<dyn DeterministicRng + Send>> = Arc::new(Mutex::new(MockRng));
-        AppState::new(pool, clock, rng, event_repository)
+        AppState::new(
+            Some(pool),
+            clock,
+            rng,
+            event_repository,
+            Arc::new(InMemoryReadModelStore::new()),
+        )
     }
 
     fn test_app_state() -> AppState {
@@ -594,9 +627,12 @@ mod tests {
 
     #[tokio::test]
     async fn test_get_inventory_returns_200_with_json() {
-        // Arrange
-        let app = router().with_state(test_app_state());
+        // Arrange — queries read the projection, which is fed from the
+        // global log, so the mock's stream is recorded there.
         let inventory_id = Uuid::new_v4();
+        let events = MockEventRepository.load_events(inventory_id).await.unwrap();
+        let repo = RecordingEventRepository::new(Ok(events));
+        let app = router().with_state(app_state_with(Arc::new(repo)));
 
         let request = Request::builder()
             .method("GET")
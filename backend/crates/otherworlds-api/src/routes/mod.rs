@@ -1,7 +1,9 @@
 //! Route modules organized by bounded context.
 
+pub mod admin;
 pub mod character;
 pub mod content;
+pub mod encounter;
 pub mod health;
 pub mod inventory;
 pub mod narrative;
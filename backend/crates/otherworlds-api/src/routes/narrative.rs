@@ -1,6 +1,7 @@
 //! Routes for the Narrative Orchestration bounded context.
 
 use axum::extract::{Path, State};
+use axum::http::HeaderMap;
 use axum::{
     Json, Router,
     routing::{get, post},
@@ -17,6 +18,7 @@ use otherworlds_narrative::domain::commands;
 use otherworlds_narrative::domain::value_objects::{ChoiceOption, SceneData};
 
 use crate::error::ApiError;
+use crate::run_scope::RunScope;
 use crate::state::AppState;
 
 /// Request body for POST /advance-beat.
@@ -91,9 +93,10 @@ pub struct CommandResponse {
 }
 
 /// POST /advance-beat
-#[instrument(skip(state, request), fields(session_id = %request.session_id))]
+#[instrument(skip(state, headers, request), fields(session_id = %request.session_id))]
 async fn advance_beat(
     State(state): State<AppState>,
+    headers: HeaderMap,
     Json(request): Json<AdvanceBeatRequest>,
 ) -> Result<Json<CommandResponse>, ApiError> {
     let command = commands::AdvanceBeat {
@@ -103,13 +106,15 @@ async fn advance_beat(
 
     info!(correlation_id = %command.correlation_id, "handling advance_beat command");
 
+    let scope = RunScope::from_headers(&state, &headers).await?;
     let stored_events = command_handlers::handle_advance_beat(
         &command,
         state.clock.as_ref(),
-        &*state.rng,
-        &*state.event_repository,
+        scope.rng(),
+        scope.repo(),
     )
     .await?;
+    scope.commit(&command, state.clock.as_ref()).await?;
 
     let event_ids = stored_events.iter().map(|e| e.event_id).collect();
 
@@ -117,9 +122,10 @@ async fn advance_beat(
 }
 
 /// POST /present-choice
-#[instrument(skip(state, request), fields(session_id = %request.session_id))]
+#[instrument(skip(state, headers, request), fields(session_id = %request.session_id))]
 async fn present_choice(
     State(state): State<AppState>,
+    headers: HeaderMap,
     Json(request): Json<PresentChoiceRequest>,
 ) -> Result<Json<CommandResponse>, ApiError> {
     let command = commands::PresentChoice {
@@ -129,13 +135,15 @@ async fn present_choice(
 
     info!(correlation_id = %command.correlation_id, "handling present_choice command");
 
+    let scope = RunScope::from_headers(&state, &headers).await?;
     let stored_events = command_handlers::handle_present_choice(
         &command,
         state.clock.as_ref(),
-        &*state.rng,
-        &*state.event_repository,
+        scope.rng(),
+        scope.repo(),
     )
     .await?;
+    scope.commit(&command, state.clock.as_ref()).await?;
 
     let event_ids = stored_events.iter().map(|e| e.event_id).collect();
 
@@ -143,9 +151,10 @@ async fn present_choice(
 }
 
 /// POST /enter-scene
-#[instrument(skip(state, request), fields(session_id = %request.session_id))]
+#[instrument(skip(state, headers, request), fields(session_id = %request.session_id))]
 async fn enter_scene(
     State(state): State<AppState>,
+    headers: HeaderMap,
     Json(request): Json<EnterSceneRequest>,
 ) -> Result<Json<CommandResponse>, ApiError> {
     let scene_data = SceneData {
@@ -170,13 +179,15 @@ async fn enter_scene(
 
     info!(correlation_id = %command.correlation_id, "handling enter_scene command");
 
+    let scope = RunScope::from_headers(&state, &headers).await?;
     let stored_events = command_handlers::handle_enter_scene(
         &command,
         state.clock.as_ref(),
-        &*state.rng,
-        &*state.event_repository,
+        scope.rng(),
+        scope.repo(),
     )
     .await?;
+    scope.commit(&command, state.clock.as_ref()).await?;
 
     let event_ids = stored_events.iter().map(|e| e.event_id).collect();
 
@@ -184,9 +195,10 @@ async fn enter_scene(
 }
 
 /// POST /select-choice
-#[instrument(skip(state, request), fields(session_id = %request.session_id))]
+#[instrument(skip(state, headers, request), fields(session_id = %request.session_id))]
 async fn select_choice(
     State(state): State<AppState>,
+    headers: HeaderMap,
     Json(request): Json<SelectChoiceRequest>,
 ) -> Result<Json<CommandResponse>, ApiError> {
     let target_scene_data = SceneData {
@@ -213,13 +225,15 @@ async fn select_choice(
 
     info!(correlation_id = %command.correlation_id, "handling select_choice command");
 
+    let scope = RunScope::from_headers(&state, &headers).await?;
     let stored_events = command_handlers::handle_select_choice(
         &command,
         state.clock.as_ref(),
-        &*state.rng,
-        &*state.event_repository,
+        scope.rng(),
+        scope.repo(),
     )
     .await?;
+    scope.commit(&command, state.clock.as_ref()).await?;
 
     let event_ids = stored_events.iter().map(|e| e.event_id).collect();
 
@@ -231,14 +245,16 @@ async fn select_choice(
 async fn list_sessions(
     State(state): State<AppState>,
 ) -> Result<Json<Vec<NarrativeSessionSummary>>, ApiError> {
-    let summaries = query_handlers::list_sessions(&*state.event_repository).await?;
+    let summaries =
+        query_handlers::list_sessions(&*state.event_repository, &*state.read_models).await?;
     Ok(Json(summaries))
 }
 
 /// DELETE /{`session_id`}
-#[instrument(skip(state), fields(session_id = %id))]
+#[instrument(skip(state, headers), fields(session_id = %id))]
 async fn archive_session(
     State(state): State<AppState>,
+    headers: HeaderMap,
     Path(id): Path<Uuid>,
 ) -> Result<Json<CommandResponse>, ApiError> {
     let command = commands::ArchiveSession {
@@ -248,13 +264,15 @@ async fn archive_session(
 
     info!(correlation_id = %command.correlation_id, "handling archive_session command");
 
+    let scope = RunScope::from_headers(&state, &headers).await?;
     let stored_events = command_handlers::handle_archive_session(
         &command,
         state.clock.as_ref(),
-        &*state.rng,
-        &*state.event_repository,
+        scope.rng(),
+        scope.repo(),
     )
     .await?;
+    scope.commit(&command, state.clock.as_ref()).await?;
 
     let event_ids = stored_events.iter().map(|e| e.event_id).collect();
 
@@ -267,7 +285,8 @@ async fn get_session(
     State(state): State<AppState>,
     Path(id): Path<Uuid>,
 ) -> Result<Json<NarrativeSessionView>, ApiError> {
-    let view = query_handlers::get_session_by_id(id, &*state.event_repository).await?;
+    let view = query_handlers::get_session_by_id(id, &*state.event_repository, &*state.read_models)
+        .await?;
     Ok(Json(view))
 }
 
@@ -296,7 +315,7 @@ mod tests {
     use otherworlds_narrative::domain::events::{BeatAdvanced, NarrativeEventKind};
     use otherworlds_test_support::{
         ConflictingEventRepository, EmptyEventRepository, FailingEventRepository, FixedClock,
-        MockRng, RecordingEventRepository,
+        InMemoryReadModelStore, MockRng, RecordingEventRepository,
     };
     use serde_json::Value;
     use sqlx::PgPool;
@@ -307,7 +326,13 @@ mod tests {
         let pool = PgPool::connect_lazy("postgres://localhost/test").unwrap();
         let clock: Arc<dyn Clock + Send + Sync> = Arc::new(FixedClock(Utc::now()));
         let rng: Arc<Mutex<dyn DeterministicRng + Send>> = Arc::new(Mutex::new(MockRng));
-        AppState::new(pool, clock, rng, event_repository)
+        AppState::new(
+            Some(pool),
+            clock,
+            rng,
+            event_repository,
+            Arc::new(InMemoryReadModelStore::new()),
+        )
     }
 
     fn test_app_state() -> AppState {
@@ -477,6 +502,7 @@ mod tests {
             correlation_id: Uuid::new_v4(),
             causation_id: Uuid::new_v4(),
             occurred_at: fixed_now,
+            schema_version: 1,
         }];
         let repo = RecordingEventRepository::new(Ok(events));
         let app = router().with_state(app_state_with(Arc::new(repo)));
@@ -624,6 +650,7 @@ mod tests {
             correlation_id: Uuid::new_v4(),
             causation_id: Uuid::new_v4(),
             occurred_at: fixed_now,
+            schema_version: 1,
         }];
         let repo = RecordingEventRepository::new(Ok(existing));
         let app = router().with_state(app_state_with(Arc::new(repo)));
@@ -762,6 +789,7 @@ mod tests {
             correlation_id: Uuid::new_v4(),
             causation_id: Uuid::new_v4(),
             occurred_at: fixed_now,
+            schema_version: 1,
         }];
         let aggregate_id = Uuid::new_v4();
         let repo = ConflictingEventRepository::new(existing, aggregate_id, 1, 2);
@@ -1,45 +1,52 @@
 //! Routes for the cross-context play loop orchestration.
 //!
-//! This module coordinates the manifesto's play loop across bounded contexts:
-//! Intent → Check → Effects → World State → Narrative.
-//! See ADR-0014 for rationale.
+//! This module exposes the manifesto's play loop across bounded contexts:
+//! Intent → Check → Effects → Owning contexts → Narrative. The loop itself lives
+//! in `orchestration::play`. See ADR-0014 for rationale.
 
 use axum::extract::State;
 use axum::http::HeaderMap;
 use axum::{Json, Router, routing::post};
 use serde::{Deserialize, Serialize};
-use tracing::{info, instrument};
+use tracing::instrument;
 use uuid::Uuid;
 
-use otherworlds_core::repository::StoredEvent;
-use otherworlds_narrative::application::command_handlers as narrative_handlers;
-use otherworlds_narrative::domain::commands as narrative_commands;
-use otherworlds_rules::application::command_handlers as rules_handlers;
 use otherworlds_rules::domain::commands as rules_commands;
-use otherworlds_world_state::application::command_handlers as world_state_handlers;
-use otherworlds_world_state::domain::commands as world_state_commands;
+use otherworlds_rules::domain::events::{OpposedParticipant, RollMode, TieBreak};
+use otherworlds_rules::domain::outcome_table::OutcomeTable;
 
 use crate::error::ApiError;
+use crate::orchestration::actor;
+use crate::orchestration::play::{
+    PlayResult, ResolveAction, ResolveOpposedAction, orchestrate_resolve_action,
+    orchestrate_resolve_opposed_action,
+};
+use crate::run_scope::RunScope;
 use crate::state::AppState;
 
 /// Specification for a single effect to produce.
 #[derive(Debug, Deserialize)]
 pub struct EffectSpec {
-    /// The type of effect (e.g., "`damage`", "`heal`", "`status_apply`").
+    /// The type of effect (e.g., "`damage`", "`heal`", "`apply_status`").
     pub effect_type: String,
     /// Optional target of the effect.
     pub target_id: Option<Uuid>,
-    /// Campaign-specific payload.
+    /// The effect's fields, shaped by its type.
     pub payload: serde_json::Value,
 }
 
 /// Request body for POST /resolve-action.
 #[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct ResolveActionRequest {
     /// The narrative session to advance.
     pub session_id: Uuid,
     /// The world snapshot to apply effects to.
     pub world_id: Uuid,
+    /// Optional campaign whose front-matter ruleset judges the check; d20
+    /// when absent.
+    #[serde(default)]
+    pub campaign_id: Option<Uuid>,
     /// The type of action (e.g., "`skill_check`", "`attack`", "`save`").
     pub action_type: String,
     /// Optional skill being used.
@@ -48,10 +55,67 @@ pub struct ResolveActionRequest {
     pub target_id: Option<Uuid>,
     /// The difficulty class to beat.
     pub difficulty_class: i32,
-    /// The modifier applied to the roll.
-    pub modifier: i32,
-    /// The effects to produce on success.
+    /// The modifier applied to the roll; required unless `character_id` is
+    /// given.
+    #[serde(default)]
+    pub modifier: Option<i32>,
+    /// The character making the check; the modifier is then derived from
+    /// its sheet for `skill`.
+    #[serde(default)]
+    pub character_id: Option<Uuid>,
+    /// The character's inventory, whose equipped items add their bonuses.
+    #[serde(default)]
+    pub inventory_id: Option<Uuid>,
+    /// Optional dice expression to roll (e.g., "`2d6+@strength`"); the
+    /// ruleset's default roll when absent.
+    #[serde(default)]
+    pub roll_expression: Option<String>,
+    /// How many times the expression is rolled and which roll counts
+    /// (e.g., "`advantage`", `{"keep_best": 3}`); rolled once when absent.
+    #[serde(default)]
+    pub roll_mode: RollMode,
+    /// Effects produced whatever the check's outcome.
+    #[serde(default)]
     pub effects: Vec<EffectSpec>,
+    /// Effects keyed by the check's outcome (e.g., `{"success": [...],
+    /// "partial_success": {"fallback": "success", "strength_percent": 50}}`),
+    /// instead of `effects`.
+    #[serde(default)]
+    pub outcome_effects: Option<OutcomeTable<rules_commands::EffectSpec>>,
+}
+
+/// Request body for POST /resolve-opposed-action.
+#[derive(Debug, Deserialize)]
+pub struct ResolveOpposedActionRequest {
+    /// The narrative session to advance.
+    pub session_id: Uuid,
+    /// The world snapshot to apply effects to.
+    pub world_id: Uuid,
+    /// Optional campaign whose front-matter ruleset the rolls are made
+    /// under; d20 when absent.
+    #[serde(default)]
+    pub campaign_id: Option<Uuid>,
+    /// The type of action (e.g., "`grapple`", "`contest`").
+    pub action_type: String,
+    /// Optional skill being contested.
+    pub skill: Option<String>,
+    /// The participant initiating the contest.
+    pub actor: OpposedParticipant,
+    /// The participant resisting it.
+    pub opponent: OpposedParticipant,
+    /// Optional dice expression both participants roll; the ruleset's
+    /// default roll when absent.
+    #[serde(default)]
+    pub roll_expression: Option<String>,
+    /// Who wins when both totals are equal; the defender when absent.
+    #[serde(default)]
+    pub tie_break: TieBreak,
+    /// Effects produced whatever the contest's outcome.
+    #[serde(default)]
+    pub effects: Vec<EffectSpec>,
+    /// Effects keyed by the contest's outcome, instead of `effects`.
+    #[serde(default)]
+    pub outcome_effects: Option<OutcomeTable<rules_commands::EffectSpec>>,
 }
 
 /// Response from a resolved action showing all events across contexts.
@@ -67,6 +131,10 @@ pub struct ResolveActionResponse {
     pub check_event_ids: Vec<Uuid>,
     /// Event IDs from producing effects.
     pub effects_event_ids: Vec<Uuid>,
+    /// Event IDs from applying effects to characters.
+    pub character_event_ids: Vec<Uuid>,
+    /// Event IDs from applying effects to inventories.
+    pub inventory_event_ids: Vec<Uuid>,
     /// Event IDs from applying effects to world state.
     pub world_state_event_ids: Vec<Uuid>,
     /// Event IDs from advancing the narrative beat.
@@ -83,145 +151,120 @@ fn extract_correlation_id(headers: &HeaderMap) -> Uuid {
         .unwrap_or_else(Uuid::new_v4)
 }
 
-fn collect_event_ids(events: &[StoredEvent]) -> Vec<Uuid> {
-    events.iter().map(|e| e.event_id).collect()
+fn effect_specs(effects: Vec<EffectSpec>) -> Vec<rules_commands::EffectSpec> {
+    effects
+        .into_iter()
+        .map(|e| rules_commands::EffectSpec {
+            effect_type: e.effect_type,
+            target_id: e.target_id,
+            payload: e.payload,
+        })
+        .collect()
+}
+
+impl ResolveActionResponse {
+    fn new(correlation_id: Uuid, result: PlayResult) -> Self {
+        Self {
+            correlation_id,
+            resolution_id: result.resolution_id,
+            intent_event_ids: result.intent_event_ids,
+            check_event_ids: result.check_event_ids,
+            effects_event_ids: result.effects_event_ids,
+            character_event_ids: result.character_event_ids,
+            inventory_event_ids: result.inventory_event_ids,
+            world_state_event_ids: result.world_state_event_ids,
+            narrative_event_ids: result.narrative_event_ids,
+        }
+    }
 }
 
 /// POST /resolve-action
 ///
-/// Orchestrates the full play loop:
-/// 1. Rules: declare intent
-/// 2. Rules: resolve check (d20 roll)
-/// 3. Rules: produce effects
-/// 4. World State: apply each effect as a world fact
-/// 5. Narrative: advance the beat
+/// Runs one turn of the play loop; see `orchestrate_resolve_action`.
 #[instrument(skip(state, headers, request), fields(session_id = %request.session_id, world_id = %request.world_id))]
 async fn resolve_action(
     State(state): State<AppState>,
     headers: HeaderMap,
     Json(request): Json<ResolveActionRequest>,
 ) -> Result<Json<ResolveActionResponse>, ApiError> {
-    let correlation_id = extract_correlation_id(&headers);
-    let resolution_id = Uuid::new_v4();
-    let intent_id = Uuid::new_v4();
-
-    info!(%correlation_id, %resolution_id, "orchestrating play loop");
-
-    // Step 1: Declare intent (rules context)
-    let declare_intent_cmd = rules_commands::DeclareIntent {
-        correlation_id,
-        resolution_id,
-        intent_id,
+    let modifier =
+        actor::supplied_modifier(request.character_id, request.inventory_id, request.modifier)?;
+    let command = ResolveAction {
+        correlation_id: extract_correlation_id(&headers),
+        session_id: request.session_id,
+        world_id: request.world_id,
+        campaign_id: request.campaign_id,
         action_type: request.action_type,
         skill: request.skill,
         target_id: request.target_id,
         difficulty_class: request.difficulty_class,
-        modifier: request.modifier,
-    };
-
-    let intent_events = rules_handlers::handle_declare_intent(
-        &declare_intent_cmd,
-        state.clock.as_ref(),
-        &state.rng,
-        &*state.event_repository,
-    )
-    .await?;
-
-    // Step 2: Resolve check (rules context)
-    let resolve_check_cmd = rules_commands::ResolveCheck {
-        correlation_id,
-        resolution_id,
+        modifier,
+        character_id: request.character_id,
+        inventory_id: request.inventory_id,
+        roll_expression: request.roll_expression,
+        roll_mode: request.roll_mode,
+        effects: effect_specs(request.effects),
+        outcome_effects: request.outcome_effects,
     };
 
-    let check_events = rules_handlers::handle_resolve_check(
-        &resolve_check_cmd,
-        state.clock.as_ref(),
-        &state.rng,
-        &*state.event_repository,
-    )
-    .await?;
+    let scope = RunScope::from_headers(&state, &headers).await?;
+    let result =
+        orchestrate_resolve_action(&command, state.clock.as_ref(), scope.rng(), scope.repo())
+            .await?;
+    scope.commit(&command, state.clock.as_ref()).await?;
 
-    // Step 3: Produce effects (rules context)
-    let effects = request
-        .effects
-        .into_iter()
-        .map(|e| rules_commands::EffectSpec {
-            effect_type: e.effect_type,
-            target_id: e.target_id,
-            payload: e.payload,
-        })
-        .collect();
-
-    let produce_effects_cmd = rules_commands::ProduceEffects {
-        correlation_id,
-        resolution_id,
-        effects,
-    };
-
-    let effects_events = rules_handlers::handle_produce_effects(
-        &produce_effects_cmd,
-        state.clock.as_ref(),
-        &state.rng,
-        &*state.event_repository,
-    )
-    .await?;
-
-    // Step 4: Apply effects to world state
-    let mut world_state_events = Vec::new();
-    // Extract produced effects from the EffectsProduced event payload
-    for stored in &effects_events {
-        if stored.event_type == "rules.effects_produced"
-            && let Ok(otherworlds_rules::domain::events::RulesEventKind::EffectsProduced(ep)) =
-                serde_json::from_value::<otherworlds_rules::domain::events::RulesEventKind>(
-                    stored.payload.clone(),
-                )
-        {
-            for effect in &ep.effects {
-                let apply_effect_cmd = world_state_commands::ApplyEffect {
-                    correlation_id,
-                    world_id: request.world_id,
-                    fact_key: format!("{}:{}", effect.effect_type, effect.payload),
-                };
-                let ws_events = world_state_handlers::handle_apply_effect(
-                    &apply_effect_cmd,
-                    state.clock.as_ref(),
-                    &state.rng,
-                    &*state.event_repository,
-                )
-                .await?;
-                world_state_events.extend(ws_events);
-            }
-        }
-    }
+    Ok(Json(ResolveActionResponse::new(
+        command.correlation_id,
+        result,
+    )))
+}
 
-    // Step 5: Advance narrative beat
-    let advance_beat_cmd = narrative_commands::AdvanceBeat {
-        correlation_id,
+/// POST /resolve-opposed-action
+///
+/// Runs one turn of the play loop for a contested action; see
+/// `orchestrate_resolve_opposed_action`.
+#[instrument(skip(state, headers, request), fields(session_id = %request.session_id, world_id = %request.world_id))]
+async fn resolve_opposed_action(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Json(request): Json<ResolveOpposedActionRequest>,
+) -> Result<Json<ResolveActionResponse>, ApiError> {
+    let command = ResolveOpposedAction {
+        correlation_id: extract_correlation_id(&headers),
         session_id: request.session_id,
+        world_id: request.world_id,
+        campaign_id: request.campaign_id,
+        action_type: request.action_type,
+        skill: request.skill,
+        actor: request.actor,
+        opponent: request.opponent,
+        roll_expression: request.roll_expression,
+        tie_break: request.tie_break,
+        effects: effect_specs(request.effects),
+        outcome_effects: request.outcome_effects,
     };
 
-    let narrative_events = narrative_handlers::handle_advance_beat(
-        &advance_beat_cmd,
+    let scope = RunScope::from_headers(&state, &headers).await?;
+    let result = orchestrate_resolve_opposed_action(
+        &command,
         state.clock.as_ref(),
-        &state.rng,
-        &*state.event_repository,
+        scope.rng(),
+        scope.repo(),
     )
     .await?;
+    scope.commit(&command, state.clock.as_ref()).await?;
 
-    Ok(Json(ResolveActionResponse {
-        correlation_id,
-        resolution_id,
-        intent_event_ids: collect_event_ids(&intent_events),
-        check_event_ids: collect_event_ids(&check_events),
-        effects_event_ids: collect_event_ids(&effects_events),
-        world_state_event_ids: collect_event_ids(&world_state_events),
-        narrative_event_ids: collect_event_ids(&narrative_events),
-    }))
+    Ok(Json(ResolveActionResponse::new(
+        command.correlation_id,
+        result,
+    )))
 }
 
 /// Returns the router for the play orchestration context.
 pub fn router() -> Router<AppState> {
-    Router::new().route("/resolve-action", post(resolve_action))
+    Router::new()
+        .route("/resolve-action", post(resolve_action))
+        .route("/resolve-opposed-action", post(resolve_opposed_action))
 }
 
 #[cfg(test)]
@@ -232,61 +275,17 @@ mod tests {
     use axum::http::{Request, StatusCode};
     use chrono::{TimeZone, Utc};
     use otherworlds_core::clock::Clock;
-    use otherworlds_core::error::DomainError;
     use otherworlds_core::repository::{EventRepository, StoredEvent};
     use otherworlds_core::rng::DeterministicRng;
-    use otherworlds_test_support::{FailingEventRepository, FixedClock, SequenceRng};
+    use otherworlds_event_store::memory_event_repository::InMemoryEventRepository;
+    use otherworlds_test_support::{
+        FailingEventRepository, FixedClock, InMemoryReadModelStore, SequenceRng,
+    };
     use serde_json::Value;
     use sqlx::PgPool;
     use std::sync::{Arc, Mutex};
     use tower::ServiceExt;
 
-    /// In-memory repository that accumulates appended events and returns them
-    /// on subsequent `load_events` calls. This enables the play loop's
-    /// multi-step orchestration where each step reads events from prior steps.
-    #[derive(Debug)]
-    struct InMemoryEventRepository {
-        events: Mutex<Vec<StoredEvent>>,
-    }
-
-    impl InMemoryEventRepository {
-        fn new() -> Self {
-            Self {
-                events: Mutex::new(Vec::new()),
-            }
-        }
-    }
-
-    #[async_trait::async_trait]
-    impl EventRepository for InMemoryEventRepository {
-        async fn load_events(&self, aggregate_id: Uuid) -> Result<Vec<StoredEvent>, DomainError> {
-            let events = self.events.lock().unwrap();
-            Ok(events
-                .iter()
-                .filter(|e| e.aggregate_id == aggregate_id)
-                .cloned()
-                .collect())
-        }
-
-        async fn append_events(
-            &self,
-            _aggregate_id: Uuid,
-            _expected_version: i64,
-            new_events: &[StoredEvent],
-        ) -> Result<(), DomainError> {
-            let mut events = self.events.lock().unwrap();
-            events.extend_from_slice(new_events);
-            Ok(())
-        }
-
-        async fn list_aggregate_ids(
-            &self,
-            _event_types: &[&str],
-        ) -> Result<Vec<Uuid>, DomainError> {
-            Ok(vec![])
-        }
-    }
-
     fn app_state_with(
         event_repository: Arc<dyn EventRepository>,
         rng: Arc<Mutex<dyn DeterministicRng + Send>>,
@@ -295,14 +294,22 @@ mod tests {
         let clock: Arc<dyn Clock + Send + Sync> = Arc::new(FixedClock(
             Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap(),
         ));
-        AppState::new(pool, clock, rng, event_repository)
+        AppState::new(
+            Some(pool),
+            clock,
+            rng,
+            event_repository,
+            Arc::new(InMemoryReadModelStore::new()),
+        )
     }
 
     fn test_app_state() -> AppState {
-        // Each next_uuid() consumes 4 u32 values, and resolve_check uses 1
-        // for the d20 roll. The full play loop (5 phases, each producing at
-        // least 1 event) needs ~21+ values. Provide a generous pool.
-        let mut values = vec![15]; // first value: d20 natural roll
+        // Each next_uuid() consumes 4 u32 values: the resolution and intent
+        // IDs take the first 8, then resolve_check uses 1 for the d20 roll.
+        // The full play loop (5 phases, each producing at least 1 event)
+        // needs ~29+ values. Provide a generous pool.
+        let mut values: Vec<u32> = (1..=8).collect(); // resolution and intent IDs
+        values.push(15); // d20 natural roll
         values.extend(std::iter::repeat_n(42, 63)); // fill remaining slots
         let rng: Arc<Mutex<dyn DeterministicRng + Send>> =
             Arc::new(Mutex::new(SequenceRng::new(values)));
@@ -323,8 +330,8 @@ mod tests {
             "difficulty_class": 15,
             "modifier": 3,
             "effects": [{
-                "effect_type": "reveal",
-                "payload": { "area": "hidden_passage" }
+                "effect_type": "set_flag",
+                "payload": { "flag": "hidden_passage_revealed", "value": true }
             }]
         });
 
@@ -370,8 +377,9 @@ mod tests {
             "difficulty_class": 10,
             "modifier": 0,
             "effects": [{
-                "effect_type": "damage",
-                "payload": { "amount": 5 }
+                "effect_type": "apply_status",
+                "target_id": Uuid::new_v4(),
+                "payload": { "status": "poisoned", "duration_rounds": 3 }
             }]
         });
 
@@ -422,7 +430,9 @@ mod tests {
     async fn test_resolve_action_returns_500_when_repository_fails() {
         // Arrange
         let rng: Arc<Mutex<dyn DeterministicRng + Send>> =
-            Arc::new(Mutex::new(SequenceRng::new(vec![15, 42, 99])));
+            Arc::new(Mutex::new(SequenceRng::new(vec![
+                1, 2, 3, 4, 5, 6, 7, 8, 15, 42, 99,
+            ])));
         let app = router().with_state(app_state_with(Arc::new(FailingEventRepository), rng));
         let body = serde_json::json!({
             "session_id": Uuid::new_v4(),
@@ -488,4 +498,319 @@ mod tests {
         // Narrative still advances
         assert!(!json["narrative_event_ids"].as_array().unwrap().is_empty());
     }
+
+    #[tokio::test]
+    async fn test_resolve_action_persists_nothing_when_a_late_step_fails() {
+        // Arrange — the narrative session is archived, so the final step fails
+        // after the rules and world-state steps have already run.
+        let session_id = Uuid::new_v4();
+        let archived = StoredEvent {
+            event_id: Uuid::new_v4(),
+            aggregate_id: session_id,
+            event_type: "narrative.session_archived".to_owned(),
+            payload: serde_json::json!({ "SessionArchived": { "session_id": session_id } }),
+            sequence_number: 1,
+            correlation_id: Uuid::new_v4(),
+            causation_id: Uuid::new_v4(),
+            occurred_at: Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap(),
+            schema_version: 1,
+        };
+        let repo = Arc::new(InMemoryEventRepository::new());
+        repo.append_events(session_id, 0, &[archived])
+            .await
+            .unwrap();
+        let mut values: Vec<u32> = (1..=8).collect();
+        values.push(15);
+        values.extend(std::iter::repeat_n(42, 63));
+        let rng: Arc<Mutex<dyn DeterministicRng + Send>> =
+            Arc::new(Mutex::new(SequenceRng::new(values)));
+        let app = router().with_state(app_state_with(repo.clone(), rng));
+        let body = serde_json::json!({
+            "session_id": session_id,
+            "world_id": Uuid::new_v4(),
+            "action_type": "skill_check",
+            "difficulty_class": 10,
+            "modifier": 0,
+            "effects": [{
+                "effect_type": "set_flag",
+                "payload": { "flag": "vault_revealed", "value": true }
+            }]
+        });
+
+        let request = Request::builder()
+            .method("POST")
+            .uri("/resolve-action")
+            .header("content-type", "application/json")
+            .body(Body::from(serde_json::to_vec(&body).unwrap()))
+            .unwrap();
+
+        // Act
+        let response = app.oneshot(request).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        let events = repo.read_all_from(1, 100).await.unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].event.event_type, "narrative.session_archived");
+    }
+
+    #[tokio::test]
+    async fn test_resolve_action_rolls_with_requested_mode() {
+        // Arrange — two 2d6 rolls: 3+4 and 6+5; disadvantage keeps the first.
+        let repo = Arc::new(InMemoryEventRepository::new());
+        let mut values: Vec<u32> = (1..=12).collect(); // IDs and intent event ID
+        values.extend([3, 4, 6, 5]);
+        values.extend(std::iter::repeat_n(42, 63));
+        let rng: Arc<Mutex<dyn DeterministicRng + Send>> =
+            Arc::new(Mutex::new(SequenceRng::new(values)));
+        let app = router().with_state(app_state_with(repo.clone(), rng));
+        let body = serde_json::json!({
+            "session_id": Uuid::new_v4(),
+            "world_id": Uuid::new_v4(),
+            "action_type": "save",
+            "difficulty_class": 8,
+            "modifier": 0,
+            "roll_expression": "2d6+1",
+            "roll_mode": "disadvantage",
+            "effects": []
+        });
+
+        let request = Request::builder()
+            .method("POST")
+            .uri("/resolve-action")
+            .header("content-type", "application/json")
+            .body(Body::from(serde_json::to_vec(&body).unwrap()))
+            .unwrap();
+
+        // Act
+        let response = app.oneshot(request).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), StatusCode::OK);
+        let events = repo.read_all_from(1, 100).await.unwrap();
+        let check = events
+            .iter()
+            .find(|e| e.event.event_type == "rules.check_resolved")
+            .unwrap();
+        let payload = &check.event.payload["CheckResolved"];
+        assert_eq!(payload["rolls"].as_array().unwrap().len(), 2);
+        assert_eq!(payload["kept_roll"], 0);
+        assert_eq!(payload["total"], 8);
+        assert_eq!(payload["outcome"], "Success");
+    }
+
+    #[tokio::test]
+    async fn test_resolve_opposed_action_records_both_rolls() {
+        // Arrange — actor rolls 7 (+5 = 12), opponent rolls 12 (+0 = 12);
+        // the tie goes to the initiator.
+        let repo = Arc::new(InMemoryEventRepository::new());
+        let mut values: Vec<u32> = (1..=12).collect(); // IDs and intent event ID
+        values.extend([7, 12]);
+        values.extend(std::iter::repeat_n(42, 63));
+        let rng: Arc<Mutex<dyn DeterministicRng + Send>> =
+            Arc::new(Mutex::new(SequenceRng::new(values)));
+        let app = router().with_state(app_state_with(repo.clone(), rng));
+        let body = serde_json::json!({
+            "session_id": Uuid::new_v4(),
+            "world_id": Uuid::new_v4(),
+            "action_type": "grapple",
+            "skill": "athletics",
+            "actor": { "modifier": 5 },
+            "opponent": { "modifier": 0 },
+            "tie_break": "initiator",
+            "effects": [{
+                "effect_type": "apply_status",
+                "target_id": Uuid::new_v4(),
+                "payload": { "status": "grappled" }
+            }]
+        });
+
+        let request = Request::builder()
+            .method("POST")
+            .uri("/resolve-opposed-action")
+            .header("content-type", "application/json")
+            .body(Body::from(serde_json::to_vec(&body).unwrap()))
+            .unwrap();
+
+        // Act
+        let response = app.oneshot(request).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), StatusCode::OK);
+        let body_bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let json: Value = serde_json::from_slice(&body_bytes).unwrap();
+        assert!(!json["world_state_event_ids"].as_array().unwrap().is_empty());
+        assert!(!json["narrative_event_ids"].as_array().unwrap().is_empty());
+
+        let events = repo.read_all_from(1, 100).await.unwrap();
+        let check = events
+            .iter()
+            .find(|e| e.event.event_type == "rules.opposed_check_resolved")
+            .unwrap();
+        let payload = &check.event.payload["OpposedCheckResolved"];
+        assert_eq!(payload["actor"]["total"], 12);
+        assert_eq!(payload["opponent"]["total"], 12);
+        assert_eq!(payload["margin"], 0);
+        assert_eq!(payload["winner"], "actor");
+    }
+
+    #[tokio::test]
+    async fn test_resolve_opposed_action_returns_422_for_missing_opponent() {
+        // Arrange
+        let app = router().with_state(test_app_state());
+        let body = serde_json::json!({
+            "session_id": Uuid::new_v4(),
+            "world_id": Uuid::new_v4(),
+            "action_type": "grapple",
+            "actor": { "modifier": 5 },
+            "effects": []
+        });
+
+        let request = Request::builder()
+            .method("POST")
+            .uri("/resolve-opposed-action")
+            .header("content-type", "application/json")
+            .body(Body::from(serde_json::to_vec(&body).unwrap()))
+            .unwrap();
+
+        // Act
+        let response = app.oneshot(request).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    fn campaign_event(
+        campaign_id: Uuid,
+        sequence_number: i64,
+        event_type: &str,
+        payload: Value,
+    ) -> StoredEvent {
+        StoredEvent {
+            event_id: Uuid::new_v4(),
+            aggregate_id: campaign_id,
+            event_type: event_type.to_owned(),
+            payload,
+            sequence_number,
+            correlation_id: Uuid::new_v4(),
+            causation_id: Uuid::new_v4(),
+            occurred_at: Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap(),
+            schema_version: 1,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_resolve_action_judges_the_check_by_the_campaign_ruleset() {
+        // Arrange — a compiled PbtA campaign; 2d6 showing 4 and 3 is a weak hit.
+        let campaign_id = Uuid::new_v4();
+        let compiled = serde_json::json!({
+            "title": "Apocalypse",
+            "ruleset": "pbta",
+            "scenes": {},
+            "npcs": {}
+        });
+        let repo = Arc::new(InMemoryEventRepository::new());
+        repo.append_events(
+            campaign_id,
+            0,
+            &[
+                campaign_event(
+                    campaign_id,
+                    1,
+                    "content.campaign_ingested",
+                    serde_json::json!({ "CampaignIngested": {
+                        "campaign_id": campaign_id,
+                        "version_hash": "abc",
+                        "source": "---\ntitle: Apocalypse\nruleset: pbta\n---\n"
+                    }}),
+                ),
+                campaign_event(
+                    campaign_id,
+                    2,
+                    "content.campaign_compiled",
+                    serde_json::json!({ "CampaignCompiled": {
+                        "campaign_id": campaign_id,
+                        "version_hash": "abc",
+                        "compiled_data": compiled.to_string()
+                    }}),
+                ),
+            ],
+        )
+        .await
+        .unwrap();
+        let mut values: Vec<u32> = (1..=12).collect(); // IDs and intent event ID
+        values.extend([4, 3]);
+        values.extend(std::iter::repeat_n(42, 63));
+        let rng: Arc<Mutex<dyn DeterministicRng + Send>> =
+            Arc::new(Mutex::new(SequenceRng::new(values)));
+        let app = router().with_state(app_state_with(repo.clone(), rng));
+        let body = serde_json::json!({
+            "session_id": Uuid::new_v4(),
+            "world_id": Uuid::new_v4(),
+            "campaign_id": campaign_id,
+            "action_type": "act_under_pressure",
+            "difficulty_class": 0,
+            "modifier": 0,
+            "effects": []
+        });
+
+        let request = Request::builder()
+            .method("POST")
+            .uri("/resolve-action")
+            .header("content-type", "application/json")
+            .body(Body::from(serde_json::to_vec(&body).unwrap()))
+            .unwrap();
+
+        // Act
+        let response = app.oneshot(request).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), StatusCode::OK);
+        let events = repo.read_all_from(1, 100).await.unwrap();
+        let payload_of = |event_type: &str| {
+            events
+                .iter()
+                .find(|e| e.event.event_type == event_type)
+                .unwrap()
+                .event
+                .payload
+                .clone()
+        };
+        let intent = payload_of("rules.intent_declared");
+        assert_eq!(intent["IntentDeclared"]["ruleset"], "pbta");
+        assert_eq!(intent["IntentDeclared"]["roll_expression"], "2d6");
+        let check = payload_of("rules.check_resolved");
+        assert_eq!(check["CheckResolved"]["total"], 7);
+        assert_eq!(check["CheckResolved"]["outcome"], "PartialSuccess");
+    }
+
+    #[tokio::test]
+    async fn test_resolve_action_returns_404_for_unknown_campaign() {
+        // Arrange
+        let app = router().with_state(test_app_state());
+        let body = serde_json::json!({
+            "session_id": Uuid::new_v4(),
+            "world_id": Uuid::new_v4(),
+            "campaign_id": Uuid::new_v4(),
+            "action_type": "skill_check",
+            "difficulty_class": 10,
+            "modifier": 0,
+            "effects": []
+        });
+
+        let request = Request::builder()
+            .method("POST")
+            .uri("/resolve-action")
+            .header("content-type", "application/json")
+            .body(Body::from(serde_json::to_vec(&body).unwrap()))
+            .unwrap();
+
+        // Act
+        let response = app.oneshot(request).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
 }
@@ -1,6 +1,6 @@
 //! Routes for the Rules & Resolution bounded context.
 
-use axum::extract::{Path, State};
+use axum::extract::{Path, Query, State};
 use axum::http::HeaderMap;
 use axum::{
     Json, Router,
@@ -10,11 +10,19 @@ use serde::{Deserialize, Serialize};
 use tracing::{info, instrument};
 use uuid::Uuid;
 
-use otherworlds_rules::application::query_handlers::{ResolutionSummary, ResolutionView};
+use otherworlds_core::error::DomainError;
+use otherworlds_rules::application::query_handlers::{
+    CheckOddsView, ResolutionSummary, ResolutionView,
+};
 use otherworlds_rules::application::{command_handlers, query_handlers};
 use otherworlds_rules::domain::commands;
+use otherworlds_rules::domain::events::{OpposedParticipant, RollMode, TieBreak};
+use otherworlds_rules::domain::outcome_table::OutcomeTable;
+use otherworlds_rules::domain::ruleset::RulesetId;
 
 use crate::error::ApiError;
+use crate::orchestration::{actor, play};
+use crate::run_scope::RunScope;
 use crate::state::AppState;
 
 /// Extracts a correlation ID from the `X-Correlation-ID` header, falling back
@@ -29,6 +37,7 @@ fn extract_correlation_id(headers: &HeaderMap) -> Uuid {
 
 /// Request body for POST /declare-intent.
 #[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
 pub struct DeclareIntentRequest {
     /// The resolution this intent belongs to.
     pub resolution_id: Uuid,
@@ -42,8 +51,56 @@ pub struct DeclareIntentRequest {
     pub target_id: Option<Uuid>,
     /// The difficulty class to beat.
     pub difficulty_class: i32,
-    /// The modifier applied to the roll.
-    pub modifier: i32,
+    /// The modifier applied to the roll; required unless `character_id` is
+    /// given.
+    #[serde(default)]
+    pub modifier: Option<i32>,
+    /// The character making the check; the modifier is then derived from
+    /// its sheet for `skill`.
+    #[serde(default)]
+    pub character_id: Option<Uuid>,
+    /// The character's inventory, whose equipped items add their bonuses.
+    #[serde(default)]
+    pub inventory_id: Option<Uuid>,
+    /// Optional dice expression to roll (e.g., "`2d6+@strength`"); the
+    /// ruleset's default roll when absent.
+    #[serde(default)]
+    pub roll_expression: Option<String>,
+    /// How many times the expression is rolled and which roll counts
+    /// (e.g., "`advantage`", `{"keep_best": 3}`); rolled once when absent.
+    #[serde(default)]
+    pub roll_mode: RollMode,
+    /// The ruleset the check is rolled and judged by (e.g., "`pbta`"); d20
+    /// when absent.
+    #[serde(default)]
+    pub ruleset: RulesetId,
+}
+
+/// Request body for POST /declare-opposed-intent.
+#[derive(Debug, Deserialize)]
+pub struct DeclareOpposedIntentRequest {
+    /// The resolution this intent belongs to.
+    pub resolution_id: Uuid,
+    /// The intent identifier.
+    pub intent_id: Uuid,
+    /// The type of action (e.g., "`grapple`", "`contest`").
+    pub action_type: String,
+    /// Optional skill being contested.
+    pub skill: Option<String>,
+    /// The participant initiating the contest.
+    pub actor: OpposedParticipant,
+    /// The participant resisting it.
+    pub opponent: OpposedParticipant,
+    /// Optional dice expression both participants roll; the ruleset's
+    /// default roll when absent.
+    #[serde(default)]
+    pub roll_expression: Option<String>,
+    /// The ruleset the rolls are made under; d20 when absent.
+    #[serde(default)]
+    pub ruleset: RulesetId,
+    /// Who wins when both totals are equal; the defender when absent.
+    #[serde(default)]
+    pub tie_break: TieBreak,
 }
 
 /// Request body for POST /resolve-check.
@@ -56,11 +113,11 @@ pub struct ResolveCheckRequest {
 /// Request body for a single effect specification.
 #[derive(Debug, Deserialize)]
 pub struct EffectSpecRequest {
-    /// The type of effect (e.g., "`damage`", "`heal`", "`status_apply`").
+    /// The type of effect (e.g., "`damage`", "`heal`", "`apply_status`").
     pub effect_type: String,
     /// Optional target of the effect.
     pub target_id: Option<Uuid>,
-    /// Campaign-specific payload.
+    /// The effect's fields, shaped by its type.
     pub payload: serde_json::Value,
 }
 
@@ -69,8 +126,71 @@ pub struct EffectSpecRequest {
 pub struct ProduceEffectsRequest {
     /// The resolution this effect production belongs to.
     pub resolution_id: Uuid,
-    /// The effects to produce.
+    /// Effects produced whatever the check's outcome.
+    #[serde(default)]
     pub effects: Vec<EffectSpecRequest>,
+    /// Effects keyed by the check's outcome, instead of `effects`.
+    #[serde(default)]
+    pub outcome_effects: Option<OutcomeTable<commands::EffectSpec>>,
+    /// Optional campaign whose front-matter custom effects the effects may
+    /// use.
+    #[serde(default)]
+    pub campaign_id: Option<Uuid>,
+}
+
+/// How many times a previewed check is rolled and which roll counts.
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OddsRollMode {
+    /// Roll once.
+    #[default]
+    Normal,
+    /// Roll twice and keep the better roll.
+    Advantage,
+    /// Roll twice and keep the worse roll.
+    Disadvantage,
+    /// Roll `rolls` times and keep the best roll.
+    KeepBest,
+    /// Roll `rolls` times and keep the worst roll.
+    KeepWorst,
+}
+
+/// Query parameters for GET /odds.
+#[derive(Debug, Deserialize)]
+pub struct OddsQuery {
+    /// The difficulty class to beat.
+    pub difficulty_class: i32,
+    /// The modifier applied to the roll; 0 when absent.
+    #[serde(default)]
+    pub modifier: i32,
+    /// The ruleset the check is rolled under; d20 when absent.
+    #[serde(default)]
+    pub ruleset: RulesetId,
+    /// How the check is rolled; once when absent.
+    #[serde(default)]
+    pub roll_mode: OddsRollMode,
+    /// How many times `keep_best` and `keep_worst` roll.
+    #[serde(default)]
+    pub rolls: Option<u32>,
+}
+
+impl OddsQuery {
+    fn roll_mode(&self) -> Result<RollMode, ApiError> {
+        let rolls = || {
+            self.rolls.ok_or_else(|| {
+                ApiError(DomainError::Validation(
+                    "rolls is required for keep_best and keep_worst".to_owned(),
+                ))
+            })
+        };
+        Ok(match self.roll_mode {
+            OddsRollMode::Normal => RollMode::Normal,
+            OddsRollMode::Advantage => RollMode::Advantage,
+            OddsRollMode::Disadvantage => RollMode::Disadvantage,
+            OddsRollMode::KeepBest => RollMode::KeepBest(rolls()?),
+            OddsRollMode::KeepWorst => RollMode::KeepWorst(rolls()?),
+        })
+    }
 }
 
 /// Response body returned after a command is successfully handled.
@@ -87,6 +207,12 @@ async fn declare_intent(
     headers: HeaderMap,
     Json(request): Json<DeclareIntentRequest>,
 ) -> Result<Json<CommandResponse>, ApiError> {
+    let modifier =
+        actor::supplied_modifier(request.character_id, request.inventory_id, request.modifier)?;
+    let scope = RunScope::from_headers(&state, &headers).await?;
+    let actor =
+        actor::load_actor_sheet(request.character_id, request.inventory_id, scope.repo()).await?;
+
     let command = commands::DeclareIntent {
         correlation_id: extract_correlation_id(&headers),
         resolution_id: request.resolution_id,
@@ -95,7 +221,11 @@ async fn declare_intent(
         skill: request.skill,
         target_id: request.target_id,
         difficulty_class: request.difficulty_class,
-        modifier: request.modifier,
+        modifier,
+        actor,
+        roll_expression: request.roll_expression,
+        roll_mode: request.roll_mode,
+        ruleset: request.ruleset,
     };
 
     info!(correlation_id = %command.correlation_id, "handling declare_intent command");
@@ -103,10 +233,48 @@ async fn declare_intent(
     let stored_events = command_handlers::handle_declare_intent(
         &command,
         state.clock.as_ref(),
-        &state.rng,
-        &*state.event_repository,
+        scope.rng(),
+        scope.repo(),
+    )
+    .await?;
+    scope.commit(&command, state.clock.as_ref()).await?;
+
+    let event_ids = stored_events.iter().map(|e| e.event_id).collect();
+
+    Ok(Json(CommandResponse { event_ids }))
+}
+
+/// POST /declare-opposed-intent
+#[instrument(skip(state, headers, request), fields(resolution_id = %request.resolution_id))]
+async fn declare_opposed_intent(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Json(request): Json<DeclareOpposedIntentRequest>,
+) -> Result<Json<CommandResponse>, ApiError> {
+    let command = commands::DeclareOpposedIntent {
+        correlation_id: extract_correlation_id(&headers),
+        resolution_id: request.resolution_id,
+        intent_id: request.intent_id,
+        action_type: request.action_type,
+        skill: request.skill,
+        actor: request.actor,
+        opponent: request.opponent,
+        roll_expression: request.roll_expression,
+        ruleset: request.ruleset,
+        tie_break: request.tie_break,
+    };
+
+    info!(correlation_id = %command.correlation_id, "handling declare_opposed_intent command");
+
+    let scope = RunScope::from_headers(&state, &headers).await?;
+    let stored_events = command_handlers::handle_declare_opposed_intent(
+        &command,
+        state.clock.as_ref(),
+        scope.rng(),
+        scope.repo(),
     )
     .await?;
+    scope.commit(&command, state.clock.as_ref()).await?;
 
     let event_ids = stored_events.iter().map(|e| e.event_id).collect();
 
@@ -127,13 +295,15 @@ async fn resolve_check(
 
     info!(correlation_id = %command.correlation_id, "handling resolve_check command");
 
+    let scope = RunScope::from_headers(&state, &headers).await?;
     let stored_events = command_handlers::handle_resolve_check(
         &command,
         state.clock.as_ref(),
-        &state.rng,
-        &*state.event_repository,
+        scope.rng(),
+        scope.repo(),
     )
     .await?;
+    scope.commit(&command, state.clock.as_ref()).await?;
 
     let event_ids = stored_events.iter().map(|e| e.event_id).collect();
 
@@ -157,10 +327,14 @@ async fn produce_effects(
         })
         .collect();
 
+    let scope = RunScope::from_headers(&state, &headers).await?;
+    let rules = play::load_campaign_rules(request.campaign_id, scope.repo()).await?;
     let command = commands::ProduceEffects {
         correlation_id: extract_correlation_id(&headers),
         resolution_id: request.resolution_id,
         effects,
+        outcome_effects: request.outcome_effects,
+        custom_effects: rules.custom_effects,
     };
 
     info!(correlation_id = %command.correlation_id, "handling produce_effects command");
@@ -168,10 +342,11 @@ async fn produce_effects(
     let stored_events = command_handlers::handle_produce_effects(
         &command,
         state.clock.as_ref(),
-        &state.rng,
-        &*state.event_repository,
+        scope.rng(),
+        scope.repo(),
     )
     .await?;
+    scope.commit(&command, state.clock.as_ref()).await?;
 
     let event_ids = stored_events.iter().map(|e| e.event_id).collect();
 
@@ -183,7 +358,8 @@ async fn produce_effects(
 async fn list_resolutions(
     State(state): State<AppState>,
 ) -> Result<Json<Vec<ResolutionSummary>>, ApiError> {
-    let summaries = query_handlers::list_resolutions(&*state.event_repository).await?;
+    let summaries =
+        query_handlers::list_resolutions(&*state.event_repository, &*state.read_models).await?;
     Ok(Json(summaries))
 }
 
@@ -193,14 +369,31 @@ async fn get_resolution(
     State(state): State<AppState>,
     Path(id): Path<Uuid>,
 ) -> Result<Json<ResolutionView>, ApiError> {
-    let view = query_handlers::get_resolution_by_id(id, &*state.event_repository).await?;
+    let view =
+        query_handlers::get_resolution_by_id(id, &*state.event_repository, &*state.read_models)
+            .await?;
+    Ok(Json(view))
+}
+
+/// GET /odds
+///
+/// Previews the chance of each outcome of a check without rolling it.
+#[instrument]
+async fn get_odds(Query(query): Query<OddsQuery>) -> Result<Json<CheckOddsView>, ApiError> {
+    let view = query_handlers::get_check_odds(
+        query.ruleset,
+        query.difficulty_class,
+        query.modifier,
+        query.roll_mode()?,
+    )?;
     Ok(Json(view))
 }
 
 /// DELETE /{`resolution_id`}
-#[instrument(skip(state), fields(resolution_id = %id))]
+#[instrument(skip(state, headers), fields(resolution_id = %id))]
 async fn archive_resolution(
     State(state): State<AppState>,
+    headers: HeaderMap,
     Path(id): Path<Uuid>,
 ) -> Result<Json<CommandResponse>, ApiError> {
     let command = commands::ArchiveResolution {
@@ -210,13 +403,15 @@ async fn archive_resolution(
 
     info!(correlation_id = %command.correlation_id, "handling archive_resolution command");
 
+    let scope = RunScope::from_headers(&state, &headers).await?;
     let stored_events = command_handlers::handle_archive_resolution(
         &command,
         state.clock.as_ref(),
-        &state.rng,
-        &*state.event_repository,
+        scope.rng(),
+        scope.repo(),
     )
     .await?;
+    scope.commit(&command, state.clock.as_ref()).await?;
 
     let event_ids = stored_events.iter().map(|e| e.event_id).collect();
 
@@ -227,11 +422,13 @@ async fn archive_resolution(
 pub fn router() -> Router<AppState> {
     Router::new()
         .route("/", get(list_resolutions))
+        .route("/odds", get(get_odds))
         .route(
             "/{resolution_id}",
             get(get_resolution).delete(archive_resolution),
         )
         .route("/declare-intent", post(declare_intent))
+        .route("/declare-opposed-intent", post(declare_opposed_intent))
         .route("/resolve-check", post(resolve_check))
         .route("/produce-effects", post(produce_effects))
 }
@@ -240,6 +437,8 @@ pub fn router() -> Router<AppState> {
 mod tests {
     use super::*;
 
+    use std::collections::BTreeMap;
+
     use axum::body::Body;
     use axum::http::{Request, StatusCode};
     use chrono::{TimeZone, Utc};
@@ -247,11 +446,12 @@ mod tests {
     use otherworlds_core::repository::EventRepository;
     use otherworlds_core::repository::StoredEvent;
     use otherworlds_core::rng::DeterministicRng;
+    use otherworlds_rules::domain::dice::{DiceExpression, DieRoll};
     use otherworlds_rules::domain::events::{
-        CheckOutcome, CheckResolved, IntentDeclared, RulesEventKind,
+        CheckOutcome, CheckResolved, CheckRoll, IntentDeclared, RulesEventKind,
     };
     use otherworlds_test_support::{
-        EmptyEventRepository, FailingEventRepository, FixedClock, MockRng,
+        EmptyEventRepository, FailingEventRepository, FixedClock, InMemoryReadModelStore, MockRng,
         RecordingEventRepository, SequenceRng,
     };
     use serde_json::Value;
@@ -265,7 +465,13 @@ mod tests {
         let pool = PgPool::connect_lazy(TEST_DATABASE_URL).unwrap();
         let clock: Arc<dyn Clock + Send + Sync> = Arc::new(FixedClock(Utc::now()));
         let rng: Arc<Mutex<dyn DeterministicRng + Send>> = Arc::new(Mutex::new(MockRng));
-        AppState::new(pool, clock, rng, event_repository)
+        AppState::new(
+            Some(pool),
+            clock,
+            rng,
+            event_repository,
+            Arc::new(InMemoryReadModelStore::new()),
+        )
     }
 
     fn app_state_with_rng(
@@ -274,7 +480,13 @@ mod tests {
     ) -> AppState {
         let pool = PgPool::connect_lazy(TEST_DATABASE_URL).unwrap();
         let clock: Arc<dyn Clock + Send + Sync> = Arc::new(FixedClock(Utc::now()));
-        AppState::new(pool, clock, rng, event_repository)
+        AppState::new(
+            Some(pool),
+            clock,
+            rng,
+            event_repository,
+            Arc::new(InMemoryReadModelStore::new()),
+        )
     }
 
     fn test_app_state() -> AppState {
@@ -302,12 +514,18 @@ mod tests {
                 target_id: None,
                 difficulty_class: 15,
                 modifier: 3,
+                roll_expression: DiceExpression::d20(),
+                stats: BTreeMap::new(),
+                roll_mode: RollMode::Normal,
+                ruleset: RulesetId::D20,
+                modifier_breakdown: None,
             }))
             .unwrap(),
             sequence_number: 1,
             correlation_id: Uuid::new_v4(),
             causation_id: Uuid::new_v4(),
             occurred_at: fixed_now(),
+            schema_version: 4,
         }
     }
 
@@ -324,12 +542,23 @@ mod tests {
                 total: 18,
                 difficulty_class: 15,
                 outcome: CheckOutcome::Success,
+                rolls: vec![CheckRoll {
+                    natural_roll: 15,
+                    dice: vec![DieRoll {
+                        sides: 20,
+                        value: 15,
+                        kept: true,
+                        exploded: false,
+                    }],
+                }],
+                kept_roll: 0,
             }))
             .unwrap(),
             sequence_number: 2,
             correlation_id: Uuid::new_v4(),
             causation_id: Uuid::new_v4(),
             occurred_at: fixed_now(),
+            schema_version: 3,
         }
     }
 
@@ -382,6 +611,31 @@ mod tests {
         assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
     }
 
+    #[tokio::test]
+    async fn test_declare_intent_returns_422_for_client_stats() {
+        let app = router().with_state(test_app_state());
+        let body = serde_json::json!({
+            "resolution_id": Uuid::new_v4(),
+            "intent_id": Uuid::new_v4(),
+            "action_type": "skill_check",
+            "skill": "stealth",
+            "difficulty_class": 15,
+            "character_id": Uuid::new_v4(),
+            "roll_expression": "1d20+@dexterity",
+            "stats": { "dexterity": 50 }
+        });
+
+        let request = Request::builder()
+            .method("POST")
+            .uri("/declare-intent")
+            .header("content-type", "application/json")
+            .body(Body::from(serde_json::to_vec(&body).unwrap()))
+            .unwrap();
+
+        let response = app.oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
     #[tokio::test]
     async fn test_declare_intent_returns_500_when_repository_fails() {
         let app = router().with_state(failing_app_state());
@@ -443,6 +697,84 @@ mod tests {
         assert_eq!(json["error"], "validation_error");
     }
 
+    // --- POST /declare-opposed-intent tests ---
+
+    #[tokio::test]
+    async fn test_declare_opposed_intent_returns_200_with_event_ids() {
+        let app = router().with_state(test_app_state());
+        let body = serde_json::json!({
+            "resolution_id": Uuid::new_v4(),
+            "intent_id": Uuid::new_v4(),
+            "action_type": "grapple",
+            "skill": "athletics",
+            "actor": { "modifier": 4 },
+            "opponent": { "modifier": 2, "roll_mode": "advantage" },
+            "tie_break": "initiator"
+        });
+
+        let request = Request::builder()
+            .method("POST")
+            .uri("/declare-opposed-intent")
+            .header("content-type", "application/json")
+            .body(Body::from(serde_json::to_vec(&body).unwrap()))
+            .unwrap();
+
+        let response = app.oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body_bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let json: Value = serde_json::from_slice(&body_bytes).unwrap();
+        assert_eq!(json["event_ids"].as_array().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_declare_opposed_intent_returns_400_for_stat_reference() {
+        let app = router().with_state(test_app_state());
+        let body = serde_json::json!({
+            "resolution_id": Uuid::new_v4(),
+            "intent_id": Uuid::new_v4(),
+            "action_type": "grapple",
+            "actor": { "modifier": 0 },
+            "opponent": { "modifier": 0 },
+            "roll_expression": "1d20+@strength"
+        });
+
+        let request = Request::builder()
+            .method("POST")
+            .uri("/declare-opposed-intent")
+            .header("content-type", "application/json")
+            .body(Body::from(serde_json::to_vec(&body).unwrap()))
+            .unwrap();
+
+        let response = app.oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_declare_opposed_intent_returns_422_for_unknown_tie_break() {
+        let app = router().with_state(test_app_state());
+        let body = serde_json::json!({
+            "resolution_id": Uuid::new_v4(),
+            "intent_id": Uuid::new_v4(),
+            "action_type": "grapple",
+            "actor": { "modifier": 0 },
+            "opponent": { "modifier": 0 },
+            "tie_break": "coin_flip"
+        });
+
+        let request = Request::builder()
+            .method("POST")
+            .uri("/declare-opposed-intent")
+            .header("content-type", "application/json")
+            .body(Body::from(serde_json::to_vec(&body).unwrap()))
+            .unwrap();
+
+        let response = app.oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
     // --- POST /resolve-check tests ---
 
     #[tokio::test]
@@ -551,6 +883,7 @@ mod tests {
             "resolution_id": resolution_id,
             "effects": [{
                 "effect_type": "damage",
+                "target_id": Uuid::new_v4(),
                 "payload": { "amount": 8 }
             }]
         });
@@ -638,6 +971,54 @@ mod tests {
         assert_eq!(json["version"], 1);
     }
 
+    // --- GET /odds tests ---
+
+    #[tokio::test]
+    async fn test_get_odds_returns_probability_of_each_outcome() {
+        let app = router().with_state(test_app_state());
+
+        let request = Request::builder()
+            .method("GET")
+            .uri("/odds?difficulty_class=15&modifier=3&roll_mode=keep_best&rolls=2")
+            .body(Body::empty())
+            .unwrap();
+
+        let response = app.oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body_bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let json: Value = serde_json::from_slice(&body_bytes).unwrap();
+
+        assert_eq!(json["ruleset"], "d20");
+        assert_eq!(json["roll_mode"], serde_json::json!({ "keep_best": 2 }));
+        let outcomes = json["outcomes"].as_array().unwrap();
+        assert_eq!(outcomes.len(), 5);
+        assert_eq!(outcomes[4]["outcome"], "critical_success");
+        assert_eq!(outcomes[4]["probability"], 39.0 / 400.0);
+    }
+
+    #[tokio::test]
+    async fn test_get_odds_returns_400_when_keep_best_has_no_roll_count() {
+        let app = router().with_state(test_app_state());
+
+        let request = Request::builder()
+            .method("GET")
+            .uri("/odds?difficulty_class=15&roll_mode=keep_best")
+            .body(Body::empty())
+            .unwrap();
+
+        let response = app.oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+        let body_bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let json: Value = serde_json::from_slice(&body_bytes).unwrap();
+        assert_eq!(json["error"], "validation_error");
+    }
+
     #[tokio::test]
     async fn test_get_resolution_returns_404_when_not_found() {
         let app = router().with_state(test_app_state());
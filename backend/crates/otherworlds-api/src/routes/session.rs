@@ -9,11 +9,13 @@ use serde::{Deserialize, Serialize};
 use tracing::{info, instrument};
 use uuid::Uuid;
 
+use otherworlds_core::error::DomainError;
 use otherworlds_session::application::query_handlers::{CampaignRunSummary, CampaignRunView};
 use otherworlds_session::application::{command_handlers, query_handlers};
 use otherworlds_session::domain::commands;
 
 use crate::error::ApiError;
+use crate::run_scope::RunScope;
 use crate::state::AppState;
 
 /// Request body for POST /start-campaign-run.
@@ -21,6 +23,8 @@ use crate::state::AppState;
 pub struct StartCampaignRunRequest {
     /// The campaign to start a run for.
     pub campaign_id: Uuid,
+    /// Seed for the run's RNG. A random seed is chosen when omitted.
+    pub seed: Option<u64>,
 }
 
 /// Request body for POST /create-checkpoint.
@@ -54,9 +58,18 @@ async fn start_campaign_run(
     State(state): State<AppState>,
     Json(request): Json<StartCampaignRunRequest>,
 ) -> Result<Json<CommandResponse>, ApiError> {
+    let seed = match request.seed {
+        Some(seed) => seed,
+        None => state
+            .rng
+            .lock()
+            .map_err(|e| DomainError::Infrastructure(format!("RNG mutex poisoned: {e}")))?
+            .next_u64(),
+    };
     let command = commands::StartCampaignRun {
         correlation_id: Uuid::new_v4(),
         campaign_id: request.campaign_id,
+        seed,
     };
 
     info!(correlation_id = %command.correlation_id, "handling start_campaign_run command");
@@ -90,13 +103,15 @@ async fn create_checkpoint(
 
     info!(correlation_id = %command.correlation_id, "handling create_checkpoint command");
 
+    let scope = RunScope::open(&state, Some(command.run_id)).await?;
     let result = command_handlers::handle_create_checkpoint(
         &command,
         state.clock.as_ref(),
-        &*state.rng,
-        &*state.event_repository,
+        scope.rng(),
+        scope.repo(),
     )
     .await?;
+    scope.commit(&command, state.clock.as_ref()).await?;
 
     let event_ids = result.stored_events.iter().map(|e| e.event_id).collect();
 
@@ -112,19 +127,26 @@ async fn branch_timeline(
     State(state): State<AppState>,
     Json(request): Json<BranchTimelineRequest>,
 ) -> Result<Json<CommandResponse>, ApiError> {
-    let correlation_id = Uuid::new_v4();
+    let command = commands::BranchTimeline {
+        correlation_id: Uuid::new_v4(),
+        source_run_id: request.source_run_id,
+        from_checkpoint_id: request.from_checkpoint_id,
+    };
 
-    info!(correlation_id = %correlation_id, "handling branch_timeline command with cross-context orchestration");
+    info!(correlation_id = %command.correlation_id, "handling branch_timeline command with cross-context orchestration");
 
+    // The branch draws its IDs and its own seed from the source run's RNG.
+    let scope = RunScope::open(&state, Some(command.source_run_id)).await?;
     let result = crate::orchestration::branch::orchestrate_branch_timeline(
-        request.source_run_id,
-        request.from_checkpoint_id,
-        correlation_id,
+        command.source_run_id,
+        command.from_checkpoint_id,
+        command.correlation_id,
         state.clock.as_ref(),
-        &*state.rng,
-        &*state.event_repository,
+        scope.rng(),
+        scope.repo(),
     )
     .await?;
+    scope.commit(&command, state.clock.as_ref()).await?;
 
     Ok(Json(CommandResponse {
         aggregate_id: result.branch_run_id,
@@ -137,7 +159,8 @@ async fn branch_timeline(
 async fn list_campaign_runs(
     State(state): State<AppState>,
 ) -> Result<Json<Vec<CampaignRunSummary>>, ApiError> {
-    let summaries = query_handlers::list_campaign_runs(&*state.event_repository).await?;
+    let summaries =
+        query_handlers::list_campaign_runs(&*state.event_repository, &*state.read_models).await?;
     Ok(Json(summaries))
 }
 
@@ -147,7 +170,9 @@ async fn get_campaign_run(
     State(state): State<AppState>,
     Path(id): Path<Uuid>,
 ) -> Result<Json<CampaignRunView>, ApiError> {
-    let view = query_handlers::get_campaign_run_by_id(id, &*state.event_repository).await?;
+    let view =
+        query_handlers::get_campaign_run_by_id(id, &*state.event_repository, &*state.read_models)
+            .await?;
     Ok(Json(view))
 }
 
@@ -164,13 +189,15 @@ async fn archive_campaign_run(
 
     info!(correlation_id = %command.correlation_id, "handling archive_campaign_run command");
 
+    let scope = RunScope::open(&state, Some(command.run_id)).await?;
     let result = command_handlers::handle_archive_campaign_run(
         &command,
         state.clock.as_ref(),
-        &*state.rng,
-        &*state.event_repository,
+        scope.rng(),
+        scope.repo(),
     )
     .await?;
+    scope.commit(&command, state.clock.as_ref()).await?;
 
     let event_ids = result.stored_events.iter().map(|e| e.event_id).collect();
 
@@ -208,7 +235,8 @@ mod tests {
         CampaignRunStarted, CheckpointCreated, SessionEventKind,
     };
     use otherworlds_test_support::{
-        EmptyEventRepository, FailingEventRepository, FixedClock, MockRng, RecordingEventRepository,
+        EmptyEventRepository, FailingEventRepository, FixedClock, InMemoryReadModelStore, MockRng,
+        RecordingEventRepository,
     };
     use serde_json::Value;
     use sqlx::PgPool;
@@ -236,6 +264,7 @@ mod tests {
                         CampaignRunStarted {
                             run_id: aggregate_id,
                             campaign_id: Uuid::new_v4(),
+                            seed: 42,
                         },
                     ))
                     .unwrap(),
@@ -243,6 +272,7 @@ mod tests {
                     correlation_id: Uuid::new_v4(),
                     causation_id: Uuid::new_v4(),
                     occurred_at: fixed_now,
+                    schema_version: 2,
                 },
                 StoredEvent {
                     event_id: Uuid::new_v4(),
@@ -259,6 +289,7 @@ mod tests {
                     correlation_id: Uuid::new_v4(),
                     causation_id: Uuid::new_v4(),
                     occurred_at: fixed_now,
+                    schema_version: 1,
                 },
             ])
         }
@@ -286,7 +317,13 @@ mod tests {
             Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap(),
         ));
         let rng: Arc<Mutex<dyn DeterministicRng + Send>> = Arc::new(Mutex::new(MockRng));
-        AppState::new(pool, clock, rng, event_repository)
+        AppState::new(
+            Some(pool),
+            clock,
+            rng,
+            event_repository,
+            Arc::new(InMemoryReadModelStore::new()),
+        )
     }
 
     fn test_app_state() -> AppState {
@@ -532,6 +569,7 @@ mod tests {
                 CampaignRunStarted {
                     run_id,
                     campaign_id,
+                    seed: 42,
                 },
             ))
             .unwrap(),
@@ -539,6 +577,7 @@ mod tests {
             correlation_id: Uuid::new_v4(),
             causation_id: Uuid::new_v4(),
             occurred_at: fixed_now,
+            schema_version: 2,
         }];
         let repo = RecordingEventRepository::new(Ok(events));
         let app = router().with_state(app_state_with(Arc::new(repo)));
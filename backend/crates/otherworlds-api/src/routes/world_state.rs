@@ -1,21 +1,30 @@
 //! Routes for the World State bounded context.
 
+use std::collections::BTreeMap;
+
 use axum::extract::{Path, State};
+use axum::http::HeaderMap;
 use axum::{
     Json, Router,
     routing::{get, post},
 };
+use otherworlds_core::error::DomainError;
 use serde::{Deserialize, Serialize};
 use tracing::{info, instrument};
 use uuid::Uuid;
 
 use otherworlds_world_state::application::query_handlers::{
-    WorldSnapshotSummary, WorldSnapshotView,
+    WorldLocationsView, WorldSnapshotSummary, WorldSnapshotView,
 };
 use otherworlds_world_state::application::{command_handlers, query_handlers};
+use otherworlds_world_state::domain::calendar::{Calendar, GameTime};
 use otherworlds_world_state::domain::commands;
+use otherworlds_world_state::domain::condition::Condition;
+use otherworlds_world_state::domain::events::{FactValue, ScheduledEffect};
 
 use crate::error::ApiError;
+use crate::orchestration::world_seed;
+use crate::run_scope::RunScope;
 use crate::state::AppState;
 
 /// Request body for POST /apply-effect.
@@ -27,6 +36,26 @@ pub struct ApplyEffectRequest {
     pub fact_key: String,
 }
 
+/// Request body for POST /assert-fact.
+#[derive(Debug, Deserialize)]
+pub struct AssertFactRequest {
+    /// The world snapshot identifier.
+    pub world_id: Uuid,
+    /// The fact key.
+    pub key: String,
+    /// The fact's value.
+    pub value: FactValue,
+}
+
+/// Request body for POST /retract-fact.
+#[derive(Debug, Deserialize)]
+pub struct RetractFactRequest {
+    /// The world snapshot identifier.
+    pub world_id: Uuid,
+    /// The fact key.
+    pub key: String,
+}
+
 /// Request body for POST /set-flag.
 #[derive(Debug, Deserialize)]
 pub struct SetFlagRequest {
@@ -45,6 +74,91 @@ pub struct UpdateDispositionRequest {
     pub world_id: Uuid,
     /// The entity whose disposition to update.
     pub entity_id: Uuid,
+    /// The amount to shift the score by; negative towards hostility.
+    #[serde(default)]
+    pub delta: i32,
+}
+
+/// Request body for POST /join-faction.
+#[derive(Debug, Deserialize)]
+pub struct JoinFactionRequest {
+    /// The world snapshot identifier.
+    pub world_id: Uuid,
+    /// The entity joining the faction.
+    pub entity_id: Uuid,
+    /// The faction to join.
+    pub faction_id: Uuid,
+}
+
+/// Request body for POST /set-connection-lock.
+#[derive(Debug, Deserialize)]
+pub struct SetConnectionLockRequest {
+    /// The world snapshot identifier.
+    pub world_id: Uuid,
+    /// One end of the connection.
+    pub location_id: Uuid,
+    /// The other end of the connection.
+    pub other_location_id: Uuid,
+    /// Whether the connection should be locked.
+    pub locked: bool,
+}
+
+/// Request body for POST /move-party.
+#[derive(Debug, Deserialize)]
+pub struct MovePartyRequest {
+    /// The world snapshot identifier.
+    pub world_id: Uuid,
+    /// The location to move to.
+    pub location_id: Uuid,
+}
+
+/// Request body for POST /move-npc.
+#[derive(Debug, Deserialize)]
+pub struct MoveNpcRequest {
+    /// The world snapshot identifier.
+    pub world_id: Uuid,
+    /// The NPC to move.
+    pub entity_id: Uuid,
+    /// The location to move to.
+    pub location_id: Uuid,
+}
+
+/// Request body for POST /set-calendar.
+#[derive(Debug, Deserialize)]
+pub struct SetCalendarRequest {
+    /// The world snapshot identifier.
+    pub world_id: Uuid,
+    /// The new calendar.
+    pub calendar: Calendar,
+}
+
+/// Request body for POST /schedule-event.
+#[derive(Debug, Deserialize)]
+pub struct ScheduleEventRequest {
+    /// The world snapshot identifier.
+    pub world_id: Uuid,
+    /// When the event is due.
+    pub at: GameTime,
+    /// What happens when it fires.
+    pub effect: ScheduledEffect,
+}
+
+/// Request body for POST /advance-time.
+#[derive(Debug, Deserialize)]
+pub struct AdvanceTimeRequest {
+    /// The world snapshot identifier.
+    pub world_id: Uuid,
+    /// The number of hours to advance by.
+    pub hours: u64,
+}
+
+/// Request body for POST /seed-from-campaign.
+#[derive(Debug, Deserialize)]
+pub struct SeedFromCampaignRequest {
+    /// The world snapshot identifier.
+    pub world_id: Uuid,
+    /// The compiled campaign whose NPCs and locations to seed.
+    pub campaign_id: Uuid,
 }
 
 /// Response body returned after a command is successfully handled.
@@ -54,27 +168,107 @@ pub struct CommandResponse {
     pub event_ids: Vec<Uuid>,
 }
 
+/// Response body for POST /seed-from-campaign.
+#[derive(Debug, Serialize)]
+pub struct SeedFromCampaignResponse {
+    /// IDs of the domain events produced and persisted.
+    pub event_ids: Vec<Uuid>,
+    /// World entity IDs of the campaign's NPCs, by NPC ID.
+    pub npc_entity_ids: BTreeMap<String, Uuid>,
+    /// World entity IDs of the campaign's factions, by faction name.
+    pub faction_entity_ids: BTreeMap<String, Uuid>,
+    /// World location IDs of the campaign's locations, by location ID.
+    pub location_entity_ids: BTreeMap<String, Uuid>,
+}
+
 /// POST /apply-effect
-#[instrument(skip(state, request), fields(world_id = %request.world_id))]
+#[instrument(skip(state, headers, request), fields(world_id = %request.world_id))]
 async fn apply_effect(
     State(state): State<AppState>,
+    headers: HeaderMap,
     Json(request): Json<ApplyEffectRequest>,
 ) -> Result<Json<CommandResponse>, ApiError> {
     let command = commands::ApplyEffect {
         correlation_id: Uuid::new_v4(),
+        causation_id: None,
         world_id: request.world_id,
         fact_key: request.fact_key,
     };
 
     info!(correlation_id = %command.correlation_id, "handling apply_effect command");
 
+    let scope = RunScope::from_headers(&state, &headers).await?;
     let stored_events = command_handlers::handle_apply_effect(
         &command,
         state.clock.as_ref(),
-        &state.rng,
-        &*state.event_repository,
+        scope.rng(),
+        scope.repo(),
+    )
+    .await?;
+    scope.commit(&command, state.clock.as_ref()).await?;
+
+    let event_ids = stored_events.iter().map(|e| e.event_id).collect();
+
+    Ok(Json(CommandResponse { event_ids }))
+}
+
+/// POST /assert-fact
+#[instrument(skip(state, headers, request), fields(world_id = %request.world_id))]
+async fn assert_fact(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Json(request): Json<AssertFactRequest>,
+) -> Result<Json<CommandResponse>, ApiError> {
+    let command = commands::AssertFact {
+        correlation_id: Uuid::new_v4(),
+        causation_id: None,
+        world_id: request.world_id,
+        key: request.key,
+        value: request.value,
+    };
+
+    info!(correlation_id = %command.correlation_id, "handling assert_fact command");
+
+    let scope = RunScope::from_headers(&state, &headers).await?;
+    let stored_events = command_handlers::handle_assert_fact(
+        &command,
+        state.clock.as_ref(),
+        scope.rng(),
+        scope.repo(),
+    )
+    .await?;
+    scope.commit(&command, state.clock.as_ref()).await?;
+
+    let event_ids = stored_events.iter().map(|e| e.event_id).collect();
+
+    Ok(Json(CommandResponse { event_ids }))
+}
+
+/// POST /retract-fact
+#[instrument(skip(state, headers, request), fields(world_id = %request.world_id))]
+async fn retract_fact(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Json(request): Json<RetractFactRequest>,
+) -> Result<Json<CommandResponse>, ApiError> {
+    let command = commands::RetractFact {
+        correlation_id: Uuid::new_v4(),
+        causation_id: None,
+        world_id: request.world_id,
+        key: request.key,
+    };
+
+    info!(correlation_id = %command.correlation_id, "handling retract_fact command");
+
+    let scope = RunScope::from_headers(&state, &headers).await?;
+    let stored_events = command_handlers::handle_retract_fact(
+        &command,
+        state.clock.as_ref(),
+        scope.rng(),
+        scope.repo(),
     )
     .await?;
+    scope.commit(&command, state.clock.as_ref()).await?;
 
     let event_ids = stored_events.iter().map(|e| e.event_id).collect();
 
@@ -82,13 +276,15 @@ async fn apply_effect(
 }
 
 /// POST /set-flag
-#[instrument(skip(state, request), fields(world_id = %request.world_id))]
+#[instrument(skip(state, headers, request), fields(world_id = %request.world_id))]
 async fn set_flag(
     State(state): State<AppState>,
+    headers: HeaderMap,
     Json(request): Json<SetFlagRequest>,
 ) -> Result<Json<CommandResponse>, ApiError> {
     let command = commands::SetFlag {
         correlation_id: Uuid::new_v4(),
+        causation_id: None,
         world_id: request.world_id,
         flag_key: request.flag_key,
         value: request.value,
@@ -96,13 +292,15 @@ async fn set_flag(
 
     info!(correlation_id = %command.correlation_id, "handling set_flag command");
 
+    let scope = RunScope::from_headers(&state, &headers).await?;
     let stored_events = command_handlers::handle_set_flag(
         &command,
         state.clock.as_ref(),
-        &state.rng,
-        &*state.event_repository,
+        scope.rng(),
+        scope.repo(),
     )
     .await?;
+    scope.commit(&command, state.clock.as_ref()).await?;
 
     let event_ids = stored_events.iter().map(|e| e.event_id).collect();
 
@@ -110,38 +308,314 @@ async fn set_flag(
 }
 
 /// POST /update-disposition
-#[instrument(skip(state, request), fields(world_id = %request.world_id))]
+#[instrument(skip(state, headers, request), fields(world_id = %request.world_id))]
 async fn update_disposition(
     State(state): State<AppState>,
+    headers: HeaderMap,
     Json(request): Json<UpdateDispositionRequest>,
 ) -> Result<Json<CommandResponse>, ApiError> {
     let command = commands::UpdateDisposition {
         correlation_id: Uuid::new_v4(),
+        causation_id: None,
         world_id: request.world_id,
         entity_id: request.entity_id,
+        delta: request.delta,
     };
 
     info!(correlation_id = %command.correlation_id, "handling update_disposition command");
 
+    let scope = RunScope::from_headers(&state, &headers).await?;
     let stored_events = command_handlers::handle_update_disposition(
         &command,
         state.clock.as_ref(),
-        &state.rng,
-        &*state.event_repository,
+        scope.rng(),
+        scope.repo(),
+    )
+    .await?;
+    scope.commit(&command, state.clock.as_ref()).await?;
+
+    let event_ids = stored_events.iter().map(|e| e.event_id).collect();
+
+    Ok(Json(CommandResponse { event_ids }))
+}
+
+/// POST /join-faction
+#[instrument(skip(state, headers, request), fields(world_id = %request.world_id))]
+async fn join_faction(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Json(request): Json<JoinFactionRequest>,
+) -> Result<Json<CommandResponse>, ApiError> {
+    let command = commands::JoinFaction {
+        correlation_id: Uuid::new_v4(),
+        world_id: request.world_id,
+        entity_id: request.entity_id,
+        faction_id: request.faction_id,
+    };
+
+    info!(correlation_id = %command.correlation_id, "handling join_faction command");
+
+    let scope = RunScope::from_headers(&state, &headers).await?;
+    let stored_events = command_handlers::handle_join_faction(
+        &command,
+        state.clock.as_ref(),
+        scope.rng(),
+        scope.repo(),
     )
     .await?;
+    scope.commit(&command, state.clock.as_ref()).await?;
 
     let event_ids = stored_events.iter().map(|e| e.event_id).collect();
 
     Ok(Json(CommandResponse { event_ids }))
 }
 
+/// POST /set-connection-lock
+#[instrument(skip(state, headers, request), fields(world_id = %request.world_id))]
+async fn set_connection_lock(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Json(request): Json<SetConnectionLockRequest>,
+) -> Result<Json<CommandResponse>, ApiError> {
+    let command = commands::SetConnectionLock {
+        correlation_id: Uuid::new_v4(),
+        world_id: request.world_id,
+        location_id: request.location_id,
+        other_location_id: request.other_location_id,
+        locked: request.locked,
+    };
+
+    info!(correlation_id = %command.correlation_id, "handling set_connection_lock command");
+
+    let scope = RunScope::from_headers(&state, &headers).await?;
+    let stored_events = command_handlers::handle_set_connection_lock(
+        &command,
+        state.clock.as_ref(),
+        scope.rng(),
+        scope.repo(),
+    )
+    .await?;
+    scope.commit(&command, state.clock.as_ref()).await?;
+
+    let event_ids = stored_events.iter().map(|e| e.event_id).collect();
+
+    Ok(Json(CommandResponse { event_ids }))
+}
+
+/// POST /move-party
+#[instrument(skip(state, headers, request), fields(world_id = %request.world_id))]
+async fn move_party(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Json(request): Json<MovePartyRequest>,
+) -> Result<Json<CommandResponse>, ApiError> {
+    let command = commands::MoveParty {
+        correlation_id: Uuid::new_v4(),
+        world_id: request.world_id,
+        location_id: request.location_id,
+    };
+
+    info!(correlation_id = %command.correlation_id, "handling move_party command");
+
+    let scope = RunScope::from_headers(&state, &headers).await?;
+    let stored_events = command_handlers::handle_move_party(
+        &command,
+        state.clock.as_ref(),
+        scope.rng(),
+        scope.repo(),
+    )
+    .await?;
+    scope.commit(&command, state.clock.as_ref()).await?;
+
+    let event_ids = stored_events.iter().map(|e| e.event_id).collect();
+
+    Ok(Json(CommandResponse { event_ids }))
+}
+
+/// POST /move-npc
+#[instrument(skip(state, headers, request), fields(world_id = %request.world_id))]
+async fn move_npc(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Json(request): Json<MoveNpcRequest>,
+) -> Result<Json<CommandResponse>, ApiError> {
+    let command = commands::MoveNpc {
+        correlation_id: Uuid::new_v4(),
+        world_id: request.world_id,
+        entity_id: request.entity_id,
+        location_id: request.location_id,
+    };
+
+    info!(correlation_id = %command.correlation_id, "handling move_npc command");
+
+    let scope = RunScope::from_headers(&state, &headers).await?;
+    let stored_events = command_handlers::handle_move_npc(
+        &command,
+        state.clock.as_ref(),
+        scope.rng(),
+        scope.repo(),
+    )
+    .await?;
+    scope.commit(&command, state.clock.as_ref()).await?;
+
+    let event_ids = stored_events.iter().map(|e| e.event_id).collect();
+
+    Ok(Json(CommandResponse { event_ids }))
+}
+
+/// POST /set-calendar
+#[instrument(skip(state, headers, request), fields(world_id = %request.world_id))]
+async fn set_calendar(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Json(request): Json<SetCalendarRequest>,
+) -> Result<Json<CommandResponse>, ApiError> {
+    let command = commands::SetCalendar {
+        correlation_id: Uuid::new_v4(),
+        world_id: request.world_id,
+        calendar: request.calendar,
+    };
+
+    info!(correlation_id = %command.correlation_id, "handling set_calendar command");
+
+    let scope = RunScope::from_headers(&state, &headers).await?;
+    let stored_events = command_handlers::handle_set_calendar(
+        &command,
+        state.clock.as_ref(),
+        scope.rng(),
+        scope.repo(),
+    )
+    .await?;
+    scope.commit(&command, state.clock.as_ref()).await?;
+
+    let event_ids = stored_events.iter().map(|e| e.event_id).collect();
+
+    Ok(Json(CommandResponse { event_ids }))
+}
+
+/// POST /schedule-event
+#[instrument(skip(state, headers, request), fields(world_id = %request.world_id))]
+async fn schedule_event(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Json(request): Json<ScheduleEventRequest>,
+) -> Result<Json<CommandResponse>, ApiError> {
+    let command = commands::ScheduleEvent {
+        correlation_id: Uuid::new_v4(),
+        world_id: request.world_id,
+        at: request.at,
+        effect: request.effect,
+    };
+
+    info!(correlation_id = %command.correlation_id, "handling schedule_event command");
+
+    let scope = RunScope::from_headers(&state, &headers).await?;
+    let stored_events = command_handlers::handle_schedule_event(
+        &command,
+        state.clock.as_ref(),
+        scope.rng(),
+        scope.repo(),
+    )
+    .await?;
+    scope.commit(&command, state.clock.as_ref()).await?;
+
+    let event_ids = stored_events.iter().map(|e| e.event_id).collect();
+
+    Ok(Json(CommandResponse { event_ids }))
+}
+
+/// POST /advance-time
+#[instrument(skip(state, headers, request), fields(world_id = %request.world_id))]
+async fn advance_time(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Json(request): Json<AdvanceTimeRequest>,
+) -> Result<Json<CommandResponse>, ApiError> {
+    let command = commands::AdvanceTime {
+        correlation_id: Uuid::new_v4(),
+        world_id: request.world_id,
+        hours: request.hours,
+    };
+
+    info!(correlation_id = %command.correlation_id, "handling advance_time command");
+
+    let scope = RunScope::from_headers(&state, &headers).await?;
+    let stored_events = command_handlers::handle_advance_time(
+        &command,
+        state.clock.as_ref(),
+        scope.rng(),
+        scope.repo(),
+    )
+    .await?;
+    scope.commit(&command, state.clock.as_ref()).await?;
+
+    let event_ids = stored_events.iter().map(|e| e.event_id).collect();
+
+    Ok(Json(CommandResponse { event_ids }))
+}
+
+/// POST /seed-from-campaign
+///
+/// Seeds dispositions and locations as two commands, each committed on its
+/// own. Both are idempotent, so a request that fails between them can simply
+/// be retried.
+#[instrument(skip(state, headers, request), fields(world_id = %request.world_id, campaign_id = %request.campaign_id))]
+async fn seed_from_campaign(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Json(request): Json<SeedFromCampaignRequest>,
+) -> Result<Json<SeedFromCampaignResponse>, ApiError> {
+    let seed = world_seed::load_world_seed(
+        request.world_id,
+        request.campaign_id,
+        Uuid::new_v4(),
+        &*state.event_repository,
+    )
+    .await?;
+
+    info!(correlation_id = %seed.dispositions.correlation_id, "handling seed_dispositions command");
+
+    let scope = RunScope::from_headers(&state, &headers).await?;
+    let mut stored_events = command_handlers::handle_seed_dispositions(
+        &seed.dispositions,
+        state.clock.as_ref(),
+        scope.rng(),
+        scope.repo(),
+    )
+    .await?;
+    scope
+        .commit(&seed.dispositions, state.clock.as_ref())
+        .await?;
+
+    info!(correlation_id = %seed.locations.correlation_id, "handling seed_locations command");
+
+    let scope = RunScope::from_headers(&state, &headers).await?;
+    stored_events.extend(
+        command_handlers::handle_seed_locations(
+            &seed.locations,
+            state.clock.as_ref(),
+            scope.rng(),
+            scope.repo(),
+        )
+        .await?,
+    );
+    scope.commit(&seed.locations, state.clock.as_ref()).await?;
+
+    Ok(Json(SeedFromCampaignResponse {
+        event_ids: stored_events.iter().map(|e| e.event_id).collect(),
+        npc_entity_ids: seed.npc_entity_ids,
+        faction_entity_ids: seed.faction_entity_ids,
+        location_entity_ids: seed.location_entity_ids,
+    }))
+}
+
 /// GET /
 #[instrument(skip(state))]
 async fn list_world_snapshots(
     State(state): State<AppState>,
 ) -> Result<Json<Vec<WorldSnapshotSummary>>, ApiError> {
-    let summaries = query_handlers::list_world_snapshots(&*state.event_repository).await?;
+    let summaries =
+        query_handlers::list_world_snapshots(&*state.event_repository, &*state.read_models).await?;
     Ok(Json(summaries))
 }
 
@@ -151,14 +625,70 @@ async fn get_world_snapshot(
     State(state): State<AppState>,
     Path(id): Path<Uuid>,
 ) -> Result<Json<WorldSnapshotView>, ApiError> {
-    let view = query_handlers::get_world_snapshot_by_id(id, &*state.event_repository).await?;
+    let view =
+        query_handlers::get_world_snapshot_by_id(id, &*state.event_repository, &*state.read_models)
+            .await?;
     Ok(Json(view))
 }
 
-/// DELETE /{`world_id`}
+/// Request body for POST /{`world_id`}/evaluate.
+#[derive(Debug, Deserialize)]
+pub struct EvaluateConditionRequest {
+    /// The condition, in the condition language.
+    pub condition: String,
+    /// The campaign whose NPC IDs and faction names the condition may use as
+    /// entity names.
+    #[serde(default)]
+    pub campaign_id: Option<Uuid>,
+}
+
+/// Response body for POST /{`world_id`}/evaluate.
+#[derive(Debug, Serialize)]
+pub struct EvaluateConditionResponse {
+    /// Whether the condition holds.
+    pub result: bool,
+}
+
+/// POST /{`world_id`}/evaluate
+#[instrument(skip(state, request), fields(world_id = %id))]
+async fn evaluate_condition(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+    Json(request): Json<EvaluateConditionRequest>,
+) -> Result<Json<EvaluateConditionResponse>, ApiError> {
+    let condition = request
+        .condition
+        .parse::<Condition>()
+        .map_err(DomainError::from)?;
+    let entities = match request.campaign_id {
+        Some(campaign_id) => {
+            world_seed::load_campaign_entity_ids(campaign_id, &*state.event_repository).await?
+        }
+        None => BTreeMap::new(),
+    };
+    let result =
+        query_handlers::evaluate_condition(id, &condition, &entities, &*state.event_repository)
+            .await?;
+    Ok(Json(EvaluateConditionResponse { result }))
+}
+
+/// GET /{`world_id`}/locations
 #[instrument(skip(state), fields(world_id = %id))]
+async fn get_world_locations(
+    State(state): State<AppState>,
+    Path(id): Path<Uuid>,
+) -> Result<Json<WorldLocationsView>, ApiError> {
+    let view =
+        query_handlers::get_world_locations(id, &*state.event_repository, &*state.read_models)
+            .await?;
+    Ok(Json(view))
+}
+
+/// DELETE /{`world_id`}
+#[instrument(skip(state, headers), fields(world_id = %id))]
 async fn archive_world_snapshot(
     State(state): State<AppState>,
+    headers: HeaderMap,
     Path(id): Path<Uuid>,
 ) -> Result<Json<CommandResponse>, ApiError> {
     let command = commands::ArchiveWorldSnapshot {
@@ -168,13 +698,15 @@ async fn archive_world_snapshot(
 
     info!(correlation_id = %command.correlation_id, "handling archive_world_snapshot command");
 
+    let scope = RunScope::from_headers(&state, &headers).await?;
     let stored_events = command_handlers::handle_archive_world_snapshot(
         &command,
         state.clock.as_ref(),
-        &state.rng,
-        &*state.event_repository,
+        scope.rng(),
+        scope.repo(),
     )
     .await?;
+    scope.commit(&command, state.clock.as_ref()).await?;
 
     let event_ids = stored_events.iter().map(|e| e.event_id).collect();
 
@@ -189,9 +721,21 @@ pub fn router() -> Router<AppState> {
             "/{world_id}",
             get(get_world_snapshot).delete(archive_world_snapshot),
         )
+        .route("/{world_id}/locations", get(get_world_locations))
+        .route("/{world_id}/evaluate", post(evaluate_condition))
         .route("/apply-effect", post(apply_effect))
+        .route("/assert-fact", post(assert_fact))
+        .route("/retract-fact", post(retract_fact))
         .route("/set-flag", post(set_flag))
         .route("/update-disposition", post(update_disposition))
+        .route("/join-faction", post(join_faction))
+        .route("/set-connection-lock", post(set_connection_lock))
+        .route("/move-party", post(move_party))
+        .route("/move-npc", post(move_npc))
+        .route("/set-calendar", post(set_calendar))
+        .route("/schedule-event", post(schedule_event))
+        .route("/advance-time", post(advance_time))
+        .route("/seed-from-campaign", post(seed_from_campaign))
 }
 
 #[cfg(test)]
@@ -206,11 +750,10 @@ mod tests {
     use otherworlds_core::repository::StoredEvent;
     use otherworlds_core::rng::DeterministicRng;
     use otherworlds_test_support::{
-        EmptyEventRepository, FailingEventRepository, FixedClock, MockRng, RecordingEventRepository,
-    };
-    use otherworlds_world_state::domain::events::{
-        WorldFactChanged, WorldSnapshotArchived, WorldStateEventKind,
+        EmptyEventRepository, FailingEventRepository, FixedClock, InMemoryReadModelStore, MockRng,
+        RecordingEventRepository,
     };
+    use otherworlds_world_state::domain::events::{WorldSnapshotArchived, WorldStateEventKind};
     use serde_json::Value;
     use sqlx::PgPool;
     use std::sync::{Arc, Mutex};
@@ -220,7 +763,13 @@ mod tests {
         let pool = PgPool::connect_lazy("postgres://localhost/test").unwrap();
         let clock: Arc<dyn Clock + Send + Sync> = Arc::new(FixedClock(Utc::now()));
         let rng: Arc<Mutex<dyn DeterministicRng + Send>> = Arc::new(Mutex::new(MockRng));
-        AppState::new(pool, clock, rng, event_repository)
+        AppState::new(
+            Some(pool),
+            clock,
+            rng,
+            event_repository,
+            Arc::new(InMemoryReadModelStore::new()),
+        )
     }
 
     fn test_app_state() -> AppState {
@@ -266,6 +815,83 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_assert_fact_returns_200_with_event_ids() {
+        // Arrange
+        let app = router().with_state(test_app_state());
+        let body = serde_json::json!({
+            "world_id": Uuid::new_v4(),
+            "key": "ferry_fare",
+            "value": { "type": "number", "value": 3 }
+        });
+
+        let request = Request::builder()
+            .method("POST")
+            .uri("/assert-fact")
+            .header("content-type", "application/json")
+            .body(Body::from(serde_json::to_vec(&body).unwrap()))
+            .unwrap();
+
+        // Act
+        let response = app.oneshot(request).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body_bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let json: Value = serde_json::from_slice(&body_bytes).unwrap();
+        assert_eq!(json["event_ids"].as_array().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_assert_fact_returns_422_for_untyped_value() {
+        // Arrange
+        let app = router().with_state(test_app_state());
+        let body = serde_json::json!({
+            "world_id": Uuid::new_v4(),
+            "key": "ferry_fare",
+            "value": 3
+        });
+
+        let request = Request::builder()
+            .method("POST")
+            .uri("/assert-fact")
+            .header("content-type", "application/json")
+            .body(Body::from(serde_json::to_vec(&body).unwrap()))
+            .unwrap();
+
+        // Act
+        let response = app.oneshot(request).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    #[tokio::test]
+    async fn test_retract_fact_returns_404_when_world_not_found() {
+        // Arrange
+        let app = router().with_state(test_app_state());
+        let body = serde_json::json!({
+            "world_id": Uuid::new_v4(),
+            "key": "ferry_fare"
+        });
+
+        let request = Request::builder()
+            .method("POST")
+            .uri("/retract-fact")
+            .header("content-type", "application/json")
+            .body(Body::from(serde_json::to_vec(&body).unwrap()))
+            .unwrap();
+
+        // Act
+        let response = app.oneshot(request).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
     #[tokio::test]
     async fn test_set_flag_returns_200_with_event_ids() {
         // Arrange
@@ -310,7 +936,8 @@ mod tests {
         let entity_id = Uuid::new_v4();
         let body = serde_json::json!({
             "world_id": world_id,
-            "entity_id": entity_id
+            "entity_id": entity_id,
+            "delta": -10
         });
 
         let request = Request::builder()
@@ -338,6 +965,77 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_join_faction_returns_400_when_entity_joins_itself() {
+        // Arrange
+        let app = router().with_state(test_app_state());
+        let faction_id = Uuid::new_v4();
+        let body = serde_json::json!({
+            "world_id": Uuid::new_v4(),
+            "entity_id": faction_id,
+            "faction_id": faction_id
+        });
+
+        let request = Request::builder()
+            .method("POST")
+            .uri("/join-faction")
+            .header("content-type", "application/json")
+            .body(Body::from(serde_json::to_vec(&body).unwrap()))
+            .unwrap();
+
+        // Act
+        let response = app.oneshot(request).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn test_move_party_returns_404_when_world_not_found() {
+        // Arrange
+        let app = router().with_state(test_app_state());
+        let body = serde_json::json!({
+            "world_id": Uuid::new_v4(),
+            "location_id": Uuid::new_v4()
+        });
+
+        let request = Request::builder()
+            .method("POST")
+            .uri("/move-party")
+            .header("content-type", "application/json")
+            .body(Body::from(serde_json::to_vec(&body).unwrap()))
+            .unwrap();
+
+        // Act
+        let response = app.oneshot(request).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn test_seed_from_campaign_returns_404_when_campaign_not_found() {
+        // Arrange
+        let app = router().with_state(test_app_state());
+        let body = serde_json::json!({
+            "world_id": Uuid::new_v4(),
+            "campaign_id": Uuid::new_v4()
+        });
+
+        let request = Request::builder()
+            .method("POST")
+            .uri("/seed-from-campaign")
+            .header("content-type", "application/json")
+            .body(Body::from(serde_json::to_vec(&body).unwrap()))
+            .unwrap();
+
+        // Act
+        let response = app.oneshot(request).await.unwrap();
+
+        // Assert
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
     #[tokio::test]
     async fn test_apply_effect_returns_422_for_missing_body() {
         // Arrange
@@ -492,17 +1190,14 @@ mod tests {
             event_id: Uuid::new_v4(),
             aggregate_id: world_id,
             event_type: "world_state.world_fact_changed".to_owned(),
-            payload: serde_json::to_value(WorldStateEventKind::WorldFactChanged(
-                WorldFactChanged {
-                    world_id,
-                    fact_key: "quest_complete".to_owned(),
-                },
-            ))
-            .unwrap(),
+            payload: serde_json::json!({
+                "WorldFactChanged": { "world_id": world_id, "fact_key": "quest_complete" }
+            }),
             sequence_number: 1,
             correlation_id: Uuid::new_v4(),
             causation_id: Uuid::new_v4(),
             occurred_at: fixed_now,
+            schema_version: 1,
         }];
         let repo = RecordingEventRepository::new(Ok(events));
         let app = router().with_state(app_state_with(Arc::new(repo)));
@@ -525,7 +1220,10 @@ mod tests {
         let json: Value = serde_json::from_slice(&body_bytes).unwrap();
 
         assert_eq!(json["world_id"], world_id.to_string());
-        assert_eq!(json["facts"], serde_json::json!(["quest_complete"]));
+        assert_eq!(
+            json["facts"],
+            serde_json::json!({ "quest_complete": { "type": "bool", "value": true } })
+        );
         assert_eq!(json["version"], 1);
     }
 
@@ -662,17 +1360,14 @@ mod tests {
             event_id: Uuid::new_v4(),
             aggregate_id: world_id,
             event_type: "world_state.world_fact_changed".to_owned(),
-            payload: serde_json::to_value(WorldStateEventKind::WorldFactChanged(
-                WorldFactChanged {
-                    world_id,
-                    fact_key: "quest_complete".to_owned(),
-                },
-            ))
-            .unwrap(),
+            payload: serde_json::json!({
+                "WorldFactChanged": { "world_id": world_id, "fact_key": "quest_complete" }
+            }),
             sequence_number: 1,
             correlation_id: Uuid::new_v4(),
             causation_id: Uuid::new_v4(),
             occurred_at: fixed_now,
+            schema_version: 1,
         }];
         let repo = RecordingEventRepository::new(Ok(events));
         let app = router().with_state(app_state_with(Arc::new(repo)));
@@ -737,17 +1432,14 @@ mod tests {
                 event_id: Uuid::new_v4(),
                 aggregate_id: world_id,
                 event_type: "world_state.world_fact_changed".to_owned(),
-                payload: serde_json::to_value(WorldStateEventKind::WorldFactChanged(
-                    WorldFactChanged {
-                        world_id,
-                        fact_key: "quest_complete".to_owned(),
-                    },
-                ))
-                .unwrap(),
+                payload: serde_json::json!({
+                    "WorldFactChanged": { "world_id": world_id, "fact_key": "quest_complete" }
+                }),
                 sequence_number: 1,
                 correlation_id: Uuid::new_v4(),
                 causation_id: Uuid::new_v4(),
                 occurred_at: fixed_now,
+                schema_version: 1,
             },
             StoredEvent {
                 event_id: Uuid::new_v4(),
@@ -761,6 +1453,7 @@ mod tests {
                 correlation_id: Uuid::new_v4(),
                 causation_id: Uuid::new_v4(),
                 occurred_at: fixed_now,
+                schema_version: 1,
             },
         ];
         let repo = RecordingEventRepository::new(Ok(events));
@@ -0,0 +1,269 @@
+//! Run scope — routes each command to its campaign run's RNG.
+//!
+//! A command sent with an `X-Campaign-Run-ID` header draws its randomness
+//! from that run's seeded RNG rather than the process-wide one. The RNG is
+//! resumed from the seed and stream position recorded on the run. The command
+//! itself and the position the RNG reaches are staged next to the command's
+//! events in one unit of work, so all three are committed together and the
+//! run can later be replayed from its command log. See ADR-0035 and ADR-0036.
+//!
+//! A command whose target is an aggregate registered with a run (such as
+//! the run's encounter) belongs to that run even without the header; see
+//! [`RunScope::for_target`]. Every other command sent without the header
+//! falls back to the process-wide RNG and the underlying repository. It
+//! still runs, but it is not recorded in any run's command log and cannot
+//! be replayed.
+
+use std::sync::{Arc, Mutex};
+
+use axum::http::HeaderMap;
+use serde::Serialize;
+use uuid::Uuid;
+
+use otherworlds_core::clock::Clock;
+use otherworlds_core::command::Command;
+use otherworlds_core::error::DomainError;
+use otherworlds_core::repository::EventRepository;
+use otherworlds_core::rng::{DeterministicRng, SeededRng};
+use otherworlds_core::unit_of_work::UnitOfWork;
+use otherworlds_session::application::command_handlers as session_handlers;
+use otherworlds_session::application::query_handlers as session_queries;
+use otherworlds_session::domain::commands::{RecordCommand, RecordRngPosition};
+use otherworlds_session::domain::events::CommitSpan;
+
+use crate::state::AppState;
+
+/// Header naming the campaign run a command belongs to.
+pub const RUN_ID_HEADER: &str = "x-campaign-run-id";
+
+/// Reads the campaign run ID from the `X-Campaign-Run-ID` header.
+///
+/// # Errors
+///
+/// Returns `DomainError::Validation` if the header is present but is not a
+/// valid UUID. Unlike a bad correlation ID, a bad run ID is rejected rather
+/// than ignored, since ignoring it would silently make the command
+/// unreplayable.
+pub fn run_id_from_headers(headers: &HeaderMap) -> Result<Option<Uuid>, DomainError> {
+    headers
+        .get(RUN_ID_HEADER)
+        .map(|value| {
+            value
+                .to_str()
+                .ok()
+                .and_then(|s| Uuid::parse_str(s).ok())
+                .ok_or_else(|| {
+                    DomainError::Validation(format!("{RUN_ID_HEADER} must be a valid UUID"))
+                })
+        })
+        .transpose()
+}
+
+/// The run's RNG plus the unit of work its position is recorded in.
+#[derive(Debug)]
+struct RunRng<'a> {
+    run_id: Uuid,
+    rng: Arc<Mutex<SeededRng>>,
+    uow: UnitOfWork<'a>,
+}
+
+/// The RNG and repository a single command should use.
+///
+/// Without a run, the scope hands out the shared RNG and the underlying
+/// repository unchanged. With a run, it hands out the run's RNG and a unit
+/// of work; `commit` then records the command and the RNG's new position and
+/// commits them atomically with the command's events.
+#[derive(Debug)]
+pub struct RunScope<'a> {
+    base: &'a dyn EventRepository,
+    rng: Arc<Mutex<dyn DeterministicRng + Send>>,
+    run: Option<RunRng<'a>>,
+}
+
+impl<'a> RunScope<'a> {
+    /// Opens the scope named by the request's `X-Campaign-Run-ID` header.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::Validation` if the header is malformed or the
+    /// run is archived, and `DomainError::AggregateNotFound` if the run does
+    /// not exist.
+    pub async fn from_headers(
+        state: &'a AppState,
+        headers: &HeaderMap,
+    ) -> Result<Self, DomainError> {
+        Self::open(state, run_id_from_headers(headers)?).await
+    }
+
+    /// Opens the scope of the run `target_id` is registered with, or the one
+    /// named by the `X-Campaign-Run-ID` header if the target belongs to no
+    /// run.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::Validation` if the header is malformed, names a
+    /// different run than the one the target is registered with, or the run
+    /// is archived, and `DomainError::AggregateNotFound` if the run does not
+    /// exist.
+    pub async fn for_target(
+        state: &'a AppState,
+        headers: &HeaderMap,
+        target_id: Uuid,
+    ) -> Result<Self, DomainError> {
+        let named = run_id_from_headers(headers)?;
+        let registered = session_queries::find_run_of_aggregate(
+            target_id,
+            &*state.event_repository,
+            &*state.read_models,
+        )
+        .await?;
+        if let (Some(named), Some(registered)) = (named, registered)
+            && named != registered
+        {
+            return Err(DomainError::Validation(format!(
+                "{target_id} belongs to campaign run {registered}, not {named}"
+            )));
+        }
+        Self::open(state, registered.or(named)).await
+    }
+
+    /// Opens the scope of `run_id`, or the unscoped fallback for `None`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::AggregateNotFound` if the run does not exist,
+    /// and `DomainError::Validation` if it is archived.
+    pub async fn open(state: &'a AppState, run_id: Option<Uuid>) -> Result<Self, DomainError> {
+        let base = &*state.event_repository;
+        let Some(run_id) = run_id else {
+            return Ok(Self {
+                base,
+                rng: state.rng.clone(),
+                run: None,
+            });
+        };
+
+        let rng = Arc::new(Mutex::new(
+            session_handlers::open_run_rng(run_id, base).await?,
+        ));
+        Ok(Self {
+            base,
+            rng: rng.clone(),
+            run: Some(RunRng {
+                run_id,
+                rng,
+                uow: UnitOfWork::new(base),
+            }),
+        })
+    }
+
+    /// Returns the RNG the command must draw from.
+    pub fn rng(&self) -> &Mutex<dyn DeterministicRng + Send> {
+        &self.rng
+    }
+
+    /// Returns the campaign run the command belongs to, if any.
+    pub fn run_id(&self) -> Option<Uuid> {
+        self.run.as_ref().map(|run| run.run_id)
+    }
+
+    /// Returns the repository the command must load from and append to.
+    pub fn repo(&self) -> &dyn EventRepository {
+        match &self.run {
+            Some(run) => &run.uow,
+            None => self.base,
+        }
+    }
+
+    /// Records `command` and the run's RNG position, if the command staged
+    /// any events, and commits everything staged in this scope.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::ConcurrencyConflict` if another command of the
+    /// same run committed first, or any error from recording or committing.
+    pub async fn commit<C>(self, command: &C, clock: &dyn Clock) -> Result<(), DomainError>
+    where
+        C: Command + Serialize,
+    {
+        let Some(run) = self.run else {
+            return Ok(());
+        };
+        let staged = run.uow.pending_event_count()?;
+        if staged == 0 {
+            return Ok(());
+        }
+
+        // The record and the RNG position follow each other on the run's
+        // stream, so the commit holds the staged events plus those two.
+        let record = RecordCommand {
+            correlation_id: command.correlation_id(),
+            run_id: run.run_id,
+            command_type: command.command_type().to_owned(),
+            command: serde_json::to_value(command).map_err(|e| {
+                DomainError::Infrastructure(format!("command serialization failed: {e}"))
+            })?,
+            commit: CommitSpan {
+                offset: run.uow.pending_events_before(run.run_id)?,
+                len: staged + 2,
+            },
+        };
+        session_handlers::handle_record_command(&record, clock, &*run.rng, &run.uow).await?;
+
+        let position = RecordRngPosition {
+            correlation_id: command.correlation_id(),
+            run_id: run.run_id,
+        };
+        session_handlers::handle_record_rng_position(&position, clock, &run.rng, &run.uow).await?;
+        run.uow.commit().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use axum::http::HeaderValue;
+
+    #[test]
+    fn test_run_id_from_headers_is_none_without_header() {
+        // Arrange
+        let headers = HeaderMap::new();
+
+        // Act
+        let run_id = run_id_from_headers(&headers).unwrap();
+
+        // Assert
+        assert_eq!(run_id, None);
+    }
+
+    #[test]
+    fn test_run_id_from_headers_parses_uuid() {
+        // Arrange
+        let run_id = Uuid::new_v4();
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            RUN_ID_HEADER,
+            HeaderValue::from_str(&run_id.to_string()).unwrap(),
+        );
+
+        // Act
+        let parsed = run_id_from_headers(&headers).unwrap();
+
+        // Assert
+        assert_eq!(parsed, Some(run_id));
+    }
+
+    #[test]
+    fn test_run_id_from_headers_rejects_malformed_value() {
+        // Arrange
+        let mut headers = HeaderMap::new();
+        headers.insert(RUN_ID_HEADER, HeaderValue::from_static("not-a-uuid"));
+
+        // Act
+        let result = run_id_from_headers(&headers);
+
+        // Assert
+        assert!(matches!(result, Err(DomainError::Validation(_))));
+    }
+}
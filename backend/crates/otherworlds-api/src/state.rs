@@ -5,36 +5,42 @@ use std::sync::{Arc, Mutex};
 use sqlx::PgPool;
 
 use otherworlds_core::clock::Clock;
+use otherworlds_core::projection::ReadModelStore;
 use otherworlds_core::repository::EventRepository;
 use otherworlds_core::rng::DeterministicRng;
 
 /// Application state shared across all request handlers.
 #[derive(Debug, Clone)]
 pub struct AppState {
-    /// `PostgreSQL` connection pool.
-    pub db_pool: PgPool,
+    /// `PostgreSQL` connection pool, or `None` when running on the in-memory
+    /// event store.
+    pub db_pool: Option<PgPool>,
     /// Clock abstraction for deterministic time.
     pub clock: Arc<dyn Clock + Send + Sync>,
     /// RNG abstraction for deterministic randomness.
     pub rng: Arc<Mutex<dyn DeterministicRng + Send>>,
     /// Event repository for persisting and loading domain events.
     pub event_repository: Arc<dyn EventRepository>,
+    /// Read-model store backing projections and query handlers.
+    pub read_models: Arc<dyn ReadModelStore>,
 }
 
 impl AppState {
     /// Create new application state.
     #[must_use]
     pub fn new(
-        db_pool: PgPool,
+        db_pool: Option<PgPool>,
         clock: Arc<dyn Clock + Send + Sync>,
         rng: Arc<Mutex<dyn DeterministicRng + Send>>,
         event_repository: Arc<dyn EventRepository>,
+        read_models: Arc<dyn ReadModelStore>,
     ) -> Self {
         Self {
             db_pool,
             clock,
             rng,
             event_repository,
+            read_models,
         }
     }
 }
@@ -43,7 +49,9 @@ impl AppState {
 mod tests {
     use super::*;
 
-    use otherworlds_test_support::{EmptyEventRepository, FixedClock, MockRng};
+    use otherworlds_test_support::{
+        EmptyEventRepository, FixedClock, InMemoryReadModelStore, MockRng,
+    };
 
     #[tokio::test]
     async fn test_app_state_holds_event_repository() {
@@ -53,9 +61,18 @@ mod tests {
         let rng: Arc<Mutex<dyn DeterministicRng + Send>> = Arc::new(Mutex::new(MockRng));
         let event_repository: Arc<dyn EventRepository> = Arc::new(EmptyEventRepository);
 
-        let state = AppState::new(pool, clock, rng, event_repository.clone());
+        let read_models: Arc<dyn ReadModelStore> = Arc::new(InMemoryReadModelStore::new());
+
+        let state = AppState::new(
+            Some(pool),
+            clock,
+            rng,
+            event_repository.clone(),
+            read_models.clone(),
+        );
 
         // Verify event_repository is accessible and is the same instance.
         assert!(Arc::ptr_eq(&state.event_repository, &event_repository));
+        assert!(Arc::ptr_eq(&state.read_models, &read_models));
     }
 }
@@ -0,0 +1,101 @@
+//! Integration tests for the projection administration endpoints.
+
+mod common;
+
+use axum::http::StatusCode;
+use sqlx::PgPool;
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_rebuild_projection_replays_existing_events(pool: PgPool) {
+    // Arrange — create a character and let the list query project it.
+    let app = common::build_test_app(pool.clone());
+    let (status, _) = common::post_json(
+        app,
+        "/api/v1/characters/create",
+        &serde_json::json!({ "name": "Alaric" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    let app = common::build_test_app(pool.clone());
+    let (status, json) = common::get_json(app, "/api/v1/characters").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(json.as_array().unwrap().len(), 1);
+
+    // Simulate a corrupted read model.
+    sqlx::query("DELETE FROM character_summaries")
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    // Act
+    let app = common::build_test_app(pool.clone());
+    let (status, json) = common::post_json(
+        app,
+        "/api/v1/admin/projections/character.characters/rebuild",
+        &serde_json::json!({}),
+    )
+    .await;
+
+    // Assert
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(json["name"], "character.characters");
+    assert_eq!(json["events_processed"], 1);
+
+    let app = common::build_test_app(pool);
+    let (status, json) = common::get_json(app, "/api/v1/characters").await;
+    assert_eq!(status, StatusCode::OK);
+    let summaries = json.as_array().unwrap();
+    assert_eq!(summaries.len(), 1);
+    assert_eq!(summaries[0]["name"], "Alaric");
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_rebuild_unknown_projection_returns_400(pool: PgPool) {
+    let app = common::build_test_app(pool);
+
+    let (status, json) = common::post_json(
+        app,
+        "/api/v1/admin/projections/unknown.projection/rebuild",
+        &serde_json::json!({}),
+    )
+    .await;
+
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(json["error"], "validation_error");
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_list_projections_reports_checkpoints(pool: PgPool) {
+    // Arrange
+    let app = common::build_test_app(pool.clone());
+    let (status, _) = common::post_json(
+        app,
+        "/api/v1/characters/create",
+        &serde_json::json!({ "name": "Brynna" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let app = common::build_test_app(pool.clone());
+    let (status, _) = common::get_json(app, "/api/v1/characters").await;
+    assert_eq!(status, StatusCode::OK);
+
+    // Act
+    let app = common::build_test_app(pool);
+    let (status, json) = common::get_json(app, "/api/v1/admin/projections").await;
+
+    // Assert
+    assert_eq!(status, StatusCode::OK);
+    let statuses = json.as_array().unwrap();
+    assert_eq!(statuses.len(), 9);
+    let character = statuses
+        .iter()
+        .find(|s| s["name"] == "character.characters")
+        .unwrap();
+    assert_eq!(character["checkpoint"], 1);
+    let campaigns = statuses
+        .iter()
+        .find(|s| s["name"] == "content.campaigns")
+        .unwrap();
+    assert_eq!(campaigns["checkpoint"], 0);
+}
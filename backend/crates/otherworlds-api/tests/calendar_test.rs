@@ -0,0 +1,132 @@
+//! Integration tests for in-game time: calendars, scheduled events, and the
+//! events that fire as time advances.
+
+mod common;
+
+use std::sync::Arc;
+
+use axum::http::StatusCode;
+use otherworlds_event_store::memory_event_repository::InMemoryEventRepository;
+use otherworlds_event_store::memory_read_model_store::InMemoryReadModelStore;
+use uuid::Uuid;
+
+#[tokio::test]
+async fn test_advancing_time_fires_due_events_and_keeps_the_rest() {
+    // Arrange
+    let event_repository = Arc::new(InMemoryEventRepository::new());
+    let read_models = Arc::new(InMemoryReadModelStore::new());
+    let app = || common::build_in_memory_app(event_repository.clone(), read_models.clone());
+    let world_id = Uuid::new_v4();
+    let (status, _) = common::post_json(
+        app(),
+        "/api/v1/world/set-calendar",
+        &serde_json::json!({
+            "world_id": world_id,
+            "calendar": {
+                "hours_per_day": 20,
+                "months": [
+                    { "name": "Sowing", "days": 3 },
+                    { "name": "Harvest", "days": 3 }
+                ]
+            }
+        }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    for (day, flag) in [(5, "harvest_fair"), (2, "gates_closed")] {
+        let (status, _) = common::post_json(
+            app(),
+            "/api/v1/world/schedule-event",
+            &serde_json::json!({
+                "world_id": world_id,
+                "at": { "day": day, "hour": 3 },
+                "effect": { "type": "set_flag", "flag_key": flag, "value": true }
+            }),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK, "{flag}");
+    }
+
+    // Act
+    let (status, advanced) = common::post_json(
+        app(),
+        "/api/v1/world/advance-time",
+        &serde_json::json!({ "world_id": world_id, "hours": 25 }),
+    )
+    .await;
+
+    // Assert — time advanced, then the day-2 event fired and set its flag.
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(advanced["event_ids"].as_array().unwrap().len(), 3);
+    let (status, snapshot) = common::get_json(app(), &format!("/api/v1/world/{world_id}")).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(snapshot["flags"]["gates_closed"], true);
+    assert!(snapshot["flags"].get("harvest_fair").is_none());
+    assert_eq!(snapshot["time"]["elapsed_hours"], 25);
+    assert_eq!(
+        snapshot["time"]["now"],
+        serde_json::json!({ "day": 2, "hour": 5 })
+    );
+    assert_eq!(
+        snapshot["time"]["date"],
+        serde_json::json!({ "year": 1, "month": "Sowing", "day_of_month": 2 })
+    );
+    let schedule = snapshot["schedule"].as_array().unwrap();
+    assert_eq!(schedule.len(), 1);
+    assert_eq!(
+        schedule[0]["at"],
+        serde_json::json!({ "day": 5, "hour": 3 })
+    );
+    assert_eq!(schedule[0]["effect"]["flag_key"], "harvest_fair");
+}
+
+#[tokio::test]
+async fn test_calendar_and_schedule_reject_times_already_passed() {
+    // Arrange
+    let event_repository = Arc::new(InMemoryEventRepository::new());
+    let read_models = Arc::new(InMemoryReadModelStore::new());
+    let app = || common::build_in_memory_app(event_repository.clone(), read_models.clone());
+    let world_id = Uuid::new_v4();
+    let (status, _) = common::post_json(
+        app(),
+        "/api/v1/world/advance-time",
+        &serde_json::json!({ "world_id": world_id, "hours": 30 }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    // Act
+    let (calendar_status, _) = common::post_json(
+        app(),
+        "/api/v1/world/set-calendar",
+        &serde_json::json!({ "world_id": world_id, "calendar": { "hours_per_day": 10 } }),
+    )
+    .await;
+    let (past_status, _) = common::post_json(
+        app(),
+        "/api/v1/world/schedule-event",
+        &serde_json::json!({
+            "world_id": world_id,
+            "at": { "day": 2, "hour": 6 },
+            "effect": { "type": "set_flag", "flag_key": "too_late", "value": true }
+        }),
+    )
+    .await;
+    let (zero_status, _) = common::post_json(
+        app(),
+        "/api/v1/world/advance-time",
+        &serde_json::json!({ "world_id": world_id, "hours": 0 }),
+    )
+    .await;
+
+    // Assert
+    assert_eq!(calendar_status, StatusCode::BAD_REQUEST);
+    assert_eq!(past_status, StatusCode::BAD_REQUEST);
+    assert_eq!(zero_status, StatusCode::BAD_REQUEST);
+    let (_, snapshot) = common::get_json(app(), &format!("/api/v1/world/{world_id}")).await;
+    assert_eq!(
+        snapshot["time"]["now"],
+        serde_json::json!({ "day": 2, "hour": 6 })
+    );
+    assert!(snapshot["time"]["date"].is_null());
+}
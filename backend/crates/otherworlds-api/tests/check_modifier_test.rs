@@ -0,0 +1,323 @@
+//! Integration tests for check modifiers derived from a character sheet:
+//! attributes, proficiencies and equipped-item bonuses.
+
+mod common;
+
+use std::sync::Arc;
+
+use axum::Router;
+use axum::http::StatusCode;
+use chrono::Utc;
+use otherworlds_core::repository::{EventRepository, StoredEvent};
+use otherworlds_event_store::memory_event_repository::InMemoryEventRepository;
+use otherworlds_event_store::memory_read_model_store::InMemoryReadModelStore;
+use otherworlds_inventory::domain::events::{ITEM_ADDED_EVENT_TYPE, InventoryEventKind, ItemAdded};
+use uuid::Uuid;
+
+/// Creates a stealthy character — dexterity 16, proficient in stealth —
+/// with a cloak of `+1 stealth` equipped, and returns the character and
+/// inventory IDs.
+async fn stealthy_character(
+    event_repository: &Arc<InMemoryEventRepository>,
+    app: impl Fn() -> Router,
+) -> (Uuid, Uuid) {
+    let (status, _) = common::post_json(
+        app(),
+        "/api/v1/characters/create",
+        &serde_json::json!({ "name": "Vex" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let character_id = event_repository.read_all_from(1, 1).await.unwrap()[0]
+        .event
+        .aggregate_id;
+
+    // Inventories are created by their first item, written directly.
+    let inventory_id = Uuid::new_v4();
+    let cloak_id = Uuid::new_v4();
+    let item_added = StoredEvent {
+        event_id: Uuid::new_v4(),
+        aggregate_id: inventory_id,
+        event_type: ITEM_ADDED_EVENT_TYPE.to_owned(),
+        payload: serde_json::to_value(InventoryEventKind::ItemAdded(ItemAdded {
+            inventory_id,
+            item_id: cloak_id,
+        }))
+        .unwrap(),
+        sequence_number: 1,
+        correlation_id: Uuid::new_v4(),
+        causation_id: Uuid::new_v4(),
+        occurred_at: Utc::now(),
+        schema_version: 1,
+    };
+    event_repository
+        .append_events(inventory_id, 0, &[item_added])
+        .await
+        .unwrap();
+
+    for (uri, body) in [
+        (
+            "/api/v1/characters/modify-attribute",
+            serde_json::json!({
+                "character_id": character_id,
+                "attribute": "dexterity",
+                "new_value": 16
+            }),
+        ),
+        (
+            "/api/v1/characters/grant-proficiency",
+            serde_json::json!({ "character_id": character_id, "skill": "stealth" }),
+        ),
+        (
+            "/api/v1/inventory/equip-item",
+            serde_json::json!({
+                "inventory_id": inventory_id,
+                "item_id": cloak_id,
+                "bonuses": { "stealth": 1 }
+            }),
+        ),
+    ] {
+        let (status, _) = common::post_json(app(), uri, &body).await;
+        assert_eq!(status, StatusCode::OK, "{uri}");
+    }
+
+    (character_id, inventory_id)
+}
+
+#[tokio::test]
+async fn test_declare_intent_derives_modifier_from_character_sheet() {
+    // Arrange
+    let event_repository = Arc::new(InMemoryEventRepository::new());
+    let read_models = Arc::new(InMemoryReadModelStore::new());
+    let app = || common::build_in_memory_app(event_repository.clone(), read_models.clone());
+    let (character_id, inventory_id) = stealthy_character(&event_repository, app).await;
+    let resolution_id = Uuid::new_v4();
+
+    // Act
+    let (status, _) = common::post_json(
+        app(),
+        "/api/v1/rules/declare-intent",
+        &serde_json::json!({
+            "resolution_id": resolution_id,
+            "intent_id": Uuid::new_v4(),
+            "action_type": "skill_check",
+            "skill": "stealth",
+            "difficulty_class": 15,
+            "character_id": character_id,
+            "inventory_id": inventory_id
+        }),
+    )
+    .await;
+
+    // Assert — +3 dexterity, +2 proficiency, +1 cloak.
+    assert_eq!(status, StatusCode::OK);
+    let (status, json) = common::get_json(app(), &format!("/api/v1/rules/{resolution_id}")).await;
+    assert_eq!(status, StatusCode::OK);
+    let intent = &json["intent"];
+    assert_eq!(intent["modifier"], 6);
+    let breakdown = &intent["modifier_breakdown"];
+    assert_eq!(breakdown["character_id"], character_id.to_string());
+    assert_eq!(breakdown["attribute"], "dexterity");
+    assert_eq!(breakdown["total"], 6);
+    let sources: Vec<&str> = breakdown["components"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|c| c["source"].as_str().unwrap())
+        .collect();
+    assert_eq!(sources, ["attribute", "proficiency", "item"]);
+
+    let (_, character) =
+        common::get_json(app(), &format!("/api/v1/characters/{character_id}")).await;
+    assert_eq!(character["proficiencies"], serde_json::json!(["stealth"]));
+    let (_, inventory) =
+        common::get_json(app(), &format!("/api/v1/inventory/{inventory_id}")).await;
+    assert_eq!(inventory["equipped"][0]["bonuses"]["stealth"], 1);
+}
+
+#[tokio::test]
+async fn test_declare_intent_reads_stat_terms_from_character_sheet() {
+    // Arrange
+    let event_repository = Arc::new(InMemoryEventRepository::new());
+    let read_models = Arc::new(InMemoryReadModelStore::new());
+    let app = || common::build_in_memory_app(event_repository.clone(), read_models.clone());
+    let (character_id, _) = stealthy_character(&event_repository, app).await;
+    let resolution_id = Uuid::new_v4();
+
+    // Act
+    let (status, _) = common::post_json(
+        app(),
+        "/api/v1/rules/declare-intent",
+        &serde_json::json!({
+            "resolution_id": resolution_id,
+            "intent_id": Uuid::new_v4(),
+            "action_type": "skill_check",
+            "skill": "stealth",
+            "difficulty_class": 15,
+            "character_id": character_id,
+            "roll_expression": "1d20+@dexterity"
+        }),
+    )
+    .await;
+    let (anonymous_status, anonymous) = common::post_json(
+        app(),
+        "/api/v1/rules/declare-intent",
+        &serde_json::json!({
+            "resolution_id": Uuid::new_v4(),
+            "intent_id": Uuid::new_v4(),
+            "action_type": "skill_check",
+            "difficulty_class": 15,
+            "modifier": 0,
+            "roll_expression": "1d20+@dexterity"
+        }),
+    )
+    .await;
+
+    // Assert — dexterity 16 is +3.
+    assert_eq!(status, StatusCode::OK);
+    let (_, json) = common::get_json(app(), &format!("/api/v1/rules/{resolution_id}")).await;
+    assert_eq!(
+        json["intent"]["stats"],
+        serde_json::json!({ "dexterity": 3 })
+    );
+    assert_eq!(anonymous_status, StatusCode::BAD_REQUEST);
+    assert!(
+        anonymous["message"]
+            .as_str()
+            .unwrap()
+            .contains("@dexterity")
+    );
+}
+
+#[tokio::test]
+async fn test_declare_intent_rejects_constant_bonus_for_character() {
+    // Arrange
+    let event_repository = Arc::new(InMemoryEventRepository::new());
+    let read_models = Arc::new(InMemoryReadModelStore::new());
+    let app = || common::build_in_memory_app(event_repository.clone(), read_models.clone());
+    let (character_id, _) = stealthy_character(&event_repository, app).await;
+
+    // Act
+    let (status, json) = common::post_json(
+        app(),
+        "/api/v1/rules/declare-intent",
+        &serde_json::json!({
+            "resolution_id": Uuid::new_v4(),
+            "intent_id": Uuid::new_v4(),
+            "action_type": "skill_check",
+            "skill": "stealth",
+            "difficulty_class": 15,
+            "character_id": character_id,
+            "roll_expression": "1d20+15"
+        }),
+    )
+    .await;
+
+    // Assert
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert!(
+        json["message"]
+            .as_str()
+            .unwrap()
+            .contains("at most one @stat")
+    );
+}
+
+#[tokio::test]
+async fn test_declare_intent_rejects_modifier_alongside_character() {
+    // Arrange
+    let event_repository = Arc::new(InMemoryEventRepository::new());
+    let read_models = Arc::new(InMemoryReadModelStore::new());
+    let app = || common::build_in_memory_app(event_repository.clone(), read_models.clone());
+    let (character_id, _) = stealthy_character(&event_repository, app).await;
+    let intent = |extra: serde_json::Value| {
+        let mut body = serde_json::json!({
+            "resolution_id": Uuid::new_v4(),
+            "intent_id": Uuid::new_v4(),
+            "action_type": "skill_check",
+            "skill": "stealth",
+            "difficulty_class": 15
+        });
+        body.as_object_mut()
+            .unwrap()
+            .extend(extra.as_object().unwrap().clone());
+        body
+    };
+
+    // Act
+    let (both_status, both) = common::post_json(
+        app(),
+        "/api/v1/rules/declare-intent",
+        &intent(serde_json::json!({ "character_id": character_id, "modifier": 9 })),
+    )
+    .await;
+    let (neither_status, _) = common::post_json(
+        app(),
+        "/api/v1/rules/declare-intent",
+        &intent(serde_json::json!({})),
+    )
+    .await;
+    let (unknown_status, _) = common::post_json(
+        app(),
+        "/api/v1/rules/declare-intent",
+        &intent(serde_json::json!({ "character_id": Uuid::new_v4() })),
+    )
+    .await;
+
+    // Assert
+    assert_eq!(both_status, StatusCode::BAD_REQUEST);
+    assert_eq!(both["error"], "validation_error");
+    assert_eq!(neither_status, StatusCode::BAD_REQUEST);
+    assert_eq!(unknown_status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_resolve_action_in_run_with_derived_modifier_replays_without_divergence() {
+    // Arrange — the sheet is built outside the run, then used inside it.
+    let event_repository = Arc::new(InMemoryEventRepository::new());
+    let read_models = Arc::new(InMemoryReadModelStore::new());
+    let app = || common::build_in_memory_app(event_repository.clone(), read_models.clone());
+    let (character_id, inventory_id) = stealthy_character(&event_repository, app).await;
+    let (status, json) = common::post_json(
+        app(),
+        "/api/v1/sessions/start-campaign-run",
+        &serde_json::json!({ "campaign_id": Uuid::from_u128(1), "seed": 7 }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let run_id: Uuid = json["aggregate_id"].as_str().unwrap().parse().unwrap();
+
+    let (status, played) = common::post_json_in_run(
+        app(),
+        "/api/v1/play/resolve-action",
+        run_id,
+        &serde_json::json!({
+            "session_id": Uuid::from_u128(2),
+            "world_id": Uuid::from_u128(3),
+            "action_type": "skill_check",
+            "skill": "stealth",
+            "difficulty_class": 12,
+            "character_id": character_id,
+            "inventory_id": inventory_id,
+            "effects": [{ "effect_type": "set_flag", "payload": { "flag": "vault_revealed", "value": true } }]
+        }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    // Act
+    let (status, replay) = common::post_json(
+        app(),
+        &format!("/api/v1/admin/runs/{run_id}/replay"),
+        &serde_json::json!({}),
+    )
+    .await;
+
+    // Assert
+    assert_eq!(status, StatusCode::OK);
+    assert!(replay["divergence"].is_null());
+    let resolution_id = played["resolution_id"].as_str().unwrap();
+    let (_, resolution) = common::get_json(app(), &format!("/api/v1/rules/{resolution_id}")).await;
+    assert_eq!(resolution["intent"]["modifier"], 6);
+    assert_eq!(resolution["check_result"]["modifier"], 6);
+}
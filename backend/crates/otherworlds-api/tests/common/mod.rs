@@ -9,11 +9,17 @@ use axum::body::Body;
 use axum::http::{Request, StatusCode};
 use http_body_util::BodyExt;
 use otherworlds_core::clock::Clock;
+use otherworlds_core::projection::ReadModelStore;
+use otherworlds_core::repository::EventRepository;
 use otherworlds_core::rng::DeterministicRng;
+use otherworlds_event_store::memory_event_repository::InMemoryEventRepository;
+use otherworlds_event_store::memory_read_model_store::InMemoryReadModelStore;
 use otherworlds_event_store::pg_event_repository::PgEventRepository;
+use otherworlds_event_store::pg_read_model_store::PgReadModelStore;
 use otherworlds_test_support::{FixedClock, SequenceRng};
 use sqlx::PgPool;
 use tower::ServiceExt;
+use uuid::Uuid;
 
 /// Global counter ensuring each `build_test_app` call gets a unique RNG range,
 /// preventing duplicate `event_id` UUIDs across requests within the same test.
@@ -29,7 +35,7 @@ fn fixed_clock() -> Arc<dyn Clock + Send + Sync> {
     ))
 }
 
-/// Build the full app router with a real `PgEventRepository` and deterministic
+/// Build the full app router with a real `PgEventRepository`, `PgReadModelStore`, and deterministic
 /// Clock/RNG. Uses the same route structure as `main.rs`.
 ///
 /// Each call gets a unique RNG range (100 values starting from a global offset)
@@ -47,17 +53,53 @@ pub fn build_test_app_with_rng(pool: PgPool, rng: SequenceRng) -> Router {
     let clock = fixed_clock();
     let rng: Arc<Mutex<dyn DeterministicRng + Send>> = Arc::new(Mutex::new(rng));
     let event_repository = Arc::new(PgEventRepository::new(pool.clone()));
-    let app_state = AppState::new(pool, clock, rng, event_repository);
+    let read_models = Arc::new(PgReadModelStore::new(pool.clone()));
+    let app_state = AppState::new(Some(pool), clock, rng, event_repository, read_models);
 
+    app_router(app_state)
+}
+
+/// Build the full app router on the in-memory event store, sharing
+/// `event_repository` and `read_models` so that state carries over between
+/// requests. Needs no database.
+pub fn build_in_memory_app(
+    event_repository: Arc<InMemoryEventRepository>,
+    read_models: Arc<InMemoryReadModelStore>,
+) -> Router {
+    let start = RNG_OFFSET.fetch_add(100, Ordering::Relaxed);
+    let values: Vec<u32> = (start..start + 100).collect();
+    build_in_memory_app_with_rng(event_repository, read_models, SequenceRng::new(values))
+}
+
+/// Build the full app router on the in-memory event store with a custom
+/// process-wide RNG.
+pub fn build_in_memory_app_with_rng(
+    event_repository: Arc<InMemoryEventRepository>,
+    read_models: Arc<InMemoryReadModelStore>,
+    rng: SequenceRng,
+) -> Router {
+    let rng: Arc<Mutex<dyn DeterministicRng + Send>> = Arc::new(Mutex::new(rng));
+    let event_repository: Arc<dyn EventRepository> = event_repository;
+    let read_models: Arc<dyn ReadModelStore> = read_models;
+    let app_state = AppState::new(None, fixed_clock(), rng, event_repository, read_models);
+
+    app_router(app_state)
+}
+
+/// Uses the same route structure as `main.rs`.
+fn app_router(app_state: AppState) -> Router {
     Router::new()
         .merge(routes::health::router())
         .nest("/api/v1/narrative", routes::narrative::router())
         .nest("/api/v1/rules", routes::rules::router())
+        .nest("/api/v1/encounters", routes::encounter::router())
         .nest("/api/v1/world", routes::world_state::router())
         .nest("/api/v1/characters", routes::character::router())
         .nest("/api/v1/inventory", routes::inventory::router())
         .nest("/api/v1/sessions", routes::session::router())
         .nest("/api/v1/content", routes::content::router())
+        .nest("/api/v1/play", routes::play::router())
+        .nest("/api/v1/admin", routes::admin::router())
         .with_state(app_state)
 }
 
@@ -67,7 +109,43 @@ pub async fn post_json(
     uri: &str,
     body: &serde_json::Value,
 ) -> (StatusCode, serde_json::Value) {
-    let request = Request::builder()
+    send_post(app, Request::builder(), uri, body).await
+}
+
+/// Send a POST request on behalf of a campaign run, via the
+/// `X-Campaign-Run-ID` header, and return the response.
+pub async fn post_json_in_run(
+    app: Router,
+    uri: &str,
+    run_id: Uuid,
+    body: &serde_json::Value,
+) -> (StatusCode, serde_json::Value) {
+    let builder = Request::builder().header("x-campaign-run-id", run_id.to_string());
+    send_post(app, builder, uri, body).await
+}
+
+/// Send a POST request on behalf of a campaign run with an explicit
+/// `X-Correlation-ID`, and return the response.
+pub async fn post_json_in_run_correlated(
+    app: Router,
+    uri: &str,
+    run_id: Uuid,
+    correlation_id: Uuid,
+    body: &serde_json::Value,
+) -> (StatusCode, serde_json::Value) {
+    let builder = Request::builder()
+        .header("x-campaign-run-id", run_id.to_string())
+        .header("x-correlation-id", correlation_id.to_string());
+    send_post(app, builder, uri, body).await
+}
+
+async fn send_post(
+    app: Router,
+    builder: axum::http::request::Builder,
+    uri: &str,
+    body: &serde_json::Value,
+) -> (StatusCode, serde_json::Value) {
+    let request = builder
         .method("POST")
         .uri(uri)
         .header("content-type", "application/json")
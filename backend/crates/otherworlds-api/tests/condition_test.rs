@@ -0,0 +1,160 @@
+//! Integration tests for evaluating conditions against a world's state.
+
+mod common;
+
+use std::sync::Arc;
+
+use axum::Router;
+use axum::http::StatusCode;
+use otherworlds_event_store::memory_event_repository::InMemoryEventRepository;
+use otherworlds_event_store::memory_read_model_store::InMemoryReadModelStore;
+use uuid::Uuid;
+
+const GATEHOUSE_CAMPAIGN: &str = "---
+title: \"Gatehouse\"
+---
+
+# Scene: gate
+
+Torches gutter in the arch.
+
+## NPCs
+- guard_captain
+
+# NPC: guard_captain
+
+- name: Captain Mora
+- disposition: friendly
+- faction: city_watch
+";
+
+/// Ingests, validates and compiles the gatehouse campaign, returning its ID.
+async fn compiled_gatehouse_campaign(app: impl Fn() -> Router) -> Uuid {
+    let (status, json) = common::post_json(
+        app(),
+        "/api/v1/content/ingest-campaign",
+        &serde_json::json!({ "source": GATEHOUSE_CAMPAIGN }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let campaign_id: Uuid = json["aggregate_id"].as_str().unwrap().parse().unwrap();
+    for uri in [
+        "/api/v1/content/validate-campaign",
+        "/api/v1/content/compile-campaign",
+    ] {
+        let (status, _) = common::post_json(
+            app(),
+            uri,
+            &serde_json::json!({ "campaign_id": campaign_id }),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK, "{uri}");
+    }
+    campaign_id
+}
+
+#[tokio::test]
+async fn test_evaluate_answers_from_flags_facts_and_campaign_dispositions() {
+    // Arrange
+    let event_repository = Arc::new(InMemoryEventRepository::new());
+    let read_models = Arc::new(InMemoryReadModelStore::new());
+    let app = || common::build_in_memory_app(event_repository.clone(), read_models.clone());
+    let campaign_id = compiled_gatehouse_campaign(app).await;
+    let world_id = Uuid::new_v4();
+    let (status, _) = common::post_json(
+        app(),
+        "/api/v1/world/seed-from-campaign",
+        &serde_json::json!({ "world_id": world_id, "campaign_id": campaign_id }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    for (uri, body) in [
+        (
+            "/api/v1/world/set-flag",
+            serde_json::json!({ "world_id": world_id, "flag_key": "door_open", "value": true }),
+        ),
+        (
+            "/api/v1/world/assert-fact",
+            serde_json::json!({
+                "world_id": world_id,
+                "key": "gold",
+                "value": { "type": "number", "value": 12 }
+            }),
+        ),
+    ] {
+        let (status, _) = common::post_json(app(), uri, &body).await;
+        assert_eq!(status, StatusCode::OK, "{uri}");
+    }
+    let evaluate_uri = format!("/api/v1/world/{world_id}/evaluate");
+    let condition = "flag `door_open` is true and disposition(guard_captain) >= friendly \
+                     and fact gold >= 10 and disposition(city_watch) == neutral";
+
+    // Act
+    let (status, holds) = common::post_json(
+        app(),
+        &evaluate_uri,
+        &serde_json::json!({ "condition": condition, "campaign_id": campaign_id }),
+    )
+    .await;
+    let (_, unmet) = common::post_json(
+        app(),
+        &evaluate_uri,
+        &serde_json::json!({ "condition": "fact gold > 12 or day > 1" }),
+    )
+    .await;
+
+    // Assert
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(holds["result"], true);
+    assert_eq!(unmet["result"], false);
+}
+
+#[tokio::test]
+async fn test_evaluate_rejects_bad_conditions_and_unknown_worlds() {
+    // Arrange
+    let event_repository = Arc::new(InMemoryEventRepository::new());
+    let read_models = Arc::new(InMemoryReadModelStore::new());
+    let app = || common::build_in_memory_app(event_repository.clone(), read_models.clone());
+    let world_id = Uuid::new_v4();
+    let (status, _) = common::post_json(
+        app(),
+        "/api/v1/world/set-flag",
+        &serde_json::json!({ "world_id": world_id, "flag_key": "door_open", "value": true }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let evaluate = |id: Uuid, condition: &str| {
+        let body = serde_json::json!({ "condition": condition });
+        let uri = format!("/api/v1/world/{id}/evaluate");
+        async move { common::post_json(app(), &uri, &body).await }
+    };
+
+    // Act
+    let (syntax_status, syntax) = evaluate(world_id, "flag door_open and").await;
+    let (entity_status, entity) = evaluate(world_id, "disposition(guard_captain) > hostile").await;
+    let (missing_status, _) = evaluate(Uuid::new_v4(), "true").await;
+    let deep = format!("{}true{}", "(".repeat(5000), ")".repeat(5000));
+    let (deep_status, _) = evaluate(world_id, &deep).await;
+    let negated = format!("{}true", "not ".repeat(1000));
+    let (negated_status, negated) = evaluate(world_id, &negated).await;
+
+    // Assert
+    assert_eq!(syntax_status, StatusCode::BAD_REQUEST);
+    assert!(syntax["message"].as_str().unwrap().contains("ends early"));
+    assert_eq!(entity_status, StatusCode::BAD_REQUEST);
+    assert!(
+        entity["message"]
+            .as_str()
+            .unwrap()
+            .contains("guard_captain")
+    );
+    assert_eq!(missing_status, StatusCode::NOT_FOUND);
+    assert_eq!(deep_status, StatusCode::BAD_REQUEST);
+    assert_eq!(negated_status, StatusCode::BAD_REQUEST);
+    assert!(
+        negated["message"]
+            .as_str()
+            .unwrap()
+            .contains("nests deeper")
+    );
+}
@@ -0,0 +1,162 @@
+//! Integration tests for numeric dispositions seeded from campaign NPCs and
+//! rippling to their factions.
+
+mod common;
+
+use std::sync::Arc;
+
+use axum::Router;
+use axum::http::StatusCode;
+use otherworlds_event_store::memory_event_repository::InMemoryEventRepository;
+use otherworlds_event_store::memory_read_model_store::InMemoryReadModelStore;
+use uuid::Uuid;
+
+const HARBOUR_CAMPAIGN: &str = "---
+title: \"Harbour\"
+---
+
+# Scene: docks
+
+Gulls wheel over the quay.
+
+## NPCs
+- captain
+- sergeant
+
+# NPC: captain
+
+- name: Captain Theron
+- disposition: friendly
+- faction: city_watch
+
+# NPC: sergeant
+
+- name: Sergeant Vell
+- disposition: -70
+- faction: city_watch
+";
+
+/// Ingests, validates and compiles the harbour campaign, returning its ID.
+async fn compiled_harbour_campaign(app: impl Fn() -> Router) -> Uuid {
+    let (status, json) = common::post_json(
+        app(),
+        "/api/v1/content/ingest-campaign",
+        &serde_json::json!({ "source": HARBOUR_CAMPAIGN }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let campaign_id: Uuid = json["aggregate_id"].as_str().unwrap().parse().unwrap();
+    for uri in [
+        "/api/v1/content/validate-campaign",
+        "/api/v1/content/compile-campaign",
+    ] {
+        let (status, _) = common::post_json(
+            app(),
+            uri,
+            &serde_json::json!({ "campaign_id": campaign_id }),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK, "{uri}");
+    }
+    campaign_id
+}
+
+#[tokio::test]
+async fn test_seeded_npc_shift_ripples_to_their_faction() {
+    // Arrange
+    let event_repository = Arc::new(InMemoryEventRepository::new());
+    let read_models = Arc::new(InMemoryReadModelStore::new());
+    let app = || common::build_in_memory_app(event_repository.clone(), read_models.clone());
+    let campaign_id = compiled_harbour_campaign(app).await;
+    let world_id = Uuid::new_v4();
+    let seed_request = serde_json::json!({ "world_id": world_id, "campaign_id": campaign_id });
+    let (status, seeded) =
+        common::post_json(app(), "/api/v1/world/seed-from-campaign", &seed_request).await;
+    assert_eq!(status, StatusCode::OK);
+    // Two NPCs, their faction, and two memberships.
+    assert_eq!(seeded["event_ids"].as_array().unwrap().len(), 5);
+    let captain_id = seeded["npc_entity_ids"]["captain"].as_str().unwrap();
+    let sergeant_id = seeded["npc_entity_ids"]["sergeant"].as_str().unwrap();
+    let watch_id = seeded["faction_entity_ids"]["city_watch"].as_str().unwrap();
+
+    // Act
+    let (status, _) = common::post_json(
+        app(),
+        "/api/v1/play/resolve-action",
+        &serde_json::json!({
+            "session_id": Uuid::new_v4(),
+            "world_id": world_id,
+            "action_type": "skill_check",
+            "difficulty_class": 1,
+            "modifier": 30,
+            "effects": [{
+                "effect_type": "shift_disposition",
+                "target_id": captain_id,
+                "payload": { "delta": -50 }
+            }]
+        }),
+    )
+    .await;
+
+    // Assert — the captain drops from 40 to −10; the watch takes half.
+    assert_eq!(status, StatusCode::OK);
+    let (_, world) = common::get_json(app(), &format!("/api/v1/world/{world_id}")).await;
+    let dispositions = &world["dispositions"];
+    assert_eq!(
+        dispositions[captain_id],
+        serde_json::json!({ "score": -10, "band": "neutral" })
+    );
+    assert_eq!(
+        dispositions[sergeant_id],
+        serde_json::json!({ "score": -70, "band": "hostile" })
+    );
+    assert_eq!(
+        dispositions[watch_id],
+        serde_json::json!({ "score": -25, "band": "unfriendly" })
+    );
+    let mut members: Vec<&str> = world["factions"][watch_id]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|id| id.as_str().unwrap())
+        .collect();
+    members.sort_unstable();
+    let mut expected = vec![captain_id, sergeant_id];
+    expected.sort_unstable();
+    assert_eq!(members, expected);
+}
+
+#[tokio::test]
+async fn test_seeding_again_keeps_scores_already_moved() {
+    // Arrange
+    let event_repository = Arc::new(InMemoryEventRepository::new());
+    let read_models = Arc::new(InMemoryReadModelStore::new());
+    let app = || common::build_in_memory_app(event_repository.clone(), read_models.clone());
+    let campaign_id = compiled_harbour_campaign(app).await;
+    let world_id = Uuid::new_v4();
+    let seed_request = serde_json::json!({ "world_id": world_id, "campaign_id": campaign_id });
+    let (_, seeded) =
+        common::post_json(app(), "/api/v1/world/seed-from-campaign", &seed_request).await;
+    let captain_id = seeded["npc_entity_ids"]["captain"].as_str().unwrap();
+    let (status, _) = common::post_json(
+        app(),
+        "/api/v1/world/update-disposition",
+        &serde_json::json!({ "world_id": world_id, "entity_id": captain_id, "delta": 30 }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    // Act
+    let (status, reseeded) =
+        common::post_json(app(), "/api/v1/world/seed-from-campaign", &seed_request).await;
+
+    // Assert
+    assert_eq!(status, StatusCode::OK);
+    assert!(reseeded["event_ids"].as_array().unwrap().is_empty());
+    assert_eq!(reseeded["npc_entity_ids"]["captain"], captain_id);
+    let (_, world) = common::get_json(app(), &format!("/api/v1/world/{world_id}")).await;
+    assert_eq!(
+        world["dispositions"][captain_id],
+        serde_json::json!({ "score": 70, "band": "allied" })
+    );
+}
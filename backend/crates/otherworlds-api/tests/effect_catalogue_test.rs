@@ -0,0 +1,165 @@
+//! Integration tests for the typed effect catalogue and campaign-declared
+//! custom effects.
+
+mod common;
+
+use std::sync::Arc;
+
+use axum::Router;
+use axum::http::StatusCode;
+use otherworlds_core::repository::EventRepository;
+use otherworlds_event_store::memory_event_repository::InMemoryEventRepository;
+use otherworlds_event_store::memory_read_model_store::InMemoryReadModelStore;
+use uuid::Uuid;
+
+const STORM_CAMPAIGN: &str = "---
+title: \"Storms\"
+custom_effects:
+  - name: summon_storm
+    version: 1
+    schema:
+      type: object
+      required: [intensity]
+      properties:
+        intensity: { type: integer, minimum: 1 }
+---
+
+# Scene: start
+
+The sky darkens.
+";
+
+/// Ingests, validates and compiles the storm campaign, returning its ID.
+async fn compiled_storm_campaign(app: impl Fn() -> Router) -> Uuid {
+    let (status, json) = common::post_json(
+        app(),
+        "/api/v1/content/ingest-campaign",
+        &serde_json::json!({ "source": STORM_CAMPAIGN }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let campaign_id: Uuid = json["aggregate_id"].as_str().unwrap().parse().unwrap();
+    for uri in [
+        "/api/v1/content/validate-campaign",
+        "/api/v1/content/compile-campaign",
+    ] {
+        let (status, _) = common::post_json(
+            app(),
+            uri,
+            &serde_json::json!({ "campaign_id": campaign_id }),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK, "{uri}");
+    }
+    campaign_id
+}
+
+fn resolve_action(campaign_id: Uuid, effects: &serde_json::Value) -> serde_json::Value {
+    serde_json::json!({
+        "session_id": Uuid::new_v4(),
+        "world_id": Uuid::new_v4(),
+        "campaign_id": campaign_id,
+        "action_type": "skill_check",
+        "difficulty_class": 1,
+        "modifier": 30,
+        "effects": effects
+    })
+}
+
+#[tokio::test]
+async fn test_resolve_action_produces_typed_and_declared_custom_effects() {
+    // Arrange
+    let event_repository = Arc::new(InMemoryEventRepository::new());
+    let read_models = Arc::new(InMemoryReadModelStore::new());
+    let app = || common::build_in_memory_app(event_repository.clone(), read_models.clone());
+    let campaign_id = compiled_storm_campaign(app).await;
+
+    // Act
+    let (status, played) = common::post_json(
+        app(),
+        "/api/v1/play/resolve-action",
+        &resolve_action(
+            campaign_id,
+            &serde_json::json!([
+                {
+                    "effect_type": "shift_disposition",
+                    "target_id": Uuid::from_u128(9),
+                    "payload": { "delta": 4 }
+                },
+                {
+                    "effect_type": "custom",
+                    "target_id": null,
+                    "payload": { "name": "summon_storm", "version": 1, "data": { "intensity": 3 } }
+                }
+            ]),
+        ),
+    )
+    .await;
+
+    // Assert
+    assert_eq!(status, StatusCode::OK);
+    let resolution_id = played["resolution_id"].as_str().unwrap();
+    let (_, resolution) = common::get_json(app(), &format!("/api/v1/rules/{resolution_id}")).await;
+    let effects = resolution["effects"].as_array().unwrap();
+    assert_eq!(effects[0]["effect_type"], "shift_disposition");
+    assert_eq!(effects[0]["payload"]["delta"], 4);
+    assert_eq!(effects[1]["effect_type"], "custom");
+    assert_eq!(effects[1]["payload"]["name"], "summon_storm");
+    assert_eq!(effects[1]["payload"]["data"]["intensity"], 3);
+}
+
+#[tokio::test]
+async fn test_resolve_action_rejects_effects_outside_the_catalogue() {
+    // Arrange
+    let event_repository = Arc::new(InMemoryEventRepository::new());
+    let read_models = Arc::new(InMemoryReadModelStore::new());
+    let app = || common::build_in_memory_app(event_repository.clone(), read_models.clone());
+    let campaign_id = compiled_storm_campaign(app).await;
+    let events_before = event_repository.read_all_from(1, 1000).await.unwrap().len();
+
+    for (effect, expected) in [
+        (
+            serde_json::json!({ "effect_type": "reveal", "target_id": null, "payload": {} }),
+            "unknown effect type 'reveal'",
+        ),
+        (
+            serde_json::json!({
+                "effect_type": "damage",
+                "target_id": Uuid::from_u128(9),
+                "payload": { "amount": "lots" }
+            }),
+            "invalid damage payload",
+        ),
+        (
+            serde_json::json!({
+                "effect_type": "custom",
+                "target_id": null,
+                "payload": { "name": "summon_storm", "version": 1, "data": { "intensity": 0 } }
+            }),
+            "does not match its schema",
+        ),
+        (
+            serde_json::json!({
+                "effect_type": "custom",
+                "target_id": null,
+                "payload": { "name": "summon_storm", "version": 2, "data": { "intensity": 3 } }
+            }),
+            "'summon_storm' v2 is not declared",
+        ),
+    ] {
+        // Act
+        let (status, json) = common::post_json(
+            app(),
+            "/api/v1/play/resolve-action",
+            &resolve_action(campaign_id, &serde_json::json!([effect])),
+        )
+        .await;
+
+        // Assert
+        assert_eq!(status, StatusCode::BAD_REQUEST, "{expected}");
+        let message = json["message"].as_str().unwrap();
+        assert!(message.contains(expected), "{message}");
+    }
+    let events_after = event_repository.read_all_from(1, 1000).await.unwrap().len();
+    assert_eq!(events_after, events_before);
+}
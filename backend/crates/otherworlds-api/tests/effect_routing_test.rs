@@ -0,0 +1,173 @@
+//! Integration tests for routing play-loop effects to the contexts that own
+//! them.
+
+mod common;
+
+use std::sync::Arc;
+
+use axum::Router;
+use axum::http::StatusCode;
+use chrono::Utc;
+use otherworlds_core::repository::{EventRepository, StoredEvent};
+use otherworlds_event_store::memory_event_repository::InMemoryEventRepository;
+use otherworlds_event_store::memory_read_model_store::InMemoryReadModelStore;
+use otherworlds_inventory::domain::events::{ITEM_ADDED_EVENT_TYPE, InventoryEventKind, ItemAdded};
+use uuid::Uuid;
+
+/// Creates a character with 10 of 12 hit points and an inventory holding a
+/// rope, and returns the character and inventory IDs.
+async fn wounded_character(
+    event_repository: &Arc<InMemoryEventRepository>,
+    app: impl Fn() -> Router,
+) -> (Uuid, Uuid) {
+    let (status, _) = common::post_json(
+        app(),
+        "/api/v1/characters/create",
+        &serde_json::json!({ "name": "Brenna" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let character_id = event_repository.read_all_from(1, 1).await.unwrap()[0]
+        .event
+        .aggregate_id;
+    for (attribute, value) in [("max_hit_points", 12), ("hit_points", 10)] {
+        let (status, _) = common::post_json(
+            app(),
+            "/api/v1/characters/modify-attribute",
+            &serde_json::json!({
+                "character_id": character_id,
+                "attribute": attribute,
+                "new_value": value
+            }),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+    }
+
+    // Inventories are created by their first item, written directly.
+    let inventory_id = Uuid::new_v4();
+    let item_added = StoredEvent {
+        event_id: Uuid::new_v4(),
+        aggregate_id: inventory_id,
+        event_type: ITEM_ADDED_EVENT_TYPE.to_owned(),
+        payload: serde_json::to_value(InventoryEventKind::ItemAdded(ItemAdded {
+            inventory_id,
+            item_id: Uuid::new_v4(),
+        }))
+        .unwrap(),
+        sequence_number: 1,
+        correlation_id: Uuid::new_v4(),
+        causation_id: Uuid::new_v4(),
+        occurred_at: Utc::now(),
+        schema_version: 1,
+    };
+    event_repository
+        .append_events(inventory_id, 0, &[item_added])
+        .await
+        .unwrap();
+
+    (character_id, inventory_id)
+}
+
+fn resolve_action(effects: &serde_json::Value) -> serde_json::Value {
+    serde_json::json!({
+        "session_id": Uuid::new_v4(),
+        "world_id": Uuid::new_v4(),
+        "action_type": "skill_check",
+        "difficulty_class": 1,
+        "modifier": 30,
+        "effects": effects
+    })
+}
+
+fn event_ids(json: &serde_json::Value, phase: &str) -> Vec<Uuid> {
+    json[phase]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|id| id.as_str().unwrap().parse().unwrap())
+        .collect()
+}
+
+#[tokio::test]
+async fn test_resolve_action_routes_effects_to_characters_and_inventories() {
+    // Arrange
+    let event_repository = Arc::new(InMemoryEventRepository::new());
+    let read_models = Arc::new(InMemoryReadModelStore::new());
+    let app = || common::build_in_memory_app(event_repository.clone(), read_models.clone());
+    let (character_id, inventory_id) = wounded_character(&event_repository, app).await;
+    let sword_id = Uuid::new_v4();
+
+    // Act
+    let (status, played) = common::post_json(
+        app(),
+        "/api/v1/play/resolve-action",
+        &resolve_action(&serde_json::json!([
+            { "effect_type": "damage", "target_id": character_id, "payload": { "amount": 7 } },
+            { "effect_type": "heal", "target_id": character_id, "payload": { "amount": 20 } },
+            { "effect_type": "damage", "target_id": character_id, "payload": { "amount": 4 } },
+            { "effect_type": "award_xp", "target_id": character_id, "payload": { "amount": 50 } },
+            { "effect_type": "grant_item", "target_id": inventory_id, "payload": { "item_id": sword_id } }
+        ])),
+    )
+    .await;
+
+    // Assert — 10 − 7 = 3, healed to the cap of 12, then 12 − 4 = 8.
+    assert_eq!(status, StatusCode::OK);
+    let (_, character) =
+        common::get_json(app(), &format!("/api/v1/characters/{character_id}")).await;
+    assert_eq!(character["attributes"]["hit_points"], 8);
+    assert_eq!(character["experience"], 50);
+    let (_, inventory) =
+        common::get_json(app(), &format!("/api/v1/inventory/{inventory_id}")).await;
+    let items = inventory["items"].as_array().unwrap();
+    assert!(items.contains(&serde_json::json!(sword_id)));
+
+    let effects_event_id = event_ids(&played, "effects_event_ids")[0];
+    let correlation_id: Uuid = played["correlation_id"].as_str().unwrap().parse().unwrap();
+    let routed: Vec<Uuid> = event_ids(&played, "character_event_ids")
+        .into_iter()
+        .chain(event_ids(&played, "inventory_event_ids"))
+        .collect();
+    assert_eq!(routed.len(), 5);
+    assert!(event_ids(&played, "world_state_event_ids").is_empty());
+    let events = event_repository.read_all_from(1, 1000).await.unwrap();
+    for event_id in routed {
+        let event = &events
+            .iter()
+            .find(|positioned| positioned.event.event_id == event_id)
+            .unwrap()
+            .event;
+        assert_eq!(event.correlation_id, correlation_id);
+        assert_eq!(event.causation_id, effects_event_id);
+    }
+}
+
+#[tokio::test]
+async fn test_resolve_action_persists_nothing_when_an_effect_cannot_be_routed() {
+    // Arrange
+    let event_repository = Arc::new(InMemoryEventRepository::new());
+    let read_models = Arc::new(InMemoryReadModelStore::new());
+    let app = || common::build_in_memory_app(event_repository.clone(), read_models.clone());
+    let (character_id, _) = wounded_character(&event_repository, app).await;
+    let events_before = event_repository.read_all_from(1, 1000).await.unwrap().len();
+
+    // Act — the XP is routable, the damage to a missing character is not.
+    let (status, _) = common::post_json(
+        app(),
+        "/api/v1/play/resolve-action",
+        &resolve_action(&serde_json::json!([
+            { "effect_type": "award_xp", "target_id": character_id, "payload": { "amount": 50 } },
+            { "effect_type": "damage", "target_id": Uuid::new_v4(), "payload": { "amount": 3 } }
+        ])),
+    )
+    .await;
+
+    // Assert
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    let events_after = event_repository.read_all_from(1, 1000).await.unwrap().len();
+    assert_eq!(events_after, events_before);
+    let (_, character) =
+        common::get_json(app(), &format!("/api/v1/characters/{character_id}")).await;
+    assert_eq!(character["experience"], 0);
+}
@@ -0,0 +1,324 @@
+//! Integration tests for combat encounters: initiative, turns, readied
+//! actions, and registration with the campaign run for branching.
+
+mod common;
+
+use std::sync::Arc;
+
+use axum::http::StatusCode;
+use otherworlds_event_store::memory_event_repository::InMemoryEventRepository;
+use otherworlds_event_store::memory_read_model_store::InMemoryReadModelStore;
+use otherworlds_test_support::SequenceRng;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_encounter_turns_round_trip(pool: PgPool) {
+    let encounter_id = Uuid::new_v4();
+    let hero = Uuid::new_v4();
+    let goblin = Uuid::new_v4();
+    let ogre = Uuid::new_v4();
+
+    // Start: initiative rolls 9+2, 14+0, 4+1, then two event IDs.
+    let rng = SequenceRng::new(vec![9, 14, 4, 51, 52, 53, 54, 55, 56, 57, 58]);
+    let app = common::build_test_app_with_rng(pool.clone(), rng);
+    let (status, json) = common::post_json(
+        app,
+        "/api/v1/encounters/start-encounter",
+        &serde_json::json!({
+            "encounter_id": encounter_id,
+            "participants": [
+                { "participant_id": hero, "name": "Hero", "initiative_modifier": 2 },
+                { "participant_id": goblin, "name": "Goblin" },
+                { "participant_id": ogre, "name": "Ogre", "initiative_modifier": 1 }
+            ]
+        }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(json["event_ids"].as_array().unwrap().len(), 2);
+
+    // The goblin acts first and readies an action; the hero delays until
+    // after the ogre.
+    let (status, _) = common::post_json(
+        common::build_test_app(pool.clone()),
+        "/api/v1/encounters/ready-action",
+        &serde_json::json!({ "encounter_id": encounter_id, "trigger": "the hero moves" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let (status, _) = common::post_json(
+        common::build_test_app(pool.clone()),
+        "/api/v1/encounters/delay-turn",
+        &serde_json::json!({ "encounter_id": encounter_id, "after_participant_id": ogre }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    // The ogre's turn: the goblin's readied action fires and is linked.
+    let resolution_id = Uuid::new_v4();
+    for (uri, body) in [
+        (
+            "/api/v1/encounters/trigger-readied-action",
+            serde_json::json!({ "encounter_id": encounter_id, "participant_id": goblin }),
+        ),
+        (
+            "/api/v1/encounters/link-resolution",
+            serde_json::json!({
+                "encounter_id": encounter_id,
+                "participant_id": goblin,
+                "resolution_id": resolution_id
+            }),
+        ),
+        (
+            "/api/v1/encounters/end-turn",
+            serde_json::json!({ "encounter_id": encounter_id }),
+        ),
+    ] {
+        let (status, _) = common::post_json(common::build_test_app(pool.clone()), uri, &body).await;
+        assert_eq!(status, StatusCode::OK, "{uri}");
+    }
+
+    let (status, json) = common::get_json(
+        common::build_test_app(pool.clone()),
+        &format!("/api/v1/encounters/{encounter_id}"),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(json["phase"], "active");
+    assert_eq!(json["round"], 1);
+    assert_eq!(json["current_participant_id"], hero.to_string());
+    assert_eq!(json["turn_order"], serde_json::json!([goblin, ogre, hero]));
+    assert_eq!(json["initiative"][0]["initiative"], 11);
+    assert_eq!(
+        json["actions"][0]["resolution_id"],
+        resolution_id.to_string()
+    );
+
+    // Ending the hero's turn wraps to round 2.
+    let (status, _) = common::post_json(
+        common::build_test_app(pool.clone()),
+        "/api/v1/encounters/end-turn",
+        &serde_json::json!({ "encounter_id": encounter_id }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let (_, json) = common::get_json(
+        common::build_test_app(pool),
+        &format!("/api/v1/encounters/{encounter_id}"),
+    )
+    .await;
+    assert_eq!(json["round"], 2);
+    assert_eq!(json["current_participant_id"], goblin.to_string());
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_encounter_rejects_turn_actions_after_it_ends(pool: PgPool) {
+    let encounter_id = Uuid::new_v4();
+    let rng = SequenceRng::new(vec![12, 51, 52, 53, 54, 55, 56, 57, 58]);
+    let (status, _) = common::post_json(
+        common::build_test_app_with_rng(pool.clone(), rng),
+        "/api/v1/encounters/start-encounter",
+        &serde_json::json!({
+            "encounter_id": encounter_id,
+            "participants": [{ "participant_id": Uuid::new_v4(), "name": "Hero" }]
+        }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (status, _) = common::post_json(
+        common::build_test_app(pool.clone()),
+        "/api/v1/encounters/end-encounter",
+        &serde_json::json!({ "encounter_id": encounter_id, "reason": "enemies fled" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (status, json) = common::post_json(
+        common::build_test_app(pool.clone()),
+        "/api/v1/encounters/end-turn",
+        &serde_json::json!({ "encounter_id": encounter_id }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(json["error"], "validation_error");
+
+    let (status, json) = common::get_json(common::build_test_app(pool), "/api/v1/encounters").await;
+    assert_eq!(status, StatusCode::OK);
+    let summary = json
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|e| e["encounter_id"] == encounter_id.to_string())
+        .unwrap();
+    assert_eq!(summary["phase"], "ended");
+}
+
+#[tokio::test]
+async fn test_encounter_in_run_is_registered_replayed_and_branched() {
+    // Arrange — a run with an encounter started and one turn ended in it.
+    let event_repository = Arc::new(InMemoryEventRepository::new());
+    let read_models = Arc::new(InMemoryReadModelStore::new());
+    let app = || common::build_in_memory_app(event_repository.clone(), read_models.clone());
+    let (status, json) = common::post_json(
+        app(),
+        "/api/v1/sessions/start-campaign-run",
+        &serde_json::json!({ "campaign_id": Uuid::from_u128(1), "seed": 7 }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let run_id: Uuid = json["aggregate_id"].as_str().unwrap().parse().unwrap();
+
+    let encounter_id = Uuid::from_u128(10);
+    let (status, _) = common::post_json_in_run(
+        app(),
+        "/api/v1/encounters/start-encounter",
+        run_id,
+        &serde_json::json!({
+            "encounter_id": encounter_id,
+            "participants": [
+                { "participant_id": Uuid::from_u128(11), "name": "Hero" },
+                { "participant_id": Uuid::from_u128(12), "name": "Goblin" }
+            ]
+        }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let (status, _) = common::post_json_in_run(
+        app(),
+        "/api/v1/encounters/end-turn",
+        run_id,
+        &serde_json::json!({ "encounter_id": encounter_id }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    // Act
+    let (_, run) = common::get_json(app(), &format!("/api/v1/sessions/{run_id}")).await;
+    let (status, replay) = common::post_json(
+        app(),
+        &format!("/api/v1/admin/runs/{run_id}/replay"),
+        &serde_json::json!({}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let (status, _) = common::post_json(
+        app(),
+        "/api/v1/sessions/create-checkpoint",
+        &serde_json::json!({ "run_id": run_id }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let (_, run_after_checkpoint) =
+        common::get_json(app(), &format!("/api/v1/sessions/{run_id}")).await;
+    let checkpoint_id = run_after_checkpoint["checkpoint_ids"][0].clone();
+    let (status, branch) = common::post_json(
+        app(),
+        "/api/v1/sessions/branch-timeline",
+        &serde_json::json!({ "source_run_id": run_id, "from_checkpoint_id": checkpoint_id }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    // Assert — the run tracks the encounter and replays without divergence.
+    assert_eq!(
+        run["registered_aggregates"]["encounter"],
+        encounter_id.to_string()
+    );
+    assert_eq!(replay["commands_replayed"], 2);
+    assert!(replay["divergence"].is_null());
+
+    // The branch holds its own copy of the encounter, at the same turn.
+    let branch_run_id = branch["aggregate_id"].as_str().unwrap();
+    let (_, branch_run) =
+        common::get_json(app(), &format!("/api/v1/sessions/{branch_run_id}")).await;
+    let cloned_id = branch_run["registered_aggregates"]["encounter"]
+        .as_str()
+        .unwrap();
+    assert_ne!(cloned_id, encounter_id.to_string());
+    let (_, original) =
+        common::get_json(app(), &format!("/api/v1/encounters/{encounter_id}")).await;
+    let (status, cloned) =
+        common::get_json(app(), &format!("/api/v1/encounters/{cloned_id}")).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(cloned["turn_order"], original["turn_order"]);
+    assert_eq!(
+        cloned["current_participant_id"],
+        original["current_participant_id"]
+    );
+}
+
+#[tokio::test]
+async fn test_encounter_commands_join_the_run_the_encounter_is_registered_with() {
+    // Arrange — two runs, with an encounter started in the first.
+    let event_repository = Arc::new(InMemoryEventRepository::new());
+    let read_models = Arc::new(InMemoryReadModelStore::new());
+    let app = || common::build_in_memory_app(event_repository.clone(), read_models.clone());
+    let mut run_ids = Vec::new();
+    for seed in [7, 8] {
+        let (status, json) = common::post_json(
+            app(),
+            "/api/v1/sessions/start-campaign-run",
+            &serde_json::json!({ "campaign_id": Uuid::from_u128(1), "seed": seed }),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        run_ids.push(
+            json["aggregate_id"]
+                .as_str()
+                .unwrap()
+                .parse::<Uuid>()
+                .unwrap(),
+        );
+    }
+    let (run_id, other_run_id) = (run_ids[0], run_ids[1]);
+    let encounter_id = Uuid::from_u128(20);
+    let (status, _) = common::post_json_in_run(
+        app(),
+        "/api/v1/encounters/start-encounter",
+        run_id,
+        &serde_json::json!({
+            "encounter_id": encounter_id,
+            "participants": [
+                { "participant_id": Uuid::from_u128(21), "name": "Hero" },
+                { "participant_id": Uuid::from_u128(22), "name": "Goblin" }
+            ]
+        }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    // Act — end a turn without the header, then name the wrong run.
+    let (unscoped_status, _) = common::post_json(
+        app(),
+        "/api/v1/encounters/end-turn",
+        &serde_json::json!({ "encounter_id": encounter_id }),
+    )
+    .await;
+    let (mismatched_status, mismatched) = common::post_json_in_run(
+        app(),
+        "/api/v1/encounters/end-turn",
+        other_run_id,
+        &serde_json::json!({ "encounter_id": encounter_id }),
+    )
+    .await;
+    let (_, replay) = common::post_json(
+        app(),
+        &format!("/api/v1/admin/runs/{run_id}/replay"),
+        &serde_json::json!({}),
+    )
+    .await;
+
+    // Assert — the turn was recorded on the encounter's run and replays.
+    assert_eq!(unscoped_status, StatusCode::OK);
+    assert_eq!(replay["commands_replayed"], 2);
+    assert!(replay["divergence"].is_null());
+    assert_eq!(mismatched_status, StatusCode::BAD_REQUEST);
+    assert!(
+        mismatched["message"]
+            .as_str()
+            .unwrap()
+            .contains(&format!("belongs to campaign run {run_id}"))
+    );
+}
@@ -0,0 +1,79 @@
+//! Integration tests running the full API on the in-memory event store,
+//! without a database.
+
+mod common;
+
+use std::sync::Arc;
+
+use axum::http::StatusCode;
+use otherworlds_core::repository::EventRepository;
+use otherworlds_event_store::memory_event_repository::InMemoryEventRepository;
+use otherworlds_event_store::memory_read_model_store::InMemoryReadModelStore;
+use uuid::Uuid;
+
+#[tokio::test]
+async fn test_character_create_and_list_without_database() {
+    let event_repository = Arc::new(InMemoryEventRepository::new());
+    let read_models = Arc::new(InMemoryReadModelStore::new());
+
+    // POST /api/v1/characters/create
+    let app = common::build_in_memory_app(event_repository.clone(), read_models.clone());
+    let (status, _) = common::post_json(
+        app,
+        "/api/v1/characters/create",
+        &serde_json::json!({ "name": "Alaric" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    let character_id = event_repository.read_all_from(1, 1).await.unwrap()[0]
+        .event
+        .aggregate_id;
+
+    // GET /api/v1/characters — served from in-memory read models
+    let app = common::build_in_memory_app(event_repository.clone(), read_models.clone());
+    let (status, json) = common::get_json(app, "/api/v1/characters").await;
+    assert_eq!(status, StatusCode::OK);
+    let characters = json.as_array().unwrap();
+    assert_eq!(characters.len(), 1);
+    assert_eq!(characters[0]["character_id"], character_id.to_string());
+
+    // GET /api/v1/characters/{character_id}
+    let app = common::build_in_memory_app(event_repository, read_models);
+    let (status, json) = common::get_json(app, &format!("/api/v1/characters/{character_id}")).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(json["name"], "Alaric");
+}
+
+#[tokio::test]
+async fn test_play_loop_without_database() {
+    let event_repository = Arc::new(InMemoryEventRepository::new());
+    let read_models = Arc::new(InMemoryReadModelStore::new());
+    let world_id = Uuid::new_v4();
+
+    // POST /api/v1/play/resolve-action
+    let app = common::build_in_memory_app(event_repository.clone(), read_models.clone());
+    let (status, _) = common::post_json(
+        app,
+        "/api/v1/play/resolve-action",
+        &serde_json::json!({
+            "session_id": Uuid::new_v4(),
+            "world_id": world_id,
+            "action_type": "skill_check",
+            "difficulty_class": 10,
+            "modifier": 0,
+            "effects": [{
+                "effect_type": "set_flag",
+                "payload": { "flag": "vault_revealed", "value": true }
+            }]
+        }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    // GET /api/v1/world/{world_id} — the flag the effect set is visible
+    let app = common::build_in_memory_app(event_repository, read_models);
+    let (status, json) = common::get_json(app, &format!("/api/v1/world/{world_id}")).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(json["flags"]["vault_revealed"], true);
+}
@@ -26,6 +26,7 @@ async fn seed_inventory(pool: &PgPool, inventory_id: Uuid, item_id: Uuid) {
         correlation_id: Uuid::new_v4(),
         causation_id: Uuid::new_v4(),
         occurred_at: Utc::now(),
+        schema_version: 1,
     };
     repo.append_events(inventory_id, 0, &[event]).await.unwrap();
 }
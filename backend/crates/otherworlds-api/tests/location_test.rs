@@ -0,0 +1,232 @@
+//! Integration tests for world locations seeded from campaign Markdown and
+//! the moves made through them.
+
+mod common;
+
+use std::sync::Arc;
+
+use axum::Router;
+use axum::http::StatusCode;
+use otherworlds_core::projection::ReadModelStore;
+use otherworlds_event_store::memory_event_repository::InMemoryEventRepository;
+use otherworlds_event_store::memory_read_model_store::InMemoryReadModelStore;
+use uuid::Uuid;
+
+const HARBOUR_CAMPAIGN: &str = "---
+title: \"Harbour\"
+---
+
+# Scene: docks
+
+Gulls wheel over the quay.
+
+# NPC: captain
+
+- name: Captain Theron
+
+# Location: quay
+
+- name: The Quay
+- exit: market
+- exit: vault (locked)
+- npc: captain
+
+# Location: market
+
+- name: Fish Market
+- exit: quay
+
+# Location: vault
+
+- name: Harbour Vault
+";
+
+/// Ingests, validates and compiles the harbour campaign, then seeds a new
+/// world from it. Returns the campaign and world IDs and the seeding
+/// response.
+async fn seeded_harbour(app: impl Fn() -> Router) -> (Uuid, Uuid, serde_json::Value) {
+    let (status, json) = common::post_json(
+        app(),
+        "/api/v1/content/ingest-campaign",
+        &serde_json::json!({ "source": HARBOUR_CAMPAIGN }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let campaign_id: Uuid = json["aggregate_id"].as_str().unwrap().parse().unwrap();
+    for uri in [
+        "/api/v1/content/validate-campaign",
+        "/api/v1/content/compile-campaign",
+    ] {
+        let (status, _) = common::post_json(
+            app(),
+            uri,
+            &serde_json::json!({ "campaign_id": campaign_id }),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK, "{uri}");
+    }
+
+    let world_id = Uuid::new_v4();
+    let (status, seeded) = common::post_json(
+        app(),
+        "/api/v1/world/seed-from-campaign",
+        &serde_json::json!({ "world_id": world_id, "campaign_id": campaign_id }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    (campaign_id, world_id, seeded)
+}
+
+fn location<'a>(locations: &'a serde_json::Value, location_id: &str) -> &'a serde_json::Value {
+    locations["locations"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|location| location["location_id"] == location_id)
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_seeded_locations_form_a_graph_the_party_moves_through() {
+    // Arrange
+    let event_repository = Arc::new(InMemoryEventRepository::new());
+    let read_models = Arc::new(InMemoryReadModelStore::new());
+    let app = || common::build_in_memory_app(event_repository.clone(), read_models.clone());
+    let (_, world_id, seeded) = seeded_harbour(app).await;
+    // One disposition; three locations, two connections, and two placements.
+    assert_eq!(seeded["event_ids"].as_array().unwrap().len(), 8);
+    let quay_id = seeded["location_entity_ids"]["quay"].as_str().unwrap();
+    let market_id = seeded["location_entity_ids"]["market"].as_str().unwrap();
+    let vault_id = seeded["location_entity_ids"]["vault"].as_str().unwrap();
+    let captain_id = seeded["npc_entity_ids"]["captain"].as_str().unwrap();
+    let locations_uri = format!("/api/v1/world/{world_id}/locations");
+
+    let (status, locations) = common::get_json(app(), &locations_uri).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(locations["locations"].as_array().unwrap().len(), 3);
+    assert_eq!(locations["party_location"], quay_id);
+    let quay = location(&locations, quay_id);
+    assert_eq!(quay["name"], "The Quay");
+    assert_eq!(quay["npcs"], serde_json::json!([captain_id]));
+    let connections = quay["connections"].as_array().unwrap();
+    assert!(
+        connections.contains(&serde_json::json!({ "location_id": market_id, "locked": false }))
+    );
+    assert!(connections.contains(&serde_json::json!({ "location_id": vault_id, "locked": true })));
+
+    // Act
+    let move_to_vault = serde_json::json!({ "world_id": world_id, "location_id": vault_id });
+    let (locked_status, _) =
+        common::post_json(app(), "/api/v1/world/move-party", &move_to_vault).await;
+    let (unlock_status, _) = common::post_json(
+        app(),
+        "/api/v1/world/set-connection-lock",
+        &serde_json::json!({
+            "world_id": world_id,
+            "location_id": vault_id,
+            "other_location_id": quay_id,
+            "locked": false
+        }),
+    )
+    .await;
+    let (moved_status, moved) =
+        common::post_json(app(), "/api/v1/world/move-party", &move_to_vault).await;
+    let (npc_status, _) = common::post_json(
+        app(),
+        "/api/v1/world/move-npc",
+        &serde_json::json!({ "world_id": world_id, "entity_id": captain_id, "location_id": market_id }),
+    )
+    .await;
+
+    // Assert
+    assert_eq!(locked_status, StatusCode::BAD_REQUEST);
+    assert_eq!(unlock_status, StatusCode::OK);
+    assert_eq!(moved_status, StatusCode::OK);
+    assert_eq!(moved["event_ids"].as_array().unwrap().len(), 1);
+    assert_eq!(npc_status, StatusCode::OK);
+    let (_, locations) = common::get_json(app(), &locations_uri).await;
+    assert_eq!(locations["party_location"], vault_id);
+    assert_eq!(
+        location(&locations, vault_id)["connections"],
+        serde_json::json!([{ "location_id": quay_id, "locked": false }])
+    );
+    assert_eq!(
+        location(&locations, market_id)["npcs"],
+        serde_json::json!([captain_id])
+    );
+    assert_eq!(location(&locations, quay_id)["npcs"], serde_json::json!([]));
+}
+
+#[tokio::test]
+async fn test_seeding_again_leaves_the_party_where_it_moved() {
+    // Arrange
+    let event_repository = Arc::new(InMemoryEventRepository::new());
+    let read_models = Arc::new(InMemoryReadModelStore::new());
+    let app = || common::build_in_memory_app(event_repository.clone(), read_models.clone());
+    let (campaign_id, world_id, seeded) = seeded_harbour(app).await;
+    let market_id = seeded["location_entity_ids"]["market"].as_str().unwrap();
+    let (status, _) = common::post_json(
+        app(),
+        "/api/v1/world/move-party",
+        &serde_json::json!({ "world_id": world_id, "location_id": market_id }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    // Act
+    let (status, reseeded) = common::post_json(
+        app(),
+        "/api/v1/world/seed-from-campaign",
+        &serde_json::json!({ "world_id": world_id, "campaign_id": campaign_id }),
+    )
+    .await;
+
+    // Assert
+    assert_eq!(status, StatusCode::OK);
+    assert!(reseeded["event_ids"].as_array().unwrap().is_empty());
+    let (_, locations) =
+        common::get_json(app(), &format!("/api/v1/world/{world_id}/locations")).await;
+    assert_eq!(locations["party_location"], market_id);
+}
+
+#[tokio::test]
+async fn test_locations_of_worlds_already_projected_are_built_on_read() {
+    // Arrange — the world snapshot projection is caught up, as it would be
+    // for worlds that existed before location views did.
+    let event_repository = Arc::new(InMemoryEventRepository::new());
+    let read_models = Arc::new(InMemoryReadModelStore::new());
+    let app = || common::build_in_memory_app(event_repository.clone(), read_models.clone());
+    let (_, world_id, _) = seeded_harbour(app).await;
+    let (status, _) = common::get_json(app(), &format!("/api/v1/world/{world_id}")).await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(
+        read_models
+            .get("world_location_views", world_id)
+            .await
+            .unwrap()
+            .is_none()
+    );
+
+    // Act
+    let (status, locations) =
+        common::get_json(app(), &format!("/api/v1/world/{world_id}/locations")).await;
+
+    // Assert
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(locations["locations"].as_array().unwrap().len(), 3);
+}
+
+#[tokio::test]
+async fn test_get_locations_returns_404_for_unknown_world() {
+    // Arrange
+    let event_repository = Arc::new(InMemoryEventRepository::new());
+    let read_models = Arc::new(InMemoryReadModelStore::new());
+    let app = common::build_in_memory_app(event_repository, read_models);
+
+    // Act
+    let (status, _) =
+        common::get_json(app, &format!("/api/v1/world/{}/locations", Uuid::new_v4())).await;
+
+    // Assert
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
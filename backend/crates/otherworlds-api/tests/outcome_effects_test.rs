@@ -0,0 +1,99 @@
+//! Integration tests for effects keyed by the check's outcome.
+
+mod common;
+
+use std::sync::Arc;
+
+use axum::http::StatusCode;
+use otherworlds_core::repository::EventRepository;
+use otherworlds_event_store::memory_event_repository::InMemoryEventRepository;
+use otherworlds_event_store::memory_read_model_store::InMemoryReadModelStore;
+use uuid::Uuid;
+
+/// A 1d4 check against DC 5 always totals 1–4, which is a partial success.
+fn partial_success_action(effects: &serde_json::Value) -> serde_json::Value {
+    let mut action = serde_json::json!({
+        "session_id": Uuid::new_v4(),
+        "world_id": Uuid::new_v4(),
+        "action_type": "skill_check",
+        "difficulty_class": 5,
+        "modifier": 0,
+        "roll_expression": "1d4",
+        "outcome_effects": {
+            "failure": [{
+                "effect_type": "shift_disposition",
+                "target_id": Uuid::from_u128(9),
+                "payload": { "delta": -3 }
+            }],
+            "success": [{
+                "effect_type": "shift_disposition",
+                "target_id": Uuid::from_u128(9),
+                "payload": { "delta": 9 }
+            }],
+            "partial_success": { "fallback": "success", "strength_percent": 50 }
+        }
+    });
+    action["effects"] = effects.clone();
+    action
+}
+
+#[tokio::test]
+async fn test_resolve_action_produces_the_branch_for_the_outcome() {
+    // Arrange
+    let event_repository = Arc::new(InMemoryEventRepository::new());
+    let read_models = Arc::new(InMemoryReadModelStore::new());
+    let app = || common::build_in_memory_app(event_repository.clone(), read_models.clone());
+
+    // Act
+    let (status, played) = common::post_json(
+        app(),
+        "/api/v1/play/resolve-action",
+        &partial_success_action(&serde_json::json!([])),
+    )
+    .await;
+
+    // Assert
+    assert_eq!(status, StatusCode::OK);
+    let resolution_id = played["resolution_id"].as_str().unwrap();
+    let (_, resolution) = common::get_json(app(), &format!("/api/v1/rules/{resolution_id}")).await;
+    assert_eq!(resolution["check_result"]["outcome"], "partial_success");
+    let effects = resolution["effects"].as_array().unwrap();
+    assert_eq!(effects.len(), 1);
+    assert_eq!(effects[0]["effect_type"], "shift_disposition");
+    assert_eq!(effects[0]["payload"]["delta"], 4);
+    assert_eq!(
+        resolution["fired_tier"],
+        serde_json::json!({
+            "outcome": "partial_success",
+            "branch": "success",
+            "strength_percent": 50
+        })
+    );
+}
+
+#[tokio::test]
+async fn test_resolve_action_rejects_effects_alongside_an_outcome_table() {
+    // Arrange
+    let event_repository = Arc::new(InMemoryEventRepository::new());
+    let read_models = Arc::new(InMemoryReadModelStore::new());
+    let app = || common::build_in_memory_app(event_repository.clone(), read_models.clone());
+
+    // Act
+    let (status, json) = common::post_json(
+        app(),
+        "/api/v1/play/resolve-action",
+        &partial_success_action(&serde_json::json!([{
+            "effect_type": "heal",
+            "target_id": Uuid::from_u128(9),
+            "payload": { "amount": 1 }
+        }])),
+    )
+    .await;
+
+    // Assert
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    let message = json["message"].as_str().unwrap();
+    assert!(message.contains("not both"), "{message}");
+    let events = event_repository.read_all_from(1, 1000).await.unwrap();
+    assert!(events.is_empty());
+}
@@ -0,0 +1,223 @@
+//! Integration tests for the run replay verifier: replaying a run's recorded
+//! commands must reproduce its stored events, and any tampering with the
+//! stored stream is reported as the first divergence.
+
+mod common;
+
+use std::sync::Arc;
+
+use axum::http::StatusCode;
+use otherworlds_core::repository::EventRepository;
+use otherworlds_event_store::memory_event_repository::InMemoryEventRepository;
+use otherworlds_event_store::memory_read_model_store::InMemoryReadModelStore;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+/// Starts a run, plays a character creation and two actions in it, and
+/// returns the run ID.
+async fn play_run(app: impl Fn() -> axum::Router) -> Uuid {
+    let (status, json) = common::post_json(
+        app(),
+        "/api/v1/sessions/start-campaign-run",
+        &serde_json::json!({ "campaign_id": Uuid::from_u128(1), "seed": 7 }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let run_id: Uuid = json["aggregate_id"].as_str().unwrap().parse().unwrap();
+
+    let (status, _) = common::post_json_in_run(
+        app(),
+        "/api/v1/characters/create",
+        run_id,
+        &serde_json::json!({ "name": "Alaric" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    for _ in 0..2 {
+        let (status, _) = common::post_json_in_run(
+            app(),
+            "/api/v1/play/resolve-action",
+            run_id,
+            &serde_json::json!({
+                "session_id": Uuid::from_u128(2),
+                "world_id": Uuid::from_u128(3),
+                "action_type": "skill_check",
+                "difficulty_class": 12,
+                "modifier": 2,
+                "effects": [{ "effect_type": "apply_status", "target_id": Uuid::from_u128(9), "payload": { "status": "winded" } }]
+            }),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+    }
+
+    run_id
+}
+
+fn replay_uri(run_id: Uuid) -> String {
+    format!("/api/v1/admin/runs/{run_id}/replay")
+}
+
+#[tokio::test]
+async fn test_replay_of_untouched_run_reports_no_divergence() {
+    // Arrange
+    let event_repository = Arc::new(InMemoryEventRepository::new());
+    let read_models = Arc::new(InMemoryReadModelStore::new());
+    let app = || common::build_in_memory_app(event_repository.clone(), read_models.clone());
+    let run_id = play_run(app).await;
+
+    // Act
+    let (status, json) =
+        common::post_json(app(), &replay_uri(run_id), &serde_json::json!({})).await;
+
+    // Assert
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(json["run_id"], run_id.to_string());
+    assert_eq!(json["commands_replayed"], 3);
+    assert!(json["events_compared"].as_u64().unwrap() > 3);
+    assert!(json["divergence"].is_null());
+}
+
+#[tokio::test]
+async fn test_replay_backfills_aggregates_written_outside_the_run() {
+    // Arrange — the character is created outside the run, then modified in it.
+    let event_repository = Arc::new(InMemoryEventRepository::new());
+    let read_models = Arc::new(InMemoryReadModelStore::new());
+    let app = || common::build_in_memory_app(event_repository.clone(), read_models.clone());
+    let run_id = play_run(app).await;
+    let (status, _) = common::post_json(
+        app(),
+        "/api/v1/characters/create",
+        &serde_json::json!({ "name": "Brenna" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let character_id = event_repository
+        .read_all_from(1, 1_000)
+        .await
+        .unwrap()
+        .into_iter()
+        .last()
+        .unwrap()
+        .event
+        .aggregate_id;
+    let (status, _) = common::post_json_in_run(
+        app(),
+        "/api/v1/characters/award-experience",
+        run_id,
+        &serde_json::json!({ "character_id": character_id, "amount": 50 }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    // Act
+    let (status, json) =
+        common::post_json(app(), &replay_uri(run_id), &serde_json::json!({})).await;
+
+    // Assert
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(json["commands_replayed"], 4);
+    assert!(json["divergence"].is_null());
+}
+
+#[tokio::test]
+async fn test_replay_separates_commands_sharing_a_correlation_id() {
+    // Arrange — two commands in a row reuse the same correlation ID.
+    let event_repository = Arc::new(InMemoryEventRepository::new());
+    let read_models = Arc::new(InMemoryReadModelStore::new());
+    let app = || common::build_in_memory_app(event_repository.clone(), read_models.clone());
+    let run_id = play_run(app).await;
+    let correlation_id = Uuid::new_v4();
+    for _ in 0..2 {
+        let (status, _) = common::post_json_in_run_correlated(
+            app(),
+            "/api/v1/play/resolve-action",
+            run_id,
+            correlation_id,
+            &serde_json::json!({
+                "session_id": Uuid::from_u128(2),
+                "world_id": Uuid::from_u128(3),
+                "action_type": "skill_check",
+                "difficulty_class": 12,
+                "modifier": 2
+            }),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+    }
+
+    // Act
+    let (status, json) =
+        common::post_json(app(), &replay_uri(run_id), &serde_json::json!({})).await;
+
+    // Assert
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(json["commands_replayed"], 5);
+    assert!(json["divergence"].is_null(), "{json}");
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_replay_reports_first_tampered_event(pool: PgPool) {
+    // Arrange — rewrite the stored roll of the first check.
+    let run_id = play_run(|| common::build_test_app(pool.clone())).await;
+    let (tampered_id, aggregate_id, sequence_number): (Uuid, Uuid, i64) = sqlx::query_as(
+        "SELECT event_id, aggregate_id, sequence_number FROM domain_events \
+         WHERE event_type = 'rules.check_resolved' ORDER BY global_position LIMIT 1",
+    )
+    .fetch_one(&pool)
+    .await
+    .unwrap();
+    sqlx::query(
+        "UPDATE domain_events \
+         SET payload = jsonb_set(payload, '{CheckResolved,natural_roll}', '99') \
+         WHERE event_id = $1",
+    )
+    .bind(tampered_id)
+    .execute(&pool)
+    .await
+    .unwrap();
+
+    // Act
+    let (status, json) = common::post_json(
+        common::build_test_app(pool),
+        &replay_uri(run_id),
+        &serde_json::json!({}),
+    )
+    .await;
+
+    // Assert
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(json["commands_replayed"], 1);
+    let divergence = &json["divergence"];
+    assert_eq!(divergence["command_index"], 1);
+    assert_eq!(divergence["command_type"], "play.resolve_action");
+    assert_eq!(divergence["kind"], "event_mismatch");
+    assert_eq!(divergence["aggregate_id"], aggregate_id.to_string());
+    assert_eq!(divergence["sequence_number"], sequence_number);
+    assert_eq!(
+        divergence["expected"]["payload"]["CheckResolved"]["natural_roll"],
+        99
+    );
+    assert_ne!(
+        divergence["actual"]["payload"]["CheckResolved"]["natural_roll"],
+        99
+    );
+}
+
+#[tokio::test]
+async fn test_replay_of_unknown_run_returns_404() {
+    // Arrange
+    let app = common::build_in_memory_app(
+        Arc::new(InMemoryEventRepository::new()),
+        Arc::new(InMemoryReadModelStore::new()),
+    );
+
+    // Act
+    let (status, json) =
+        common::post_json(app, &replay_uri(Uuid::new_v4()), &serde_json::json!({})).await;
+
+    // Assert
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(json["error"], "aggregate_not_found");
+}
@@ -86,6 +86,7 @@ async fn test_rules_full_resolution_lifecycle(pool: PgPool) {
             "resolution_id": resolution_id,
             "effects": [{
                 "effect_type": "damage",
+                "target_id": Uuid::from_u128(9),
                 "payload": { "amount": 8 }
             }]
         }),
@@ -302,3 +303,233 @@ async fn test_rules_command_on_archived_returns_error(pool: PgPool) {
     assert_ne!(status, StatusCode::OK);
     assert!(json.get("error").is_some());
 }
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_rules_roll_expression_records_every_die(pool: PgPool) {
+    // Arrange
+    let resolution_id = Uuid::new_v4();
+    let (status, _) = common::post_json(
+        common::build_test_app(pool.clone()),
+        "/api/v1/rules/declare-intent",
+        &serde_json::json!({
+            "resolution_id": resolution_id,
+            "intent_id": Uuid::new_v4(),
+            "action_type": "attack",
+            "difficulty_class": 12,
+            "modifier": 1,
+            "roll_expression": "4d6kh3+2"
+        }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    // Act — four d6 faces, then the check and event IDs.
+    let rng = SequenceRng::new(vec![3, 6, 1, 5, 42, 99, 7, 13, 51, 52, 53, 54]);
+    let (status, _) = common::post_json(
+        common::build_test_app_with_rng(pool.clone(), rng),
+        "/api/v1/rules/resolve-check",
+        &serde_json::json!({ "resolution_id": resolution_id }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    // Assert
+    let (status, json) = common::get_json(
+        common::build_test_app(pool),
+        &format!("/api/v1/rules/{resolution_id}"),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(json["intent"]["roll_expression"], "4d6kh3+2");
+    let check = &json["check_result"];
+    assert_eq!(check["natural_roll"], 14);
+    assert_eq!(check["modifier"], 3);
+    assert_eq!(check["total"], 17);
+    assert_eq!(check["outcome"], "success");
+    assert_eq!(check["kept_roll"], 0);
+    let dice = check["rolls"][0]["dice"].as_array().unwrap();
+    assert_eq!(dice.len(), 4);
+    assert_eq!(dice[2]["value"], 1);
+    assert_eq!(dice[2]["kept"], false);
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_rules_declare_intent_with_invalid_roll_expression_returns_400(pool: PgPool) {
+    // Act
+    let (status, json) = common::post_json(
+        common::build_test_app(pool),
+        "/api/v1/rules/declare-intent",
+        &serde_json::json!({
+            "resolution_id": Uuid::new_v4(),
+            "intent_id": Uuid::new_v4(),
+            "action_type": "attack",
+            "difficulty_class": 12,
+            "modifier": 0,
+            "roll_expression": "1d20+@luck"
+        }),
+    )
+    .await;
+
+    // Assert
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(json["error"], "validation_error");
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_rules_advantage_records_both_rolls_and_keeps_the_better(pool: PgPool) {
+    // Arrange
+    let resolution_id = Uuid::new_v4();
+    let (status, _) = common::post_json(
+        common::build_test_app(pool.clone()),
+        "/api/v1/rules/declare-intent",
+        &serde_json::json!({
+            "resolution_id": resolution_id,
+            "intent_id": Uuid::new_v4(),
+            "action_type": "attack",
+            "difficulty_class": 12,
+            "modifier": 2,
+            "roll_mode": "advantage"
+        }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    // Act — a natural 1 then a 15, then the check and event IDs.
+    let rng = SequenceRng::new(vec![1, 15, 42, 99, 7, 13, 51, 52, 53, 54]);
+    let (status, _) = common::post_json(
+        common::build_test_app_with_rng(pool.clone(), rng),
+        "/api/v1/rules/resolve-check",
+        &serde_json::json!({ "resolution_id": resolution_id }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    // Assert
+    let (status, json) = common::get_json(
+        common::build_test_app(pool),
+        &format!("/api/v1/rules/{resolution_id}"),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(json["intent"]["roll_mode"], "advantage");
+    let check = &json["check_result"];
+    assert_eq!(check["rolls"][0]["natural_roll"], 1);
+    assert_eq!(check["rolls"][1]["natural_roll"], 15);
+    assert_eq!(check["kept_roll"], 1);
+    assert_eq!(check["natural_roll"], 15);
+    assert_eq!(check["outcome"], "success");
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_rules_declare_intent_with_too_many_rolls_returns_400(pool: PgPool) {
+    // Act
+    let (status, json) = common::post_json(
+        common::build_test_app(pool),
+        "/api/v1/rules/declare-intent",
+        &serde_json::json!({
+            "resolution_id": Uuid::new_v4(),
+            "intent_id": Uuid::new_v4(),
+            "action_type": "attack",
+            "difficulty_class": 12,
+            "modifier": 0,
+            "roll_mode": { "keep_best": 50 }
+        }),
+    )
+    .await;
+
+    // Assert
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(json["error"], "validation_error");
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_rules_percentile_ruleset_rolls_under_and_names_its_tier(pool: PgPool) {
+    // Arrange
+    let resolution_id = Uuid::new_v4();
+    let (status, _) = common::post_json(
+        common::build_test_app(pool.clone()),
+        "/api/v1/rules/declare-intent",
+        &serde_json::json!({
+            "resolution_id": resolution_id,
+            "intent_id": Uuid::new_v4(),
+            "action_type": "skill_check",
+            "difficulty_class": 60,
+            "modifier": 0,
+            "ruleset": "percentile"
+        }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    // Act — a 12 on the d100, then the check and event IDs.
+    let rng = SequenceRng::new(vec![12, 42, 99, 7, 13, 51, 52, 53, 54]);
+    let (status, _) = common::post_json(
+        common::build_test_app_with_rng(pool.clone(), rng),
+        "/api/v1/rules/resolve-check",
+        &serde_json::json!({ "resolution_id": resolution_id }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    // Assert
+    let (status, json) = common::get_json(
+        common::build_test_app(pool),
+        &format!("/api/v1/rules/{resolution_id}"),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(json["intent"]["ruleset"], "percentile");
+    assert_eq!(json["intent"]["roll_expression"], "1d100");
+    let check = &json["check_result"];
+    assert_eq!(check["natural_roll"], 12);
+    assert_eq!(check["outcome"], "critical_success");
+    assert_eq!(check["tier"], "extreme success");
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_rules_opposed_check_records_both_rolls_and_the_winner(pool: PgPool) {
+    // Arrange
+    let resolution_id = Uuid::new_v4();
+    let (status, _) = common::post_json(
+        common::build_test_app(pool.clone()),
+        "/api/v1/rules/declare-opposed-intent",
+        &serde_json::json!({
+            "resolution_id": resolution_id,
+            "intent_id": Uuid::new_v4(),
+            "action_type": "grapple",
+            "skill": "athletics",
+            "actor": { "modifier": 2 },
+            "opponent": { "modifier": 4, "roll_mode": "disadvantage" }
+        }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    // Act — the actor rolls 16; the opponent rolls 18 and 5 and keeps the 5.
+    let rng = SequenceRng::new(vec![16, 18, 5, 42, 99, 7, 13, 51, 52, 53, 54]);
+    let (status, _) = common::post_json(
+        common::build_test_app_with_rng(pool.clone(), rng),
+        "/api/v1/rules/resolve-check",
+        &serde_json::json!({ "resolution_id": resolution_id }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    // Assert
+    let (status, json) = common::get_json(
+        common::build_test_app(pool),
+        &format!("/api/v1/rules/{resolution_id}"),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(json["phase"], "check_resolved");
+    assert_eq!(json["opposed_intent"]["tie_break"], "defender");
+    let result = &json["opposed_result"];
+    assert_eq!(result["actor"]["total"], 18);
+    assert_eq!(result["opponent"]["rolls"].as_array().unwrap().len(), 2);
+    assert_eq!(result["opponent"]["kept_roll"], 1);
+    assert_eq!(result["opponent"]["total"], 9);
+    assert_eq!(result["margin"], 9);
+    assert_eq!(result["outcome"], "success");
+    assert_eq!(result["winner"], "actor");
+}
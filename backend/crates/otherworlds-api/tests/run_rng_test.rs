@@ -0,0 +1,128 @@
+//! Integration tests for per-run RNG routing: commands sent on behalf of a
+//! campaign run draw from that run's seeded RNG, so re-executing the same
+//! commands reproduces the same dice rolls and UUIDs.
+
+mod common;
+
+use std::sync::Arc;
+
+use axum::http::StatusCode;
+use otherworlds_core::repository::EventRepository;
+use otherworlds_event_store::memory_event_repository::InMemoryEventRepository;
+use otherworlds_event_store::memory_read_model_store::InMemoryReadModelStore;
+use otherworlds_test_support::SequenceRng;
+use uuid::Uuid;
+
+/// The parts of a stored event that must be identical across replays.
+/// Correlation IDs are excluded, including the one inside each recorded
+/// command: they come from the request, not the run.
+type EventFingerprint = (Uuid, Uuid, String, serde_json::Value);
+
+/// Starts a run with `seed`, plays two actions in it, and returns every
+/// event in the log.
+async fn play_run(seed: u64) -> Vec<EventFingerprint> {
+    let event_repository = Arc::new(InMemoryEventRepository::new());
+    let read_models = Arc::new(InMemoryReadModelStore::new());
+    let app = || {
+        common::build_in_memory_app_with_rng(
+            event_repository.clone(),
+            read_models.clone(),
+            SequenceRng::new((1..=100).collect()),
+        )
+    };
+    let campaign_id = Uuid::from_u128(1);
+
+    let (status, json) = common::post_json(
+        app(),
+        "/api/v1/sessions/start-campaign-run",
+        &serde_json::json!({ "campaign_id": campaign_id, "seed": seed }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let run_id: Uuid = json["aggregate_id"].as_str().unwrap().parse().unwrap();
+
+    for _ in 0..2 {
+        let (status, _) = common::post_json_in_run(
+            app(),
+            "/api/v1/play/resolve-action",
+            run_id,
+            &serde_json::json!({
+                "session_id": Uuid::from_u128(2),
+                "world_id": Uuid::from_u128(3),
+                "action_type": "skill_check",
+                "difficulty_class": 12,
+                "modifier": 2,
+                "effects": [{ "effect_type": "apply_status", "target_id": Uuid::from_u128(9), "payload": { "status": "winded" } }]
+            }),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+    }
+
+    event_repository
+        .read_all_from(1, 1_000)
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|positioned| {
+            let mut event = positioned.event;
+            if let Some(command) = event.payload["CommandRecorded"]["command"].as_object_mut() {
+                command.remove("correlation_id");
+            }
+            (
+                event.event_id,
+                event.aggregate_id,
+                event.event_type,
+                event.payload,
+            )
+        })
+        .collect()
+}
+
+#[tokio::test]
+async fn test_replaying_run_commands_reproduces_identical_events() {
+    // Act
+    let first = play_run(7).await;
+    let second = play_run(7).await;
+
+    // Assert
+    assert!(first.iter().any(|e| e.2 == "rules.check_resolved"));
+    assert!(first.iter().any(|e| e.2 == "session.rng_advanced"));
+    assert_eq!(first, second);
+}
+
+#[tokio::test]
+async fn test_different_seeds_produce_different_streams() {
+    // Act
+    let first = play_run(7).await;
+    let second = play_run(8).await;
+
+    // Assert — the run itself comes from the shared RNG and matches; every
+    // event drawn from the run's RNG differs.
+    assert_eq!(first[0].1, second[0].1);
+    let first_ids: Vec<Uuid> = first[1..].iter().map(|e| e.0).collect();
+    let second_ids: Vec<Uuid> = second[1..].iter().map(|e| e.0).collect();
+    assert!(first_ids.iter().all(|id| !second_ids.contains(id)));
+}
+
+#[tokio::test]
+async fn test_command_for_unknown_run_returns_404() {
+    // Arrange
+    let app = common::build_in_memory_app(
+        Arc::new(InMemoryEventRepository::new()),
+        Arc::new(InMemoryReadModelStore::new()),
+    );
+
+    // Act
+    let (status, json) = common::post_json_in_run(
+        app,
+        "/api/v1/characters/create",
+        Uuid::new_v4(),
+        &serde_json::json!({ "name": "Alaric" }),
+    )
+    .await;
+
+    // Assert
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_eq!(json["error"], "aggregate_not_found");
+}
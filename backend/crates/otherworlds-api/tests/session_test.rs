@@ -60,12 +60,14 @@ async fn test_session_create_checkpoint_round_trip(pool: PgPool) {
     assert_eq!(json["aggregate_id"], run_id.to_string());
     assert_eq!(json["event_ids"].as_array().unwrap().len(), 1);
 
-    // GET — verify version incremented
+    // GET — verify version incremented by CheckpointCreated, the
+    // CommandRecorded event logging the command, and the RngAdvanced event
+    // recording the run's RNG position
     let app = common::build_test_app(pool);
     let (status, json) = common::get_json(app, &format!("/api/v1/sessions/{run_id}")).await;
 
     assert_eq!(status, StatusCode::OK);
-    assert_eq!(json["version"], 2);
+    assert_eq!(json["version"], 4);
 }
 
 #[sqlx::test(migrations = "../../migrations")]
@@ -122,10 +124,11 @@ async fn test_session_archive_campaign_run_round_trip(pool: PgPool) {
     assert_eq!(status, StatusCode::OK);
     assert_eq!(json["event_ids"].as_array().unwrap().len(), 1);
 
+    // CampaignRunArchived plus CommandRecorded and RngAdvanced
     let app = common::build_test_app(pool);
     let (status, json) = common::get_json(app, &format!("/api/v1/sessions/{run_id}")).await;
     assert_eq!(status, StatusCode::OK);
-    assert_eq!(json["version"], 2);
+    assert_eq!(json["version"], 4);
 }
 
 #[sqlx::test(migrations = "../../migrations")]
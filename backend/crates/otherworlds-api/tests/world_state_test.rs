@@ -31,10 +31,72 @@ async fn test_world_apply_effect_round_trip(pool: PgPool) {
 
     assert_eq!(status, StatusCode::OK);
     assert_eq!(json["world_id"], world_id.to_string());
-    assert_eq!(json["facts"], serde_json::json!(["quest_complete"]));
+    assert_eq!(
+        json["facts"],
+        serde_json::json!({ "quest_complete": { "type": "bool", "value": true } })
+    );
     assert_eq!(json["version"], 1);
 }
 
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_world_facts_assert_and_retract_round_trip(pool: PgPool) {
+    let world_id = Uuid::new_v4();
+    let ruler_id = Uuid::new_v4();
+
+    // POST /api/v1/world/assert-fact — the second "ruler" replaces the first
+    for (key, value) in [
+        (
+            "ruler",
+            serde_json::json!({ "type": "string", "value": "nobody" }),
+        ),
+        (
+            "ruler",
+            serde_json::json!({ "type": "entity", "value": ruler_id }),
+        ),
+        (
+            "ferry_fare",
+            serde_json::json!({ "type": "number", "value": 3 }),
+        ),
+        (
+            "bridge_standing",
+            serde_json::json!({ "type": "bool", "value": true }),
+        ),
+    ] {
+        let (status, _) = common::post_json(
+            common::build_test_app(pool.clone()),
+            "/api/v1/world/assert-fact",
+            &serde_json::json!({ "world_id": world_id, "key": key, "value": value }),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+    }
+
+    // POST /api/v1/world/retract-fact
+    let (status, _) = common::post_json(
+        common::build_test_app(pool.clone()),
+        "/api/v1/world/retract-fact",
+        &serde_json::json!({ "world_id": world_id, "key": "bridge_standing" }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    // GET /api/v1/world/{world_id}
+    let (status, json) = common::get_json(
+        common::build_test_app(pool),
+        &format!("/api/v1/world/{world_id}"),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(
+        json["facts"],
+        serde_json::json!({
+            "ruler": { "type": "entity", "value": ruler_id },
+            "ferry_fare": { "type": "number", "value": 3 }
+        })
+    );
+    assert_eq!(json["version"], 5);
+}
+
 #[sqlx::test(migrations = "../../migrations")]
 async fn test_world_set_flag_round_trip(pool: PgPool) {
     let app = common::build_test_app(pool.clone());
@@ -203,7 +265,8 @@ async fn test_world_update_disposition_round_trip(pool: PgPool) {
         "/api/v1/world/update-disposition",
         &serde_json::json!({
             "world_id": world_id,
-            "entity_id": entity_id
+            "entity_id": entity_id,
+            "delta": 25
         }),
     )
     .await;
@@ -212,11 +275,9 @@ async fn test_world_update_disposition_round_trip(pool: PgPool) {
     let app = common::build_test_app(pool);
     let (status, json) = common::get_json(app, &format!("/api/v1/world/{world_id}")).await;
     assert_eq!(status, StatusCode::OK);
-    let disposition_ids = json["disposition_entity_ids"].as_array().unwrap();
-    assert!(
-        disposition_ids
-            .iter()
-            .any(|id| id == &serde_json::json!(entity_id.to_string()))
+    assert_eq!(
+        json["dispositions"][entity_id.to_string()],
+        serde_json::json!({ "score": 25, "band": "friendly" })
     );
     assert_eq!(json["version"], 2);
 }
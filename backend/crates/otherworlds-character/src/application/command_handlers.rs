@@ -11,14 +11,16 @@ use otherworlds_core::error::DomainError;
 use otherworlds_core::event::DomainEvent;
 use otherworlds_core::repository::{EventRepository, StoredEvent};
 use otherworlds_core::rng::DeterministicRng;
+use otherworlds_core::snapshot::{load_with_snapshot, save_snapshot_if_due};
 use tracing::instrument;
 use uuid::Uuid;
 
 use crate::domain::aggregates::Character;
 use crate::domain::commands::{
-    ArchiveCharacter, AwardExperience, CreateCharacter, ModifyAttribute,
+    ArchiveCharacter, AwardExperience, CreateCharacter, GrantProficiency, ModifyAttribute,
 };
 use crate::domain::events::{CharacterEvent, CharacterEventKind};
+use crate::domain::upcasters;
 
 fn to_stored_event(event: &CharacterEvent) -> StoredEvent {
     let meta = event.metadata();
@@ -31,6 +33,7 @@ fn to_stored_event(event: &CharacterEvent) -> StoredEvent {
         correlation_id: meta.correlation_id,
         causation_id: meta.causation_id,
         occurred_at: meta.occurred_at,
+        schema_version: meta.schema_version,
     }
 }
 
@@ -43,12 +46,36 @@ pub(crate) fn reconstitute(
     character_id: Uuid,
     existing_events: &[StoredEvent],
 ) -> Result<Character, DomainError> {
-    let mut character = Character::new(character_id);
-    for stored in existing_events {
-        let kind: CharacterEventKind =
-            serde_json::from_value(stored.payload.clone()).map_err(|e| {
-                DomainError::Infrastructure(format!("event deserialization failed: {e}"))
-            })?;
+    replay(Character::new(character_id), existing_events)
+}
+
+/// Loads a `Character`, starting from its latest snapshot when one is
+/// available and replaying only the events recorded after it.
+///
+/// Returns `None` if the aggregate has neither a snapshot nor any events.
+///
+/// # Errors
+///
+/// Returns `DomainError` if loading fails or event deserialization fails.
+pub(crate) async fn load(
+    character_id: Uuid,
+    repo: &dyn EventRepository,
+) -> Result<Option<Character>, DomainError> {
+    let (restored, events) = load_with_snapshot::<Character>(repo, character_id).await?;
+    match restored {
+        Some(character) => replay(character, &events).map(Some),
+        None if events.is_empty() => Ok(None),
+        None => reconstitute(character_id, &events).map(Some),
+    }
+}
+
+/// Applies stored events on top of an existing `Character` state.
+fn replay(mut character: Character, events: &[StoredEvent]) -> Result<Character, DomainError> {
+    for stored in events {
+        let payload = upcasters::registry().upcast(stored)?;
+        let kind: CharacterEventKind = serde_json::from_value(payload).map_err(|e| {
+            DomainError::Infrastructure(format!("event deserialization failed: {e}"))
+        })?;
         let event = CharacterEvent {
             metadata: otherworlds_core::event::EventMetadata {
                 event_id: stored.event_id,
@@ -58,6 +85,7 @@ pub(crate) fn reconstitute(
                 correlation_id: stored.correlation_id,
                 causation_id: stored.causation_id,
                 occurred_at: stored.occurred_at,
+                schema_version: upcasters::current_schema_version(&stored.event_type),
             },
             kind,
         };
@@ -108,6 +136,7 @@ pub async fn handle_create_character(
 
     repo.append_events(character_id, character.version, &stored_events)
         .await?;
+    save_snapshot_if_due(repo, character, clock).await;
 
     Ok(stored_events)
 }
@@ -131,11 +160,9 @@ pub async fn handle_modify_attribute(
         ));
     }
 
-    let existing_events = repo.load_events(command.character_id).await?;
-    if existing_events.is_empty() {
-        return Err(DomainError::AggregateNotFound(command.character_id));
-    }
-    let mut character = reconstitute(command.character_id, &existing_events)?;
+    let mut character = load(command.character_id, repo)
+        .await?
+        .ok_or(DomainError::AggregateNotFound(command.character_id))?;
 
     if character.archived {
         return Err(DomainError::Validation("character is archived".into()));
@@ -157,11 +184,12 @@ pub async fn handle_modify_attribute(
     let stored_events: Vec<StoredEvent> = character
         .uncommitted_events()
         .iter()
-        .map(to_stored_event)
+        .map(|event| to_stored_event(event).caused_by(command.causation_id))
         .collect();
 
     repo.append_events(command.character_id, character.version, &stored_events)
         .await?;
+    save_snapshot_if_due(repo, character, clock).await;
 
     Ok(stored_events)
 }
@@ -185,11 +213,9 @@ pub async fn handle_award_experience(
         ));
     }
 
-    let existing_events = repo.load_events(command.character_id).await?;
-    if existing_events.is_empty() {
-        return Err(DomainError::AggregateNotFound(command.character_id));
-    }
-    let mut character = reconstitute(command.character_id, &existing_events)?;
+    let mut character = load(command.character_id, repo)
+        .await?
+        .ok_or(DomainError::AggregateNotFound(command.character_id))?;
 
     if character.archived {
         return Err(DomainError::Validation("character is archived".into()));
@@ -207,6 +233,58 @@ pub async fn handle_award_experience(
         );
     }
 
+    let stored_events: Vec<StoredEvent> = character
+        .uncommitted_events()
+        .iter()
+        .map(|event| to_stored_event(event).caused_by(command.causation_id))
+        .collect();
+
+    repo.append_events(command.character_id, character.version, &stored_events)
+        .await?;
+    save_snapshot_if_due(repo, character, clock).await;
+
+    Ok(stored_events)
+}
+
+/// Handles the `GrantProficiency` command: reconstitutes the aggregate,
+/// grants the proficiency, and persists the resulting events.
+///
+/// # Errors
+///
+/// Returns `DomainError::Validation` if the skill is blank, the character is
+/// archived, or it is already proficient in the skill.
+/// Returns `DomainError` if event loading or appending fails.
+#[instrument(skip(clock, rng, repo), fields(character_id = %command.character_id, correlation_id = %command.correlation_id))]
+pub async fn handle_grant_proficiency(
+    command: &GrantProficiency,
+    clock: &dyn Clock,
+    rng: &Mutex<dyn DeterministicRng + Send>,
+    repo: &dyn EventRepository,
+) -> Result<Vec<StoredEvent>, DomainError> {
+    if command.skill.trim().is_empty() {
+        return Err(DomainError::Validation("skill must not be empty".into()));
+    }
+
+    let mut character = load(command.character_id, repo)
+        .await?
+        .ok_or(DomainError::AggregateNotFound(command.character_id))?;
+
+    if character.archived {
+        return Err(DomainError::Validation("character is archived".into()));
+    }
+
+    {
+        let mut rng_guard = rng
+            .lock()
+            .map_err(|e| DomainError::Infrastructure(format!("RNG mutex poisoned: {e}")))?;
+        character.grant_proficiency(
+            command.skill.clone(),
+            command.correlation_id,
+            clock,
+            &mut *rng_guard,
+        )?;
+    }
+
     let stored_events: Vec<StoredEvent> = character
         .uncommitted_events()
         .iter()
@@ -215,6 +293,7 @@ pub async fn handle_award_experience(
 
     repo.append_events(command.character_id, character.version, &stored_events)
         .await?;
+    save_snapshot_if_due(repo, character, clock).await;
 
     Ok(stored_events)
 }
@@ -234,11 +313,9 @@ pub async fn handle_archive_character(
     rng: &Mutex<dyn DeterministicRng + Send>,
     repo: &dyn EventRepository,
 ) -> Result<Vec<StoredEvent>, DomainError> {
-    let existing_events = repo.load_events(command.character_id).await?;
-    if existing_events.is_empty() {
-        return Err(DomainError::AggregateNotFound(command.character_id));
-    }
-    let mut character = reconstitute(command.character_id, &existing_events)?;
+    let mut character = load(command.character_id, repo)
+        .await?
+        .ok_or(DomainError::AggregateNotFound(command.character_id))?;
 
     {
         let mut rng_guard = rng
@@ -255,12 +332,14 @@ pub async fn handle_archive_character(
 
     repo.append_events(command.character_id, character.version, &stored_events)
         .await?;
+    save_snapshot_if_due(repo, character, clock).await;
 
     Ok(stored_events)
 }
 
 #[cfg(test)]
 mod tests {
+    use std::collections::HashMap;
     use std::sync::{Arc, Mutex};
 
     use chrono::{TimeZone, Utc};
@@ -268,17 +347,21 @@ mod tests {
     use otherworlds_core::rng::DeterministicRng;
     use uuid::Uuid;
 
-    use otherworlds_core::repository::StoredEvent;
+    use otherworlds_core::repository::{
+        AggregateSnapshot, EventRepository, SnapshotPolicy, StoredEvent,
+    };
 
     use crate::application::command_handlers::{
         handle_archive_character, handle_award_experience, handle_create_character,
-        handle_modify_attribute,
+        handle_grant_proficiency, handle_modify_attribute,
     };
     use crate::domain::commands::{
-        ArchiveCharacter, AwardExperience, CreateCharacter, ModifyAttribute,
+        ArchiveCharacter, AwardExperience, CreateCharacter, GrantProficiency, ModifyAttribute,
     };
     use crate::domain::events::{CharacterCreated, CharacterEventKind};
-    use otherworlds_test_support::{FixedClock, MockRng, RecordingEventRepository};
+    use otherworlds_test_support::{
+        FixedClock, MockRng, MultiAggregateEventRepository, RecordingEventRepository,
+    };
 
     fn character_created_event(
         character_id: Uuid,
@@ -297,6 +380,7 @@ mod tests {
             correlation_id: Uuid::new_v4(),
             causation_id: Uuid::new_v4(),
             occurred_at: fixed_now,
+            schema_version: 1,
         }
     }
 
@@ -352,6 +436,7 @@ mod tests {
 
         let command = ModifyAttribute {
             correlation_id,
+            causation_id: None,
             character_id,
             attribute: "strength".to_owned(),
             new_value: 18,
@@ -393,6 +478,7 @@ mod tests {
 
         let command = AwardExperience {
             correlation_id,
+            causation_id: None,
             character_id,
             amount: 250,
         };
@@ -420,6 +506,34 @@ mod tests {
         assert_eq!(stored.occurred_at, fixed_now);
     }
 
+    #[tokio::test]
+    async fn test_handle_grant_proficiency_persists_proficiency_granted_event() {
+        // Arrange
+        let character_id = Uuid::new_v4();
+        let fixed_now = Utc.with_ymd_and_hms(2026, 1, 15, 10, 0, 0).unwrap();
+        let clock = FixedClock(fixed_now);
+        let rng: Arc<Mutex<dyn DeterministicRng + Send>> = Arc::new(Mutex::new(MockRng));
+        let existing = vec![character_created_event(character_id, fixed_now)];
+        let repo = RecordingEventRepository::new(Ok(existing));
+
+        let command = GrantProficiency {
+            correlation_id: Uuid::new_v4(),
+            character_id,
+            skill: "stealth".to_owned(),
+        };
+
+        // Act
+        let events = handle_grant_proficiency(&command, &clock, &*rng, &repo)
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].event_type, "character.proficiency_granted");
+        assert_eq!(events[0].sequence_number, 2);
+        assert_eq!(events[0].payload["ProficiencyGranted"]["skill"], "stealth");
+    }
+
     #[tokio::test]
     async fn test_handle_create_character_rejects_empty_name() {
         // Arrange
@@ -455,6 +569,7 @@ mod tests {
 
         let command = ModifyAttribute {
             correlation_id: Uuid::new_v4(),
+            causation_id: None,
             character_id: Uuid::new_v4(),
             attribute: String::new(),
             new_value: 18,
@@ -482,6 +597,7 @@ mod tests {
 
         let command = AwardExperience {
             correlation_id: Uuid::new_v4(),
+            causation_id: None,
             character_id: Uuid::new_v4(),
             amount: 0,
         };
@@ -582,6 +698,7 @@ mod tests {
             correlation_id: Uuid::new_v4(),
             causation_id: Uuid::new_v4(),
             occurred_at: fixed_now,
+            schema_version: 1,
         };
         let existing = vec![
             character_created_event(character_id, fixed_now),
@@ -627,6 +744,7 @@ mod tests {
             correlation_id: Uuid::new_v4(),
             causation_id: Uuid::new_v4(),
             occurred_at: fixed_now,
+            schema_version: 1,
         };
         let existing = vec![
             character_created_event(character_id, fixed_now),
@@ -636,6 +754,7 @@ mod tests {
 
         let command = ModifyAttribute {
             correlation_id: Uuid::new_v4(),
+            causation_id: None,
             character_id,
             attribute: "strength".to_owned(),
             new_value: 18,
@@ -674,6 +793,7 @@ mod tests {
             correlation_id: Uuid::new_v4(),
             causation_id: Uuid::new_v4(),
             occurred_at: fixed_now,
+            schema_version: 1,
         };
         let existing = vec![
             character_created_event(character_id, fixed_now),
@@ -683,6 +803,7 @@ mod tests {
 
         let command = AwardExperience {
             correlation_id: Uuid::new_v4(),
+            causation_id: None,
             character_id,
             amount: 250,
         };
@@ -710,6 +831,7 @@ mod tests {
 
         let command = ModifyAttribute {
             correlation_id: Uuid::new_v4(),
+            causation_id: None,
             character_id,
             attribute: "strength".to_owned(),
             new_value: 18,
@@ -736,6 +858,7 @@ mod tests {
 
         let command = AwardExperience {
             correlation_id: Uuid::new_v4(),
+            causation_id: None,
             character_id,
             amount: 250,
         };
@@ -750,4 +873,131 @@ mod tests {
             other => panic!("expected AggregateNotFound, got {other:?}"),
         }
     }
+
+    #[tokio::test]
+    async fn test_handle_award_experience_saves_snapshot_when_policy_due() {
+        // Arrange
+        let character_id = Uuid::new_v4();
+        let fixed_now = Utc.with_ymd_and_hms(2026, 1, 15, 10, 0, 0).unwrap();
+        let clock = FixedClock(fixed_now);
+        let rng: Arc<Mutex<dyn DeterministicRng + Send>> = Arc::new(Mutex::new(MockRng));
+        let repo = MultiAggregateEventRepository::with_snapshots(
+            HashMap::from([(
+                character_id,
+                vec![character_created_event(character_id, fixed_now)],
+            )]),
+            SnapshotPolicy::every(2),
+        );
+
+        let command = AwardExperience {
+            correlation_id: Uuid::new_v4(),
+            causation_id: None,
+            character_id,
+            amount: 250,
+        };
+
+        // Act
+        handle_award_experience(&command, &clock, &*rng, &repo)
+            .await
+            .unwrap();
+
+        // Assert
+        let store = repo.snapshot_store().unwrap();
+        let snapshot = store.load_snapshot(character_id).await.unwrap().unwrap();
+        assert_eq!(snapshot.aggregate_type, "character.character");
+        assert_eq!(snapshot.version, 2);
+        assert_eq!(snapshot.state["name"], "Alaric");
+        assert_eq!(snapshot.state["experience"], 250);
+        assert_eq!(snapshot.taken_at, fixed_now);
+    }
+
+    #[tokio::test]
+    async fn test_handle_award_experience_replays_tail_on_top_of_snapshot() {
+        // Arrange
+        let character_id = Uuid::new_v4();
+        let fixed_now = Utc.with_ymd_and_hms(2026, 1, 15, 10, 0, 0).unwrap();
+        let clock = FixedClock(fixed_now);
+        let rng: Arc<Mutex<dyn DeterministicRng + Send>> = Arc::new(Mutex::new(MockRng));
+        let repo = MultiAggregateEventRepository::with_snapshots(
+            HashMap::from([(
+                character_id,
+                vec![character_created_event(character_id, fixed_now)],
+            )]),
+            SnapshotPolicy::every(2),
+        );
+        // The snapshot carries state the event stream does not, proving the
+        // handler started from it rather than from the first event.
+        repo.snapshot_store()
+            .unwrap()
+            .save_snapshot(&AggregateSnapshot {
+                aggregate_id: character_id,
+                aggregate_type: "character.character".to_owned(),
+                version: 1,
+                state: serde_json::json!({
+                    "id": character_id,
+                    "version": 1,
+                    "name": "Alaric",
+                    "attributes": {},
+                    "experience": 100,
+                    "proficiencies": [],
+                    "archived": false,
+                }),
+                taken_at: fixed_now,
+            })
+            .await
+            .unwrap();
+
+        let command = AwardExperience {
+            correlation_id: Uuid::new_v4(),
+            causation_id: None,
+            character_id,
+            amount: 50,
+        };
+
+        // Act
+        handle_award_experience(&command, &clock, &*rng, &repo)
+            .await
+            .unwrap();
+
+        // Assert
+        let appended = repo.appended_events();
+        assert_eq!(appended[0].1, 1);
+        assert_eq!(appended[0].2[0].sequence_number, 2);
+
+        let snapshot = repo
+            .snapshot_store()
+            .unwrap()
+            .load_snapshot(character_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(snapshot.version, 2);
+        assert_eq!(snapshot.state["experience"], 150);
+    }
+
+    #[tokio::test]
+    async fn test_handle_award_experience_rejects_unsupported_schema_version() {
+        // Arrange
+        let character_id = Uuid::new_v4();
+        let fixed_now = Utc.with_ymd_and_hms(2026, 1, 15, 10, 0, 0).unwrap();
+        let clock = FixedClock(fixed_now);
+        let rng: Arc<Mutex<dyn DeterministicRng + Send>> = Arc::new(Mutex::new(MockRng));
+        let mut created = character_created_event(character_id, fixed_now);
+        created.schema_version = 99;
+        let repo = RecordingEventRepository::new(Ok(vec![created]));
+
+        let command = AwardExperience {
+            correlation_id: Uuid::new_v4(),
+            causation_id: None,
+            character_id,
+            amount: 50,
+        };
+
+        // Act
+        let result = handle_award_experience(&command, &clock, &*rng, &repo).await;
+
+        // Assert
+        assert!(matches!(result, Err(DomainError::Infrastructure(_))));
+        assert!(repo.appended_events().is_empty());
+    }
 }
@@ -1,4 +1,5 @@
 //! Application layer for the Character Management context.
 
 pub mod command_handlers;
+pub mod projections;
 pub mod query_handlers;
@@ -0,0 +1,240 @@
+//! Read-model projection for the Character Management context.
+//!
+//! Maintains one `CharacterView` per character and one `CharacterSummary`
+//! per non-archived character, rebuilt from the aggregate whenever its
+//! stream changes.
+
+use async_trait::async_trait;
+use otherworlds_core::error::DomainError;
+use otherworlds_core::projection::{Projection, ReadModelStore, put_document};
+use otherworlds_core::repository::EventRepository;
+use uuid::Uuid;
+
+use crate::application::command_handlers;
+use crate::application::query_handlers::{CharacterSummary, CharacterView};
+use crate::domain::aggregates::Character;
+
+/// Read-model collection holding one `CharacterView` per character.
+pub(crate) const CHARACTER_VIEWS: &str = "character_views";
+
+/// Read-model collection holding one `CharacterSummary` per listed character.
+pub(crate) const CHARACTER_SUMMARIES: &str = "character_summaries";
+
+/// Event types used by the Character Management context.
+const EVENT_TYPES: &[&str] = &[
+    "character.character_created",
+    "character.attribute_modified",
+    "character.experience_gained",
+    "character.proficiency_granted",
+    "character.character_archived",
+];
+
+/// Projects character streams into the character view and summary tables.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CharacterProjection;
+
+#[async_trait]
+impl Projection for CharacterProjection {
+    fn name(&self) -> &'static str {
+        "character.characters"
+    }
+
+    // v2: views list proficiencies.
+    fn version(&self) -> i32 {
+        2
+    }
+
+    fn event_types(&self) -> &'static [&'static str] {
+        EVENT_TYPES
+    }
+
+    async fn project(
+        &self,
+        aggregate_id: Uuid,
+        repo: &dyn EventRepository,
+        store: &dyn ReadModelStore,
+    ) -> Result<(), DomainError> {
+        let Some(character) = command_handlers::load(aggregate_id, repo).await? else {
+            store.delete(CHARACTER_VIEWS, aggregate_id).await?;
+            return store.delete(CHARACTER_SUMMARIES, aggregate_id).await;
+        };
+
+        put_document(store, CHARACTER_VIEWS, aggregate_id, &view(&character)).await?;
+        if character.archived {
+            store.delete(CHARACTER_SUMMARIES, aggregate_id).await
+        } else {
+            put_document(
+                store,
+                CHARACTER_SUMMARIES,
+                aggregate_id,
+                &summary(&character),
+            )
+            .await
+        }
+    }
+
+    async fn reset(&self, store: &dyn ReadModelStore) -> Result<(), DomainError> {
+        store.clear(CHARACTER_VIEWS).await?;
+        store.clear(CHARACTER_SUMMARIES).await
+    }
+}
+
+fn view(character: &Character) -> CharacterView {
+    CharacterView {
+        character_id: character.id,
+        name: character.name.clone(),
+        attributes: character.attributes.clone(),
+        experience: character.experience,
+        proficiencies: character.proficiencies.clone(),
+        version: character.version,
+    }
+}
+
+fn summary(character: &Character) -> CharacterSummary {
+    CharacterSummary {
+        character_id: character.id,
+        name: character.name.clone(),
+        experience: character.experience,
+        version: character.version,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+    use otherworlds_core::projection::{ProjectionRunner, get_document, list_documents};
+    use otherworlds_core::repository::StoredEvent;
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::domain::events::{CharacterArchived, CharacterCreated, CharacterEventKind};
+    use otherworlds_test_support::{InMemoryReadModelStore, MultiAggregateEventRepository};
+
+    fn created(character_id: Uuid, name: &str) -> StoredEvent {
+        StoredEvent {
+            event_id: Uuid::new_v4(),
+            aggregate_id: character_id,
+            event_type: "character.character_created".to_owned(),
+            payload: serde_json::to_value(CharacterEventKind::CharacterCreated(CharacterCreated {
+                character_id,
+                name: name.to_owned(),
+            }))
+            .unwrap(),
+            sequence_number: 1,
+            correlation_id: Uuid::new_v4(),
+            causation_id: Uuid::new_v4(),
+            occurred_at: Utc.with_ymd_and_hms(2026, 1, 15, 10, 0, 0).unwrap(),
+            schema_version: 1,
+        }
+    }
+
+    fn archived(character_id: Uuid) -> StoredEvent {
+        StoredEvent {
+            event_id: Uuid::new_v4(),
+            aggregate_id: character_id,
+            event_type: "character.character_archived".to_owned(),
+            payload: serde_json::to_value(CharacterEventKind::CharacterArchived(
+                CharacterArchived { character_id },
+            ))
+            .unwrap(),
+            sequence_number: 2,
+            correlation_id: Uuid::new_v4(),
+            causation_id: Uuid::new_v4(),
+            occurred_at: Utc.with_ymd_and_hms(2026, 1, 15, 10, 0, 1).unwrap(),
+            schema_version: 1,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_projection_writes_view_and_summary() {
+        // Arrange
+        let character_id = Uuid::new_v4();
+        let repo = MultiAggregateEventRepository::new(
+            [(character_id, vec![created(character_id, "Alaric")])].into(),
+        );
+        let store = InMemoryReadModelStore::new();
+
+        // Act
+        ProjectionRunner::default()
+            .catch_up(&CharacterProjection, &repo, &store)
+            .await
+            .unwrap();
+
+        // Assert
+        let view: CharacterView = get_document(&store, CHARACTER_VIEWS, character_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(view.name, Some("Alaric".to_owned()));
+        let summaries: Vec<CharacterSummary> =
+            list_documents(&store, CHARACTER_SUMMARIES).await.unwrap();
+        assert_eq!(summaries.len(), 1);
+        assert_eq!(summaries[0].character_id, character_id);
+    }
+
+    #[tokio::test]
+    async fn test_projection_drops_summary_of_archived_character() {
+        // Arrange
+        let character_id = Uuid::new_v4();
+        let repo = MultiAggregateEventRepository::new(
+            [(
+                character_id,
+                vec![created(character_id, "Alaric"), archived(character_id)],
+            )]
+            .into(),
+        );
+        let store = InMemoryReadModelStore::new();
+
+        // Act
+        ProjectionRunner::default()
+            .catch_up(&CharacterProjection, &repo, &store)
+            .await
+            .unwrap();
+
+        // Assert
+        let view: Option<CharacterView> = get_document(&store, CHARACTER_VIEWS, character_id)
+            .await
+            .unwrap();
+        assert!(view.is_some());
+        let summaries: Vec<CharacterSummary> =
+            list_documents(&store, CHARACTER_SUMMARIES).await.unwrap();
+        assert!(summaries.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_rebuild_discards_rows_without_events() {
+        // Arrange
+        let character_id = Uuid::new_v4();
+        let repo = MultiAggregateEventRepository::new(
+            [(character_id, vec![created(character_id, "Alaric")])].into(),
+        );
+        let store = InMemoryReadModelStore::new();
+        let orphan = Uuid::new_v4();
+        put_document(
+            &store,
+            CHARACTER_SUMMARIES,
+            orphan,
+            &CharacterSummary {
+                character_id: orphan,
+                name: None,
+                experience: 0,
+                version: 1,
+            },
+        )
+        .await
+        .unwrap();
+
+        // Act
+        let processed = ProjectionRunner::default()
+            .rebuild(&CharacterProjection, &repo, &store)
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(processed, 1);
+        let summaries: Vec<CharacterSummary> =
+            list_documents(&store, CHARACTER_SUMMARIES).await.unwrap();
+        assert_eq!(summaries.len(), 1);
+        assert_eq!(summaries[0].character_id, character_id);
+    }
+}
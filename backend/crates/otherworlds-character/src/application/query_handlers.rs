@@ -1,19 +1,23 @@
 //! Query handlers for the Character Management context.
 //!
-//! This module contains query handlers that reconstitute aggregates
-//! from stored events and return read-only view DTOs.
+//! This module contains query handlers that bring the character projection
+//! up to date and return read-only view DTOs from its read-model tables.
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 
 use otherworlds_core::error::DomainError;
+use otherworlds_core::projection::{
+    ProjectionRunner, ReadModelStore, get_document, list_documents,
+};
 use otherworlds_core::repository::EventRepository;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 use crate::application::command_handlers;
+use crate::application::projections::{CHARACTER_SUMMARIES, CHARACTER_VIEWS, CharacterProjection};
 
 /// Read-only view of a character aggregate.
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct CharacterView {
     /// The character identifier.
     pub character_id: Uuid,
@@ -23,20 +27,25 @@ pub struct CharacterView {
     pub attributes: HashMap<String, i32>,
     /// Total experience accumulated.
     pub experience: u32,
+    /// Skills and attributes the character is proficient in.
+    pub proficiencies: BTreeSet<String>,
     /// Current version (event count).
     pub version: i64,
 }
 
-/// Event types used by the Character Management context.
-const EVENT_TYPES: &[&str] = &[
-    "character.character_created",
-    "character.attribute_modified",
-    "character.experience_gained",
-    "character.character_archived",
-];
+/// The parts of a character other contexts derive check modifiers from.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CharacterSheet {
+    /// The character identifier.
+    pub character_id: Uuid,
+    /// Character attributes (e.g., "strength" → 18).
+    pub attributes: BTreeMap<String, i32>,
+    /// Skills and attributes the character is proficient in.
+    pub proficiencies: BTreeSet<String>,
+}
 
 /// Summary view for listing characters.
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct CharacterSummary {
     /// The character identifier.
     pub character_id: Uuid,
@@ -48,56 +57,64 @@ pub struct CharacterSummary {
     pub version: i64,
 }
 
-/// Lists all characters.
+/// Lists all characters that have not been archived.
 ///
 /// # Errors
 ///
-/// Returns `DomainError::Infrastructure` if querying or deserialization fails.
+/// Returns `DomainError::Infrastructure` if catching up the projection or
+/// reading the read model fails.
 pub async fn list_characters(
     repo: &dyn EventRepository,
+    read_models: &dyn ReadModelStore,
 ) -> Result<Vec<CharacterSummary>, DomainError> {
-    let ids = repo.list_aggregate_ids(EVENT_TYPES).await?;
-    let mut summaries = Vec::with_capacity(ids.len());
-    for id in ids {
-        let stored_events = repo.load_events(id).await?;
-        if stored_events.is_empty() {
-            continue;
-        }
-        let character = command_handlers::reconstitute(id, &stored_events)?;
-        if character.archived {
-            continue;
-        }
-        summaries.push(CharacterSummary {
-            character_id: id,
-            name: character.name.clone(),
-            experience: character.experience,
-            version: character.version,
-        });
-    }
-    Ok(summaries)
+    ProjectionRunner::default()
+        .catch_up(&CharacterProjection, repo, read_models)
+        .await?;
+    list_documents(read_models, CHARACTER_SUMMARIES).await
 }
 
 /// Retrieves a character by its aggregate ID.
 ///
 /// # Errors
 ///
-/// Returns `DomainError::AggregateNotFound` if no events exist for the ID.
-/// Returns `DomainError::Infrastructure` if event deserialization fails.
+/// Returns `DomainError::AggregateNotFound` if the character has no view.
+/// Returns `DomainError::Infrastructure` if catching up the projection or
+/// reading the read model fails.
 pub async fn get_character_by_id(
     character_id: Uuid,
     repo: &dyn EventRepository,
+    read_models: &dyn ReadModelStore,
 ) -> Result<CharacterView, DomainError> {
-    let stored_events = repo.load_events(character_id).await?;
-    if stored_events.is_empty() {
-        return Err(DomainError::AggregateNotFound(character_id));
+    ProjectionRunner::default()
+        .catch_up(&CharacterProjection, repo, read_models)
+        .await?;
+    get_document(read_models, CHARACTER_VIEWS, character_id)
+        .await?
+        .ok_or(DomainError::AggregateNotFound(character_id))
+}
+
+/// Loads a character's sheet straight from its event stream, for other
+/// contexts to derive check modifiers from.
+///
+/// # Errors
+///
+/// Returns `DomainError::AggregateNotFound` if the character does not exist,
+/// `DomainError::Validation` if it is archived, and
+/// `DomainError::Infrastructure` if loading fails.
+pub async fn get_character_sheet(
+    character_id: Uuid,
+    repo: &dyn EventRepository,
+) -> Result<CharacterSheet, DomainError> {
+    let character = command_handlers::load(character_id, repo)
+        .await?
+        .ok_or(DomainError::AggregateNotFound(character_id))?;
+    if character.archived {
+        return Err(DomainError::Validation("character is archived".into()));
     }
-    let character = command_handlers::reconstitute(character_id, &stored_events)?;
-    Ok(CharacterView {
+    Ok(CharacterSheet {
         character_id,
-        name: character.name.clone(),
-        attributes: character.attributes.clone(),
-        experience: character.experience,
-        version: character.version,
+        attributes: character.attributes.into_iter().collect(),
+        proficiencies: character.proficiencies,
     })
 }
 
@@ -108,9 +125,16 @@ mod tests {
     use otherworlds_core::repository::StoredEvent;
     use uuid::Uuid;
 
-    use crate::application::query_handlers::{get_character_by_id, list_characters};
-    use crate::domain::events::{CharacterArchived, CharacterCreated, CharacterEventKind};
-    use otherworlds_test_support::{EmptyEventRepository, RecordingEventRepository};
+    use crate::application::query_handlers::{
+        get_character_by_id, get_character_sheet, list_characters,
+    };
+    use crate::domain::events::{
+        AttributeModified, CharacterArchived, CharacterCreated, CharacterEventKind,
+        ProficiencyGranted,
+    };
+    use otherworlds_test_support::{
+        EmptyEventRepository, InMemoryReadModelStore, RecordingEventRepository,
+    };
 
     #[tokio::test]
     async fn test_get_character_by_id_returns_view_with_state() {
@@ -131,11 +155,14 @@ mod tests {
             correlation_id: Uuid::new_v4(),
             causation_id: Uuid::new_v4(),
             occurred_at: fixed_now,
+            schema_version: 1,
         }];
         let repo = RecordingEventRepository::new(Ok(events));
 
         // Act
-        let view = get_character_by_id(character_id, &repo).await.unwrap();
+        let view = get_character_by_id(character_id, &repo, &InMemoryReadModelStore::new())
+            .await
+            .unwrap();
 
         // Assert
         assert_eq!(view.character_id, character_id);
@@ -152,7 +179,7 @@ mod tests {
         let repo = EmptyEventRepository;
 
         // Act
-        let result = get_character_by_id(character_id, &repo).await;
+        let result = get_character_by_id(character_id, &repo, &InMemoryReadModelStore::new()).await;
 
         // Assert
         assert!(result.is_err());
@@ -166,7 +193,9 @@ mod tests {
     async fn test_list_characters_returns_empty_when_no_aggregates() {
         let repo = EmptyEventRepository;
 
-        let result = list_characters(&repo).await.unwrap();
+        let result = list_characters(&repo, &InMemoryReadModelStore::new())
+            .await
+            .unwrap();
 
         assert!(result.is_empty());
     }
@@ -189,10 +218,13 @@ mod tests {
             correlation_id: Uuid::new_v4(),
             causation_id: Uuid::new_v4(),
             occurred_at: fixed_now,
+            schema_version: 1,
         }];
         let repo = RecordingEventRepository::with_aggregate_ids(Ok(events), vec![character_id]);
 
-        let result = list_characters(&repo).await.unwrap();
+        let result = list_characters(&repo, &InMemoryReadModelStore::new())
+            .await
+            .unwrap();
 
         assert_eq!(result.len(), 1);
         assert_eq!(result[0].character_id, character_id);
@@ -222,6 +254,7 @@ mod tests {
                 correlation_id: Uuid::new_v4(),
                 causation_id: Uuid::new_v4(),
                 occurred_at: fixed_now,
+                schema_version: 1,
             },
             StoredEvent {
                 event_id: Uuid::new_v4(),
@@ -235,12 +268,70 @@ mod tests {
                 correlation_id: Uuid::new_v4(),
                 causation_id: Uuid::new_v4(),
                 occurred_at: fixed_now,
+                schema_version: 1,
             },
         ];
         let repo = RecordingEventRepository::with_aggregate_ids(Ok(events), vec![character_id]);
 
-        let result = list_characters(&repo).await.unwrap();
+        let result = list_characters(&repo, &InMemoryReadModelStore::new())
+            .await
+            .unwrap();
 
         assert!(result.is_empty());
     }
+
+    #[tokio::test]
+    async fn test_get_character_sheet_collects_attributes_and_proficiencies() {
+        // Arrange
+        let character_id = Uuid::new_v4();
+        let fixed_now = Utc.with_ymd_and_hms(2026, 1, 15, 10, 0, 0).unwrap();
+        let kinds = [
+            (
+                "character.character_created",
+                CharacterEventKind::CharacterCreated(CharacterCreated {
+                    character_id,
+                    name: "Alaric".to_owned(),
+                }),
+            ),
+            (
+                "character.attribute_modified",
+                CharacterEventKind::AttributeModified(AttributeModified {
+                    character_id,
+                    attribute: "dexterity".to_owned(),
+                    new_value: 16,
+                }),
+            ),
+            (
+                "character.proficiency_granted",
+                CharacterEventKind::ProficiencyGranted(ProficiencyGranted {
+                    character_id,
+                    skill: "stealth".to_owned(),
+                }),
+            ),
+        ];
+        let events = kinds
+            .into_iter()
+            .zip(1..)
+            .map(|((event_type, kind), sequence_number)| StoredEvent {
+                event_id: Uuid::new_v4(),
+                aggregate_id: character_id,
+                event_type: event_type.to_owned(),
+                payload: serde_json::to_value(kind).unwrap(),
+                sequence_number,
+                correlation_id: Uuid::new_v4(),
+                causation_id: Uuid::new_v4(),
+                occurred_at: fixed_now,
+                schema_version: 1,
+            })
+            .collect();
+        let repo = RecordingEventRepository::new(Ok(events));
+
+        // Act
+        let sheet = get_character_sheet(character_id, &repo).await.unwrap();
+
+        // Assert
+        assert_eq!(sheet.character_id, character_id);
+        assert_eq!(sheet.attributes.get("dexterity"), Some(&16));
+        assert!(sheet.proficiencies.contains("stealth"));
+    }
 }
@@ -1,22 +1,25 @@
 //! Aggregate roots for the Character Management context.
 
-use std::collections::HashMap;
+use std::collections::{BTreeSet, HashMap};
 
 use otherworlds_core::aggregate::AggregateRoot;
 use otherworlds_core::clock::Clock;
 use otherworlds_core::event::EventMetadata;
 use otherworlds_core::rng::DeterministicRng;
+use otherworlds_core::snapshot::Snapshottable;
+use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 use otherworlds_core::error::DomainError;
 
 use super::events::{
     AttributeModified, CharacterArchived, CharacterCreated, CharacterEvent, CharacterEventKind,
-    ExperienceGained,
+    ExperienceGained, ProficiencyGranted,
 };
+use super::upcasters::current_schema_version;
 
 /// The aggregate root for a character.
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct Character {
     /// Aggregate identifier.
     pub id: Uuid,
@@ -28,9 +31,12 @@ pub struct Character {
     pub(crate) attributes: HashMap<String, i32>,
     /// Total experience accumulated.
     pub(crate) experience: u32,
+    /// Skills and attributes the character is proficient in.
+    pub(crate) proficiencies: BTreeSet<String>,
     /// Whether this character has been archived (soft-deleted).
     pub(crate) archived: bool,
     /// Uncommitted events pending persistence.
+    #[serde(skip)]
     uncommitted_events: Vec<CharacterEvent>,
 }
 
@@ -44,6 +50,7 @@ impl Character {
             name: None,
             attributes: HashMap::new(),
             experience: 0,
+            proficiencies: BTreeSet::new(),
             archived: false,
             uncommitted_events: Vec::new(),
         }
@@ -72,6 +79,7 @@ impl Character {
                 correlation_id,
                 causation_id: correlation_id,
                 occurred_at: clock.now(),
+                schema_version: current_schema_version("character.character_created"),
             },
             kind: CharacterEventKind::CharacterCreated(CharacterCreated {
                 character_id: self.id,
@@ -100,6 +108,7 @@ impl Character {
                 correlation_id,
                 causation_id: correlation_id,
                 occurred_at: clock.now(),
+                schema_version: current_schema_version("character.attribute_modified"),
             },
             kind: CharacterEventKind::AttributeModified(AttributeModified {
                 character_id: self.id,
@@ -111,6 +120,47 @@ impl Character {
         self.uncommitted_events.push(event);
     }
 
+    /// Makes the character proficient in `skill`, producing a
+    /// `ProficiencyGranted` event.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::Validation` if the character is already
+    /// proficient in `skill`.
+    pub fn grant_proficiency(
+        &mut self,
+        skill: String,
+        correlation_id: Uuid,
+        clock: &dyn Clock,
+        rng: &mut dyn DeterministicRng,
+    ) -> Result<(), DomainError> {
+        if self.proficiencies.contains(&skill) {
+            return Err(DomainError::Validation(format!(
+                "character is already proficient in {skill}"
+            )));
+        }
+
+        let event = CharacterEvent {
+            metadata: EventMetadata {
+                event_id: rng.next_uuid(),
+                event_type: "character.proficiency_granted".to_owned(),
+                aggregate_id: self.id,
+                sequence_number: self.next_sequence_number(),
+                correlation_id,
+                causation_id: correlation_id,
+                occurred_at: clock.now(),
+                schema_version: current_schema_version("character.proficiency_granted"),
+            },
+            kind: CharacterEventKind::ProficiencyGranted(ProficiencyGranted {
+                character_id: self.id,
+                skill,
+            }),
+        };
+
+        self.uncommitted_events.push(event);
+        Ok(())
+    }
+
     /// Archives (soft-deletes) a character, producing a `CharacterArchived` event.
     ///
     /// # Errors
@@ -137,6 +187,7 @@ impl Character {
                 correlation_id,
                 causation_id: correlation_id,
                 occurred_at: clock.now(),
+                schema_version: current_schema_version("character.character_archived"),
             },
             kind: CharacterEventKind::CharacterArchived(CharacterArchived {
                 character_id: self.id,
@@ -164,6 +215,7 @@ impl Character {
                 correlation_id,
                 causation_id: correlation_id,
                 occurred_at: clock.now(),
+                schema_version: current_schema_version("character.experience_gained"),
             },
             kind: CharacterEventKind::ExperienceGained(ExperienceGained {
                 character_id: self.id,
@@ -198,6 +250,9 @@ impl AggregateRoot for Character {
             CharacterEventKind::ExperienceGained(payload) => {
                 self.experience += payload.amount;
             }
+            CharacterEventKind::ProficiencyGranted(payload) => {
+                self.proficiencies.insert(payload.skill.clone());
+            }
             CharacterEventKind::CharacterArchived(_) => {
                 self.archived = true;
             }
@@ -214,6 +269,10 @@ impl AggregateRoot for Character {
     }
 }
 
+impl Snapshottable for Character {
+    const AGGREGATE_TYPE: &'static str = "character.character";
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -314,6 +373,7 @@ mod tests {
                 correlation_id: Uuid::new_v4(),
                 causation_id: Uuid::new_v4(),
                 occurred_at: fixed_now,
+                schema_version: current_schema_version("character.character_created"),
             },
             kind: CharacterEventKind::CharacterCreated(CharacterCreated {
                 character_id,
@@ -344,6 +404,7 @@ mod tests {
                 correlation_id: Uuid::new_v4(),
                 causation_id: Uuid::new_v4(),
                 occurred_at: fixed_now,
+                schema_version: current_schema_version("character.attribute_modified"),
             },
             kind: CharacterEventKind::AttributeModified(AttributeModified {
                 character_id,
@@ -375,6 +436,7 @@ mod tests {
                 correlation_id: Uuid::new_v4(),
                 causation_id: Uuid::new_v4(),
                 occurred_at: fixed_now,
+                schema_version: current_schema_version("character.experience_gained"),
             },
             kind: CharacterEventKind::ExperienceGained(ExperienceGained {
                 character_id,
@@ -390,6 +452,7 @@ mod tests {
                 correlation_id: Uuid::new_v4(),
                 causation_id: Uuid::new_v4(),
                 occurred_at: fixed_now,
+                schema_version: current_schema_version("character.experience_gained"),
             },
             kind: CharacterEventKind::ExperienceGained(ExperienceGained {
                 character_id,
@@ -492,6 +555,7 @@ mod tests {
                 correlation_id: Uuid::new_v4(),
                 causation_id: Uuid::new_v4(),
                 occurred_at: fixed_now,
+                schema_version: current_schema_version("character.character_archived"),
             },
             kind: CharacterEventKind::CharacterArchived(CharacterArchived { character_id }),
         };
@@ -526,4 +590,38 @@ mod tests {
             other => panic!("expected Validation, got {other:?}"),
         }
     }
+
+    #[test]
+    fn test_grant_proficiency_produces_event_and_rejects_duplicates() {
+        // Arrange
+        let character_id = Uuid::new_v4();
+        let correlation_id = Uuid::new_v4();
+        let clock = FixedClock(Utc.with_ymd_and_hms(2026, 1, 15, 10, 0, 0).unwrap());
+        let mut character = Character::new(character_id);
+        character.proficiencies.insert("athletics".to_owned());
+
+        // Act
+        let granted =
+            character.grant_proficiency("stealth".to_owned(), correlation_id, &clock, &mut MockRng);
+        let duplicate = character.grant_proficiency(
+            "athletics".to_owned(),
+            correlation_id,
+            &clock,
+            &mut MockRng,
+        );
+
+        // Assert
+        assert!(granted.is_ok());
+        assert!(matches!(duplicate, Err(DomainError::Validation(_))));
+        let events = character.uncommitted_events();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].event_type(), "character.proficiency_granted");
+        match &events[0].kind {
+            CharacterEventKind::ProficiencyGranted(payload) => {
+                assert_eq!(payload.character_id, character_id);
+                assert_eq!(payload.skill, "stealth");
+            }
+            other => panic!("expected ProficiencyGranted, got {other:?}"),
+        }
+    }
 }
@@ -1,10 +1,11 @@
 //! Commands for the Character Management context.
 
 use otherworlds_core::command::Command;
+use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 /// Command to create a new character.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CreateCharacter {
     /// The correlation ID for tracing.
     pub correlation_id: Uuid,
@@ -25,10 +26,14 @@ impl Command for CreateCharacter {
 }
 
 /// Command to modify a character attribute.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ModifyAttribute {
     /// The correlation ID for tracing.
     pub correlation_id: Uuid,
+    /// The event that caused this command, when it was dispatched in
+    /// reaction to another context's event.
+    #[serde(default)]
+    pub causation_id: Option<Uuid>,
     /// The character identifier.
     pub character_id: Uuid,
     /// The attribute key.
@@ -48,7 +53,7 @@ impl Command for ModifyAttribute {
 }
 
 /// Command to archive (soft-delete) a character.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ArchiveCharacter {
     /// The correlation ID for tracing.
     pub correlation_id: Uuid,
@@ -67,10 +72,14 @@ impl Command for ArchiveCharacter {
 }
 
 /// Command to award experience to a character.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AwardExperience {
     /// The correlation ID for tracing.
     pub correlation_id: Uuid,
+    /// The event that caused this command, when it was dispatched in
+    /// reaction to another context's event.
+    #[serde(default)]
+    pub causation_id: Option<Uuid>,
     /// The character identifier.
     pub character_id: Uuid,
     /// The amount of experience to award.
@@ -86,3 +95,24 @@ impl Command for AwardExperience {
         self.correlation_id
     }
 }
+
+/// Command to make a character proficient in a skill or attribute.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GrantProficiency {
+    /// The correlation ID for tracing.
+    pub correlation_id: Uuid,
+    /// The character identifier.
+    pub character_id: Uuid,
+    /// The skill or attribute (e.g., "stealth").
+    pub skill: String,
+}
+
+impl Command for GrantProficiency {
+    fn command_type(&self) -> &'static str {
+        "character.grant_proficiency"
+    }
+
+    fn correlation_id(&self) -> Uuid {
+        self.correlation_id
+    }
+}
@@ -33,6 +33,15 @@ pub struct ExperienceGained {
     pub amount: u32,
 }
 
+/// Emitted when a character becomes proficient in a skill or attribute.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProficiencyGranted {
+    /// The character identifier.
+    pub character_id: Uuid,
+    /// The skill or attribute the character is now proficient in.
+    pub skill: String,
+}
+
 /// Emitted when a character is archived (soft-deleted).
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CharacterArchived {
@@ -49,6 +58,8 @@ pub enum CharacterEventKind {
     AttributeModified(AttributeModified),
     /// A character has gained experience.
     ExperienceGained(ExperienceGained),
+    /// A character has become proficient in a skill or attribute.
+    ProficiencyGranted(ProficiencyGranted),
     /// A character has been archived (soft-deleted).
     CharacterArchived(CharacterArchived),
 }
@@ -68,6 +79,7 @@ impl DomainEvent for CharacterEvent {
             CharacterEventKind::CharacterCreated(_) => "character.character_created",
             CharacterEventKind::AttributeModified(_) => "character.attribute_modified",
             CharacterEventKind::ExperienceGained(_) => "character.experience_gained",
+            CharacterEventKind::ProficiencyGranted(_) => "character.proficiency_granted",
             CharacterEventKind::CharacterArchived(_) => "character.character_archived",
         }
     }
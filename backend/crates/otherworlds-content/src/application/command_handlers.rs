@@ -11,12 +11,14 @@ use otherworlds_core::error::DomainError;
 use otherworlds_core::event::DomainEvent;
 use otherworlds_core::repository::{EventRepository, StoredEvent};
 use otherworlds_core::rng::DeterministicRng;
+use otherworlds_core::snapshot::{load_with_snapshot, save_snapshot_if_due};
 use tracing::instrument;
 use uuid::Uuid;
 
 use crate::domain::aggregates::Campaign;
 use crate::domain::commands::{ArchiveCampaign, CompileCampaign, IngestCampaign, ValidateCampaign};
 use crate::domain::events::{ContentEvent, ContentEventKind};
+use crate::domain::upcasters;
 
 /// Result of a successfully handled command.
 #[derive(Debug)]
@@ -38,6 +40,7 @@ fn to_stored_event(event: &ContentEvent) -> StoredEvent {
         correlation_id: meta.correlation_id,
         causation_id: meta.causation_id,
         occurred_at: meta.occurred_at,
+        schema_version: meta.schema_version,
     }
 }
 
@@ -50,12 +53,36 @@ pub(crate) fn reconstitute(
     campaign_id: Uuid,
     existing_events: &[StoredEvent],
 ) -> Result<Campaign, DomainError> {
-    let mut campaign = Campaign::new(campaign_id);
-    for stored in existing_events {
-        let kind: ContentEventKind =
-            serde_json::from_value(stored.payload.clone()).map_err(|e| {
-                DomainError::Infrastructure(format!("event deserialization failed: {e}"))
-            })?;
+    replay(Campaign::new(campaign_id), existing_events)
+}
+
+/// Loads a `Campaign`, starting from its latest snapshot when one is
+/// available and replaying only the events recorded after it.
+///
+/// Returns `None` if the aggregate has neither a snapshot nor any events.
+///
+/// # Errors
+///
+/// Returns `DomainError` if loading fails or event deserialization fails.
+pub(crate) async fn load(
+    campaign_id: Uuid,
+    repo: &dyn EventRepository,
+) -> Result<Option<Campaign>, DomainError> {
+    let (restored, events) = load_with_snapshot::<Campaign>(repo, campaign_id).await?;
+    match restored {
+        Some(campaign) => replay(campaign, &events).map(Some),
+        None if events.is_empty() => Ok(None),
+        None => reconstitute(campaign_id, &events).map(Some),
+    }
+}
+
+/// Applies stored events on top of an existing `Campaign` state.
+fn replay(mut campaign: Campaign, events: &[StoredEvent]) -> Result<Campaign, DomainError> {
+    for stored in events {
+        let payload = upcasters::registry().upcast(stored)?;
+        let kind: ContentEventKind = serde_json::from_value(payload).map_err(|e| {
+            DomainError::Infrastructure(format!("event deserialization failed: {e}"))
+        })?;
         let event = ContentEvent {
             metadata: otherworlds_core::event::EventMetadata {
                 event_id: stored.event_id,
@@ -65,6 +92,7 @@ pub(crate) fn reconstitute(
                 correlation_id: stored.correlation_id,
                 causation_id: stored.causation_id,
                 occurred_at: stored.occurred_at,
+                schema_version: upcasters::current_schema_version(&stored.event_type),
             },
             kind,
         };
@@ -116,6 +144,7 @@ pub async fn handle_ingest_campaign(
 
     repo.append_events(campaign_id, campaign.version(), &stored_events)
         .await?;
+    save_snapshot_if_due(repo, campaign, clock).await;
 
     Ok(ContentCommandResult {
         aggregate_id: campaign_id,
@@ -136,11 +165,9 @@ pub async fn handle_validate_campaign(
     rng: &Mutex<dyn DeterministicRng + Send>,
     repo: &dyn EventRepository,
 ) -> Result<ContentCommandResult, DomainError> {
-    let existing_events = repo.load_events(command.campaign_id).await?;
-    if existing_events.is_empty() {
-        return Err(DomainError::AggregateNotFound(command.campaign_id));
-    }
-    let mut campaign = reconstitute(command.campaign_id, &existing_events)?;
+    let mut campaign = load(command.campaign_id, repo)
+        .await?
+        .ok_or(DomainError::AggregateNotFound(command.campaign_id))?;
     if campaign.archived {
         return Err(DomainError::Validation("campaign is archived".into()));
     }
@@ -160,6 +187,7 @@ pub async fn handle_validate_campaign(
 
     repo.append_events(command.campaign_id, campaign.version(), &stored_events)
         .await?;
+    save_snapshot_if_due(repo, campaign, clock).await;
 
     Ok(ContentCommandResult {
         aggregate_id: command.campaign_id,
@@ -180,11 +208,9 @@ pub async fn handle_compile_campaign(
     rng: &Mutex<dyn DeterministicRng + Send>,
     repo: &dyn EventRepository,
 ) -> Result<ContentCommandResult, DomainError> {
-    let existing_events = repo.load_events(command.campaign_id).await?;
-    if existing_events.is_empty() {
-        return Err(DomainError::AggregateNotFound(command.campaign_id));
-    }
-    let mut campaign = reconstitute(command.campaign_id, &existing_events)?;
+    let mut campaign = load(command.campaign_id, repo)
+        .await?
+        .ok_or(DomainError::AggregateNotFound(command.campaign_id))?;
     if campaign.archived {
         return Err(DomainError::Validation("campaign is archived".into()));
     }
@@ -204,6 +230,7 @@ pub async fn handle_compile_campaign(
 
     repo.append_events(command.campaign_id, campaign.version(), &stored_events)
         .await?;
+    save_snapshot_if_due(repo, campaign, clock).await;
 
     Ok(ContentCommandResult {
         aggregate_id: command.campaign_id,
@@ -225,11 +252,9 @@ pub async fn handle_archive_campaign(
     rng: &Mutex<dyn DeterministicRng + Send>,
     repo: &dyn EventRepository,
 ) -> Result<ContentCommandResult, DomainError> {
-    let existing_events = repo.load_events(command.campaign_id).await?;
-    if existing_events.is_empty() {
-        return Err(DomainError::AggregateNotFound(command.campaign_id));
-    }
-    let mut campaign = reconstitute(command.campaign_id, &existing_events)?;
+    let mut campaign = load(command.campaign_id, repo)
+        .await?
+        .ok_or(DomainError::AggregateNotFound(command.campaign_id))?;
 
     {
         let mut rng_guard = rng
@@ -246,6 +271,7 @@ pub async fn handle_archive_campaign(
 
     repo.append_events(command.campaign_id, campaign.version(), &stored_events)
         .await?;
+    save_snapshot_if_due(repo, campaign, clock).await;
 
     Ok(ContentCommandResult {
         aggregate_id: command.campaign_id,
@@ -296,6 +322,7 @@ mod tests {
             correlation_id: Uuid::new_v4(),
             causation_id: Uuid::new_v4(),
             occurred_at: fixed_now,
+            schema_version: 1,
         }
     }
 
@@ -312,6 +339,7 @@ mod tests {
             correlation_id: Uuid::new_v4(),
             causation_id: Uuid::new_v4(),
             occurred_at: fixed_now,
+            schema_version: 1,
         }
     }
 
@@ -328,6 +356,7 @@ mod tests {
             correlation_id: Uuid::new_v4(),
             causation_id: Uuid::new_v4(),
             occurred_at: fixed_now,
+            schema_version: 1,
         }
     }
 
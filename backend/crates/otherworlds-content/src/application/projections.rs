@@ -0,0 +1,89 @@
+//! Read-model projection for the Content Authoring context.
+//!
+//! Maintains one `CampaignView` per campaign and one `CampaignSummary`
+//! per non-archived campaign, rebuilt from the aggregate whenever its
+//! stream changes.
+
+use async_trait::async_trait;
+use otherworlds_core::error::DomainError;
+use otherworlds_core::projection::{Projection, ReadModelStore, put_document};
+use otherworlds_core::repository::EventRepository;
+use uuid::Uuid;
+
+use crate::application::command_handlers;
+use crate::application::query_handlers::{CampaignSummary, CampaignView};
+use crate::domain::aggregates::Campaign;
+
+/// Read-model collection holding one `CampaignView` per campaign.
+pub(crate) const CAMPAIGN_VIEWS: &str = "campaign_views";
+
+/// Read-model collection holding one `CampaignSummary` per listed campaign.
+pub(crate) const CAMPAIGN_SUMMARIES: &str = "campaign_summaries";
+
+/// Event types used by the Content Authoring context.
+const EVENT_TYPES: &[&str] = &[
+    "content.campaign_ingested",
+    "content.campaign_validated",
+    "content.campaign_compiled",
+    "content.campaign_archived",
+];
+
+/// Projects campaign streams into the campaign view and summary tables.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CampaignProjection;
+
+#[async_trait]
+impl Projection for CampaignProjection {
+    fn name(&self) -> &'static str {
+        "content.campaigns"
+    }
+
+    fn event_types(&self) -> &'static [&'static str] {
+        EVENT_TYPES
+    }
+
+    async fn project(
+        &self,
+        aggregate_id: Uuid,
+        repo: &dyn EventRepository,
+        store: &dyn ReadModelStore,
+    ) -> Result<(), DomainError> {
+        let Some(campaign) = command_handlers::load(aggregate_id, repo).await? else {
+            store.delete(CAMPAIGN_VIEWS, aggregate_id).await?;
+            return store.delete(CAMPAIGN_SUMMARIES, aggregate_id).await;
+        };
+
+        put_document(store, CAMPAIGN_VIEWS, aggregate_id, &view(&campaign)).await?;
+        if campaign.archived {
+            store.delete(CAMPAIGN_SUMMARIES, aggregate_id).await
+        } else {
+            put_document(store, CAMPAIGN_SUMMARIES, aggregate_id, &summary(&campaign)).await
+        }
+    }
+
+    async fn reset(&self, store: &dyn ReadModelStore) -> Result<(), DomainError> {
+        store.clear(CAMPAIGN_VIEWS).await?;
+        store.clear(CAMPAIGN_SUMMARIES).await
+    }
+}
+
+fn view(campaign: &Campaign) -> CampaignView {
+    CampaignView {
+        campaign_id: campaign.id,
+        ingested: campaign.ingested,
+        validated: campaign.validated,
+        compiled: campaign.compiled,
+        version_hash: campaign.version_hash.clone(),
+        version: campaign.version,
+    }
+}
+
+fn summary(campaign: &Campaign) -> CampaignSummary {
+    CampaignSummary {
+        campaign_id: campaign.id,
+        ingested: campaign.ingested,
+        validated: campaign.validated,
+        compiled: campaign.compiled,
+        version: campaign.version,
+    }
+}
@@ -1,17 +1,22 @@
 //! Query handlers for the Content Authoring context.
 //!
-//! This module contains query handlers that reconstitute aggregates
-//! from stored events and return read-only view DTOs.
+//! This module contains query handlers that bring the campaign projection
+//! up to date and return read-only view DTOs from its read-model tables.
 
 use otherworlds_core::error::DomainError;
+use otherworlds_core::projection::{
+    ProjectionRunner, ReadModelStore, get_document, list_documents,
+};
 use otherworlds_core::repository::EventRepository;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 use crate::application::command_handlers;
+use crate::application::projections::{CAMPAIGN_SUMMARIES, CAMPAIGN_VIEWS, CampaignProjection};
+use crate::domain::campaign_model::CompiledCampaign;
 
 /// Read-only view of a campaign aggregate.
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct CampaignView {
     /// The campaign identifier.
     pub campaign_id: Uuid,
@@ -27,16 +32,8 @@ pub struct CampaignView {
     pub version: i64,
 }
 
-/// Event types used by the Content Authoring context.
-const EVENT_TYPES: &[&str] = &[
-    "content.campaign_ingested",
-    "content.campaign_validated",
-    "content.campaign_compiled",
-    "content.campaign_archived",
-];
-
 /// Summary view for listing campaigns.
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct CampaignSummary {
     /// The campaign identifier.
     pub campaign_id: Uuid,
@@ -50,58 +47,63 @@ pub struct CampaignSummary {
     pub version: i64,
 }
 
-/// Lists all campaigns.
+/// Lists all campaigns that have not been archived.
 ///
 /// # Errors
 ///
-/// Returns `DomainError::Infrastructure` if querying or deserialization fails.
+/// Returns `DomainError::Infrastructure` if catching up the projection or
+/// reading the read model fails.
 pub async fn list_campaigns(
     repo: &dyn EventRepository,
+    read_models: &dyn ReadModelStore,
 ) -> Result<Vec<CampaignSummary>, DomainError> {
-    let ids = repo.list_aggregate_ids(EVENT_TYPES).await?;
-    let mut summaries = Vec::with_capacity(ids.len());
-    for id in ids {
-        let stored_events = repo.load_events(id).await?;
-        if stored_events.is_empty() {
-            continue;
-        }
-        let campaign = command_handlers::reconstitute(id, &stored_events)?;
-        if campaign.archived {
-            continue;
-        }
-        summaries.push(CampaignSummary {
-            campaign_id: id,
-            ingested: campaign.ingested,
-            validated: campaign.validated,
-            compiled: campaign.compiled,
-            version: campaign.version,
-        });
-    }
-    Ok(summaries)
+    ProjectionRunner::default()
+        .catch_up(&CampaignProjection, repo, read_models)
+        .await?;
+    list_documents(read_models, CAMPAIGN_SUMMARIES).await
 }
 
 /// Retrieves a campaign by its aggregate ID.
 ///
 /// # Errors
 ///
-/// Returns `DomainError::AggregateNotFound` if no events exist for the ID.
-/// Returns `DomainError::Infrastructure` if event deserialization fails.
+/// Returns `DomainError::AggregateNotFound` if the campaign has no view.
+/// Returns `DomainError::Infrastructure` if catching up the projection or
+/// reading the read model fails.
 pub async fn get_campaign_by_id(
     campaign_id: Uuid,
     repo: &dyn EventRepository,
+    read_models: &dyn ReadModelStore,
 ) -> Result<CampaignView, DomainError> {
-    let stored_events = repo.load_events(campaign_id).await?;
-    if stored_events.is_empty() {
-        return Err(DomainError::AggregateNotFound(campaign_id));
-    }
-    let campaign = command_handlers::reconstitute(campaign_id, &stored_events)?;
-    Ok(CampaignView {
-        campaign_id,
-        ingested: campaign.ingested,
-        validated: campaign.validated,
-        compiled: campaign.compiled,
-        version_hash: campaign.version_hash.clone(),
-        version: campaign.version,
+    ProjectionRunner::default()
+        .catch_up(&CampaignProjection, repo, read_models)
+        .await?;
+    get_document(read_models, CAMPAIGN_VIEWS, campaign_id)
+        .await?
+        .ok_or(DomainError::AggregateNotFound(campaign_id))
+}
+
+/// Loads the compiled form of a campaign straight from its event stream, for
+/// other contexts that need the campaign's runtime data.
+///
+/// # Errors
+///
+/// Returns `DomainError::AggregateNotFound` if the campaign has no events.
+/// Returns `DomainError::Validation` if the campaign has not been compiled.
+/// Returns `DomainError::Infrastructure` if loading the stream or decoding
+/// the compiled data fails.
+pub async fn get_compiled_campaign(
+    campaign_id: Uuid,
+    repo: &dyn EventRepository,
+) -> Result<CompiledCampaign, DomainError> {
+    let campaign = command_handlers::load(campaign_id, repo)
+        .await?
+        .ok_or(DomainError::AggregateNotFound(campaign_id))?;
+    let compiled_data = campaign
+        .compiled_data
+        .ok_or_else(|| DomainError::Validation("campaign has not been compiled".into()))?;
+    serde_json::from_str(&compiled_data).map_err(|e| {
+        DomainError::Infrastructure(format!("compiled campaign deserialization failed: {e}"))
     })
 }
 
@@ -112,13 +114,17 @@ mod tests {
     use otherworlds_core::repository::StoredEvent;
     use uuid::Uuid;
 
-    use crate::application::query_handlers::{get_campaign_by_id, list_campaigns};
+    use crate::application::query_handlers::{
+        get_campaign_by_id, get_compiled_campaign, list_campaigns,
+    };
     use crate::domain::events::{
         CAMPAIGN_ARCHIVED_EVENT_TYPE, CAMPAIGN_COMPILED_EVENT_TYPE, CAMPAIGN_INGESTED_EVENT_TYPE,
         CAMPAIGN_VALIDATED_EVENT_TYPE, CampaignArchived, CampaignCompiled, CampaignIngested,
         CampaignValidated, ContentEventKind,
     };
-    use otherworlds_test_support::{EmptyEventRepository, RecordingEventRepository};
+    use otherworlds_test_support::{
+        EmptyEventRepository, InMemoryReadModelStore, RecordingEventRepository,
+    };
 
     #[tokio::test]
     async fn test_get_campaign_by_id_returns_view_with_state() {
@@ -140,11 +146,14 @@ mod tests {
             correlation_id: Uuid::new_v4(),
             causation_id: Uuid::new_v4(),
             occurred_at: fixed_now,
+            schema_version: 1,
         }];
         let repo = RecordingEventRepository::new(Ok(events));
 
         // Act
-        let view = get_campaign_by_id(campaign_id, &repo).await.unwrap();
+        let view = get_campaign_by_id(campaign_id, &repo, &InMemoryReadModelStore::new())
+            .await
+            .unwrap();
 
         // Assert
         assert_eq!(view.campaign_id, campaign_id);
@@ -162,7 +171,7 @@ mod tests {
         let repo = EmptyEventRepository;
 
         // Act
-        let result = get_campaign_by_id(campaign_id, &repo).await;
+        let result = get_campaign_by_id(campaign_id, &repo, &InMemoryReadModelStore::new()).await;
 
         // Assert
         assert!(result.is_err());
@@ -176,7 +185,9 @@ mod tests {
     async fn test_list_campaigns_returns_empty_when_no_aggregates() {
         let repo = EmptyEventRepository;
 
-        let result = list_campaigns(&repo).await.unwrap();
+        let result = list_campaigns(&repo, &InMemoryReadModelStore::new())
+            .await
+            .unwrap();
 
         assert!(result.is_empty());
     }
@@ -200,10 +211,13 @@ mod tests {
             correlation_id: Uuid::new_v4(),
             causation_id: Uuid::new_v4(),
             occurred_at: fixed_now,
+            schema_version: 1,
         }];
         let repo = RecordingEventRepository::with_aggregate_ids(Ok(events), vec![campaign_id]);
 
-        let result = list_campaigns(&repo).await.unwrap();
+        let result = list_campaigns(&repo, &InMemoryReadModelStore::new())
+            .await
+            .unwrap();
 
         assert_eq!(result.len(), 1);
         assert_eq!(result[0].campaign_id, campaign_id);
@@ -235,6 +249,7 @@ mod tests {
                 correlation_id: Uuid::new_v4(),
                 causation_id: Uuid::new_v4(),
                 occurred_at: fixed_now,
+                schema_version: 1,
             },
             StoredEvent {
                 event_id: Uuid::new_v4(),
@@ -248,12 +263,15 @@ mod tests {
                 correlation_id: Uuid::new_v4(),
                 causation_id: Uuid::new_v4(),
                 occurred_at: fixed_now,
+                schema_version: 1,
             },
         ];
         let repo = RecordingEventRepository::with_aggregate_ids(Ok(events), vec![campaign_id]);
 
         // Act
-        let result = list_campaigns(&repo).await.unwrap();
+        let result = list_campaigns(&repo, &InMemoryReadModelStore::new())
+            .await
+            .unwrap();
 
         // Assert
         assert!(result.is_empty());
@@ -282,6 +300,7 @@ mod tests {
                 correlation_id: Uuid::new_v4(),
                 causation_id: Uuid::new_v4(),
                 occurred_at: fixed_now,
+                schema_version: 1,
             },
             StoredEvent {
                 event_id: Uuid::new_v4(),
@@ -295,6 +314,7 @@ mod tests {
                 correlation_id: Uuid::new_v4(),
                 causation_id: Uuid::new_v4(),
                 occurred_at: fixed_now,
+                schema_version: 1,
             },
             StoredEvent {
                 event_id: Uuid::new_v4(),
@@ -312,12 +332,15 @@ mod tests {
                 correlation_id: Uuid::new_v4(),
                 causation_id: Uuid::new_v4(),
                 occurred_at: fixed_now,
+                schema_version: 1,
             },
         ];
         let repo = RecordingEventRepository::new(Ok(events));
 
         // Act
-        let view = get_campaign_by_id(campaign_id, &repo).await.unwrap();
+        let view = get_campaign_by_id(campaign_id, &repo, &InMemoryReadModelStore::new())
+            .await
+            .unwrap();
 
         // Assert
         assert!(view.compiled);
@@ -345,14 +368,101 @@ mod tests {
             correlation_id: Uuid::new_v4(),
             causation_id: Uuid::new_v4(),
             occurred_at: fixed_now,
+            schema_version: 1,
         }];
         let repo = RecordingEventRepository::with_aggregate_ids(Ok(events), vec![campaign_id]);
 
         // Act
-        let result = list_campaigns(&repo).await.unwrap();
+        let result = list_campaigns(&repo, &InMemoryReadModelStore::new())
+            .await
+            .unwrap();
 
         // Assert
         assert_eq!(result.len(), 1);
         assert!(!result[0].compiled);
     }
+
+    fn compiled_stream(campaign_id: Uuid, compiled_data: &str) -> Vec<StoredEvent> {
+        let fixed_now = Utc.with_ymd_and_hms(2026, 1, 15, 10, 0, 0).unwrap();
+        vec![
+            StoredEvent {
+                event_id: Uuid::new_v4(),
+                aggregate_id: campaign_id,
+                event_type: CAMPAIGN_INGESTED_EVENT_TYPE.to_owned(),
+                payload: serde_json::to_value(ContentEventKind::CampaignIngested(
+                    CampaignIngested {
+                        campaign_id,
+                        version_hash: "abc123".to_owned(),
+                        source: "# My Campaign".to_owned(),
+                    },
+                ))
+                .unwrap(),
+                sequence_number: 1,
+                correlation_id: Uuid::new_v4(),
+                causation_id: Uuid::new_v4(),
+                occurred_at: fixed_now,
+                schema_version: 1,
+            },
+            StoredEvent {
+                event_id: Uuid::new_v4(),
+                aggregate_id: campaign_id,
+                event_type: CAMPAIGN_COMPILED_EVENT_TYPE.to_owned(),
+                payload: serde_json::to_value(ContentEventKind::CampaignCompiled(
+                    CampaignCompiled {
+                        campaign_id,
+                        version_hash: "abc123".to_owned(),
+                        compiled_data: compiled_data.to_owned(),
+                    },
+                ))
+                .unwrap(),
+                sequence_number: 2,
+                correlation_id: Uuid::new_v4(),
+                causation_id: Uuid::new_v4(),
+                occurred_at: fixed_now,
+                schema_version: 1,
+            },
+        ]
+    }
+
+    #[tokio::test]
+    async fn test_get_compiled_campaign_returns_compiled_data() {
+        // Arrange
+        let campaign_id = Uuid::new_v4();
+        let repo = RecordingEventRepository::new(Ok(compiled_stream(
+            campaign_id,
+            r#"{"title":"Apocalypse","ruleset":"pbta","scenes":{},"npcs":{}}"#,
+        )));
+
+        // Act
+        let compiled = get_compiled_campaign(campaign_id, &repo).await.unwrap();
+
+        // Assert
+        assert_eq!(compiled.title, "Apocalypse");
+        assert_eq!(compiled.ruleset, Some("pbta".to_owned()));
+    }
+
+    #[tokio::test]
+    async fn test_get_compiled_campaign_without_ruleset_defaults_to_none() {
+        // Arrange
+        let campaign_id = Uuid::new_v4();
+        let repo = RecordingEventRepository::new(Ok(compiled_stream(
+            campaign_id,
+            r#"{"title":"Old","description":null,"min_engine_version":null,"scenes":{},"npcs":{}}"#,
+        )));
+
+        // Act
+        let compiled = get_compiled_campaign(campaign_id, &repo).await.unwrap();
+
+        // Assert
+        assert_eq!(compiled.ruleset, None);
+    }
+
+    #[tokio::test]
+    async fn test_get_compiled_campaign_returns_not_found_when_no_events() {
+        // Act
+        let result = get_compiled_campaign(Uuid::new_v4(), &EmptyEventRepository).await;
+
+        // Assert
+        assert!(matches!(result, Err(DomainError::AggregateNotFound(_))));
+    }
 }
@@ -7,6 +7,8 @@ use otherworlds_core::clock::Clock;
 use otherworlds_core::error::DomainError;
 use otherworlds_core::event::EventMetadata;
 use otherworlds_core::rng::DeterministicRng;
+use otherworlds_core::snapshot::Snapshottable;
+use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 use super::compiler::compile_parsed_campaign;
@@ -18,9 +20,10 @@ use super::events::{
     CAMPAIGN_VALIDATED_EVENT_TYPE, CampaignArchived, CampaignCompiled, CampaignIngested,
     CampaignValidated, ContentEvent, ContentEventKind,
 };
+use super::upcasters::current_schema_version;
 
 /// The aggregate root for a campaign.
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 #[allow(clippy::struct_excessive_bools)]
 pub struct Campaign {
     /// Aggregate identifier.
@@ -42,6 +45,7 @@ pub struct Campaign {
     /// The compiled campaign data as JSON (set on compilation).
     pub(crate) compiled_data: Option<String>,
     /// Uncommitted events pending persistence.
+    #[serde(skip)]
     uncommitted_events: Vec<ContentEvent>,
 }
 
@@ -114,6 +118,7 @@ impl Campaign {
                 correlation_id,
                 causation_id: correlation_id,
                 occurred_at: clock.now(),
+                schema_version: current_schema_version(CAMPAIGN_INGESTED_EVENT_TYPE),
             },
             kind: ContentEventKind::CampaignIngested(CampaignIngested {
                 campaign_id: self.id,
@@ -167,6 +172,7 @@ impl Campaign {
                 correlation_id,
                 causation_id: correlation_id,
                 occurred_at: clock.now(),
+                schema_version: current_schema_version(CAMPAIGN_VALIDATED_EVENT_TYPE),
             },
             kind: ContentEventKind::CampaignValidated(CampaignValidated {
                 campaign_id: self.id,
@@ -228,6 +234,7 @@ impl Campaign {
                 correlation_id,
                 causation_id: correlation_id,
                 occurred_at: clock.now(),
+                schema_version: current_schema_version(CAMPAIGN_COMPILED_EVENT_TYPE),
             },
             kind: ContentEventKind::CampaignCompiled(CampaignCompiled {
                 campaign_id: self.id,
@@ -266,6 +273,7 @@ impl Campaign {
                 correlation_id,
                 causation_id: correlation_id,
                 occurred_at: clock.now(),
+                schema_version: current_schema_version(CAMPAIGN_ARCHIVED_EVENT_TYPE),
             },
             kind: ContentEventKind::CampaignArchived(CampaignArchived {
                 campaign_id: self.id,
@@ -318,6 +326,10 @@ impl AggregateRoot for Campaign {
     }
 }
 
+impl Snapshottable for Campaign {
+    const AGGREGATE_TYPE: &'static str = "content.campaign";
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -678,6 +690,7 @@ mod tests {
                 correlation_id: Uuid::new_v4(),
                 causation_id: Uuid::new_v4(),
                 occurred_at: fixed_now,
+                schema_version: current_schema_version(CAMPAIGN_ARCHIVED_EVENT_TYPE),
             },
             kind: ContentEventKind::CampaignArchived(CampaignArchived { campaign_id }),
         };
@@ -717,6 +730,7 @@ mod tests {
                 correlation_id: Uuid::new_v4(),
                 causation_id: Uuid::new_v4(),
                 occurred_at: fixed_now,
+                schema_version: current_schema_version(CAMPAIGN_INGESTED_EVENT_TYPE),
             },
             kind: ContentEventKind::CampaignIngested(CampaignIngested {
                 campaign_id,
@@ -751,6 +765,7 @@ mod tests {
                 correlation_id: Uuid::new_v4(),
                 causation_id: Uuid::new_v4(),
                 occurred_at: fixed_now,
+                schema_version: current_schema_version(CAMPAIGN_INGESTED_EVENT_TYPE),
             },
             kind: ContentEventKind::CampaignIngested(CampaignIngested {
                 campaign_id,
@@ -769,6 +784,7 @@ mod tests {
                 correlation_id: Uuid::new_v4(),
                 causation_id: Uuid::new_v4(),
                 occurred_at: fixed_now,
+                schema_version: current_schema_version(CAMPAIGN_COMPILED_EVENT_TYPE),
             },
             kind: ContentEventKind::CampaignCompiled(CampaignCompiled {
                 campaign_id,
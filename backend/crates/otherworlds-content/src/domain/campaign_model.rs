@@ -3,6 +3,7 @@
 use std::collections::HashMap;
 
 use serde::{Deserialize, Serialize};
+use uuid::Uuid;
 
 /// Front-matter metadata extracted from the YAML block at the top of campaign source.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
@@ -13,6 +14,25 @@ pub struct CampaignFrontMatter {
     pub description: Option<String>,
     /// Minimum engine version required to run this campaign.
     pub min_engine_version: Option<u32>,
+    /// Ruleset checks are rolled and judged by (one of
+    /// `otherworlds_core::ruleset::RULESET_NAMES`); d20 when absent.
+    #[serde(default)]
+    pub ruleset: Option<String>,
+    /// Campaign-defined effects beyond the engine's catalogue.
+    #[serde(default)]
+    pub custom_effects: Vec<CustomEffectDefinition>,
+}
+
+/// One version of a campaign-defined effect, declared in front matter.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CustomEffectDefinition {
+    /// Effect name, unique per version.
+    pub name: String,
+    /// Declaration version, from 1. A changed schema gets a new version, so
+    /// effects produced under the old one keep their meaning.
+    pub version: u32,
+    /// JSON schema the effect's data must satisfy.
+    pub schema: serde_json::Value,
 }
 
 /// A choice within a scene, linking to another scene by ID.
@@ -44,8 +64,33 @@ pub struct ParsedNpc {
     pub id: String,
     /// NPC display name.
     pub name: String,
-    /// Optional NPC disposition.
+    /// Optional NPC disposition: a band name or a score from −100 to 100.
     pub disposition: Option<String>,
+    /// Factions the NPC belongs to.
+    #[serde(default)]
+    pub factions: Vec<String>,
+}
+
+/// An exit from a location, leading to another location by ID.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ParsedExit {
+    /// Target location ID this exit leads to.
+    pub target: String,
+    /// Whether the way starts locked (`- exit: <id> (locked)`).
+    pub locked: bool,
+}
+
+/// A location parsed from the campaign Markdown source.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ParsedLocation {
+    /// Unique location identifier (from `# Location: <id>`).
+    pub id: String,
+    /// Location display name.
+    pub name: String,
+    /// Exits to other locations. Exits run both ways.
+    pub exits: Vec<ParsedExit>,
+    /// IDs of the NPCs that start at this location.
+    pub npc_refs: Vec<String>,
 }
 
 /// Intermediate representation of a fully parsed campaign.
@@ -57,6 +102,10 @@ pub struct ParsedCampaign {
     pub scenes: Vec<ParsedScene>,
     /// NPC definitions in document order.
     pub npcs: Vec<ParsedNpc>,
+    /// Location definitions in document order. The party starts at the
+    /// first.
+    #[serde(default)]
+    pub locations: Vec<ParsedLocation>,
 }
 
 /// A compiled choice with resolved scene reference.
@@ -88,8 +137,33 @@ pub struct CompiledNpc {
     pub id: String,
     /// NPC display name.
     pub name: String,
-    /// Optional NPC disposition.
+    /// Optional NPC disposition: a band name or a score from −100 to 100.
     pub disposition: Option<String>,
+    /// Factions the NPC belongs to.
+    #[serde(default)]
+    pub factions: Vec<String>,
+}
+
+/// A compiled exit with resolved location reference.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CompiledExit {
+    /// Target location ID this exit leads to.
+    pub target_location_id: String,
+    /// Whether the way starts locked.
+    pub locked: bool,
+}
+
+/// A compiled location indexed for O(1) lookup.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CompiledLocation {
+    /// Unique location identifier.
+    pub id: String,
+    /// Location display name.
+    pub name: String,
+    /// Exits to other locations.
+    pub exits: Vec<CompiledExit>,
+    /// IDs of the NPCs that start at this location.
+    pub npc_refs: Vec<String>,
 }
 
 /// Compiled campaign data optimised for runtime access.
@@ -101,10 +175,42 @@ pub struct CompiledCampaign {
     pub description: Option<String>,
     /// Minimum engine version required.
     pub min_engine_version: Option<u32>,
+    /// Ruleset checks are rolled and judged by; d20 when absent.
+    #[serde(default)]
+    pub ruleset: Option<String>,
+    /// Campaign-defined effects beyond the engine's catalogue.
+    #[serde(default)]
+    pub custom_effects: Vec<CustomEffectDefinition>,
     /// Scenes indexed by scene ID.
     pub scenes: HashMap<String, CompiledScene>,
     /// NPCs indexed by NPC ID.
     pub npcs: HashMap<String, CompiledNpc>,
+    /// Locations indexed by location ID.
+    #[serde(default)]
+    pub locations: HashMap<String, CompiledLocation>,
+    /// ID of the location the party starts at, if the campaign has any.
+    #[serde(default)]
+    pub start_location: Option<String>,
+}
+
+/// Returns the world entity ID of an NPC of a campaign. The ID is derived
+/// from the campaign and NPC IDs, so every world seeded from the campaign
+/// knows the NPC by the same ID.
+#[must_use]
+pub fn npc_entity_id(campaign_id: Uuid, npc_id: &str) -> Uuid {
+    Uuid::new_v5(&campaign_id, format!("npc:{npc_id}").as_bytes())
+}
+
+/// Returns the world entity ID of a faction named by a campaign's NPCs.
+#[must_use]
+pub fn faction_entity_id(campaign_id: Uuid, faction: &str) -> Uuid {
+    Uuid::new_v5(&campaign_id, format!("faction:{faction}").as_bytes())
+}
+
+/// Returns the world entity ID of a location of a campaign.
+#[must_use]
+pub fn location_entity_id(campaign_id: Uuid, location_id: &str) -> Uuid {
+    Uuid::new_v5(&campaign_id, format!("location:{location_id}").as_bytes())
 }
 
 #[cfg(test)]
@@ -117,6 +223,8 @@ mod tests {
             title: "The Lost Temple".to_owned(),
             description: Some("An adventure".to_owned()),
             min_engine_version: Some(1),
+            ruleset: None,
+            custom_effects: Vec::new(),
         };
         let json = serde_json::to_string(&fm).unwrap();
         let deserialized: CampaignFrontMatter = serde_json::from_str(&json).unwrap();
@@ -130,6 +238,8 @@ mod tests {
                 title: "Test".to_owned(),
                 description: None,
                 min_engine_version: None,
+                ruleset: None,
+                custom_effects: Vec::new(),
             },
             scenes: vec![ParsedScene {
                 id: "start".to_owned(),
@@ -144,6 +254,16 @@ mod tests {
                 id: "guard".to_owned(),
                 name: "Guard".to_owned(),
                 disposition: Some("neutral".to_owned()),
+                factions: vec!["city_watch".to_owned()],
+            }],
+            locations: vec![ParsedLocation {
+                id: "harbour".to_owned(),
+                name: "Harbour".to_owned(),
+                exits: vec![ParsedExit {
+                    target: "vault".to_owned(),
+                    locked: true,
+                }],
+                npc_refs: vec!["guard".to_owned()],
             }],
         };
         let json = serde_json::to_string(&parsed).unwrap();
@@ -173,14 +293,19 @@ mod tests {
                 id: "guard".to_owned(),
                 name: "Guard".to_owned(),
                 disposition: Some("neutral".to_owned()),
+                factions: vec!["city_watch".to_owned()],
             },
         );
         let compiled = CompiledCampaign {
             title: "Test".to_owned(),
             description: None,
             min_engine_version: None,
+            ruleset: None,
+            custom_effects: Vec::new(),
             scenes,
             npcs,
+            locations: HashMap::new(),
+            start_location: None,
         };
         let json = serde_json::to_string(&compiled).unwrap();
         let deserialized: CompiledCampaign = serde_json::from_str(&json).unwrap();
@@ -193,10 +318,36 @@ mod tests {
             title: "Minimal".to_owned(),
             description: None,
             min_engine_version: None,
+            ruleset: None,
+            custom_effects: Vec::new(),
             scenes: HashMap::new(),
             npcs: HashMap::new(),
+            locations: HashMap::new(),
+            start_location: None,
         };
         assert!(compiled.description.is_none());
         assert!(compiled.min_engine_version.is_none());
     }
+
+    #[test]
+    fn test_entity_ids_are_stable_per_campaign_and_kind() {
+        let campaign_id = Uuid::new_v4();
+
+        assert_eq!(
+            npc_entity_id(campaign_id, "guard"),
+            npc_entity_id(campaign_id, "guard")
+        );
+        assert_ne!(
+            npc_entity_id(campaign_id, "guard"),
+            faction_entity_id(campaign_id, "guard")
+        );
+        assert_ne!(
+            npc_entity_id(campaign_id, "guard"),
+            location_entity_id(campaign_id, "guard")
+        );
+        assert_ne!(
+            npc_entity_id(campaign_id, "guard"),
+            npc_entity_id(Uuid::new_v4(), "guard")
+        );
+    }
 }
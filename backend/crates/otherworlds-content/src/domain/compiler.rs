@@ -1,12 +1,13 @@
 //! Content Authoring — campaign compiler.
 //!
 //! Converts a `ParsedCampaign` into a `CompiledCampaign` with
-//! HashMap-indexed scenes and NPCs for O(1) runtime lookup.
+//! HashMap-indexed scenes, NPCs and locations for O(1) runtime lookup.
 
 use std::collections::HashMap;
 
 use super::campaign_model::{
-    CompiledCampaign, CompiledChoice, CompiledNpc, CompiledScene, ParsedCampaign,
+    CompiledCampaign, CompiledChoice, CompiledExit, CompiledLocation, CompiledNpc, CompiledScene,
+    ParsedCampaign,
 };
 
 /// Compiles a parsed campaign into an indexed runtime representation.
@@ -46,17 +47,43 @@ pub fn compile_parsed_campaign(parsed: &ParsedCampaign) -> CompiledCampaign {
                 id: n.id.clone(),
                 name: n.name.clone(),
                 disposition: n.disposition.clone(),
+                factions: n.factions.clone(),
             };
             (n.id.clone(), npc)
         })
         .collect();
 
+    let locations: HashMap<String, CompiledLocation> = parsed
+        .locations
+        .iter()
+        .map(|l| {
+            let location = CompiledLocation {
+                id: l.id.clone(),
+                name: l.name.clone(),
+                exits: l
+                    .exits
+                    .iter()
+                    .map(|e| CompiledExit {
+                        target_location_id: e.target.clone(),
+                        locked: e.locked,
+                    })
+                    .collect(),
+                npc_refs: l.npc_refs.clone(),
+            };
+            (l.id.clone(), location)
+        })
+        .collect();
+
     CompiledCampaign {
         title: parsed.front_matter.title.clone(),
         description: parsed.front_matter.description.clone(),
         min_engine_version: parsed.front_matter.min_engine_version,
+        ruleset: parsed.front_matter.ruleset.clone(),
+        custom_effects: parsed.front_matter.custom_effects.clone(),
         scenes,
         npcs,
+        locations,
+        start_location: parsed.locations.first().map(|l| l.id.clone()),
     }
 }
 
@@ -64,7 +91,7 @@ pub fn compile_parsed_campaign(parsed: &ParsedCampaign) -> CompiledCampaign {
 mod tests {
     use super::*;
     use crate::domain::campaign_model::{
-        CampaignFrontMatter, ParsedChoice, ParsedNpc, ParsedScene,
+        CampaignFrontMatter, ParsedChoice, ParsedExit, ParsedLocation, ParsedNpc, ParsedScene,
     };
 
     #[test]
@@ -74,6 +101,8 @@ mod tests {
                 title: "Test".to_owned(),
                 description: None,
                 min_engine_version: None,
+                ruleset: None,
+                custom_effects: Vec::new(),
             },
             scenes: vec![ParsedScene {
                 id: "start".to_owned(),
@@ -82,6 +111,7 @@ mod tests {
                 npc_refs: Vec::new(),
             }],
             npcs: Vec::new(),
+            locations: Vec::new(),
         };
 
         let compiled = compile_parsed_campaign(&parsed);
@@ -90,6 +120,8 @@ mod tests {
         assert!(compiled.scenes.contains_key("start"));
         assert_eq!(compiled.scenes["start"].narrative_text, "Hello.");
         assert!(compiled.npcs.is_empty());
+        assert!(compiled.locations.is_empty());
+        assert_eq!(compiled.start_location, None);
     }
 
     #[test]
@@ -99,6 +131,8 @@ mod tests {
                 title: "Test".to_owned(),
                 description: Some("A test campaign".to_owned()),
                 min_engine_version: Some(1),
+                ruleset: None,
+                custom_effects: Vec::new(),
             },
             scenes: vec![ParsedScene {
                 id: "start".to_owned(),
@@ -113,7 +147,9 @@ mod tests {
                 id: "guard".to_owned(),
                 name: "Guard".to_owned(),
                 disposition: Some("neutral".to_owned()),
+                factions: vec!["city_watch".to_owned()],
             }],
+            locations: Vec::new(),
         };
 
         let compiled = compile_parsed_campaign(&parsed);
@@ -126,6 +162,7 @@ mod tests {
             compiled.npcs["guard"].disposition,
             Some("neutral".to_owned())
         );
+        assert_eq!(compiled.npcs["guard"].factions, vec!["city_watch"]);
         assert_eq!(compiled.scenes["start"].choices.len(), 1);
         assert_eq!(compiled.scenes["start"].choices[0].target_scene_id, "start");
     }
@@ -137,6 +174,8 @@ mod tests {
                 title: "Round Trip".to_owned(),
                 description: None,
                 min_engine_version: None,
+                ruleset: None,
+                custom_effects: Vec::new(),
             },
             scenes: vec![ParsedScene {
                 id: "start".to_owned(),
@@ -145,6 +184,7 @@ mod tests {
                 npc_refs: Vec::new(),
             }],
             npcs: Vec::new(),
+            locations: Vec::new(),
         };
 
         let compiled = compile_parsed_campaign(&parsed);
@@ -152,4 +192,47 @@ mod tests {
         let deserialized: CompiledCampaign = serde_json::from_str(&json).unwrap();
         assert_eq!(compiled, deserialized);
     }
+
+    #[test]
+    fn test_compile_campaign_with_locations_starts_at_the_first() {
+        let location = |id: &str, exits: Vec<ParsedExit>| ParsedLocation {
+            id: id.to_owned(),
+            name: id.to_uppercase(),
+            exits,
+            npc_refs: Vec::new(),
+        };
+        let parsed = ParsedCampaign {
+            front_matter: CampaignFrontMatter {
+                title: "Harbour".to_owned(),
+                description: None,
+                min_engine_version: None,
+                ruleset: None,
+                custom_effects: Vec::new(),
+            },
+            scenes: Vec::new(),
+            npcs: Vec::new(),
+            locations: vec![
+                location(
+                    "market",
+                    vec![ParsedExit {
+                        target: "vault".to_owned(),
+                        locked: true,
+                    }],
+                ),
+                location("vault", Vec::new()),
+            ],
+        };
+
+        let compiled = compile_parsed_campaign(&parsed);
+        assert_eq!(compiled.start_location, Some("market".to_owned()));
+        assert_eq!(compiled.locations.len(), 2);
+        assert_eq!(compiled.locations["market"].name, "MARKET");
+        assert_eq!(
+            compiled.locations["market"].exits,
+            vec![CompiledExit {
+                target_location_id: "vault".to_owned(),
+                locked: true,
+            }]
+        );
+    }
 }
@@ -6,4 +6,5 @@ pub mod commands;
 pub mod compiler;
 pub mod events;
 pub mod parser;
+pub mod upcasters;
 pub mod validator;
@@ -7,7 +7,8 @@ use otherworlds_core::error::DomainError;
 use pulldown_cmark::{Event, HeadingLevel, Options, Parser, Tag, TagEnd};
 
 use super::campaign_model::{
-    CampaignFrontMatter, ParsedCampaign, ParsedChoice, ParsedNpc, ParsedScene,
+    CampaignFrontMatter, ParsedCampaign, ParsedChoice, ParsedExit, ParsedLocation, ParsedNpc,
+    ParsedScene,
 };
 
 /// Extracts YAML front-matter from campaign source.
@@ -63,6 +64,24 @@ enum SectionKind {
     SceneNpcRefs,
     /// Inside a `# NPC: <id>` block.
     NpcDefinition,
+    /// Inside a `# Location: <id>` block.
+    LocationDefinition,
+}
+
+/// Parses the value of a location's `exit:` item: a location ID, optionally
+/// followed by `(locked)`.
+fn parse_exit(value: &str) -> ParsedExit {
+    let value = value.trim();
+    match value.strip_suffix("(locked)") {
+        Some(target) => ParsedExit {
+            target: target.trim().to_owned(),
+            locked: true,
+        },
+        None => ParsedExit {
+            target: value.to_owned(),
+            locked: false,
+        },
+    }
 }
 
 /// Parses the full campaign source into a `ParsedCampaign`.
@@ -76,6 +95,7 @@ pub fn parse_campaign(source: &str) -> Result<ParsedCampaign, DomainError> {
 
     let mut scenes: Vec<ParsedScene> = Vec::new();
     let mut npcs: Vec<ParsedNpc> = Vec::new();
+    let mut locations: Vec<ParsedLocation> = Vec::new();
     let mut current_section: Option<SectionKind> = None;
 
     let parser = Parser::new_ext(body, Options::empty());
@@ -115,8 +135,17 @@ pub fn parse_campaign(source: &str) -> Result<ParsedCampaign, DomainError> {
                                 id: npc_id,
                                 name: String::new(),
                                 disposition: None,
+                                factions: Vec::new(),
                             });
                             current_section = Some(SectionKind::NpcDefinition);
+                        } else if let Some(location_id) = heading_text.strip_prefix("Location:") {
+                            locations.push(ParsedLocation {
+                                id: location_id.trim().to_owned(),
+                                name: String::new(),
+                                exits: Vec::new(),
+                                npc_refs: Vec::new(),
+                            });
+                            current_section = Some(SectionKind::LocationDefinition);
                         } else {
                             current_section = None;
                         }
@@ -213,7 +242,8 @@ pub fn parse_campaign(source: &str) -> Result<ParsedCampaign, DomainError> {
                 }
             }
 
-            // Parse list items in NPC definition section — `name:` and `disposition:` properties.
+            // Parse list items in NPC definition section — `name:`, `disposition:`
+            // and `faction:` properties. Each `faction:` item adds a faction.
             Event::Start(Tag::Item) if current_section == Some(SectionKind::NpcDefinition) => {
                 i += 1;
                 let mut item_text = String::new();
@@ -230,10 +260,40 @@ pub fn parse_campaign(source: &str) -> Result<ParsedCampaign, DomainError> {
                     if let Some(npc) = npcs.last_mut() {
                         name_val.trim().clone_into(&mut npc.name);
                     }
-                } else if let Some(disp_val) = item_trimmed.strip_prefix("disposition:")
+                } else if let Some(disp_val) = item_trimmed.strip_prefix("disposition:") {
+                    if let Some(npc) = npcs.last_mut() {
+                        npc.disposition = Some(disp_val.trim().to_owned());
+                    }
+                } else if let Some(faction) = item_trimmed.strip_prefix("faction:")
                     && let Some(npc) = npcs.last_mut()
                 {
-                    npc.disposition = Some(disp_val.trim().to_owned());
+                    npc.factions.push(faction.trim().to_owned());
+                }
+            }
+
+            // Parse list items in location definition section — `name:`,
+            // `exit:` and `npc:` properties. Each `exit:` and `npc:` item adds
+            // one exit or NPC.
+            Event::Start(Tag::Item) if current_section == Some(SectionKind::LocationDefinition) => {
+                i += 1;
+                let mut item_text = String::new();
+                while i < events.len() {
+                    match &events[i] {
+                        Event::End(TagEnd::Item) => break,
+                        Event::Text(t) => item_text.push_str(t),
+                        _ => {}
+                    }
+                    i += 1;
+                }
+                let item_trimmed = item_text.trim();
+                if let Some(location) = locations.last_mut() {
+                    if let Some(name_val) = item_trimmed.strip_prefix("name:") {
+                        name_val.trim().clone_into(&mut location.name);
+                    } else if let Some(exit) = item_trimmed.strip_prefix("exit:") {
+                        location.exits.push(parse_exit(exit));
+                    } else if let Some(npc_ref) = item_trimmed.strip_prefix("npc:") {
+                        location.npc_refs.push(npc_ref.trim().to_owned());
+                    }
                 }
             }
 
@@ -246,6 +306,7 @@ pub fn parse_campaign(source: &str) -> Result<ParsedCampaign, DomainError> {
         front_matter,
         scenes,
         npcs,
+        locations,
     })
 }
 
@@ -263,6 +324,26 @@ mod tests {
         assert!(body.trim_start().starts_with("# Scene: start"));
     }
 
+    #[test]
+    fn test_extract_front_matter_reads_ruleset() {
+        let source = "---\ntitle: \"Apocalypse\"\nruleset: pbta\n---\n\n# Scene: start\n";
+        let (fm, _) = extract_front_matter(source).unwrap();
+        assert_eq!(fm.ruleset, Some("pbta".to_owned()));
+    }
+
+    #[test]
+    fn test_extract_front_matter_reads_custom_effects() {
+        let source = "---\ntitle: \"Storms\"\ncustom_effects:\n  - name: summon_storm\n    version: 1\n    schema:\n      type: object\n      required: [intensity]\n---\n\n# Scene: start\n";
+        let (fm, _) = extract_front_matter(source).unwrap();
+        assert_eq!(fm.custom_effects.len(), 1);
+        assert_eq!(fm.custom_effects[0].name, "summon_storm");
+        assert_eq!(fm.custom_effects[0].version, 1);
+        assert_eq!(
+            fm.custom_effects[0].schema,
+            serde_json::json!({ "type": "object", "required": ["intensity"] })
+        );
+    }
+
     #[test]
     fn test_extract_front_matter_missing_opening() {
         let source = "# No front matter here\n";
@@ -353,6 +434,58 @@ mod tests {
         assert_eq!(parsed.npcs[0].id, "guard_captain");
         assert_eq!(parsed.npcs[0].name, "Captain Theron");
         assert_eq!(parsed.npcs[0].disposition, Some("neutral".to_owned()));
+        assert!(parsed.npcs[0].factions.is_empty());
+    }
+
+    #[test]
+    fn test_parse_npc_factions() {
+        let source = concat!(
+            "---\ntitle: \"Test\"\n---\n\n",
+            "# Scene: start\n\nHello.\n\n",
+            "# NPC: guard_captain\n\n",
+            "- name: Captain Theron\n",
+            "- disposition: -30\n",
+            "- faction: city_watch\n",
+            "- faction: temple\n",
+        );
+        let parsed = parse_campaign(source).unwrap();
+        assert_eq!(parsed.npcs[0].disposition, Some("-30".to_owned()));
+        assert_eq!(parsed.npcs[0].factions, vec!["city_watch", "temple"]);
+    }
+
+    #[test]
+    fn test_parse_location_definitions() {
+        let source = concat!(
+            "---\ntitle: \"Test\"\n---\n\n",
+            "# Scene: start\n\nHello.\n\n",
+            "# Location: market\n\n",
+            "- name: Harbour Market\n",
+            "- exit: dock\n",
+            "- exit: vault (locked)\n",
+            "- npc: guard_captain\n\n",
+            "# Location: dock\n\n",
+            "- name: Dock\n",
+        );
+        let parsed = parse_campaign(source).unwrap();
+        assert_eq!(parsed.locations.len(), 2);
+        let market = &parsed.locations[0];
+        assert_eq!(market.id, "market");
+        assert_eq!(market.name, "Harbour Market");
+        assert_eq!(
+            market.exits,
+            vec![
+                ParsedExit {
+                    target: "dock".to_owned(),
+                    locked: false,
+                },
+                ParsedExit {
+                    target: "vault".to_owned(),
+                    locked: true,
+                },
+            ]
+        );
+        assert_eq!(market.npc_refs, vec!["guard_captain"]);
+        assert!(parsed.locations[1].exits.is_empty());
     }
 
     #[test]
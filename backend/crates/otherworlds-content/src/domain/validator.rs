@@ -3,15 +3,17 @@
 //! Validates a `ParsedCampaign` against structural integrity rules
 //! before compilation.
 
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
+use otherworlds_core::disposition::parse_disposition;
 use otherworlds_core::error::DomainError;
+use otherworlds_core::ruleset::ruleset_index;
 
-use super::campaign_model::ParsedCampaign;
+use super::campaign_model::{ParsedCampaign, ParsedNpc};
 
 /// Validates a parsed campaign for structural correctness.
 ///
-/// Checks seven rules:
+/// Checks eleven rules:
 /// 1. Front-matter title must be non-empty (after trim)
 /// 2. At least one scene defined
 /// 3. No duplicate scene IDs
@@ -19,6 +21,14 @@ use super::campaign_model::ParsedCampaign;
 /// 5. All choice targets reference defined scene IDs
 /// 6. All NPC refs in scenes reference defined NPC IDs
 /// 7. Every NPC must have a non-empty name
+/// 8. A front-matter ruleset, if given, must be one the engine supports
+/// 9. Every custom effect has a name, a version from 1 unique for that name,
+///    and a valid JSON schema
+/// 10. Every NPC disposition parses as a band name or an in-range score (see
+///     `otherworlds_core::disposition`), and every faction is named
+/// 11. Locations have unique IDs and non-empty names, exits lead to other
+///     defined locations and agree on whether they are locked, and each
+///     defined NPC starts in at most one location
 ///
 /// # Errors
 ///
@@ -83,6 +93,47 @@ pub fn validate_parsed_campaign(parsed: &ParsedCampaign) -> Result<(), DomainErr
         }
     }
 
+    // Rule 8: A named ruleset must be supported.
+    if let Some(ruleset) = &parsed.front_matter.ruleset
+        && let Err(DomainError::Validation(reason)) = ruleset_index(ruleset)
+    {
+        errors.push(reason);
+    }
+
+    // Rule 9: Custom effects are named, uniquely versioned, and have a valid schema.
+    let mut custom_effects = HashSet::new();
+    for effect in &parsed.front_matter.custom_effects {
+        if effect.name.trim().is_empty() {
+            errors.push("custom effect must have a non-empty name".to_owned());
+        }
+        if effect.version == 0 {
+            errors.push(format!(
+                "custom effect '{}' versions start at 1",
+                effect.name
+            ));
+        }
+        if !custom_effects.insert((&effect.name, effect.version)) {
+            errors.push(format!(
+                "duplicate custom effect: {} v{}",
+                effect.name, effect.version
+            ));
+        }
+        if let Err(e) = jsonschema::validator_for(&effect.schema) {
+            errors.push(format!(
+                "custom effect '{}' v{} has an invalid schema: {e}",
+                effect.name, effect.version
+            ));
+        }
+    }
+
+    // Rule 10: NPC dispositions and factions seed a world's dispositions.
+    for npc in &parsed.npcs {
+        check_npc_standing(npc, &mut errors);
+    }
+
+    // Rule 11: Locations form a consistent graph.
+    check_locations(parsed, &npc_ids, &mut errors);
+
     if errors.is_empty() {
         Ok(())
     } else {
@@ -90,11 +141,89 @@ pub fn validate_parsed_campaign(parsed: &ParsedCampaign) -> Result<(), DomainErr
     }
 }
 
+/// Checks an NPC's disposition and factions, adding any errors to `errors`.
+fn check_npc_standing(npc: &ParsedNpc, errors: &mut Vec<String>) {
+    // The world seeder parses the same text, so whatever passes here seeds.
+    if let Some(disposition) = &npc.disposition
+        && let Err(DomainError::Validation(reason)) = parse_disposition(disposition)
+    {
+        errors.push(format!(
+            "NPC '{}' has disposition '{disposition}': {reason}",
+            npc.id
+        ));
+    }
+    if npc.factions.iter().any(|faction| faction.trim().is_empty()) {
+        errors.push(format!("NPC '{}' has an unnamed faction", npc.id));
+    }
+}
+
+/// Checks the campaign's locations, adding any errors to `errors`.
+fn check_locations(parsed: &ParsedCampaign, npc_ids: &HashSet<&String>, errors: &mut Vec<String>) {
+    let mut location_ids = HashSet::new();
+    for location in &parsed.locations {
+        if !location_ids.insert(location.id.as_str()) {
+            errors.push(format!("duplicate location ID: {}", location.id));
+        }
+        if location.name.trim().is_empty() {
+            errors.push(format!(
+                "location '{}' must have a non-empty name",
+                location.id
+            ));
+        }
+    }
+
+    // Exits run both ways, so one declared from both ends must agree.
+    let mut exits: HashMap<(&str, &str), bool> = HashMap::new();
+    let mut placements: HashMap<&str, &str> = HashMap::new();
+    for location in &parsed.locations {
+        for exit in &location.exits {
+            if exit.target == location.id {
+                errors.push(format!("location '{}' has an exit to itself", location.id));
+                continue;
+            }
+            if !location_ids.contains(exit.target.as_str()) {
+                errors.push(format!(
+                    "location '{}' has exit to undefined location '{}'",
+                    location.id, exit.target
+                ));
+            }
+            let ends = if location.id < exit.target {
+                (location.id.as_str(), exit.target.as_str())
+            } else {
+                (exit.target.as_str(), location.id.as_str())
+            };
+            if let Some(locked) = exits.insert(ends, exit.locked)
+                && locked != exit.locked
+            {
+                errors.push(format!(
+                    "exit between '{}' and '{}' is declared both locked and unlocked",
+                    ends.0, ends.1
+                ));
+            }
+        }
+        for npc_ref in &location.npc_refs {
+            if !npc_ids.contains(npc_ref) {
+                errors.push(format!(
+                    "location '{}' references undefined NPC '{npc_ref}'",
+                    location.id
+                ));
+            }
+            if let Some(first) = placements.insert(npc_ref, &location.id) {
+                errors.push(format!(
+                    "NPC '{npc_ref}' starts at both '{first}' and '{}'",
+                    location.id
+                ));
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::domain::campaign_model::{
-        CampaignFrontMatter, ParsedChoice, ParsedNpc, ParsedScene,
+        CampaignFrontMatter, CustomEffectDefinition, ParsedChoice, ParsedExit, ParsedLocation,
+        ParsedNpc, ParsedScene,
     };
 
     fn valid_campaign() -> ParsedCampaign {
@@ -103,6 +232,8 @@ mod tests {
                 title: "Test Campaign".to_owned(),
                 description: None,
                 min_engine_version: None,
+                ruleset: None,
+                custom_effects: Vec::new(),
             },
             scenes: vec![ParsedScene {
                 id: "start".to_owned(),
@@ -111,6 +242,7 @@ mod tests {
                 npc_refs: Vec::new(),
             }],
             npcs: Vec::new(),
+            locations: Vec::new(),
         }
     }
 
@@ -131,6 +263,69 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_supported_ruleset_passes() {
+        let mut parsed = valid_campaign();
+        parsed.front_matter.ruleset = Some("pbta".to_owned());
+        assert!(validate_parsed_campaign(&parsed).is_ok());
+    }
+
+    #[test]
+    fn test_unknown_ruleset_fails() {
+        let mut parsed = valid_campaign();
+        parsed.front_matter.ruleset = Some("gurps".to_owned());
+        let err = validate_parsed_campaign(&parsed).unwrap_err();
+        match err {
+            DomainError::Validation(msg) => assert!(msg.contains("unknown ruleset 'gurps'")),
+            other => panic!("expected Validation, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_custom_effects_need_name_version_and_valid_schema() {
+        let mut parsed = valid_campaign();
+        let storm = CustomEffectDefinition {
+            name: "summon_storm".to_owned(),
+            version: 1,
+            schema: serde_json::json!({ "type": "object" }),
+        };
+        parsed.front_matter.custom_effects = vec![
+            storm.clone(),
+            CustomEffectDefinition {
+                version: 2,
+                ..storm.clone()
+            },
+        ];
+        assert!(validate_parsed_campaign(&parsed).is_ok());
+
+        parsed.front_matter.custom_effects = vec![
+            storm.clone(),
+            storm.clone(),
+            CustomEffectDefinition {
+                name: " ".to_owned(),
+                ..storm.clone()
+            },
+            CustomEffectDefinition {
+                version: 0,
+                ..storm.clone()
+            },
+            CustomEffectDefinition {
+                schema: serde_json::json!({ "type": "no-such-type" }),
+                ..storm
+            },
+        ];
+        let err = validate_parsed_campaign(&parsed).unwrap_err();
+        match err {
+            DomainError::Validation(msg) => {
+                assert!(msg.contains("duplicate custom effect: summon_storm v1"));
+                assert!(msg.contains("custom effect must have a non-empty name"));
+                assert!(msg.contains("'summon_storm' versions start at 1"));
+                assert!(msg.contains("'summon_storm' v1 has an invalid schema"));
+            }
+            other => panic!("expected Validation, got {other:?}"),
+        }
+    }
+
     #[test]
     fn test_no_scenes_fails() {
         let mut parsed = valid_campaign();
@@ -165,11 +360,13 @@ mod tests {
             id: "guard".to_owned(),
             name: "Guard A".to_owned(),
             disposition: None,
+            factions: Vec::new(),
         });
         parsed.npcs.push(ParsedNpc {
             id: "guard".to_owned(),
             name: "Guard B".to_owned(),
             disposition: None,
+            factions: Vec::new(),
         });
         let err = validate_parsed_campaign(&parsed).unwrap_err();
         match err {
@@ -214,6 +411,7 @@ mod tests {
             id: "guard".to_owned(),
             name: "  ".to_owned(),
             disposition: None,
+            factions: Vec::new(),
         });
         let err = validate_parsed_campaign(&parsed).unwrap_err();
         match err {
@@ -224,6 +422,93 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_npc_dispositions_must_be_bands_or_scores() {
+        let mut parsed = valid_campaign();
+        for (id, disposition) in [
+            ("a", "Friendly"),
+            ("b", "-100"),
+            ("c", "wary"),
+            ("d", "101"),
+        ] {
+            parsed.npcs.push(ParsedNpc {
+                id: id.to_owned(),
+                name: id.to_owned(),
+                disposition: Some(disposition.to_owned()),
+                factions: vec!["city_watch".to_owned()],
+            });
+        }
+        parsed.npcs[0].factions.push(" ".to_owned());
+        let err = validate_parsed_campaign(&parsed).unwrap_err();
+        match err {
+            DomainError::Validation(msg) => {
+                assert!(msg.contains("NPC 'c' has disposition 'wary': unknown disposition band"));
+                assert!(msg.contains("NPC 'd' has disposition '101': disposition score 101"));
+                assert!(msg.contains("NPC 'a' has an unnamed faction"));
+                assert!(!msg.contains("NPC 'b'"));
+            }
+            other => panic!("expected Validation, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_locations_must_form_a_consistent_graph() {
+        let mut parsed = valid_campaign();
+        parsed.npcs = vec![ParsedNpc {
+            id: "guard".to_owned(),
+            name: "Guard".to_owned(),
+            disposition: None,
+            factions: Vec::new(),
+        }];
+        let exit = |target: &str, locked: bool| ParsedExit {
+            target: target.to_owned(),
+            locked,
+        };
+        let location = |id: &str, exits: Vec<ParsedExit>, npc_refs: &[&str]| ParsedLocation {
+            id: id.to_owned(),
+            name: id.to_owned(),
+            exits,
+            npc_refs: npc_refs.iter().map(|n| (*n).to_owned()).collect(),
+        };
+        parsed.locations = vec![
+            location("market", vec![exit("vault", true)], &["guard"]),
+            location("vault", vec![exit("market", true)], &[]),
+        ];
+        assert!(validate_parsed_campaign(&parsed).is_ok());
+
+        parsed.locations = vec![
+            location(
+                "market",
+                vec![
+                    exit("vault", true),
+                    exit("market", false),
+                    exit("cave", false),
+                ],
+                &["guard", "ghost"],
+            ),
+            location("vault", vec![exit("market", false)], &["guard"]),
+            ParsedLocation {
+                name: " ".to_owned(),
+                ..location("vault", Vec::new(), &[])
+            },
+        ];
+        let err = validate_parsed_campaign(&parsed).unwrap_err();
+        match err {
+            DomainError::Validation(msg) => {
+                assert!(msg.contains("duplicate location ID: vault"));
+                assert!(msg.contains("location 'vault' must have a non-empty name"));
+                assert!(msg.contains("location 'market' has an exit to itself"));
+                assert!(msg.contains("exit to undefined location 'cave'"));
+                assert!(msg.contains(
+                    "exit between 'market' and 'vault' is declared both locked and unlocked"
+                ));
+                assert!(msg.contains("references undefined NPC 'ghost'"));
+                assert!(msg.contains("NPC 'guard' starts at both 'market' and 'vault'"));
+            }
+            other => panic!("expected Validation, got {other:?}"),
+        }
+    }
+
     #[test]
     fn test_multiple_errors_collected() {
         let parsed = ParsedCampaign {
@@ -231,9 +516,12 @@ mod tests {
                 title: String::new(),
                 description: None,
                 min_engine_version: None,
+                ruleset: None,
+                custom_effects: Vec::new(),
             },
             scenes: Vec::new(),
             npcs: Vec::new(),
+            locations: Vec::new(),
         };
         let err = validate_parsed_campaign(&parsed).unwrap_err();
         match err {
@@ -39,6 +39,7 @@ pub fn clone_events_for_branch(
                 correlation_id,
                 causation_id: correlation_id,
                 occurred_at: clock.now(),
+                schema_version: event.schema_version,
             }
         })
         .collect()
@@ -106,6 +107,7 @@ mod tests {
             correlation_id: Uuid::new_v4(),
             causation_id: Uuid::new_v4(),
             occurred_at: Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap(),
+            schema_version: 1,
         }
     }
 
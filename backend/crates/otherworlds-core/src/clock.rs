@@ -17,3 +17,16 @@ impl Clock for SystemClock {
         Utc::now()
     }
 }
+
+/// A clock that always returns a fixed point in time.
+///
+/// Used by tests and by replays, which re-execute each recorded command at
+/// the time it originally ran.
+#[derive(Debug, Clone, Copy)]
+pub struct FixedClock(pub DateTime<Utc>);
+
+impl Clock for FixedClock {
+    fn now(&self) -> DateTime<Utc> {
+        self.0
+    }
+}
@@ -0,0 +1,185 @@
+//! Dispositions — the scale on which an NPC or faction regards the party.
+//!
+//! A disposition is a whole-number score between [`MIN_SCORE`] and
+//! [`MAX_SCORE`]. Every score falls in exactly one named [`DispositionBand`].
+//! The scale lives here so that content, which validates the dispositions
+//! authors write, and world state, which keeps the scores, read them the same
+//! way. See ADR-0048.
+
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::DomainError;
+
+/// The lowest disposition score.
+pub const MIN_SCORE: i32 = -100;
+
+/// The highest disposition score.
+pub const MAX_SCORE: i32 = 100;
+
+/// A named band of disposition scores.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DispositionBand {
+    /// −100 to −60: will act against the party.
+    Hostile,
+    /// −59 to −20: distrustful and unhelpful.
+    Unfriendly,
+    /// −19 to 19: indifferent.
+    Neutral,
+    /// 20 to 59: well disposed and willing to help.
+    Friendly,
+    /// 60 to 100: will take risks for the party.
+    Allied,
+}
+
+impl DispositionBand {
+    /// Every band, from most hostile to most allied.
+    pub const ALL: [Self; 5] = [
+        Self::Hostile,
+        Self::Unfriendly,
+        Self::Neutral,
+        Self::Friendly,
+        Self::Allied,
+    ];
+
+    /// Returns the band `score` falls in. Scores outside the valid range
+    /// fall in the nearest band.
+    #[must_use]
+    pub fn of(score: i32) -> Self {
+        match score {
+            ..=-60 => Self::Hostile,
+            -59..=-20 => Self::Unfriendly,
+            -19..=19 => Self::Neutral,
+            20..=59 => Self::Friendly,
+            60.. => Self::Allied,
+        }
+    }
+
+    /// Returns the score an entity described only by this band starts at.
+    #[must_use]
+    pub fn seed_score(self) -> i32 {
+        match self {
+            Self::Hostile => -80,
+            Self::Unfriendly => -40,
+            Self::Neutral => 0,
+            Self::Friendly => 40,
+            Self::Allied => 80,
+        }
+    }
+
+    /// Returns the band's snake-case name.
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Hostile => "hostile",
+            Self::Unfriendly => "unfriendly",
+            Self::Neutral => "neutral",
+            Self::Friendly => "friendly",
+            Self::Allied => "allied",
+        }
+    }
+}
+
+impl fmt::Display for DispositionBand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for DispositionBand {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let name = s.trim().to_ascii_lowercase();
+        Self::ALL
+            .into_iter()
+            .find(|band| band.as_str() == name)
+            .ok_or_else(|| {
+                let names: Vec<&str> = Self::ALL.iter().map(|band| band.as_str()).collect();
+                DomainError::Validation(format!(
+                    "unknown disposition band '{s}' (expected one of: {})",
+                    names.join(", ")
+                ))
+            })
+    }
+}
+
+/// Checks that `score` lies between [`MIN_SCORE`] and [`MAX_SCORE`].
+///
+/// # Errors
+///
+/// Returns `DomainError::Validation` if the score is out of range.
+pub fn validate_score(score: i32) -> Result<(), DomainError> {
+    if (MIN_SCORE..=MAX_SCORE).contains(&score) {
+        Ok(())
+    } else {
+        Err(DomainError::Validation(format!(
+            "disposition score {score} is outside {MIN_SCORE}..={MAX_SCORE}"
+        )))
+    }
+}
+
+/// Parses a disposition as written in content: either a band name such as
+/// `"friendly"`, which seeds the band's score, or a whole-number score.
+///
+/// # Errors
+///
+/// Returns `DomainError::Validation` if the text is neither a band name nor a
+/// score in range.
+pub fn parse_disposition(text: &str) -> Result<i32, DomainError> {
+    if let Ok(score) = text.trim().parse::<i32>() {
+        validate_score(score)?;
+        return Ok(score);
+    }
+    text.parse::<DispositionBand>()
+        .map(DispositionBand::seed_score)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bands_cover_the_score_range_in_order() {
+        // Act
+        let bands: Vec<DispositionBand> =
+            (MIN_SCORE..=MAX_SCORE).map(DispositionBand::of).collect();
+
+        // Assert — each band starts where the previous one ends.
+        assert!(bands.windows(2).all(|pair| pair[0] <= pair[1]));
+        assert_eq!(DispositionBand::of(-60), DispositionBand::Hostile);
+        assert_eq!(DispositionBand::of(-59), DispositionBand::Unfriendly);
+        assert_eq!(DispositionBand::of(-19), DispositionBand::Neutral);
+        assert_eq!(DispositionBand::of(20), DispositionBand::Friendly);
+        assert_eq!(DispositionBand::of(60), DispositionBand::Allied);
+    }
+
+    #[test]
+    fn test_seed_scores_fall_in_their_own_band() {
+        for band in DispositionBand::ALL {
+            assert_eq!(DispositionBand::of(band.seed_score()), band);
+        }
+    }
+
+    #[test]
+    fn test_parse_disposition_accepts_band_names_and_scores() {
+        assert_eq!(parse_disposition("Friendly").unwrap(), 40);
+        assert_eq!(parse_disposition(" hostile ").unwrap(), -80);
+        assert_eq!(parse_disposition("-25").unwrap(), -25);
+    }
+
+    #[test]
+    fn test_parse_disposition_rejects_unknown_text_and_out_of_range_scores() {
+        assert!(matches!(
+            parse_disposition("wary"),
+            Err(DomainError::Validation(m)) if m.contains("wary")
+        ));
+        assert!(matches!(
+            parse_disposition("150"),
+            Err(DomainError::Validation(_))
+        ));
+    }
+}
@@ -21,6 +21,8 @@ pub struct EventMetadata {
     pub causation_id: Uuid,
     /// Timestamp of event creation.
     pub occurred_at: DateTime<Utc>,
+    /// Version of the payload shape this event was written with.
+    pub schema_version: i32,
 }
 
 /// Trait that all domain events implement.
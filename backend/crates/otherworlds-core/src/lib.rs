@@ -7,7 +7,14 @@ pub mod aggregate;
 pub mod branching;
 pub mod clock;
 pub mod command;
+pub mod disposition;
 pub mod error;
 pub mod event;
+pub mod projection;
 pub mod repository;
 pub mod rng;
+pub mod ruleset;
+pub mod snapshot;
+pub mod subscription;
+pub mod unit_of_work;
+pub mod upcasting;
@@ -0,0 +1,719 @@
+//! Read-model projections — query-side tables kept up to date from the
+//! global event log.
+//!
+//! A projection owns one or more read-model collections and knows how to
+//! bring the rows for a single aggregate up to date. A `ProjectionRunner`
+//! reads the global log from the projection's checkpoint, projects every
+//! aggregate touched by the new events, and advances the checkpoint. Because
+//! projecting an aggregate rewrites its rows from the aggregate's current
+//! state, re-processing an event is harmless: runners may overlap and a
+//! crash between projecting and checkpointing only repeats work. Documents
+//! carrying a `version` never replace a newer one, so an overlapping runner
+//! that loaded an aggregate earlier cannot roll its rows back.
+//!
+//! Each projection declares the version of its document shape. When it
+//! differs from the version the checkpoint was recorded under, the runner
+//! drops the projection's rows and replays the log before catching up.
+
+use std::collections::HashSet;
+
+use async_trait::async_trait;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use tracing::debug;
+use uuid::Uuid;
+
+use crate::error::DomainError;
+use crate::repository::EventRepository;
+
+/// Default number of events a runner reads from the global log per batch.
+pub const DEFAULT_BATCH_SIZE: i64 = 500;
+
+/// Document store backing read-model collections and projection checkpoints.
+///
+/// Each collection maps an aggregate ID to one JSON document. Collections are
+/// listed in aggregate ID order.
+#[async_trait]
+pub trait ReadModelStore: Send + Sync + std::fmt::Debug {
+    /// Load the document stored under `id`, if any.
+    async fn get(&self, collection: &str, id: Uuid) -> Result<Option<Value>, DomainError>;
+
+    /// Load every document in a collection, ordered by ID.
+    async fn list(&self, collection: &str) -> Result<Vec<Value>, DomainError>;
+
+    /// Insert or replace the document stored under `id`.
+    async fn upsert(&self, collection: &str, id: Uuid, document: &Value)
+    -> Result<(), DomainError>;
+
+    /// Insert the document stored under `id`, or replace it unless the
+    /// stored document's `version` field is greater than `version`.
+    async fn upsert_if_newer(
+        &self,
+        collection: &str,
+        id: Uuid,
+        document: &Value,
+        version: i64,
+    ) -> Result<(), DomainError>;
+
+    /// Remove the document stored under `id`. Removing a missing document is
+    /// not an error.
+    async fn delete(&self, collection: &str, id: Uuid) -> Result<(), DomainError>;
+
+    /// Remove every document in a collection.
+    async fn clear(&self, collection: &str) -> Result<(), DomainError>;
+
+    /// Load the last global log position processed by a projection, or 0 if
+    /// it has never run.
+    async fn load_checkpoint(&self, projection: &str) -> Result<i64, DomainError>;
+
+    /// Record the last global log position processed by a projection.
+    async fn save_checkpoint(&self, projection: &str, position: i64) -> Result<(), DomainError>;
+
+    /// Load the version a projection's rows were built under, or `None` if
+    /// none has been recorded.
+    async fn load_projection_version(&self, projection: &str) -> Result<Option<i32>, DomainError>;
+
+    /// Record the version a projection's rows are built under.
+    async fn save_projection_version(
+        &self,
+        projection: &str,
+        version: i32,
+    ) -> Result<(), DomainError>;
+}
+
+/// A read model derived from the event streams of one bounded context.
+#[async_trait]
+pub trait Projection: Send + Sync + std::fmt::Debug {
+    /// Stable name used as the checkpoint key (e.g., "`character.characters`").
+    fn name(&self) -> &'static str;
+
+    /// Event types that affect this projection's read models.
+    fn event_types(&self) -> &'static [&'static str];
+
+    /// Version of the read models' shape. Bump it whenever a document gains,
+    /// loses or reshapes a field, so existing rows are rebuilt.
+    fn version(&self) -> i32 {
+        1
+    }
+
+    /// Rewrite the read-model rows for one aggregate from its current state.
+    async fn project(
+        &self,
+        aggregate_id: Uuid,
+        repo: &dyn EventRepository,
+        store: &dyn ReadModelStore,
+    ) -> Result<(), DomainError>;
+
+    /// Remove every row owned by this projection.
+    async fn reset(&self, store: &dyn ReadModelStore) -> Result<(), DomainError>;
+}
+
+/// Drives a projection forward through the global event log.
+#[derive(Debug, Clone, Copy)]
+pub struct ProjectionRunner {
+    batch_size: i64,
+}
+
+impl Default for ProjectionRunner {
+    fn default() -> Self {
+        Self::new(DEFAULT_BATCH_SIZE)
+    }
+}
+
+impl ProjectionRunner {
+    /// Creates a runner reading `batch_size` events per round trip. A
+    /// non-positive size falls back to `DEFAULT_BATCH_SIZE`.
+    #[must_use]
+    pub const fn new(batch_size: i64) -> Self {
+        let batch_size = if batch_size > 0 {
+            batch_size
+        } else {
+            DEFAULT_BATCH_SIZE
+        };
+        Self { batch_size }
+    }
+
+    /// Projects every event recorded after the projection's checkpoint and
+    /// returns the number of events processed.
+    ///
+    /// If the projection's rows were built under another version, they are
+    /// dropped first and the whole log is replayed.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError` if reading the log, projecting an aggregate, or
+    /// saving the checkpoint fails. Work completed before the failure stays
+    /// checkpointed.
+    pub async fn catch_up(
+        &self,
+        projection: &dyn Projection,
+        repo: &dyn EventRepository,
+        store: &dyn ReadModelStore,
+    ) -> Result<u64, DomainError> {
+        let built_under = store.load_projection_version(projection.name()).await?;
+        if built_under != Some(projection.version()) {
+            debug!(
+                projection = projection.name(),
+                ?built_under,
+                version = projection.version(),
+                "projection version changed, rebuilding"
+            );
+            self.reset(projection, store).await?;
+        }
+
+        let mut checkpoint = store.load_checkpoint(projection.name()).await?;
+        let mut processed = 0_u64;
+
+        loop {
+            let batch = repo
+                .read_by_types_from(projection.event_types(), checkpoint + 1, self.batch_size)
+                .await?;
+            let Some(last) = batch.last() else {
+                break;
+            };
+            let last_position = last.position;
+
+            let mut seen = HashSet::new();
+            for positioned in &batch {
+                let aggregate_id = positioned.event.aggregate_id;
+                if seen.insert(aggregate_id) {
+                    projection.project(aggregate_id, repo, store).await?;
+                }
+            }
+
+            store
+                .save_checkpoint(projection.name(), last_position)
+                .await?;
+            checkpoint = last_position;
+            processed += batch.len() as u64;
+
+            if i64::try_from(batch.len()).unwrap_or(i64::MAX) < self.batch_size {
+                break;
+            }
+        }
+
+        if processed > 0 {
+            debug!(
+                projection = projection.name(),
+                processed, checkpoint, "projection caught up"
+            );
+        }
+        Ok(processed)
+    }
+
+    /// Drops a projection's read models and checkpoint, then replays the
+    /// whole global log into it. Returns the number of events processed.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError` if resetting or catching up fails.
+    pub async fn rebuild(
+        &self,
+        projection: &dyn Projection,
+        repo: &dyn EventRepository,
+        store: &dyn ReadModelStore,
+    ) -> Result<u64, DomainError> {
+        self.reset(projection, store).await?;
+        self.catch_up(projection, repo, store).await
+    }
+
+    /// Drops a projection's read models and records its checkpoint as 0
+    /// under the projection's current version.
+    async fn reset(
+        &self,
+        projection: &dyn Projection,
+        store: &dyn ReadModelStore,
+    ) -> Result<(), DomainError> {
+        projection.reset(store).await?;
+        store.save_checkpoint(projection.name(), 0).await?;
+        store
+            .save_projection_version(projection.name(), projection.version())
+            .await
+    }
+}
+
+/// Loads and deserializes the document stored under `id`.
+///
+/// # Errors
+///
+/// Returns `DomainError::Infrastructure` if the store fails or the document
+/// does not match `T`.
+pub async fn get_document<T: DeserializeOwned>(
+    store: &dyn ReadModelStore,
+    collection: &str,
+    id: Uuid,
+) -> Result<Option<T>, DomainError> {
+    store
+        .get(collection, id)
+        .await?
+        .map(|document| decode(collection, document))
+        .transpose()
+}
+
+/// Loads and deserializes every document in a collection, ordered by ID.
+///
+/// # Errors
+///
+/// Returns `DomainError::Infrastructure` if the store fails or a document
+/// does not match `T`.
+pub async fn list_documents<T: DeserializeOwned>(
+    store: &dyn ReadModelStore,
+    collection: &str,
+) -> Result<Vec<T>, DomainError> {
+    store
+        .list(collection)
+        .await?
+        .into_iter()
+        .map(|document| decode(collection, document))
+        .collect()
+}
+
+/// Serializes `document` and stores it under `id`.
+///
+/// A document with a numeric `version` field does not replace a stored one
+/// with a greater version.
+///
+/// # Errors
+///
+/// Returns `DomainError::Infrastructure` if serialization or the store fails.
+pub async fn put_document<T: Serialize + Sync>(
+    store: &dyn ReadModelStore,
+    collection: &str,
+    id: Uuid,
+    document: &T,
+) -> Result<(), DomainError> {
+    let value = serde_json::to_value(document).map_err(|e| {
+        DomainError::Infrastructure(format!("failed to serialize {collection} document: {e}"))
+    })?;
+    match document_version(&value) {
+        Some(version) => store.upsert_if_newer(collection, id, &value, version).await,
+        None => store.upsert(collection, id, &value).await,
+    }
+}
+
+/// Returns the `version` field of a stored document, if it has one.
+#[must_use]
+pub fn document_version(document: &Value) -> Option<i64> {
+    document.get("version").and_then(Value::as_i64)
+}
+
+fn decode<T: DeserializeOwned>(collection: &str, document: Value) -> Result<T, DomainError> {
+    serde_json::from_value(document).map_err(|e| {
+        DomainError::Infrastructure(format!("failed to deserialize {collection} document: {e}"))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{BTreeMap, HashMap};
+    use std::sync::Mutex;
+
+    use chrono::{TimeZone, Utc};
+    use serde_json::json;
+
+    use super::*;
+    use crate::repository::{PositionedEvent, StoredEvent};
+
+    const COLLECTION: &str = "counter_views";
+
+    #[derive(Debug, Default)]
+    struct MemoryStore {
+        documents: Mutex<HashMap<String, BTreeMap<Uuid, Value>>>,
+        checkpoints: Mutex<HashMap<String, i64>>,
+        versions: Mutex<HashMap<String, i32>>,
+    }
+
+    #[async_trait]
+    impl ReadModelStore for MemoryStore {
+        async fn get(&self, collection: &str, id: Uuid) -> Result<Option<Value>, DomainError> {
+            let documents = self.documents.lock().unwrap();
+            Ok(documents.get(collection).and_then(|c| c.get(&id).cloned()))
+        }
+
+        async fn list(&self, collection: &str) -> Result<Vec<Value>, DomainError> {
+            let documents = self.documents.lock().unwrap();
+            Ok(documents
+                .get(collection)
+                .map(|c| c.values().cloned().collect())
+                .unwrap_or_default())
+        }
+
+        async fn upsert(
+            &self,
+            collection: &str,
+            id: Uuid,
+            document: &Value,
+        ) -> Result<(), DomainError> {
+            let mut documents = self.documents.lock().unwrap();
+            documents
+                .entry(collection.to_owned())
+                .or_default()
+                .insert(id, document.clone());
+            Ok(())
+        }
+
+        async fn upsert_if_newer(
+            &self,
+            collection: &str,
+            id: Uuid,
+            document: &Value,
+            version: i64,
+        ) -> Result<(), DomainError> {
+            let mut documents = self.documents.lock().unwrap();
+            let documents = documents.entry(collection.to_owned()).or_default();
+            if documents
+                .get(&id)
+                .and_then(document_version)
+                .is_none_or(|stored| stored <= version)
+            {
+                documents.insert(id, document.clone());
+            }
+            Ok(())
+        }
+
+        async fn delete(&self, collection: &str, id: Uuid) -> Result<(), DomainError> {
+            let mut documents = self.documents.lock().unwrap();
+            if let Some(c) = documents.get_mut(collection) {
+                c.remove(&id);
+            }
+            Ok(())
+        }
+
+        async fn clear(&self, collection: &str) -> Result<(), DomainError> {
+            self.documents.lock().unwrap().remove(collection);
+            Ok(())
+        }
+
+        async fn load_checkpoint(&self, projection: &str) -> Result<i64, DomainError> {
+            Ok(self
+                .checkpoints
+                .lock()
+                .unwrap()
+                .get(projection)
+                .copied()
+                .unwrap_or(0))
+        }
+
+        async fn save_checkpoint(
+            &self,
+            projection: &str,
+            position: i64,
+        ) -> Result<(), DomainError> {
+            self.checkpoints
+                .lock()
+                .unwrap()
+                .insert(projection.to_owned(), position);
+            Ok(())
+        }
+
+        async fn load_projection_version(
+            &self,
+            projection: &str,
+        ) -> Result<Option<i32>, DomainError> {
+            Ok(self.versions.lock().unwrap().get(projection).copied())
+        }
+
+        async fn save_projection_version(
+            &self,
+            projection: &str,
+            version: i32,
+        ) -> Result<(), DomainError> {
+            self.versions
+                .lock()
+                .unwrap()
+                .insert(projection.to_owned(), version);
+            Ok(())
+        }
+    }
+
+    #[derive(Debug)]
+    struct LogRepo {
+        log: Vec<PositionedEvent>,
+    }
+
+    impl LogRepo {
+        fn new(aggregate_ids: &[Uuid]) -> Self {
+            let mut sequences: HashMap<Uuid, i64> = HashMap::new();
+            let log = aggregate_ids
+                .iter()
+                .zip(1_i64..)
+                .map(|(&aggregate_id, position)| {
+                    let sequence = sequences.entry(aggregate_id).or_default();
+                    *sequence += 1;
+                    PositionedEvent {
+                        position,
+                        event: StoredEvent {
+                            event_id: Uuid::new_v4(),
+                            aggregate_id,
+                            event_type: "counter.incremented".to_owned(),
+                            payload: json!({}),
+                            sequence_number: *sequence,
+                            correlation_id: Uuid::new_v4(),
+                            causation_id: Uuid::new_v4(),
+                            occurred_at: Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap(),
+                            schema_version: 1,
+                        },
+                    }
+                })
+                .collect();
+            Self { log }
+        }
+    }
+
+    #[async_trait]
+    impl EventRepository for LogRepo {
+        async fn load_events(&self, aggregate_id: Uuid) -> Result<Vec<StoredEvent>, DomainError> {
+            Ok(self
+                .log
+                .iter()
+                .filter(|p| p.event.aggregate_id == aggregate_id)
+                .map(|p| p.event.clone())
+                .collect())
+        }
+
+        async fn append_events(
+            &self,
+            _aggregate_id: Uuid,
+            _expected_version: i64,
+            _events: &[StoredEvent],
+        ) -> Result<(), DomainError> {
+            Ok(())
+        }
+
+        async fn list_aggregate_ids(
+            &self,
+            _event_types: &[&str],
+        ) -> Result<Vec<Uuid>, DomainError> {
+            Ok(vec![])
+        }
+
+        async fn read_by_types_from(
+            &self,
+            event_types: &[&str],
+            from_position: i64,
+            limit: i64,
+        ) -> Result<Vec<PositionedEvent>, DomainError> {
+            Ok(self
+                .log
+                .iter()
+                .filter(|p| p.position >= from_position)
+                .filter(|p| event_types.contains(&p.event.event_type.as_str()))
+                .take(usize::try_from(limit).unwrap())
+                .cloned()
+                .collect())
+        }
+    }
+
+    /// Stores the event count of each aggregate and records every call.
+    #[derive(Debug, Default)]
+    struct CounterProjection {
+        projected: Mutex<Vec<Uuid>>,
+    }
+
+    #[async_trait]
+    impl Projection for CounterProjection {
+        fn name(&self) -> &'static str {
+            "counter.counters"
+        }
+
+        fn event_types(&self) -> &'static [&'static str] {
+            &["counter.incremented"]
+        }
+
+        async fn project(
+            &self,
+            aggregate_id: Uuid,
+            repo: &dyn EventRepository,
+            store: &dyn ReadModelStore,
+        ) -> Result<(), DomainError> {
+            self.projected.lock().unwrap().push(aggregate_id);
+            let count = repo.load_events(aggregate_id).await?.len();
+            put_document(store, COLLECTION, aggregate_id, &count).await
+        }
+
+        async fn reset(&self, store: &dyn ReadModelStore) -> Result<(), DomainError> {
+            store.clear(COLLECTION).await
+        }
+    }
+
+    #[tokio::test]
+    async fn test_catch_up_projects_each_aggregate_and_saves_checkpoint() {
+        // Arrange
+        let a = Uuid::new_v4();
+        let b = Uuid::new_v4();
+        let repo = LogRepo::new(&[a, b, a]);
+        let store = MemoryStore::default();
+        let projection = CounterProjection::default();
+
+        // Act
+        let processed = ProjectionRunner::default()
+            .catch_up(&projection, &repo, &store)
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(processed, 3);
+        assert_eq!(*projection.projected.lock().unwrap(), vec![a, b]);
+        let count: Option<usize> = get_document(&store, COLLECTION, a).await.unwrap();
+        assert_eq!(count, Some(2));
+        assert_eq!(store.load_checkpoint("counter.counters").await.unwrap(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_catch_up_resumes_after_checkpoint() {
+        // Arrange
+        let a = Uuid::new_v4();
+        let b = Uuid::new_v4();
+        let repo = LogRepo::new(&[a, b]);
+        let store = MemoryStore::default();
+        store.save_checkpoint("counter.counters", 1).await.unwrap();
+        store
+            .save_projection_version("counter.counters", 1)
+            .await
+            .unwrap();
+        let projection = CounterProjection::default();
+
+        // Act
+        let processed = ProjectionRunner::default()
+            .catch_up(&projection, &repo, &store)
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(processed, 1);
+        assert_eq!(*projection.projected.lock().unwrap(), vec![b]);
+    }
+
+    #[tokio::test]
+    async fn test_catch_up_reads_in_batches() {
+        // Arrange
+        let ids: Vec<Uuid> = (0..5).map(|_| Uuid::new_v4()).collect();
+        let repo = LogRepo::new(&ids);
+        let store = MemoryStore::default();
+        let projection = CounterProjection::default();
+
+        // Act
+        let processed = ProjectionRunner::new(2)
+            .catch_up(&projection, &repo, &store)
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(processed, 5);
+        assert_eq!(*projection.projected.lock().unwrap(), ids);
+        assert_eq!(store.load_checkpoint("counter.counters").await.unwrap(), 5);
+    }
+
+    #[tokio::test]
+    async fn test_rebuild_replays_from_scratch() {
+        // Arrange
+        let a = Uuid::new_v4();
+        let repo = LogRepo::new(&[a]);
+        let store = MemoryStore::default();
+        let stale = Uuid::new_v4();
+        put_document(&store, COLLECTION, stale, &99_usize)
+            .await
+            .unwrap();
+        store.save_checkpoint("counter.counters", 1).await.unwrap();
+        let projection = CounterProjection::default();
+
+        // Act
+        let processed = ProjectionRunner::default()
+            .rebuild(&projection, &repo, &store)
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(processed, 1);
+        let documents: Vec<usize> = list_documents(&store, COLLECTION).await.unwrap();
+        assert_eq!(documents, vec![1]);
+    }
+
+    #[tokio::test]
+    async fn test_catch_up_rebuilds_when_version_changed() {
+        // Arrange — rows built under version 0 by an older release.
+        let a = Uuid::new_v4();
+        let repo = LogRepo::new(&[a, a]);
+        let store = MemoryStore::default();
+        let stale = Uuid::new_v4();
+        put_document(&store, COLLECTION, stale, &99_usize)
+            .await
+            .unwrap();
+        store.save_checkpoint("counter.counters", 2).await.unwrap();
+        store
+            .save_projection_version("counter.counters", 0)
+            .await
+            .unwrap();
+        let projection = CounterProjection::default();
+
+        // Act
+        let processed = ProjectionRunner::default()
+            .catch_up(&projection, &repo, &store)
+            .await
+            .unwrap();
+        let again = ProjectionRunner::default()
+            .catch_up(&projection, &repo, &store)
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(processed, 2);
+        assert_eq!(again, 0);
+        let documents: Vec<usize> = list_documents(&store, COLLECTION).await.unwrap();
+        assert_eq!(documents, vec![2]);
+        assert_eq!(
+            store
+                .load_projection_version("counter.counters")
+                .await
+                .unwrap(),
+            Some(1)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_put_document_keeps_newer_version() {
+        // Arrange
+        let store = MemoryStore::default();
+        let id = Uuid::new_v4();
+        put_document(
+            &store,
+            COLLECTION,
+            id,
+            &json!({ "version": 3, "name": "new" }),
+        )
+        .await
+        .unwrap();
+
+        // Act
+        put_document(
+            &store,
+            COLLECTION,
+            id,
+            &json!({ "version": 2, "name": "stale" }),
+        )
+        .await
+        .unwrap();
+
+        // Assert
+        let document: Option<Value> = get_document(&store, COLLECTION, id).await.unwrap();
+        assert_eq!(document.unwrap()["name"], "new");
+    }
+
+    #[tokio::test]
+    async fn test_get_document_rejects_mismatched_shape() {
+        // Arrange
+        let store = MemoryStore::default();
+        let id = Uuid::new_v4();
+        store
+            .upsert(COLLECTION, id, &json!("not a number"))
+            .await
+            .unwrap();
+
+        // Act
+        let result = get_document::<usize>(&store, COLLECTION, id).await;
+
+        // Assert
+        assert!(matches!(result, Err(DomainError::Infrastructure(_))));
+    }
+}
@@ -1,6 +1,7 @@
 //! Event repository abstraction.
 
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
 use crate::error::DomainError;
@@ -24,6 +25,46 @@ pub struct StoredEvent {
     pub causation_id: Uuid,
     /// Timestamp of event creation.
     pub occurred_at: chrono::DateTime<chrono::Utc>,
+    /// Version of the payload shape, used to select upcasters on load.
+    pub schema_version: i32,
+}
+
+impl StoredEvent {
+    /// Returns the event with its causation ID replaced by `causation_id`,
+    /// when one is given. Aggregates record the command's correlation ID as
+    /// the cause; a command dispatched in reaction to another event names
+    /// that event instead.
+    #[must_use]
+    pub fn caused_by(mut self, causation_id: Option<Uuid>) -> Self {
+        if let Some(causation_id) = causation_id {
+            self.causation_id = causation_id;
+        }
+        self
+    }
+}
+
+/// A stored event together with its position in the global event log.
+///
+/// Positions start at 1, increase monotonically across all aggregates, and
+/// follow commit order, so a reader that has processed position `n` can
+/// resume from `n + 1` without missing events.
+#[derive(Debug, Clone)]
+pub struct PositionedEvent {
+    /// Position in the global event log.
+    pub position: i64,
+    /// The stored event at this position.
+    pub event: StoredEvent,
+}
+
+/// New events for one aggregate stream within a multi-stream append.
+#[derive(Debug, Clone)]
+pub struct StreamAppend {
+    /// Aggregate the events belong to.
+    pub aggregate_id: Uuid,
+    /// Last sequence number the writer saw for this aggregate.
+    pub expected_version: i64,
+    /// Events to append, in sequence order.
+    pub events: Vec<StoredEvent>,
 }
 
 /// Repository trait for loading and appending domain events.
@@ -41,7 +82,213 @@ pub trait EventRepository: Send + Sync + std::fmt::Debug {
         events: &[StoredEvent],
     ) -> Result<(), DomainError>;
 
+    /// Append events to several aggregate streams, each checked against its
+    /// own expected version.
+    ///
+    /// Backends with transactions override this so that either every stream
+    /// is appended or none is. The default implementation appends the
+    /// streams one after another and is therefore not atomic: a failure
+    /// leaves earlier streams committed.
+    async fn append_many(&self, appends: &[StreamAppend]) -> Result<(), DomainError> {
+        for append in appends {
+            self.append_events(append.aggregate_id, append.expected_version, &append.events)
+                .await?;
+        }
+        Ok(())
+    }
+
     /// List distinct aggregate IDs that have at least one event matching the
     /// given event types.
     async fn list_aggregate_ids(&self, event_types: &[&str]) -> Result<Vec<Uuid>, DomainError>;
+
+    /// Load events for an aggregate whose sequence number is greater than
+    /// `after_sequence`, ordered by sequence number.
+    ///
+    /// Used to replay only the tail of a stream on top of a snapshot. The
+    /// default implementation filters the result of `load_events`; backends
+    /// should override it with a ranged query.
+    async fn load_events_after(
+        &self,
+        aggregate_id: Uuid,
+        after_sequence: i64,
+    ) -> Result<Vec<StoredEvent>, DomainError> {
+        let events = self.load_events(aggregate_id).await?;
+        Ok(events
+            .into_iter()
+            .filter(|e| e.sequence_number > after_sequence)
+            .collect())
+    }
+
+    /// Read up to `limit` events from the global log, starting at
+    /// `from_position` (inclusive), in commit order.
+    ///
+    /// The default implementation reports that the backend has no global log.
+    async fn read_all_from(
+        &self,
+        from_position: i64,
+        limit: i64,
+    ) -> Result<Vec<PositionedEvent>, DomainError> {
+        let _ = (from_position, limit);
+        Err(DomainError::Infrastructure(
+            "event repository does not support reading the global log".into(),
+        ))
+    }
+
+    /// Load all events for an aggregate with their global log positions,
+    /// ordered by sequence number.
+    ///
+    /// The default implementation reports that the backend has no global log.
+    async fn load_positioned_events(
+        &self,
+        aggregate_id: Uuid,
+    ) -> Result<Vec<PositionedEvent>, DomainError> {
+        let _ = aggregate_id;
+        Err(DomainError::Infrastructure(
+            "event repository does not support reading the global log".into(),
+        ))
+    }
+
+    /// Read up to `limit` events of the given types from the global log,
+    /// starting at `from_position` (inclusive), in commit order.
+    ///
+    /// The default implementation reports that the backend has no global log.
+    async fn read_by_types_from(
+        &self,
+        event_types: &[&str],
+        from_position: i64,
+        limit: i64,
+    ) -> Result<Vec<PositionedEvent>, DomainError> {
+        let _ = (event_types, from_position, limit);
+        Err(DomainError::Infrastructure(
+            "event repository does not support reading the global log".into(),
+        ))
+    }
+
+    /// Returns the snapshot store paired with this repository, or `None` if
+    /// snapshotting is disabled.
+    fn snapshot_store(&self) -> Option<&dyn SnapshotStore> {
+        None
+    }
+}
+
+/// Serialized point-in-time state of an aggregate.
+#[derive(Debug, Clone)]
+pub struct AggregateSnapshot {
+    /// Aggregate this snapshot belongs to.
+    pub aggregate_id: Uuid,
+    /// Stable aggregate type name (e.g., "`character.character`").
+    pub aggregate_type: String,
+    /// Sequence number of the last event folded into `state`.
+    pub version: i64,
+    /// Serialized aggregate state.
+    pub state: serde_json::Value,
+    /// Timestamp the snapshot was taken.
+    pub taken_at: DateTime<Utc>,
+}
+
+/// Policy deciding when a new snapshot should be taken.
+///
+/// A snapshot is due whenever a commit moves an aggregate's version across a
+/// multiple of `every_n_events`. A value of zero disables snapshotting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SnapshotPolicy {
+    every_n_events: i64,
+}
+
+impl SnapshotPolicy {
+    /// Snapshot every `n` events. A non-positive `n` disables snapshotting.
+    #[must_use]
+    pub const fn every(n: i64) -> Self {
+        Self { every_n_events: n }
+    }
+
+    /// Never take snapshots.
+    #[must_use]
+    pub const fn disabled() -> Self {
+        Self { every_n_events: 0 }
+    }
+
+    /// Returns the configured snapshot interval.
+    #[must_use]
+    pub const fn every_n_events(&self) -> i64 {
+        self.every_n_events
+    }
+
+    /// Returns `true` if moving from `previous_version` to `new_version`
+    /// crosses a snapshot boundary.
+    #[must_use]
+    pub fn is_due(&self, previous_version: i64, new_version: i64) -> bool {
+        if self.every_n_events <= 0 || new_version <= previous_version {
+            return false;
+        }
+        new_version / self.every_n_events > previous_version / self.every_n_events
+    }
+}
+
+/// Store for aggregate snapshots, keeping the latest snapshot per aggregate.
+#[async_trait]
+pub trait SnapshotStore: Send + Sync + std::fmt::Debug {
+    /// Load the latest snapshot for an aggregate, if one exists.
+    async fn load_snapshot(
+        &self,
+        aggregate_id: Uuid,
+    ) -> Result<Option<AggregateSnapshot>, DomainError>;
+
+    /// Save a snapshot, replacing any older snapshot for the same aggregate.
+    /// A snapshot older than the stored one is ignored.
+    async fn save_snapshot(&self, snapshot: &AggregateSnapshot) -> Result<(), DomainError>;
+
+    /// Returns the policy deciding when snapshots are taken.
+    fn policy(&self) -> SnapshotPolicy;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_policy_is_due_when_crossing_boundary() {
+        let policy = SnapshotPolicy::every(10);
+        assert!(policy.is_due(9, 10));
+        assert!(policy.is_due(8, 12));
+        assert!(policy.is_due(19, 21));
+    }
+
+    #[test]
+    fn test_policy_is_not_due_within_interval() {
+        let policy = SnapshotPolicy::every(10);
+        assert!(!policy.is_due(0, 9));
+        assert!(!policy.is_due(10, 19));
+        assert!(!policy.is_due(10, 10));
+    }
+
+    #[test]
+    fn test_disabled_policy_is_never_due() {
+        let policy = SnapshotPolicy::disabled();
+        assert!(!policy.is_due(0, 1_000));
+        assert!(!SnapshotPolicy::every(-5).is_due(0, 1_000));
+    }
+
+    #[test]
+    fn test_caused_by_replaces_causation_only_when_given() {
+        let event = StoredEvent {
+            event_id: Uuid::nil(),
+            aggregate_id: Uuid::nil(),
+            event_type: "test.event".to_owned(),
+            payload: serde_json::Value::Null,
+            sequence_number: 1,
+            correlation_id: Uuid::from_u128(1),
+            causation_id: Uuid::from_u128(1),
+            occurred_at: DateTime::UNIX_EPOCH,
+            schema_version: 1,
+        };
+
+        assert_eq!(
+            event.clone().caused_by(None).causation_id,
+            Uuid::from_u128(1)
+        );
+        let caused = event.caused_by(Some(Uuid::from_u128(2)));
+        assert_eq!(caused.causation_id, Uuid::from_u128(2));
+        assert_eq!(caused.correlation_id, Uuid::from_u128(1));
+    }
 }
@@ -1,9 +1,12 @@
 //! Random number generator abstraction for determinism.
 //!
-//! In production, this wraps a real RNG. In tests and replays,
-//! a seeded or recorded implementation is injected.
+//! In production, commands that belong to a campaign run draw from that
+//! run's `SeededRng`, whose seed and stream position are recorded on the run
+//! so its randomness can be reproduced. `StdRng` is the entropy source for
+//! everything else. In tests, a fixed or sequenced implementation is injected.
 
-use rand::Rng;
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha20Rng;
 use uuid::Uuid;
 
 /// Abstraction over random number generation.
@@ -18,6 +21,13 @@ pub trait DeterministicRng: Send + Sync + std::fmt::Debug {
     /// Generate a random `f64` in `[0.0, 1.0)`.
     fn next_f64(&mut self) -> f64;
 
+    /// Generate a random `u64` from two `u32` values, high word first.
+    fn next_u64(&mut self) -> u64 {
+        let high = u64::from(self.next_u32_range(0, u32::MAX));
+        let low = u64::from(self.next_u32_range(0, u32::MAX));
+        (high << 32) | low
+    }
+
     /// Generate a deterministic UUID from four `u32` values.
     ///
     /// Consumes four calls to `next_u32_range(0, u32::MAX)` and combines them
@@ -33,6 +43,9 @@ pub trait DeterministicRng: Send + Sync + std::fmt::Debug {
 }
 
 /// Production RNG backed by the thread-local random number generator.
+///
+/// Not replayable: use it only to pick seeds and for commands that are not
+/// part of a campaign run.
 #[derive(Debug)]
 pub struct StdRng;
 
@@ -45,3 +58,132 @@ impl DeterministicRng for StdRng {
         rand::rng().random::<f64>()
     }
 }
+
+/// Replayable RNG backed by a `ChaCha20` stream.
+///
+/// The stream is fully described by its seed and its position (the number
+/// of 32-bit words consumed so far), so an RNG restored with `at_position`
+/// continues exactly where the recorded one stopped.
+#[derive(Debug, Clone)]
+pub struct SeededRng {
+    seed: u64,
+    inner: ChaCha20Rng,
+}
+
+impl SeededRng {
+    /// Creates an RNG at the start of the stream for `seed`.
+    #[must_use]
+    pub fn new(seed: u64) -> Self {
+        Self {
+            seed,
+            inner: ChaCha20Rng::seed_from_u64(seed),
+        }
+    }
+
+    /// Creates an RNG for `seed` that resumes at `position`.
+    #[must_use]
+    pub fn at_position(seed: u64, position: u64) -> Self {
+        let mut rng = Self::new(seed);
+        rng.inner.set_word_pos(u128::from(position));
+        rng
+    }
+
+    /// Returns the seed this RNG was created with.
+    #[must_use]
+    pub fn seed(&self) -> u64 {
+        self.seed
+    }
+
+    /// Returns the number of 32-bit words consumed from the stream.
+    ///
+    /// Saturates at `u64::MAX`, which no campaign run can reach.
+    #[must_use]
+    pub fn position(&self) -> u64 {
+        u64::try_from(self.inner.get_word_pos()).unwrap_or(u64::MAX)
+    }
+}
+
+impl DeterministicRng for SeededRng {
+    fn next_u32_range(&mut self, min: u32, max: u32) -> u32 {
+        self.inner.random_range(min..=max)
+    }
+
+    fn next_f64(&mut self) -> f64 {
+        self.inner.random::<f64>()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn draw(rng: &mut SeededRng) -> (u32, Uuid) {
+        (rng.next_u32_range(1, 20), rng.next_uuid())
+    }
+
+    #[test]
+    fn test_seeded_rng_same_seed_produces_same_stream() {
+        // Arrange
+        let mut first = SeededRng::new(42);
+        let mut second = SeededRng::new(42);
+
+        // Act & Assert
+        for _ in 0..10 {
+            assert_eq!(draw(&mut first), draw(&mut second));
+        }
+    }
+
+    #[test]
+    fn test_seeded_rng_different_seeds_diverge() {
+        // Arrange
+        let mut first = SeededRng::new(1);
+        let mut second = SeededRng::new(2);
+
+        // Act & Assert
+        assert_ne!(first.next_uuid(), second.next_uuid());
+    }
+
+    #[test]
+    fn test_seeded_rng_resumes_from_recorded_position() {
+        // Arrange
+        let mut original = SeededRng::new(7);
+        draw(&mut original);
+        let position = original.position();
+
+        // Act
+        let mut resumed = SeededRng::at_position(7, position);
+
+        // Assert
+        assert_eq!(resumed.seed(), 7);
+        assert_eq!(resumed.position(), position);
+        assert_eq!(draw(&mut resumed), draw(&mut original));
+    }
+
+    #[test]
+    fn test_seeded_rng_position_advances_with_draws() {
+        // Arrange
+        let mut rng = SeededRng::new(3);
+        assert_eq!(rng.position(), 0);
+
+        // Act
+        rng.next_uuid();
+
+        // Assert
+        assert!(rng.position() >= 4);
+    }
+
+    #[test]
+    fn test_next_u64_combines_two_words() {
+        // Arrange
+        let mut rng = SeededRng::new(9);
+        let mut words = SeededRng::new(9);
+
+        // Act
+        let value = rng.next_u64();
+
+        // Assert
+        let high = u64::from(words.next_u32_range(0, u32::MAX));
+        let low = u64::from(words.next_u32_range(0, u32::MAX));
+        assert_eq!(value, (high << 32) | low);
+    }
+}
@@ -0,0 +1,59 @@
+//! Ruleset names — the rulesets a campaign can be run under.
+//!
+//! The rules context implements one ruleset per name in [`RULESET_NAMES`].
+//! The names live here so that content, which validates the ruleset authors
+//! name in front matter, and rules, which resolves checks under it, accept
+//! the same set. See ADR-0039.
+
+use crate::error::DomainError;
+
+/// Names of the built-in rulesets. The first is the default when a campaign
+/// names none.
+pub const RULESET_NAMES: [&str; 3] = ["d20", "pbta", "percentile"];
+
+/// Returns the index of `name` in [`RULESET_NAMES`].
+///
+/// # Errors
+///
+/// Returns `DomainError::Validation` naming the supported rulesets if `name`
+/// is not one of them.
+pub fn ruleset_index(name: &str) -> Result<usize, DomainError> {
+    RULESET_NAMES
+        .iter()
+        .position(|known| *known == name)
+        .ok_or_else(|| {
+            DomainError::Validation(format!(
+                "unknown ruleset '{name}' (expected one of: {})",
+                RULESET_NAMES.join(", ")
+            ))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ruleset_index_finds_every_name() {
+        for (index, name) in RULESET_NAMES.iter().enumerate() {
+            assert_eq!(ruleset_index(name).unwrap(), index);
+        }
+    }
+
+    #[test]
+    fn test_ruleset_index_lists_names_for_unknown_ruleset() {
+        // Act
+        let result = ruleset_index("gurps");
+
+        // Assert
+        match result {
+            Err(DomainError::Validation(msg)) => {
+                assert_eq!(
+                    msg,
+                    "unknown ruleset 'gurps' (expected one of: d20, pbta, percentile)"
+                );
+            }
+            other => panic!("expected Validation error, got {other:?}"),
+        }
+    }
+}
@@ -0,0 +1,477 @@
+//! Aggregate snapshots — serialized state that shortens stream replay.
+//!
+//! Command and query handlers restore an aggregate from its latest snapshot
+//! and replay only the events recorded after it. Snapshots are an
+//! optimization: a missing, stale, or unreadable snapshot always falls back
+//! to a full replay of the event stream.
+
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use tracing::{debug, warn};
+use uuid::Uuid;
+
+use crate::aggregate::AggregateRoot;
+use crate::clock::Clock;
+use crate::error::DomainError;
+use crate::repository::{AggregateSnapshot, EventRepository, StoredEvent};
+
+/// Aggregate roots whose state can be captured in a snapshot.
+///
+/// Implementors serialize their full state (excluding uncommitted events) so
+/// that deserializing a snapshot yields an aggregate equivalent to replaying
+/// every event up to the snapshot's version.
+pub trait Snapshottable: AggregateRoot + Serialize + DeserializeOwned {
+    /// Stable type name recorded with each snapshot (e.g., "`character.character`").
+    const AGGREGATE_TYPE: &'static str;
+}
+
+/// Serializes an aggregate into a snapshot at its current version.
+///
+/// # Errors
+///
+/// Returns `DomainError::Infrastructure` if the aggregate cannot be serialized.
+pub fn take_snapshot<A: Snapshottable>(
+    aggregate: &A,
+    clock: &dyn Clock,
+) -> Result<AggregateSnapshot, DomainError> {
+    let state = serde_json::to_value(aggregate)
+        .map_err(|e| DomainError::Infrastructure(format!("snapshot serialization failed: {e}")))?;
+    Ok(AggregateSnapshot {
+        aggregate_id: aggregate.aggregate_id(),
+        aggregate_type: A::AGGREGATE_TYPE.to_owned(),
+        version: aggregate.version(),
+        state,
+        taken_at: clock.now(),
+    })
+}
+
+/// Restores an aggregate from a snapshot.
+///
+/// # Errors
+///
+/// Returns `DomainError::Infrastructure` if the snapshot belongs to a
+/// different aggregate type or identity, or cannot be deserialized.
+pub fn restore_snapshot<A: Snapshottable>(snapshot: &AggregateSnapshot) -> Result<A, DomainError> {
+    if snapshot.aggregate_type != A::AGGREGATE_TYPE {
+        return Err(DomainError::Infrastructure(format!(
+            "snapshot type mismatch: expected {}, found {}",
+            A::AGGREGATE_TYPE,
+            snapshot.aggregate_type
+        )));
+    }
+    let aggregate: A = serde_json::from_value(snapshot.state.clone()).map_err(|e| {
+        DomainError::Infrastructure(format!("snapshot deserialization failed: {e}"))
+    })?;
+    if aggregate.aggregate_id() != snapshot.aggregate_id || aggregate.version() != snapshot.version
+    {
+        return Err(DomainError::Infrastructure(format!(
+            "snapshot for aggregate {} does not match its recorded identity or version",
+            snapshot.aggregate_id
+        )));
+    }
+    Ok(aggregate)
+}
+
+/// Loads the latest usable snapshot for an aggregate together with the
+/// events that must be replayed on top of it.
+///
+/// Returns `(Some(aggregate), tail_events)` when a snapshot was restored, or
+/// `(None, all_events)` when the repository has no snapshot store, no
+/// snapshot exists, or the stored snapshot cannot be restored.
+///
+/// # Errors
+///
+/// Returns `DomainError` if loading the snapshot or events fails.
+pub async fn load_with_snapshot<A: Snapshottable>(
+    repo: &dyn EventRepository,
+    aggregate_id: Uuid,
+) -> Result<(Option<A>, Vec<StoredEvent>), DomainError> {
+    if let Some(store) = repo.snapshot_store()
+        && let Some(snapshot) = store.load_snapshot(aggregate_id).await?
+    {
+        match restore_snapshot::<A>(&snapshot) {
+            Ok(aggregate) => {
+                let tail = repo
+                    .load_events_after(aggregate_id, snapshot.version)
+                    .await?;
+                debug!(
+                    %aggregate_id,
+                    snapshot_version = snapshot.version,
+                    tail_count = tail.len(),
+                    "restored aggregate from snapshot"
+                );
+                return Ok((Some(aggregate), tail));
+            }
+            Err(err) => {
+                warn!(%aggregate_id, "ignoring unusable snapshot: {err}");
+            }
+        }
+    }
+
+    Ok((None, repo.load_events(aggregate_id).await?))
+}
+
+/// Folds an aggregate's uncommitted events into its state and saves a
+/// snapshot if the repository's policy says one is due.
+///
+/// Call this after the uncommitted events have been appended. Snapshot
+/// failures are logged and swallowed — the events are already committed and
+/// the next load simply replays more of the stream.
+pub async fn save_snapshot_if_due<A: Snapshottable>(
+    repo: &dyn EventRepository,
+    mut aggregate: A,
+    clock: &dyn Clock,
+) {
+    let Some(store) = repo.snapshot_store() else {
+        return;
+    };
+
+    let previous_version = aggregate.version();
+    let pending = aggregate.uncommitted_events().to_vec();
+    aggregate.clear_uncommitted_events();
+    for event in &pending {
+        aggregate.apply(event);
+    }
+
+    if !store.policy().is_due(previous_version, aggregate.version()) {
+        return;
+    }
+
+    let aggregate_id = aggregate.aggregate_id();
+    let result = match take_snapshot(&aggregate, clock) {
+        Ok(snapshot) => store.save_snapshot(&snapshot).await,
+        Err(err) => Err(err),
+    };
+    match result {
+        Ok(()) => debug!(%aggregate_id, version = aggregate.version(), "saved snapshot"),
+        Err(err) => warn!(%aggregate_id, "failed to save snapshot: {err}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+
+    use async_trait::async_trait;
+    use chrono::{TimeZone, Utc};
+    use serde::Deserialize;
+
+    use super::*;
+    use crate::clock::FixedClock;
+    use crate::event::{DomainEvent, EventMetadata};
+    use crate::repository::{SnapshotPolicy, SnapshotStore};
+
+    #[derive(Debug, Clone)]
+    struct Incremented {
+        metadata: EventMetadata,
+    }
+
+    impl DomainEvent for Incremented {
+        fn event_type(&self) -> &'static str {
+            "test.incremented"
+        }
+
+        fn to_payload(&self) -> serde_json::Value {
+            serde_json::json!({})
+        }
+
+        fn metadata(&self) -> &EventMetadata {
+            &self.metadata
+        }
+    }
+
+    #[derive(Debug, Serialize, Deserialize)]
+    struct Counter {
+        id: Uuid,
+        version: i64,
+        count: i64,
+        #[serde(skip)]
+        uncommitted_events: Vec<Incremented>,
+    }
+
+    impl Counter {
+        fn new(id: Uuid) -> Self {
+            Self {
+                id,
+                version: 0,
+                count: 0,
+                uncommitted_events: Vec::new(),
+            }
+        }
+
+        fn increment(&mut self) {
+            #[allow(clippy::cast_possible_wrap)]
+            let sequence_number = self.version + self.uncommitted_events.len() as i64 + 1;
+            self.uncommitted_events.push(Incremented {
+                metadata: EventMetadata {
+                    event_id: Uuid::new_v4(),
+                    event_type: "test.incremented".to_owned(),
+                    aggregate_id: self.id,
+                    sequence_number,
+                    correlation_id: Uuid::new_v4(),
+                    causation_id: Uuid::new_v4(),
+                    occurred_at: Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap(),
+                    schema_version: 1,
+                },
+            });
+        }
+    }
+
+    impl AggregateRoot for Counter {
+        type Event = Incremented;
+
+        fn aggregate_id(&self) -> Uuid {
+            self.id
+        }
+
+        fn version(&self) -> i64 {
+            self.version
+        }
+
+        fn apply(&mut self, _event: &Self::Event) {
+            self.count += 1;
+            self.version += 1;
+        }
+
+        fn uncommitted_events(&self) -> &[Self::Event] {
+            &self.uncommitted_events
+        }
+
+        fn clear_uncommitted_events(&mut self) {
+            self.uncommitted_events.clear();
+        }
+    }
+
+    impl Snapshottable for Counter {
+        const AGGREGATE_TYPE: &'static str = "test.counter";
+    }
+
+    #[derive(Debug)]
+    struct MemorySnapshots {
+        policy: SnapshotPolicy,
+        snapshots: Mutex<HashMap<Uuid, AggregateSnapshot>>,
+    }
+
+    #[async_trait]
+    impl SnapshotStore for MemorySnapshots {
+        async fn load_snapshot(
+            &self,
+            aggregate_id: Uuid,
+        ) -> Result<Option<AggregateSnapshot>, DomainError> {
+            Ok(self.snapshots.lock().unwrap().get(&aggregate_id).cloned())
+        }
+
+        async fn save_snapshot(&self, snapshot: &AggregateSnapshot) -> Result<(), DomainError> {
+            self.snapshots
+                .lock()
+                .unwrap()
+                .insert(snapshot.aggregate_id, snapshot.clone());
+            Ok(())
+        }
+
+        fn policy(&self) -> SnapshotPolicy {
+            self.policy
+        }
+    }
+
+    #[derive(Debug)]
+    struct SnapshottingRepo {
+        events: Vec<StoredEvent>,
+        snapshots: MemorySnapshots,
+    }
+
+    impl SnapshottingRepo {
+        fn new(events: Vec<StoredEvent>, policy: SnapshotPolicy) -> Self {
+            Self {
+                events,
+                snapshots: MemorySnapshots {
+                    policy,
+                    snapshots: Mutex::new(HashMap::new()),
+                },
+            }
+        }
+    }
+
+    #[async_trait]
+    impl EventRepository for SnapshottingRepo {
+        async fn load_events(&self, aggregate_id: Uuid) -> Result<Vec<StoredEvent>, DomainError> {
+            Ok(self
+                .events
+                .iter()
+                .filter(|e| e.aggregate_id == aggregate_id)
+                .cloned()
+                .collect())
+        }
+
+        async fn append_events(
+            &self,
+            _aggregate_id: Uuid,
+            _expected_version: i64,
+            _events: &[StoredEvent],
+        ) -> Result<(), DomainError> {
+            Ok(())
+        }
+
+        async fn list_aggregate_ids(
+            &self,
+            _event_types: &[&str],
+        ) -> Result<Vec<Uuid>, DomainError> {
+            Ok(vec![])
+        }
+
+        fn snapshot_store(&self) -> Option<&dyn SnapshotStore> {
+            Some(&self.snapshots)
+        }
+    }
+
+    fn stored_events(aggregate_id: Uuid, count: i64) -> Vec<StoredEvent> {
+        (1..=count)
+            .map(|sequence_number| StoredEvent {
+                event_id: Uuid::new_v4(),
+                aggregate_id,
+                event_type: "test.incremented".to_owned(),
+                payload: serde_json::json!({}),
+                sequence_number,
+                correlation_id: Uuid::new_v4(),
+                causation_id: Uuid::new_v4(),
+                occurred_at: Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap(),
+                schema_version: 1,
+            })
+            .collect()
+    }
+
+    fn clock() -> FixedClock {
+        FixedClock(Utc.with_ymd_and_hms(2026, 3, 1, 12, 0, 0).unwrap())
+    }
+
+    #[test]
+    fn test_take_and_restore_snapshot_round_trip() {
+        let mut counter = Counter::new(Uuid::new_v4());
+        counter.count = 7;
+        counter.version = 7;
+
+        let snapshot = take_snapshot(&counter, &clock()).unwrap();
+        let restored: Counter = restore_snapshot(&snapshot).unwrap();
+
+        assert_eq!(snapshot.aggregate_type, "test.counter");
+        assert_eq!(snapshot.version, 7);
+        assert_eq!(restored.id, counter.id);
+        assert_eq!(restored.count, 7);
+        assert!(restored.uncommitted_events.is_empty());
+    }
+
+    #[test]
+    fn test_restore_snapshot_rejects_type_mismatch() {
+        let counter = Counter::new(Uuid::new_v4());
+        let mut snapshot = take_snapshot(&counter, &clock()).unwrap();
+        snapshot.aggregate_type = "test.other".to_owned();
+
+        let result = restore_snapshot::<Counter>(&snapshot);
+
+        assert!(matches!(result, Err(DomainError::Infrastructure(_))));
+    }
+
+    #[tokio::test]
+    async fn test_load_with_snapshot_without_store_returns_all_events() {
+        #[derive(Debug)]
+        struct PlainRepo(Vec<StoredEvent>);
+
+        #[async_trait]
+        impl EventRepository for PlainRepo {
+            async fn load_events(&self, _id: Uuid) -> Result<Vec<StoredEvent>, DomainError> {
+                Ok(self.0.clone())
+            }
+
+            async fn append_events(
+                &self,
+                _id: Uuid,
+                _expected_version: i64,
+                _events: &[StoredEvent],
+            ) -> Result<(), DomainError> {
+                Ok(())
+            }
+
+            async fn list_aggregate_ids(&self, _types: &[&str]) -> Result<Vec<Uuid>, DomainError> {
+                Ok(vec![])
+            }
+        }
+
+        let id = Uuid::new_v4();
+        let repo = PlainRepo(stored_events(id, 3));
+
+        let (restored, events) = load_with_snapshot::<Counter>(&repo, id).await.unwrap();
+
+        assert!(restored.is_none());
+        assert_eq!(events.len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_load_with_snapshot_replays_only_tail() {
+        let id = Uuid::new_v4();
+        let repo = SnapshottingRepo::new(stored_events(id, 5), SnapshotPolicy::every(3));
+        let mut counter = Counter::new(id);
+        counter.count = 3;
+        counter.version = 3;
+        repo.snapshots
+            .save_snapshot(&take_snapshot(&counter, &clock()).unwrap())
+            .await
+            .unwrap();
+
+        let (restored, tail) = load_with_snapshot::<Counter>(&repo, id).await.unwrap();
+
+        assert_eq!(restored.unwrap().version, 3);
+        assert_eq!(tail.len(), 2);
+        assert_eq!(tail[0].sequence_number, 4);
+        assert_eq!(tail[1].sequence_number, 5);
+    }
+
+    #[tokio::test]
+    async fn test_load_with_snapshot_falls_back_on_unreadable_snapshot() {
+        let id = Uuid::new_v4();
+        let repo = SnapshottingRepo::new(stored_events(id, 4), SnapshotPolicy::every(3));
+        repo.snapshots
+            .save_snapshot(&AggregateSnapshot {
+                aggregate_id: id,
+                aggregate_type: "test.counter".to_owned(),
+                version: 3,
+                state: serde_json::json!({ "unexpected": true }),
+                taken_at: clock().now(),
+            })
+            .await
+            .unwrap();
+
+        let (restored, events) = load_with_snapshot::<Counter>(&repo, id).await.unwrap();
+
+        assert!(restored.is_none());
+        assert_eq!(events.len(), 4);
+    }
+
+    #[tokio::test]
+    async fn test_save_snapshot_if_due_saves_when_boundary_crossed() {
+        let id = Uuid::new_v4();
+        let repo = SnapshottingRepo::new(vec![], SnapshotPolicy::every(3));
+        let mut counter = Counter::new(id);
+        counter.version = 2;
+        counter.count = 2;
+        counter.increment();
+
+        save_snapshot_if_due(&repo, counter, &clock()).await;
+
+        let snapshot = repo.snapshots.load_snapshot(id).await.unwrap().unwrap();
+        assert_eq!(snapshot.version, 3);
+        assert_eq!(snapshot.state["count"], 3);
+    }
+
+    #[tokio::test]
+    async fn test_save_snapshot_if_due_skips_within_interval() {
+        let id = Uuid::new_v4();
+        let repo = SnapshottingRepo::new(vec![], SnapshotPolicy::every(3));
+        let mut counter = Counter::new(id);
+        counter.increment();
+
+        save_snapshot_if_due(&repo, counter, &clock()).await;
+
+        assert!(repo.snapshots.load_snapshot(id).await.unwrap().is_none());
+    }
+}
@@ -0,0 +1,140 @@
+//! Subscription filters — which events a live subscriber wants to see.
+
+use uuid::Uuid;
+
+use crate::repository::StoredEvent;
+
+/// Selects the events delivered to a subscriber.
+///
+/// Every criterion that is set must match; an empty filter matches every
+/// event.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SubscriptionFilter {
+    aggregate_id: Option<Uuid>,
+    event_type_prefix: Option<String>,
+    correlation_id: Option<Uuid>,
+}
+
+impl SubscriptionFilter {
+    /// A filter matching every event.
+    #[must_use]
+    pub fn all() -> Self {
+        Self::default()
+    }
+
+    /// Only events belonging to `aggregate_id`.
+    #[must_use]
+    pub fn for_aggregate(mut self, aggregate_id: Uuid) -> Self {
+        self.aggregate_id = Some(aggregate_id);
+        self
+    }
+
+    /// Only events whose type starts with `prefix`. A trailing `*` is
+    /// ignored, so `"narrative.*"` and `"narrative."` are equivalent.
+    #[must_use]
+    pub fn with_event_type_prefix(mut self, prefix: &str) -> Self {
+        self.event_type_prefix = Some(prefix.trim_end_matches('*').to_owned());
+        self
+    }
+
+    /// Only events carrying `correlation_id`.
+    #[must_use]
+    pub fn with_correlation_id(mut self, correlation_id: Uuid) -> Self {
+        self.correlation_id = Some(correlation_id);
+        self
+    }
+
+    /// The aggregate ID criterion, if set.
+    #[must_use]
+    pub fn aggregate_id(&self) -> Option<Uuid> {
+        self.aggregate_id
+    }
+
+    /// The event type prefix criterion (without any trailing `*`), if set.
+    #[must_use]
+    pub fn event_type_prefix(&self) -> Option<&str> {
+        self.event_type_prefix.as_deref()
+    }
+
+    /// The correlation ID criterion, if set.
+    #[must_use]
+    pub fn correlation_id(&self) -> Option<Uuid> {
+        self.correlation_id
+    }
+
+    /// Returns `true` if `event` satisfies every criterion of this filter.
+    #[must_use]
+    pub fn matches(&self, event: &StoredEvent) -> bool {
+        self.aggregate_id.is_none_or(|id| event.aggregate_id == id)
+            && self
+                .event_type_prefix
+                .as_deref()
+                .is_none_or(|prefix| event.event_type.starts_with(prefix))
+            && self
+                .correlation_id
+                .is_none_or(|id| event.correlation_id == id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+
+    use super::*;
+
+    fn event(aggregate_id: Uuid, event_type: &str, correlation_id: Uuid) -> StoredEvent {
+        StoredEvent {
+            event_id: Uuid::new_v4(),
+            aggregate_id,
+            event_type: event_type.to_owned(),
+            payload: serde_json::json!({}),
+            sequence_number: 1,
+            correlation_id,
+            causation_id: Uuid::new_v4(),
+            occurred_at: Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap(),
+            schema_version: 1,
+        }
+    }
+
+    #[test]
+    fn test_empty_filter_matches_everything() {
+        let stored = event(Uuid::new_v4(), "narrative.beat_advanced", Uuid::new_v4());
+
+        assert!(SubscriptionFilter::all().matches(&stored));
+    }
+
+    #[test]
+    fn test_event_type_prefix_accepts_wildcard_suffix() {
+        let filter = SubscriptionFilter::all().with_event_type_prefix("narrative.*");
+
+        assert_eq!(filter.event_type_prefix(), Some("narrative."));
+        assert!(filter.matches(&event(
+            Uuid::new_v4(),
+            "narrative.beat_advanced",
+            Uuid::new_v4()
+        )));
+        assert!(!filter.matches(&event(
+            Uuid::new_v4(),
+            "rules.check_resolved",
+            Uuid::new_v4()
+        )));
+    }
+
+    #[test]
+    fn test_all_criteria_must_match() {
+        let aggregate_id = Uuid::new_v4();
+        let correlation_id = Uuid::new_v4();
+        let filter = SubscriptionFilter::all()
+            .for_aggregate(aggregate_id)
+            .with_event_type_prefix("rules.")
+            .with_correlation_id(correlation_id);
+
+        assert!(filter.matches(&event(aggregate_id, "rules.check_resolved", correlation_id)));
+        assert!(!filter.matches(&event(
+            Uuid::new_v4(),
+            "rules.check_resolved",
+            correlation_id
+        )));
+        assert!(!filter.matches(&event(aggregate_id, "rules.check_resolved", Uuid::new_v4())));
+    }
+}
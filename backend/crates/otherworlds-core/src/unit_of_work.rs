@@ -0,0 +1,399 @@
+//! Unit of work — buffers appends across aggregates and commits them together.
+//!
+//! Orchestrators that run several command handlers in sequence wrap their
+//! repository in a `UnitOfWork`. Handlers see their own earlier appends when
+//! they load aggregates, but nothing reaches the underlying store until
+//! `commit`, which hands every buffered stream to `append_many` at once.
+
+use std::sync::Mutex;
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::error::DomainError;
+use crate::repository::{EventRepository, PositionedEvent, StoredEvent, StreamAppend};
+
+/// An `EventRepository` that stages appends in memory over a base repository.
+///
+/// Reads combine committed events from the base repository with the staged
+/// events of this unit of work. Global log reads only see committed events,
+/// and snapshots are disabled so that no snapshot can outlive a unit of work
+/// that is never committed.
+#[derive(Debug)]
+pub struct UnitOfWork<'a> {
+    base: &'a dyn EventRepository,
+    pending: Mutex<Vec<StreamAppend>>,
+}
+
+impl<'a> UnitOfWork<'a> {
+    /// Starts an empty unit of work over `base`.
+    #[must_use]
+    pub fn new(base: &'a dyn EventRepository) -> Self {
+        Self {
+            base,
+            pending: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Returns the number of events staged so far.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::Infrastructure` if the internal mutex is poisoned.
+    pub fn pending_event_count(&self) -> Result<usize, DomainError> {
+        Ok(self.lock()?.iter().map(|append| append.events.len()).sum())
+    }
+
+    /// Returns the number of staged events `commit` will write ahead of the
+    /// next event appended to `aggregate_id`.
+    ///
+    /// Streams are committed in the order they were first staged, so this
+    /// counts every stream staged up to and including `aggregate_id`'s.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::Infrastructure` if the internal mutex is poisoned.
+    pub fn pending_events_before(&self, aggregate_id: Uuid) -> Result<usize, DomainError> {
+        let pending = self.lock()?;
+        let mut count = 0;
+        for append in pending.iter() {
+            count += append.events.len();
+            if append.aggregate_id == aggregate_id {
+                break;
+            }
+        }
+        Ok(count)
+    }
+
+    /// Appends every staged stream to the base repository in one call to
+    /// `append_many`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the base repository's error, typically
+    /// `DomainError::ConcurrencyConflict` if another writer appended to one
+    /// of the streams since it was loaded.
+    pub async fn commit(self) -> Result<(), DomainError> {
+        let appends = self
+            .pending
+            .into_inner()
+            .map_err(|e| DomainError::Infrastructure(format!("unit of work poisoned: {e}")))?;
+        if appends.is_empty() {
+            return Ok(());
+        }
+        self.base.append_many(&appends).await
+    }
+
+    fn lock(&self) -> Result<std::sync::MutexGuard<'_, Vec<StreamAppend>>, DomainError> {
+        self.pending
+            .lock()
+            .map_err(|e| DomainError::Infrastructure(format!("unit of work poisoned: {e}")))
+    }
+}
+
+#[async_trait]
+impl EventRepository for UnitOfWork<'_> {
+    async fn load_events(&self, aggregate_id: Uuid) -> Result<Vec<StoredEvent>, DomainError> {
+        let mut events = self.base.load_events(aggregate_id).await?;
+        let pending = self.lock()?;
+        if let Some(append) = pending.iter().find(|a| a.aggregate_id == aggregate_id) {
+            events.extend(append.events.iter().cloned());
+        }
+        Ok(events)
+    }
+
+    async fn append_events(
+        &self,
+        aggregate_id: Uuid,
+        expected_version: i64,
+        events: &[StoredEvent],
+    ) -> Result<(), DomainError> {
+        if events.is_empty() {
+            return Ok(());
+        }
+
+        let mut pending = self.lock()?;
+        if let Some(append) = pending.iter_mut().find(|a| a.aggregate_id == aggregate_id) {
+            let staged_version = append
+                .events
+                .last()
+                .map_or(append.expected_version, |e| e.sequence_number);
+            if staged_version != expected_version {
+                return Err(DomainError::ConcurrencyConflict {
+                    aggregate_id,
+                    expected: expected_version,
+                    actual: staged_version,
+                });
+            }
+            append.events.extend_from_slice(events);
+        } else {
+            pending.push(StreamAppend {
+                aggregate_id,
+                expected_version,
+                events: events.to_vec(),
+            });
+        }
+        Ok(())
+    }
+
+    async fn list_aggregate_ids(&self, event_types: &[&str]) -> Result<Vec<Uuid>, DomainError> {
+        let mut ids = self.base.list_aggregate_ids(event_types).await?;
+        let pending = self.lock()?;
+        for append in pending.iter() {
+            if !ids.contains(&append.aggregate_id)
+                && append
+                    .events
+                    .iter()
+                    .any(|e| event_types.contains(&e.event_type.as_str()))
+            {
+                ids.push(append.aggregate_id);
+            }
+        }
+        Ok(ids)
+    }
+
+    async fn load_positioned_events(
+        &self,
+        aggregate_id: Uuid,
+    ) -> Result<Vec<PositionedEvent>, DomainError> {
+        self.base.load_positioned_events(aggregate_id).await
+    }
+
+    async fn read_all_from(
+        &self,
+        from_position: i64,
+        limit: i64,
+    ) -> Result<Vec<PositionedEvent>, DomainError> {
+        self.base.read_all_from(from_position, limit).await
+    }
+
+    async fn read_by_types_from(
+        &self,
+        event_types: &[&str],
+        from_position: i64,
+        limit: i64,
+    ) -> Result<Vec<PositionedEvent>, DomainError> {
+        self.base
+            .read_by_types_from(event_types, from_position, limit)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+
+    use super::*;
+
+    /// Base repository that records `append_many` calls and can be told to
+    /// reject them.
+    #[derive(Debug, Default)]
+    struct BaseRepo {
+        committed: Mutex<Vec<StoredEvent>>,
+        batches: Mutex<Vec<Vec<StreamAppend>>>,
+        reject: bool,
+    }
+
+    #[async_trait]
+    impl EventRepository for BaseRepo {
+        async fn load_events(&self, aggregate_id: Uuid) -> Result<Vec<StoredEvent>, DomainError> {
+            Ok(self
+                .committed
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|e| e.aggregate_id == aggregate_id)
+                .cloned()
+                .collect())
+        }
+
+        async fn append_events(
+            &self,
+            _aggregate_id: Uuid,
+            _expected_version: i64,
+            _events: &[StoredEvent],
+        ) -> Result<(), DomainError> {
+            panic!("unit of work must commit through append_many");
+        }
+
+        async fn append_many(&self, appends: &[StreamAppend]) -> Result<(), DomainError> {
+            if self.reject {
+                return Err(DomainError::ConcurrencyConflict {
+                    aggregate_id: appends[0].aggregate_id,
+                    expected: appends[0].expected_version,
+                    actual: appends[0].expected_version + 1,
+                });
+            }
+            self.batches.lock().unwrap().push(appends.to_vec());
+            Ok(())
+        }
+
+        async fn list_aggregate_ids(
+            &self,
+            _event_types: &[&str],
+        ) -> Result<Vec<Uuid>, DomainError> {
+            Ok(vec![])
+        }
+    }
+
+    fn event(aggregate_id: Uuid, event_type: &str, sequence_number: i64) -> StoredEvent {
+        StoredEvent {
+            event_id: Uuid::new_v4(),
+            aggregate_id,
+            event_type: event_type.to_owned(),
+            payload: serde_json::json!({}),
+            sequence_number,
+            correlation_id: Uuid::new_v4(),
+            causation_id: Uuid::new_v4(),
+            occurred_at: Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap(),
+            schema_version: 1,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_staged_events_are_visible_to_later_loads() {
+        // Arrange
+        let base = BaseRepo::default();
+        let aggregate_id = Uuid::new_v4();
+        base.committed
+            .lock()
+            .unwrap()
+            .push(event(aggregate_id, "test.created", 1));
+        let uow = UnitOfWork::new(&base);
+
+        // Act
+        uow.append_events(aggregate_id, 1, &[event(aggregate_id, "test.changed", 2)])
+            .await
+            .unwrap();
+        let loaded = uow.load_events(aggregate_id).await.unwrap();
+
+        // Assert
+        assert_eq!(loaded.len(), 2);
+        assert_eq!(loaded[1].sequence_number, 2);
+        assert_eq!(uow.pending_event_count().unwrap(), 1);
+        assert!(base.batches.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_commit_sends_one_batch_with_first_expected_version_per_stream() {
+        // Arrange
+        let base = BaseRepo::default();
+        let first = Uuid::new_v4();
+        let second = Uuid::new_v4();
+        let uow = UnitOfWork::new(&base);
+        uow.append_events(first, 0, &[event(first, "test.created", 1)])
+            .await
+            .unwrap();
+        uow.append_events(second, 4, &[event(second, "test.changed", 5)])
+            .await
+            .unwrap();
+        uow.append_events(first, 1, &[event(first, "test.changed", 2)])
+            .await
+            .unwrap();
+
+        // Act
+        uow.commit().await.unwrap();
+
+        // Assert
+        let batches = base.batches.lock().unwrap();
+        assert_eq!(batches.len(), 1);
+        let batch = &batches[0];
+        assert_eq!(batch.len(), 2);
+        assert_eq!(batch[0].aggregate_id, first);
+        assert_eq!(batch[0].expected_version, 0);
+        assert_eq!(batch[0].events.len(), 2);
+        assert_eq!(batch[1].aggregate_id, second);
+        assert_eq!(batch[1].expected_version, 4);
+    }
+
+    #[tokio::test]
+    async fn test_pending_events_before_counts_streams_committed_first() {
+        // Arrange
+        let base = BaseRepo::default();
+        let first = Uuid::new_v4();
+        let second = Uuid::new_v4();
+        let uow = UnitOfWork::new(&base);
+        uow.append_events(first, 0, &[event(first, "test.created", 1)])
+            .await
+            .unwrap();
+        uow.append_events(second, 0, &[event(second, "test.created", 1)])
+            .await
+            .unwrap();
+
+        // Act
+        let before_first = uow.pending_events_before(first).unwrap();
+        let before_new = uow.pending_events_before(Uuid::new_v4()).unwrap();
+
+        // Assert
+        assert_eq!(before_first, 1);
+        assert_eq!(before_new, 2);
+    }
+
+    #[tokio::test]
+    async fn test_stale_append_within_unit_of_work_is_a_conflict() {
+        // Arrange
+        let base = BaseRepo::default();
+        let aggregate_id = Uuid::new_v4();
+        let uow = UnitOfWork::new(&base);
+        uow.append_events(aggregate_id, 0, &[event(aggregate_id, "test.created", 1)])
+            .await
+            .unwrap();
+
+        // Act
+        let result = uow
+            .append_events(aggregate_id, 0, &[event(aggregate_id, "test.created", 1)])
+            .await;
+
+        // Assert
+        assert!(matches!(
+            result,
+            Err(DomainError::ConcurrencyConflict {
+                expected: 0,
+                actual: 1,
+                ..
+            })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_commit_propagates_base_rejection() {
+        // Arrange
+        let base = BaseRepo {
+            reject: true,
+            ..BaseRepo::default()
+        };
+        let aggregate_id = Uuid::new_v4();
+        let uow = UnitOfWork::new(&base);
+        uow.append_events(aggregate_id, 0, &[event(aggregate_id, "test.created", 1)])
+            .await
+            .unwrap();
+
+        // Act
+        let result = uow.commit().await;
+
+        // Assert
+        assert!(matches!(
+            result,
+            Err(DomainError::ConcurrencyConflict { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_list_aggregate_ids_includes_staged_streams() {
+        // Arrange
+        let base = BaseRepo::default();
+        let aggregate_id = Uuid::new_v4();
+        let uow = UnitOfWork::new(&base);
+        uow.append_events(aggregate_id, 0, &[event(aggregate_id, "test.created", 1)])
+            .await
+            .unwrap();
+
+        // Act
+        let matching = uow.list_aggregate_ids(&["test.created"]).await.unwrap();
+        let other = uow.list_aggregate_ids(&["test.deleted"]).await.unwrap();
+
+        // Assert
+        assert_eq!(matching, vec![aggregate_id]);
+        assert!(other.is_empty());
+    }
+}
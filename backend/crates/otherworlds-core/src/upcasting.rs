@@ -0,0 +1,223 @@
+//! Upcasting — step-by-step migration of stored event payloads.
+//!
+//! Every stored event records the schema version its payload was written
+//! with. When an event shape changes, the owning context bumps that event
+//! type's version by registering an upcaster that rewrites a payload from the
+//! previous version to the next. Before an event is applied, the registry
+//! runs every step between the stored version and the current one.
+
+use std::collections::HashMap;
+
+use serde_json::Value;
+
+use crate::error::DomainError;
+use crate::repository::StoredEvent;
+
+/// Schema version assigned to every event type that has no upcasters.
+pub const INITIAL_SCHEMA_VERSION: i32 = 1;
+
+/// Rewrites a payload from one schema version to the next.
+pub type Upcaster = fn(Value) -> Result<Value, DomainError>;
+
+/// Per-context registry of upcasters, keyed by event type and source version.
+#[derive(Debug, Default)]
+pub struct UpcasterRegistry {
+    steps: HashMap<(String, i32), Upcaster>,
+    current_versions: HashMap<String, i32>,
+}
+
+impl UpcasterRegistry {
+    /// Creates an empty registry. Every event type starts at
+    /// `INITIAL_SCHEMA_VERSION`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers an upcaster migrating `event_type` payloads from
+    /// `from_version` to `from_version + 1`.
+    ///
+    /// Steps must be registered contiguously starting at
+    /// `INITIAL_SCHEMA_VERSION`; the current version of the event type
+    /// becomes one past the highest registered step.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a step for the same event type and version is already
+    /// registered, or if the step does not continue the existing chain.
+    /// Registries are built once at startup, so either indicates a
+    /// programming error.
+    #[must_use]
+    pub fn register(mut self, event_type: &str, from_version: i32, upcaster: Upcaster) -> Self {
+        let current = self.current_version(event_type);
+        assert_eq!(
+            from_version, current,
+            "upcaster for {event_type} must start at version {current}, got {from_version}"
+        );
+        self.steps
+            .insert((event_type.to_owned(), from_version), upcaster);
+        self.current_versions
+            .insert(event_type.to_owned(), from_version + 1);
+        self
+    }
+
+    /// Returns the schema version that newly produced events of
+    /// `event_type` are written with.
+    #[must_use]
+    pub fn current_version(&self, event_type: &str) -> i32 {
+        self.current_versions
+            .get(event_type)
+            .copied()
+            .unwrap_or(INITIAL_SCHEMA_VERSION)
+    }
+
+    /// Migrates a stored event's payload to the current schema version of its
+    /// event type.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::Infrastructure` if the stored version is newer
+    /// than the current version, if a step in the chain is missing, or if an
+    /// upcaster fails.
+    pub fn upcast(&self, stored: &StoredEvent) -> Result<Value, DomainError> {
+        let current = self.current_version(&stored.event_type);
+        if stored.schema_version > current {
+            return Err(DomainError::Infrastructure(format!(
+                "event {} has schema version {} but {} supports up to {current}",
+                stored.event_id, stored.schema_version, stored.event_type
+            )));
+        }
+
+        let mut payload = stored.payload.clone();
+        for version in stored.schema_version..current {
+            let step = self
+                .steps
+                .get(&(stored.event_type.clone(), version))
+                .ok_or_else(|| {
+                    DomainError::Infrastructure(format!(
+                        "no upcaster for {} from schema version {version}",
+                        stored.event_type
+                    ))
+                })?;
+            payload = step(payload)?;
+        }
+        Ok(payload)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+    use serde_json::json;
+    use uuid::Uuid;
+
+    use super::*;
+
+    fn stored_event(event_type: &str, schema_version: i32, payload: Value) -> StoredEvent {
+        StoredEvent {
+            event_id: Uuid::new_v4(),
+            aggregate_id: Uuid::new_v4(),
+            event_type: event_type.to_owned(),
+            payload,
+            sequence_number: 1,
+            correlation_id: Uuid::new_v4(),
+            causation_id: Uuid::new_v4(),
+            occurred_at: Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap(),
+            schema_version,
+        }
+    }
+
+    #[allow(clippy::unnecessary_wraps)]
+    fn add_quantity(mut payload: Value) -> Result<Value, DomainError> {
+        payload["ItemAdded"]["quantity"] = json!(1);
+        Ok(payload)
+    }
+
+    #[allow(clippy::unnecessary_wraps)]
+    fn rename_item_id(mut payload: Value) -> Result<Value, DomainError> {
+        let item = payload["ItemAdded"]
+            .as_object_mut()
+            .and_then(|obj| obj.remove("item_id"))
+            .unwrap_or(Value::Null);
+        payload["ItemAdded"]["item_ref"] = item;
+        Ok(payload)
+    }
+
+    fn registry() -> UpcasterRegistry {
+        UpcasterRegistry::new()
+            .register("inventory.item_added", 1, add_quantity)
+            .register("inventory.item_added", 2, rename_item_id)
+    }
+
+    #[test]
+    fn test_current_version_defaults_to_initial() {
+        let registry = UpcasterRegistry::new();
+
+        assert_eq!(
+            registry.current_version("any.event"),
+            INITIAL_SCHEMA_VERSION
+        );
+    }
+
+    #[test]
+    fn test_current_version_follows_registered_steps() {
+        assert_eq!(registry().current_version("inventory.item_added"), 3);
+    }
+
+    #[test]
+    fn test_upcast_runs_every_step_in_order() {
+        let stored = stored_event(
+            "inventory.item_added",
+            1,
+            json!({ "ItemAdded": { "item_id": "sword" } }),
+        );
+
+        let payload = registry().upcast(&stored).unwrap();
+
+        assert_eq!(
+            payload,
+            json!({ "ItemAdded": { "item_ref": "sword", "quantity": 1 } })
+        );
+    }
+
+    #[test]
+    fn test_upcast_starts_from_stored_version() {
+        let stored = stored_event(
+            "inventory.item_added",
+            2,
+            json!({ "ItemAdded": { "item_id": "sword", "quantity": 4 } }),
+        );
+
+        let payload = registry().upcast(&stored).unwrap();
+
+        assert_eq!(
+            payload,
+            json!({ "ItemAdded": { "item_ref": "sword", "quantity": 4 } })
+        );
+    }
+
+    #[test]
+    fn test_upcast_leaves_current_payload_untouched() {
+        let original = json!({ "FlagSet": { "flag": "door_open", "value": true } });
+        let stored = stored_event("world_state.flag_set", 1, original.clone());
+
+        let payload = registry().upcast(&stored).unwrap();
+
+        assert_eq!(payload, original);
+    }
+
+    #[test]
+    fn test_upcast_rejects_future_schema_version() {
+        let stored = stored_event("inventory.item_added", 4, json!({}));
+
+        let result = registry().upcast(&stored);
+
+        assert!(matches!(result, Err(DomainError::Infrastructure(_))));
+    }
+
+    #[test]
+    #[should_panic(expected = "must start at version 1")]
+    fn test_register_rejects_gap_in_chain() {
+        let _ = UpcasterRegistry::new().register("inventory.item_added", 2, add_quantity);
+    }
+}
@@ -1,5 +1,14 @@
 //! Otherworlds Event Store — PostgreSQL-backed event persistence.
 //!
-//! Schema is managed via sqlx migrations in `backend/migrations/`.
+//! Schema is managed via sqlx migrations in `backend/migrations/`. The
+//! `memory_*` modules provide in-memory equivalents for running without a
+//! database, and `sqlite_event_repository` a file-backed one for
+//! single-player builds (schema in `backend/migrations-sqlite/`).
 
+pub mod memory_event_repository;
+pub mod memory_read_model_store;
 pub mod pg_event_repository;
+pub mod pg_event_subscriber;
+pub mod pg_read_model_store;
+pub mod pg_snapshot_store;
+pub mod sqlite_event_repository;
@@ -0,0 +1,452 @@
+//! Event Store — in-memory `EventRepository` implementation.
+//!
+//! Serves real traffic without `PostgreSQL`: appends are checked for
+//! optimistic concurrency, the global log is kept in commit order, and the
+//! log can optionally be mirrored to a JSON Lines file so a local playtest
+//! survives restarts.
+
+use std::collections::{BTreeSet, HashMap};
+use std::fs::OpenOptions;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tokio::sync::Mutex;
+use tracing::{debug, info, instrument, warn};
+use uuid::Uuid;
+
+use otherworlds_core::error::DomainError;
+use otherworlds_core::repository::{EventRepository, PositionedEvent, StoredEvent, StreamAppend};
+
+/// On-disk representation of a stored event.
+#[derive(Serialize, Deserialize)]
+struct PersistedEvent {
+    event_id: Uuid,
+    aggregate_id: Uuid,
+    event_type: String,
+    payload: serde_json::Value,
+    sequence_number: i64,
+    correlation_id: Uuid,
+    causation_id: Uuid,
+    occurred_at: DateTime<Utc>,
+    schema_version: i32,
+}
+
+impl From<&StoredEvent> for PersistedEvent {
+    fn from(event: &StoredEvent) -> Self {
+        Self {
+            event_id: event.event_id,
+            aggregate_id: event.aggregate_id,
+            event_type: event.event_type.clone(),
+            payload: event.payload.clone(),
+            sequence_number: event.sequence_number,
+            correlation_id: event.correlation_id,
+            causation_id: event.causation_id,
+            occurred_at: event.occurred_at,
+            schema_version: event.schema_version,
+        }
+    }
+}
+
+impl From<PersistedEvent> for StoredEvent {
+    fn from(event: PersistedEvent) -> Self {
+        Self {
+            event_id: event.event_id,
+            aggregate_id: event.aggregate_id,
+            event_type: event.event_type,
+            payload: event.payload,
+            sequence_number: event.sequence_number,
+            correlation_id: event.correlation_id,
+            causation_id: event.causation_id,
+            occurred_at: event.occurred_at,
+            schema_version: event.schema_version,
+        }
+    }
+}
+
+/// The global log plus a per-aggregate index into it.
+#[derive(Debug, Default)]
+struct EventLog {
+    /// Every event in commit order; position `n` is at index `n - 1`.
+    events: Vec<StoredEvent>,
+    /// Indexes into `events` for each aggregate, in sequence order.
+    streams: HashMap<Uuid, Vec<usize>>,
+}
+
+impl EventLog {
+    fn from_events(events: Vec<StoredEvent>) -> Self {
+        let mut log = Self::default();
+        for event in events {
+            log.push(event);
+        }
+        log
+    }
+
+    fn push(&mut self, event: StoredEvent) {
+        self.streams
+            .entry(event.aggregate_id)
+            .or_default()
+            .push(self.events.len());
+        self.events.push(event);
+    }
+
+    /// Removes every event at or after `len`, undoing a failed append.
+    fn truncate(&mut self, len: usize) {
+        for event in self.events.drain(len..) {
+            if let Some(indexes) = self.streams.get_mut(&event.aggregate_id) {
+                indexes.retain(|&index| index < len);
+                if indexes.is_empty() {
+                    self.streams.remove(&event.aggregate_id);
+                }
+            }
+        }
+    }
+
+    fn version(&self, aggregate_id: Uuid) -> i64 {
+        self.streams
+            .get(&aggregate_id)
+            .and_then(|indexes| indexes.last())
+            .map_or(0, |&index| self.events[index].sequence_number)
+    }
+
+    fn stream(&self, aggregate_id: Uuid) -> impl Iterator<Item = &StoredEvent> {
+        self.streams
+            .get(&aggregate_id)
+            .into_iter()
+            .flatten()
+            .map(|&index| &self.events[index])
+    }
+
+    fn positioned_stream(&self, aggregate_id: Uuid) -> Vec<PositionedEvent> {
+        self.streams
+            .get(&aggregate_id)
+            .into_iter()
+            .flatten()
+            .map(|&index| PositionedEvent {
+                position: i64::try_from(index).map_or(i64::MAX, |i| i + 1),
+                event: self.events[index].clone(),
+            })
+            .collect()
+    }
+
+    fn read_from(
+        &self,
+        event_types: Option<&[&str]>,
+        from_position: i64,
+        limit: i64,
+    ) -> Vec<PositionedEvent> {
+        let skip = usize::try_from(from_position.saturating_sub(1)).unwrap_or(0);
+        let take = usize::try_from(limit).unwrap_or(0);
+        self.events
+            .iter()
+            .enumerate()
+            .skip(skip)
+            .filter(|(_, event)| {
+                event_types.is_none_or(|types| types.contains(&event.event_type.as_str()))
+            })
+            .take(take)
+            .map(|(index, event)| PositionedEvent {
+                position: i64::try_from(index).map_or(i64::MAX, |i| i + 1),
+                event: event.clone(),
+            })
+            .collect()
+    }
+}
+
+/// In-memory event repository with optional file persistence.
+///
+/// All state lives behind one mutex, so appends are serialized and global
+/// positions follow commit order exactly as in `PgEventRepository`. When a
+/// file is configured, each append adds its events to the end of the file,
+/// one JSON object per line, on the blocking thread pool; the mutex is held
+/// until the write is synced so the file stays in commit order.
+#[derive(Debug, Default)]
+pub struct InMemoryEventRepository {
+    log: Mutex<EventLog>,
+    file: Option<PathBuf>,
+}
+
+impl InMemoryEventRepository {
+    /// Creates an empty repository that keeps events only in memory.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Opens a repository mirrored to the JSON Lines file at `path`.
+    ///
+    /// Events already in the file are loaded; a missing file starts an
+    /// empty log and is created on the first append. An incomplete last
+    /// line, left by a crash during an append, is dropped by rewriting the
+    /// file through a temporary sibling renamed into place. This reads the
+    /// file with blocking I/O and is meant to be called once at startup.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::Infrastructure` if the file exists but cannot
+    /// be read, holds an event that does not decode, or cannot be rewritten.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, DomainError> {
+        let path = path.as_ref().to_path_buf();
+        let (events, rewrite) = match std::fs::read(&path) {
+            Ok(bytes) => decode_event_file(&path, &bytes)?,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => (Vec::new(), false),
+            Err(err) => {
+                return Err(DomainError::Infrastructure(format!(
+                    "failed to read event file {}: {err}",
+                    path.display()
+                )));
+            }
+        };
+        if rewrite {
+            replace_event_file(&path, &events)?;
+        }
+
+        info!(path = %path.display(), event_count = events.len(), "opened event file");
+
+        Ok(Self {
+            log: Mutex::new(EventLog::from_events(events)),
+            file: Some(path),
+        })
+    }
+
+    /// Checks every stream's expected version, then appends all events.
+    ///
+    /// Either every stream is appended or, on a conflict or a failed file
+    /// write, none is.
+    async fn append_streams(&self, appends: &[StreamAppend]) -> Result<(), DomainError> {
+        let mut log = self.log.lock().await;
+
+        // Streams may repeat within one call; later entries must continue
+        // from the events of earlier ones.
+        let mut versions: HashMap<Uuid, i64> = HashMap::new();
+        for append in appends.iter().filter(|a| !a.events.is_empty()) {
+            let actual = *versions
+                .entry(append.aggregate_id)
+                .or_insert_with(|| log.version(append.aggregate_id));
+            if actual != append.expected_version {
+                return Err(DomainError::ConcurrencyConflict {
+                    aggregate_id: append.aggregate_id,
+                    expected: append.expected_version,
+                    actual,
+                });
+            }
+            let mut next = actual;
+            for event in &append.events {
+                if event.sequence_number <= next {
+                    return Err(DomainError::ConcurrencyConflict {
+                        aggregate_id: append.aggregate_id,
+                        expected: append.expected_version,
+                        actual: next,
+                    });
+                }
+                next = event.sequence_number;
+            }
+            versions.insert(append.aggregate_id, next);
+        }
+
+        let committed_len = log.events.len();
+        for append in appends {
+            for event in &append.events {
+                log.push(event.clone());
+            }
+        }
+
+        if let Err(err) = self.persist(&log.events[committed_len..]).await {
+            log.truncate(committed_len);
+            return Err(err);
+        }
+
+        Ok(())
+    }
+
+    /// Appends `events` to the backing file, if any.
+    async fn persist(&self, events: &[StoredEvent]) -> Result<(), DomainError> {
+        let Some(path) = &self.file else {
+            return Ok(());
+        };
+
+        let bytes = encode_lines(events)?;
+        let path = path.clone();
+        tokio::task::spawn_blocking(move || append_to_event_file(&path, &bytes))
+            .await
+            .map_err(|e| DomainError::Infrastructure(format!("event file write failed: {e}")))?
+    }
+}
+
+/// Encodes `events` as JSON Lines.
+fn encode_lines(events: &[StoredEvent]) -> Result<Vec<u8>, DomainError> {
+    let mut bytes = Vec::new();
+    for event in events {
+        serde_json::to_writer(&mut bytes, &PersistedEvent::from(event))
+            .map_err(|e| DomainError::Infrastructure(format!("failed to encode events: {e}")))?;
+        bytes.push(b'\n');
+    }
+    Ok(bytes)
+}
+
+/// Decodes an event file, returning its events and whether the file must be
+/// rewritten because it ends in a torn line.
+fn decode_event_file(path: &Path, bytes: &[u8]) -> Result<(Vec<StoredEvent>, bool), DomainError> {
+    let invalid = |e: serde_json::Error| {
+        DomainError::Infrastructure(format!("invalid event file {}: {e}", path.display()))
+    };
+
+    // Every complete event ends in a newline; anything after the last one
+    // is a write that never finished, and was never acknowledged.
+    let (complete, tail) = match bytes.iter().rposition(|&b| b == b'\n') {
+        Some(end) => bytes.split_at(end + 1),
+        None => (&bytes[..0], bytes),
+    };
+    let mut events = Vec::new();
+    for line in complete.split(|&b| b == b'\n') {
+        if line.trim_ascii().is_empty() {
+            continue;
+        }
+        let event = serde_json::from_slice::<PersistedEvent>(line).map_err(invalid)?;
+        events.push(StoredEvent::from(event));
+    }
+    let torn = !tail.trim_ascii().is_empty();
+    if torn {
+        warn!(path = %path.display(), "dropping an incomplete event at the end of the event file");
+    }
+    Ok((events, torn))
+}
+
+/// Appends encoded events to the file at `path`, creating it if needed.
+///
+/// A failed write is cut back off so the file always ends on a whole event.
+fn append_to_event_file(path: &Path, bytes: &[u8]) -> Result<(), DomainError> {
+    let append = || -> std::io::Result<()> {
+        let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+        let len = file.metadata()?.len();
+        file.write_all(bytes)
+            .and_then(|()| file.sync_data())
+            .inspect_err(|_| {
+                file.set_len(len).ok();
+            })
+    };
+    append().map_err(|e| {
+        DomainError::Infrastructure(format!(
+            "failed to write event file {}: {e}",
+            path.display()
+        ))
+    })
+}
+
+/// Replaces the file at `path` with `events`.
+///
+/// Writes to a sibling temporary file first and renames it into place so a
+/// crash never leaves a truncated log behind.
+fn replace_event_file(path: &Path, events: &[StoredEvent]) -> Result<(), DomainError> {
+    let bytes = encode_lines(events)?;
+    let mut tmp_path = path.as_os_str().to_owned();
+    tmp_path.push(".tmp");
+    std::fs::write(&tmp_path, bytes)
+        .and_then(|()| std::fs::rename(&tmp_path, path))
+        .map_err(|e| {
+            DomainError::Infrastructure(format!(
+                "failed to write event file {}: {e}",
+                path.display()
+            ))
+        })
+}
+
+#[async_trait]
+impl EventRepository for InMemoryEventRepository {
+    #[instrument(skip(self), fields(%aggregate_id))]
+    async fn load_events(&self, aggregate_id: Uuid) -> Result<Vec<StoredEvent>, DomainError> {
+        let log = self.log.lock().await;
+        let events: Vec<StoredEvent> = log.stream(aggregate_id).cloned().collect();
+
+        debug!(event_count = events.len(), "loaded events for aggregate");
+
+        Ok(events)
+    }
+
+    #[instrument(skip(self), fields(%aggregate_id, %after_sequence))]
+    async fn load_events_after(
+        &self,
+        aggregate_id: Uuid,
+        after_sequence: i64,
+    ) -> Result<Vec<StoredEvent>, DomainError> {
+        let log = self.log.lock().await;
+        Ok(log
+            .stream(aggregate_id)
+            .filter(|e| e.sequence_number > after_sequence)
+            .cloned()
+            .collect())
+    }
+
+    #[instrument(skip(self, events), fields(%aggregate_id, %expected_version, event_count = events.len()))]
+    async fn append_events(
+        &self,
+        aggregate_id: Uuid,
+        expected_version: i64,
+        events: &[StoredEvent],
+    ) -> Result<(), DomainError> {
+        if events.is_empty() {
+            return Ok(());
+        }
+
+        self.append_streams(&[StreamAppend {
+            aggregate_id,
+            expected_version,
+            events: events.to_vec(),
+        }])
+        .await?;
+
+        debug!("committed events for aggregate");
+
+        Ok(())
+    }
+
+    #[instrument(skip(self, appends), fields(stream_count = appends.len()))]
+    async fn append_many(&self, appends: &[StreamAppend]) -> Result<(), DomainError> {
+        self.append_streams(appends).await?;
+
+        debug!("committed events for all streams");
+
+        Ok(())
+    }
+
+    #[instrument(skip(self, event_types), fields(type_count = event_types.len()))]
+    async fn list_aggregate_ids(&self, event_types: &[&str]) -> Result<Vec<Uuid>, DomainError> {
+        let log = self.log.lock().await;
+        let ids: BTreeSet<Uuid> = log
+            .events
+            .iter()
+            .filter(|e| event_types.contains(&e.event_type.as_str()))
+            .map(|e| e.aggregate_id)
+            .collect();
+        Ok(ids.into_iter().collect())
+    }
+
+    async fn load_positioned_events(
+        &self,
+        aggregate_id: Uuid,
+    ) -> Result<Vec<PositionedEvent>, DomainError> {
+        let log = self.log.lock().await;
+        Ok(log.positioned_stream(aggregate_id))
+    }
+
+    async fn read_all_from(
+        &self,
+        from_position: i64,
+        limit: i64,
+    ) -> Result<Vec<PositionedEvent>, DomainError> {
+        let log = self.log.lock().await;
+        Ok(log.read_from(None, from_position, limit))
+    }
+
+    async fn read_by_types_from(
+        &self,
+        event_types: &[&str],
+        from_position: i64,
+        limit: i64,
+    ) -> Result<Vec<PositionedEvent>, DomainError> {
+        let log = self.log.lock().await;
+        Ok(log.read_from(Some(event_types), from_position, limit))
+    }
+}
@@ -0,0 +1,135 @@
+//! Event Store — in-memory `ReadModelStore` implementation.
+
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Mutex, MutexGuard};
+
+use async_trait::async_trait;
+use otherworlds_core::error::DomainError;
+use otherworlds_core::projection::{ReadModelStore, document_version};
+use serde_json::Value;
+use uuid::Uuid;
+
+/// Documents of every collection, keyed by collection name then ID.
+type Collections = HashMap<String, BTreeMap<Uuid, Value>>;
+
+/// A read-model store that keeps collections and checkpoints in memory.
+///
+/// Collections are ordered by ID, matching the `PostgreSQL` store. Nothing
+/// is persisted: checkpoints start at zero, so projections rebuild from the
+/// event log on startup.
+#[derive(Debug, Default)]
+pub struct InMemoryReadModelStore {
+    collections: Mutex<Collections>,
+    checkpoints: Mutex<HashMap<String, i64>>,
+    versions: Mutex<HashMap<String, i32>>,
+}
+
+impl InMemoryReadModelStore {
+    /// Creates an empty store.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn collections(&self) -> Result<MutexGuard<'_, Collections>, DomainError> {
+        self.collections
+            .lock()
+            .map_err(|e| DomainError::Infrastructure(format!("read model mutex poisoned: {e}")))
+    }
+
+    fn checkpoints(&self) -> Result<MutexGuard<'_, HashMap<String, i64>>, DomainError> {
+        self.checkpoints
+            .lock()
+            .map_err(|e| DomainError::Infrastructure(format!("checkpoint mutex poisoned: {e}")))
+    }
+
+    fn versions(&self) -> Result<MutexGuard<'_, HashMap<String, i32>>, DomainError> {
+        self.versions.lock().map_err(|e| {
+            DomainError::Infrastructure(format!("projection version mutex poisoned: {e}"))
+        })
+    }
+}
+
+#[async_trait]
+impl ReadModelStore for InMemoryReadModelStore {
+    async fn get(&self, collection: &str, id: Uuid) -> Result<Option<Value>, DomainError> {
+        Ok(self
+            .collections()?
+            .get(collection)
+            .and_then(|documents| documents.get(&id).cloned()))
+    }
+
+    async fn list(&self, collection: &str) -> Result<Vec<Value>, DomainError> {
+        Ok(self
+            .collections()?
+            .get(collection)
+            .map(|documents| documents.values().cloned().collect())
+            .unwrap_or_default())
+    }
+
+    async fn upsert(
+        &self,
+        collection: &str,
+        id: Uuid,
+        document: &Value,
+    ) -> Result<(), DomainError> {
+        self.collections()?
+            .entry(collection.to_owned())
+            .or_default()
+            .insert(id, document.clone());
+        Ok(())
+    }
+
+    async fn upsert_if_newer(
+        &self,
+        collection: &str,
+        id: Uuid,
+        document: &Value,
+        version: i64,
+    ) -> Result<(), DomainError> {
+        let mut collections = self.collections()?;
+        let documents = collections.entry(collection.to_owned()).or_default();
+        if documents
+            .get(&id)
+            .and_then(document_version)
+            .is_none_or(|stored| stored <= version)
+        {
+            documents.insert(id, document.clone());
+        }
+        Ok(())
+    }
+
+    async fn delete(&self, collection: &str, id: Uuid) -> Result<(), DomainError> {
+        if let Some(documents) = self.collections()?.get_mut(collection) {
+            documents.remove(&id);
+        }
+        Ok(())
+    }
+
+    async fn clear(&self, collection: &str) -> Result<(), DomainError> {
+        self.collections()?.remove(collection);
+        Ok(())
+    }
+
+    async fn load_checkpoint(&self, projection: &str) -> Result<i64, DomainError> {
+        Ok(self.checkpoints()?.get(projection).copied().unwrap_or(0))
+    }
+
+    async fn save_checkpoint(&self, projection: &str, position: i64) -> Result<(), DomainError> {
+        self.checkpoints()?.insert(projection.to_owned(), position);
+        Ok(())
+    }
+
+    async fn load_projection_version(&self, projection: &str) -> Result<Option<i32>, DomainError> {
+        Ok(self.versions()?.get(projection).copied())
+    }
+
+    async fn save_projection_version(
+        &self,
+        projection: &str,
+        version: i32,
+    ) -> Result<(), DomainError> {
+        self.versions()?.insert(projection.to_owned(), version);
+        Ok(())
+    }
+}
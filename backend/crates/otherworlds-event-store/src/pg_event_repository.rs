@@ -2,19 +2,31 @@
 
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
-use sqlx::PgPool;
+use sqlx::{PgPool, Postgres, Transaction};
 use tracing::{debug, instrument, warn};
 use uuid::Uuid;
 
 use otherworlds_core::error::DomainError;
-use otherworlds_core::repository::{EventRepository, StoredEvent};
+use otherworlds_core::repository::{
+    EventRepository, PositionedEvent, SnapshotPolicy, SnapshotStore, StoredEvent, StreamAppend,
+};
+
+use crate::pg_snapshot_store::PgSnapshotStore;
 
 /// `PostgreSQL` unique-violation error code.
 const UNIQUE_VIOLATION: &str = "23505";
 
+/// Advisory lock key held by every append transaction so that global
+/// positions are assigned and committed in the same order.
+const GLOBAL_LOG_LOCK_KEY: i64 = 0x6f74_6877_6c6f_6721;
+
+/// `LISTEN`/`NOTIFY` channel signalled by every committed append. The payload
+/// is the ID of the aggregate that was appended to.
+pub const EVENT_NOTIFY_CHANNEL: &str = "domain_events";
+
 /// Internal row type for mapping `sqlx::FromRow` results.
 #[derive(sqlx::FromRow)]
-struct StoredEventRow {
+pub(crate) struct StoredEventRow {
     event_id: Uuid,
     aggregate_id: Uuid,
     event_type: String,
@@ -23,6 +35,7 @@ struct StoredEventRow {
     correlation_id: Uuid,
     causation_id: Uuid,
     occurred_at: DateTime<Utc>,
+    schema_version: i32,
 }
 
 impl From<StoredEventRow> for StoredEvent {
@@ -36,6 +49,24 @@ impl From<StoredEventRow> for StoredEvent {
             correlation_id: row.correlation_id,
             causation_id: row.causation_id,
             occurred_at: row.occurred_at,
+            schema_version: row.schema_version,
+        }
+    }
+}
+
+/// Internal row type for global log reads.
+#[derive(sqlx::FromRow)]
+pub(crate) struct PositionedEventRow {
+    global_position: i64,
+    #[sqlx(flatten)]
+    event: StoredEventRow,
+}
+
+impl From<PositionedEventRow> for PositionedEvent {
+    fn from(row: PositionedEventRow) -> Self {
+        Self {
+            position: row.global_position,
+            event: StoredEvent::from(row.event),
         }
     }
 }
@@ -98,14 +129,123 @@ async fn map_sqlx_error(
 #[derive(Debug, Clone)]
 pub struct PgEventRepository {
     pool: PgPool,
+    snapshots: Option<PgSnapshotStore>,
 }
 
 impl PgEventRepository {
-    /// Creates a new `PgEventRepository`.
+    /// Creates a new `PgEventRepository` without snapshot support.
     #[must_use]
     pub fn new(pool: PgPool) -> Self {
-        Self { pool }
+        Self {
+            pool,
+            snapshots: None,
+        }
+    }
+
+    /// Creates a new `PgEventRepository` that stores aggregate snapshots in
+    /// the same database according to `policy`.
+    #[must_use]
+    pub fn with_snapshots(pool: PgPool, policy: SnapshotPolicy) -> Self {
+        let snapshots = PgSnapshotStore::new(pool.clone(), policy);
+        Self {
+            pool,
+            snapshots: Some(snapshots),
+        }
+    }
+}
+
+impl PgEventRepository {
+    /// Opens an append transaction holding the global log lock.
+    async fn begin_append(&self) -> Result<Transaction<'static, Postgres>, DomainError> {
+        let mut tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| DomainError::Infrastructure(e.to_string()))?;
+
+        // Serialize appends so global positions become visible in the order
+        // they were assigned; otherwise a reader tailing the global log could
+        // see position n + 1 before n commits and skip n forever.
+        sqlx::query("SELECT pg_advisory_xact_lock($1)")
+            .bind(GLOBAL_LOG_LOCK_KEY)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| DomainError::Infrastructure(e.to_string()))?;
+
+        Ok(tx)
+    }
+}
+
+/// Checks the expected version of one stream and inserts its events inside
+/// an open append transaction.
+async fn append_stream(
+    tx: &mut Transaction<'static, Postgres>,
+    pool: &PgPool,
+    aggregate_id: Uuid,
+    expected_version: i64,
+    events: &[StoredEvent],
+) -> Result<(), DomainError> {
+    // Proactive optimistic concurrency check: verify expected_version
+    // matches the current max sequence_number within the transaction.
+    let row: (Option<i64>,) =
+        sqlx::query_as("SELECT MAX(sequence_number) FROM domain_events WHERE aggregate_id = $1")
+            .bind(aggregate_id)
+            .fetch_one(&mut **tx)
+            .await
+            .map_err(|e| DomainError::Infrastructure(e.to_string()))?;
+    let actual_version = row.0.unwrap_or(0);
+
+    if actual_version != expected_version {
+        return Err(DomainError::ConcurrencyConflict {
+            aggregate_id,
+            expected: expected_version,
+            actual: actual_version,
+        });
+    }
+
+    let event_ids: Vec<Uuid> = events.iter().map(|e| e.event_id).collect();
+    let aggregate_ids: Vec<Uuid> = events.iter().map(|e| e.aggregate_id).collect();
+    let event_types: Vec<&str> = events.iter().map(|e| e.event_type.as_str()).collect();
+    let payloads: Vec<&serde_json::Value> = events.iter().map(|e| &e.payload).collect();
+    let sequence_numbers: Vec<i64> = events.iter().map(|e| e.sequence_number).collect();
+    let correlation_ids: Vec<Uuid> = events.iter().map(|e| e.correlation_id).collect();
+    let causation_ids: Vec<Uuid> = events.iter().map(|e| e.causation_id).collect();
+    let occurred_ats: Vec<DateTime<Utc>> = events.iter().map(|e| e.occurred_at).collect();
+    let schema_versions: Vec<i32> = events.iter().map(|e| e.schema_version).collect();
+
+    let result = sqlx::query(
+        "INSERT INTO domain_events \
+            (event_id, aggregate_id, event_type, payload, \
+             sequence_number, correlation_id, causation_id, occurred_at, \
+             schema_version) \
+         SELECT * FROM UNNEST($1, $2, $3, $4, $5, $6, $7, $8, $9)",
+    )
+    .bind(&event_ids)
+    .bind(&aggregate_ids)
+    .bind(&event_types)
+    .bind(&payloads)
+    .bind(&sequence_numbers)
+    .bind(&correlation_ids)
+    .bind(&causation_ids)
+    .bind(&occurred_ats)
+    .bind(&schema_versions)
+    .execute(&mut **tx)
+    .await;
+
+    if let Err(err) = result {
+        return Err(map_sqlx_error(err, pool, aggregate_id, expected_version).await);
     }
+
+    // Wake live subscribers. Postgres delivers the notification only if the
+    // transaction commits.
+    sqlx::query("SELECT pg_notify($1, $2)")
+        .bind(EVENT_NOTIFY_CHANNEL)
+        .bind(aggregate_id.to_string())
+        .execute(&mut **tx)
+        .await
+        .map_err(|e| DomainError::Infrastructure(e.to_string()))?;
+
+    Ok(())
 }
 
 #[async_trait]
@@ -114,7 +254,8 @@ impl EventRepository for PgEventRepository {
     async fn load_events(&self, aggregate_id: Uuid) -> Result<Vec<StoredEvent>, DomainError> {
         let rows: Vec<StoredEventRow> = sqlx::query_as(
             "SELECT event_id, aggregate_id, event_type, payload, \
-                    sequence_number, correlation_id, causation_id, occurred_at \
+                    sequence_number, correlation_id, causation_id, occurred_at, \
+                    schema_version \
              FROM domain_events \
              WHERE aggregate_id = $1 \
              ORDER BY sequence_number ASC",
@@ -129,6 +270,31 @@ impl EventRepository for PgEventRepository {
         Ok(rows.into_iter().map(StoredEvent::from).collect())
     }
 
+    #[instrument(skip(self), fields(%aggregate_id, %after_sequence))]
+    async fn load_events_after(
+        &self,
+        aggregate_id: Uuid,
+        after_sequence: i64,
+    ) -> Result<Vec<StoredEvent>, DomainError> {
+        let rows: Vec<StoredEventRow> = sqlx::query_as(
+            "SELECT event_id, aggregate_id, event_type, payload, \
+                    sequence_number, correlation_id, causation_id, occurred_at, \
+                    schema_version \
+             FROM domain_events \
+             WHERE aggregate_id = $1 AND sequence_number > $2 \
+             ORDER BY sequence_number ASC",
+        )
+        .bind(aggregate_id)
+        .bind(after_sequence)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::Infrastructure(e.to_string()))?;
+
+        debug!(event_count = rows.len(), "loaded tail events for aggregate");
+
+        Ok(rows.into_iter().map(StoredEvent::from).collect())
+    }
+
     #[instrument(skip(self, events), fields(%aggregate_id, %expected_version, event_count = events.len()))]
     async fn append_events(
         &self,
@@ -140,66 +306,42 @@ impl EventRepository for PgEventRepository {
             return Ok(());
         }
 
-        let mut tx = self
-            .pool
-            .begin()
+        let mut tx = self.begin_append().await?;
+        append_stream(&mut tx, &self.pool, aggregate_id, expected_version, events).await?;
+        tx.commit()
             .await
             .map_err(|e| DomainError::Infrastructure(e.to_string()))?;
 
-        // Proactive optimistic concurrency check: verify expected_version
-        // matches the current max sequence_number within the transaction.
-        let row: (Option<i64>,) = sqlx::query_as(
-            "SELECT MAX(sequence_number) FROM domain_events WHERE aggregate_id = $1",
-        )
-        .bind(aggregate_id)
-        .fetch_one(&mut *tx)
-        .await
-        .map_err(|e| DomainError::Infrastructure(e.to_string()))?;
-        let actual_version = row.0.unwrap_or(0);
-
-        if actual_version != expected_version {
-            return Err(DomainError::ConcurrencyConflict {
-                aggregate_id,
-                expected: expected_version,
-                actual: actual_version,
-            });
-        }
+        debug!("committed events for aggregate");
 
-        let event_ids: Vec<Uuid> = events.iter().map(|e| e.event_id).collect();
-        let aggregate_ids: Vec<Uuid> = events.iter().map(|e| e.aggregate_id).collect();
-        let event_types: Vec<&str> = events.iter().map(|e| e.event_type.as_str()).collect();
-        let payloads: Vec<&serde_json::Value> = events.iter().map(|e| &e.payload).collect();
-        let sequence_numbers: Vec<i64> = events.iter().map(|e| e.sequence_number).collect();
-        let correlation_ids: Vec<Uuid> = events.iter().map(|e| e.correlation_id).collect();
-        let causation_ids: Vec<Uuid> = events.iter().map(|e| e.causation_id).collect();
-        let occurred_ats: Vec<DateTime<Utc>> = events.iter().map(|e| e.occurred_at).collect();
-
-        let result = sqlx::query(
-            "INSERT INTO domain_events \
-                (event_id, aggregate_id, event_type, payload, \
-                 sequence_number, correlation_id, causation_id, occurred_at) \
-             SELECT * FROM UNNEST($1, $2, $3, $4, $5, $6, $7, $8)",
-        )
-        .bind(&event_ids)
-        .bind(&aggregate_ids)
-        .bind(&event_types)
-        .bind(&payloads)
-        .bind(&sequence_numbers)
-        .bind(&correlation_ids)
-        .bind(&causation_ids)
-        .bind(&occurred_ats)
-        .execute(&mut *tx)
-        .await;
-
-        if let Err(err) = result {
-            return Err(map_sqlx_error(err, &self.pool, aggregate_id, expected_version).await);
+        Ok(())
+    }
+
+    #[instrument(skip(self, appends), fields(stream_count = appends.len()))]
+    async fn append_many(&self, appends: &[StreamAppend]) -> Result<(), DomainError> {
+        if appends.iter().all(|append| append.events.is_empty()) {
+            return Ok(());
         }
 
+        // Every stream is checked and inserted inside one transaction; an
+        // early return drops `tx`, which rolls back the streams already
+        // inserted.
+        let mut tx = self.begin_append().await?;
+        for append in appends.iter().filter(|append| !append.events.is_empty()) {
+            append_stream(
+                &mut tx,
+                &self.pool,
+                append.aggregate_id,
+                append.expected_version,
+                &append.events,
+            )
+            .await?;
+        }
         tx.commit()
             .await
             .map_err(|e| DomainError::Infrastructure(e.to_string()))?;
 
-        debug!("committed events for aggregate");
+        debug!("committed events for all streams");
 
         Ok(())
     }
@@ -232,4 +374,99 @@ impl EventRepository for PgEventRepository {
 
         Ok(ids)
     }
+
+    #[instrument(skip(self), fields(%aggregate_id))]
+    async fn load_positioned_events(
+        &self,
+        aggregate_id: Uuid,
+    ) -> Result<Vec<PositionedEvent>, DomainError> {
+        let rows: Vec<PositionedEventRow> = sqlx::query_as(
+            "SELECT global_position, event_id, aggregate_id, event_type, payload, \
+                    sequence_number, correlation_id, causation_id, occurred_at, \
+                    schema_version \
+             FROM domain_events \
+             WHERE aggregate_id = $1 \
+             ORDER BY sequence_number ASC",
+        )
+        .bind(aggregate_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::Infrastructure(e.to_string()))?;
+
+        debug!(
+            event_count = rows.len(),
+            "loaded positioned events for aggregate"
+        );
+
+        Ok(rows.into_iter().map(PositionedEvent::from).collect())
+    }
+
+    #[instrument(skip(self), fields(%from_position, %limit))]
+    async fn read_all_from(
+        &self,
+        from_position: i64,
+        limit: i64,
+    ) -> Result<Vec<PositionedEvent>, DomainError> {
+        let rows: Vec<PositionedEventRow> = sqlx::query_as(
+            "SELECT global_position, event_id, aggregate_id, event_type, payload, \
+                    sequence_number, correlation_id, causation_id, occurred_at, \
+                    schema_version \
+             FROM domain_events \
+             WHERE global_position >= $1 \
+             ORDER BY global_position ASC \
+             LIMIT $2",
+        )
+        .bind(from_position)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::Infrastructure(e.to_string()))?;
+
+        debug!(event_count = rows.len(), "read events from global log");
+
+        Ok(rows.into_iter().map(PositionedEvent::from).collect())
+    }
+
+    #[instrument(skip(self, event_types), fields(type_count = event_types.len(), %from_position, %limit))]
+    async fn read_by_types_from(
+        &self,
+        event_types: &[&str],
+        from_position: i64,
+        limit: i64,
+    ) -> Result<Vec<PositionedEvent>, DomainError> {
+        if event_types.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let event_type_strings: Vec<String> = event_types.iter().map(|s| (*s).to_owned()).collect();
+
+        let rows: Vec<PositionedEventRow> = sqlx::query_as(
+            "SELECT global_position, event_id, aggregate_id, event_type, payload, \
+                    sequence_number, correlation_id, causation_id, occurred_at, \
+                    schema_version \
+             FROM domain_events \
+             WHERE global_position >= $1 AND event_type = ANY($2) \
+             ORDER BY global_position ASC \
+             LIMIT $3",
+        )
+        .bind(from_position)
+        .bind(&event_type_strings)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::Infrastructure(e.to_string()))?;
+
+        debug!(
+            event_count = rows.len(),
+            "read events by type from global log"
+        );
+
+        Ok(rows.into_iter().map(PositionedEvent::from).collect())
+    }
+
+    fn snapshot_store(&self) -> Option<&dyn SnapshotStore> {
+        self.snapshots
+            .as_ref()
+            .map(|store| store as &dyn SnapshotStore)
+    }
 }
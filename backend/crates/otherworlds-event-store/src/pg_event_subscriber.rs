@@ -0,0 +1,158 @@
+//! Event Store — live event subscriptions over `PostgreSQL` `LISTEN`/`NOTIFY`.
+//!
+//! A subscription listens on `EVENT_NOTIFY_CHANNEL` and treats each
+//! notification as a wake-up: it reads every matching event after its cursor
+//! from `domain_events` and yields them in global log order. Notifications
+//! are never trusted to carry events, so a dropped notification or a
+//! reconnect only delays delivery until the next wake-up or fallback poll.
+
+use std::collections::VecDeque;
+use std::time::Duration;
+
+use futures::stream::{self, BoxStream, StreamExt};
+use sqlx::PgPool;
+use sqlx::postgres::PgListener;
+use tracing::{debug, instrument, warn};
+
+use otherworlds_core::error::DomainError;
+use otherworlds_core::repository::PositionedEvent;
+use otherworlds_core::subscription::SubscriptionFilter;
+
+use crate::pg_event_repository::{EVENT_NOTIFY_CHANNEL, PositionedEventRow};
+
+/// Maximum number of events read from the database per round trip.
+const BATCH_SIZE: i64 = 500;
+
+/// How long a caught-up subscription waits for a notification before
+/// re-reading the log anyway.
+const FALLBACK_POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Stream of events delivered to a subscriber, in global log order.
+///
+/// The stream never ends on its own. It yields an error and terminates if the
+/// database cannot be read.
+pub type EventStream = BoxStream<'static, Result<PositionedEvent, DomainError>>;
+
+/// Opens live subscriptions to the `PostgreSQL` event log.
+#[derive(Debug, Clone)]
+pub struct PgEventSubscriber {
+    pool: PgPool,
+}
+
+impl PgEventSubscriber {
+    /// Creates a new `PgEventSubscriber` backed by the given connection pool.
+    #[must_use]
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// Subscribes to events matching `filter` whose global position is
+    /// greater than `after_position`.
+    ///
+    /// Pass 0 to start from the beginning of the log, or the position of the
+    /// last event a previous subscription delivered to resume without gaps
+    /// or duplicates. Existing events are delivered first, followed by new
+    /// events as they are committed.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::Infrastructure` if the listener connection
+    /// cannot be established.
+    #[instrument(skip(self))]
+    pub async fn subscribe(
+        &self,
+        filter: SubscriptionFilter,
+        after_position: i64,
+    ) -> Result<EventStream, DomainError> {
+        // Listen before the first read so that nothing committed between the
+        // read and the first wait can be missed.
+        let mut listener = PgListener::connect_with(&self.pool)
+            .await
+            .map_err(|e| DomainError::Infrastructure(e.to_string()))?;
+        listener
+            .listen(EVENT_NOTIFY_CHANNEL)
+            .await
+            .map_err(|e| DomainError::Infrastructure(e.to_string()))?;
+
+        let state = Subscription {
+            pool: self.pool.clone(),
+            listener,
+            filter,
+            cursor: after_position,
+            buffer: VecDeque::new(),
+        };
+
+        Ok(stream::try_unfold(state, Subscription::next).boxed())
+    }
+}
+
+/// State carried between items of an `EventStream`.
+struct Subscription {
+    pool: PgPool,
+    listener: PgListener,
+    filter: SubscriptionFilter,
+    cursor: i64,
+    buffer: VecDeque<PositionedEvent>,
+}
+
+impl Subscription {
+    async fn next(mut self) -> Result<Option<(PositionedEvent, Self)>, DomainError> {
+        loop {
+            if let Some(event) = self.buffer.pop_front() {
+                self.cursor = event.position;
+                return Ok(Some((event, self)));
+            }
+
+            let batch = self.read_batch().await?;
+            if !batch.is_empty() {
+                debug!(
+                    event_count = batch.len(),
+                    "subscription gap-filled from log"
+                );
+                self.buffer.extend(batch);
+                continue;
+            }
+
+            self.wait_for_notification().await;
+        }
+    }
+
+    /// Reads the next batch of matching events after the cursor.
+    async fn read_batch(&self) -> Result<Vec<PositionedEvent>, DomainError> {
+        let rows: Vec<PositionedEventRow> = sqlx::query_as(
+            "SELECT global_position, event_id, aggregate_id, event_type, payload, \
+                    sequence_number, correlation_id, causation_id, occurred_at, \
+                    schema_version \
+             FROM domain_events \
+             WHERE global_position > $1 \
+               AND ($2::uuid IS NULL OR aggregate_id = $2) \
+               AND ($3::text IS NULL OR starts_with(event_type, $3)) \
+               AND ($4::uuid IS NULL OR correlation_id = $4) \
+             ORDER BY global_position ASC \
+             LIMIT $5",
+        )
+        .bind(self.cursor)
+        .bind(self.filter.aggregate_id())
+        .bind(self.filter.event_type_prefix())
+        .bind(self.filter.correlation_id())
+        .bind(BATCH_SIZE)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::Infrastructure(e.to_string()))?;
+
+        Ok(rows.into_iter().map(PositionedEvent::from).collect())
+    }
+
+    /// Waits until another append commits or the fallback interval passes.
+    async fn wait_for_notification(&mut self) {
+        match tokio::time::timeout(FALLBACK_POLL_INTERVAL, self.listener.recv()).await {
+            Ok(Ok(_)) | Err(_) => {}
+            Ok(Err(err)) => {
+                // The listener reconnects on the next `recv`; back off so a
+                // database outage does not turn into a busy loop.
+                warn!("event subscription listener failed: {err}");
+                tokio::time::sleep(FALLBACK_POLL_INTERVAL).await;
+            }
+        }
+    }
+}
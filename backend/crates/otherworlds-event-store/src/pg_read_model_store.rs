@@ -0,0 +1,219 @@
+//! Event Store — `PostgreSQL` `ReadModelStore` implementation.
+
+use async_trait::async_trait;
+use sqlx::PgPool;
+use tracing::{debug, instrument};
+use uuid::Uuid;
+
+use otherworlds_core::error::DomainError;
+use otherworlds_core::projection::ReadModelStore;
+
+/// `PostgreSQL`-backed read-model store.
+///
+/// Each collection is a table named after it with `id`, `document`, and
+/// `updated_at` columns, created by a migration. Projection checkpoints and
+/// the versions they were built under live in `projection_checkpoints`.
+#[derive(Debug, Clone)]
+pub struct PgReadModelStore {
+    pool: PgPool,
+}
+
+impl PgReadModelStore {
+    /// Creates a new `PgReadModelStore` backed by the given connection pool.
+    #[must_use]
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+/// Returns `collection` if it is safe to splice into SQL as a table name.
+fn table_name(collection: &str) -> Result<&str, DomainError> {
+    let valid = collection
+        .chars()
+        .next()
+        .is_some_and(|c| c.is_ascii_lowercase())
+        && collection
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_');
+    if valid {
+        Ok(collection)
+    } else {
+        Err(DomainError::Infrastructure(format!(
+            "invalid read-model collection name: {collection:?}"
+        )))
+    }
+}
+
+#[async_trait]
+impl ReadModelStore for PgReadModelStore {
+    #[instrument(skip(self), fields(%id))]
+    async fn get(
+        &self,
+        collection: &str,
+        id: Uuid,
+    ) -> Result<Option<serde_json::Value>, DomainError> {
+        let table = table_name(collection)?;
+        let row: Option<(serde_json::Value,)> =
+            sqlx::query_as(&format!("SELECT document FROM {table} WHERE id = $1"))
+                .bind(id)
+                .fetch_optional(&self.pool)
+                .await
+                .map_err(|e| DomainError::Infrastructure(e.to_string()))?;
+
+        Ok(row.map(|(document,)| document))
+    }
+
+    #[instrument(skip(self))]
+    async fn list(&self, collection: &str) -> Result<Vec<serde_json::Value>, DomainError> {
+        let table = table_name(collection)?;
+        let rows: Vec<(serde_json::Value,)> =
+            sqlx::query_as(&format!("SELECT document FROM {table} ORDER BY id"))
+                .fetch_all(&self.pool)
+                .await
+                .map_err(|e| DomainError::Infrastructure(e.to_string()))?;
+
+        Ok(rows.into_iter().map(|(document,)| document).collect())
+    }
+
+    #[instrument(skip(self, document), fields(%id))]
+    async fn upsert(
+        &self,
+        collection: &str,
+        id: Uuid,
+        document: &serde_json::Value,
+    ) -> Result<(), DomainError> {
+        let table = table_name(collection)?;
+        sqlx::query(&format!(
+            "INSERT INTO {table} (id, document, updated_at) \
+             VALUES ($1, $2, NOW()) \
+             ON CONFLICT (id) DO UPDATE \
+             SET document = EXCLUDED.document, \
+                 updated_at = EXCLUDED.updated_at"
+        ))
+        .bind(id)
+        .bind(document)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::Infrastructure(e.to_string()))?;
+
+        Ok(())
+    }
+
+    #[instrument(skip(self, document), fields(%id))]
+    async fn upsert_if_newer(
+        &self,
+        collection: &str,
+        id: Uuid,
+        document: &serde_json::Value,
+        version: i64,
+    ) -> Result<(), DomainError> {
+        let table = table_name(collection)?;
+        sqlx::query(&format!(
+            "INSERT INTO {table} (id, document, updated_at) \
+             VALUES ($1, $2, NOW()) \
+             ON CONFLICT (id) DO UPDATE \
+             SET document = EXCLUDED.document, \
+                 updated_at = EXCLUDED.updated_at \
+             WHERE jsonb_typeof({table}.document -> 'version') IS DISTINCT FROM 'number' \
+                OR ({table}.document ->> 'version')::BIGINT <= $3"
+        ))
+        .bind(id)
+        .bind(document)
+        .bind(version)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::Infrastructure(e.to_string()))?;
+
+        Ok(())
+    }
+
+    #[instrument(skip(self), fields(%id))]
+    async fn delete(&self, collection: &str, id: Uuid) -> Result<(), DomainError> {
+        let table = table_name(collection)?;
+        sqlx::query(&format!("DELETE FROM {table} WHERE id = $1"))
+            .bind(id)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| DomainError::Infrastructure(e.to_string()))?;
+
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    async fn clear(&self, collection: &str) -> Result<(), DomainError> {
+        let table = table_name(collection)?;
+        sqlx::query(&format!("DELETE FROM {table}"))
+            .execute(&self.pool)
+            .await
+            .map_err(|e| DomainError::Infrastructure(e.to_string()))?;
+
+        debug!("cleared read-model collection");
+
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    async fn load_checkpoint(&self, projection: &str) -> Result<i64, DomainError> {
+        let row: Option<(i64,)> = sqlx::query_as(
+            "SELECT position FROM projection_checkpoints WHERE projection_name = $1",
+        )
+        .bind(projection)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DomainError::Infrastructure(e.to_string()))?;
+
+        Ok(row.map_or(0, |(position,)| position))
+    }
+
+    #[instrument(skip(self))]
+    async fn save_checkpoint(&self, projection: &str, position: i64) -> Result<(), DomainError> {
+        sqlx::query(
+            "INSERT INTO projection_checkpoints (projection_name, position, updated_at) \
+             VALUES ($1, $2, NOW()) \
+             ON CONFLICT (projection_name) DO UPDATE \
+             SET position = EXCLUDED.position, \
+                 updated_at = EXCLUDED.updated_at",
+        )
+        .bind(projection)
+        .bind(position)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::Infrastructure(e.to_string()))?;
+
+        Ok(())
+    }
+
+    #[instrument(skip(self))]
+    async fn load_projection_version(&self, projection: &str) -> Result<Option<i32>, DomainError> {
+        let row: Option<(i32,)> =
+            sqlx::query_as("SELECT version FROM projection_checkpoints WHERE projection_name = $1")
+                .bind(projection)
+                .fetch_optional(&self.pool)
+                .await
+                .map_err(|e| DomainError::Infrastructure(e.to_string()))?;
+
+        Ok(row.map(|(version,)| version))
+    }
+
+    #[instrument(skip(self))]
+    async fn save_projection_version(
+        &self,
+        projection: &str,
+        version: i32,
+    ) -> Result<(), DomainError> {
+        sqlx::query(
+            "INSERT INTO projection_checkpoints (projection_name, position, version, updated_at) \
+             VALUES ($1, 0, $2, NOW()) \
+             ON CONFLICT (projection_name) DO UPDATE \
+             SET version = EXCLUDED.version, \
+                 updated_at = EXCLUDED.updated_at",
+        )
+        .bind(projection)
+        .bind(version)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::Infrastructure(e.to_string()))?;
+
+        Ok(())
+    }
+}
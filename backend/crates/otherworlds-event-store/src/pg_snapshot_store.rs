@@ -0,0 +1,102 @@
+//! Event Store — `PostgreSQL` `SnapshotStore` implementation.
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use tracing::{debug, instrument};
+use uuid::Uuid;
+
+use otherworlds_core::error::DomainError;
+use otherworlds_core::repository::{AggregateSnapshot, SnapshotPolicy, SnapshotStore};
+
+/// Internal row type for mapping `sqlx::FromRow` results.
+#[derive(sqlx::FromRow)]
+struct SnapshotRow {
+    aggregate_id: Uuid,
+    aggregate_type: String,
+    version: i64,
+    state: serde_json::Value,
+    taken_at: DateTime<Utc>,
+}
+
+impl From<SnapshotRow> for AggregateSnapshot {
+    fn from(row: SnapshotRow) -> Self {
+        Self {
+            aggregate_id: row.aggregate_id,
+            aggregate_type: row.aggregate_type,
+            version: row.version,
+            state: row.state,
+            taken_at: row.taken_at,
+        }
+    }
+}
+
+/// `PostgreSQL`-backed snapshot store.
+///
+/// Keeps only the latest snapshot per aggregate; older snapshots are
+/// replaced, and a snapshot never overwrites a newer one.
+#[derive(Debug, Clone)]
+pub struct PgSnapshotStore {
+    pool: PgPool,
+    policy: SnapshotPolicy,
+}
+
+impl PgSnapshotStore {
+    /// Creates a new `PgSnapshotStore` with the given snapshot policy.
+    #[must_use]
+    pub fn new(pool: PgPool, policy: SnapshotPolicy) -> Self {
+        Self { pool, policy }
+    }
+}
+
+#[async_trait]
+impl SnapshotStore for PgSnapshotStore {
+    #[instrument(skip(self), fields(%aggregate_id))]
+    async fn load_snapshot(
+        &self,
+        aggregate_id: Uuid,
+    ) -> Result<Option<AggregateSnapshot>, DomainError> {
+        let row: Option<SnapshotRow> = sqlx::query_as(
+            "SELECT aggregate_id, aggregate_type, version, state, taken_at \
+             FROM aggregate_snapshots \
+             WHERE aggregate_id = $1",
+        )
+        .bind(aggregate_id)
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| DomainError::Infrastructure(e.to_string()))?;
+
+        Ok(row.map(AggregateSnapshot::from))
+    }
+
+    #[instrument(skip(self, snapshot), fields(aggregate_id = %snapshot.aggregate_id, version = snapshot.version))]
+    async fn save_snapshot(&self, snapshot: &AggregateSnapshot) -> Result<(), DomainError> {
+        sqlx::query(
+            "INSERT INTO aggregate_snapshots \
+                (aggregate_id, aggregate_type, version, state, taken_at) \
+             VALUES ($1, $2, $3, $4, $5) \
+             ON CONFLICT (aggregate_id) DO UPDATE \
+             SET aggregate_type = EXCLUDED.aggregate_type, \
+                 version = EXCLUDED.version, \
+                 state = EXCLUDED.state, \
+                 taken_at = EXCLUDED.taken_at \
+             WHERE aggregate_snapshots.version < EXCLUDED.version",
+        )
+        .bind(snapshot.aggregate_id)
+        .bind(&snapshot.aggregate_type)
+        .bind(snapshot.version)
+        .bind(&snapshot.state)
+        .bind(snapshot.taken_at)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| DomainError::Infrastructure(e.to_string()))?;
+
+        debug!("saved aggregate snapshot");
+
+        Ok(())
+    }
+
+    fn policy(&self) -> SnapshotPolicy {
+        self.policy
+    }
+}
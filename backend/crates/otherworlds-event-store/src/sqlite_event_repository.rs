@@ -0,0 +1,357 @@
+//! Event Store — `SQLite` `EventRepository` implementation.
+//!
+//! Intended for single-user builds that run without a database server. The
+//! schema lives in `backend/migrations-sqlite/` and is applied by
+//! `SqliteEventRepository::connect`.
+
+use std::str::FromStr;
+
+use async_trait::async_trait;
+use sqlx::sqlite::{SqliteConnectOptions, SqlitePool, SqlitePoolOptions};
+use sqlx::{Sqlite, Transaction};
+use tracing::{debug, instrument};
+use uuid::Uuid;
+
+use otherworlds_core::error::DomainError;
+use otherworlds_core::repository::{EventRepository, PositionedEvent, StoredEvent, StreamAppend};
+
+use crate::pg_event_repository::{PositionedEventRow, StoredEventRow};
+
+/// Maps a sqlx error from an append to the appropriate `DomainError`.
+///
+/// `SQLite` rolls back only the failing statement on a constraint error, so
+/// the current version can still be read inside the open transaction.
+async fn map_append_error(
+    err: sqlx::Error,
+    tx: &mut Transaction<'static, Sqlite>,
+    aggregate_id: Uuid,
+    expected_version: i64,
+) -> DomainError {
+    let is_unique_violation =
+        matches!(&err, sqlx::Error::Database(db_err) if db_err.is_unique_violation());
+    if !is_unique_violation {
+        return DomainError::Infrastructure(err.to_string());
+    }
+    match current_version(tx, aggregate_id).await {
+        Ok(actual) => DomainError::ConcurrencyConflict {
+            aggregate_id,
+            expected: expected_version,
+            actual,
+        },
+        Err(version_err) => DomainError::Infrastructure(format!(
+            "unique violation on aggregate {aggregate_id}, \
+             but failed to determine current version: {version_err}"
+        )),
+    }
+}
+
+/// Queries the current max sequence number for an aggregate.
+async fn current_version(
+    tx: &mut Transaction<'static, Sqlite>,
+    aggregate_id: Uuid,
+) -> Result<i64, DomainError> {
+    let row: (Option<i64>,) =
+        sqlx::query_as("SELECT MAX(sequence_number) FROM domain_events WHERE aggregate_id = $1")
+            .bind(aggregate_id)
+            .fetch_one(&mut **tx)
+            .await
+            .map_err(|e| DomainError::Infrastructure(e.to_string()))?;
+
+    Ok(row.0.unwrap_or(0))
+}
+
+/// Checks the expected version of one stream and inserts its events inside
+/// an open append transaction.
+async fn append_stream(
+    tx: &mut Transaction<'static, Sqlite>,
+    aggregate_id: Uuid,
+    expected_version: i64,
+    events: &[StoredEvent],
+) -> Result<(), DomainError> {
+    let actual_version = current_version(tx, aggregate_id).await?;
+    if actual_version != expected_version {
+        return Err(DomainError::ConcurrencyConflict {
+            aggregate_id,
+            expected: expected_version,
+            actual: actual_version,
+        });
+    }
+
+    for event in events {
+        let result = sqlx::query(
+            "INSERT INTO domain_events \
+                (event_id, aggregate_id, event_type, payload, \
+                 sequence_number, correlation_id, causation_id, occurred_at, \
+                 schema_version) \
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)",
+        )
+        .bind(event.event_id)
+        .bind(event.aggregate_id)
+        .bind(&event.event_type)
+        .bind(&event.payload)
+        .bind(event.sequence_number)
+        .bind(event.correlation_id)
+        .bind(event.causation_id)
+        .bind(event.occurred_at)
+        .bind(event.schema_version)
+        .execute(&mut **tx)
+        .await;
+
+        if let Err(err) = result {
+            return Err(map_append_error(err, tx, aggregate_id, expected_version).await);
+        }
+    }
+
+    Ok(())
+}
+
+/// `SQLite`-backed event repository.
+///
+/// Appends run in `BEGIN IMMEDIATE` transactions, which take the database
+/// write lock up front. Writers are therefore serialized, and global
+/// positions (an `AUTOINCREMENT` key) become visible in the order they were
+/// assigned, as ADR-0029 requires.
+#[derive(Debug, Clone)]
+pub struct SqliteEventRepository {
+    pool: SqlitePool,
+}
+
+impl SqliteEventRepository {
+    /// Creates a new `SqliteEventRepository` over an already migrated pool.
+    #[must_use]
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    /// Opens the database at `url` (e.g. `sqlite://otherworlds.db` or
+    /// `sqlite::memory:`), creating the file if missing, and applies the
+    /// `SQLite` migrations.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::Infrastructure` if the URL is invalid, the
+    /// database cannot be opened, or a migration fails.
+    pub async fn connect(url: &str) -> Result<Self, DomainError> {
+        let options = SqliteConnectOptions::from_str(url)
+            .map_err(|e| DomainError::Infrastructure(e.to_string()))?
+            .create_if_missing(true);
+        let pool = SqlitePoolOptions::new()
+            .connect_with(options)
+            .await
+            .map_err(|e| DomainError::Infrastructure(e.to_string()))?;
+
+        let repo = Self::new(pool);
+        repo.migrate().await?;
+        Ok(repo)
+    }
+
+    /// Applies any pending `SQLite` migrations.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::Infrastructure` if a migration fails.
+    pub async fn migrate(&self) -> Result<(), DomainError> {
+        sqlx::migrate!("../../migrations-sqlite")
+            .run(&self.pool)
+            .await
+            .map_err(|e| DomainError::Infrastructure(e.to_string()))
+    }
+
+    /// Opens an append transaction holding the database write lock.
+    async fn begin_append(&self) -> Result<Transaction<'static, Sqlite>, DomainError> {
+        self.pool
+            .begin_with("BEGIN IMMEDIATE")
+            .await
+            .map_err(|e| DomainError::Infrastructure(e.to_string()))
+    }
+}
+
+#[async_trait]
+impl EventRepository for SqliteEventRepository {
+    #[instrument(skip(self), fields(%aggregate_id))]
+    async fn load_events(&self, aggregate_id: Uuid) -> Result<Vec<StoredEvent>, DomainError> {
+        self.load_events_after(aggregate_id, 0).await
+    }
+
+    #[instrument(skip(self), fields(%aggregate_id, %after_sequence))]
+    async fn load_events_after(
+        &self,
+        aggregate_id: Uuid,
+        after_sequence: i64,
+    ) -> Result<Vec<StoredEvent>, DomainError> {
+        let rows: Vec<StoredEventRow> = sqlx::query_as(
+            "SELECT event_id, aggregate_id, event_type, payload, \
+                    sequence_number, correlation_id, causation_id, occurred_at, \
+                    schema_version \
+             FROM domain_events \
+             WHERE aggregate_id = $1 AND sequence_number > $2 \
+             ORDER BY sequence_number ASC",
+        )
+        .bind(aggregate_id)
+        .bind(after_sequence)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::Infrastructure(e.to_string()))?;
+
+        debug!(event_count = rows.len(), "loaded events for aggregate");
+
+        Ok(rows.into_iter().map(StoredEvent::from).collect())
+    }
+
+    #[instrument(skip(self, events), fields(%aggregate_id, %expected_version, event_count = events.len()))]
+    async fn append_events(
+        &self,
+        aggregate_id: Uuid,
+        expected_version: i64,
+        events: &[StoredEvent],
+    ) -> Result<(), DomainError> {
+        if events.is_empty() {
+            return Ok(());
+        }
+
+        let mut tx = self.begin_append().await?;
+        append_stream(&mut tx, aggregate_id, expected_version, events).await?;
+        tx.commit()
+            .await
+            .map_err(|e| DomainError::Infrastructure(e.to_string()))?;
+
+        debug!("committed events for aggregate");
+
+        Ok(())
+    }
+
+    #[instrument(skip(self, appends), fields(stream_count = appends.len()))]
+    async fn append_many(&self, appends: &[StreamAppend]) -> Result<(), DomainError> {
+        if appends.iter().all(|append| append.events.is_empty()) {
+            return Ok(());
+        }
+
+        // An early return drops `tx`, which rolls back every stream already
+        // inserted.
+        let mut tx = self.begin_append().await?;
+        for append in appends.iter().filter(|append| !append.events.is_empty()) {
+            append_stream(
+                &mut tx,
+                append.aggregate_id,
+                append.expected_version,
+                &append.events,
+            )
+            .await?;
+        }
+        tx.commit()
+            .await
+            .map_err(|e| DomainError::Infrastructure(e.to_string()))?;
+
+        debug!("committed events for all streams");
+
+        Ok(())
+    }
+
+    #[instrument(skip(self, event_types), fields(type_count = event_types.len()))]
+    async fn list_aggregate_ids(&self, event_types: &[&str]) -> Result<Vec<Uuid>, DomainError> {
+        if event_types.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let rows: Vec<(Uuid,)> = sqlx::query_as(
+            "SELECT DISTINCT aggregate_id \
+             FROM domain_events \
+             WHERE event_type IN (SELECT value FROM json_each($1)) \
+             ORDER BY aggregate_id",
+        )
+        .bind(serde_json::json!(event_types))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::Infrastructure(e.to_string()))?;
+
+        Ok(rows.into_iter().map(|(id,)| id).collect())
+    }
+
+    #[instrument(skip(self), fields(%aggregate_id))]
+    async fn load_positioned_events(
+        &self,
+        aggregate_id: Uuid,
+    ) -> Result<Vec<PositionedEvent>, DomainError> {
+        let rows: Vec<PositionedEventRow> = sqlx::query_as(
+            "SELECT global_position, event_id, aggregate_id, event_type, payload, \
+                    sequence_number, correlation_id, causation_id, occurred_at, \
+                    schema_version \
+             FROM domain_events \
+             WHERE aggregate_id = $1 \
+             ORDER BY sequence_number ASC",
+        )
+        .bind(aggregate_id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::Infrastructure(e.to_string()))?;
+
+        debug!(
+            event_count = rows.len(),
+            "loaded positioned events for aggregate"
+        );
+
+        Ok(rows.into_iter().map(PositionedEvent::from).collect())
+    }
+
+    #[instrument(skip(self), fields(%from_position, %limit))]
+    async fn read_all_from(
+        &self,
+        from_position: i64,
+        limit: i64,
+    ) -> Result<Vec<PositionedEvent>, DomainError> {
+        let rows: Vec<PositionedEventRow> = sqlx::query_as(
+            "SELECT global_position, event_id, aggregate_id, event_type, payload, \
+                    sequence_number, correlation_id, causation_id, occurred_at, \
+                    schema_version \
+             FROM domain_events \
+             WHERE global_position >= $1 \
+             ORDER BY global_position ASC \
+             LIMIT $2",
+        )
+        .bind(from_position)
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::Infrastructure(e.to_string()))?;
+
+        debug!(event_count = rows.len(), "read events from global log");
+
+        Ok(rows.into_iter().map(PositionedEvent::from).collect())
+    }
+
+    #[instrument(skip(self, event_types), fields(type_count = event_types.len(), %from_position, %limit))]
+    async fn read_by_types_from(
+        &self,
+        event_types: &[&str],
+        from_position: i64,
+        limit: i64,
+    ) -> Result<Vec<PositionedEvent>, DomainError> {
+        if event_types.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let rows: Vec<PositionedEventRow> = sqlx::query_as(
+            "SELECT global_position, event_id, aggregate_id, event_type, payload, \
+                    sequence_number, correlation_id, causation_id, occurred_at, \
+                    schema_version \
+             FROM domain_events \
+             WHERE global_position >= $1 \
+               AND event_type IN (SELECT value FROM json_each($2)) \
+             ORDER BY global_position ASC \
+             LIMIT $3",
+        )
+        .bind(from_position)
+        .bind(serde_json::json!(event_types))
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| DomainError::Infrastructure(e.to_string()))?;
+
+        debug!(
+            event_count = rows.len(),
+            "read events by type from global log"
+        );
+
+        Ok(rows.into_iter().map(PositionedEvent::from).collect())
+    }
+}
@@ -0,0 +1,707 @@
+//! Backend-agnostic conformance suite for `EventRepository` implementations.
+//!
+//! Each test takes a fresh, empty repository. A backend's test file defines a
+//! harness macro that builds one and passes it in, then expands
+//! `conformance_suite!` with that harness to run every test.
+
+use chrono::{DateTime, Utc};
+use otherworlds_core::error::DomainError;
+use otherworlds_core::repository::{EventRepository, StoredEvent, StreamAppend};
+use uuid::Uuid;
+
+/// Helper to build a `StoredEvent` with sensible defaults.
+fn make_stored_event(aggregate_id: Uuid, sequence_number: i64) -> StoredEvent {
+    make_stored_event_with_type(aggregate_id, sequence_number, "TestEvent")
+}
+
+/// Helper to build a `StoredEvent` with a custom event type.
+fn make_stored_event_with_type(
+    aggregate_id: Uuid,
+    sequence_number: i64,
+    event_type: &str,
+) -> StoredEvent {
+    StoredEvent {
+        event_id: Uuid::new_v4(),
+        aggregate_id,
+        event_type: event_type.to_string(),
+        payload: serde_json::json!({"key": "value"}),
+        sequence_number,
+        correlation_id: Uuid::new_v4(),
+        causation_id: Uuid::new_v4(),
+        // Truncate to microsecond precision to match PostgreSQL TIMESTAMPTZ.
+        occurred_at: DateTime::from_timestamp_micros(Utc::now().timestamp_micros()).unwrap(),
+        schema_version: 1,
+    }
+}
+
+// --- load_events ---
+
+pub async fn test_load_events_returns_empty_vec_for_nonexistent_aggregate(
+    repo: &dyn EventRepository,
+) {
+    let aggregate_id = Uuid::new_v4();
+
+    let events = repo.load_events(aggregate_id).await.unwrap();
+
+    assert!(events.is_empty());
+}
+
+// --- append_events + load_events round-trip ---
+
+pub async fn test_append_and_load_single_event(repo: &dyn EventRepository) {
+    let aggregate_id = Uuid::new_v4();
+    let event = make_stored_event(aggregate_id, 1);
+    let expected_event_id = event.event_id;
+    let expected_event_type = event.event_type.clone();
+    let expected_payload = event.payload.clone();
+    let expected_correlation_id = event.correlation_id;
+    let expected_causation_id = event.causation_id;
+    let expected_occurred_at = event.occurred_at;
+
+    repo.append_events(aggregate_id, 0, &[event]).await.unwrap();
+
+    let loaded = repo.load_events(aggregate_id).await.unwrap();
+    assert_eq!(loaded.len(), 1);
+
+    let e = &loaded[0];
+    assert_eq!(e.event_id, expected_event_id);
+    assert_eq!(e.aggregate_id, aggregate_id);
+    assert_eq!(e.event_type, expected_event_type);
+    assert_eq!(e.payload, expected_payload);
+    assert_eq!(e.sequence_number, 1);
+    assert_eq!(e.correlation_id, expected_correlation_id);
+    assert_eq!(e.causation_id, expected_causation_id);
+    assert_eq!(e.occurred_at, expected_occurred_at);
+    assert_eq!(e.schema_version, 1);
+}
+
+// --- schema version ---
+
+pub async fn test_schema_version_round_trips(repo: &dyn EventRepository) {
+    let aggregate_id = Uuid::new_v4();
+    let mut first = make_stored_event(aggregate_id, 1);
+    first.schema_version = 1;
+    let mut second = make_stored_event(aggregate_id, 2);
+    second.schema_version = 3;
+
+    repo.append_events(aggregate_id, 0, &[first, second])
+        .await
+        .unwrap();
+
+    let loaded = repo.load_events(aggregate_id).await.unwrap();
+    let versions: Vec<i32> = loaded.iter().map(|e| e.schema_version).collect();
+    assert_eq!(versions, vec![1, 3]);
+}
+
+// --- ordering ---
+
+pub async fn test_append_multiple_events_preserves_sequence_order(repo: &dyn EventRepository) {
+    let aggregate_id = Uuid::new_v4();
+    let events = vec![
+        make_stored_event(aggregate_id, 1),
+        make_stored_event(aggregate_id, 2),
+        make_stored_event(aggregate_id, 3),
+    ];
+
+    repo.append_events(aggregate_id, 0, &events).await.unwrap();
+
+    let loaded = repo.load_events(aggregate_id).await.unwrap();
+    assert_eq!(loaded.len(), 3);
+    assert_eq!(loaded[0].sequence_number, 1);
+    assert_eq!(loaded[1].sequence_number, 2);
+    assert_eq!(loaded[2].sequence_number, 3);
+}
+
+// --- aggregate isolation ---
+
+pub async fn test_aggregate_isolation(repo: &dyn EventRepository) {
+    let agg_a = Uuid::new_v4();
+    let agg_b = Uuid::new_v4();
+
+    repo.append_events(agg_a, 0, &[make_stored_event(agg_a, 1)])
+        .await
+        .unwrap();
+    repo.append_events(agg_b, 0, &[make_stored_event(agg_b, 1)])
+        .await
+        .unwrap();
+
+    let loaded_a = repo.load_events(agg_a).await.unwrap();
+    let loaded_b = repo.load_events(agg_b).await.unwrap();
+
+    assert_eq!(loaded_a.len(), 1);
+    assert_eq!(loaded_b.len(), 1);
+    assert_eq!(loaded_a[0].aggregate_id, agg_a);
+    assert_eq!(loaded_b[0].aggregate_id, agg_b);
+}
+
+// --- concurrency ---
+
+pub async fn test_concurrency_conflict_on_duplicate_sequence_number(repo: &dyn EventRepository) {
+    let aggregate_id = Uuid::new_v4();
+
+    // First append succeeds.
+    repo.append_events(aggregate_id, 0, &[make_stored_event(aggregate_id, 1)])
+        .await
+        .unwrap();
+
+    // Second append with same sequence_number should fail.
+    let result = repo
+        .append_events(aggregate_id, 0, &[make_stored_event(aggregate_id, 1)])
+        .await;
+
+    match result {
+        Err(DomainError::ConcurrencyConflict {
+            aggregate_id: conflict_agg_id,
+            expected,
+            actual,
+        }) => {
+            assert_eq!(conflict_agg_id, aggregate_id);
+            assert_eq!(expected, 0);
+            assert_eq!(actual, 1);
+        }
+        other => panic!("expected ConcurrencyConflict, got {other:?}"),
+    }
+}
+
+pub async fn test_stale_expected_version_with_non_overlapping_sequences(
+    repo: &dyn EventRepository,
+) {
+    let aggregate_id = Uuid::new_v4();
+
+    // Append events 1-2 with expected_version 0.
+    repo.append_events(
+        aggregate_id,
+        0,
+        &[
+            make_stored_event(aggregate_id, 1),
+            make_stored_event(aggregate_id, 2),
+        ],
+    )
+    .await
+    .unwrap();
+
+    // Attempt to append events 3-4 with stale expected_version 0 (actual is 2).
+    // Sequence numbers don't collide, but the version check must still reject.
+    let result = repo
+        .append_events(
+            aggregate_id,
+            0,
+            &[
+                make_stored_event(aggregate_id, 3),
+                make_stored_event(aggregate_id, 4),
+            ],
+        )
+        .await;
+
+    match result {
+        Err(DomainError::ConcurrencyConflict {
+            aggregate_id: conflict_agg_id,
+            expected,
+            actual,
+        }) => {
+            assert_eq!(conflict_agg_id, aggregate_id);
+            assert_eq!(expected, 0);
+            assert_eq!(actual, 2);
+        }
+        other => panic!("expected ConcurrencyConflict, got {other:?}"),
+    }
+}
+
+pub async fn test_sequential_appends_with_correct_expected_version(repo: &dyn EventRepository) {
+    let aggregate_id = Uuid::new_v4();
+
+    // First batch: events 1-2, expected version 0.
+    repo.append_events(
+        aggregate_id,
+        0,
+        &[
+            make_stored_event(aggregate_id, 1),
+            make_stored_event(aggregate_id, 2),
+        ],
+    )
+    .await
+    .unwrap();
+
+    // Second batch: events 3-4, expected version 2.
+    repo.append_events(
+        aggregate_id,
+        2,
+        &[
+            make_stored_event(aggregate_id, 3),
+            make_stored_event(aggregate_id, 4),
+        ],
+    )
+    .await
+    .unwrap();
+
+    let loaded = repo.load_events(aggregate_id).await.unwrap();
+    assert_eq!(loaded.len(), 4);
+    for (i, event) in loaded.iter().enumerate() {
+        assert_eq!(event.sequence_number, i64::try_from(i + 1).unwrap());
+    }
+}
+
+// --- edge cases ---
+
+pub async fn test_append_empty_events_is_noop(repo: &dyn EventRepository) {
+    let aggregate_id = Uuid::new_v4();
+
+    repo.append_events(aggregate_id, 0, &[]).await.unwrap();
+
+    let loaded = repo.load_events(aggregate_id).await.unwrap();
+    assert!(loaded.is_empty());
+}
+
+// --- payload serialization ---
+
+pub async fn test_complex_json_payload_round_trip(repo: &dyn EventRepository) {
+    let aggregate_id = Uuid::new_v4();
+    let complex_payload = serde_json::json!({
+        "nested": {"key": "value", "number": 42},
+        "array": [1, "two", null, true, false],
+        "null_field": null,
+        "boolean": true,
+        "empty_object": {},
+        "empty_array": []
+    });
+
+    let mut event = make_stored_event(aggregate_id, 1);
+    event.payload = complex_payload.clone();
+
+    repo.append_events(aggregate_id, 0, &[event]).await.unwrap();
+
+    let loaded = repo.load_events(aggregate_id).await.unwrap();
+    assert_eq!(loaded.len(), 1);
+    assert_eq!(loaded[0].payload, complex_payload);
+}
+
+// --- timestamp precision ---
+
+pub async fn test_timestamp_precision(repo: &dyn EventRepository) {
+    let aggregate_id = Uuid::new_v4();
+    let event = make_stored_event(aggregate_id, 1);
+    let original_timestamp = event.occurred_at;
+
+    repo.append_events(aggregate_id, 0, &[event]).await.unwrap();
+
+    let loaded = repo.load_events(aggregate_id).await.unwrap();
+    assert_eq!(loaded.len(), 1);
+
+    // PostgreSQL TIMESTAMPTZ has microsecond precision.
+    let original_micros = original_timestamp.timestamp_micros();
+    let loaded_micros = loaded[0].occurred_at.timestamp_micros();
+    assert_eq!(original_micros, loaded_micros);
+}
+
+// --- list_aggregate_ids ---
+
+pub async fn test_list_aggregate_ids_returns_empty_for_no_matching_events(
+    repo: &dyn EventRepository,
+) {
+    let ids = repo
+        .list_aggregate_ids(&["nonexistent.event_type"])
+        .await
+        .unwrap();
+
+    assert!(ids.is_empty());
+}
+
+pub async fn test_list_aggregate_ids_returns_matching_aggregates(repo: &dyn EventRepository) {
+    let agg_a = Uuid::new_v4();
+    let agg_b = Uuid::new_v4();
+
+    repo.append_events(
+        agg_a,
+        0,
+        &[make_stored_event_with_type(
+            agg_a,
+            1,
+            "narrative.beat_advanced",
+        )],
+    )
+    .await
+    .unwrap();
+    repo.append_events(
+        agg_b,
+        0,
+        &[make_stored_event_with_type(
+            agg_b,
+            1,
+            "rules.intent_declared",
+        )],
+    )
+    .await
+    .unwrap();
+
+    let ids = repo
+        .list_aggregate_ids(&["narrative.beat_advanced"])
+        .await
+        .unwrap();
+
+    assert_eq!(ids.len(), 1);
+    assert!(ids.contains(&agg_a));
+    assert!(!ids.contains(&agg_b));
+}
+
+pub async fn test_list_aggregate_ids_returns_distinct_ids(repo: &dyn EventRepository) {
+    let agg = Uuid::new_v4();
+
+    // Append two events of the same type for the same aggregate.
+    repo.append_events(
+        agg,
+        0,
+        &[
+            make_stored_event_with_type(agg, 1, "narrative.beat_advanced"),
+            make_stored_event_with_type(agg, 2, "narrative.beat_advanced"),
+        ],
+    )
+    .await
+    .unwrap();
+
+    let ids = repo
+        .list_aggregate_ids(&["narrative.beat_advanced"])
+        .await
+        .unwrap();
+
+    assert_eq!(ids.len(), 1);
+    assert_eq!(ids[0], agg);
+}
+
+pub async fn test_list_aggregate_ids_filters_across_contexts(repo: &dyn EventRepository) {
+    let narrative_agg = Uuid::new_v4();
+    let rules_agg = Uuid::new_v4();
+    let world_agg = Uuid::new_v4();
+
+    repo.append_events(
+        narrative_agg,
+        0,
+        &[make_stored_event_with_type(
+            narrative_agg,
+            1,
+            "narrative.beat_advanced",
+        )],
+    )
+    .await
+    .unwrap();
+    repo.append_events(
+        rules_agg,
+        0,
+        &[make_stored_event_with_type(
+            rules_agg,
+            1,
+            "rules.intent_declared",
+        )],
+    )
+    .await
+    .unwrap();
+    repo.append_events(
+        world_agg,
+        0,
+        &[make_stored_event_with_type(
+            world_agg,
+            1,
+            "world_state.flag_set",
+        )],
+    )
+    .await
+    .unwrap();
+
+    // Query for narrative and rules, should not include world_state.
+    let ids = repo
+        .list_aggregate_ids(&["narrative.beat_advanced", "rules.intent_declared"])
+        .await
+        .unwrap();
+
+    assert_eq!(ids.len(), 2);
+    assert!(ids.contains(&narrative_agg));
+    assert!(ids.contains(&rules_agg));
+    assert!(!ids.contains(&world_agg));
+}
+
+pub async fn test_list_aggregate_ids_returns_empty_for_empty_event_types(
+    repo: &dyn EventRepository,
+) {
+    let agg = Uuid::new_v4();
+
+    repo.append_events(agg, 0, &[make_stored_event(agg, 1)])
+        .await
+        .unwrap();
+
+    let ids = repo.list_aggregate_ids(&[]).await.unwrap();
+
+    assert!(ids.is_empty());
+}
+
+// --- global log ---
+
+pub async fn test_read_all_from_returns_events_across_aggregates_in_commit_order(
+    repo: &dyn EventRepository,
+) {
+    // Arrange
+    let first_agg = Uuid::new_v4();
+    let second_agg = Uuid::new_v4();
+    let first = make_stored_event(first_agg, 1);
+    let second = make_stored_event(second_agg, 1);
+    let third = make_stored_event(first_agg, 2);
+    let expected_ids = vec![first.event_id, second.event_id, third.event_id];
+
+    repo.append_events(first_agg, 0, &[first]).await.unwrap();
+    repo.append_events(second_agg, 0, &[second]).await.unwrap();
+    repo.append_events(first_agg, 1, &[third]).await.unwrap();
+
+    // Act
+    let events = repo.read_all_from(1, 100).await.unwrap();
+
+    // Assert
+    let ids: Vec<Uuid> = events.iter().map(|e| e.event.event_id).collect();
+    assert_eq!(ids, expected_ids);
+    assert!(events.windows(2).all(|w| w[0].position < w[1].position));
+}
+
+pub async fn test_read_all_from_resumes_after_position_and_honours_limit(
+    repo: &dyn EventRepository,
+) {
+    let agg = Uuid::new_v4();
+    let events: Vec<StoredEvent> = (1..=5).map(|seq| make_stored_event(agg, seq)).collect();
+    repo.append_events(agg, 0, &events).await.unwrap();
+
+    let first_page = repo.read_all_from(1, 2).await.unwrap();
+    let next_from = first_page.last().unwrap().position + 1;
+    let second_page = repo.read_all_from(next_from, 2).await.unwrap();
+
+    let first_sequences: Vec<i64> = first_page.iter().map(|e| e.event.sequence_number).collect();
+    let second_sequences: Vec<i64> = second_page
+        .iter()
+        .map(|e| e.event.sequence_number)
+        .collect();
+    assert_eq!(first_sequences, vec![1, 2]);
+    assert_eq!(second_sequences, vec![3, 4]);
+}
+
+pub async fn test_read_all_from_returns_empty_past_end_of_log(repo: &dyn EventRepository) {
+    let agg = Uuid::new_v4();
+    repo.append_events(agg, 0, &[make_stored_event(agg, 1)])
+        .await
+        .unwrap();
+    let last = repo.read_all_from(1, 10).await.unwrap()[0].position;
+
+    let events = repo.read_all_from(last + 1, 10).await.unwrap();
+
+    assert!(events.is_empty());
+}
+
+pub async fn test_load_positioned_events_matches_global_log(repo: &dyn EventRepository) {
+    // Arrange
+    let agg = Uuid::new_v4();
+    let other_agg = Uuid::new_v4();
+    repo.append_events(agg, 0, &[make_stored_event(agg, 1)])
+        .await
+        .unwrap();
+    repo.append_events(other_agg, 0, &[make_stored_event(other_agg, 1)])
+        .await
+        .unwrap();
+    repo.append_events(agg, 1, &[make_stored_event(agg, 2)])
+        .await
+        .unwrap();
+
+    // Act
+    let stream = repo.load_positioned_events(agg).await.unwrap();
+
+    // Assert — each event carries the position the global log gives it.
+    let sequences: Vec<i64> = stream.iter().map(|p| p.event.sequence_number).collect();
+    assert_eq!(sequences, vec![1, 2]);
+    for positioned in &stream {
+        let logged = repo.read_all_from(positioned.position, 1).await.unwrap();
+        assert_eq!(logged[0].event.event_id, positioned.event.event_id);
+    }
+    assert!(
+        repo.load_positioned_events(Uuid::new_v4())
+            .await
+            .unwrap()
+            .is_empty()
+    );
+}
+
+pub async fn test_read_by_types_from_filters_event_types(repo: &dyn EventRepository) {
+    // Arrange
+    let narrative_agg = Uuid::new_v4();
+    let world_agg = Uuid::new_v4();
+    repo.append_events(
+        narrative_agg,
+        0,
+        &[make_stored_event_with_type(
+            narrative_agg,
+            1,
+            "narrative.beat_advanced",
+        )],
+    )
+    .await
+    .unwrap();
+    repo.append_events(
+        world_agg,
+        0,
+        &[make_stored_event_with_type(
+            world_agg,
+            1,
+            "world_state.flag_set",
+        )],
+    )
+    .await
+    .unwrap();
+    repo.append_events(
+        narrative_agg,
+        1,
+        &[make_stored_event_with_type(
+            narrative_agg,
+            2,
+            "narrative.beat_advanced",
+        )],
+    )
+    .await
+    .unwrap();
+
+    // Act
+    let events = repo
+        .read_by_types_from(&["narrative.beat_advanced"], 1, 100)
+        .await
+        .unwrap();
+
+    // Assert
+    assert_eq!(events.len(), 2);
+    assert!(
+        events
+            .iter()
+            .all(|e| e.event.event_type == "narrative.beat_advanced")
+    );
+    assert!(events[0].position < events[1].position);
+}
+
+pub async fn test_read_by_types_from_returns_empty_for_empty_event_types(
+    repo: &dyn EventRepository,
+) {
+    let agg = Uuid::new_v4();
+    repo.append_events(agg, 0, &[make_stored_event(agg, 1)])
+        .await
+        .unwrap();
+
+    let events = repo.read_by_types_from(&[], 1, 100).await.unwrap();
+
+    assert!(events.is_empty());
+}
+
+// --- append_many ---
+
+pub async fn test_append_many_commits_every_stream(repo: &dyn EventRepository) {
+    let agg_a = Uuid::new_v4();
+    let agg_b = Uuid::new_v4();
+    repo.append_events(agg_b, 0, &[make_stored_event(agg_b, 1)])
+        .await
+        .unwrap();
+
+    repo.append_many(&[
+        StreamAppend {
+            aggregate_id: agg_a,
+            expected_version: 0,
+            events: vec![make_stored_event(agg_a, 1), make_stored_event(agg_a, 2)],
+        },
+        StreamAppend {
+            aggregate_id: agg_b,
+            expected_version: 1,
+            events: vec![make_stored_event(agg_b, 2)],
+        },
+    ])
+    .await
+    .unwrap();
+
+    assert_eq!(repo.load_events(agg_a).await.unwrap().len(), 2);
+    assert_eq!(repo.load_events(agg_b).await.unwrap().len(), 2);
+    let log = repo.read_all_from(1, 100).await.unwrap();
+    assert_eq!(log.len(), 4);
+}
+
+pub async fn test_append_many_rolls_back_all_streams_on_conflict(repo: &dyn EventRepository) {
+    let agg_a = Uuid::new_v4();
+    let agg_b = Uuid::new_v4();
+    repo.append_events(agg_b, 0, &[make_stored_event(agg_b, 1)])
+        .await
+        .unwrap();
+
+    // The second stream's expected version is stale, so the first stream
+    // must not be committed either.
+    let result = repo
+        .append_many(&[
+            StreamAppend {
+                aggregate_id: agg_a,
+                expected_version: 0,
+                events: vec![make_stored_event(agg_a, 1)],
+            },
+            StreamAppend {
+                aggregate_id: agg_b,
+                expected_version: 0,
+                events: vec![make_stored_event(agg_b, 1)],
+            },
+        ])
+        .await;
+
+    match result {
+        Err(DomainError::ConcurrencyConflict {
+            aggregate_id,
+            expected,
+            actual,
+        }) => {
+            assert_eq!(aggregate_id, agg_b);
+            assert_eq!(expected, 0);
+            assert_eq!(actual, 1);
+        }
+        other => panic!("expected ConcurrencyConflict, got {other:?}"),
+    }
+    assert!(repo.load_events(agg_a).await.unwrap().is_empty());
+    assert_eq!(repo.load_events(agg_b).await.unwrap().len(), 1);
+}
+
+pub async fn test_append_many_with_no_events_is_noop(repo: &dyn EventRepository) {
+    let agg = Uuid::new_v4();
+
+    repo.append_many(&[StreamAppend {
+        aggregate_id: agg,
+        expected_version: 0,
+        events: vec![],
+    }])
+    .await
+    .unwrap();
+
+    assert!(repo.read_all_from(1, 100).await.unwrap().is_empty());
+}
+
+/// Expands `$harness!(test_name);` for every conformance test.
+macro_rules! conformance_suite {
+    ($harness:ident) => {
+        $harness!(test_load_events_returns_empty_vec_for_nonexistent_aggregate);
+        $harness!(test_append_and_load_single_event);
+        $harness!(test_schema_version_round_trips);
+        $harness!(test_append_multiple_events_preserves_sequence_order);
+        $harness!(test_aggregate_isolation);
+        $harness!(test_concurrency_conflict_on_duplicate_sequence_number);
+        $harness!(test_stale_expected_version_with_non_overlapping_sequences);
+        $harness!(test_sequential_appends_with_correct_expected_version);
+        $harness!(test_append_empty_events_is_noop);
+        $harness!(test_complex_json_payload_round_trip);
+        $harness!(test_timestamp_precision);
+        $harness!(test_list_aggregate_ids_returns_empty_for_no_matching_events);
+        $harness!(test_list_aggregate_ids_returns_matching_aggregates);
+        $harness!(test_list_aggregate_ids_returns_distinct_ids);
+        $harness!(test_list_aggregate_ids_filters_across_contexts);
+        $harness!(test_list_aggregate_ids_returns_empty_for_empty_event_types);
+        $harness!(test_read_all_from_returns_events_across_aggregates_in_commit_order);
+        $harness!(test_read_all_from_resumes_after_position_and_honours_limit);
+        $harness!(test_read_all_from_returns_empty_past_end_of_log);
+        $harness!(test_load_positioned_events_matches_global_log);
+        $harness!(test_read_by_types_from_filters_event_types);
+        $harness!(test_read_by_types_from_returns_empty_for_empty_event_types);
+        $harness!(test_append_many_commits_every_stream);
+        $harness!(test_append_many_rolls_back_all_streams_on_conflict);
+        $harness!(test_append_many_with_no_events_is_noop);
+    };
+}
+
+pub(crate) use conformance_suite;
@@ -0,0 +1,141 @@
+//! Integration tests for `InMemoryEventRepository`.
+//!
+//! Runs the shared conformance suite plus checks for JSON Lines file
+//! persistence.
+
+mod conformance;
+
+use chrono::{DateTime, Utc};
+use otherworlds_core::error::DomainError;
+use otherworlds_core::repository::{EventRepository, StoredEvent};
+use otherworlds_event_store::memory_event_repository::InMemoryEventRepository;
+use uuid::Uuid;
+
+macro_rules! memory_test {
+    ($name:ident) => {
+        #[tokio::test]
+        async fn $name() {
+            conformance::$name(&InMemoryEventRepository::new()).await;
+        }
+    };
+}
+
+conformance::conformance_suite!(memory_test);
+
+/// Helper to build a `StoredEvent` with a custom event type.
+fn make_stored_event(aggregate_id: Uuid, sequence_number: i64, event_type: &str) -> StoredEvent {
+    StoredEvent {
+        event_id: Uuid::new_v4(),
+        aggregate_id,
+        event_type: event_type.to_string(),
+        payload: serde_json::json!({"key": "value"}),
+        sequence_number,
+        correlation_id: Uuid::new_v4(),
+        causation_id: Uuid::new_v4(),
+        occurred_at: DateTime::from_timestamp_micros(Utc::now().timestamp_micros()).unwrap(),
+        schema_version: 1,
+    }
+}
+
+/// Returns a path in the system temp directory that no other test uses.
+fn temp_event_file() -> std::path::PathBuf {
+    std::env::temp_dir().join(format!("otherworlds-events-{}.jsonl", Uuid::new_v4()))
+}
+
+// --- file persistence ---
+
+#[tokio::test]
+async fn test_events_survive_reopening_the_file() {
+    let path = temp_event_file();
+    let aggregate_id = Uuid::new_v4();
+    let event = make_stored_event(aggregate_id, 1, "TestEvent");
+    let expected = event.clone();
+    {
+        let repo = InMemoryEventRepository::open(&path).unwrap();
+        repo.append_events(aggregate_id, 0, &[event]).await.unwrap();
+    }
+
+    let reopened = InMemoryEventRepository::open(&path).unwrap();
+    let loaded = reopened.load_events(aggregate_id).await.unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(loaded.len(), 1);
+    assert_eq!(loaded[0].event_id, expected.event_id);
+    assert_eq!(loaded[0].payload, expected.payload);
+    assert_eq!(loaded[0].occurred_at, expected.occurred_at);
+    assert_eq!(reopened.read_all_from(1, 10).await.unwrap()[0].position, 1);
+}
+
+#[tokio::test]
+async fn test_open_rejects_malformed_file() {
+    let path = temp_event_file();
+    std::fs::write(&path, b"not json\n").unwrap();
+
+    let result = InMemoryEventRepository::open(&path);
+    std::fs::remove_file(&path).unwrap();
+
+    assert!(matches!(result, Err(DomainError::Infrastructure(_))));
+}
+
+#[tokio::test]
+async fn test_appends_add_one_line_per_event() {
+    let path = temp_event_file();
+    let aggregate_id = Uuid::new_v4();
+    let repo = InMemoryEventRepository::open(&path).unwrap();
+    repo.append_events(
+        aggregate_id,
+        0,
+        &[make_stored_event(aggregate_id, 1, "TestEvent")],
+    )
+    .await
+    .unwrap();
+    let first = std::fs::read_to_string(&path).unwrap();
+
+    repo.append_events(
+        aggregate_id,
+        1,
+        &[make_stored_event(aggregate_id, 2, "TestEvent")],
+    )
+    .await
+    .unwrap();
+    let second = std::fs::read_to_string(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(first.lines().count(), 1);
+    assert_eq!(second.lines().count(), 2);
+    assert!(second.starts_with(&first));
+}
+
+#[tokio::test]
+async fn test_open_drops_incomplete_last_line() {
+    let path = temp_event_file();
+    let aggregate_id = Uuid::new_v4();
+    {
+        let repo = InMemoryEventRepository::open(&path).unwrap();
+        repo.append_events(
+            aggregate_id,
+            0,
+            &[make_stored_event(aggregate_id, 1, "TestEvent")],
+        )
+        .await
+        .unwrap();
+    }
+    let mut bytes = std::fs::read(&path).unwrap();
+    bytes.extend_from_slice(b"{\"event_id\":");
+    std::fs::write(&path, bytes).unwrap();
+
+    let repo = InMemoryEventRepository::open(&path).unwrap();
+    repo.append_events(
+        aggregate_id,
+        1,
+        &[make_stored_event(aggregate_id, 2, "TestEvent")],
+    )
+    .await
+    .unwrap();
+    let reopened = InMemoryEventRepository::open(&path).unwrap();
+    let loaded = reopened.load_events(aggregate_id).await.unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(loaded.len(), 2);
+    assert_eq!(loaded[1].sequence_number, 2);
+}
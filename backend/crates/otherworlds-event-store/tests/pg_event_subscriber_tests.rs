@@ -0,0 +1,209 @@
+//! Integration tests for `PgEventSubscriber`.
+
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use futures::StreamExt;
+use otherworlds_core::repository::{EventRepository, PositionedEvent, StoredEvent};
+use otherworlds_core::subscription::SubscriptionFilter;
+use otherworlds_event_store::pg_event_repository::PgEventRepository;
+use otherworlds_event_store::pg_event_subscriber::{EventStream, PgEventSubscriber};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+/// Shorter than the subscriber's fallback poll, so a delivery within this
+/// window proves the notification woke the subscription.
+const DELIVERY_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Helper to build a `StoredEvent` with a custom event type and correlation ID.
+fn make_stored_event(
+    aggregate_id: Uuid,
+    sequence_number: i64,
+    event_type: &str,
+    correlation_id: Uuid,
+) -> StoredEvent {
+    StoredEvent {
+        event_id: Uuid::new_v4(),
+        aggregate_id,
+        event_type: event_type.to_string(),
+        payload: serde_json::json!({"key": "value"}),
+        sequence_number,
+        correlation_id,
+        causation_id: Uuid::new_v4(),
+        // Truncate to microsecond precision to match PostgreSQL TIMESTAMPTZ.
+        occurred_at: DateTime::from_timestamp_micros(Utc::now().timestamp_micros()).unwrap(),
+        schema_version: 1,
+    }
+}
+
+/// Waits for the next event on the stream, failing the test on timeout.
+async fn next_event(stream: &mut EventStream) -> PositionedEvent {
+    tokio::time::timeout(DELIVERY_TIMEOUT, stream.next())
+        .await
+        .expect("timed out waiting for event")
+        .expect("stream ended")
+        .expect("stream yielded an error")
+}
+
+/// Asserts that nothing is delivered within a short window.
+async fn assert_idle(stream: &mut EventStream) {
+    let result = tokio::time::timeout(Duration::from_millis(300), stream.next()).await;
+    assert!(result.is_err(), "expected no event, got {result:?}");
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_subscribe_delivers_existing_then_live_events(pool: PgPool) {
+    // Arrange
+    let repo = PgEventRepository::new(pool.clone());
+    let aggregate_id = Uuid::new_v4();
+    let first = make_stored_event(aggregate_id, 1, "narrative.beat_advanced", Uuid::new_v4());
+    repo.append_events(aggregate_id, 0, std::slice::from_ref(&first))
+        .await
+        .unwrap();
+
+    let mut stream = PgEventSubscriber::new(pool)
+        .subscribe(SubscriptionFilter::all(), 0)
+        .await
+        .unwrap();
+
+    // Act & Assert — gap-fill delivers the stored event.
+    let delivered = next_event(&mut stream).await;
+    assert_eq!(delivered.event.event_id, first.event_id);
+
+    // Act & Assert — a later append is pushed via NOTIFY.
+    let second = make_stored_event(aggregate_id, 2, "narrative.beat_advanced", Uuid::new_v4());
+    repo.append_events(aggregate_id, 1, std::slice::from_ref(&second))
+        .await
+        .unwrap();
+    let delivered = next_event(&mut stream).await;
+    assert_eq!(delivered.event.event_id, second.event_id);
+    assert!(delivered.position > 1);
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_subscribe_resumes_after_last_seen_position(pool: PgPool) {
+    // Arrange
+    let repo = PgEventRepository::new(pool.clone());
+    let aggregate_id = Uuid::new_v4();
+    let events: Vec<StoredEvent> = (1..=3)
+        .map(|seq| make_stored_event(aggregate_id, seq, "rules.check_resolved", Uuid::new_v4()))
+        .collect();
+    repo.append_events(aggregate_id, 0, &events).await.unwrap();
+    let subscriber = PgEventSubscriber::new(pool);
+
+    let mut first_run = subscriber
+        .subscribe(SubscriptionFilter::all(), 0)
+        .await
+        .unwrap();
+    let last_seen = next_event(&mut first_run).await.position;
+    drop(first_run);
+
+    // Act
+    let mut resumed = subscriber
+        .subscribe(SubscriptionFilter::all(), last_seen)
+        .await
+        .unwrap();
+
+    // Assert
+    assert_eq!(
+        next_event(&mut resumed).await.event.event_id,
+        events[1].event_id
+    );
+    assert_eq!(
+        next_event(&mut resumed).await.event.event_id,
+        events[2].event_id
+    );
+    assert_idle(&mut resumed).await;
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_subscribe_filters_by_aggregate_id(pool: PgPool) {
+    // Arrange
+    let repo = PgEventRepository::new(pool.clone());
+    let watched = Uuid::new_v4();
+    let other = Uuid::new_v4();
+    let filter = SubscriptionFilter::all().for_aggregate(watched);
+    let mut stream = PgEventSubscriber::new(pool)
+        .subscribe(filter, 0)
+        .await
+        .unwrap();
+
+    // Act
+    let ignored = make_stored_event(other, 1, "character.character_created", Uuid::new_v4());
+    repo.append_events(other, 0, &[ignored]).await.unwrap();
+    let wanted = make_stored_event(watched, 1, "character.character_created", Uuid::new_v4());
+    repo.append_events(watched, 0, std::slice::from_ref(&wanted))
+        .await
+        .unwrap();
+
+    // Assert
+    assert_eq!(
+        next_event(&mut stream).await.event.event_id,
+        wanted.event_id
+    );
+    assert_idle(&mut stream).await;
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_subscribe_filters_by_event_type_prefix(pool: PgPool) {
+    // Arrange
+    let repo = PgEventRepository::new(pool.clone());
+    let filter = SubscriptionFilter::all().with_event_type_prefix("narrative.*");
+    let mut stream = PgEventSubscriber::new(pool)
+        .subscribe(filter, 0)
+        .await
+        .unwrap();
+
+    // Act
+    let rules_id = Uuid::new_v4();
+    repo.append_events(
+        rules_id,
+        0,
+        &[make_stored_event(
+            rules_id,
+            1,
+            "rules.intent_declared",
+            Uuid::new_v4(),
+        )],
+    )
+    .await
+    .unwrap();
+    let narrative_id = Uuid::new_v4();
+    let wanted = make_stored_event(narrative_id, 1, "narrative.scene_started", Uuid::new_v4());
+    repo.append_events(narrative_id, 0, std::slice::from_ref(&wanted))
+        .await
+        .unwrap();
+
+    // Assert
+    assert_eq!(
+        next_event(&mut stream).await.event.event_id,
+        wanted.event_id
+    );
+    assert_idle(&mut stream).await;
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_subscribe_filters_by_correlation_id(pool: PgPool) {
+    // Arrange
+    let repo = PgEventRepository::new(pool.clone());
+    let correlation_id = Uuid::new_v4();
+    let filter = SubscriptionFilter::all().with_correlation_id(correlation_id);
+    let mut stream = PgEventSubscriber::new(pool)
+        .subscribe(filter, 0)
+        .await
+        .unwrap();
+
+    // Act
+    let aggregate_id = Uuid::new_v4();
+    let events = vec![
+        make_stored_event(aggregate_id, 1, "world_state.flag_set", Uuid::new_v4()),
+        make_stored_event(aggregate_id, 2, "world_state.flag_set", correlation_id),
+    ];
+    repo.append_events(aggregate_id, 0, &events).await.unwrap();
+
+    // Assert
+    let delivered = next_event(&mut stream).await;
+    assert_eq!(delivered.event.event_id, events[1].event_id);
+    assert_eq!(delivered.event.correlation_id, correlation_id);
+    assert_idle(&mut stream).await;
+}
@@ -0,0 +1,192 @@
+//! Integration tests for `PgReadModelStore`.
+
+use otherworlds_core::error::DomainError;
+use otherworlds_core::projection::ReadModelStore;
+use otherworlds_event_store::pg_read_model_store::PgReadModelStore;
+use serde_json::{Value, json};
+use sqlx::PgPool;
+use uuid::Uuid;
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_get_returns_none_when_absent(pool: PgPool) {
+    let store = PgReadModelStore::new(pool);
+
+    let document = store.get("character_views", Uuid::new_v4()).await.unwrap();
+
+    assert!(document.is_none());
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_upsert_and_get_round_trip(pool: PgPool) {
+    // Arrange
+    let store = PgReadModelStore::new(pool);
+    let id = Uuid::new_v4();
+
+    // Act
+    store
+        .upsert("character_views", id, &json!({ "name": "Alaric" }))
+        .await
+        .unwrap();
+    store
+        .upsert("character_views", id, &json!({ "name": "Brenna" }))
+        .await
+        .unwrap();
+
+    // Assert
+    let document = store.get("character_views", id).await.unwrap();
+    assert_eq!(document, Some(json!({ "name": "Brenna" })));
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_upsert_if_newer_keeps_newer_document(pool: PgPool) {
+    // Arrange
+    let store = PgReadModelStore::new(pool);
+    let id = Uuid::new_v4();
+    store
+        .upsert_if_newer(
+            "character_views",
+            id,
+            &json!({ "version": 3, "name": "Alaric" }),
+            3,
+        )
+        .await
+        .unwrap();
+
+    // Act
+    store
+        .upsert_if_newer(
+            "character_views",
+            id,
+            &json!({ "version": 2, "name": "Stale" }),
+            2,
+        )
+        .await
+        .unwrap();
+    store
+        .upsert_if_newer(
+            "character_views",
+            id,
+            &json!({ "version": 3, "name": "Brenna" }),
+            3,
+        )
+        .await
+        .unwrap();
+
+    // Assert
+    let document = store.get("character_views", id).await.unwrap();
+    assert_eq!(document, Some(json!({ "version": 3, "name": "Brenna" })));
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_list_orders_by_id(pool: PgPool) {
+    // Arrange
+    let store = PgReadModelStore::new(pool);
+    let mut ids = [Uuid::new_v4(), Uuid::new_v4(), Uuid::new_v4()];
+    for id in &ids {
+        store
+            .upsert("inventory_summaries", *id, &json!({ "id": id }))
+            .await
+            .unwrap();
+    }
+    ids.sort();
+
+    // Act
+    let documents = store.list("inventory_summaries").await.unwrap();
+
+    // Assert
+    let listed: Vec<Value> = documents.into_iter().map(|d| d["id"].clone()).collect();
+    let expected: Vec<Value> = ids.iter().map(|id| json!(id)).collect();
+    assert_eq!(listed, expected);
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_delete_and_clear_remove_documents(pool: PgPool) {
+    // Arrange
+    let store = PgReadModelStore::new(pool);
+    let first = Uuid::new_v4();
+    let second = Uuid::new_v4();
+    store
+        .upsert("campaign_summaries", first, &json!({}))
+        .await
+        .unwrap();
+    store
+        .upsert("campaign_summaries", second, &json!({}))
+        .await
+        .unwrap();
+
+    // Act & Assert
+    store.delete("campaign_summaries", first).await.unwrap();
+    assert_eq!(store.list("campaign_summaries").await.unwrap().len(), 1);
+
+    store.clear("campaign_summaries").await.unwrap();
+    assert!(store.list("campaign_summaries").await.unwrap().is_empty());
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_checkpoint_defaults_to_zero_and_round_trips(pool: PgPool) {
+    let store = PgReadModelStore::new(pool);
+
+    assert_eq!(
+        store.load_checkpoint("character.characters").await.unwrap(),
+        0
+    );
+
+    store
+        .save_checkpoint("character.characters", 42)
+        .await
+        .unwrap();
+    store
+        .save_checkpoint("character.characters", 7)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        store.load_checkpoint("character.characters").await.unwrap(),
+        7
+    );
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_projection_version_is_recorded_beside_checkpoint(pool: PgPool) {
+    let store = PgReadModelStore::new(pool);
+
+    assert_eq!(
+        store
+            .load_projection_version("character.characters")
+            .await
+            .unwrap(),
+        None
+    );
+
+    store
+        .save_projection_version("character.characters", 2)
+        .await
+        .unwrap();
+    store
+        .save_checkpoint("character.characters", 9)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        store
+            .load_projection_version("character.characters")
+            .await
+            .unwrap(),
+        Some(2)
+    );
+    assert_eq!(
+        store.load_checkpoint("character.characters").await.unwrap(),
+        9
+    );
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_rejects_unsafe_collection_name(pool: PgPool) {
+    let store = PgReadModelStore::new(pool);
+
+    let result = store
+        .get("character_views; DROP TABLE domain_events", Uuid::new_v4())
+        .await;
+
+    assert!(matches!(result, Err(DomainError::Infrastructure(_))));
+}
@@ -0,0 +1,139 @@
+//! Integration tests for `PgSnapshotStore` and snapshot-aware `PgEventRepository`.
+
+use chrono::{DateTime, Utc};
+use otherworlds_core::repository::{
+    AggregateSnapshot, EventRepository, SnapshotPolicy, SnapshotStore, StoredEvent,
+};
+use otherworlds_event_store::pg_event_repository::PgEventRepository;
+use otherworlds_event_store::pg_snapshot_store::PgSnapshotStore;
+use sqlx::PgPool;
+use uuid::Uuid;
+
+/// Truncates the current time to microsecond precision to match `TIMESTAMPTZ`.
+fn now_micros() -> DateTime<Utc> {
+    DateTime::from_timestamp_micros(Utc::now().timestamp_micros()).unwrap()
+}
+
+/// Helper to build a snapshot at the given version.
+fn make_snapshot(aggregate_id: Uuid, version: i64) -> AggregateSnapshot {
+    AggregateSnapshot {
+        aggregate_id,
+        aggregate_type: "test.aggregate".to_owned(),
+        version,
+        state: serde_json::json!({ "version": version }),
+        taken_at: now_micros(),
+    }
+}
+
+/// Helper to build a `StoredEvent` with sensible defaults.
+fn make_stored_event(aggregate_id: Uuid, sequence_number: i64) -> StoredEvent {
+    StoredEvent {
+        event_id: Uuid::new_v4(),
+        aggregate_id,
+        event_type: "TestEvent".to_owned(),
+        payload: serde_json::json!({"key": "value"}),
+        sequence_number,
+        correlation_id: Uuid::new_v4(),
+        causation_id: Uuid::new_v4(),
+        occurred_at: now_micros(),
+        schema_version: 1,
+    }
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_load_snapshot_returns_none_when_absent(pool: PgPool) {
+    let store = PgSnapshotStore::new(pool, SnapshotPolicy::every(10));
+
+    let snapshot = store.load_snapshot(Uuid::new_v4()).await.unwrap();
+
+    assert!(snapshot.is_none());
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_save_and_load_snapshot_round_trip(pool: PgPool) {
+    // Arrange
+    let store = PgSnapshotStore::new(pool, SnapshotPolicy::every(10));
+    let aggregate_id = Uuid::new_v4();
+    let snapshot = make_snapshot(aggregate_id, 10);
+
+    // Act
+    store.save_snapshot(&snapshot).await.unwrap();
+    let loaded = store.load_snapshot(aggregate_id).await.unwrap().unwrap();
+
+    // Assert
+    assert_eq!(loaded.aggregate_id, aggregate_id);
+    assert_eq!(loaded.aggregate_type, "test.aggregate");
+    assert_eq!(loaded.version, 10);
+    assert_eq!(loaded.state, snapshot.state);
+    assert_eq!(loaded.taken_at, snapshot.taken_at);
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_save_snapshot_replaces_older_version(pool: PgPool) {
+    let store = PgSnapshotStore::new(pool, SnapshotPolicy::every(10));
+    let aggregate_id = Uuid::new_v4();
+
+    store
+        .save_snapshot(&make_snapshot(aggregate_id, 10))
+        .await
+        .unwrap();
+    store
+        .save_snapshot(&make_snapshot(aggregate_id, 20))
+        .await
+        .unwrap();
+
+    let loaded = store.load_snapshot(aggregate_id).await.unwrap().unwrap();
+    assert_eq!(loaded.version, 20);
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_save_snapshot_never_overwrites_newer_version(pool: PgPool) {
+    let store = PgSnapshotStore::new(pool, SnapshotPolicy::every(10));
+    let aggregate_id = Uuid::new_v4();
+
+    store
+        .save_snapshot(&make_snapshot(aggregate_id, 20))
+        .await
+        .unwrap();
+    store
+        .save_snapshot(&make_snapshot(aggregate_id, 10))
+        .await
+        .unwrap();
+
+    let loaded = store.load_snapshot(aggregate_id).await.unwrap().unwrap();
+    assert_eq!(loaded.version, 20);
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_repository_without_snapshots_has_no_store(pool: PgPool) {
+    let repo = PgEventRepository::new(pool);
+
+    assert!(repo.snapshot_store().is_none());
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_repository_with_snapshots_exposes_policy(pool: PgPool) {
+    let repo = PgEventRepository::with_snapshots(pool, SnapshotPolicy::every(25));
+
+    let store = repo.snapshot_store().unwrap();
+
+    assert_eq!(store.policy().every_n_events(), 25);
+}
+
+#[sqlx::test(migrations = "../../migrations")]
+async fn test_load_events_after_returns_only_tail(pool: PgPool) {
+    // Arrange
+    let repo = PgEventRepository::new(pool);
+    let aggregate_id = Uuid::new_v4();
+    let events: Vec<StoredEvent> = (1..=5)
+        .map(|seq| make_stored_event(aggregate_id, seq))
+        .collect();
+    repo.append_events(aggregate_id, 0, &events).await.unwrap();
+
+    // Act
+    let tail = repo.load_events_after(aggregate_id, 3).await.unwrap();
+
+    // Assert
+    let sequence_numbers: Vec<i64> = tail.iter().map(|e| e.sequence_number).collect();
+    assert_eq!(sequence_numbers, vec![4, 5]);
+}
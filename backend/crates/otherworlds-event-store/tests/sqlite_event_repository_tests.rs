@@ -0,0 +1,102 @@
+//! Integration tests for `SqliteEventRepository`.
+//!
+//! Runs the shared conformance suite against a private in-memory database
+//! per test, plus checks specific to the `SQLite` backend.
+
+mod conformance;
+
+use chrono::{DateTime, Utc};
+use otherworlds_core::error::DomainError;
+use otherworlds_core::repository::{EventRepository, StoredEvent};
+use otherworlds_event_store::sqlite_event_repository::SqliteEventRepository;
+use uuid::Uuid;
+
+/// Opens a fresh, migrated in-memory database.
+async fn repository() -> SqliteEventRepository {
+    SqliteEventRepository::connect("sqlite::memory:")
+        .await
+        .unwrap()
+}
+
+macro_rules! sqlite_test {
+    ($name:ident) => {
+        #[tokio::test]
+        async fn $name() {
+            conformance::$name(&repository().await).await;
+        }
+    };
+}
+
+conformance::conformance_suite!(sqlite_test);
+
+fn make_stored_event(aggregate_id: Uuid, sequence_number: i64) -> StoredEvent {
+    StoredEvent {
+        event_id: Uuid::new_v4(),
+        aggregate_id,
+        event_type: "TestEvent".to_string(),
+        payload: serde_json::json!({"key": "value"}),
+        sequence_number,
+        correlation_id: Uuid::new_v4(),
+        causation_id: Uuid::new_v4(),
+        occurred_at: DateTime::from_timestamp_micros(Utc::now().timestamp_micros()).unwrap(),
+        schema_version: 1,
+    }
+}
+
+// --- unique constraint ---
+
+#[tokio::test]
+async fn test_duplicate_sequence_in_batch_maps_to_concurrency_conflict() {
+    let repo = repository().await;
+    let aggregate_id = Uuid::new_v4();
+
+    // The expected version is correct, so only the UNIQUE constraint on
+    // (aggregate_id, sequence_number) can reject this batch.
+    let result = repo
+        .append_events(
+            aggregate_id,
+            0,
+            &[
+                make_stored_event(aggregate_id, 1),
+                make_stored_event(aggregate_id, 1),
+            ],
+        )
+        .await;
+
+    match result {
+        Err(DomainError::ConcurrencyConflict {
+            aggregate_id: conflict_agg_id,
+            expected,
+            actual,
+        }) => {
+            assert_eq!(conflict_agg_id, aggregate_id);
+            assert_eq!(expected, 0);
+            assert_eq!(actual, 1);
+        }
+        other => panic!("expected ConcurrencyConflict, got {other:?}"),
+    }
+    assert!(repo.load_events(aggregate_id).await.unwrap().is_empty());
+}
+
+// --- persistence ---
+
+#[tokio::test]
+async fn test_events_survive_reopening_the_database_file() {
+    let path = std::env::temp_dir().join(format!("otherworlds-{}.db", Uuid::new_v4()));
+    let url = format!("sqlite://{}", path.display());
+    let aggregate_id = Uuid::new_v4();
+    {
+        let repo = SqliteEventRepository::connect(&url).await.unwrap();
+        repo.append_events(aggregate_id, 0, &[make_stored_event(aggregate_id, 1)])
+            .await
+            .unwrap();
+    }
+
+    // Reopening runs the migrations again, which must be a no-op.
+    let reopened = SqliteEventRepository::connect(&url).await.unwrap();
+    let loaded = reopened.load_events(aggregate_id).await.unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(loaded.len(), 1);
+    assert_eq!(loaded[0].sequence_number, 1);
+}
@@ -11,12 +11,14 @@ use otherworlds_core::error::DomainError;
 use otherworlds_core::event::DomainEvent;
 use otherworlds_core::repository::{EventRepository, StoredEvent};
 use otherworlds_core::rng::DeterministicRng;
+use otherworlds_core::snapshot::{load_with_snapshot, save_snapshot_if_due};
 use tracing::instrument;
 use uuid::Uuid;
 
 use crate::domain::aggregates::Inventory;
 use crate::domain::commands::{AddItem, ArchiveInventory, EquipItem, RemoveItem};
 use crate::domain::events::{InventoryEvent, InventoryEventKind};
+use crate::domain::upcasters;
 
 /// Result of a successfully handled command.
 #[derive(Debug)]
@@ -38,6 +40,7 @@ fn to_stored_event(event: &InventoryEvent) -> StoredEvent {
         correlation_id: meta.correlation_id,
         causation_id: meta.causation_id,
         occurred_at: meta.occurred_at,
+        schema_version: meta.schema_version,
     }
 }
 
@@ -50,12 +53,36 @@ pub(crate) fn reconstitute(
     inventory_id: Uuid,
     existing_events: &[StoredEvent],
 ) -> Result<Inventory, DomainError> {
-    let mut inventory = Inventory::new(inventory_id);
-    for stored in existing_events {
-        let kind: InventoryEventKind =
-            serde_json::from_value(stored.payload.clone()).map_err(|e| {
-                DomainError::Infrastructure(format!("event deserialization failed: {e}"))
-            })?;
+    replay(Inventory::new(inventory_id), existing_events)
+}
+
+/// Loads a `Inventory`, starting from its latest snapshot when one is
+/// available and replaying only the events recorded after it.
+///
+/// Returns `None` if the aggregate has neither a snapshot nor any events.
+///
+/// # Errors
+///
+/// Returns `DomainError` if loading fails or event deserialization fails.
+pub(crate) async fn load(
+    inventory_id: Uuid,
+    repo: &dyn EventRepository,
+) -> Result<Option<Inventory>, DomainError> {
+    let (restored, events) = load_with_snapshot::<Inventory>(repo, inventory_id).await?;
+    match restored {
+        Some(inventory) => replay(inventory, &events).map(Some),
+        None if events.is_empty() => Ok(None),
+        None => reconstitute(inventory_id, &events).map(Some),
+    }
+}
+
+/// Applies stored events on top of an existing `Inventory` state.
+fn replay(mut inventory: Inventory, events: &[StoredEvent]) -> Result<Inventory, DomainError> {
+    for stored in events {
+        let payload = upcasters::registry().upcast(stored)?;
+        let kind: InventoryEventKind = serde_json::from_value(payload).map_err(|e| {
+            DomainError::Infrastructure(format!("event deserialization failed: {e}"))
+        })?;
         let event = InventoryEvent {
             metadata: otherworlds_core::event::EventMetadata {
                 event_id: stored.event_id,
@@ -65,6 +92,7 @@ pub(crate) fn reconstitute(
                 correlation_id: stored.correlation_id,
                 causation_id: stored.causation_id,
                 occurred_at: stored.occurred_at,
+                schema_version: upcasters::current_schema_version(&stored.event_type),
             },
             kind,
         };
@@ -86,11 +114,9 @@ pub async fn handle_add_item(
     rng: &Mutex<dyn DeterministicRng + Send>,
     repo: &dyn EventRepository,
 ) -> Result<InventoryCommandResult, DomainError> {
-    let existing_events = repo.load_events(command.inventory_id).await?;
-    if existing_events.is_empty() {
-        return Err(DomainError::AggregateNotFound(command.inventory_id));
-    }
-    let mut inventory = reconstitute(command.inventory_id, &existing_events)?;
+    let mut inventory = load(command.inventory_id, repo)
+        .await?
+        .ok_or(DomainError::AggregateNotFound(command.inventory_id))?;
 
     if inventory.archived {
         return Err(DomainError::Validation("inventory is archived".into()));
@@ -111,11 +137,12 @@ pub async fn handle_add_item(
     let stored_events: Vec<StoredEvent> = inventory
         .uncommitted_events()
         .iter()
-        .map(to_stored_event)
+        .map(|event| to_stored_event(event).caused_by(command.causation_id))
         .collect();
 
     repo.append_events(command.inventory_id, inventory.version(), &stored_events)
         .await?;
+    save_snapshot_if_due(repo, inventory, clock).await;
 
     Ok(InventoryCommandResult {
         aggregate_id: command.inventory_id,
@@ -136,11 +163,9 @@ pub async fn handle_remove_item(
     rng: &Mutex<dyn DeterministicRng + Send>,
     repo: &dyn EventRepository,
 ) -> Result<InventoryCommandResult, DomainError> {
-    let existing_events = repo.load_events(command.inventory_id).await?;
-    if existing_events.is_empty() {
-        return Err(DomainError::AggregateNotFound(command.inventory_id));
-    }
-    let mut inventory = reconstitute(command.inventory_id, &existing_events)?;
+    let mut inventory = load(command.inventory_id, repo)
+        .await?
+        .ok_or(DomainError::AggregateNotFound(command.inventory_id))?;
 
     if inventory.archived {
         return Err(DomainError::Validation("inventory is archived".into()));
@@ -166,6 +191,7 @@ pub async fn handle_remove_item(
 
     repo.append_events(command.inventory_id, inventory.version(), &stored_events)
         .await?;
+    save_snapshot_if_due(repo, inventory, clock).await;
 
     Ok(InventoryCommandResult {
         aggregate_id: command.inventory_id,
@@ -186,11 +212,9 @@ pub async fn handle_equip_item(
     rng: &Mutex<dyn DeterministicRng + Send>,
     repo: &dyn EventRepository,
 ) -> Result<InventoryCommandResult, DomainError> {
-    let existing_events = repo.load_events(command.inventory_id).await?;
-    if existing_events.is_empty() {
-        return Err(DomainError::AggregateNotFound(command.inventory_id));
-    }
-    let mut inventory = reconstitute(command.inventory_id, &existing_events)?;
+    let mut inventory = load(command.inventory_id, repo)
+        .await?
+        .ok_or(DomainError::AggregateNotFound(command.inventory_id))?;
 
     if inventory.archived {
         return Err(DomainError::Validation("inventory is archived".into()));
@@ -202,6 +226,7 @@ pub async fn handle_equip_item(
             .map_err(|e| DomainError::Infrastructure(format!("RNG mutex poisoned: {e}")))?;
         inventory.equip_item(
             command.item_id,
+            command.bonuses.clone(),
             command.correlation_id,
             clock,
             &mut *rng_guard,
@@ -216,6 +241,7 @@ pub async fn handle_equip_item(
 
     repo.append_events(command.inventory_id, inventory.version(), &stored_events)
         .await?;
+    save_snapshot_if_due(repo, inventory, clock).await;
 
     Ok(InventoryCommandResult {
         aggregate_id: command.inventory_id,
@@ -236,11 +262,9 @@ pub async fn handle_archive_inventory(
     rng: &Mutex<dyn DeterministicRng + Send>,
     repo: &dyn EventRepository,
 ) -> Result<InventoryCommandResult, DomainError> {
-    let existing_events = repo.load_events(command.inventory_id).await?;
-    if existing_events.is_empty() {
-        return Err(DomainError::AggregateNotFound(command.inventory_id));
-    }
-    let mut inventory = reconstitute(command.inventory_id, &existing_events)?;
+    let mut inventory = load(command.inventory_id, repo)
+        .await?
+        .ok_or(DomainError::AggregateNotFound(command.inventory_id))?;
 
     {
         let mut rng_guard = rng
@@ -257,6 +281,7 @@ pub async fn handle_archive_inventory(
 
     repo.append_events(command.inventory_id, inventory.version(), &stored_events)
         .await?;
+    save_snapshot_if_due(repo, inventory, clock).await;
 
     Ok(InventoryCommandResult {
         aggregate_id: command.inventory_id,
@@ -266,6 +291,7 @@ pub async fn handle_archive_inventory(
 
 #[cfg(test)]
 mod tests {
+    use std::collections::BTreeMap;
     use std::sync::{Arc, Mutex};
 
     use chrono::{DateTime, TimeZone, Utc};
@@ -302,6 +328,7 @@ mod tests {
             correlation_id: Uuid::new_v4(),
             causation_id: Uuid::new_v4(),
             occurred_at: fixed_now,
+            schema_version: 1,
         }
     }
 
@@ -320,6 +347,7 @@ mod tests {
 
         let command = AddItem {
             correlation_id,
+            causation_id: None,
             inventory_id,
             item_id,
         };
@@ -426,6 +454,7 @@ mod tests {
             correlation_id,
             inventory_id,
             item_id,
+            bonuses: BTreeMap::new(),
         };
 
         // Act
@@ -475,6 +504,7 @@ mod tests {
 
         let command = AddItem {
             correlation_id,
+            causation_id: None,
             inventory_id,
             item_id,
         };
@@ -532,6 +562,7 @@ mod tests {
 
         let command = AddItem {
             correlation_id,
+            causation_id: None,
             inventory_id,
             item_id,
         };
@@ -598,6 +629,7 @@ mod tests {
             correlation_id,
             inventory_id,
             item_id: missing_item_id,
+            bonuses: BTreeMap::new(),
         };
 
         // Act
@@ -628,6 +660,7 @@ mod tests {
             correlation_id,
             inventory_id,
             item_id,
+            bonuses: BTreeMap::new(),
         };
 
         // Act
@@ -660,6 +693,7 @@ mod tests {
             correlation_id: Uuid::new_v4(),
             causation_id: Uuid::new_v4(),
             occurred_at: fixed_now,
+            schema_version: 1,
         }
     }
 
@@ -786,6 +820,7 @@ mod tests {
 
         let command = AddItem {
             correlation_id,
+            causation_id: None,
             inventory_id,
             item_id: new_item_id,
         };
@@ -852,6 +887,7 @@ mod tests {
             correlation_id,
             inventory_id,
             item_id,
+            bonuses: BTreeMap::new(),
         };
 
         // Act
@@ -0,0 +1,98 @@
+//! Read-model projection for the Inventory & Economy context.
+//!
+//! Maintains one `InventoryView` per inventory and one `InventorySummary`
+//! per non-archived inventory, rebuilt from the aggregate whenever its
+//! stream changes.
+
+use async_trait::async_trait;
+use otherworlds_core::error::DomainError;
+use otherworlds_core::projection::{Projection, ReadModelStore, put_document};
+use otherworlds_core::repository::EventRepository;
+use uuid::Uuid;
+
+use crate::application::command_handlers;
+use crate::application::query_handlers::{self, InventorySummary, InventoryView};
+use crate::domain::aggregates::Inventory;
+
+/// Read-model collection holding one `InventoryView` per inventory.
+pub(crate) const INVENTORY_VIEWS: &str = "inventory_views";
+
+/// Read-model collection holding one `InventorySummary` per listed inventory.
+pub(crate) const INVENTORY_SUMMARIES: &str = "inventory_summaries";
+
+/// Event types used by the Inventory & Economy context.
+const EVENT_TYPES: &[&str] = &[
+    "inventory.item_added",
+    "inventory.item_removed",
+    "inventory.item_equipped",
+    "inventory.inventory_archived",
+];
+
+/// Projects inventory streams into the inventory view and summary tables.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct InventoryProjection;
+
+#[async_trait]
+impl Projection for InventoryProjection {
+    fn name(&self) -> &'static str {
+        "inventory.inventories"
+    }
+
+    // v2: views list equipped items and their bonuses.
+    fn version(&self) -> i32 {
+        2
+    }
+
+    fn event_types(&self) -> &'static [&'static str] {
+        EVENT_TYPES
+    }
+
+    async fn project(
+        &self,
+        aggregate_id: Uuid,
+        repo: &dyn EventRepository,
+        store: &dyn ReadModelStore,
+    ) -> Result<(), DomainError> {
+        let Some(inventory) = command_handlers::load(aggregate_id, repo).await? else {
+            store.delete(INVENTORY_VIEWS, aggregate_id).await?;
+            return store.delete(INVENTORY_SUMMARIES, aggregate_id).await;
+        };
+
+        put_document(store, INVENTORY_VIEWS, aggregate_id, &view(&inventory)).await?;
+        if inventory.archived {
+            store.delete(INVENTORY_SUMMARIES, aggregate_id).await
+        } else {
+            put_document(
+                store,
+                INVENTORY_SUMMARIES,
+                aggregate_id,
+                &summary(&inventory),
+            )
+            .await
+        }
+    }
+
+    async fn reset(&self, store: &dyn ReadModelStore) -> Result<(), DomainError> {
+        store.clear(INVENTORY_VIEWS).await?;
+        store.clear(INVENTORY_SUMMARIES).await
+    }
+}
+
+fn view(inventory: &Inventory) -> InventoryView {
+    let mut items: Vec<Uuid> = inventory.items.iter().copied().collect();
+    items.sort();
+    InventoryView {
+        inventory_id: inventory.id,
+        items,
+        equipped: query_handlers::equipped_items(inventory),
+        version: inventory.version,
+    }
+}
+
+fn summary(inventory: &Inventory) -> InventorySummary {
+    InventorySummary {
+        inventory_id: inventory.id,
+        item_count: inventory.items.len(),
+        version: inventory.version,
+    }
+}
@@ -1,36 +1,46 @@
 //! Query handlers for the Inventory & Economy context.
 //!
-//! This module contains query handlers that reconstitute aggregates
-//! from stored events and return read-only view DTOs.
+//! This module contains query handlers that bring the inventory projection
+//! up to date and return read-only view DTOs from its read-model tables.
+
+use std::collections::BTreeMap;
 
 use otherworlds_core::error::DomainError;
+use otherworlds_core::projection::{
+    ProjectionRunner, ReadModelStore, get_document, list_documents,
+};
 use otherworlds_core::repository::EventRepository;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 use crate::application::command_handlers;
+use crate::application::projections::{INVENTORY_SUMMARIES, INVENTORY_VIEWS, InventoryProjection};
+use crate::domain::aggregates::Inventory;
 
 /// Read-only view of an inventory aggregate.
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct InventoryView {
     /// The inventory identifier.
     pub inventory_id: Uuid,
     /// Items currently in the inventory (sorted for determinism).
     pub items: Vec<Uuid>,
+    /// Equipped items and their bonuses (sorted by item).
+    pub equipped: Vec<EquippedItem>,
     /// Current version (event count).
     pub version: i64,
 }
 
-/// Event types used by the Inventory & Economy context.
-const EVENT_TYPES: &[&str] = &[
-    "inventory.item_added",
-    "inventory.item_removed",
-    "inventory.item_equipped",
-    "inventory.inventory_archived",
-];
+/// An equipped item and the check bonuses it grants.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EquippedItem {
+    /// The item identifier.
+    pub item_id: Uuid,
+    /// Check bonuses keyed by skill or attribute name.
+    pub bonuses: BTreeMap<String, i32>,
+}
 
 /// Summary view for listing inventories.
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct InventorySummary {
     /// The inventory identifier.
     pub inventory_id: Uuid,
@@ -40,59 +50,75 @@ pub struct InventorySummary {
     pub version: i64,
 }
 
-/// Lists all inventories.
+/// Lists all inventories that have not been archived.
 ///
 /// # Errors
 ///
-/// Returns `DomainError::Infrastructure` if querying or deserialization fails.
+/// Returns `DomainError::Infrastructure` if catching up the projection or
+/// reading the read model fails.
 pub async fn list_inventories(
     repo: &dyn EventRepository,
+    read_models: &dyn ReadModelStore,
 ) -> Result<Vec<InventorySummary>, DomainError> {
-    let ids = repo.list_aggregate_ids(EVENT_TYPES).await?;
-    let mut summaries = Vec::with_capacity(ids.len());
-    for id in ids {
-        let stored_events = repo.load_events(id).await?;
-        if stored_events.is_empty() {
-            continue;
-        }
-        let inventory = command_handlers::reconstitute(id, &stored_events)?;
-        if inventory.archived {
-            continue;
-        }
-        summaries.push(InventorySummary {
-            inventory_id: id,
-            item_count: inventory.items.len(),
-            version: inventory.version,
-        });
-    }
-    Ok(summaries)
+    ProjectionRunner::default()
+        .catch_up(&InventoryProjection, repo, read_models)
+        .await?;
+    list_documents(read_models, INVENTORY_SUMMARIES).await
 }
 
 /// Retrieves an inventory by its aggregate ID.
 ///
-/// Loads all stored events for the aggregate, reconstitutes the inventory,
-/// and returns a serializable view.
-///
 /// # Errors
 ///
-/// Returns `DomainError::AggregateNotFound` if no events exist for the ID.
-/// Returns `DomainError::Infrastructure` if event deserialization fails.
+/// Returns `DomainError::AggregateNotFound` if the inventory has no view.
+/// Returns `DomainError::Infrastructure` if catching up the projection or
+/// reading the read model fails.
 pub async fn get_inventory_by_id(
     inventory_id: Uuid,
     repo: &dyn EventRepository,
+    read_models: &dyn ReadModelStore,
 ) -> Result<InventoryView, DomainError> {
-    let stored_events = repo.load_events(inventory_id).await?;
-    if stored_events.is_empty() {
-        return Err(DomainError::AggregateNotFound(inventory_id));
+    ProjectionRunner::default()
+        .catch_up(&InventoryProjection, repo, read_models)
+        .await?;
+    get_document(read_models, INVENTORY_VIEWS, inventory_id)
+        .await?
+        .ok_or(DomainError::AggregateNotFound(inventory_id))
+}
+
+/// Loads an inventory's equipped items straight from its event stream, for
+/// other contexts to derive check modifiers from.
+///
+/// # Errors
+///
+/// Returns `DomainError::AggregateNotFound` if the inventory does not exist,
+/// `DomainError::Validation` if it is archived, and
+/// `DomainError::Infrastructure` if loading fails.
+pub async fn get_equipped_items(
+    inventory_id: Uuid,
+    repo: &dyn EventRepository,
+) -> Result<Vec<EquippedItem>, DomainError> {
+    let inventory = command_handlers::load(inventory_id, repo)
+        .await?
+        .ok_or(DomainError::AggregateNotFound(inventory_id))?;
+    if inventory.archived {
+        return Err(DomainError::Validation("inventory is archived".into()));
     }
-    let inventory = command_handlers::reconstitute(inventory_id, &stored_events)?;
-    let mut items: Vec<Uuid> = inventory.items.iter().copied().collect();
-    items.sort();
-    Ok(InventoryView {
-        inventory_id,
-        items,
-        version: inventory.version,
-    })
+    Ok(equipped_items(&inventory))
+}
+
+/// Returns the inventory's equipped items, sorted by item for determinism.
+pub(crate) fn equipped_items(inventory: &Inventory) -> Vec<EquippedItem> {
+    let mut equipped: Vec<EquippedItem> = inventory
+        .equipped
+        .iter()
+        .map(|(item_id, bonuses)| EquippedItem {
+            item_id: *item_id,
+            bonuses: bonuses.clone(),
+        })
+        .collect();
+    equipped.sort_by_key(|item| item.item_id);
+    equipped
 }
 
 #[cfg(test)]
@@ -107,7 +133,9 @@ mod tests {
         INVENTORY_ARCHIVED_EVENT_TYPE, ITEM_ADDED_EVENT_TYPE, InventoryArchived,
         InventoryEventKind, ItemAdded,
     };
-    use otherworlds_test_support::{EmptyEventRepository, RecordingEventRepository};
+    use otherworlds_test_support::{
+        EmptyEventRepository, InMemoryReadModelStore, RecordingEventRepository,
+    };
 
     #[tokio::test]
     async fn test_get_inventory_by_id_returns_view_with_items() {
@@ -131,6 +159,7 @@ mod tests {
                 correlation_id: Uuid::new_v4(),
                 causation_id: Uuid::new_v4(),
                 occurred_at: fixed_now,
+                schema_version: 1,
             },
             StoredEvent {
                 event_id: Uuid::new_v4(),
@@ -145,12 +174,15 @@ mod tests {
                 correlation_id: Uuid::new_v4(),
                 causation_id: Uuid::new_v4(),
                 occurred_at: fixed_now,
+                schema_version: 1,
             },
         ];
         let repo = RecordingEventRepository::new(Ok(events));
 
         // Act
-        let view = get_inventory_by_id(inventory_id, &repo).await.unwrap();
+        let view = get_inventory_by_id(inventory_id, &repo, &InMemoryReadModelStore::new())
+            .await
+            .unwrap();
 
         // Assert
         assert_eq!(view.inventory_id, inventory_id);
@@ -167,7 +199,7 @@ mod tests {
         let repo = EmptyEventRepository;
 
         // Act
-        let result = get_inventory_by_id(inventory_id, &repo).await;
+        let result = get_inventory_by_id(inventory_id, &repo, &InMemoryReadModelStore::new()).await;
 
         // Assert
         assert!(result.is_err());
@@ -181,7 +213,9 @@ mod tests {
     async fn test_list_inventories_returns_empty_when_no_aggregates() {
         let repo = EmptyEventRepository;
 
-        let result = list_inventories(&repo).await.unwrap();
+        let result = list_inventories(&repo, &InMemoryReadModelStore::new())
+            .await
+            .unwrap();
 
         assert!(result.is_empty());
     }
@@ -205,10 +239,13 @@ mod tests {
             correlation_id: Uuid::new_v4(),
             causation_id: Uuid::new_v4(),
             occurred_at: fixed_now,
+            schema_version: 1,
         }];
         let repo = RecordingEventRepository::with_aggregate_ids(Ok(events), vec![inventory_id]);
 
-        let result = list_inventories(&repo).await.unwrap();
+        let result = list_inventories(&repo, &InMemoryReadModelStore::new())
+            .await
+            .unwrap();
 
         assert_eq!(result.len(), 1);
         assert_eq!(result[0].inventory_id, inventory_id);
@@ -236,6 +273,7 @@ mod tests {
                 correlation_id: Uuid::new_v4(),
                 causation_id: Uuid::new_v4(),
                 occurred_at: fixed_now,
+                schema_version: 1,
             },
             StoredEvent {
                 event_id: Uuid::new_v4(),
@@ -249,11 +287,14 @@ mod tests {
                 correlation_id: Uuid::new_v4(),
                 causation_id: Uuid::new_v4(),
                 occurred_at: fixed_now,
+                schema_version: 1,
             },
         ];
         let repo = RecordingEventRepository::with_aggregate_ids(Ok(events), vec![inventory_id]);
 
-        let result = list_inventories(&repo).await.unwrap();
+        let result = list_inventories(&repo, &InMemoryReadModelStore::new())
+            .await
+            .unwrap();
 
         assert!(result.is_empty());
     }
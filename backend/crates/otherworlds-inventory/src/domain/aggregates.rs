@@ -1,12 +1,14 @@
 //! Aggregate roots for the Inventory & Economy context.
 
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashMap, HashSet};
 
 use otherworlds_core::aggregate::AggregateRoot;
 use otherworlds_core::clock::Clock;
 use otherworlds_core::error::DomainError;
 use otherworlds_core::event::EventMetadata;
 use otherworlds_core::rng::DeterministicRng;
+use otherworlds_core::snapshot::Snapshottable;
+use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 use super::events::{
@@ -14,9 +16,10 @@ use super::events::{
     ITEM_REMOVED_EVENT_TYPE, InventoryArchived, InventoryEvent, InventoryEventKind, ItemAdded,
     ItemEquipped, ItemRemoved,
 };
+use super::upcasters::current_schema_version;
 
 /// The aggregate root for an inventory.
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct Inventory {
     /// Aggregate identifier.
     pub id: Uuid,
@@ -24,9 +27,12 @@ pub struct Inventory {
     pub(crate) version: i64,
     /// Items currently in the inventory.
     pub(crate) items: HashSet<Uuid>,
+    /// Equipped items and the check bonuses each grants.
+    pub(crate) equipped: HashMap<Uuid, BTreeMap<String, i32>>,
     /// Whether this inventory has been archived (soft-deleted).
     pub(crate) archived: bool,
     /// Uncommitted events pending persistence.
+    #[serde(skip)]
     uncommitted_events: Vec<InventoryEvent>,
 }
 
@@ -38,6 +44,7 @@ impl Inventory {
             id,
             version: 0,
             items: HashSet::new(),
+            equipped: HashMap::new(),
             archived: false,
             uncommitted_events: Vec::new(),
         }
@@ -76,6 +83,7 @@ impl Inventory {
                 correlation_id,
                 causation_id: correlation_id,
                 occurred_at: clock.now(),
+                schema_version: current_schema_version(ITEM_ADDED_EVENT_TYPE),
             },
             kind: InventoryEventKind::ItemAdded(ItemAdded {
                 inventory_id: self.id,
@@ -114,6 +122,7 @@ impl Inventory {
                 correlation_id,
                 causation_id: correlation_id,
                 occurred_at: clock.now(),
+                schema_version: current_schema_version(ITEM_REMOVED_EVENT_TYPE),
             },
             kind: InventoryEventKind::ItemRemoved(ItemRemoved {
                 inventory_id: self.id,
@@ -125,14 +134,17 @@ impl Inventory {
         Ok(())
     }
 
-    /// Equips an item, producing an `ItemEquipped` event.
+    /// Equips an item, producing an `ItemEquipped` event. Equipping an item
+    /// that is already equipped replaces its bonuses.
     ///
     /// # Errors
     ///
-    /// Returns `DomainError::Validation` if the item is not in the inventory.
+    /// Returns `DomainError::Validation` if the item is not in the inventory
+    /// or a bonus has a blank name.
     pub fn equip_item(
         &mut self,
         item_id: Uuid,
+        bonuses: BTreeMap<String, i32>,
         correlation_id: Uuid,
         clock: &dyn Clock,
         rng: &mut dyn DeterministicRng,
@@ -143,6 +155,11 @@ impl Inventory {
                 self.id
             )));
         }
+        if bonuses.keys().any(|name| name.trim().is_empty()) {
+            return Err(DomainError::Validation(
+                "bonus names must not be empty".into(),
+            ));
+        }
         let event = InventoryEvent {
             metadata: EventMetadata {
                 event_id: rng.next_uuid(),
@@ -152,10 +169,12 @@ impl Inventory {
                 correlation_id,
                 causation_id: correlation_id,
                 occurred_at: clock.now(),
+                schema_version: current_schema_version(ITEM_EQUIPPED_EVENT_TYPE),
             },
             kind: InventoryEventKind::ItemEquipped(ItemEquipped {
                 inventory_id: self.id,
                 item_id,
+                bonuses,
             }),
         };
 
@@ -188,6 +207,7 @@ impl Inventory {
                 correlation_id,
                 causation_id: correlation_id,
                 occurred_at: clock.now(),
+                schema_version: current_schema_version(INVENTORY_ARCHIVED_EVENT_TYPE),
             },
             kind: InventoryEventKind::InventoryArchived(InventoryArchived {
                 inventory_id: self.id,
@@ -217,8 +237,12 @@ impl AggregateRoot for Inventory {
             }
             InventoryEventKind::ItemRemoved(payload) => {
                 self.items.remove(&payload.item_id);
+                self.equipped.remove(&payload.item_id);
+            }
+            InventoryEventKind::ItemEquipped(payload) => {
+                self.equipped
+                    .insert(payload.item_id, payload.bonuses.clone());
             }
-            InventoryEventKind::ItemEquipped(_) => {}
             InventoryEventKind::InventoryArchived(_) => {
                 self.archived = true;
             }
@@ -235,6 +259,10 @@ impl AggregateRoot for Inventory {
     }
 }
 
+impl Snapshottable for Inventory {
+    const AGGREGATE_TYPE: &'static str = "inventory.inventory";
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -404,7 +432,13 @@ mod tests {
 
         // Act
         inventory
-            .equip_item(item_id, correlation_id, &clock, &mut MockRng)
+            .equip_item(
+                item_id,
+                BTreeMap::new(),
+                correlation_id,
+                &clock,
+                &mut MockRng,
+            )
             .unwrap();
 
         // Assert
@@ -441,7 +475,13 @@ mod tests {
         let mut inventory = Inventory::new(inventory_id);
 
         // Act
-        let result = inventory.equip_item(item_id, correlation_id, &clock, &mut MockRng);
+        let result = inventory.equip_item(
+            item_id,
+            BTreeMap::new(),
+            correlation_id,
+            &clock,
+            &mut MockRng,
+        );
 
         // Assert
         assert!(result.is_err());
@@ -537,6 +577,7 @@ mod tests {
                 correlation_id: Uuid::new_v4(),
                 causation_id: Uuid::new_v4(),
                 occurred_at: fixed_now,
+                schema_version: current_schema_version(INVENTORY_ARCHIVED_EVENT_TYPE),
             },
             kind: InventoryEventKind::InventoryArchived(InventoryArchived { inventory_id }),
         };
@@ -548,4 +589,46 @@ mod tests {
         assert!(inventory.archived);
         assert_eq!(inventory.version, 1);
     }
+
+    #[test]
+    fn test_apply_item_removed_unequips_the_item() {
+        // Arrange
+        let inventory_id = Uuid::new_v4();
+        let item_id = Uuid::new_v4();
+        let clock = FixedClock(Utc.with_ymd_and_hms(2026, 1, 15, 10, 0, 0).unwrap());
+        let mut inventory = Inventory::new(inventory_id);
+        inventory
+            .add_item(item_id, Uuid::new_v4(), &clock, &mut MockRng)
+            .unwrap();
+        for event in inventory.uncommitted_events().to_vec() {
+            inventory.apply(&event);
+        }
+        inventory.clear_uncommitted_events();
+        let bonuses = BTreeMap::from([("stealth".to_owned(), 2)]);
+        inventory
+            .equip_item(
+                item_id,
+                bonuses.clone(),
+                Uuid::new_v4(),
+                &clock,
+                &mut MockRng,
+            )
+            .unwrap();
+        for event in inventory.uncommitted_events().to_vec() {
+            inventory.apply(&event);
+        }
+        inventory.clear_uncommitted_events();
+        assert_eq!(inventory.equipped.get(&item_id), Some(&bonuses));
+
+        // Act
+        inventory
+            .remove_item(item_id, Uuid::new_v4(), &clock, &mut MockRng)
+            .unwrap();
+        for event in inventory.uncommitted_events().to_vec() {
+            inventory.apply(&event);
+        }
+
+        // Assert
+        assert!(inventory.equipped.is_empty());
+    }
 }
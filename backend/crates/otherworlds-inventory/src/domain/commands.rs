@@ -1,13 +1,20 @@
 //! Commands for the Inventory & Economy context.
 
+use std::collections::BTreeMap;
+
 use otherworlds_core::command::Command;
+use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 /// Command to add an item to an inventory.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AddItem {
     /// The correlation ID for tracing.
     pub correlation_id: Uuid,
+    /// The event that caused this command, when it was dispatched in
+    /// reaction to another context's event.
+    #[serde(default)]
+    pub causation_id: Option<Uuid>,
     /// The inventory identifier.
     pub inventory_id: Uuid,
     /// The item identifier.
@@ -25,7 +32,7 @@ impl Command for AddItem {
 }
 
 /// Command to remove an item from an inventory.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RemoveItem {
     /// The correlation ID for tracing.
     pub correlation_id: Uuid,
@@ -46,7 +53,7 @@ impl Command for RemoveItem {
 }
 
 /// Command to equip an item.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EquipItem {
     /// The correlation ID for tracing.
     pub correlation_id: Uuid,
@@ -54,6 +61,10 @@ pub struct EquipItem {
     pub inventory_id: Uuid,
     /// The item identifier.
     pub item_id: Uuid,
+    /// Check bonuses the item grants while equipped, keyed by skill or
+    /// attribute name.
+    #[serde(default)]
+    pub bonuses: BTreeMap<String, i32>,
 }
 
 impl Command for EquipItem {
@@ -67,7 +78,7 @@ impl Command for EquipItem {
 }
 
 /// Command to archive (soft-delete) an inventory.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ArchiveInventory {
     /// The correlation ID for tracing.
     pub correlation_id: Uuid,
@@ -1,5 +1,7 @@
 //! Domain events for the Inventory & Economy context.
 
+use std::collections::BTreeMap;
+
 use otherworlds_core::event::{DomainEvent, EventMetadata};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
@@ -29,6 +31,9 @@ pub struct ItemEquipped {
     pub inventory_id: Uuid,
     /// The item identifier.
     pub item_id: Uuid,
+    /// Check bonuses the item grants while equipped, keyed by skill or
+    /// attribute name.
+    pub bonuses: BTreeMap<String, i32>,
 }
 
 /// Emitted when an inventory is archived (soft-deleted).
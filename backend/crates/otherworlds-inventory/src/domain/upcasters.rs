@@ -0,0 +1,78 @@
+//! Upcasters for the Inventory & Economy context.
+//!
+//! Register a step here whenever an event payload changes shape, so streams
+//! written with the previous shape still load.
+
+use std::sync::LazyLock;
+
+use otherworlds_core::error::DomainError;
+use otherworlds_core::upcasting::UpcasterRegistry;
+use serde_json::{Value, json};
+
+use super::events::ITEM_EQUIPPED_EVENT_TYPE;
+
+static REGISTRY: LazyLock<UpcasterRegistry> = LazyLock::new(|| {
+    UpcasterRegistry::new().register(ITEM_EQUIPPED_EVENT_TYPE, 1, add_empty_bonuses)
+});
+
+/// Returns the upcaster registry for inventory events.
+#[must_use]
+pub fn registry() -> &'static UpcasterRegistry {
+    &REGISTRY
+}
+
+/// Returns the schema version newly produced events of `event_type` carry.
+#[must_use]
+pub fn current_schema_version(event_type: &str) -> i32 {
+    REGISTRY.current_version(event_type)
+}
+
+/// v1 → v2: `ItemEquipped` records the check bonuses the item grants.
+/// Items equipped before bonuses existed grant none.
+fn add_empty_bonuses(mut payload: Value) -> Result<Value, DomainError> {
+    payload
+        .get_mut("ItemEquipped")
+        .and_then(Value::as_object_mut)
+        .ok_or_else(|| DomainError::Infrastructure("ItemEquipped payload has no body".into()))?
+        .insert("bonuses".into(), json!({}));
+    Ok(payload)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+    use otherworlds_core::repository::StoredEvent;
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::domain::events::InventoryEventKind;
+
+    #[test]
+    fn test_item_equipped_v1_gains_empty_bonuses() {
+        // Arrange
+        let stored = StoredEvent {
+            event_id: Uuid::new_v4(),
+            aggregate_id: Uuid::new_v4(),
+            event_type: ITEM_EQUIPPED_EVENT_TYPE.to_owned(),
+            payload: json!({ "ItemEquipped": {
+                "inventory_id": Uuid::new_v4(),
+                "item_id": Uuid::new_v4()
+            }}),
+            sequence_number: 1,
+            correlation_id: Uuid::new_v4(),
+            causation_id: Uuid::new_v4(),
+            occurred_at: Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap(),
+            schema_version: 1,
+        };
+
+        // Act
+        let payload = registry().upcast(&stored).unwrap();
+
+        // Assert
+        assert_eq!(current_schema_version(ITEM_EQUIPPED_EVENT_TYPE), 2);
+        match serde_json::from_value(payload).unwrap() {
+            InventoryEventKind::ItemEquipped(equipped) => assert!(equipped.bonuses.is_empty()),
+            other => panic!("expected ItemEquipped, got {other:?}"),
+        }
+    }
+}
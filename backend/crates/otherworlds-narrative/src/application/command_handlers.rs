@@ -11,6 +11,7 @@ use otherworlds_core::error::DomainError;
 use otherworlds_core::event::DomainEvent;
 use otherworlds_core::repository::{EventRepository, StoredEvent};
 use otherworlds_core::rng::DeterministicRng;
+use otherworlds_core::snapshot::{load_with_snapshot, save_snapshot_if_due};
 use tracing::instrument;
 use uuid::Uuid;
 
@@ -19,6 +20,7 @@ use crate::domain::commands::{
     AdvanceBeat, ArchiveSession, EnterScene, PresentChoice, SelectChoice,
 };
 use crate::domain::events::{NarrativeEvent, NarrativeEventKind};
+use crate::domain::upcasters;
 
 fn to_stored_event(event: &NarrativeEvent) -> StoredEvent {
     let meta = event.metadata();
@@ -31,6 +33,7 @@ fn to_stored_event(event: &NarrativeEvent) -> StoredEvent {
         correlation_id: meta.correlation_id,
         causation_id: meta.causation_id,
         occurred_at: meta.occurred_at,
+        schema_version: meta.schema_version,
     }
 }
 
@@ -43,12 +46,39 @@ pub(crate) fn reconstitute(
     session_id: Uuid,
     existing_events: &[StoredEvent],
 ) -> Result<NarrativeSession, DomainError> {
-    let mut session = NarrativeSession::new(session_id);
-    for stored in existing_events {
-        let kind: NarrativeEventKind =
-            serde_json::from_value(stored.payload.clone()).map_err(|e| {
-                DomainError::Infrastructure(format!("event deserialization failed: {e}"))
-            })?;
+    replay(NarrativeSession::new(session_id), existing_events)
+}
+
+/// Loads a `NarrativeSession`, starting from its latest snapshot when one is
+/// available and replaying only the events recorded after it.
+///
+/// Returns `None` if the aggregate has neither a snapshot nor any events.
+///
+/// # Errors
+///
+/// Returns `DomainError` if loading fails or event deserialization fails.
+pub(crate) async fn load(
+    session_id: Uuid,
+    repo: &dyn EventRepository,
+) -> Result<Option<NarrativeSession>, DomainError> {
+    let (restored, events) = load_with_snapshot::<NarrativeSession>(repo, session_id).await?;
+    match restored {
+        Some(session) => replay(session, &events).map(Some),
+        None if events.is_empty() => Ok(None),
+        None => reconstitute(session_id, &events).map(Some),
+    }
+}
+
+/// Applies stored events on top of an existing `NarrativeSession` state.
+fn replay(
+    mut session: NarrativeSession,
+    events: &[StoredEvent],
+) -> Result<NarrativeSession, DomainError> {
+    for stored in events {
+        let payload = upcasters::registry().upcast(stored)?;
+        let kind: NarrativeEventKind = serde_json::from_value(payload).map_err(|e| {
+            DomainError::Infrastructure(format!("event deserialization failed: {e}"))
+        })?;
         let event = NarrativeEvent {
             metadata: otherworlds_core::event::EventMetadata {
                 event_id: stored.event_id,
@@ -58,6 +88,7 @@ pub(crate) fn reconstitute(
                 correlation_id: stored.correlation_id,
                 causation_id: stored.causation_id,
                 occurred_at: stored.occurred_at,
+                schema_version: upcasters::current_schema_version(&stored.event_type),
             },
             kind,
         };
@@ -79,8 +110,9 @@ pub async fn handle_advance_beat(
     rng: &Mutex<dyn DeterministicRng + Send>,
     repo: &dyn EventRepository,
 ) -> Result<Vec<StoredEvent>, DomainError> {
-    let existing_events = repo.load_events(command.session_id).await?;
-    let mut session = reconstitute(command.session_id, &existing_events)?;
+    let mut session = load(command.session_id, repo)
+        .await?
+        .unwrap_or_else(|| NarrativeSession::new(command.session_id));
 
     if session.archived {
         return Err(DomainError::Validation("session is archived".into()));
@@ -101,6 +133,7 @@ pub async fn handle_advance_beat(
 
     repo.append_events(command.session_id, session.version, &stored_events)
         .await?;
+    save_snapshot_if_due(repo, session, clock).await;
 
     Ok(stored_events)
 }
@@ -118,8 +151,9 @@ pub async fn handle_present_choice(
     rng: &Mutex<dyn DeterministicRng + Send>,
     repo: &dyn EventRepository,
 ) -> Result<Vec<StoredEvent>, DomainError> {
-    let existing_events = repo.load_events(command.session_id).await?;
-    let mut session = reconstitute(command.session_id, &existing_events)?;
+    let mut session = load(command.session_id, repo)
+        .await?
+        .unwrap_or_else(|| NarrativeSession::new(command.session_id));
 
     if session.archived {
         return Err(DomainError::Validation("session is archived".into()));
@@ -140,6 +174,7 @@ pub async fn handle_present_choice(
 
     repo.append_events(command.session_id, session.version, &stored_events)
         .await?;
+    save_snapshot_if_due(repo, session, clock).await;
 
     Ok(stored_events)
 }
@@ -159,11 +194,9 @@ pub async fn handle_archive_session(
     rng: &Mutex<dyn DeterministicRng + Send>,
     repo: &dyn EventRepository,
 ) -> Result<Vec<StoredEvent>, DomainError> {
-    let existing_events = repo.load_events(command.session_id).await?;
-    if existing_events.is_empty() {
-        return Err(DomainError::AggregateNotFound(command.session_id));
-    }
-    let mut session = reconstitute(command.session_id, &existing_events)?;
+    let mut session = load(command.session_id, repo)
+        .await?
+        .ok_or(DomainError::AggregateNotFound(command.session_id))?;
 
     {
         let mut rng_guard = rng
@@ -180,6 +213,7 @@ pub async fn handle_archive_session(
 
     repo.append_events(command.session_id, session.version, &stored_events)
         .await?;
+    save_snapshot_if_due(repo, session, clock).await;
 
     Ok(stored_events)
 }
@@ -197,8 +231,9 @@ pub async fn handle_enter_scene(
     rng: &Mutex<dyn DeterministicRng + Send>,
     repo: &dyn EventRepository,
 ) -> Result<Vec<StoredEvent>, DomainError> {
-    let existing_events = repo.load_events(command.session_id).await?;
-    let mut session = reconstitute(command.session_id, &existing_events)?;
+    let mut session = load(command.session_id, repo)
+        .await?
+        .unwrap_or_else(|| NarrativeSession::new(command.session_id));
 
     {
         let mut rng_guard = rng
@@ -220,6 +255,7 @@ pub async fn handle_enter_scene(
 
     repo.append_events(command.session_id, session.version, &stored_events)
         .await?;
+    save_snapshot_if_due(repo, session, clock).await;
 
     Ok(stored_events)
 }
@@ -237,8 +273,9 @@ pub async fn handle_select_choice(
     rng: &Mutex<dyn DeterministicRng + Send>,
     repo: &dyn EventRepository,
 ) -> Result<Vec<StoredEvent>, DomainError> {
-    let existing_events = repo.load_events(command.session_id).await?;
-    let mut session = reconstitute(command.session_id, &existing_events)?;
+    let mut session = load(command.session_id, repo)
+        .await?
+        .unwrap_or_else(|| NarrativeSession::new(command.session_id));
 
     {
         let mut rng_guard = rng
@@ -261,6 +298,7 @@ pub async fn handle_select_choice(
 
     repo.append_events(command.session_id, session.version, &stored_events)
         .await?;
+    save_snapshot_if_due(repo, session, clock).await;
 
     Ok(stored_events)
 }
@@ -302,6 +340,7 @@ mod tests {
             correlation_id: Uuid::new_v4(),
             causation_id: Uuid::new_v4(),
             occurred_at: fixed_now,
+            schema_version: 1,
         }
     }
 
@@ -463,6 +502,7 @@ mod tests {
             correlation_id: Uuid::new_v4(),
             causation_id: Uuid::new_v4(),
             occurred_at: fixed_now,
+            schema_version: 1,
         };
         let existing = vec![beat_advanced_event(session_id, fixed_now), archived_event];
         let repo = RecordingEventRepository::new(Ok(existing));
@@ -506,6 +546,7 @@ mod tests {
             correlation_id: Uuid::new_v4(),
             causation_id: Uuid::new_v4(),
             occurred_at: fixed_now,
+            schema_version: 1,
         };
         let existing = vec![beat_advanced_event(session_id, fixed_now), archived_event];
         let repo = RecordingEventRepository::new(Ok(existing));
@@ -549,6 +590,7 @@ mod tests {
             correlation_id: Uuid::new_v4(),
             causation_id: Uuid::new_v4(),
             occurred_at: fixed_now,
+            schema_version: 1,
         };
         let existing = vec![beat_advanced_event(session_id, fixed_now), archived_event];
         let repo = RecordingEventRepository::new(Ok(existing));
@@ -711,6 +753,7 @@ mod tests {
             correlation_id: Uuid::new_v4(),
             causation_id: Uuid::new_v4(),
             occurred_at: fixed_now,
+            schema_version: 1,
         }
     }
 
@@ -770,6 +813,7 @@ mod tests {
             correlation_id: Uuid::new_v4(),
             causation_id: Uuid::new_v4(),
             occurred_at: fixed_now,
+            schema_version: 1,
         };
         let existing = vec![beat_advanced_event(session_id, fixed_now), archived_event];
         let repo = RecordingEventRepository::new(Ok(existing));
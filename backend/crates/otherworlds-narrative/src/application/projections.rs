@@ -0,0 +1,106 @@
+//! Read-model projection for the Narrative Orchestration context.
+//!
+//! Maintains one `NarrativeSessionView` per narrative session and one `NarrativeSessionSummary`
+//! per non-archived narrative session, rebuilt from the aggregate whenever its
+//! stream changes.
+
+use async_trait::async_trait;
+use otherworlds_core::error::DomainError;
+use otherworlds_core::projection::{Projection, ReadModelStore, put_document};
+use otherworlds_core::repository::EventRepository;
+use uuid::Uuid;
+
+use crate::application::command_handlers;
+use crate::application::query_handlers::{NarrativeSessionSummary, NarrativeSessionView};
+use crate::domain::aggregates::NarrativeSession;
+
+/// Read-model collection holding one `NarrativeSessionView` per narrative session.
+pub(crate) const NARRATIVE_SESSION_VIEWS: &str = "narrative_session_views";
+
+/// Read-model collection holding one `NarrativeSessionSummary` per listed narrative session.
+pub(crate) const NARRATIVE_SESSION_SUMMARIES: &str = "narrative_session_summaries";
+
+/// Event types used by the Narrative Orchestration context.
+const EVENT_TYPES: &[&str] = &[
+    "narrative.beat_advanced",
+    "narrative.choice_presented",
+    "narrative.choice_selected",
+    "narrative.scene_started",
+    "narrative.session_archived",
+];
+
+/// Projects narrative session streams into the narrative session view and summary tables.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NarrativeSessionProjection;
+
+#[async_trait]
+impl Projection for NarrativeSessionProjection {
+    fn name(&self) -> &'static str {
+        "narrative.sessions"
+    }
+
+    fn event_types(&self) -> &'static [&'static str] {
+        EVENT_TYPES
+    }
+
+    async fn project(
+        &self,
+        aggregate_id: Uuid,
+        repo: &dyn EventRepository,
+        store: &dyn ReadModelStore,
+    ) -> Result<(), DomainError> {
+        let Some(session) = command_handlers::load(aggregate_id, repo).await? else {
+            store.delete(NARRATIVE_SESSION_VIEWS, aggregate_id).await?;
+            return store
+                .delete(NARRATIVE_SESSION_SUMMARIES, aggregate_id)
+                .await;
+        };
+
+        put_document(
+            store,
+            NARRATIVE_SESSION_VIEWS,
+            aggregate_id,
+            &view(&session),
+        )
+        .await?;
+        if session.archived {
+            store
+                .delete(NARRATIVE_SESSION_SUMMARIES, aggregate_id)
+                .await
+        } else {
+            put_document(
+                store,
+                NARRATIVE_SESSION_SUMMARIES,
+                aggregate_id,
+                &summary(&session),
+            )
+            .await
+        }
+    }
+
+    async fn reset(&self, store: &dyn ReadModelStore) -> Result<(), DomainError> {
+        store.clear(NARRATIVE_SESSION_VIEWS).await?;
+        store.clear(NARRATIVE_SESSION_SUMMARIES).await
+    }
+}
+
+fn view(session: &NarrativeSession) -> NarrativeSessionView {
+    NarrativeSessionView {
+        session_id: session.id,
+        current_beat_id: session.current_beat_id,
+        choice_ids: session.choice_ids.clone(),
+        current_scene_id: session.current_scene_id.clone(),
+        scene_history: session.scene_history.clone(),
+        active_choice_options: session.active_choice_options.clone(),
+        version: session.version,
+    }
+}
+
+fn summary(session: &NarrativeSession) -> NarrativeSessionSummary {
+    NarrativeSessionSummary {
+        session_id: session.id,
+        current_beat_id: session.current_beat_id,
+        current_scene_id: session.current_scene_id.clone(),
+        version: session.version,
+    }
+}
@@ -1,18 +1,23 @@
 //! Query handlers for the Narrative Orchestration context.
 //!
-//! This module contains query handlers that reconstitute aggregates
-//! from stored events and return read-only view DTOs.
+//! This module contains query handlers that bring the narrative session projection
+//! up to date and return read-only view DTOs from its read-model tables.
 
 use otherworlds_core::error::DomainError;
+use otherworlds_core::projection::{
+    ProjectionRunner, ReadModelStore, get_document, list_documents,
+};
 use otherworlds_core::repository::EventRepository;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use crate::application::command_handlers;
+use crate::application::projections::{
+    NARRATIVE_SESSION_SUMMARIES, NARRATIVE_SESSION_VIEWS, NarrativeSessionProjection,
+};
 use crate::domain::value_objects::ChoiceOption;
 
 /// Read-only view of a narrative session aggregate.
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct NarrativeSessionView {
     /// The session identifier.
     pub session_id: Uuid,
@@ -30,17 +35,8 @@ pub struct NarrativeSessionView {
     pub version: i64,
 }
 
-/// Event types used by the Narrative Orchestration context.
-const EVENT_TYPES: &[&str] = &[
-    "narrative.beat_advanced",
-    "narrative.choice_presented",
-    "narrative.choice_selected",
-    "narrative.scene_started",
-    "narrative.session_archived",
-];
-
 /// Summary view for listing narrative sessions.
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct NarrativeSessionSummary {
     /// The session identifier.
     pub session_id: Uuid,
@@ -52,59 +48,40 @@ pub struct NarrativeSessionSummary {
     pub version: i64,
 }
 
-/// Lists all narrative sessions.
+/// Lists all narrative sessions that have not been archived.
 ///
 /// # Errors
 ///
-/// Returns `DomainError::Infrastructure` if querying or deserialization fails.
+/// Returns `DomainError::Infrastructure` if catching up the projection or
+/// reading the read model fails.
 pub async fn list_sessions(
     repo: &dyn EventRepository,
+    read_models: &dyn ReadModelStore,
 ) -> Result<Vec<NarrativeSessionSummary>, DomainError> {
-    let ids = repo.list_aggregate_ids(EVENT_TYPES).await?;
-    let mut summaries = Vec::with_capacity(ids.len());
-    for id in ids {
-        let stored_events = repo.load_events(id).await?;
-        if stored_events.is_empty() {
-            continue;
-        }
-        let session = command_handlers::reconstitute(id, &stored_events)?;
-        if session.archived {
-            continue;
-        }
-        summaries.push(NarrativeSessionSummary {
-            session_id: id,
-            current_beat_id: session.current_beat_id,
-            current_scene_id: session.current_scene_id.clone(),
-            version: session.version,
-        });
-    }
-    Ok(summaries)
+    ProjectionRunner::default()
+        .catch_up(&NarrativeSessionProjection, repo, read_models)
+        .await?;
+    list_documents(read_models, NARRATIVE_SESSION_SUMMARIES).await
 }
 
 /// Retrieves a narrative session by its aggregate ID.
 ///
 /// # Errors
 ///
-/// Returns `DomainError::AggregateNotFound` if no events exist for the ID.
-/// Returns `DomainError::Infrastructure` if event deserialization fails.
+/// Returns `DomainError::AggregateNotFound` if the narrative session has no view.
+/// Returns `DomainError::Infrastructure` if catching up the projection or
+/// reading the read model fails.
 pub async fn get_session_by_id(
     session_id: Uuid,
     repo: &dyn EventRepository,
+    read_models: &dyn ReadModelStore,
 ) -> Result<NarrativeSessionView, DomainError> {
-    let stored_events = repo.load_events(session_id).await?;
-    if stored_events.is_empty() {
-        return Err(DomainError::AggregateNotFound(session_id));
-    }
-    let session = command_handlers::reconstitute(session_id, &stored_events)?;
-    Ok(NarrativeSessionView {
-        session_id,
-        current_beat_id: session.current_beat_id,
-        choice_ids: session.choice_ids.clone(),
-        current_scene_id: session.current_scene_id.clone(),
-        scene_history: session.scene_history.clone(),
-        active_choice_options: session.active_choice_options.clone(),
-        version: session.version,
-    })
+    ProjectionRunner::default()
+        .catch_up(&NarrativeSessionProjection, repo, read_models)
+        .await?;
+    get_document(read_models, NARRATIVE_SESSION_VIEWS, session_id)
+        .await?
+        .ok_or(DomainError::AggregateNotFound(session_id))
 }
 
 #[cfg(test)]
@@ -117,7 +94,9 @@ mod tests {
     use crate::application::query_handlers::{get_session_by_id, list_sessions};
     use crate::domain::events::{BeatAdvanced, NarrativeEventKind, SceneStarted, SessionArchived};
     use crate::domain::value_objects::ChoiceOption;
-    use otherworlds_test_support::{EmptyEventRepository, RecordingEventRepository};
+    use otherworlds_test_support::{
+        EmptyEventRepository, InMemoryReadModelStore, RecordingEventRepository,
+    };
 
     #[tokio::test]
     async fn test_get_session_by_id_returns_view_with_state() {
@@ -139,11 +118,14 @@ mod tests {
             correlation_id: Uuid::new_v4(),
             causation_id: Uuid::new_v4(),
             occurred_at: fixed_now,
+            schema_version: 1,
         }];
         let repo = RecordingEventRepository::new(Ok(events));
 
         // Act
-        let view = get_session_by_id(session_id, &repo).await.unwrap();
+        let view = get_session_by_id(session_id, &repo, &InMemoryReadModelStore::new())
+            .await
+            .unwrap();
 
         // Assert
         assert_eq!(view.session_id, session_id);
@@ -159,7 +141,7 @@ mod tests {
         let repo = EmptyEventRepository;
 
         // Act
-        let result = get_session_by_id(session_id, &repo).await;
+        let result = get_session_by_id(session_id, &repo, &InMemoryReadModelStore::new()).await;
 
         // Assert
         assert!(result.is_err());
@@ -175,7 +157,9 @@ mod tests {
         let repo = EmptyEventRepository;
 
         // Act
-        let result = list_sessions(&repo).await.unwrap();
+        let result = list_sessions(&repo, &InMemoryReadModelStore::new())
+            .await
+            .unwrap();
 
         // Assert
         assert!(result.is_empty());
@@ -201,11 +185,14 @@ mod tests {
             correlation_id: Uuid::new_v4(),
             causation_id: Uuid::new_v4(),
             occurred_at: fixed_now,
+            schema_version: 1,
         }];
         let repo = RecordingEventRepository::with_aggregate_ids(Ok(events), vec![session_id]);
 
         // Act
-        let result = list_sessions(&repo).await.unwrap();
+        let result = list_sessions(&repo, &InMemoryReadModelStore::new())
+            .await
+            .unwrap();
 
         // Assert
         assert_eq!(result.len(), 1);
@@ -234,6 +221,7 @@ mod tests {
                 correlation_id: Uuid::new_v4(),
                 causation_id: Uuid::new_v4(),
                 occurred_at: fixed_now,
+                schema_version: 1,
             },
             StoredEvent {
                 event_id: Uuid::new_v4(),
@@ -247,11 +235,14 @@ mod tests {
                 correlation_id: Uuid::new_v4(),
                 causation_id: Uuid::new_v4(),
                 occurred_at: fixed_now,
+                schema_version: 1,
             },
         ];
         let repo = RecordingEventRepository::with_aggregate_ids(Ok(events), vec![session_id]);
 
-        let result = list_sessions(&repo).await.unwrap();
+        let result = list_sessions(&repo, &InMemoryReadModelStore::new())
+            .await
+            .unwrap();
 
         assert!(result.is_empty());
     }
@@ -283,11 +274,14 @@ mod tests {
             correlation_id: Uuid::new_v4(),
             causation_id: Uuid::new_v4(),
             occurred_at: fixed_now,
+            schema_version: 1,
         }];
         let repo = RecordingEventRepository::new(Ok(events));
 
         // Act
-        let view = get_session_by_id(session_id, &repo).await.unwrap();
+        let view = get_session_by_id(session_id, &repo, &InMemoryReadModelStore::new())
+            .await
+            .unwrap();
 
         // Assert
         assert_eq!(view.current_scene_id, Some("tavern".to_owned()));
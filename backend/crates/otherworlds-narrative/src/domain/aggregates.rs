@@ -5,16 +5,19 @@ use otherworlds_core::clock::Clock;
 use otherworlds_core::error::DomainError;
 use otherworlds_core::event::EventMetadata;
 use otherworlds_core::rng::DeterministicRng;
+use otherworlds_core::snapshot::Snapshottable;
+use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 use super::events::{
     BeatAdvanced, ChoicePresented, ChoiceSelected, NarrativeEvent, NarrativeEventKind,
     SceneStarted, SessionArchived,
 };
+use super::upcasters::current_schema_version;
 use super::value_objects::{ChoiceOption, SceneData};
 
 /// The aggregate root for a narrative session.
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct NarrativeSession {
     /// Aggregate identifier.
     pub id: Uuid,
@@ -33,6 +36,7 @@ pub struct NarrativeSession {
     /// Whether this session has been archived (soft-deleted).
     pub(crate) archived: bool,
     /// Uncommitted events pending persistence.
+    #[serde(skip)]
     uncommitted_events: Vec<NarrativeEvent>,
 }
 
@@ -75,6 +79,7 @@ impl NarrativeSession {
                 correlation_id,
                 causation_id: correlation_id,
                 occurred_at: clock.now(),
+                schema_version: current_schema_version("narrative.beat_advanced"),
             },
             kind: NarrativeEventKind::BeatAdvanced(BeatAdvanced {
                 session_id: self.id,
@@ -101,6 +106,7 @@ impl NarrativeSession {
                 correlation_id,
                 causation_id: correlation_id,
                 occurred_at: clock.now(),
+                schema_version: current_schema_version("narrative.choice_presented"),
             },
             kind: NarrativeEventKind::ChoicePresented(ChoicePresented {
                 session_id: self.id,
@@ -136,6 +142,7 @@ impl NarrativeSession {
                 correlation_id,
                 causation_id: correlation_id,
                 occurred_at: clock.now(),
+                schema_version: current_schema_version("narrative.scene_started"),
             },
             kind: NarrativeEventKind::SceneStarted(SceneStarted {
                 session_id: self.id,
@@ -195,6 +202,7 @@ impl NarrativeSession {
                 correlation_id,
                 causation_id: correlation_id,
                 occurred_at: clock.now(),
+                schema_version: current_schema_version("narrative.choice_selected"),
             },
             kind: NarrativeEventKind::ChoiceSelected(ChoiceSelected {
                 session_id: self.id,
@@ -214,6 +222,7 @@ impl NarrativeSession {
                 correlation_id,
                 causation_id: correlation_id,
                 occurred_at: clock.now(),
+                schema_version: current_schema_version("narrative.scene_started"),
             },
             kind: NarrativeEventKind::SceneStarted(SceneStarted {
                 session_id: self.id,
@@ -254,6 +263,7 @@ impl NarrativeSession {
                 correlation_id,
                 causation_id: correlation_id,
                 occurred_at: clock.now(),
+                schema_version: current_schema_version("narrative.session_archived"),
             },
             kind: NarrativeEventKind::SessionArchived(SessionArchived {
                 session_id: self.id,
@@ -308,6 +318,10 @@ impl AggregateRoot for NarrativeSession {
     }
 }
 
+impl Snapshottable for NarrativeSession {
+    const AGGREGATE_TYPE: &'static str = "narrative.narrative_session";
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -367,6 +381,7 @@ mod tests {
                 correlation_id: Uuid::new_v4(),
                 causation_id: Uuid::new_v4(),
                 occurred_at: fixed_now,
+                schema_version: current_schema_version("narrative.beat_advanced"),
             },
             kind: NarrativeEventKind::BeatAdvanced(BeatAdvanced {
                 session_id,
@@ -398,6 +413,7 @@ mod tests {
                 correlation_id: Uuid::new_v4(),
                 causation_id: Uuid::new_v4(),
                 occurred_at: fixed_now,
+                schema_version: current_schema_version("narrative.choice_presented"),
             },
             kind: NarrativeEventKind::ChoicePresented(ChoicePresented {
                 session_id,
@@ -500,6 +516,7 @@ mod tests {
                 correlation_id: Uuid::new_v4(),
                 causation_id: Uuid::new_v4(),
                 occurred_at: fixed_now,
+                schema_version: current_schema_version("narrative.session_archived"),
             },
             kind: NarrativeEventKind::SessionArchived(SessionArchived { session_id }),
         };
@@ -626,6 +643,7 @@ mod tests {
                 correlation_id: Uuid::new_v4(),
                 causation_id: Uuid::new_v4(),
                 occurred_at: fixed_now,
+                schema_version: current_schema_version("narrative.scene_started"),
             },
             kind: NarrativeEventKind::SceneStarted(SceneStarted {
                 session_id,
@@ -667,6 +685,7 @@ mod tests {
                 correlation_id: Uuid::new_v4(),
                 causation_id: Uuid::new_v4(),
                 occurred_at: fixed_now,
+                schema_version: current_schema_version("narrative.choice_selected"),
             },
             kind: NarrativeEventKind::ChoiceSelected(ChoiceSelected {
                 session_id,
@@ -1,12 +1,13 @@
 //! Commands for the Narrative Orchestration context.
 
 use otherworlds_core::command::Command;
+use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 use super::value_objects::SceneData;
 
 /// Command to advance the current narrative beat.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AdvanceBeat {
     /// The correlation ID for tracing.
     pub correlation_id: Uuid,
@@ -25,7 +26,7 @@ impl Command for AdvanceBeat {
 }
 
 /// Command to present a choice to the player.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PresentChoice {
     /// The correlation ID for tracing.
     pub correlation_id: Uuid,
@@ -44,7 +45,7 @@ impl Command for PresentChoice {
 }
 
 /// Command to enter a scene in the narrative session.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EnterScene {
     /// The correlation ID for tracing.
     pub correlation_id: Uuid,
@@ -65,7 +66,7 @@ impl Command for EnterScene {
 }
 
 /// Command to select a choice and transition to the next scene.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SelectChoice {
     /// The correlation ID for tracing.
     pub correlation_id: Uuid,
@@ -88,7 +89,7 @@ impl Command for SelectChoice {
 }
 
 /// Command to archive (soft-delete) a narrative session.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ArchiveSession {
     /// The correlation ID for tracing.
     pub correlation_id: Uuid,
@@ -3,4 +3,5 @@
 pub mod aggregates;
 pub mod commands;
 pub mod events;
+pub mod upcasters;
 pub mod value_objects;
@@ -0,0 +1,22 @@
+//! Upcasters for the Narrative Orchestration context.
+//!
+//! Register a step here whenever an event payload changes shape, so streams
+//! written with the previous shape still load.
+
+use std::sync::LazyLock;
+
+use otherworlds_core::upcasting::UpcasterRegistry;
+
+static REGISTRY: LazyLock<UpcasterRegistry> = LazyLock::new(UpcasterRegistry::new);
+
+/// Returns the upcaster registry for narrative events.
+#[must_use]
+pub fn registry() -> &'static UpcasterRegistry {
+    &REGISTRY
+}
+
+/// Returns the schema version newly produced events of `event_type` carry.
+#[must_use]
+pub fn current_schema_version(event_type: &str) -> i32 {
+    REGISTRY.current_version(event_type)
+}
@@ -3,6 +3,7 @@
 //! This module contains application-level command handler functions that
 //! orchestrate domain logic: load aggregate, execute command, persist events.
 
+use std::collections::BTreeMap;
 use std::sync::Mutex;
 
 use otherworlds_core::aggregate::AggregateRoot;
@@ -11,14 +12,20 @@ use otherworlds_core::error::DomainError;
 use otherworlds_core::event::DomainEvent;
 use otherworlds_core::repository::{EventRepository, StoredEvent};
 use otherworlds_core::rng::DeterministicRng;
+use otherworlds_core::snapshot::{load_with_snapshot, save_snapshot_if_due};
 use tracing::instrument;
 use uuid::Uuid;
 
-use crate::domain::aggregates::{DeclareIntentParams, Resolution};
+use crate::domain::aggregates::{DeclareIntentParams, DeclareOpposedIntentParams, Resolution};
 use crate::domain::commands::{
-    ArchiveResolution, DeclareIntent, EffectSpec, ProduceEffects, ResolveCheck,
+    ArchiveResolution, DeclareIntent, DeclareOpposedIntent, EffectSpec, ProduceEffects,
+    ResolveCheck,
 };
+use crate::domain::dice::{DiceExpression, DiceTerm};
 use crate::domain::events::{RulesEvent, RulesEventKind};
+use crate::domain::modifiers::{ModifierBreakdown, derive_modifier, sheet_stats};
+use crate::domain::ruleset::Ruleset;
+use crate::domain::upcasters;
 
 fn to_stored_event(event: &RulesEvent) -> StoredEvent {
     let meta = event.metadata();
@@ -31,6 +38,7 @@ fn to_stored_event(event: &RulesEvent) -> StoredEvent {
         correlation_id: meta.correlation_id,
         causation_id: meta.causation_id,
         occurred_at: meta.occurred_at,
+        schema_version: meta.schema_version,
     }
 }
 
@@ -43,9 +51,34 @@ pub(crate) fn reconstitute(
     resolution_id: Uuid,
     existing_events: &[StoredEvent],
 ) -> Result<Resolution, DomainError> {
-    let mut resolution = Resolution::new(resolution_id);
-    for stored in existing_events {
-        let kind: RulesEventKind = serde_json::from_value(stored.payload.clone()).map_err(|e| {
+    replay(Resolution::new(resolution_id), existing_events)
+}
+
+/// Loads a `Resolution`, starting from its latest snapshot when one is
+/// available and replaying only the events recorded after it.
+///
+/// Returns `None` if the aggregate has neither a snapshot nor any events.
+///
+/// # Errors
+///
+/// Returns `DomainError` if loading fails or event deserialization fails.
+pub(crate) async fn load(
+    resolution_id: Uuid,
+    repo: &dyn EventRepository,
+) -> Result<Option<Resolution>, DomainError> {
+    let (restored, events) = load_with_snapshot::<Resolution>(repo, resolution_id).await?;
+    match restored {
+        Some(resolution) => replay(resolution, &events).map(Some),
+        None if events.is_empty() => Ok(None),
+        None => reconstitute(resolution_id, &events).map(Some),
+    }
+}
+
+/// Applies stored events on top of an existing `Resolution` state.
+fn replay(mut resolution: Resolution, events: &[StoredEvent]) -> Result<Resolution, DomainError> {
+    for stored in events {
+        let payload = upcasters::registry().upcast(stored)?;
+        let kind: RulesEventKind = serde_json::from_value(payload).map_err(|e| {
             DomainError::Infrastructure(format!("event deserialization failed: {e}"))
         })?;
         let event = RulesEvent {
@@ -57,6 +90,7 @@ pub(crate) fn reconstitute(
                 correlation_id: stored.correlation_id,
                 causation_id: stored.causation_id,
                 occurred_at: stored.occurred_at,
+                schema_version: upcasters::current_schema_version(&stored.event_type),
             },
             kind,
         };
@@ -65,6 +99,48 @@ pub(crate) fn reconstitute(
     Ok(resolution)
 }
 
+/// Returns the intent's modifier: derived from the actor's sheet when the
+/// command names an actor, otherwise the one the command carries.
+fn derived_modifier(
+    command: &DeclareIntent,
+) -> Result<(i32, Option<ModifierBreakdown>), DomainError> {
+    let Some(actor) = &command.actor else {
+        return Ok((command.modifier, None));
+    };
+    if command.modifier != 0 {
+        return Err(DomainError::Validation(
+            "modifier is derived from the actor's sheet and must not be given".into(),
+        ));
+    }
+    let skill = command.skill.as_deref().ok_or_else(|| {
+        DomainError::Validation("a skill or attribute is required to derive the modifier".into())
+    })?;
+    let breakdown = derive_modifier(actor, skill, command.ruleset.ruleset())?;
+    Ok((breakdown.total, Some(breakdown)))
+}
+
+/// Checks that a character's roll is the ruleset's own: its default roll,
+/// optionally plus one `@stat` read from the sheet. A character's bonuses
+/// come from its sheet, so constants, extra dice, and repeated stats are
+/// rejected.
+fn check_actor_roll(
+    roll_expression: &DiceExpression,
+    ruleset: &dyn Ruleset,
+) -> Result<(), DomainError> {
+    let default_roll = ruleset.default_roll();
+    let (stats, dice): (Vec<&DiceTerm>, Vec<&DiceTerm>) = roll_expression
+        .terms()
+        .iter()
+        .partition(|term| matches!(term, DiceTerm::Stat { .. }));
+    if stats.len() <= 1 && dice.into_iter().eq(default_roll.terms()) {
+        return Ok(());
+    }
+    Err(DomainError::Validation(format!(
+        "roll expression '{roll_expression}' must be {default_roll} plus at most one @stat; \
+         a character's check takes its bonuses from the sheet"
+    )))
+}
+
 /// Handles the `DeclareIntent` command: reconstitutes the aggregate, declares
 /// the intent, and persists the resulting events.
 ///
@@ -78,13 +154,30 @@ pub async fn handle_declare_intent(
     rng: &Mutex<dyn DeterministicRng + Send>,
     repo: &dyn EventRepository,
 ) -> Result<Vec<StoredEvent>, DomainError> {
-    let existing_events = repo.load_events(command.resolution_id).await?;
-    let mut resolution = reconstitute(command.resolution_id, &existing_events)?;
+    let mut resolution = load(command.resolution_id, repo)
+        .await?
+        .unwrap_or_else(|| Resolution::new(command.resolution_id));
 
     if resolution.archived {
         return Err(DomainError::Validation("resolution is archived".into()));
     }
 
+    let roll_expression = match &command.roll_expression {
+        Some(notation) => notation.parse::<DiceExpression>()?,
+        None => command.ruleset.ruleset().default_roll(),
+    };
+    let (modifier, modifier_breakdown) = derived_modifier(command)?;
+    let stats = if let Some(actor) = &command.actor {
+        check_actor_roll(&roll_expression, command.ruleset.ruleset())?;
+        sheet_stats(actor, &roll_expression, command.ruleset.ruleset())?
+    } else if let Some(name) = roll_expression.stat_names().next() {
+        return Err(DomainError::Validation(format!(
+            "@{name} is read from the acting character's sheet, and no character is given"
+        )));
+    } else {
+        BTreeMap::new()
+    };
+
     {
         let mut rng_guard = rng
             .lock()
@@ -96,7 +189,72 @@ pub async fn handle_declare_intent(
                 skill: command.skill.clone(),
                 target_id: command.target_id,
                 difficulty_class: command.difficulty_class,
-                modifier: command.modifier,
+                modifier,
+                roll_expression,
+                stats,
+                roll_mode: command.roll_mode,
+                ruleset: command.ruleset,
+                modifier_breakdown,
+            },
+            command.correlation_id,
+            clock,
+            &mut *rng_guard,
+        )?;
+    }
+
+    let stored_events: Vec<StoredEvent> = resolution
+        .uncommitted_events()
+        .iter()
+        .map(to_stored_event)
+        .collect();
+
+    repo.append_events(command.resolution_id, resolution.version, &stored_events)
+        .await?;
+    save_snapshot_if_due(repo, resolution, clock).await;
+
+    Ok(stored_events)
+}
+
+/// Handles the `DeclareOpposedIntent` command: reconstitutes the aggregate,
+/// declares the contested intent, and persists the resulting events.
+///
+/// # Errors
+///
+/// Returns `DomainError` if event loading, validation, or appending fails.
+#[instrument(skip(clock, rng, repo), fields(resolution_id = %command.resolution_id, correlation_id = %command.correlation_id))]
+pub async fn handle_declare_opposed_intent(
+    command: &DeclareOpposedIntent,
+    clock: &dyn Clock,
+    rng: &Mutex<dyn DeterministicRng + Send>,
+    repo: &dyn EventRepository,
+) -> Result<Vec<StoredEvent>, DomainError> {
+    let mut resolution = load(command.resolution_id, repo)
+        .await?
+        .unwrap_or_else(|| Resolution::new(command.resolution_id));
+
+    if resolution.archived {
+        return Err(DomainError::Validation("resolution is archived".into()));
+    }
+
+    let roll_expression = match &command.roll_expression {
+        Some(notation) => notation.parse::<DiceExpression>()?,
+        None => command.ruleset.ruleset().default_roll(),
+    };
+
+    {
+        let mut rng_guard = rng
+            .lock()
+            .map_err(|e| DomainError::Infrastructure(format!("RNG mutex poisoned: {e}")))?;
+        resolution.declare_opposed_intent(
+            DeclareOpposedIntentParams {
+                intent_id: command.intent_id,
+                action_type: command.action_type.clone(),
+                skill: command.skill.clone(),
+                actor: command.actor,
+                opponent: command.opponent,
+                roll_expression,
+                ruleset: command.ruleset,
+                tie_break: command.tie_break,
             },
             command.correlation_id,
             clock,
@@ -112,6 +270,7 @@ pub async fn handle_declare_intent(
 
     repo.append_events(command.resolution_id, resolution.version, &stored_events)
         .await?;
+    save_snapshot_if_due(repo, resolution, clock).await;
 
     Ok(stored_events)
 }
@@ -132,8 +291,9 @@ pub async fn handle_resolve_check(
     rng: &Mutex<dyn DeterministicRng + Send>,
     repo: &dyn EventRepository,
 ) -> Result<Vec<StoredEvent>, DomainError> {
-    let existing_events = repo.load_events(command.resolution_id).await?;
-    let mut resolution = reconstitute(command.resolution_id, &existing_events)?;
+    let mut resolution = load(command.resolution_id, repo)
+        .await?
+        .unwrap_or_else(|| Resolution::new(command.resolution_id));
 
     if resolution.archived {
         return Err(DomainError::Validation("resolution is archived".into()));
@@ -155,6 +315,7 @@ pub async fn handle_resolve_check(
 
     repo.append_events(command.resolution_id, resolution.version, &stored_events)
         .await?;
+    save_snapshot_if_due(repo, resolution, clock).await;
 
     Ok(stored_events)
 }
@@ -172,25 +333,60 @@ pub async fn handle_produce_effects(
     rng: &Mutex<dyn DeterministicRng + Send>,
     repo: &dyn EventRepository,
 ) -> Result<Vec<StoredEvent>, DomainError> {
-    let existing_events = repo.load_events(command.resolution_id).await?;
-    let mut resolution = reconstitute(command.resolution_id, &existing_events)?;
+    let mut resolution = load(command.resolution_id, repo)
+        .await?
+        .unwrap_or_else(|| Resolution::new(command.resolution_id));
 
     if resolution.archived {
         return Err(DomainError::Validation("resolution is archived".into()));
     }
 
+    let resolve = |label: String, spec: EffectSpec| {
+        spec.into_resolved_effect(&command.custom_effects)
+            .map_err(|e| match e {
+                DomainError::Validation(msg) => DomainError::Validation(format!("{label}: {msg}")),
+                other => other,
+            })
+    };
+    let table = match &command.outcome_effects {
+        Some(_) if !command.effects.is_empty() => {
+            return Err(DomainError::Validation(
+                "give either effects or outcome_effects, not both".into(),
+            ));
+        }
+        Some(table) => Some(
+            table
+                .clone()
+                .try_map(|tier, index, spec| resolve(format!("{tier} effect {index}"), spec))?,
+        ),
+        None => None,
+    };
     let effects = command
         .effects
         .iter()
         .cloned()
-        .map(EffectSpec::into_resolved_effect)
-        .collect();
+        .enumerate()
+        .map(|(index, spec)| resolve(format!("effect {index}"), spec))
+        .collect::<Result<Vec<_>, _>>()?;
 
     {
         let mut rng_guard = rng
             .lock()
             .map_err(|e| DomainError::Infrastructure(format!("RNG mutex poisoned: {e}")))?;
-        resolution.produce_effects(effects, command.correlation_id, clock, &mut *rng_guard)?;
+        match &table {
+            Some(table) => resolution.produce_outcome_effects(
+                table,
+                command.correlation_id,
+                clock,
+                &mut *rng_guard,
+            )?,
+            None => resolution.produce_effects(
+                effects,
+                command.correlation_id,
+                clock,
+                &mut *rng_guard,
+            )?,
+        }
     }
 
     let stored_events: Vec<StoredEvent> = resolution
@@ -201,6 +397,7 @@ pub async fn handle_produce_effects(
 
     repo.append_events(command.resolution_id, resolution.version, &stored_events)
         .await?;
+    save_snapshot_if_due(repo, resolution, clock).await;
 
     Ok(stored_events)
 }
@@ -220,11 +417,9 @@ pub async fn handle_archive_resolution(
     rng: &Mutex<dyn DeterministicRng + Send>,
     repo: &dyn EventRepository,
 ) -> Result<Vec<StoredEvent>, DomainError> {
-    let existing_events = repo.load_events(command.resolution_id).await?;
-    if existing_events.is_empty() {
-        return Err(DomainError::AggregateNotFound(command.resolution_id));
-    }
-    let mut resolution = reconstitute(command.resolution_id, &existing_events)?;
+    let mut resolution = load(command.resolution_id, repo)
+        .await?
+        .ok_or(DomainError::AggregateNotFound(command.resolution_id))?;
 
     {
         let mut rng_guard = rng
@@ -241,12 +436,15 @@ pub async fn handle_archive_resolution(
 
     repo.append_events(command.resolution_id, resolution.version, &stored_events)
         .await?;
+    save_snapshot_if_due(repo, resolution, clock).await;
 
     Ok(stored_events)
 }
 
 #[cfg(test)]
 mod tests {
+    use std::collections::BTreeMap;
+
     use chrono::{TimeZone, Utc};
     use otherworlds_core::error::DomainError;
     use otherworlds_core::repository::StoredEvent;
@@ -255,15 +453,22 @@ mod tests {
     use uuid::Uuid;
 
     use crate::application::command_handlers::{
-        handle_archive_resolution, handle_declare_intent, handle_produce_effects,
-        handle_resolve_check, reconstitute,
+        handle_archive_resolution, handle_declare_intent, handle_declare_opposed_intent,
+        handle_produce_effects, handle_resolve_check, reconstitute,
     };
     use crate::domain::commands::{
-        ArchiveResolution, DeclareIntent, EffectSpec, ProduceEffects, ResolveCheck,
+        ArchiveResolution, DeclareIntent, DeclareOpposedIntent, EffectSpec, ProduceEffects,
+        ResolveCheck,
     };
+    use crate::domain::dice::DiceExpression;
+    use crate::domain::effects::{CustomEffectSchema, Damage, Effect, Heal};
     use crate::domain::events::{
-        CheckOutcome, CheckResolved, IntentDeclared, ResolutionArchived, RulesEventKind,
+        CheckOutcome, CheckResolved, IntentDeclared, OpposedParticipant, ResolutionArchived,
+        RollMode, RulesEventKind, TieBreak,
     };
+    use crate::domain::modifiers::ActorSheet;
+    use crate::domain::outcome_table::{OutcomeBranch, OutcomeTable};
+    use crate::domain::ruleset::RulesetId;
     use otherworlds_test_support::{
         EmptyEventRepository, FixedClock, MockRng, RecordingEventRepository, SequenceRng,
     };
@@ -289,6 +494,10 @@ mod tests {
             target_id: None,
             difficulty_class: 15,
             modifier: 3,
+            actor: None,
+            roll_expression: None,
+            roll_mode: RollMode::Normal,
+            ruleset: RulesetId::D20,
         };
 
         let rng: Mutex<MockRng> = Mutex::new(MockRng);
@@ -312,6 +521,195 @@ mod tests {
         assert_eq!(stored.correlation_id, correlation_id);
     }
 
+    #[tokio::test]
+    async fn test_handle_declare_intent_with_invalid_roll_expression_returns_validation_error() {
+        let repo = RecordingEventRepository::new(Ok(Vec::new()));
+        let command = DeclareIntent {
+            correlation_id: Uuid::new_v4(),
+            resolution_id: Uuid::new_v4(),
+            intent_id: Uuid::new_v4(),
+            action_type: "attack".to_owned(),
+            skill: None,
+            target_id: None,
+            difficulty_class: 12,
+            modifier: 0,
+            actor: None,
+            roll_expression: Some("2d6*3".to_owned()),
+            roll_mode: RollMode::Normal,
+            ruleset: RulesetId::D20,
+        };
+
+        let rng: Mutex<MockRng> = Mutex::new(MockRng);
+        let rng_ref: &Mutex<dyn DeterministicRng + Send> = &rng;
+
+        let result = handle_declare_intent(&command, &fixed_clock(), rng_ref, &repo).await;
+
+        assert!(matches!(result, Err(DomainError::Validation(_))));
+        assert!(repo.appended_events().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_handle_declare_intent_without_expression_rolls_ruleset_default() {
+        let repo = RecordingEventRepository::new(Ok(Vec::new()));
+        let command = DeclareIntent {
+            correlation_id: Uuid::new_v4(),
+            resolution_id: Uuid::new_v4(),
+            intent_id: Uuid::new_v4(),
+            action_type: "act_under_pressure".to_owned(),
+            skill: None,
+            target_id: None,
+            difficulty_class: 0,
+            modifier: 1,
+            actor: None,
+            roll_expression: None,
+            roll_mode: RollMode::Normal,
+            ruleset: RulesetId::Pbta,
+        };
+
+        let rng: Mutex<MockRng> = Mutex::new(MockRng);
+        let rng_ref: &Mutex<dyn DeterministicRng + Send> = &rng;
+
+        handle_declare_intent(&command, &fixed_clock(), rng_ref, &repo)
+            .await
+            .unwrap();
+
+        let appended = repo.appended_events();
+        match serde_json::from_value::<RulesEventKind>(appended[0].2[0].payload.clone()).unwrap() {
+            RulesEventKind::IntentDeclared(intent) => {
+                assert_eq!(intent.roll_expression.to_string(), "2d6");
+                assert_eq!(intent.ruleset, RulesetId::Pbta);
+            }
+            other => panic!("expected IntentDeclared, got {other:?}"),
+        }
+    }
+
+    fn declare_intent_by_actor(skill: Option<&str>, modifier: i32) -> DeclareIntent {
+        DeclareIntent {
+            correlation_id: Uuid::new_v4(),
+            resolution_id: Uuid::new_v4(),
+            intent_id: Uuid::new_v4(),
+            action_type: "skill_check".to_owned(),
+            skill: skill.map(str::to_owned),
+            target_id: None,
+            difficulty_class: 15,
+            modifier,
+            actor: Some(ActorSheet {
+                character_id: Uuid::from_u128(7),
+                attributes: BTreeMap::from([("wisdom".to_owned(), 14)]),
+                proficiencies: ["perception".to_owned()].into(),
+                equipment: Vec::new(),
+            }),
+            roll_expression: None,
+            roll_mode: RollMode::Normal,
+            ruleset: RulesetId::D20,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_handle_declare_intent_derives_modifier_from_actor_sheet() {
+        let repo = RecordingEventRepository::new(Ok(Vec::new()));
+        let command = declare_intent_by_actor(Some("perception"), 0);
+        let rng: Mutex<MockRng> = Mutex::new(MockRng);
+        let rng_ref: &Mutex<dyn DeterministicRng + Send> = &rng;
+
+        handle_declare_intent(&command, &fixed_clock(), rng_ref, &repo)
+            .await
+            .unwrap();
+
+        let appended = repo.appended_events();
+        match serde_json::from_value::<RulesEventKind>(appended[0].2[0].payload.clone()).unwrap() {
+            RulesEventKind::IntentDeclared(intent) => {
+                // +2 wisdom, +2 proficiency.
+                assert_eq!(intent.modifier, 4);
+                let breakdown = intent.modifier_breakdown.unwrap();
+                assert_eq!(breakdown.character_id, Uuid::from_u128(7));
+                assert_eq!(breakdown.attribute, "wisdom");
+                assert_eq!(breakdown.components.len(), 2);
+                assert_eq!(breakdown.total, 4);
+            }
+            other => panic!("expected IntentDeclared, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_handle_declare_intent_by_actor_rejects_modifier_or_missing_skill() {
+        let repo = RecordingEventRepository::new(Ok(Vec::new()));
+        let rng: Mutex<MockRng> = Mutex::new(MockRng);
+        let rng_ref: &Mutex<dyn DeterministicRng + Send> = &rng;
+
+        for command in [
+            declare_intent_by_actor(Some("perception"), 3),
+            declare_intent_by_actor(None, 0),
+        ] {
+            let result = handle_declare_intent(&command, &fixed_clock(), rng_ref, &repo).await;
+            assert!(matches!(result, Err(DomainError::Validation(_))));
+        }
+        assert!(repo.appended_events().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_handle_declare_intent_reads_stats_from_actor_sheet() {
+        let repo = RecordingEventRepository::new(Ok(Vec::new()));
+        let mut command = declare_intent_by_actor(Some("perception"), 0);
+        command.roll_expression = Some("1d20+@wisdom".to_owned());
+        let rng: Mutex<MockRng> = Mutex::new(MockRng);
+        let rng_ref: &Mutex<dyn DeterministicRng + Send> = &rng;
+
+        handle_declare_intent(&command, &fixed_clock(), rng_ref, &repo)
+            .await
+            .unwrap();
+
+        let appended = repo.appended_events();
+        match serde_json::from_value::<RulesEventKind>(appended[0].2[0].payload.clone()).unwrap() {
+            // Wisdom 14 is +2 under d20.
+            RulesEventKind::IntentDeclared(intent) => {
+                assert_eq!(intent.stats, BTreeMap::from([("wisdom".to_owned(), 2)]));
+            }
+            other => panic!("expected IntentDeclared, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_handle_declare_intent_rejects_stats_without_actor() {
+        let repo = RecordingEventRepository::new(Ok(Vec::new()));
+        let mut command = declare_intent_by_actor(Some("perception"), 0);
+        command.actor = None;
+        command.roll_expression = Some("1d20+@wisdom".to_owned());
+        let rng: Mutex<MockRng> = Mutex::new(MockRng);
+        let rng_ref: &Mutex<dyn DeterministicRng + Send> = &rng;
+
+        let result = handle_declare_intent(&command, &fixed_clock(), rng_ref, &repo).await;
+
+        assert!(matches!(result, Err(DomainError::Validation(msg)) if msg.contains("@wisdom")));
+        assert!(repo.appended_events().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_handle_declare_intent_rejects_bonuses_beyond_the_sheet_with_actor() {
+        let repo = RecordingEventRepository::new(Ok(Vec::new()));
+        let rng: Mutex<MockRng> = Mutex::new(MockRng);
+        let rng_ref: &Mutex<dyn DeterministicRng + Send> = &rng;
+
+        for notation in [
+            "1d20+15",
+            "1d20+15d1",
+            "1d20+1d6",
+            "1d20+@dexterity+@dexterity",
+            "2d20",
+        ] {
+            let mut command = declare_intent_by_actor(Some("perception"), 0);
+            command.roll_expression = Some(notation.to_owned());
+
+            let result = handle_declare_intent(&command, &fixed_clock(), rng_ref, &repo).await;
+
+            assert!(
+                matches!(&result, Err(DomainError::Validation(msg)) if msg.contains("at most one @stat")),
+                "{notation} was not rejected: {result:?}"
+            );
+        }
+        assert!(repo.appended_events().is_empty());
+    }
+
     #[tokio::test]
     async fn test_handle_declare_intent_with_existing_events_validates_phase() {
         let resolution_id = Uuid::new_v4();
@@ -330,12 +728,18 @@ mod tests {
                 target_id: None,
                 difficulty_class: 12,
                 modifier: 0,
+                roll_expression: DiceExpression::d20(),
+                stats: BTreeMap::new(),
+                roll_mode: RollMode::Normal,
+                ruleset: RulesetId::D20,
+                modifier_breakdown: None,
             }))
             .unwrap(),
             sequence_number: 1,
             correlation_id: Uuid::new_v4(),
             causation_id: Uuid::new_v4(),
             occurred_at: fixed_now,
+            schema_version: 1,
         }];
         let repo = RecordingEventRepository::new(Ok(existing));
 
@@ -348,6 +752,10 @@ mod tests {
             target_id: None,
             difficulty_class: 10,
             modifier: 0,
+            actor: None,
+            roll_expression: None,
+            roll_mode: RollMode::Normal,
+            ruleset: RulesetId::D20,
         };
 
         let rng: Mutex<MockRng> = Mutex::new(MockRng);
@@ -357,6 +765,88 @@ mod tests {
         assert!(result.is_err());
     }
 
+    fn declare_opposed_intent(resolution_id: Uuid) -> DeclareOpposedIntent {
+        DeclareOpposedIntent {
+            correlation_id: Uuid::new_v4(),
+            resolution_id,
+            intent_id: Uuid::new_v4(),
+            action_type: "grapple".to_owned(),
+            skill: Some("athletics".to_owned()),
+            actor: OpposedParticipant {
+                participant_id: None,
+                modifier: 4,
+                roll_mode: RollMode::Normal,
+            },
+            opponent: OpposedParticipant {
+                participant_id: None,
+                modifier: 2,
+                roll_mode: RollMode::Normal,
+            },
+            roll_expression: None,
+            ruleset: RulesetId::D20,
+            tie_break: TieBreak::Defender,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_handle_declare_opposed_intent_then_resolve_check_persists_both_rolls() {
+        // Arrange
+        let resolution_id = Uuid::new_v4();
+        let declare_repo = RecordingEventRepository::new(Ok(Vec::new()));
+        let rng: Mutex<SequenceRng> = Mutex::new(SequenceRng::new(vec![
+            1, 2, 3, 4, 11, 9, 42, 99, 7, 13, 0, 0, 0, 0,
+        ]));
+        let rng_ref: &Mutex<dyn DeterministicRng + Send> = &rng;
+        handle_declare_opposed_intent(
+            &declare_opposed_intent(resolution_id),
+            &fixed_clock(),
+            rng_ref,
+            &declare_repo,
+        )
+        .await
+        .unwrap();
+        let declared = declare_repo.appended_events()[0].2.clone();
+        let resolve_repo = RecordingEventRepository::new(Ok(declared));
+
+        // Act
+        let command = ResolveCheck {
+            correlation_id: Uuid::new_v4(),
+            resolution_id,
+        };
+        handle_resolve_check(&command, &fixed_clock(), rng_ref, &resolve_repo)
+            .await
+            .unwrap();
+
+        // Assert
+        let appended = resolve_repo.appended_events();
+        let stored = &appended[0].2[0];
+        assert_eq!(stored.event_type, "rules.opposed_check_resolved");
+        match serde_json::from_value::<RulesEventKind>(stored.payload.clone()).unwrap() {
+            RulesEventKind::OpposedCheckResolved(payload) => {
+                assert_eq!(payload.actor.total, 15);
+                assert_eq!(payload.opponent.total, 11);
+                assert_eq!(payload.margin, 4);
+                assert_eq!(payload.outcome, CheckOutcome::Success);
+            }
+            other => panic!("expected OpposedCheckResolved, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_handle_declare_opposed_intent_with_invalid_roll_expression_returns_validation_error()
+     {
+        let repo = RecordingEventRepository::new(Ok(Vec::new()));
+        let mut command = declare_opposed_intent(Uuid::new_v4());
+        command.roll_expression = Some("d".to_owned());
+        let rng: Mutex<MockRng> = Mutex::new(MockRng);
+        let rng_ref: &Mutex<dyn DeterministicRng + Send> = &rng;
+
+        let result = handle_declare_opposed_intent(&command, &fixed_clock(), rng_ref, &repo).await;
+
+        assert!(matches!(result, Err(DomainError::Validation(_))));
+        assert!(repo.appended_events().is_empty());
+    }
+
     #[tokio::test]
     async fn test_handle_resolve_check_persists_event() {
         let resolution_id = Uuid::new_v4();
@@ -376,12 +866,18 @@ mod tests {
                 target_id: None,
                 difficulty_class: 15,
                 modifier: 3,
+                roll_expression: DiceExpression::d20(),
+                stats: BTreeMap::new(),
+                roll_mode: RollMode::Normal,
+                ruleset: RulesetId::D20,
+                modifier_breakdown: None,
             }))
             .unwrap(),
             sequence_number: 1,
             correlation_id: Uuid::new_v4(),
             causation_id: Uuid::new_v4(),
             occurred_at: fixed_now,
+            schema_version: 1,
         }];
         let repo = RecordingEventRepository::new(Ok(existing));
         let rng: Mutex<SequenceRng> =
@@ -417,14 +913,10 @@ mod tests {
         assert!(result.is_err());
     }
 
-    #[tokio::test]
-    async fn test_handle_produce_effects_persists_event() {
-        let resolution_id = Uuid::new_v4();
-        let correlation_id = Uuid::new_v4();
+    /// Events leaving a resolution in the `CheckResolved` phase.
+    fn resolved_check_events(resolution_id: Uuid) -> Vec<StoredEvent> {
         let fixed_now = Utc.with_ymd_and_hms(2026, 1, 15, 10, 0, 0).unwrap();
-
-        // Pre-load IntentDeclared + CheckResolved so aggregate is in correct phase
-        let existing = vec![
+        vec![
             StoredEvent {
                 event_id: Uuid::new_v4(),
                 aggregate_id: resolution_id,
@@ -437,12 +929,18 @@ mod tests {
                     target_id: None,
                     difficulty_class: 15,
                     modifier: 3,
+                    roll_expression: DiceExpression::d20(),
+                    stats: BTreeMap::new(),
+                    roll_mode: RollMode::Normal,
+                    ruleset: RulesetId::D20,
+                    modifier_breakdown: None,
                 }))
                 .unwrap(),
                 sequence_number: 1,
                 correlation_id: Uuid::new_v4(),
                 causation_id: Uuid::new_v4(),
                 occurred_at: fixed_now,
+                schema_version: 1,
             },
             StoredEvent {
                 event_id: Uuid::new_v4(),
@@ -456,14 +954,26 @@ mod tests {
                     total: 18,
                     difficulty_class: 15,
                     outcome: CheckOutcome::Success,
+                    rolls: Vec::new(),
+                    kept_roll: 0,
                 }))
                 .unwrap(),
                 sequence_number: 2,
                 correlation_id: Uuid::new_v4(),
                 causation_id: Uuid::new_v4(),
                 occurred_at: fixed_now,
+                schema_version: 1,
             },
-        ];
+        ]
+    }
+
+    #[tokio::test]
+    async fn test_handle_produce_effects_persists_event() {
+        let resolution_id = Uuid::new_v4();
+        let correlation_id = Uuid::new_v4();
+
+        // Pre-load IntentDeclared + CheckResolved so aggregate is in correct phase
+        let existing = resolved_check_events(resolution_id);
         let repo = RecordingEventRepository::new(Ok(existing));
 
         let command = ProduceEffects {
@@ -471,9 +981,11 @@ mod tests {
             resolution_id,
             effects: vec![EffectSpec {
                 effect_type: "damage".to_owned(),
-                target_id: None,
+                target_id: Some(Uuid::new_v4()),
                 payload: serde_json::json!({ "amount": 8 }),
             }],
+            outcome_effects: None,
+            custom_effects: Vec::new(),
         };
 
         let rng: Mutex<MockRng> = Mutex::new(MockRng);
@@ -488,6 +1000,133 @@ mod tests {
         assert_eq!(stored.event_type, "rules.effects_produced");
     }
 
+    #[tokio::test]
+    async fn test_handle_produce_effects_rejects_invalid_effect_before_persisting() {
+        // Arrange
+        let resolution_id = Uuid::new_v4();
+        let repo = RecordingEventRepository::new(Ok(resolved_check_events(resolution_id)));
+        let command = ProduceEffects {
+            correlation_id: Uuid::new_v4(),
+            resolution_id,
+            effects: vec![
+                EffectSpec {
+                    effect_type: "heal".to_owned(),
+                    target_id: Some(Uuid::new_v4()),
+                    payload: serde_json::json!({ "amount": 4 }),
+                },
+                EffectSpec {
+                    effect_type: "custom".to_owned(),
+                    target_id: None,
+                    payload: serde_json::json!({
+                        "name": "summon_storm",
+                        "version": 1,
+                        "data": { "intensity": "high" }
+                    }),
+                },
+            ],
+            outcome_effects: None,
+            custom_effects: vec![CustomEffectSchema {
+                name: "summon_storm".to_owned(),
+                version: 1,
+                schema: serde_json::json!({
+                    "type": "object",
+                    "properties": { "intensity": { "type": "integer" } }
+                }),
+            }],
+        };
+        let rng: Mutex<MockRng> = Mutex::new(MockRng);
+        let rng_ref: &Mutex<dyn DeterministicRng + Send> = &rng;
+
+        // Act
+        let result = handle_produce_effects(&command, &fixed_clock(), rng_ref, &repo).await;
+
+        // Assert
+        match result {
+            Err(DomainError::Validation(msg)) => {
+                assert!(
+                    msg.starts_with("effect 1: custom effect 'summon_storm' v1"),
+                    "{msg}"
+                );
+            }
+            other => panic!("expected Validation, got {other:?}"),
+        }
+        assert!(repo.appended_events().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_handle_produce_effects_selects_the_outcome_branch() {
+        // Arrange — resolved_check_events rolls a success.
+        let resolution_id = Uuid::new_v4();
+        let repo = RecordingEventRepository::new(Ok(resolved_check_events(resolution_id)));
+        let heal = |amount: u32| EffectSpec {
+            effect_type: "heal".to_owned(),
+            target_id: Some(Uuid::from_u128(9)),
+            payload: serde_json::json!({ "amount": amount }),
+        };
+        let command = ProduceEffects {
+            correlation_id: Uuid::new_v4(),
+            resolution_id,
+            effects: vec![],
+            outcome_effects: Some(OutcomeTable {
+                failure: Some(OutcomeBranch::Effects(vec![heal(1)])),
+                success: Some(OutcomeBranch::Effects(vec![heal(6)])),
+                ..OutcomeTable::default()
+            }),
+            custom_effects: Vec::new(),
+        };
+        let rng: Mutex<MockRng> = Mutex::new(MockRng);
+        let rng_ref: &Mutex<dyn DeterministicRng + Send> = &rng;
+
+        // Act
+        handle_produce_effects(&command, &fixed_clock(), rng_ref, &repo)
+            .await
+            .unwrap();
+
+        // Assert
+        let appended = repo.appended_events();
+        let kind: RulesEventKind =
+            serde_json::from_value(appended[0].2[0].payload.clone()).unwrap();
+        let RulesEventKind::EffectsProduced(produced) = kind else {
+            panic!("expected EffectsProduced");
+        };
+        assert_eq!(produced.effects.len(), 1);
+        assert_eq!(produced.effects[0].effect, Effect::Heal(Heal { amount: 6 }));
+        let tier = produced.tier.unwrap();
+        assert_eq!(tier.branch, Some(tier.outcome));
+        assert_eq!(tier.strength_percent, 100);
+    }
+
+    #[tokio::test]
+    async fn test_handle_produce_effects_rejects_effects_alongside_an_outcome_table() {
+        // Arrange
+        let resolution_id = Uuid::new_v4();
+        let repo = RecordingEventRepository::new(Ok(resolved_check_events(resolution_id)));
+        let heal = EffectSpec {
+            effect_type: "heal".to_owned(),
+            target_id: Some(Uuid::from_u128(9)),
+            payload: serde_json::json!({ "amount": 2 }),
+        };
+        let command = ProduceEffects {
+            correlation_id: Uuid::new_v4(),
+            resolution_id,
+            effects: vec![heal.clone()],
+            outcome_effects: Some(OutcomeTable {
+                success: Some(OutcomeBranch::Effects(vec![heal])),
+                ..OutcomeTable::default()
+            }),
+            custom_effects: Vec::new(),
+        };
+        let rng: Mutex<MockRng> = Mutex::new(MockRng);
+        let rng_ref: &Mutex<dyn DeterministicRng + Send> = &rng;
+
+        // Act
+        let result = handle_produce_effects(&command, &fixed_clock(), rng_ref, &repo).await;
+
+        // Assert
+        assert!(matches!(result, Err(DomainError::Validation(_))));
+        assert!(repo.appended_events().is_empty());
+    }
+
     #[tokio::test]
     async fn test_handle_produce_effects_on_fresh_aggregate_returns_error() {
         let repo = RecordingEventRepository::new(Ok(Vec::new()));
@@ -496,6 +1135,8 @@ mod tests {
             correlation_id: Uuid::new_v4(),
             resolution_id: Uuid::new_v4(),
             effects: vec![],
+            outcome_effects: None,
+            custom_effects: Vec::new(),
         };
 
         let rng: Mutex<MockRng> = Mutex::new(MockRng);
@@ -523,12 +1164,18 @@ mod tests {
                 target_id: None,
                 difficulty_class: 12,
                 modifier: 2,
+                roll_expression: DiceExpression::d20(),
+                stats: BTreeMap::new(),
+                roll_mode: RollMode::Normal,
+                ruleset: RulesetId::D20,
+                modifier_breakdown: None,
             }))
             .unwrap(),
             sequence_number: 1,
             correlation_id: Uuid::new_v4(),
             causation_id: Uuid::new_v4(),
             occurred_at: fixed_now,
+            schema_version: 1,
         }];
 
         let resolution = reconstitute(resolution_id, &events).unwrap();
@@ -559,12 +1206,18 @@ mod tests {
                     target_id: None,
                     difficulty_class: 15,
                     modifier: 3,
+                    roll_expression: DiceExpression::d20(),
+                    stats: BTreeMap::new(),
+                    roll_mode: RollMode::Normal,
+                    ruleset: RulesetId::D20,
+                    modifier_breakdown: None,
                 }))
                 .unwrap(),
                 sequence_number: 1,
                 correlation_id: Uuid::new_v4(),
                 causation_id: Uuid::new_v4(),
                 occurred_at: fixed_now,
+                schema_version: 1,
             },
             StoredEvent {
                 event_id: Uuid::new_v4(),
@@ -578,12 +1231,15 @@ mod tests {
                     total: 21,
                     difficulty_class: 15,
                     outcome: CheckOutcome::Success,
+                    rolls: Vec::new(),
+                    kept_roll: 0,
                 }))
                 .unwrap(),
                 sequence_number: 2,
                 correlation_id: Uuid::new_v4(),
                 causation_id: Uuid::new_v4(),
                 occurred_at: fixed_now,
+                schema_version: 1,
             },
         ];
 
@@ -614,12 +1270,18 @@ mod tests {
                     target_id: None,
                     difficulty_class: 15,
                     modifier: 3,
+                    roll_expression: DiceExpression::d20(),
+                    stats: BTreeMap::new(),
+                    roll_mode: RollMode::Normal,
+                    ruleset: RulesetId::D20,
+                    modifier_breakdown: None,
                 }))
                 .unwrap(),
                 sequence_number: 1,
                 correlation_id: Uuid::new_v4(),
                 causation_id: Uuid::new_v4(),
                 occurred_at: fixed_now,
+                schema_version: 1,
             },
             StoredEvent {
                 event_id: Uuid::new_v4(),
@@ -633,12 +1295,15 @@ mod tests {
                     total: 18,
                     difficulty_class: 15,
                     outcome: CheckOutcome::Success,
+                    rolls: Vec::new(),
+                    kept_roll: 0,
                 }))
                 .unwrap(),
                 sequence_number: 2,
                 correlation_id: Uuid::new_v4(),
                 causation_id: Uuid::new_v4(),
                 occurred_at: fixed_now,
+                schema_version: 1,
             },
             StoredEvent {
                 event_id: Uuid::new_v4(),
@@ -648,10 +1313,13 @@ mod tests {
                     crate::domain::events::EffectsProduced {
                         resolution_id,
                         effects: vec![crate::domain::events::ResolvedEffect {
-                            effect_type: "damage".to_owned(),
                             target_id: None,
-                            payload: serde_json::json!({ "amount": 8 }),
+                            effect: Effect::Damage(Damage {
+                                amount: 8,
+                                damage_type: None,
+                            }),
                         }],
+                        tier: None,
                     },
                 ))
                 .unwrap(),
@@ -659,14 +1327,15 @@ mod tests {
                 correlation_id: Uuid::new_v4(),
                 causation_id: Uuid::new_v4(),
                 occurred_at: fixed_now,
+                schema_version: 1,
             },
         ];
 
         let resolution = reconstitute(resolution_id, &events).unwrap();
         assert_eq!(resolution.version, 3);
         assert_eq!(resolution.effects.len(), 1);
-        assert_eq!(resolution.effects[0].effect_type, "damage");
-        assert_eq!(resolution.effects[0].payload["amount"], 8);
+        assert_eq!(resolution.effects[0].effect.effect_type(), "damage");
+        assert_eq!(resolution.effects[0].effect.payload()["amount"], 8);
     }
 
     // --- archive handler tests ---
@@ -684,12 +1353,18 @@ mod tests {
                 target_id: None,
                 difficulty_class: 15,
                 modifier: 3,
+                roll_expression: DiceExpression::d20(),
+                stats: BTreeMap::new(),
+                roll_mode: RollMode::Normal,
+                ruleset: RulesetId::D20,
+                modifier_breakdown: None,
             }))
             .unwrap(),
             sequence_number: 1,
             correlation_id: Uuid::new_v4(),
             causation_id: Uuid::new_v4(),
             occurred_at: fixed_now,
+            schema_version: 1,
         }
     }
 
@@ -709,6 +1384,7 @@ mod tests {
             correlation_id: Uuid::new_v4(),
             causation_id: Uuid::new_v4(),
             occurred_at: fixed_now,
+            schema_version: 1,
         }
     }
 
@@ -813,6 +1489,10 @@ mod tests {
             target_id: None,
             difficulty_class: 15,
             modifier: 0,
+            actor: None,
+            roll_expression: None,
+            roll_mode: RollMode::Normal,
+            ruleset: RulesetId::D20,
         };
 
         let rng: Mutex<MockRng> = Mutex::new(MockRng);
@@ -867,6 +1547,8 @@ mod tests {
             correlation_id: Uuid::new_v4(),
             resolution_id,
             effects: vec![],
+            outcome_effects: None,
+            custom_effects: Vec::new(),
         };
 
         let rng: Mutex<MockRng> = Mutex::new(MockRng);
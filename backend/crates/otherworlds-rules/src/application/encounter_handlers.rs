@@ -0,0 +1,383 @@
+//! Command handlers for combat encounters.
+//!
+//! Mirrors `command_handlers` for the `Encounter` aggregate: load it, run
+//! the domain method with the RNG locked, persist the resulting events.
+
+use std::sync::Mutex;
+
+use otherworlds_core::aggregate::AggregateRoot;
+use otherworlds_core::clock::Clock;
+use otherworlds_core::error::DomainError;
+use otherworlds_core::event::DomainEvent;
+use otherworlds_core::repository::{EventRepository, StoredEvent};
+use otherworlds_core::rng::DeterministicRng;
+use otherworlds_core::snapshot::{load_with_snapshot, save_snapshot_if_due};
+use tracing::instrument;
+use uuid::Uuid;
+
+use crate::domain::commands::{
+    DelayTurn, EndEncounter, EndTurn, LinkResolution, ReadyAction, StartEncounter,
+    TriggerReadiedAction,
+};
+use crate::domain::encounter::Encounter;
+use crate::domain::events::{EncounterEvent, EncounterEventKind};
+use crate::domain::upcasters;
+
+fn to_stored_event(event: &EncounterEvent) -> StoredEvent {
+    let meta = event.metadata();
+    StoredEvent {
+        event_id: meta.event_id,
+        aggregate_id: meta.aggregate_id,
+        event_type: event.event_type().to_owned(),
+        payload: event.to_payload(),
+        sequence_number: meta.sequence_number,
+        correlation_id: meta.correlation_id,
+        causation_id: meta.causation_id,
+        occurred_at: meta.occurred_at,
+        schema_version: meta.schema_version,
+    }
+}
+
+/// Reconstitutes an `Encounter` from stored events.
+///
+/// # Errors
+///
+/// Returns `DomainError::Infrastructure` if event deserialization fails.
+pub(crate) fn reconstitute(
+    encounter_id: Uuid,
+    existing_events: &[StoredEvent],
+) -> Result<Encounter, DomainError> {
+    replay(Encounter::new(encounter_id), existing_events)
+}
+
+/// Loads an `Encounter`, starting from its latest snapshot when one is
+/// available and replaying only the events recorded after it.
+///
+/// Returns `None` if the aggregate has neither a snapshot nor any events.
+///
+/// # Errors
+///
+/// Returns `DomainError` if loading fails or event deserialization fails.
+pub(crate) async fn load(
+    encounter_id: Uuid,
+    repo: &dyn EventRepository,
+) -> Result<Option<Encounter>, DomainError> {
+    let (restored, events) = load_with_snapshot::<Encounter>(repo, encounter_id).await?;
+    match restored {
+        Some(encounter) => replay(encounter, &events).map(Some),
+        None if events.is_empty() => Ok(None),
+        None => reconstitute(encounter_id, &events).map(Some),
+    }
+}
+
+/// Applies stored events on top of an existing `Encounter` state.
+fn replay(mut encounter: Encounter, events: &[StoredEvent]) -> Result<Encounter, DomainError> {
+    for stored in events {
+        let payload = upcasters::registry().upcast(stored)?;
+        let kind: EncounterEventKind = serde_json::from_value(payload).map_err(|e| {
+            DomainError::Infrastructure(format!("event deserialization failed: {e}"))
+        })?;
+        let event = EncounterEvent {
+            metadata: otherworlds_core::event::EventMetadata {
+                event_id: stored.event_id,
+                event_type: stored.event_type.clone(),
+                aggregate_id: stored.aggregate_id,
+                sequence_number: stored.sequence_number,
+                correlation_id: stored.correlation_id,
+                causation_id: stored.causation_id,
+                occurred_at: stored.occurred_at,
+                schema_version: upcasters::current_schema_version(&stored.event_type),
+            },
+            kind,
+        };
+        encounter.apply(&event);
+    }
+    Ok(encounter)
+}
+
+/// Runs `operation` against an encounter and persists the events it
+/// produces. The RNG is locked only for the synchronous domain call.
+async fn execute(
+    mut encounter: Encounter,
+    clock: &dyn Clock,
+    rng: &Mutex<dyn DeterministicRng + Send>,
+    repo: &dyn EventRepository,
+    operation: impl FnOnce(&mut Encounter, &mut dyn DeterministicRng) -> Result<(), DomainError>,
+) -> Result<Vec<StoredEvent>, DomainError> {
+    {
+        let mut rng_guard = rng
+            .lock()
+            .map_err(|e| DomainError::Infrastructure(format!("RNG mutex poisoned: {e}")))?;
+        operation(&mut encounter, &mut *rng_guard)?;
+    }
+
+    let stored_events: Vec<StoredEvent> = encounter
+        .uncommitted_events()
+        .iter()
+        .map(to_stored_event)
+        .collect();
+
+    repo.append_events(encounter.id, encounter.version, &stored_events)
+        .await?;
+    save_snapshot_if_due(repo, encounter, clock).await;
+
+    Ok(stored_events)
+}
+
+/// Loads an encounter that must already exist.
+async fn load_existing(
+    encounter_id: Uuid,
+    repo: &dyn EventRepository,
+) -> Result<Encounter, DomainError> {
+    load(encounter_id, repo)
+        .await?
+        .ok_or(DomainError::AggregateNotFound(encounter_id))
+}
+
+/// Handles the `StartEncounter` command: rolls initiative for every
+/// participant and starts the first turn.
+///
+/// # Errors
+///
+/// Returns `DomainError` if event loading, validation, or appending fails.
+#[instrument(skip(clock, rng, repo), fields(encounter_id = %command.encounter_id, correlation_id = %command.correlation_id))]
+pub async fn handle_start_encounter(
+    command: &StartEncounter,
+    clock: &dyn Clock,
+    rng: &Mutex<dyn DeterministicRng + Send>,
+    repo: &dyn EventRepository,
+) -> Result<Vec<StoredEvent>, DomainError> {
+    let encounter = load(command.encounter_id, repo)
+        .await?
+        .unwrap_or_else(|| Encounter::new(command.encounter_id));
+    execute(encounter, clock, rng, repo, |encounter, rng| {
+        encounter.start(&command.participants, command.correlation_id, clock, rng)
+    })
+    .await
+}
+
+/// Handles the `EndTurn` command: ends the acting participant's turn and
+/// starts the next.
+///
+/// # Errors
+///
+/// Returns `DomainError::AggregateNotFound` if the encounter does not exist.
+/// Returns `DomainError` if event loading, validation, or appending fails.
+#[instrument(skip(clock, rng, repo), fields(encounter_id = %command.encounter_id, correlation_id = %command.correlation_id))]
+pub async fn handle_end_turn(
+    command: &EndTurn,
+    clock: &dyn Clock,
+    rng: &Mutex<dyn DeterministicRng + Send>,
+    repo: &dyn EventRepository,
+) -> Result<Vec<StoredEvent>, DomainError> {
+    let encounter = load_existing(command.encounter_id, repo).await?;
+    execute(encounter, clock, rng, repo, |encounter, rng| {
+        encounter.end_turn(command.correlation_id, clock, rng)
+    })
+    .await
+}
+
+/// Handles the `DelayTurn` command: moves the acting participant after
+/// another and starts the next turn.
+///
+/// # Errors
+///
+/// Returns `DomainError::AggregateNotFound` if the encounter does not exist.
+/// Returns `DomainError` if event loading, validation, or appending fails.
+#[instrument(skip(clock, rng, repo), fields(encounter_id = %command.encounter_id, correlation_id = %command.correlation_id))]
+pub async fn handle_delay_turn(
+    command: &DelayTurn,
+    clock: &dyn Clock,
+    rng: &Mutex<dyn DeterministicRng + Send>,
+    repo: &dyn EventRepository,
+) -> Result<Vec<StoredEvent>, DomainError> {
+    let encounter = load_existing(command.encounter_id, repo).await?;
+    execute(encounter, clock, rng, repo, |encounter, rng| {
+        encounter.delay_turn(
+            command.after_participant_id,
+            command.correlation_id,
+            clock,
+            rng,
+        )
+    })
+    .await
+}
+
+/// Handles the `ReadyAction` command: readies an action for the acting
+/// participant and starts the next turn.
+///
+/// # Errors
+///
+/// Returns `DomainError::AggregateNotFound` if the encounter does not exist.
+/// Returns `DomainError` if event loading, validation, or appending fails.
+#[instrument(skip(clock, rng, repo), fields(encounter_id = %command.encounter_id, correlation_id = %command.correlation_id))]
+pub async fn handle_ready_action(
+    command: &ReadyAction,
+    clock: &dyn Clock,
+    rng: &Mutex<dyn DeterministicRng + Send>,
+    repo: &dyn EventRepository,
+) -> Result<Vec<StoredEvent>, DomainError> {
+    let encounter = load_existing(command.encounter_id, repo).await?;
+    execute(encounter, clock, rng, repo, |encounter, rng| {
+        encounter.ready_action(&command.trigger, command.correlation_id, clock, rng)
+    })
+    .await
+}
+
+/// Handles the `TriggerReadiedAction` command: lets a participant act on
+/// their readied action during the current turn.
+///
+/// # Errors
+///
+/// Returns `DomainError::AggregateNotFound` if the encounter does not exist.
+/// Returns `DomainError` if event loading, validation, or appending fails.
+#[instrument(skip(clock, rng, repo), fields(encounter_id = %command.encounter_id, correlation_id = %command.correlation_id))]
+pub async fn handle_trigger_readied_action(
+    command: &TriggerReadiedAction,
+    clock: &dyn Clock,
+    rng: &Mutex<dyn DeterministicRng + Send>,
+    repo: &dyn EventRepository,
+) -> Result<Vec<StoredEvent>, DomainError> {
+    let encounter = load_existing(command.encounter_id, repo).await?;
+    execute(encounter, clock, rng, repo, |encounter, rng| {
+        encounter.trigger_readied_action(command.participant_id, command.correlation_id, clock, rng)
+    })
+    .await
+}
+
+/// Handles the `LinkResolution` command: records a resolution as part of a
+/// participant's action this round.
+///
+/// # Errors
+///
+/// Returns `DomainError::AggregateNotFound` if the encounter does not exist.
+/// Returns `DomainError` if event loading, validation, or appending fails.
+#[instrument(skip(clock, rng, repo), fields(encounter_id = %command.encounter_id, correlation_id = %command.correlation_id))]
+pub async fn handle_link_resolution(
+    command: &LinkResolution,
+    clock: &dyn Clock,
+    rng: &Mutex<dyn DeterministicRng + Send>,
+    repo: &dyn EventRepository,
+) -> Result<Vec<StoredEvent>, DomainError> {
+    let encounter = load_existing(command.encounter_id, repo).await?;
+    execute(encounter, clock, rng, repo, |encounter, rng| {
+        encounter.link_resolution(
+            command.participant_id,
+            command.resolution_id,
+            command.correlation_id,
+            clock,
+            rng,
+        )
+    })
+    .await
+}
+
+/// Handles the `EndEncounter` command.
+///
+/// # Errors
+///
+/// Returns `DomainError::AggregateNotFound` if the encounter does not exist.
+/// Returns `DomainError` if event loading, validation, or appending fails.
+#[instrument(skip(clock, rng, repo), fields(encounter_id = %command.encounter_id, correlation_id = %command.correlation_id))]
+pub async fn handle_end_encounter(
+    command: &EndEncounter,
+    clock: &dyn Clock,
+    rng: &Mutex<dyn DeterministicRng + Send>,
+    repo: &dyn EventRepository,
+) -> Result<Vec<StoredEvent>, DomainError> {
+    let encounter = load_existing(command.encounter_id, repo).await?;
+    execute(encounter, clock, rng, repo, |encounter, rng| {
+        encounter.end(command.reason.clone(), command.correlation_id, clock, rng)
+    })
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use chrono::{TimeZone, Utc};
+    use otherworlds_core::error::DomainError;
+    use otherworlds_core::rng::DeterministicRng;
+    use otherworlds_test_support::{
+        EmptyEventRepository, FixedClock, MockRng, RecordingEventRepository, SequenceRng,
+    };
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::domain::encounter::EncounterParticipant;
+
+    fn fixed_clock() -> FixedClock {
+        FixedClock(Utc.with_ymd_and_hms(2026, 1, 15, 10, 0, 0).unwrap())
+    }
+
+    #[tokio::test]
+    async fn test_handle_start_encounter_persists_initiative_and_first_turn() {
+        // Arrange
+        let encounter_id = Uuid::new_v4();
+        let hero = Uuid::new_v4();
+        let goblin = Uuid::new_v4();
+        let repo = RecordingEventRepository::new(Ok(Vec::new()));
+        let command = StartEncounter {
+            correlation_id: Uuid::new_v4(),
+            encounter_id,
+            participants: vec![
+                EncounterParticipant {
+                    participant_id: hero,
+                    name: "Hero".to_owned(),
+                    initiative_modifier: 2,
+                },
+                EncounterParticipant {
+                    participant_id: goblin,
+                    name: "Goblin".to_owned(),
+                    initiative_modifier: 1,
+                },
+            ],
+        };
+        let rng: Mutex<SequenceRng> =
+            Mutex::new(SequenceRng::new(vec![5, 15, 1, 2, 3, 4, 5, 6, 7, 8]));
+        let rng_ref: &Mutex<dyn DeterministicRng + Send> = &rng;
+
+        // Act
+        let result = handle_start_encounter(&command, &fixed_clock(), rng_ref, &repo).await;
+
+        // Assert — the goblin's 16 beats the hero's 7.
+        assert!(result.is_ok());
+        let appended = repo.appended_events();
+        assert_eq!(appended.len(), 1);
+        let (agg_id, expected_version, events) = &appended[0];
+        assert_eq!(*agg_id, encounter_id);
+        assert_eq!(*expected_version, 0);
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].event_type, "rules.encounter_started");
+        assert_eq!(events[1].event_type, "rules.turn_started");
+        assert_eq!(
+            events[1].payload["TurnStarted"]["participant_id"],
+            goblin.to_string()
+        );
+
+        let encounter = reconstitute(encounter_id, events).unwrap();
+        assert_eq!(encounter.current_participant(), Some(goblin));
+        assert_eq!(encounter.turn_order, vec![goblin, hero]);
+    }
+
+    #[tokio::test]
+    async fn test_handle_end_turn_rejects_not_found() {
+        // Arrange
+        let command = EndTurn {
+            correlation_id: Uuid::new_v4(),
+            encounter_id: Uuid::new_v4(),
+        };
+        let rng: Mutex<MockRng> = Mutex::new(MockRng);
+        let rng_ref: &Mutex<dyn DeterministicRng + Send> = &rng;
+
+        // Act
+        let result =
+            handle_end_turn(&command, &fixed_clock(), rng_ref, &EmptyEventRepository).await;
+
+        // Assert
+        assert!(
+            matches!(result, Err(DomainError::AggregateNotFound(id)) if id == command.encounter_id)
+        );
+    }
+}
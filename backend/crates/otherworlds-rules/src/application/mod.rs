@@ -1,4 +1,6 @@
 //! Application layer for the Rules & Resolution context.
 
 pub mod command_handlers;
+pub mod encounter_handlers;
+pub mod projections;
 pub mod query_handlers;
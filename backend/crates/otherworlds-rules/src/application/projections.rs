@@ -0,0 +1,318 @@
+//! Read-model projections for the Rules & Resolution context.
+//!
+//! Maintains one `ResolutionView` per resolution and one `ResolutionSummary`
+//! per non-archived resolution, plus one `EncounterView` and
+//! `EncounterSummary` per encounter, rebuilt from the aggregate whenever its
+//! stream changes.
+
+use async_trait::async_trait;
+use otherworlds_core::error::DomainError;
+use otherworlds_core::projection::{Projection, ReadModelStore, put_document};
+use otherworlds_core::repository::EventRepository;
+use uuid::Uuid;
+
+use crate::application::query_handlers::{
+    CheckResultView, CheckRollView, DieRollView, EffectView, EncounterActionView, EncounterSummary,
+    EncounterView, FiredTierView, InitiativeView, IntentView, OpposedCheckView, OpposedIntentView,
+    OpposedRollView, ResolutionSummary, ResolutionView,
+};
+use crate::application::{command_handlers, encounter_handlers};
+use crate::domain::aggregates::Resolution;
+use crate::domain::encounter::Encounter;
+use crate::domain::events::{CheckRoll, OpposedRoll};
+use crate::domain::ruleset::RulesetId;
+
+/// Read-model collection holding one `ResolutionView` per resolution.
+pub(crate) const RESOLUTION_VIEWS: &str = "resolution_views";
+
+/// Read-model collection holding one `ResolutionSummary` per listed resolution.
+pub(crate) const RESOLUTION_SUMMARIES: &str = "resolution_summaries";
+
+/// Read-model collection holding one `EncounterView` per encounter.
+pub(crate) const ENCOUNTER_VIEWS: &str = "encounter_views";
+
+/// Read-model collection holding one `EncounterSummary` per encounter.
+pub(crate) const ENCOUNTER_SUMMARIES: &str = "encounter_summaries";
+
+/// Event types of the `Resolution` aggregate.
+const EVENT_TYPES: &[&str] = &[
+    "rules.intent_declared",
+    "rules.check_resolved",
+    "rules.opposed_intent_declared",
+    "rules.opposed_check_resolved",
+    "rules.effects_produced",
+    "rules.resolution_archived",
+];
+
+/// Projects resolution streams into the resolution view and summary tables.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ResolutionProjection;
+
+#[async_trait]
+impl Projection for ResolutionProjection {
+    fn name(&self) -> &'static str {
+        "rules.resolutions"
+    }
+
+    // v2: intents and checks record dice expressions, roll modes and rulesets.
+    fn version(&self) -> i32 {
+        2
+    }
+
+    fn event_types(&self) -> &'static [&'static str] {
+        EVENT_TYPES
+    }
+
+    async fn project(
+        &self,
+        aggregate_id: Uuid,
+        repo: &dyn EventRepository,
+        store: &dyn ReadModelStore,
+    ) -> Result<(), DomainError> {
+        let Some(resolution) = command_handlers::load(aggregate_id, repo).await? else {
+            store.delete(RESOLUTION_VIEWS, aggregate_id).await?;
+            return store.delete(RESOLUTION_SUMMARIES, aggregate_id).await;
+        };
+
+        put_document(store, RESOLUTION_VIEWS, aggregate_id, &view(&resolution)).await?;
+        if resolution.archived {
+            store.delete(RESOLUTION_SUMMARIES, aggregate_id).await
+        } else {
+            put_document(
+                store,
+                RESOLUTION_SUMMARIES,
+                aggregate_id,
+                &summary(&resolution),
+            )
+            .await
+        }
+    }
+
+    async fn reset(&self, store: &dyn ReadModelStore) -> Result<(), DomainError> {
+        store.clear(RESOLUTION_VIEWS).await?;
+        store.clear(RESOLUTION_SUMMARIES).await
+    }
+}
+
+fn view(resolution: &Resolution) -> ResolutionView {
+    let intent = resolution.intent.as_ref().map(|i| IntentView {
+        intent_id: i.intent_id,
+        action_type: i.action_type.clone(),
+        skill: i.skill.clone(),
+        target_id: i.target_id,
+        difficulty_class: i.difficulty_class,
+        modifier: i.modifier,
+        roll_expression: i.roll_expression.to_string(),
+        stats: i.stats.clone(),
+        roll_mode: i.roll_mode,
+        ruleset: i.ruleset,
+        modifier_breakdown: i.modifier_breakdown.clone(),
+    });
+    let ruleset = resolution
+        .intent
+        .as_ref()
+        .map(|i| i.ruleset)
+        .or_else(|| resolution.opposed_intent.as_ref().map(|i| i.ruleset))
+        .unwrap_or(RulesetId::D20)
+        .ruleset();
+
+    let check_result = resolution.check_result.as_ref().map(|c| CheckResultView {
+        check_id: c.check_id,
+        natural_roll: c.natural_roll,
+        modifier: c.modifier,
+        total: c.total,
+        difficulty_class: c.difficulty_class,
+        outcome: c.outcome.to_string(),
+        tier: ruleset.tier_name(c.outcome).to_owned(),
+        rolls: check_roll_views(&c.rolls),
+        kept_roll: c.kept_roll,
+    });
+
+    let opposed_intent = resolution
+        .opposed_intent
+        .as_ref()
+        .map(|i| OpposedIntentView {
+            intent_id: i.intent_id,
+            action_type: i.action_type.clone(),
+            skill: i.skill.clone(),
+            actor: i.actor,
+            opponent: i.opponent,
+            roll_expression: i.roll_expression.to_string(),
+            ruleset: i.ruleset,
+            tie_break: i.tie_break,
+        });
+
+    let opposed_result = resolution
+        .opposed_result
+        .as_ref()
+        .map(|r| OpposedCheckView {
+            check_id: r.check_id,
+            actor: opposed_roll_view(&r.actor),
+            opponent: opposed_roll_view(&r.opponent),
+            margin: r.margin,
+            outcome: r.outcome.to_string(),
+            tier: ruleset.tier_name(r.outcome).to_owned(),
+            winner: r.winner,
+        });
+
+    let effects = resolution
+        .effects
+        .iter()
+        .map(|e| EffectView {
+            effect_type: e.effect.effect_type().to_owned(),
+            target_id: e.target_id,
+            payload: e.effect.payload(),
+        })
+        .collect();
+
+    let fired_tier = resolution.fired_tier.map(|t| FiredTierView {
+        outcome: t.outcome.to_string(),
+        branch: t.branch.map(|b| b.to_string()),
+        strength_percent: t.strength_percent,
+    });
+
+    ResolutionView {
+        resolution_id: resolution.id,
+        phase: resolution.phase_name().to_owned(),
+        intent,
+        check_result,
+        opposed_intent,
+        opposed_result,
+        effects,
+        fired_tier,
+        version: resolution.version,
+    }
+}
+
+fn check_roll_views(rolls: &[CheckRoll]) -> Vec<CheckRollView> {
+    rolls
+        .iter()
+        .map(|r| CheckRollView {
+            natural_roll: r.natural_roll,
+            dice: r
+                .dice
+                .iter()
+                .map(|d| DieRollView {
+                    sides: d.sides,
+                    value: d.value,
+                    kept: d.kept,
+                    exploded: d.exploded,
+                })
+                .collect(),
+        })
+        .collect()
+}
+
+fn opposed_roll_view(roll: &OpposedRoll) -> OpposedRollView {
+    OpposedRollView {
+        natural_roll: roll.natural_roll,
+        modifier: roll.modifier,
+        total: roll.total,
+        rolls: check_roll_views(&roll.rolls),
+        kept_roll: roll.kept_roll,
+    }
+}
+
+fn summary(resolution: &Resolution) -> ResolutionSummary {
+    ResolutionSummary {
+        resolution_id: resolution.id,
+        phase: resolution.phase_name().to_owned(),
+        version: resolution.version,
+    }
+}
+
+/// Event types of the `Encounter` aggregate.
+const ENCOUNTER_EVENT_TYPES: &[&str] = &[
+    "rules.encounter_started",
+    "rules.turn_started",
+    "rules.turn_delayed",
+    "rules.action_readied",
+    "rules.readied_action_triggered",
+    "rules.resolution_linked",
+    "rules.encounter_ended",
+];
+
+/// Projects encounter streams into the encounter view and summary tables.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct EncounterProjection;
+
+#[async_trait]
+impl Projection for EncounterProjection {
+    fn name(&self) -> &'static str {
+        "rules.encounters"
+    }
+
+    fn event_types(&self) -> &'static [&'static str] {
+        ENCOUNTER_EVENT_TYPES
+    }
+
+    async fn project(
+        &self,
+        aggregate_id: Uuid,
+        repo: &dyn EventRepository,
+        store: &dyn ReadModelStore,
+    ) -> Result<(), DomainError> {
+        let Some(encounter) = encounter_handlers::load(aggregate_id, repo).await? else {
+            store.delete(ENCOUNTER_VIEWS, aggregate_id).await?;
+            return store.delete(ENCOUNTER_SUMMARIES, aggregate_id).await;
+        };
+
+        put_document(
+            store,
+            ENCOUNTER_VIEWS,
+            aggregate_id,
+            &encounter_view(&encounter),
+        )
+        .await?;
+        put_document(
+            store,
+            ENCOUNTER_SUMMARIES,
+            aggregate_id,
+            &EncounterSummary {
+                encounter_id: encounter.id,
+                phase: encounter.phase_name().to_owned(),
+                round: encounter.round,
+                version: encounter.version,
+            },
+        )
+        .await
+    }
+
+    async fn reset(&self, store: &dyn ReadModelStore) -> Result<(), DomainError> {
+        store.clear(ENCOUNTER_VIEWS).await?;
+        store.clear(ENCOUNTER_SUMMARIES).await
+    }
+}
+
+fn encounter_view(encounter: &Encounter) -> EncounterView {
+    EncounterView {
+        encounter_id: encounter.id,
+        phase: encounter.phase_name().to_owned(),
+        round: encounter.round,
+        current_participant_id: encounter.current_participant(),
+        initiative: encounter
+            .initiative
+            .iter()
+            .map(|roll| InitiativeView {
+                participant_id: roll.participant_id,
+                name: roll.name.clone(),
+                modifier: roll.modifier,
+                natural_roll: roll.natural_roll,
+                initiative: roll.initiative,
+            })
+            .collect(),
+        turn_order: encounter.turn_order.clone(),
+        readied: encounter.readied.clone(),
+        actions: encounter
+            .resolutions
+            .iter()
+            .map(|linked| EncounterActionView {
+                round: linked.round,
+                participant_id: linked.participant_id,
+                resolution_id: linked.resolution_id,
+            })
+            .collect(),
+        end_reason: encounter.end_reason.clone(),
+        version: encounter.version,
+    }
+}
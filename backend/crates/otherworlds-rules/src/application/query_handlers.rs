@@ -1,17 +1,29 @@
 //! Query handlers for the Rules & Resolution context.
 //!
-//! This module contains query handlers that reconstitute aggregates
-//! from stored events and return read-only view DTOs.
+//! This module contains query handlers that bring the resolution projection
+//! up to date and return read-only view DTOs from its read-model tables.
+
+use std::collections::BTreeMap;
 
 use otherworlds_core::error::DomainError;
+use otherworlds_core::projection::{
+    ProjectionRunner, ReadModelStore, get_document, list_documents,
+};
 use otherworlds_core::repository::EventRepository;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use crate::application::command_handlers;
+use crate::application::projections::{
+    ENCOUNTER_SUMMARIES, ENCOUNTER_VIEWS, EncounterProjection, RESOLUTION_SUMMARIES,
+    RESOLUTION_VIEWS, ResolutionProjection,
+};
+use crate::domain::events::{CheckOutcome, OpposedParticipant, OpposedSide, RollMode, TieBreak};
+use crate::domain::modifiers::ModifierBreakdown;
+use crate::domain::odds::check_odds;
+use crate::domain::ruleset::RulesetId;
 
 /// Read-only view of a resolution's declared intent.
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct IntentView {
     /// The intent identifier.
     pub intent_id: Uuid,
@@ -25,38 +37,143 @@ pub struct IntentView {
     pub difficulty_class: i32,
     /// The roll modifier.
     pub modifier: i32,
+    /// The dice expression rolled for the check.
+    pub roll_expression: String,
+    /// Values of the stats the roll expression references.
+    pub stats: BTreeMap<String, i32>,
+    /// How many times the expression is rolled and which roll counts.
+    pub roll_mode: RollMode,
+    /// The ruleset the check is rolled and judged by.
+    pub ruleset: RulesetId,
+    /// How the modifier was derived from the acting character's sheet, or
+    /// `None` if the client supplied it.
+    pub modifier_breakdown: Option<ModifierBreakdown>,
+}
+
+/// Read-only view of a single die drawn for a check.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct DieRollView {
+    /// Sides of the die.
+    pub sides: u32,
+    /// The face rolled.
+    pub value: u32,
+    /// Whether the die counts towards the total.
+    pub kept: bool,
+    /// Whether the roll was triggered by an explosion.
+    pub exploded: bool,
+}
+
+/// Read-only view of one roll of a check's expression.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CheckRollView {
+    /// The sum of the roll's kept dice.
+    pub natural_roll: u32,
+    /// Every die drawn for the roll, in the order it was drawn.
+    pub dice: Vec<DieRollView>,
 }
 
 /// Read-only view of a resolution's check result.
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct CheckResultView {
     /// The check identifier.
     pub check_id: Uuid,
-    /// The raw d20 result.
+    /// The natural roll of the kept roll.
     pub natural_roll: u32,
     /// The modifier applied.
     pub modifier: i32,
-    /// The total (`natural_roll` + modifier).
+    /// The total the ruleset judged.
     pub total: i32,
     /// The difficulty class.
     pub difficulty_class: i32,
     /// The outcome as a string.
     pub outcome: String,
+    /// The ruleset's name for the outcome (e.g., "weak hit").
+    pub tier: String,
+    /// Every roll of the expression, in the order it was rolled.
+    pub rolls: Vec<CheckRollView>,
+    /// Index of the roll in `rolls` that counts.
+    pub kept_roll: usize,
+}
+
+/// Read-only view of a resolution's declared opposed intent.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct OpposedIntentView {
+    /// The intent identifier.
+    pub intent_id: Uuid,
+    /// The type of action.
+    pub action_type: String,
+    /// Optional skill being used.
+    pub skill: Option<String>,
+    /// The participant initiating the contest.
+    pub actor: OpposedParticipant,
+    /// The participant resisting it.
+    pub opponent: OpposedParticipant,
+    /// The dice expression both participants roll.
+    pub roll_expression: String,
+    /// The ruleset the rolls are made under.
+    pub ruleset: RulesetId,
+    /// How equal totals are settled.
+    pub tie_break: TieBreak,
+}
+
+/// Read-only view of one participant's side of an opposed check.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct OpposedRollView {
+    /// The natural roll of the kept roll.
+    pub natural_roll: u32,
+    /// The modifier applied.
+    pub modifier: i32,
+    /// The total compared against the other side's.
+    pub total: i32,
+    /// Every roll of the expression, in the order it was rolled.
+    pub rolls: Vec<CheckRollView>,
+    /// Index of the roll in `rolls` that counts.
+    pub kept_roll: usize,
+}
+
+/// Read-only view of a resolution's opposed check result.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct OpposedCheckView {
+    /// The check identifier.
+    pub check_id: Uuid,
+    /// The actor's roll.
+    pub actor: OpposedRollView,
+    /// The opponent's roll.
+    pub opponent: OpposedRollView,
+    /// How far the actor beat the opponent; negative when the actor lost.
+    pub margin: i32,
+    /// The outcome from the actor's point of view, as a string.
+    pub outcome: String,
+    /// The ruleset's name for the outcome.
+    pub tier: String,
+    /// The winning side, or `None` on a standoff.
+    pub winner: Option<OpposedSide>,
 }
 
 /// Read-only view of a single effect.
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct EffectView {
     /// The type of effect.
     pub effect_type: String,
     /// Optional target of the effect.
     pub target_id: Option<Uuid>,
-    /// Campaign-specific payload.
+    /// The effect's typed payload.
     pub payload: serde_json::Value,
 }
 
+/// Read-only view of the outcome-table tier that produced the effects.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FiredTierView {
+    /// The check's outcome as a string.
+    pub outcome: String,
+    /// The tier whose effects were produced, if any.
+    pub branch: Option<String>,
+    /// Strength the effects were produced at, in percent.
+    pub strength_percent: u32,
+}
+
 /// Read-only view of a resolution aggregate.
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct ResolutionView {
     /// The resolution identifier.
     pub resolution_id: Uuid,
@@ -66,22 +183,20 @@ pub struct ResolutionView {
     pub intent: Option<IntentView>,
     /// Check result, if any.
     pub check_result: Option<CheckResultView>,
+    /// Declared opposed intent, if any.
+    pub opposed_intent: Option<OpposedIntentView>,
+    /// Opposed check result, if any.
+    pub opposed_result: Option<OpposedCheckView>,
     /// Produced effects.
     pub effects: Vec<EffectView>,
+    /// The outcome-table tier that fired, if effects came from a table.
+    pub fired_tier: Option<FiredTierView>,
     /// Current version (event count).
     pub version: i64,
 }
 
-/// Event types used by the Rules & Resolution context.
-const EVENT_TYPES: &[&str] = &[
-    "rules.intent_declared",
-    "rules.check_resolved",
-    "rules.effects_produced",
-    "rules.resolution_archived",
-];
-
 /// Summary view for listing resolutions.
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct ResolutionSummary {
     /// The resolution identifier.
     pub resolution_id: Uuid,
@@ -91,108 +206,239 @@ pub struct ResolutionSummary {
     pub version: i64,
 }
 
-/// Lists all resolutions.
+/// Read-only view of a participant's initiative roll.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct InitiativeView {
+    /// The participant identifier.
+    pub participant_id: Uuid,
+    /// Display name of the participant.
+    pub name: String,
+    /// The modifier added to the roll.
+    pub modifier: i32,
+    /// The face the d20 showed.
+    pub natural_roll: u32,
+    /// Natural roll plus modifier.
+    pub initiative: i32,
+}
+
+/// Read-only view of a resolution linked to an encounter action.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct EncounterActionView {
+    /// The round the action was taken in.
+    pub round: u32,
+    /// The participant who acted.
+    pub participant_id: Uuid,
+    /// The resolution recording the check.
+    pub resolution_id: Uuid,
+}
+
+/// Read-only view of an encounter aggregate.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct EncounterView {
+    /// The encounter identifier.
+    pub encounter_id: Uuid,
+    /// Current phase as a string.
+    pub phase: String,
+    /// The current round (0 before the encounter starts).
+    pub round: u32,
+    /// The participant whose turn it is, while the encounter is active.
+    pub current_participant_id: Option<Uuid>,
+    /// Initiative rolls, in the order participants joined.
+    pub initiative: Vec<InitiativeView>,
+    /// Participant IDs in the order they act.
+    pub turn_order: Vec<Uuid>,
+    /// Readied actions by participant, with their triggers.
+    pub readied: BTreeMap<Uuid, String>,
+    /// Resolutions linked to participants' actions, in order.
+    pub actions: Vec<EncounterActionView>,
+    /// Why the encounter ended, if it has.
+    pub end_reason: Option<String>,
+    /// Current version (event count).
+    pub version: i64,
+}
+
+/// Summary view for listing encounters.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct EncounterSummary {
+    /// The encounter identifier.
+    pub encounter_id: Uuid,
+    /// Current phase as a string.
+    pub phase: String,
+    /// The current round.
+    pub round: u32,
+    /// Current version (event count).
+    pub version: i64,
+}
+
+/// Read-only view of the chance of one outcome tier.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct OutcomeOddsView {
+    /// The outcome as a string.
+    pub outcome: String,
+    /// The ruleset's name for the outcome (e.g., "weak hit").
+    pub tier: String,
+    /// The probability of the outcome, between 0 and 1.
+    pub probability: f64,
+}
+
+/// Read-only view of the odds of a check before it is rolled.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CheckOddsView {
+    /// The ruleset the check would be rolled under.
+    pub ruleset: RulesetId,
+    /// The difficulty class to beat.
+    pub difficulty_class: i32,
+    /// The modifier applied to the roll.
+    pub modifier: i32,
+    /// How many times the expression would be rolled and which roll counts.
+    pub roll_mode: RollMode,
+    /// The odds of each outcome, from worst to best.
+    pub outcomes: Vec<OutcomeOddsView>,
+}
+
+/// Calculates the odds of each outcome of a check rolled with the ruleset's
+/// default expression. Nothing is rolled or stored.
 ///
 /// # Errors
 ///
-/// Returns `DomainError::Infrastructure` if querying or deserialization fails.
+/// Returns `DomainError::Validation` if `roll_mode` is out of range or a
+/// total overflows.
+pub fn get_check_odds(
+    ruleset: RulesetId,
+    difficulty_class: i32,
+    modifier: i32,
+    roll_mode: RollMode,
+) -> Result<CheckOddsView, DomainError> {
+    let rules = ruleset.ruleset();
+    let odds = check_odds(rules, difficulty_class, modifier, roll_mode)?;
+    let outcomes = CheckOutcome::ALL
+        .into_iter()
+        .map(|outcome| OutcomeOddsView {
+            outcome: outcome.to_string(),
+            tier: rules.tier_name(outcome).to_owned(),
+            probability: odds.probability(outcome),
+        })
+        .collect();
+    Ok(CheckOddsView {
+        ruleset,
+        difficulty_class,
+        modifier,
+        roll_mode,
+        outcomes,
+    })
+}
+
+/// Lists all resolutions that have not been archived.
+///
+/// # Errors
+///
+/// Returns `DomainError::Infrastructure` if catching up the projection or
+/// reading the read model fails.
 pub async fn list_resolutions(
     repo: &dyn EventRepository,
+    read_models: &dyn ReadModelStore,
 ) -> Result<Vec<ResolutionSummary>, DomainError> {
-    let ids = repo.list_aggregate_ids(EVENT_TYPES).await?;
-    let mut summaries = Vec::with_capacity(ids.len());
-    for id in ids {
-        let stored_events = repo.load_events(id).await?;
-        if stored_events.is_empty() {
-            continue;
-        }
-        let resolution = command_handlers::reconstitute(id, &stored_events)?;
-        if resolution.archived {
-            continue;
-        }
-        summaries.push(ResolutionSummary {
-            resolution_id: id,
-            phase: resolution.phase_name().to_owned(),
-            version: resolution.version,
-        });
-    }
-    Ok(summaries)
+    ProjectionRunner::default()
+        .catch_up(&ResolutionProjection, repo, read_models)
+        .await?;
+    list_documents(read_models, RESOLUTION_SUMMARIES).await
 }
 
 /// Retrieves a resolution by its aggregate ID.
 ///
 /// # Errors
 ///
-/// Returns `DomainError::AggregateNotFound` if no events exist for the ID.
-/// Returns `DomainError::Infrastructure` if event deserialization fails.
+/// Returns `DomainError::AggregateNotFound` if the resolution has no view.
+/// Returns `DomainError::Infrastructure` if catching up the projection or
+/// reading the read model fails.
 pub async fn get_resolution_by_id(
     resolution_id: Uuid,
     repo: &dyn EventRepository,
+    read_models: &dyn ReadModelStore,
 ) -> Result<ResolutionView, DomainError> {
-    let stored_events = repo.load_events(resolution_id).await?;
-    if stored_events.is_empty() {
-        return Err(DomainError::AggregateNotFound(resolution_id));
-    }
-    let resolution = command_handlers::reconstitute(resolution_id, &stored_events)?;
-
-    let phase = resolution.phase_name().to_owned();
-
-    let intent = resolution.intent.map(|i| IntentView {
-        intent_id: i.intent_id,
-        action_type: i.action_type,
-        skill: i.skill,
-        target_id: i.target_id,
-        difficulty_class: i.difficulty_class,
-        modifier: i.modifier,
-    });
-
-    let check_result = resolution.check_result.map(|c| CheckResultView {
-        check_id: c.check_id,
-        natural_roll: c.natural_roll,
-        modifier: c.modifier,
-        total: c.total,
-        difficulty_class: c.difficulty_class,
-        outcome: c.outcome.to_string(),
-    });
-
-    let effects = resolution
-        .effects
-        .into_iter()
-        .map(|e| EffectView {
-            effect_type: e.effect_type,
-            target_id: e.target_id,
-            payload: e.payload,
-        })
-        .collect();
+    ProjectionRunner::default()
+        .catch_up(&ResolutionProjection, repo, read_models)
+        .await?;
+    get_document(read_models, RESOLUTION_VIEWS, resolution_id)
+        .await?
+        .ok_or(DomainError::AggregateNotFound(resolution_id))
+}
 
-    Ok(ResolutionView {
-        resolution_id,
-        phase,
-        intent,
-        check_result,
-        effects,
-        version: resolution.version,
-    })
+/// Lists all encounters.
+///
+/// # Errors
+///
+/// Returns `DomainError::Infrastructure` if catching up the projection or
+/// reading the read model fails.
+pub async fn list_encounters(
+    repo: &dyn EventRepository,
+    read_models: &dyn ReadModelStore,
+) -> Result<Vec<EncounterSummary>, DomainError> {
+    ProjectionRunner::default()
+        .catch_up(&EncounterProjection, repo, read_models)
+        .await?;
+    list_documents(read_models, ENCOUNTER_SUMMARIES).await
+}
+
+/// Retrieves an encounter by its aggregate ID.
+///
+/// # Errors
+///
+/// Returns `DomainError::AggregateNotFound` if the encounter has no view.
+/// Returns `DomainError::Infrastructure` if catching up the projection or
+/// reading the read model fails.
+pub async fn get_encounter_by_id(
+    encounter_id: Uuid,
+    repo: &dyn EventRepository,
+    read_models: &dyn ReadModelStore,
+) -> Result<EncounterView, DomainError> {
+    ProjectionRunner::default()
+        .catch_up(&EncounterProjection, repo, read_models)
+        .await?;
+    get_document(read_models, ENCOUNTER_VIEWS, encounter_id)
+        .await?
+        .ok_or(DomainError::AggregateNotFound(encounter_id))
 }
 
 #[cfg(test)]
 mod tests {
+    use std::collections::BTreeMap;
+
     use chrono::{TimeZone, Utc};
     use otherworlds_core::error::DomainError;
     use otherworlds_core::repository::StoredEvent;
     use uuid::Uuid;
 
-    use crate::application::query_handlers::{get_resolution_by_id, list_resolutions};
+    use crate::application::query_handlers::{
+        get_check_odds, get_encounter_by_id, get_resolution_by_id, list_encounters,
+        list_resolutions,
+    };
+    use crate::domain::dice::{DiceExpression, DieRoll};
+    use crate::domain::effects::{Damage, Effect};
     use crate::domain::events::{
-        CheckOutcome, CheckResolved, EffectsProduced, IntentDeclared, ResolutionArchived,
-        ResolvedEffect, RulesEventKind,
+        CheckOutcome, CheckResolved, CheckRoll, EffectsProduced, EncounterEventKind,
+        EncounterStarted, InitiativeRoll, IntentDeclared, OpposedCheckResolved,
+        OpposedIntentDeclared, OpposedParticipant, OpposedRoll, OpposedSide, ResolutionArchived,
+        ResolutionLinked, ResolvedEffect, RollMode, RulesEventKind, TieBreak, TurnStarted,
+    };
+    use crate::domain::ruleset::RulesetId;
+    use otherworlds_test_support::{
+        EmptyEventRepository, InMemoryReadModelStore, RecordingEventRepository,
     };
-    use otherworlds_test_support::{EmptyEventRepository, RecordingEventRepository};
 
     fn fixed_now() -> chrono::DateTime<Utc> {
         Utc.with_ymd_and_hms(2026, 1, 15, 10, 0, 0).unwrap()
     }
 
+    fn d20(value: u32) -> DieRoll {
+        DieRoll {
+            sides: 20,
+            value,
+            kept: true,
+            exploded: false,
+        }
+    }
+
     fn intent_declared_event(resolution_id: Uuid) -> StoredEvent {
         StoredEvent {
             event_id: Uuid::new_v4(),
@@ -206,12 +452,18 @@ mod tests {
                 target_id: None,
                 difficulty_class: 15,
                 modifier: 3,
+                roll_expression: DiceExpression::d20(),
+                stats: BTreeMap::new(),
+                roll_mode: RollMode::Advantage,
+                ruleset: RulesetId::D20,
+                modifier_breakdown: None,
             }))
             .unwrap(),
             sequence_number: 1,
             correlation_id: Uuid::new_v4(),
             causation_id: Uuid::new_v4(),
             occurred_at: fixed_now(),
+            schema_version: 4,
         }
     }
 
@@ -228,12 +480,24 @@ mod tests {
                 total: 18,
                 difficulty_class: 15,
                 outcome: CheckOutcome::Success,
+                rolls: vec![
+                    CheckRoll {
+                        natural_roll: 6,
+                        dice: vec![d20(6)],
+                    },
+                    CheckRoll {
+                        natural_roll: 15,
+                        dice: vec![d20(15)],
+                    },
+                ],
+                kept_roll: 1,
             }))
             .unwrap(),
             sequence_number: 2,
             correlation_id: Uuid::new_v4(),
             causation_id: Uuid::new_v4(),
             occurred_at: fixed_now(),
+            schema_version: 3,
         }
     }
 
@@ -245,16 +509,20 @@ mod tests {
             payload: serde_json::to_value(RulesEventKind::EffectsProduced(EffectsProduced {
                 resolution_id,
                 effects: vec![ResolvedEffect {
-                    effect_type: "damage".to_owned(),
                     target_id: None,
-                    payload: serde_json::json!({ "amount": 8 }),
+                    effect: Effect::Damage(Damage {
+                        amount: 8,
+                        damage_type: None,
+                    }),
                 }],
+                tier: None,
             }))
             .unwrap(),
             sequence_number: 3,
             correlation_id: Uuid::new_v4(),
             causation_id: Uuid::new_v4(),
             occurred_at: fixed_now(),
+            schema_version: 1,
         }
     }
 
@@ -263,7 +531,9 @@ mod tests {
         let resolution_id = Uuid::new_v4();
         let repo = RecordingEventRepository::new(Ok(vec![intent_declared_event(resolution_id)]));
 
-        let view = get_resolution_by_id(resolution_id, &repo).await.unwrap();
+        let view = get_resolution_by_id(resolution_id, &repo, &InMemoryReadModelStore::new())
+            .await
+            .unwrap();
 
         assert_eq!(view.resolution_id, resolution_id);
         assert_eq!(view.phase, "intent_declared");
@@ -281,13 +551,22 @@ mod tests {
             check_resolved_event(resolution_id),
         ]));
 
-        let view = get_resolution_by_id(resolution_id, &repo).await.unwrap();
+        let view = get_resolution_by_id(resolution_id, &repo, &InMemoryReadModelStore::new())
+            .await
+            .unwrap();
 
         assert_eq!(view.phase, "check_resolved");
-        assert!(view.intent.is_some());
+        let intent = view.intent.unwrap();
+        assert_eq!(intent.roll_expression, "1d20");
+        assert_eq!(intent.roll_mode, RollMode::Advantage);
+        assert_eq!(intent.ruleset, RulesetId::D20);
         assert!(view.check_result.is_some());
         let check = view.check_result.unwrap();
         assert_eq!(check.outcome, "success");
+        assert_eq!(check.tier, "success");
+        assert_eq!(check.rolls.len(), 2);
+        assert_eq!(check.kept_roll, 1);
+        assert_eq!(check.rolls[1].dice[0].value, 15);
         assert!(view.effects.is_empty());
     }
 
@@ -300,7 +579,9 @@ mod tests {
             effects_produced_event(resolution_id),
         ]));
 
-        let view = get_resolution_by_id(resolution_id, &repo).await.unwrap();
+        let view = get_resolution_by_id(resolution_id, &repo, &InMemoryReadModelStore::new())
+            .await
+            .unwrap();
 
         assert_eq!(view.phase, "effects_produced");
         assert!(view.intent.is_some());
@@ -310,13 +591,104 @@ mod tests {
         assert_eq!(view.version, 3);
     }
 
+    fn opposed_roll(natural_roll: u32, modifier: i32) -> OpposedRoll {
+        OpposedRoll {
+            natural_roll,
+            modifier,
+            total: i32::try_from(natural_roll).unwrap() + modifier,
+            rolls: vec![CheckRoll {
+                natural_roll,
+                dice: vec![d20(natural_roll)],
+            }],
+            kept_roll: 0,
+        }
+    }
+
+    fn opposed_events(resolution_id: Uuid) -> Vec<StoredEvent> {
+        let participant = |modifier| OpposedParticipant {
+            participant_id: Some(Uuid::new_v4()),
+            modifier,
+            roll_mode: RollMode::Normal,
+        };
+        let payloads = [
+            (
+                "rules.opposed_intent_declared",
+                RulesEventKind::OpposedIntentDeclared(OpposedIntentDeclared {
+                    resolution_id,
+                    intent_id: Uuid::new_v4(),
+                    action_type: "grapple".to_owned(),
+                    skill: Some("athletics".to_owned()),
+                    actor: participant(2),
+                    opponent: participant(5),
+                    roll_expression: DiceExpression::d20(),
+                    ruleset: RulesetId::D20,
+                    tie_break: TieBreak::HigherModifier,
+                }),
+            ),
+            (
+                "rules.opposed_check_resolved",
+                RulesEventKind::OpposedCheckResolved(OpposedCheckResolved {
+                    resolution_id,
+                    check_id: Uuid::new_v4(),
+                    actor: opposed_roll(4, 2),
+                    opponent: opposed_roll(19, 5),
+                    margin: -18,
+                    outcome: CheckOutcome::CriticalFailure,
+                    winner: Some(OpposedSide::Opponent),
+                }),
+            ),
+        ];
+        payloads
+            .into_iter()
+            .zip(1..)
+            .map(|((event_type, kind), sequence_number)| StoredEvent {
+                event_id: Uuid::new_v4(),
+                aggregate_id: resolution_id,
+                event_type: event_type.to_owned(),
+                payload: serde_json::to_value(kind).unwrap(),
+                sequence_number,
+                correlation_id: Uuid::new_v4(),
+                causation_id: Uuid::new_v4(),
+                occurred_at: fixed_now(),
+                schema_version: 1,
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_view_from_opposed_check() {
+        let resolution_id = Uuid::new_v4();
+        let repo = RecordingEventRepository::new(Ok(opposed_events(resolution_id)));
+
+        let view = get_resolution_by_id(resolution_id, &repo, &InMemoryReadModelStore::new())
+            .await
+            .unwrap();
+
+        assert_eq!(view.phase, "check_resolved");
+        assert!(view.intent.is_none());
+        assert!(view.check_result.is_none());
+        let intent = view.opposed_intent.unwrap();
+        assert_eq!(intent.roll_expression, "1d20");
+        assert_eq!(intent.opponent.modifier, 5);
+        assert_eq!(intent.tie_break, TieBreak::HigherModifier);
+        let result = view.opposed_result.unwrap();
+        assert_eq!(result.margin, -18);
+        assert_eq!(result.outcome, "critical_failure");
+        assert_eq!(result.tier, "critical failure");
+        assert_eq!(result.winner, Some(OpposedSide::Opponent));
+        assert_eq!(result.opponent.total, 24);
+        assert_eq!(result.opponent.rolls[0].dice[0].value, 19);
+    }
+
     #[tokio::test]
     async fn test_phase_string_for_each_phase() {
         let resolution_id = Uuid::new_v4();
 
         // Intent declared phase
         let repo = RecordingEventRepository::new(Ok(vec![intent_declared_event(resolution_id)]));
-        let view = get_resolution_by_id(resolution_id, &repo).await.unwrap();
+        let view = get_resolution_by_id(resolution_id, &repo, &InMemoryReadModelStore::new())
+            .await
+            .unwrap();
         assert_eq!(view.phase, "intent_declared");
     }
 
@@ -325,7 +697,8 @@ mod tests {
         let resolution_id = Uuid::new_v4();
         let repo = EmptyEventRepository;
 
-        let result = get_resolution_by_id(resolution_id, &repo).await;
+        let result =
+            get_resolution_by_id(resolution_id, &repo, &InMemoryReadModelStore::new()).await;
 
         assert!(result.is_err());
         match result.unwrap_err() {
@@ -338,7 +711,9 @@ mod tests {
     async fn test_list_resolutions_returns_empty_when_no_aggregates() {
         let repo = EmptyEventRepository;
 
-        let result = list_resolutions(&repo).await.unwrap();
+        let result = list_resolutions(&repo, &InMemoryReadModelStore::new())
+            .await
+            .unwrap();
 
         assert!(result.is_empty());
     }
@@ -351,7 +726,9 @@ mod tests {
             vec![resolution_id],
         );
 
-        let result = list_resolutions(&repo).await.unwrap();
+        let result = list_resolutions(&repo, &InMemoryReadModelStore::new())
+            .await
+            .unwrap();
 
         assert_eq!(result.len(), 1);
         assert_eq!(result[0].resolution_id, resolution_id);
@@ -374,14 +751,118 @@ mod tests {
             correlation_id: Uuid::new_v4(),
             causation_id: Uuid::new_v4(),
             occurred_at: fixed_now(),
+            schema_version: 1,
         };
         let repo = RecordingEventRepository::with_aggregate_ids(
             Ok(vec![intent_declared_event(resolution_id), archived_event]),
             vec![resolution_id],
         );
 
-        let result = list_resolutions(&repo).await.unwrap();
+        let result = list_resolutions(&repo, &InMemoryReadModelStore::new())
+            .await
+            .unwrap();
 
         assert!(result.is_empty());
     }
+
+    fn encounter_event(
+        encounter_id: Uuid,
+        sequence_number: i64,
+        kind: EncounterEventKind,
+    ) -> StoredEvent {
+        let event_type = match &kind {
+            EncounterEventKind::EncounterStarted(_) => "rules.encounter_started",
+            EncounterEventKind::TurnStarted(_) => "rules.turn_started",
+            EncounterEventKind::ResolutionLinked(_) => "rules.resolution_linked",
+            other => panic!("unexpected event {other:?}"),
+        };
+        StoredEvent {
+            event_id: Uuid::new_v4(),
+            aggregate_id: encounter_id,
+            event_type: event_type.to_owned(),
+            payload: serde_json::to_value(kind).unwrap(),
+            sequence_number,
+            correlation_id: Uuid::new_v4(),
+            causation_id: Uuid::new_v4(),
+            occurred_at: fixed_now(),
+            schema_version: 1,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_get_encounter_by_id_returns_turn_state_and_linked_actions() {
+        // Arrange
+        let encounter_id = Uuid::new_v4();
+        let hero = Uuid::new_v4();
+        let resolution_id = Uuid::new_v4();
+        let events = vec![
+            encounter_event(
+                encounter_id,
+                1,
+                EncounterEventKind::EncounterStarted(EncounterStarted {
+                    encounter_id,
+                    initiative: vec![InitiativeRoll {
+                        participant_id: hero,
+                        name: "Hero".to_owned(),
+                        modifier: 2,
+                        natural_roll: 11,
+                        initiative: 13,
+                    }],
+                    turn_order: vec![hero],
+                }),
+            ),
+            encounter_event(
+                encounter_id,
+                2,
+                EncounterEventKind::TurnStarted(TurnStarted {
+                    encounter_id,
+                    round: 1,
+                    participant_id: hero,
+                }),
+            ),
+            encounter_event(
+                encounter_id,
+                3,
+                EncounterEventKind::ResolutionLinked(ResolutionLinked {
+                    encounter_id,
+                    round: 1,
+                    participant_id: hero,
+                    resolution_id,
+                }),
+            ),
+        ];
+        let repo = RecordingEventRepository::with_aggregate_ids(Ok(events), vec![encounter_id]);
+        let store = InMemoryReadModelStore::new();
+
+        // Act
+        let view = get_encounter_by_id(encounter_id, &repo, &store)
+            .await
+            .unwrap();
+        let summaries = list_encounters(&repo, &store).await.unwrap();
+
+        // Assert
+        assert_eq!(view.phase, "active");
+        assert_eq!(view.round, 1);
+        assert_eq!(view.current_participant_id, Some(hero));
+        assert_eq!(view.initiative[0].initiative, 13);
+        assert_eq!(view.actions.len(), 1);
+        assert_eq!(view.actions[0].resolution_id, resolution_id);
+        assert_eq!(view.version, 3);
+        assert_eq!(summaries.len(), 1);
+        assert_eq!(summaries[0].encounter_id, encounter_id);
+    }
+
+    #[test]
+    fn test_get_check_odds_names_tiers_by_ruleset() {
+        // Act
+        let view = get_check_odds(RulesetId::Pbta, 0, 1, RollMode::Normal).unwrap();
+
+        // Assert
+        let weak_hit = &view.outcomes[2];
+        assert_eq!(weak_hit.outcome, "partial_success");
+        assert_eq!(weak_hit.tier, "weak hit");
+        assert!((weak_hit.probability - 16.0 / 36.0).abs() < 1e-12);
+        let total: f64 = view.outcomes.iter().map(|o| o.probability).sum();
+        assert!((total - 1.0).abs() < 1e-12);
+    }
 }
@@ -1,19 +1,30 @@
 //! Aggregate roots for the Rules & Resolution context.
 
+use std::collections::BTreeMap;
+
 use otherworlds_core::aggregate::AggregateRoot;
 use otherworlds_core::clock::Clock;
 use otherworlds_core::error::DomainError;
 use otherworlds_core::event::EventMetadata;
 use otherworlds_core::rng::DeterministicRng;
+use otherworlds_core::snapshot::Snapshottable;
+use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use super::dice::{DiceExpression, DiceRoll};
 use super::events::{
-    CheckOutcome, CheckResolved, EffectsProduced, IntentDeclared, ResolutionArchived,
-    ResolvedEffect, RulesEvent, RulesEventKind, determine_outcome,
+    CheckOutcome, CheckResolved, CheckRoll, EffectsProduced, FiredTier, IntentDeclared,
+    OpposedCheckResolved, OpposedIntentDeclared, OpposedParticipant, OpposedRoll, OpposedSide,
+    ResolutionArchived, ResolvedEffect, RollMode, RulesEvent, RulesEventKind, TieBreak,
+    determine_opposed_outcome,
 };
+use super::modifiers::ModifierBreakdown;
+use super::outcome_table::OutcomeTable;
+use super::ruleset::{Ruleset, RulesetId};
+use super::upcasters::current_schema_version;
 
 /// Resolution phase state machine.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub(crate) enum ResolutionPhase {
     Created,
     IntentDeclared,
@@ -22,7 +33,7 @@ pub(crate) enum ResolutionPhase {
 }
 
 /// Captured intent details within the aggregate.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub(crate) struct DeclaredIntent {
     pub intent_id: Uuid,
     pub action_type: String,
@@ -30,10 +41,15 @@ pub(crate) struct DeclaredIntent {
     pub target_id: Option<Uuid>,
     pub difficulty_class: i32,
     pub modifier: i32,
+    pub roll_expression: DiceExpression,
+    pub stats: BTreeMap<String, i32>,
+    pub roll_mode: RollMode,
+    pub ruleset: RulesetId,
+    pub modifier_breakdown: Option<ModifierBreakdown>,
 }
 
 /// Captured check result within the aggregate.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub(crate) struct CheckResult {
     pub check_id: Uuid,
     pub natural_roll: u32,
@@ -41,6 +57,100 @@ pub(crate) struct CheckResult {
     pub total: i32,
     pub difficulty_class: i32,
     pub outcome: CheckOutcome,
+    pub rolls: Vec<CheckRoll>,
+    pub kept_roll: usize,
+}
+
+/// Captured opposed intent details within the aggregate.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct DeclaredOpposedIntent {
+    pub intent_id: Uuid,
+    pub action_type: String,
+    pub skill: Option<String>,
+    pub actor: OpposedParticipant,
+    pub opponent: OpposedParticipant,
+    pub roll_expression: DiceExpression,
+    pub ruleset: RulesetId,
+    pub tie_break: TieBreak,
+}
+
+/// Captured opposed check result within the aggregate.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct OpposedResult {
+    pub check_id: Uuid,
+    pub actor: OpposedRoll,
+    pub opponent: OpposedRoll,
+    pub margin: i32,
+    pub outcome: CheckOutcome,
+    pub winner: Option<OpposedSide>,
+}
+
+/// The rolls made for one side of a check.
+struct RolledCheck {
+    rolls: Vec<DiceRoll>,
+    kept_roll: usize,
+    modifier: i32,
+    total: i32,
+}
+
+impl RolledCheck {
+    /// Rolls `expression` as many times as `roll_mode` asks, keeps the roll
+    /// `ruleset` considers best or worst, and totals it with `modifier`.
+    fn roll(
+        expression: &DiceExpression,
+        stats: &BTreeMap<String, i32>,
+        roll_mode: RollMode,
+        modifier: i32,
+        ruleset: &dyn Ruleset,
+        rng: &mut dyn DeterministicRng,
+    ) -> Result<Self, DomainError> {
+        let rolls = (0..roll_mode.rolls())
+            .map(|_| expression.evaluate(stats, rng))
+            .collect::<Result<Vec<DiceRoll>, DomainError>>()?;
+        let natural_rolls: Vec<u32> = rolls.iter().map(|r| r.dice_total).collect();
+        let kept_roll = roll_mode.select(&natural_rolls, ruleset.higher_is_better());
+        let roll = &rolls[kept_roll];
+
+        let (modifier, total) = roll
+            .modifier
+            .checked_add(modifier)
+            .and_then(|modifier| Some((modifier, ruleset.total(roll.dice_total, modifier)?)))
+            .ok_or_else(|| DomainError::Validation("roll modifier overflows".to_owned()))?;
+        Ok(Self {
+            rolls,
+            kept_roll,
+            modifier,
+            total,
+        })
+    }
+
+    fn kept(&self) -> &DiceRoll {
+        &self.rolls[self.kept_roll]
+    }
+
+    fn natural_roll(&self) -> u32 {
+        self.kept().dice_total
+    }
+
+    fn into_check_rolls(self) -> Vec<CheckRoll> {
+        self.rolls
+            .into_iter()
+            .map(|r| CheckRoll {
+                natural_roll: r.dice_total,
+                dice: r.dice,
+            })
+            .collect()
+    }
+
+    fn into_opposed_roll(self) -> OpposedRoll {
+        OpposedRoll {
+            natural_roll: self.natural_roll(),
+            modifier: self.modifier,
+            total: self.total,
+            kept_roll: self.kept_roll,
+            rolls: self.into_check_rolls(),
+        }
+    }
 }
 
 /// Parameters for declaring a player intent.
@@ -58,10 +168,43 @@ pub struct DeclareIntentParams {
     pub difficulty_class: i32,
     /// The modifier applied to the roll.
     pub modifier: i32,
+    /// The dice expression to roll (the ruleset's default roll when the
+    /// player names none).
+    pub roll_expression: DiceExpression,
+    /// Values of the stats the roll expression references.
+    pub stats: BTreeMap<String, i32>,
+    /// How many times the expression is rolled and which roll counts.
+    pub roll_mode: RollMode,
+    /// The ruleset the check is rolled and judged by.
+    pub ruleset: RulesetId,
+    /// How the modifier was derived from the acting character's sheet, if
+    /// it was.
+    pub modifier_breakdown: Option<ModifierBreakdown>,
+}
+
+/// Parameters for declaring an intent contested by an opponent.
+#[derive(Debug, Clone)]
+pub struct DeclareOpposedIntentParams {
+    /// The intent identifier.
+    pub intent_id: Uuid,
+    /// The type of action (e.g., "`grapple`", "`stealth`", "`haggle`").
+    pub action_type: String,
+    /// Optional skill being used.
+    pub skill: Option<String>,
+    /// The participant initiating the contest.
+    pub actor: OpposedParticipant,
+    /// The participant resisting it.
+    pub opponent: OpposedParticipant,
+    /// The dice expression both participants roll.
+    pub roll_expression: DiceExpression,
+    /// The ruleset the rolls are made under.
+    pub ruleset: RulesetId,
+    /// How equal totals are settled.
+    pub tie_break: TieBreak,
 }
 
 /// The aggregate root for a resolution.
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct Resolution {
     /// Aggregate identifier.
     pub id: Uuid,
@@ -73,11 +216,21 @@ pub struct Resolution {
     pub(crate) intent: Option<DeclaredIntent>,
     /// Check result (set after `CheckResolved`).
     pub(crate) check_result: Option<CheckResult>,
+    /// Declared opposed intent (set after `OpposedIntentDeclared`).
+    #[serde(default)]
+    pub(crate) opposed_intent: Option<DeclaredOpposedIntent>,
+    /// Opposed check result (set after `OpposedCheckResolved`).
+    #[serde(default)]
+    pub(crate) opposed_result: Option<OpposedResult>,
     /// Produced effects (set after `EffectsProduced`).
     pub(crate) effects: Vec<ResolvedEffect>,
+    /// The outcome table tier that selected the effects, if one did.
+    #[serde(default)]
+    pub(crate) fired_tier: Option<FiredTier>,
     /// Whether this resolution has been archived (soft-deleted).
     pub(crate) archived: bool,
     /// Uncommitted events pending persistence.
+    #[serde(skip)]
     uncommitted_events: Vec<RulesEvent>,
 }
 
@@ -91,7 +244,10 @@ impl Resolution {
             phase: ResolutionPhase::Created,
             intent: None,
             check_result: None,
+            opposed_intent: None,
+            opposed_result: None,
             effects: Vec::new(),
+            fired_tier: None,
             archived: false,
             uncommitted_events: Vec::new(),
         }
@@ -118,7 +274,9 @@ impl Resolution {
     ///
     /// # Errors
     ///
-    /// Returns `DomainError::Validation` if not in `Created` phase.
+    /// Returns `DomainError::Validation` if not in `Created` phase, if the
+    /// roll expression references a stat missing from `params.stats`, or if
+    /// the roll mode rolls too few or too many times.
     pub fn declare_intent(
         &mut self,
         params: DeclareIntentParams,
@@ -131,6 +289,24 @@ impl Resolution {
                 "resolution must be in Created phase".to_owned(),
             ));
         }
+        if let Some(missing) = params
+            .roll_expression
+            .stat_names()
+            .find(|name| !params.stats.contains_key(*name))
+        {
+            return Err(DomainError::Validation(format!(
+                "roll references unknown stat @{missing}"
+            )));
+        }
+        params.roll_mode.validate()?;
+        if let Some(breakdown) = &params.modifier_breakdown
+            && breakdown.total != params.modifier
+        {
+            return Err(DomainError::Validation(format!(
+                "modifier {} does not match its derived total {}",
+                params.modifier, breakdown.total
+            )));
+        }
 
         let event = RulesEvent {
             metadata: EventMetadata {
@@ -141,6 +317,7 @@ impl Resolution {
                 correlation_id,
                 causation_id: correlation_id,
                 occurred_at: clock.now(),
+                schema_version: current_schema_version("rules.intent_declared"),
             },
             kind: RulesEventKind::IntentDeclared(IntentDeclared {
                 resolution_id: self.id,
@@ -150,6 +327,11 @@ impl Resolution {
                 target_id: params.target_id,
                 difficulty_class: params.difficulty_class,
                 modifier: params.modifier,
+                roll_expression: params.roll_expression,
+                stats: params.stats,
+                roll_mode: params.roll_mode,
+                ruleset: params.ruleset,
+                modifier_breakdown: params.modifier_breakdown,
             }),
         };
 
@@ -157,16 +339,74 @@ impl Resolution {
         Ok(())
     }
 
-    /// Resolves a d20 check, producing a `CheckResolved` event.
+    /// Declares an intent contested by an opponent, producing an
+    /// `OpposedIntentDeclared` event.
     ///
-    /// Rolls 1d20 using the provided RNG, adds the intent's modifier,
-    /// and determines the five-tier outcome.
+    /// # Errors
+    ///
+    /// Returns `DomainError::Validation` if not in `Created` phase, if the
+    /// roll expression references a stat, or if either roll mode rolls too
+    /// few or too many times.
+    pub fn declare_opposed_intent(
+        &mut self,
+        params: DeclareOpposedIntentParams,
+        correlation_id: Uuid,
+        clock: &dyn Clock,
+        rng: &mut dyn DeterministicRng,
+    ) -> Result<(), DomainError> {
+        if self.phase != ResolutionPhase::Created {
+            return Err(DomainError::Validation(
+                "resolution must be in Created phase".to_owned(),
+            ));
+        }
+        if let Some(stat) = params.roll_expression.stat_names().next() {
+            return Err(DomainError::Validation(format!(
+                "opposed rolls cannot reference stats (found @{stat})"
+            )));
+        }
+        params.actor.roll_mode.validate()?;
+        params.opponent.roll_mode.validate()?;
+
+        let event = RulesEvent {
+            metadata: EventMetadata {
+                event_id: rng.next_uuid(),
+                event_type: "rules.opposed_intent_declared".to_owned(),
+                aggregate_id: self.id,
+                sequence_number: self.next_sequence_number(),
+                correlation_id,
+                causation_id: correlation_id,
+                occurred_at: clock.now(),
+                schema_version: current_schema_version("rules.opposed_intent_declared"),
+            },
+            kind: RulesEventKind::OpposedIntentDeclared(OpposedIntentDeclared {
+                resolution_id: self.id,
+                intent_id: params.intent_id,
+                action_type: params.action_type,
+                skill: params.skill,
+                actor: params.actor,
+                opponent: params.opponent,
+                roll_expression: params.roll_expression,
+                ruleset: params.ruleset,
+                tie_break: params.tie_break,
+            }),
+        };
+
+        self.uncommitted_events.push(event);
+        Ok(())
+    }
+
+    /// Resolves a check, producing a `CheckResolved` event, or an
+    /// `OpposedCheckResolved` event when the declared intent is opposed.
+    ///
+    /// Rolls the intent's dice expression as many times as its roll mode asks,
+    /// keeps the best or worst roll, adds the intent's modifier, and lets the
+    /// intent's ruleset determine the five-tier outcome. Under the d20 rules,
+    /// natural 1s and 20s only count when the kept roll keeps a single d20.
     ///
     /// # Errors
     ///
-    /// Returns `DomainError::Validation` if not in `IntentDeclared` phase
-    /// or if intent state is missing.
-    #[allow(clippy::cast_possible_wrap)]
+    /// Returns `DomainError::Validation` if not in `IntentDeclared` phase,
+    /// if intent state is missing, or if the roll cannot be evaluated.
     pub fn resolve_check(
         &mut self,
         correlation_id: Uuid,
@@ -178,14 +418,24 @@ impl Resolution {
                 "resolution must be in IntentDeclared phase".to_owned(),
             ));
         }
+        if self.opposed_intent.is_some() {
+            return self.resolve_opposed_check(correlation_id, clock, rng);
+        }
 
         let intent = self.intent.as_ref().ok_or_else(|| {
             DomainError::Validation("missing intent in IntentDeclared phase".to_owned())
         })?;
 
-        let natural_roll = rng.next_u32_range(1, 20);
-        let total = natural_roll as i32 + intent.modifier;
-        let outcome = determine_outcome(natural_roll, total, intent.difficulty_class);
+        let ruleset = intent.ruleset.ruleset();
+        let rolled = RolledCheck::roll(
+            &intent.roll_expression,
+            &intent.stats,
+            intent.roll_mode,
+            intent.modifier,
+            ruleset,
+            rng,
+        )?;
+        let outcome = ruleset.outcome(rolled.kept(), rolled.total, intent.difficulty_class);
 
         let check_id = rng.next_uuid();
 
@@ -198,15 +448,88 @@ impl Resolution {
                 correlation_id,
                 causation_id: correlation_id,
                 occurred_at: clock.now(),
+                schema_version: current_schema_version("rules.check_resolved"),
             },
             kind: RulesEventKind::CheckResolved(CheckResolved {
                 resolution_id: self.id,
                 check_id,
-                natural_roll,
-                modifier: intent.modifier,
-                total,
+                natural_roll: rolled.natural_roll(),
+                modifier: rolled.modifier,
+                total: rolled.total,
                 difficulty_class: intent.difficulty_class,
                 outcome,
+                kept_roll: rolled.kept_roll,
+                rolls: rolled.into_check_rolls(),
+            }),
+        };
+
+        self.uncommitted_events.push(event);
+        Ok(())
+    }
+
+    /// Rolls both sides of an opposed check, actor first, and compares their
+    /// totals, producing an `OpposedCheckResolved` event.
+    fn resolve_opposed_check(
+        &mut self,
+        correlation_id: Uuid,
+        clock: &dyn Clock,
+        rng: &mut dyn DeterministicRng,
+    ) -> Result<(), DomainError> {
+        let intent = self.opposed_intent.as_ref().ok_or_else(|| {
+            DomainError::Validation("missing opposed intent in IntentDeclared phase".to_owned())
+        })?;
+
+        let ruleset = intent.ruleset.ruleset();
+        let no_stats = BTreeMap::new();
+        let [actor, opponent] = [intent.actor, intent.opponent].map(|participant| {
+            RolledCheck::roll(
+                &intent.roll_expression,
+                &no_stats,
+                participant.roll_mode,
+                participant.modifier,
+                ruleset,
+                rng,
+            )
+        });
+        let (actor, opponent) = (actor?, opponent?);
+
+        let difference = actor
+            .total
+            .checked_sub(opponent.total)
+            .ok_or_else(|| DomainError::Validation("opposed margin overflows".to_owned()))?;
+        let margin = if ruleset.higher_is_better() {
+            difference
+        } else {
+            -difference
+        };
+        let (outcome, winner) = determine_opposed_outcome(
+            margin,
+            intent.tie_break,
+            intent.actor.modifier,
+            intent.opponent.modifier,
+        );
+
+        let check_id = rng.next_uuid();
+
+        let event = RulesEvent {
+            metadata: EventMetadata {
+                event_id: rng.next_uuid(),
+                event_type: "rules.opposed_check_resolved".to_owned(),
+                aggregate_id: self.id,
+                sequence_number: self.next_sequence_number(),
+                correlation_id,
+                causation_id: correlation_id,
+                occurred_at: clock.now(),
+                schema_version: current_schema_version("rules.opposed_check_resolved"),
+            },
+            kind: RulesEventKind::OpposedCheckResolved(OpposedCheckResolved {
+                resolution_id: self.id,
+                check_id,
+                actor: actor.into_opposed_roll(),
+                opponent: opponent.into_opposed_roll(),
+                margin,
+                outcome,
+                winner,
             }),
         };
 
@@ -225,6 +548,47 @@ impl Resolution {
         correlation_id: Uuid,
         clock: &dyn Clock,
         rng: &mut dyn DeterministicRng,
+    ) -> Result<(), DomainError> {
+        self.record_effects(effects, None, correlation_id, clock, rng)
+    }
+
+    /// Produces the effects of the `table` tier the check's outcome selects,
+    /// following fallbacks, producing an `EffectsProduced` event that records
+    /// which tier fired.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::Validation` if not in `CheckResolved` phase or
+    /// if the table's fallbacks do not resolve.
+    pub fn produce_outcome_effects(
+        &mut self,
+        table: &OutcomeTable<ResolvedEffect>,
+        correlation_id: Uuid,
+        clock: &dyn Clock,
+        rng: &mut dyn DeterministicRng,
+    ) -> Result<(), DomainError> {
+        let outcome = self
+            .check_result
+            .as_ref()
+            .map(|c| c.outcome)
+            .or_else(|| self.opposed_result.as_ref().map(|o| o.outcome));
+        let Some(outcome) = outcome.filter(|_| self.phase == ResolutionPhase::CheckResolved) else {
+            return Err(DomainError::Validation(
+                "resolution must be in CheckResolved phase".to_owned(),
+            ));
+        };
+        table.validate()?;
+        let (tier, effects) = table.select(outcome)?;
+        self.record_effects(effects, Some(tier), correlation_id, clock, rng)
+    }
+
+    fn record_effects(
+        &mut self,
+        effects: Vec<ResolvedEffect>,
+        tier: Option<FiredTier>,
+        correlation_id: Uuid,
+        clock: &dyn Clock,
+        rng: &mut dyn DeterministicRng,
     ) -> Result<(), DomainError> {
         if self.phase != ResolutionPhase::CheckResolved {
             return Err(DomainError::Validation(
@@ -241,10 +605,12 @@ impl Resolution {
                 correlation_id,
                 causation_id: correlation_id,
                 occurred_at: clock.now(),
+                schema_version: current_schema_version("rules.effects_produced"),
             },
             kind: RulesEventKind::EffectsProduced(EffectsProduced {
                 resolution_id: self.id,
                 effects,
+                tier,
             }),
         };
 
@@ -278,6 +644,7 @@ impl Resolution {
                 correlation_id,
                 causation_id: correlation_id,
                 occurred_at: clock.now(),
+                schema_version: current_schema_version("rules.resolution_archived"),
             },
             kind: RulesEventKind::ResolutionArchived(ResolutionArchived {
                 resolution_id: self.id,
@@ -311,6 +678,11 @@ impl AggregateRoot for Resolution {
                     target_id: payload.target_id,
                     difficulty_class: payload.difficulty_class,
                     modifier: payload.modifier,
+                    roll_expression: payload.roll_expression.clone(),
+                    stats: payload.stats.clone(),
+                    roll_mode: payload.roll_mode,
+                    ruleset: payload.ruleset,
+                    modifier_breakdown: payload.modifier_breakdown.clone(),
                 });
             }
             RulesEventKind::CheckResolved(payload) => {
@@ -322,11 +694,38 @@ impl AggregateRoot for Resolution {
                     total: payload.total,
                     difficulty_class: payload.difficulty_class,
                     outcome: payload.outcome,
+                    rolls: payload.rolls.clone(),
+                    kept_roll: payload.kept_roll,
+                });
+            }
+            RulesEventKind::OpposedIntentDeclared(payload) => {
+                self.phase = ResolutionPhase::IntentDeclared;
+                self.opposed_intent = Some(DeclaredOpposedIntent {
+                    intent_id: payload.intent_id,
+                    action_type: payload.action_type.clone(),
+                    skill: payload.skill.clone(),
+                    actor: payload.actor,
+                    opponent: payload.opponent,
+                    roll_expression: payload.roll_expression.clone(),
+                    ruleset: payload.ruleset,
+                    tie_break: payload.tie_break,
+                });
+            }
+            RulesEventKind::OpposedCheckResolved(payload) => {
+                self.phase = ResolutionPhase::CheckResolved;
+                self.opposed_result = Some(OpposedResult {
+                    check_id: payload.check_id,
+                    actor: payload.actor.clone(),
+                    opponent: payload.opponent.clone(),
+                    margin: payload.margin,
+                    outcome: payload.outcome,
+                    winner: payload.winner,
                 });
             }
             RulesEventKind::EffectsProduced(payload) => {
                 self.phase = ResolutionPhase::EffectsProduced;
                 self.effects.clone_from(&payload.effects);
+                self.fired_tier = payload.tier;
             }
             RulesEventKind::ResolutionArchived(_) => {
                 self.archived = true;
@@ -344,6 +743,10 @@ impl AggregateRoot for Resolution {
     }
 }
 
+impl Snapshottable for Resolution {
+    const AGGREGATE_TYPE: &'static str = "rules.resolution";
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -352,6 +755,9 @@ mod tests {
     use otherworlds_core::event::DomainEvent;
     use otherworlds_test_support::{FixedClock, MockRng, SequenceRng};
 
+    use crate::domain::effects::{ApplyStatus, Damage, Effect, Heal};
+    use crate::domain::outcome_table::{Fallback, OutcomeBranch};
+
     fn fixed_clock() -> FixedClock {
         FixedClock(Utc.with_ymd_and_hms(2026, 1, 15, 10, 0, 0).unwrap())
     }
@@ -374,6 +780,11 @@ mod tests {
                 target_id: None,
                 difficulty_class: 15,
                 modifier: 3,
+                roll_expression: DiceExpression::d20(),
+                stats: BTreeMap::new(),
+                roll_mode: RollMode::Normal,
+                ruleset: RulesetId::D20,
+                modifier_breakdown: None,
             },
             correlation_id,
             &clock,
@@ -391,6 +802,38 @@ mod tests {
         assert_eq!(meta.correlation_id, correlation_id);
     }
 
+    #[test]
+    fn test_declare_intent_with_breakdown_not_matching_modifier_returns_error() {
+        let mut resolution = Resolution::new(Uuid::new_v4());
+
+        let result = resolution.declare_intent(
+            DeclareIntentParams {
+                intent_id: Uuid::new_v4(),
+                action_type: "skill_check".to_owned(),
+                skill: Some("strength".to_owned()),
+                target_id: None,
+                difficulty_class: 12,
+                modifier: 5,
+                roll_expression: DiceExpression::d20(),
+                stats: BTreeMap::new(),
+                roll_mode: RollMode::Normal,
+                ruleset: RulesetId::D20,
+                modifier_breakdown: Some(ModifierBreakdown {
+                    character_id: Uuid::new_v4(),
+                    skill: "strength".to_owned(),
+                    attribute: "strength".to_owned(),
+                    components: Vec::new(),
+                    total: 0,
+                }),
+            },
+            Uuid::new_v4(),
+            &fixed_clock(),
+            &mut MockRng,
+        );
+
+        assert!(matches!(result, Err(DomainError::Validation(msg)) if msg.contains("derived")));
+    }
+
     #[test]
     fn test_declare_intent_in_intent_declared_phase_returns_error() {
         let mut resolution = Resolution::new(Uuid::new_v4());
@@ -404,6 +847,11 @@ mod tests {
                 target_id: None,
                 difficulty_class: 12,
                 modifier: 0,
+                roll_expression: DiceExpression::d20(),
+                stats: BTreeMap::new(),
+                roll_mode: RollMode::Normal,
+                ruleset: RulesetId::D20,
+                modifier_breakdown: None,
             },
             Uuid::new_v4(),
             &fixed_clock(),
@@ -432,6 +880,11 @@ mod tests {
                 target_id: None,
                 difficulty_class: 10,
                 modifier: 2,
+                roll_expression: DiceExpression::d20(),
+                stats: BTreeMap::new(),
+                roll_mode: RollMode::Normal,
+                ruleset: RulesetId::D20,
+                modifier_breakdown: None,
             },
             Uuid::new_v4(),
             &fixed_clock(),
@@ -455,6 +908,7 @@ mod tests {
                 correlation_id: Uuid::new_v4(),
                 causation_id: Uuid::new_v4(),
                 occurred_at: Utc::now(),
+                schema_version: current_schema_version("rules.intent_declared"),
             },
             kind: RulesEventKind::IntentDeclared(IntentDeclared {
                 resolution_id,
@@ -464,6 +918,11 @@ mod tests {
                 target_id: None,
                 difficulty_class: 15,
                 modifier: 5,
+                roll_expression: DiceExpression::d20(),
+                stats: BTreeMap::new(),
+                roll_mode: RollMode::Normal,
+                ruleset: RulesetId::D20,
+                modifier_breakdown: None,
             }),
         };
 
@@ -493,6 +952,7 @@ mod tests {
                 correlation_id: Uuid::new_v4(),
                 causation_id: Uuid::new_v4(),
                 occurred_at: Utc::now(),
+                schema_version: current_schema_version("rules.intent_declared"),
             },
             kind: RulesEventKind::IntentDeclared(IntentDeclared {
                 resolution_id,
@@ -502,6 +962,11 @@ mod tests {
                 target_id: Some(target_id),
                 difficulty_class: 12,
                 modifier: -1,
+                roll_expression: DiceExpression::d20(),
+                stats: BTreeMap::new(),
+                roll_mode: RollMode::Normal,
+                ruleset: RulesetId::D20,
+                modifier_breakdown: None,
             }),
         };
 
@@ -528,6 +993,11 @@ mod tests {
                     target_id: None,
                     difficulty_class: 15,
                     modifier: 0,
+                    roll_expression: DiceExpression::d20(),
+                    stats: BTreeMap::new(),
+                    roll_mode: RollMode::Normal,
+                    ruleset: RulesetId::D20,
+                    modifier_breakdown: None,
                 },
                 Uuid::new_v4(),
                 &clock,
@@ -558,6 +1028,7 @@ mod tests {
                 correlation_id: Uuid::new_v4(),
                 causation_id: Uuid::new_v4(),
                 occurred_at: Utc::now(),
+                schema_version: current_schema_version("rules.intent_declared"),
             },
             kind: RulesEventKind::IntentDeclared(IntentDeclared {
                 resolution_id,
@@ -567,6 +1038,11 @@ mod tests {
                 target_id: None,
                 difficulty_class: 15,
                 modifier: 3,
+                roll_expression: DiceExpression::d20(),
+                stats: BTreeMap::new(),
+                roll_mode: RollMode::Normal,
+                ruleset: RulesetId::D20,
+                modifier_breakdown: None,
             }),
         };
         let event2 = RulesEvent {
@@ -578,6 +1054,7 @@ mod tests {
                 correlation_id: Uuid::new_v4(),
                 causation_id: Uuid::new_v4(),
                 occurred_at: Utc::now(),
+                schema_version: current_schema_version("rules.check_resolved"),
             },
             kind: RulesEventKind::CheckResolved(CheckResolved {
                 resolution_id,
@@ -587,6 +1064,8 @@ mod tests {
                 total: 18,
                 difficulty_class: 15,
                 outcome: CheckOutcome::Success,
+                rolls: Vec::new(),
+                kept_roll: 0,
             }),
         };
 
@@ -615,6 +1094,11 @@ mod tests {
             target_id: None,
             difficulty_class: 15,
             modifier: 3,
+            roll_expression: DiceExpression::d20(),
+            stats: BTreeMap::new(),
+            roll_mode: RollMode::Normal,
+            ruleset: RulesetId::D20,
+            modifier_breakdown: None,
         });
 
         let mut rng = SequenceRng::new(vec![15, 42, 99, 7, 13, 0, 0, 0, 0]);
@@ -676,6 +1160,11 @@ mod tests {
             target_id: None,
             difficulty_class: 5,
             modifier: 10,
+            roll_expression: DiceExpression::d20(),
+            stats: BTreeMap::new(),
+            roll_mode: RollMode::Normal,
+            ruleset: RulesetId::D20,
+            modifier_breakdown: None,
         });
         let mut rng = SequenceRng::new(vec![1, 42, 99, 7, 13, 0, 0, 0, 0]);
 
@@ -703,6 +1192,11 @@ mod tests {
             target_id: None,
             difficulty_class: 30,
             modifier: -5,
+            roll_expression: DiceExpression::d20(),
+            stats: BTreeMap::new(),
+            roll_mode: RollMode::Normal,
+            ruleset: RulesetId::D20,
+            modifier_breakdown: None,
         });
         let mut rng = SequenceRng::new(vec![20, 42, 99, 7, 13, 0, 0, 0, 0]);
 
@@ -730,6 +1224,11 @@ mod tests {
             target_id: None,
             difficulty_class: 15,
             modifier: 3,
+            roll_expression: DiceExpression::d20(),
+            stats: BTreeMap::new(),
+            roll_mode: RollMode::Normal,
+            ruleset: RulesetId::D20,
+            modifier_breakdown: None,
         });
         // Roll 15 + modifier 3 = total 18, DC 15 → Success
         let mut rng = SequenceRng::new(vec![15, 42, 99, 7, 13, 0, 0, 0, 0]);
@@ -758,6 +1257,11 @@ mod tests {
             target_id: None,
             difficulty_class: 15,
             modifier: 2,
+            roll_expression: DiceExpression::d20(),
+            stats: BTreeMap::new(),
+            roll_mode: RollMode::Normal,
+            ruleset: RulesetId::D20,
+            modifier_breakdown: None,
         });
         // Roll 8 + modifier 2 = total 10, DC 15 → 10 >= 15-5 → PartialSuccess
         let mut rng = SequenceRng::new(vec![8, 42, 99, 7, 13, 0, 0, 0, 0]);
@@ -786,6 +1290,11 @@ mod tests {
             target_id: None,
             difficulty_class: 15,
             modifier: 1,
+            roll_expression: DiceExpression::d20(),
+            stats: BTreeMap::new(),
+            roll_mode: RollMode::Normal,
+            ruleset: RulesetId::D20,
+            modifier_breakdown: None,
         });
         // Roll 3 + modifier 1 = total 4, DC 15 → 4 < 10 → Failure
         let mut rng = SequenceRng::new(vec![3, 42, 99, 7, 13, 0, 0, 0, 0]);
@@ -814,6 +1323,11 @@ mod tests {
             target_id: None,
             difficulty_class: 5,
             modifier: 5,
+            roll_expression: DiceExpression::d20(),
+            stats: BTreeMap::new(),
+            roll_mode: RollMode::Normal,
+            ruleset: RulesetId::D20,
+            modifier_breakdown: None,
         });
         // Roll 10 + modifier 5 = total 15, DC 5 → 15 >= 5+10 → CriticalSuccess
         let mut rng = SequenceRng::new(vec![10, 42, 99, 7, 13, 0, 0, 0, 0]);
@@ -831,6 +1345,472 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_declare_intent_with_unbound_stat_returns_error() {
+        let mut resolution = Resolution::new(Uuid::new_v4());
+
+        let result = resolution.declare_intent(
+            DeclareIntentParams {
+                intent_id: Uuid::new_v4(),
+                action_type: "skill_check".to_owned(),
+                skill: Some("athletics".to_owned()),
+                target_id: None,
+                difficulty_class: 10,
+                modifier: 0,
+                roll_expression: "1d20+@strength".parse().unwrap(),
+                stats: BTreeMap::new(),
+                roll_mode: RollMode::Normal,
+                ruleset: RulesetId::D20,
+                modifier_breakdown: None,
+            },
+            Uuid::new_v4(),
+            &fixed_clock(),
+            &mut MockRng,
+        );
+
+        assert!(matches!(result, Err(DomainError::Validation(msg)) if msg.contains("@strength")));
+    }
+
+    #[test]
+    fn test_resolve_check_rolls_expression_and_records_dice() {
+        let mut resolution = Resolution::new(Uuid::new_v4());
+        resolution.phase = ResolutionPhase::IntentDeclared;
+        resolution.intent = Some(DeclaredIntent {
+            intent_id: Uuid::new_v4(),
+            action_type: "attack".to_owned(),
+            skill: None,
+            target_id: None,
+            difficulty_class: 12,
+            modifier: 1,
+            roll_expression: "4d6kh3+@strength".parse().unwrap(),
+            stats: BTreeMap::from([("strength".to_owned(), 2)]),
+            roll_mode: RollMode::Normal,
+            ruleset: RulesetId::D20,
+            modifier_breakdown: None,
+        });
+        // Dice 3, 6, 1, 5 keep 14; + stat 2 + intent modifier 1 = 17
+        let mut rng = SequenceRng::new(vec![3, 6, 1, 5, 42, 99, 7, 13, 0, 0, 0, 0]);
+
+        resolution
+            .resolve_check(Uuid::new_v4(), &fixed_clock(), &mut rng)
+            .unwrap();
+
+        match &resolution.uncommitted_events()[0].kind {
+            RulesEventKind::CheckResolved(payload) => {
+                assert_eq!(payload.natural_roll, 14);
+                assert_eq!(payload.modifier, 3);
+                assert_eq!(payload.total, 17);
+                assert_eq!(payload.outcome, CheckOutcome::Success);
+                let dice = &payload.rolls[0].dice;
+                let values: Vec<u32> = dice.iter().map(|d| d.value).collect();
+                assert_eq!(values, [3, 6, 1, 5]);
+                assert!(!dice[2].kept);
+            }
+            other => panic!("expected CheckResolved, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_resolve_check_without_single_d20_ignores_natural_faces() {
+        let mut resolution = Resolution::new(Uuid::new_v4());
+        resolution.phase = ResolutionPhase::IntentDeclared;
+        resolution.intent = Some(DeclaredIntent {
+            intent_id: Uuid::new_v4(),
+            action_type: "skill_check".to_owned(),
+            skill: None,
+            target_id: None,
+            difficulty_class: 15,
+            modifier: 0,
+            roll_expression: "1d100".parse().unwrap(),
+            stats: BTreeMap::new(),
+            roll_mode: RollMode::Normal,
+            ruleset: RulesetId::D20,
+            modifier_breakdown: None,
+        });
+        // A d100 showing 20 is a Success by total, not a natural 20.
+        let mut rng = SequenceRng::new(vec![20, 42, 99, 7, 13, 0, 0, 0, 0]);
+
+        resolution
+            .resolve_check(Uuid::new_v4(), &fixed_clock(), &mut rng)
+            .unwrap();
+
+        match &resolution.uncommitted_events()[0].kind {
+            RulesEventKind::CheckResolved(payload) => {
+                assert_eq!(payload.natural_roll, 20);
+                assert_eq!(payload.outcome, CheckOutcome::Success);
+            }
+            other => panic!("expected CheckResolved, got {other:?}"),
+        }
+    }
+
+    fn resolution_with_roll_mode(roll_mode: RollMode) -> Resolution {
+        let mut resolution = Resolution::new(Uuid::new_v4());
+        resolution.phase = ResolutionPhase::IntentDeclared;
+        resolution.intent = Some(DeclaredIntent {
+            intent_id: Uuid::new_v4(),
+            action_type: "attack".to_owned(),
+            skill: None,
+            target_id: None,
+            difficulty_class: 12,
+            modifier: 2,
+            roll_expression: DiceExpression::d20(),
+            stats: BTreeMap::new(),
+            roll_mode,
+            ruleset: RulesetId::D20,
+            modifier_breakdown: None,
+        });
+        resolution
+    }
+
+    fn resolved_check(resolution: &Resolution) -> &CheckResolved {
+        match &resolution.uncommitted_events()[0].kind {
+            RulesEventKind::CheckResolved(payload) => payload,
+            other => panic!("expected CheckResolved, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_resolve_check_with_advantage_discards_natural_1() {
+        let mut resolution = resolution_with_roll_mode(RollMode::Advantage);
+        let mut rng = SequenceRng::new(vec![1, 15, 42, 99, 7, 13, 0, 0, 0, 0]);
+
+        resolution
+            .resolve_check(Uuid::new_v4(), &fixed_clock(), &mut rng)
+            .unwrap();
+
+        let payload = resolved_check(&resolution);
+        let naturals: Vec<u32> = payload.rolls.iter().map(|r| r.natural_roll).collect();
+        assert_eq!(naturals, [1, 15]);
+        assert_eq!(payload.kept_roll, 1);
+        assert_eq!(payload.natural_roll, 15);
+        assert_eq!(payload.total, 17);
+        assert_eq!(payload.outcome, CheckOutcome::Success);
+    }
+
+    #[test]
+    fn test_resolve_check_with_disadvantage_discards_natural_20() {
+        let mut resolution = resolution_with_roll_mode(RollMode::Disadvantage);
+        let mut rng = SequenceRng::new(vec![20, 4, 42, 99, 7, 13, 0, 0, 0, 0]);
+
+        resolution
+            .resolve_check(Uuid::new_v4(), &fixed_clock(), &mut rng)
+            .unwrap();
+
+        let payload = resolved_check(&resolution);
+        assert_eq!(payload.kept_roll, 1);
+        assert_eq!(payload.natural_roll, 4);
+        assert_eq!(payload.outcome, CheckOutcome::Failure);
+    }
+
+    #[test]
+    fn test_resolve_check_kept_natural_20_is_critical_success() {
+        let mut resolution = resolution_with_roll_mode(RollMode::Advantage);
+        let mut rng = SequenceRng::new(vec![3, 20, 42, 99, 7, 13, 0, 0, 0, 0]);
+
+        resolution
+            .resolve_check(Uuid::new_v4(), &fixed_clock(), &mut rng)
+            .unwrap();
+
+        assert_eq!(
+            resolved_check(&resolution).outcome,
+            CheckOutcome::CriticalSuccess
+        );
+    }
+
+    #[test]
+    fn test_resolve_check_keep_worst_of_three_records_every_roll() {
+        let mut resolution = resolution_with_roll_mode(RollMode::KeepWorst(3));
+        let mut rng = SequenceRng::new(vec![14, 9, 17, 42, 99, 7, 13, 0, 0, 0, 0]);
+
+        resolution
+            .resolve_check(Uuid::new_v4(), &fixed_clock(), &mut rng)
+            .unwrap();
+
+        let payload = resolved_check(&resolution);
+        assert_eq!(payload.rolls.len(), 3);
+        assert_eq!(payload.kept_roll, 1);
+        assert_eq!(payload.total, 11);
+        assert_eq!(payload.outcome, CheckOutcome::PartialSuccess);
+    }
+
+    fn resolution_with_ruleset(ruleset: RulesetId, roll_mode: RollMode) -> Resolution {
+        let mut resolution = resolution_with_roll_mode(roll_mode);
+        let intent = resolution.intent.as_mut().unwrap();
+        intent.roll_expression = ruleset.ruleset().default_roll();
+        intent.ruleset = ruleset;
+        resolution
+    }
+
+    #[test]
+    fn test_resolve_check_under_pbta_reads_fixed_bands() {
+        // Arrange — 2d6 showing 4 and 3, +2: a weak hit whatever the DC.
+        let mut resolution = resolution_with_ruleset(RulesetId::Pbta, RollMode::Normal);
+        let mut rng = SequenceRng::new(vec![4, 3, 42, 99, 7, 13, 0, 0, 0, 0]);
+
+        // Act
+        resolution
+            .resolve_check(Uuid::new_v4(), &fixed_clock(), &mut rng)
+            .unwrap();
+
+        // Assert
+        let payload = resolved_check(&resolution);
+        assert_eq!(payload.natural_roll, 7);
+        assert_eq!(payload.total, 9);
+        assert_eq!(payload.outcome, CheckOutcome::PartialSuccess);
+    }
+
+    #[test]
+    fn test_resolve_check_under_percentile_keeps_the_lowest_roll_with_advantage() {
+        // Arrange — rating 12, +2 bonus; rolls of 40 and 11 keep the 11.
+        let mut resolution = resolution_with_ruleset(RulesetId::Percentile, RollMode::Advantage);
+        let mut rng = SequenceRng::new(vec![40, 11, 42, 99, 7, 13, 0, 0, 0, 0]);
+
+        // Act
+        resolution
+            .resolve_check(Uuid::new_v4(), &fixed_clock(), &mut rng)
+            .unwrap();
+
+        // Assert
+        let payload = resolved_check(&resolution);
+        assert_eq!(payload.kept_roll, 1);
+        assert_eq!(payload.natural_roll, 11);
+        assert_eq!(payload.total, 9);
+        assert_eq!(payload.outcome, CheckOutcome::Success);
+    }
+
+    #[test]
+    fn test_declare_intent_with_invalid_roll_mode_returns_error() {
+        let mut resolution = Resolution::new(Uuid::new_v4());
+
+        let result = resolution.declare_intent(
+            DeclareIntentParams {
+                intent_id: Uuid::new_v4(),
+                action_type: "attack".to_owned(),
+                skill: None,
+                target_id: None,
+                difficulty_class: 12,
+                modifier: 0,
+                roll_expression: DiceExpression::d20(),
+                stats: BTreeMap::new(),
+                roll_mode: RollMode::KeepBest(0),
+                ruleset: RulesetId::D20,
+                modifier_breakdown: None,
+            },
+            Uuid::new_v4(),
+            &fixed_clock(),
+            &mut MockRng,
+        );
+
+        assert!(matches!(result, Err(DomainError::Validation(_))));
+    }
+
+    fn participant(modifier: i32, roll_mode: RollMode) -> OpposedParticipant {
+        OpposedParticipant {
+            participant_id: Some(Uuid::new_v4()),
+            modifier,
+            roll_mode,
+        }
+    }
+
+    fn opposed_params(
+        actor: OpposedParticipant,
+        opponent: OpposedParticipant,
+        tie_break: TieBreak,
+    ) -> DeclareOpposedIntentParams {
+        DeclareOpposedIntentParams {
+            intent_id: Uuid::new_v4(),
+            action_type: "grapple".to_owned(),
+            skill: Some("athletics".to_owned()),
+            actor,
+            opponent,
+            roll_expression: DiceExpression::d20(),
+            ruleset: RulesetId::D20,
+            tie_break,
+        }
+    }
+
+    /// Declares an opposed intent and applies it, ready for `resolve_check`.
+    fn opposed_resolution(params: DeclareOpposedIntentParams) -> Resolution {
+        let mut resolution = Resolution::new(Uuid::new_v4());
+        resolution
+            .declare_opposed_intent(params, Uuid::new_v4(), &fixed_clock(), &mut MockRng)
+            .unwrap();
+        let event = resolution.uncommitted_events()[0].clone();
+        resolution.clear_uncommitted_events();
+        resolution.apply(&event);
+        resolution
+    }
+
+    fn resolved_opposed_check(resolution: &Resolution) -> &OpposedCheckResolved {
+        match &resolution.uncommitted_events()[0].kind {
+            RulesEventKind::OpposedCheckResolved(payload) => payload,
+            other => panic!("expected OpposedCheckResolved, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_declare_opposed_intent_produces_event() {
+        // Arrange
+        let mut resolution = Resolution::new(Uuid::new_v4());
+        let params = opposed_params(
+            participant(3, RollMode::Normal),
+            participant(1, RollMode::Advantage),
+            TieBreak::Standoff,
+        );
+
+        // Act
+        resolution
+            .declare_opposed_intent(params, Uuid::new_v4(), &fixed_clock(), &mut MockRng)
+            .unwrap();
+
+        // Assert
+        let events = resolution.uncommitted_events();
+        assert_eq!(events[0].event_type(), "rules.opposed_intent_declared");
+        match &events[0].kind {
+            RulesEventKind::OpposedIntentDeclared(payload) => {
+                assert_eq!(payload.actor.modifier, 3);
+                assert_eq!(payload.opponent.roll_mode, RollMode::Advantage);
+                assert_eq!(payload.tie_break, TieBreak::Standoff);
+            }
+            other => panic!("expected OpposedIntentDeclared, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_declare_opposed_intent_with_stat_reference_returns_error() {
+        // Arrange
+        let mut resolution = Resolution::new(Uuid::new_v4());
+        let mut params = opposed_params(
+            participant(0, RollMode::Normal),
+            participant(0, RollMode::Normal),
+            TieBreak::Defender,
+        );
+        params.roll_expression = "1d20+@strength".parse().unwrap();
+
+        // Act
+        let result =
+            resolution.declare_opposed_intent(params, Uuid::new_v4(), &fixed_clock(), &mut MockRng);
+
+        // Assert
+        assert!(matches!(result, Err(DomainError::Validation(_))));
+    }
+
+    #[test]
+    fn test_declare_opposed_intent_with_invalid_roll_mode_returns_error() {
+        // Arrange
+        let mut resolution = Resolution::new(Uuid::new_v4());
+        let params = opposed_params(
+            participant(0, RollMode::Normal),
+            participant(0, RollMode::KeepWorst(0)),
+            TieBreak::Defender,
+        );
+
+        // Act
+        let result =
+            resolution.declare_opposed_intent(params, Uuid::new_v4(), &fixed_clock(), &mut MockRng);
+
+        // Assert
+        assert!(matches!(result, Err(DomainError::Validation(_))));
+    }
+
+    #[test]
+    fn test_resolve_opposed_check_records_both_rolls_and_margin() {
+        // Arrange — actor rolls 14 (+3 = 17), opponent rolls 8 and 12 with
+        // advantage, keeping 12 (+1 = 13).
+        let mut resolution = opposed_resolution(opposed_params(
+            participant(3, RollMode::Normal),
+            participant(1, RollMode::Advantage),
+            TieBreak::Defender,
+        ));
+        let mut rng = SequenceRng::new(vec![14, 8, 12, 42, 99, 7, 13, 0, 0, 0, 0]);
+
+        // Act
+        resolution
+            .resolve_check(Uuid::new_v4(), &fixed_clock(), &mut rng)
+            .unwrap();
+
+        // Assert
+        let payload = resolved_opposed_check(&resolution);
+        assert_eq!(payload.actor.total, 17);
+        assert_eq!(payload.opponent.rolls.len(), 2);
+        assert_eq!(payload.opponent.kept_roll, 1);
+        assert_eq!(payload.opponent.total, 13);
+        assert_eq!(payload.margin, 4);
+        assert_eq!(payload.outcome, CheckOutcome::Success);
+        assert_eq!(payload.winner, Some(OpposedSide::Actor));
+    }
+
+    #[test]
+    fn test_resolve_opposed_check_tie_goes_to_defender() {
+        // Arrange — both total 12.
+        let mut resolution = opposed_resolution(opposed_params(
+            participant(2, RollMode::Normal),
+            participant(0, RollMode::Normal),
+            TieBreak::Defender,
+        ));
+        let mut rng = SequenceRng::new(vec![10, 12, 42, 99, 7, 13, 0, 0, 0, 0]);
+
+        // Act
+        resolution
+            .resolve_check(Uuid::new_v4(), &fixed_clock(), &mut rng)
+            .unwrap();
+
+        // Assert
+        let payload = resolved_opposed_check(&resolution);
+        assert_eq!(payload.margin, 0);
+        assert_eq!(payload.outcome, CheckOutcome::Failure);
+        assert_eq!(payload.winner, Some(OpposedSide::Opponent));
+    }
+
+    #[test]
+    fn test_resolve_opposed_check_under_percentile_lower_roll_wins() {
+        // Arrange — actor rolls 30, opponent 55: the actor wins by 25.
+        let mut params = opposed_params(
+            participant(0, RollMode::Normal),
+            participant(0, RollMode::Normal),
+            TieBreak::Defender,
+        );
+        params.ruleset = RulesetId::Percentile;
+        params.roll_expression = RulesetId::Percentile.ruleset().default_roll();
+        let mut resolution = opposed_resolution(params);
+        let mut rng = SequenceRng::new(vec![30, 55, 42, 99, 7, 13, 0, 0, 0, 0]);
+
+        // Act
+        resolution
+            .resolve_check(Uuid::new_v4(), &fixed_clock(), &mut rng)
+            .unwrap();
+
+        // Assert
+        let payload = resolved_opposed_check(&resolution);
+        assert_eq!(payload.margin, 25);
+        assert_eq!(payload.outcome, CheckOutcome::CriticalSuccess);
+    }
+
+    #[test]
+    fn test_apply_opposed_check_resolved_moves_to_check_resolved_phase() {
+        // Arrange
+        let mut resolution = opposed_resolution(opposed_params(
+            participant(0, RollMode::Normal),
+            participant(0, RollMode::Normal),
+            TieBreak::Standoff,
+        ));
+        let mut rng = SequenceRng::new(vec![9, 9, 42, 99, 7, 13, 0, 0, 0, 0]);
+        resolution
+            .resolve_check(Uuid::new_v4(), &fixed_clock(), &mut rng)
+            .unwrap();
+        let event = resolution.uncommitted_events()[0].clone();
+        resolution.clear_uncommitted_events();
+
+        // Act
+        resolution.apply(&event);
+
+        // Assert
+        assert_eq!(resolution.phase, ResolutionPhase::CheckResolved);
+        let result = resolution.opposed_result.as_ref().unwrap();
+        assert_eq!(result.outcome, CheckOutcome::PartialSuccess);
+        assert_eq!(result.winner, None);
+    }
+
     #[test]
     fn test_apply_check_resolved_updates_phase_and_result() {
         let resolution_id = Uuid::new_v4();
@@ -847,6 +1827,7 @@ mod tests {
                 correlation_id: Uuid::new_v4(),
                 causation_id: Uuid::new_v4(),
                 occurred_at: Utc::now(),
+                schema_version: current_schema_version("rules.check_resolved"),
             },
             kind: RulesEventKind::CheckResolved(CheckResolved {
                 resolution_id,
@@ -856,6 +1837,8 @@ mod tests {
                 total: 21,
                 difficulty_class: 15,
                 outcome: CheckOutcome::Success,
+                rolls: Vec::new(),
+                kept_roll: 0,
             }),
         };
 
@@ -885,6 +1868,11 @@ mod tests {
                     target_id: None,
                     difficulty_class: 15,
                     modifier: 3,
+                    roll_expression: DiceExpression::d20(),
+                    stats: BTreeMap::new(),
+                    roll_mode: RollMode::Normal,
+                    ruleset: RulesetId::D20,
+                    modifier_breakdown: None,
                 },
                 Uuid::new_v4(),
                 &clock,
@@ -929,9 +1917,11 @@ mod tests {
         resolution.phase = ResolutionPhase::CheckResolved;
 
         let effects = vec![ResolvedEffect {
-            effect_type: "damage".to_owned(),
             target_id: Some(Uuid::new_v4()),
-            payload: serde_json::json!({ "amount": 8 }),
+            effect: Effect::Damage(Damage {
+                amount: 8,
+                damage_type: None,
+            }),
         }];
 
         let result = resolution.produce_effects(effects, correlation_id, &clock, &mut MockRng);
@@ -978,6 +1968,100 @@ mod tests {
         assert!(result.is_err());
     }
 
+    fn resolved_with(outcome: CheckOutcome) -> Resolution {
+        let mut resolution = Resolution::new(Uuid::new_v4());
+        resolution.phase = ResolutionPhase::CheckResolved;
+        resolution.check_result = Some(CheckResult {
+            check_id: Uuid::new_v4(),
+            natural_roll: 10,
+            modifier: 0,
+            total: 10,
+            difficulty_class: 10,
+            outcome,
+            rolls: Vec::new(),
+            kept_roll: 0,
+        });
+        resolution
+    }
+
+    #[test]
+    fn test_produce_outcome_effects_records_the_fired_tier() {
+        // Arrange
+        let mut resolution = resolved_with(CheckOutcome::PartialSuccess);
+        let table = OutcomeTable {
+            success: Some(OutcomeBranch::Effects(vec![ResolvedEffect {
+                target_id: Some(Uuid::new_v4()),
+                effect: Effect::Heal(Heal { amount: 9 }),
+            }])),
+            partial_success: Some(OutcomeBranch::Fallback(Fallback {
+                fallback: CheckOutcome::Success,
+                strength_percent: 50,
+            })),
+            ..OutcomeTable::default()
+        };
+
+        // Act
+        resolution
+            .produce_outcome_effects(&table, Uuid::new_v4(), &fixed_clock(), &mut MockRng)
+            .unwrap();
+
+        // Assert
+        let event = resolution.uncommitted_events()[0].clone();
+        let RulesEventKind::EffectsProduced(payload) = &event.kind else {
+            panic!("expected EffectsProduced");
+        };
+        assert_eq!(payload.effects[0].effect, Effect::Heal(Heal { amount: 4 }));
+        let expected = FiredTier {
+            outcome: CheckOutcome::PartialSuccess,
+            branch: Some(CheckOutcome::Success),
+            strength_percent: 50,
+        };
+        assert_eq!(payload.tier, Some(expected));
+        resolution.apply(&event);
+        assert_eq!(resolution.fired_tier, Some(expected));
+    }
+
+    #[test]
+    fn test_produce_outcome_effects_without_a_matching_tier_produces_nothing() {
+        // Arrange
+        let mut resolution = resolved_with(CheckOutcome::CriticalFailure);
+        let table = OutcomeTable {
+            success: Some(OutcomeBranch::Effects(vec![ResolvedEffect {
+                target_id: Some(Uuid::new_v4()),
+                effect: Effect::Heal(Heal { amount: 9 }),
+            }])),
+            ..OutcomeTable::default()
+        };
+
+        // Act
+        resolution
+            .produce_outcome_effects(&table, Uuid::new_v4(), &fixed_clock(), &mut MockRng)
+            .unwrap();
+
+        // Assert
+        match &resolution.uncommitted_events()[0].kind {
+            RulesEventKind::EffectsProduced(payload) => {
+                assert!(payload.effects.is_empty());
+                assert_eq!(payload.tier.unwrap().branch, None);
+            }
+            other => panic!("expected EffectsProduced, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_produce_outcome_effects_before_check_resolved_returns_error() {
+        let mut resolution = Resolution::new(Uuid::new_v4());
+        resolution.phase = ResolutionPhase::IntentDeclared;
+
+        let result = resolution.produce_outcome_effects(
+            &OutcomeTable::default(),
+            Uuid::new_v4(),
+            &fixed_clock(),
+            &mut MockRng,
+        );
+        assert!(matches!(result, Err(DomainError::Validation(_))));
+    }
+
     #[test]
     fn test_produce_effects_event_contains_correct_effects() {
         let mut resolution = Resolution::new(Uuid::new_v4());
@@ -986,14 +2070,18 @@ mod tests {
 
         let effects = vec![
             ResolvedEffect {
-                effect_type: "damage".to_owned(),
                 target_id: Some(target_id),
-                payload: serde_json::json!({ "amount": 8 }),
+                effect: Effect::Damage(Damage {
+                    amount: 8,
+                    damage_type: None,
+                }),
             },
             ResolvedEffect {
-                effect_type: "status_apply".to_owned(),
                 target_id: Some(target_id),
-                payload: serde_json::json!({ "status": "prone" }),
+                effect: Effect::ApplyStatus(ApplyStatus {
+                    status: "prone".to_owned(),
+                    duration_rounds: None,
+                }),
             },
         ];
 
@@ -1004,8 +2092,8 @@ mod tests {
         match &resolution.uncommitted_events()[0].kind {
             RulesEventKind::EffectsProduced(payload) => {
                 assert_eq!(payload.effects.len(), 2);
-                assert_eq!(payload.effects[0].effect_type, "damage");
-                assert_eq!(payload.effects[1].effect_type, "status_apply");
+                assert_eq!(payload.effects[0].effect.effect_type(), "damage");
+                assert_eq!(payload.effects[1].effect.effect_type(), "apply_status");
             }
             other => panic!("expected EffectsProduced, got {other:?}"),
         }
@@ -1026,14 +2114,15 @@ mod tests {
                 correlation_id: Uuid::new_v4(),
                 causation_id: Uuid::new_v4(),
                 occurred_at: Utc::now(),
+                schema_version: current_schema_version("rules.effects_produced"),
             },
             kind: RulesEventKind::EffectsProduced(EffectsProduced {
                 resolution_id,
                 effects: vec![ResolvedEffect {
-                    effect_type: "heal".to_owned(),
                     target_id: None,
-                    payload: serde_json::json!({ "amount": 5 }),
+                    effect: Effect::Heal(Heal { amount: 5 }),
                 }],
+                tier: None,
             }),
         };
 
@@ -1041,7 +2130,7 @@ mod tests {
 
         assert_eq!(resolution.phase, ResolutionPhase::EffectsProduced);
         assert_eq!(resolution.effects.len(), 1);
-        assert_eq!(resolution.effects[0].effect_type, "heal");
+        assert_eq!(resolution.effects[0].effect.effect_type(), "heal");
     }
 
     // --- Full lifecycle integration test ---
@@ -1062,6 +2151,11 @@ mod tests {
                     target_id: None,
                     difficulty_class: 15,
                     modifier: 3,
+                    roll_expression: DiceExpression::d20(),
+                    stats: BTreeMap::new(),
+                    roll_mode: RollMode::Normal,
+                    ruleset: RulesetId::D20,
+                    modifier_breakdown: None,
                 },
                 Uuid::new_v4(),
                 &clock,
@@ -1088,9 +2182,11 @@ mod tests {
 
         // Phase 3: Produce effects
         let effects = vec![ResolvedEffect {
-            effect_type: "damage".to_owned(),
             target_id: None,
-            payload: serde_json::json!({ "amount": 8 }),
+            effect: Effect::Damage(Damage {
+                amount: 8,
+                damage_type: None,
+            }),
         }];
         resolution
             .produce_effects(effects, Uuid::new_v4(), &clock, &mut MockRng)
@@ -1146,6 +2242,7 @@ mod tests {
                 correlation_id: Uuid::new_v4(),
                 causation_id: Uuid::new_v4(),
                 occurred_at: Utc::now(),
+                schema_version: current_schema_version("rules.resolution_archived"),
             },
             kind: RulesEventKind::ResolutionArchived(super::super::events::ResolutionArchived {
                 resolution_id,
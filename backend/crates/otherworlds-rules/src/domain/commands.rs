@@ -1,12 +1,19 @@
 //! Commands for the Rules & Resolution context.
 
 use otherworlds_core::command::Command;
+use otherworlds_core::error::DomainError;
+use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use super::events::ResolvedEffect;
+use super::effects::{CustomEffectSchema, Effect};
+use super::encounter::EncounterParticipant;
+use super::events::{OpposedParticipant, ResolvedEffect, RollMode, TieBreak};
+use super::modifiers::ActorSheet;
+use super::outcome_table::OutcomeTable;
+use super::ruleset::RulesetId;
 
 /// Command to declare a player intent.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DeclareIntent {
     /// The correlation ID for tracing.
     pub correlation_id: Uuid,
@@ -22,8 +29,26 @@ pub struct DeclareIntent {
     pub target_id: Option<Uuid>,
     /// The difficulty class to beat.
     pub difficulty_class: i32,
-    /// The modifier applied to the roll.
+    /// The modifier applied to the roll; must be zero when `actor` is given,
+    /// since the modifier is then derived from the actor's sheet.
+    #[serde(default)]
     pub modifier: i32,
+    /// The sheet of the character making the check, gathered at the API
+    /// boundary; the modifier is derived from it for `skill`.
+    #[serde(default)]
+    pub actor: Option<ActorSheet>,
+    /// Optional dice expression to roll (e.g., "`2d6+@strength`"); the
+    /// ruleset's default roll when absent. `@name` terms read the actor's
+    /// sheet, so an expression referencing a stat needs `actor`; with an
+    /// actor, constant terms are rejected.
+    #[serde(default)]
+    pub roll_expression: Option<String>,
+    /// How many times the expression is rolled and which roll counts.
+    #[serde(default)]
+    pub roll_mode: RollMode,
+    /// The ruleset the check is rolled and judged by; d20 when absent.
+    #[serde(default)]
+    pub ruleset: RulesetId,
 }
 
 impl Command for DeclareIntent {
@@ -36,8 +61,48 @@ impl Command for DeclareIntent {
     }
 }
 
-/// Command to resolve a d20 check.
-#[derive(Debug, Clone)]
+/// Command to declare an intent contested by an opponent.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DeclareOpposedIntent {
+    /// The correlation ID for tracing.
+    pub correlation_id: Uuid,
+    /// The resolution this intent belongs to.
+    pub resolution_id: Uuid,
+    /// The intent identifier.
+    pub intent_id: Uuid,
+    /// The type of action (e.g., "`grapple`", "`stealth`", "`haggle`").
+    pub action_type: String,
+    /// Optional skill being used.
+    pub skill: Option<String>,
+    /// The participant initiating the contest.
+    pub actor: OpposedParticipant,
+    /// The participant resisting it.
+    pub opponent: OpposedParticipant,
+    /// Optional dice expression both participants roll; the ruleset's
+    /// default roll when absent.
+    #[serde(default)]
+    pub roll_expression: Option<String>,
+    /// The ruleset the rolls are made under; d20 when absent.
+    #[serde(default)]
+    pub ruleset: RulesetId,
+    /// How equal totals are settled; the opponent wins when absent.
+    #[serde(default)]
+    pub tie_break: TieBreak,
+}
+
+impl Command for DeclareOpposedIntent {
+    fn command_type(&self) -> &'static str {
+        "rules.declare_opposed_intent"
+    }
+
+    fn correlation_id(&self) -> Uuid {
+        self.correlation_id
+    }
+}
+
+/// Command to resolve a check by rolling the intent's dice expression (both
+/// participants' rolls for an opposed intent).
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ResolveCheck {
     /// The correlation ID for tracing.
     pub correlation_id: Uuid,
@@ -56,37 +121,54 @@ impl Command for ResolveCheck {
 }
 
 /// Specification for a single effect to produce.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EffectSpec {
-    /// The type of effect (e.g., "`damage`", "`heal`", "`status_apply`").
+    /// The type of effect (one of `EFFECT_TYPES`, e.g., "`damage`").
     pub effect_type: String,
     /// Optional target of the effect.
     pub target_id: Option<Uuid>,
-    /// Campaign-specific payload.
+    /// The effect's fields, shaped by its type.
     pub payload: serde_json::Value,
 }
 
 impl EffectSpec {
-    /// Converts this spec into a `ResolvedEffect` for the domain.
-    #[must_use]
-    pub fn into_resolved_effect(self) -> ResolvedEffect {
-        ResolvedEffect {
-            effect_type: self.effect_type,
+    /// Parses and validates this spec into a `ResolvedEffect` for the
+    /// domain, checking custom effects against `custom_effects`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::Validation` if the effect type is unknown or
+    /// the payload or target does not fit it.
+    pub fn into_resolved_effect(
+        self,
+        custom_effects: &[CustomEffectSchema],
+    ) -> Result<ResolvedEffect, DomainError> {
+        let effect = Effect::parse(&self.effect_type, self.payload)?;
+        effect.validate(self.target_id, custom_effects)?;
+        Ok(ResolvedEffect {
             target_id: self.target_id,
-            payload: self.payload,
-        }
+            effect,
+        })
     }
 }
 
 /// Command to produce effects from a resolved check.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ProduceEffects {
     /// The correlation ID for tracing.
     pub correlation_id: Uuid,
     /// The resolution this effect production belongs to.
     pub resolution_id: Uuid,
-    /// The effects to produce.
+    /// Effects produced whatever the check's outcome.
+    #[serde(default)]
     pub effects: Vec<EffectSpec>,
+    /// Effects keyed by the check's outcome, instead of `effects`.
+    #[serde(default)]
+    pub outcome_effects: Option<OutcomeTable<EffectSpec>>,
+    /// The custom effects the campaign declares, which custom effects are
+    /// checked against.
+    #[serde(default)]
+    pub custom_effects: Vec<CustomEffectSchema>,
 }
 
 impl Command for ProduceEffects {
@@ -100,7 +182,7 @@ impl Command for ProduceEffects {
 }
 
 /// Command to archive (soft-delete) a resolution.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ArchiveResolution {
     /// The correlation ID for tracing.
     pub correlation_id: Uuid,
@@ -117,3 +199,153 @@ impl Command for ArchiveResolution {
         self.correlation_id
     }
 }
+
+/// Command to start an encounter, rolling initiative for every participant.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StartEncounter {
+    /// The correlation ID for tracing.
+    pub correlation_id: Uuid,
+    /// The encounter identifier.
+    pub encounter_id: Uuid,
+    /// The participants, in the order they are listed.
+    pub participants: Vec<EncounterParticipant>,
+}
+
+impl Command for StartEncounter {
+    fn command_type(&self) -> &'static str {
+        "rules.start_encounter"
+    }
+
+    fn correlation_id(&self) -> Uuid {
+        self.correlation_id
+    }
+}
+
+/// Command to end the acting participant's turn and start the next one.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EndTurn {
+    /// The correlation ID for tracing.
+    pub correlation_id: Uuid,
+    /// The encounter identifier.
+    pub encounter_id: Uuid,
+}
+
+impl Command for EndTurn {
+    fn command_type(&self) -> &'static str {
+        "rules.end_turn"
+    }
+
+    fn correlation_id(&self) -> Uuid {
+        self.correlation_id
+    }
+}
+
+/// Command for the acting participant to delay until after another
+/// participant.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DelayTurn {
+    /// The correlation ID for tracing.
+    pub correlation_id: Uuid,
+    /// The encounter identifier.
+    pub encounter_id: Uuid,
+    /// The participant to act after; must not have acted yet this round.
+    pub after_participant_id: Uuid,
+}
+
+impl Command for DelayTurn {
+    fn command_type(&self) -> &'static str {
+        "rules.delay_turn"
+    }
+
+    fn correlation_id(&self) -> Uuid {
+        self.correlation_id
+    }
+}
+
+/// Command for the acting participant to ready an action and end their turn.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReadyAction {
+    /// The correlation ID for tracing.
+    pub correlation_id: Uuid,
+    /// The encounter identifier.
+    pub encounter_id: Uuid,
+    /// The circumstance the action waits for.
+    pub trigger: String,
+}
+
+impl Command for ReadyAction {
+    fn command_type(&self) -> &'static str {
+        "rules.ready_action"
+    }
+
+    fn correlation_id(&self) -> Uuid {
+        self.correlation_id
+    }
+}
+
+/// Command to fire a participant's readied action.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TriggerReadiedAction {
+    /// The correlation ID for tracing.
+    pub correlation_id: Uuid,
+    /// The encounter identifier.
+    pub encounter_id: Uuid,
+    /// The participant whose readied action fires.
+    pub participant_id: Uuid,
+}
+
+impl Command for TriggerReadiedAction {
+    fn command_type(&self) -> &'static str {
+        "rules.trigger_readied_action"
+    }
+
+    fn correlation_id(&self) -> Uuid {
+        self.correlation_id
+    }
+}
+
+/// Command to link a resolution to a participant's action in an encounter.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LinkResolution {
+    /// The correlation ID for tracing.
+    pub correlation_id: Uuid,
+    /// The encounter identifier.
+    pub encounter_id: Uuid,
+    /// The acting participant: the one whose turn it is, or one whose
+    /// readied action was triggered during this turn.
+    pub participant_id: Uuid,
+    /// The resolution to link.
+    pub resolution_id: Uuid,
+}
+
+impl Command for LinkResolution {
+    fn command_type(&self) -> &'static str {
+        "rules.link_resolution"
+    }
+
+    fn correlation_id(&self) -> Uuid {
+        self.correlation_id
+    }
+}
+
+/// Command to end an encounter.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EndEncounter {
+    /// The correlation ID for tracing.
+    pub correlation_id: Uuid,
+    /// The encounter identifier.
+    pub encounter_id: Uuid,
+    /// Optional reason (e.g., "`enemies_defeated`", "`fled`").
+    #[serde(default)]
+    pub reason: Option<String>,
+}
+
+impl Command for EndEncounter {
+    fn command_type(&self) -> &'static str {
+        "rules.end_encounter"
+    }
+
+    fn correlation_id(&self) -> Uuid {
+        self.correlation_id
+    }
+}
@@ -0,0 +1,882 @@
+//! Dice notation — parsing and evaluating roll expressions.
+//!
+//! An expression is a sum of terms separated by `+` or `-`:
+//!
+//! - `NdS` rolls `N` dice with `S` sides (`N` defaults to 1, `d%` is `d100`).
+//! - `khK` / `klK` after a dice term keeps the highest / lowest `K` dice.
+//! - `!` after a dice term explodes it: each die showing its maximum face is
+//!   rolled again and the extra roll added to that die.
+//! - an integer adds a flat modifier.
+//! - `@name` adds the value of the named stat, supplied at evaluation time.
+//!
+//! Examples: `1d20+5`, `2d6+3`, `4d6kh3`, `1d100`, `1d6!`, `1d20+@dexterity`.
+//! Dice terms may only be added, so the sum of the kept dice is never
+//! negative. Every die drawn is reported, so a check can record exactly how
+//! its total came about.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
+
+use otherworlds_core::error::DomainError;
+use otherworlds_core::rng::DeterministicRng;
+use serde::{Deserialize, Serialize};
+
+/// Most dice a single term may roll.
+pub const MAX_DICE_PER_TERM: u32 = 100;
+
+/// Most sides a single die may have.
+pub const MAX_SIDES: u32 = 1000;
+
+/// Most extra rolls one exploding die may trigger.
+pub const MAX_EXPLOSIONS_PER_DIE: u32 = 20;
+
+/// Most rolls [`DiceExpression::outcomes`] will list.
+pub const MAX_ENUMERATED_ROLLS: usize = 10_000;
+
+/// Which dice of a term count towards its total.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Keep {
+    /// Keep the highest `n` dice.
+    Highest(u32),
+    /// Keep the lowest `n` dice.
+    Lowest(u32),
+}
+
+/// A single term of a dice expression.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DiceTerm {
+    /// `count` dice with `sides` sides each.
+    Dice {
+        /// Number of dice rolled.
+        count: u32,
+        /// Sides per die.
+        sides: u32,
+        /// Optional keep-highest / keep-lowest selection.
+        keep: Option<Keep>,
+        /// Whether dice showing their maximum face roll again.
+        exploding: bool,
+    },
+    /// A flat, signed modifier.
+    Constant(i32),
+    /// A stat reference, added (or subtracted when `negated`).
+    Stat {
+        /// The stat name, without the leading `@`.
+        name: String,
+        /// Whether the stat is subtracted.
+        negated: bool,
+    },
+}
+
+/// A parsed roll expression such as `4d6kh3+2`.
+///
+/// Serializes as its canonical notation string.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct DiceExpression {
+    terms: Vec<DiceTerm>,
+}
+
+/// One die drawn while evaluating an expression.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DieRoll {
+    /// Sides of the die.
+    pub sides: u32,
+    /// The face rolled.
+    pub value: u32,
+    /// Whether the die counts towards the total.
+    pub kept: bool,
+    /// Whether the roll is an extra roll triggered by an explosion.
+    pub exploded: bool,
+}
+
+/// The result of evaluating a dice expression.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiceRoll {
+    /// Every die drawn, in the order it was drawn.
+    pub dice: Vec<DieRoll>,
+    /// Sum of the kept dice.
+    pub dice_total: u32,
+    /// Sum of the flat modifiers and stats.
+    pub modifier: i32,
+}
+
+impl DiceRoll {
+    /// Returns the roll total (`dice_total` + `modifier`), which `evaluate`
+    /// guarantees fits in an `i32`.
+    #[must_use]
+    #[allow(clippy::cast_possible_wrap)]
+    pub fn total(&self) -> i32 {
+        self.dice_total as i32 + self.modifier
+    }
+
+    /// Returns the natural d20 face when exactly one d20 is kept and no other
+    /// die counts, so that natural 1s and 20s can be honoured.
+    #[must_use]
+    pub fn natural_d20(&self) -> Option<u32> {
+        let mut kept = self.dice.iter().filter(|d| d.kept);
+        match (kept.next(), kept.next()) {
+            (Some(die), None) if die.sides == 20 => Some(die.value),
+            _ => None,
+        }
+    }
+}
+
+impl DiceExpression {
+    /// Returns the single-d20 expression every check used before roll
+    /// expressions existed.
+    #[must_use]
+    pub fn d20() -> Self {
+        Self {
+            terms: vec![DiceTerm::Dice {
+                count: 1,
+                sides: 20,
+                keep: None,
+                exploding: false,
+            }],
+        }
+    }
+
+    /// Returns the terms of the expression.
+    #[must_use]
+    pub fn terms(&self) -> &[DiceTerm] {
+        &self.terms
+    }
+
+    /// Returns the names of the stats the expression references.
+    pub fn stat_names(&self) -> impl Iterator<Item = &str> {
+        self.terms.iter().filter_map(|term| match term {
+            DiceTerm::Stat { name, .. } => Some(name.as_str()),
+            _ => None,
+        })
+    }
+
+    /// Rolls the expression.
+    ///
+    /// Dice are drawn from `rng` term by term, left to right, with an
+    /// exploding die's extra rolls drawn immediately after it.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::Validation` if a referenced stat is missing from
+    /// `stats` or the total overflows.
+    pub fn evaluate(
+        &self,
+        stats: &BTreeMap<String, i32>,
+        rng: &mut dyn DeterministicRng,
+    ) -> Result<DiceRoll, DomainError> {
+        let mut dice = Vec::new();
+        let mut dice_total: u32 = 0;
+        let mut modifier: i32 = 0;
+
+        for term in &self.terms {
+            match term {
+                DiceTerm::Dice {
+                    count,
+                    sides,
+                    keep,
+                    exploding,
+                } => {
+                    dice_total = dice_total.saturating_add(roll_dice(
+                        *count, *sides, *keep, *exploding, rng, &mut dice,
+                    ));
+                }
+                DiceTerm::Constant(value) => {
+                    modifier = checked_add(modifier, *value)?;
+                }
+                DiceTerm::Stat { name, negated } => {
+                    modifier = checked_add(modifier, stat_value(name, *negated, stats)?)?;
+                }
+            }
+        }
+
+        check_total(dice_total, modifier)?;
+
+        Ok(DiceRoll {
+            dice,
+            dice_total,
+            modifier,
+        })
+    }
+
+    /// Lists every equally likely roll of the expression: one per
+    /// combination of faces, with the faces of later dice changing fastest.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::Validation` if the expression explodes (its
+    /// rolls are then not equally likely), has more than
+    /// `MAX_ENUMERATED_ROLLS` combinations, references a stat missing from
+    /// `stats`, or overflows.
+    pub fn outcomes(&self, stats: &BTreeMap<String, i32>) -> Result<Vec<DiceRoll>, DomainError> {
+        let mut modifier: i32 = 0;
+        for term in &self.terms {
+            match term {
+                DiceTerm::Dice { .. } => {}
+                DiceTerm::Constant(value) => modifier = checked_add(modifier, *value)?,
+                DiceTerm::Stat { name, negated } => {
+                    modifier = checked_add(modifier, stat_value(name, *negated, stats)?)?;
+                }
+            }
+        }
+
+        let mut rolls = vec![DiceRoll {
+            dice: Vec::new(),
+            dice_total: 0,
+            modifier,
+        }];
+        for term in &self.terms {
+            let DiceTerm::Dice {
+                count,
+                sides,
+                keep,
+                exploding,
+            } = *term
+            else {
+                continue;
+            };
+            if exploding {
+                return Err(DomainError::Validation(format!(
+                    "cannot list the rolls of '{self}': exploding dice are not equally likely"
+                )));
+            }
+            let combinations = usize::try_from(sides)
+                .ok()
+                .and_then(|sides| sides.checked_pow(count))
+                .and_then(|combinations| combinations.checked_mul(rolls.len()))
+                .filter(|combinations| *combinations <= MAX_ENUMERATED_ROLLS)
+                .ok_or_else(|| {
+                    DomainError::Validation(format!(
+                        "'{self}' has more than {MAX_ENUMERATED_ROLLS} possible rolls"
+                    ))
+                })?;
+            let mut next = Vec::with_capacity(combinations);
+            for roll in &rolls {
+                let mut faces = vec![1; count as usize];
+                loop {
+                    let mut dice = roll.dice.clone();
+                    let chains = faces.iter().map(|face| vec![*face]).collect();
+                    let kept = keep_dice(chains, sides, keep, &mut dice);
+                    next.push(DiceRoll {
+                        dice,
+                        dice_total: roll.dice_total.saturating_add(kept),
+                        modifier,
+                    });
+                    // Advance the faces like an odometer, last die fastest.
+                    let Some(position) = faces.iter().rposition(|face| *face < sides) else {
+                        break;
+                    };
+                    faces[position] += 1;
+                    faces[position + 1..].fill(1);
+                }
+            }
+            rolls = next;
+        }
+
+        for roll in &rolls {
+            check_total(roll.dice_total, roll.modifier)?;
+        }
+        Ok(rolls)
+    }
+}
+
+/// Returns the value of the stat `name`, negated when `negated`.
+fn stat_value(
+    name: &str,
+    negated: bool,
+    stats: &BTreeMap<String, i32>,
+) -> Result<i32, DomainError> {
+    let value = *stats
+        .get(name)
+        .ok_or_else(|| DomainError::Validation(format!("roll references unknown stat @{name}")))?;
+    if negated {
+        value.checked_neg().ok_or_else(overflow)
+    } else {
+        Ok(value)
+    }
+}
+
+/// Checks that a roll's total fits in an `i32`.
+fn check_total(dice_total: u32, modifier: i32) -> Result<(), DomainError> {
+    i32::try_from(dice_total)
+        .ok()
+        .and_then(|dice_total| dice_total.checked_add(modifier))
+        .map(|_| ())
+        .ok_or_else(overflow)
+}
+
+fn overflow() -> DomainError {
+    DomainError::Validation("roll total overflows".to_owned())
+}
+
+fn checked_add(a: i32, b: i32) -> Result<i32, DomainError> {
+    a.checked_add(b).ok_or_else(overflow)
+}
+
+/// Rolls one dice term, appends every die drawn to `out`, and returns the sum
+/// of the kept dice.
+fn roll_dice(
+    count: u32,
+    sides: u32,
+    keep: Option<Keep>,
+    exploding: bool,
+    rng: &mut dyn DeterministicRng,
+    out: &mut Vec<DieRoll>,
+) -> u32 {
+    // Each die is a chain: the first roll plus any explosion rolls.
+    let chains: Vec<Vec<u32>> = (0..count)
+        .map(|_| {
+            let mut chain = vec![rng.next_u32_range(1, sides)];
+            while exploding
+                && chain.last() == Some(&sides)
+                && chain.len() <= MAX_EXPLOSIONS_PER_DIE as usize
+            {
+                chain.push(rng.next_u32_range(1, sides));
+            }
+            chain
+        })
+        .collect();
+    keep_dice(chains, sides, keep, out)
+}
+
+/// Marks the dice of a term that `keep` selects, appends every die to `out`,
+/// and returns the sum of the kept dice. Each chain is one die's first roll
+/// followed by its explosion rolls.
+fn keep_dice(chains: Vec<Vec<u32>>, sides: u32, keep: Option<Keep>, out: &mut Vec<DieRoll>) -> u32 {
+    let chain_totals: Vec<u32> = chains.iter().map(|c| c.iter().sum()).collect();
+
+    let mut kept = vec![keep.is_none(); chains.len()];
+    if let Some(keep) = keep {
+        let mut order: Vec<usize> = (0..chains.len()).collect();
+        let n = match keep {
+            Keep::Highest(n) => {
+                order.sort_by(|a, b| chain_totals[*b].cmp(&chain_totals[*a]));
+                n
+            }
+            Keep::Lowest(n) => {
+                order.sort_by_key(|i| chain_totals[*i]);
+                n
+            }
+        };
+        for index in order.into_iter().take(n as usize) {
+            kept[index] = true;
+        }
+    }
+
+    let mut total = 0;
+    for (index, chain) in chains.into_iter().enumerate() {
+        if kept[index] {
+            total += chain_totals[index];
+        }
+        out.extend(chain.into_iter().enumerate().map(|(i, value)| DieRoll {
+            sides,
+            value,
+            kept: kept[index],
+            exploded: i > 0,
+        }));
+    }
+    total
+}
+
+impl fmt::Display for DiceExpression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, term) in self.terms.iter().enumerate() {
+            let negative = match term {
+                DiceTerm::Dice { .. } => false,
+                DiceTerm::Constant(value) => *value < 0,
+                DiceTerm::Stat { negated, .. } => *negated,
+            };
+            if negative {
+                write!(f, "-")?;
+            } else if index > 0 {
+                write!(f, "+")?;
+            }
+            match term {
+                DiceTerm::Dice {
+                    count,
+                    sides,
+                    keep,
+                    exploding,
+                } => {
+                    write!(f, "{count}d{sides}")?;
+                    if *exploding {
+                        write!(f, "!")?;
+                    }
+                    match keep {
+                        Some(Keep::Highest(n)) => write!(f, "kh{n}")?,
+                        Some(Keep::Lowest(n)) => write!(f, "kl{n}")?,
+                        None => {}
+                    }
+                }
+                DiceTerm::Constant(value) => write!(f, "{}", value.unsigned_abs())?,
+                DiceTerm::Stat { name, .. } => write!(f, "@{name}")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+impl From<DiceExpression> for String {
+    fn from(expression: DiceExpression) -> Self {
+        expression.to_string()
+    }
+}
+
+impl TryFrom<String> for DiceExpression {
+    type Error = DomainError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl FromStr for DiceExpression {
+    type Err = DomainError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Parser::new(s).parse()
+    }
+}
+
+/// Recursive-descent parser over the expression with whitespace removed.
+struct Parser<'a> {
+    source: &'a str,
+    chars: Vec<char>,
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(source: &'a str) -> Self {
+        Self {
+            source,
+            chars: source.chars().filter(|c| !c.is_whitespace()).collect(),
+            pos: 0,
+        }
+    }
+
+    fn error(&self, reason: &str) -> DomainError {
+        DomainError::Validation(format!(
+            "invalid roll expression '{}': {reason}",
+            self.source
+        ))
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        if self.peek() == Some(c) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn parse(mut self) -> Result<DiceExpression, DomainError> {
+        if self.chars.is_empty() {
+            return Err(self.error("expression is empty"));
+        }
+        let mut terms = Vec::new();
+        let mut negated = self.eat('-');
+        if !negated {
+            self.eat('+');
+        }
+        loop {
+            terms.push(self.term(negated)?);
+            if self.eat('+') {
+                negated = false;
+            } else if self.eat('-') {
+                negated = true;
+            } else if let Some(c) = self.peek() {
+                return Err(self.error(&format!("unexpected '{c}'")));
+            } else {
+                break;
+            }
+        }
+        if !terms.iter().any(|t| matches!(t, DiceTerm::Dice { .. })) {
+            return Err(self.error("expression must roll at least one die"));
+        }
+        Ok(DiceExpression { terms })
+    }
+
+    fn term(&mut self, negated: bool) -> Result<DiceTerm, DomainError> {
+        if self.eat('@') {
+            let start = self.pos;
+            while self
+                .peek()
+                .is_some_and(|c| c.is_ascii_alphanumeric() || c == '_')
+            {
+                self.pos += 1;
+            }
+            if start == self.pos {
+                return Err(self.error("'@' must be followed by a stat name"));
+            }
+            let name = self.chars[start..self.pos].iter().collect();
+            return Ok(DiceTerm::Stat { name, negated });
+        }
+
+        let count = self.number()?;
+        if !(self.eat('d') || self.eat('D')) {
+            let value = count.ok_or_else(|| self.error("expected a number, dice or @stat"))?;
+            let value = i32::try_from(value).map_err(|_| self.error("number is too large"))?;
+            return Ok(DiceTerm::Constant(if negated { -value } else { value }));
+        }
+        if negated {
+            return Err(self.error("dice terms cannot be subtracted"));
+        }
+        self.dice(count.unwrap_or(1))
+    }
+
+    fn dice(&mut self, count: u32) -> Result<DiceTerm, DomainError> {
+        let sides = if self.eat('%') {
+            100
+        } else {
+            self.number()?
+                .ok_or_else(|| self.error("dice need a number of sides"))?
+        };
+        if count == 0 || count > MAX_DICE_PER_TERM {
+            return Err(self.error(&format!(
+                "dice count must be between 1 and {MAX_DICE_PER_TERM}"
+            )));
+        }
+        if sides == 0 || sides > MAX_SIDES {
+            return Err(self.error(&format!("dice sides must be between 1 and {MAX_SIDES}")));
+        }
+
+        let mut keep = None;
+        let mut exploding = false;
+        loop {
+            if self.eat('!') {
+                if exploding {
+                    return Err(self.error("'!' given twice"));
+                }
+                if sides < 2 {
+                    return Err(self.error("a one-sided die cannot explode"));
+                }
+                exploding = true;
+            } else if self.eat('k') {
+                if keep.is_some() {
+                    return Err(self.error("keep given twice"));
+                }
+                let highest = if self.eat('h') {
+                    true
+                } else if self.eat('l') {
+                    false
+                } else {
+                    return Err(self.error("'k' must be followed by 'h' or 'l'"));
+                };
+                let n = self
+                    .number()?
+                    .ok_or_else(|| self.error("keep needs a number of dice"))?;
+                if n == 0 || n > count {
+                    return Err(self.error(&format!("keep must be between 1 and {count}")));
+                }
+                keep = Some(if highest {
+                    Keep::Highest(n)
+                } else {
+                    Keep::Lowest(n)
+                });
+            } else {
+                break;
+            }
+        }
+
+        Ok(DiceTerm::Dice {
+            count,
+            sides,
+            keep,
+            exploding,
+        })
+    }
+
+    fn number(&mut self) -> Result<Option<u32>, DomainError> {
+        let start = self.pos;
+        while self.peek().is_some_and(|c| c.is_ascii_digit()) {
+            self.pos += 1;
+        }
+        if start == self.pos {
+            return Ok(None);
+        }
+        let digits: String = self.chars[start..self.pos].iter().collect();
+        digits
+            .parse()
+            .map(Some)
+            .map_err(|_| self.error("number is too large"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use otherworlds_test_support::{MockRng, SequenceRng};
+
+    use super::*;
+
+    fn parse(s: &str) -> DiceExpression {
+        s.parse().unwrap()
+    }
+
+    fn parse_error(s: &str) -> String {
+        match s.parse::<DiceExpression>() {
+            Err(DomainError::Validation(msg)) => msg,
+            other => panic!("expected validation error for '{s}', got {other:?}"),
+        }
+    }
+
+    // --- parsing ---
+
+    #[test]
+    fn test_parse_round_trips_canonical_notation() {
+        for notation in [
+            "1d20",
+            "2d6+3",
+            "4d6kh3",
+            "2d20kl1",
+            "1d100",
+            "1d6!",
+            "3d6!kh2",
+            "1d20+@dexterity-2",
+            "-1+1d4-@str",
+        ] {
+            assert_eq!(parse(notation).to_string(), notation);
+        }
+    }
+
+    #[test]
+    fn test_parse_normalizes_shorthand_and_whitespace() {
+        assert_eq!(parse("d20").to_string(), "1d20");
+        assert_eq!(parse("d%").to_string(), "1d100");
+        assert_eq!(parse(" 2D6 + 3 ").to_string(), "2d6+3");
+        assert_eq!(parse("+1d8").to_string(), "1d8");
+    }
+
+    #[test]
+    fn test_parse_keep_and_explode_in_either_order() {
+        assert_eq!(parse("3d6kh2!"), parse("3d6!kh2"));
+    }
+
+    #[test]
+    fn test_parse_rejects_malformed_expressions() {
+        assert!(parse_error("").contains("empty"));
+        assert!(parse_error("5").contains("at least one die"));
+        assert!(parse_error("2d").contains("number of sides"));
+        assert!(parse_error("1d20+").contains("expected a number"));
+        assert!(parse_error("1d20*2").contains("unexpected '*'"));
+        assert!(parse_error("0d6").contains("dice count"));
+        assert!(parse_error("101d6").contains("dice count"));
+        assert!(parse_error("1d0").contains("dice sides"));
+        assert!(parse_error("1d1!").contains("cannot explode"));
+        assert!(parse_error("4d6kh5").contains("keep must be between 1 and 4"));
+        assert!(parse_error("4d6kx3").contains("'h' or 'l'"));
+        assert!(parse_error("1d20-1d4").contains("cannot be subtracted"));
+        assert!(parse_error("1d20+@").contains("stat name"));
+        assert!(parse_error("1d20+99999999999").contains("too large"));
+    }
+
+    #[test]
+    fn test_expression_serializes_as_notation_string() {
+        let json = serde_json::to_value(parse("4d6kh3")).unwrap();
+        assert_eq!(json, serde_json::json!("4d6kh3"));
+
+        let back: DiceExpression = serde_json::from_value(json).unwrap();
+        assert_eq!(back, parse("4d6kh3"));
+        assert!(serde_json::from_value::<DiceExpression>(serde_json::json!("2x")).is_err());
+    }
+
+    #[test]
+    fn test_stat_names_lists_references() {
+        let expression = parse("1d20+@strength-@fatigue+2");
+        assert_eq!(
+            expression.stat_names().collect::<Vec<_>>(),
+            ["strength", "fatigue"]
+        );
+    }
+
+    // --- evaluation ---
+
+    #[test]
+    fn test_evaluate_sums_dice_and_modifier() {
+        // Arrange
+        let mut rng = SequenceRng::new(vec![4, 5]);
+
+        // Act
+        let roll = parse("2d6+3").evaluate(&BTreeMap::new(), &mut rng).unwrap();
+
+        // Assert
+        assert_eq!(roll.dice_total, 9);
+        assert_eq!(roll.modifier, 3);
+        assert_eq!(roll.total(), 12);
+        assert_eq!(roll.dice.len(), 2);
+        assert!(
+            roll.dice
+                .iter()
+                .all(|d| d.kept && !d.exploded && d.sides == 6)
+        );
+    }
+
+    #[test]
+    fn test_evaluate_keep_highest_drops_lowest_die() {
+        // Arrange
+        let mut rng = SequenceRng::new(vec![3, 6, 1, 5]);
+
+        // Act
+        let roll = parse("4d6kh3")
+            .evaluate(&BTreeMap::new(), &mut rng)
+            .unwrap();
+
+        // Assert
+        assert_eq!(roll.dice_total, 14);
+        let kept: Vec<bool> = roll.dice.iter().map(|d| d.kept).collect();
+        assert_eq!(kept, [true, true, false, true]);
+    }
+
+    #[test]
+    fn test_evaluate_keep_lowest_prefers_earliest_die_on_ties() {
+        // Arrange
+        let mut rng = SequenceRng::new(vec![7, 7]);
+
+        // Act
+        let roll = parse("2d20kl1")
+            .evaluate(&BTreeMap::new(), &mut rng)
+            .unwrap();
+
+        // Assert
+        let kept: Vec<bool> = roll.dice.iter().map(|d| d.kept).collect();
+        assert_eq!(kept, [true, false]);
+        assert_eq!(roll.natural_d20(), Some(7));
+    }
+
+    #[test]
+    fn test_evaluate_exploding_die_rolls_again_on_max() {
+        // Arrange — 6 explodes into 6, which explodes into 2.
+        let mut rng = SequenceRng::new(vec![6, 6, 2, 3]);
+
+        // Act
+        let roll = parse("2d6!").evaluate(&BTreeMap::new(), &mut rng).unwrap();
+
+        // Assert
+        assert_eq!(roll.dice_total, 17);
+        let exploded: Vec<bool> = roll.dice.iter().map(|d| d.exploded).collect();
+        assert_eq!(exploded, [false, true, true, false]);
+    }
+
+    #[test]
+    fn test_evaluate_caps_explosions_per_die() {
+        // Arrange — every draw is the maximum face.
+        let mut rng = SequenceRng::new(vec![4; 64]);
+
+        // Act
+        let roll = parse("1d4!").evaluate(&BTreeMap::new(), &mut rng).unwrap();
+
+        // Assert
+        assert_eq!(roll.dice.len(), 1 + MAX_EXPLOSIONS_PER_DIE as usize);
+    }
+
+    #[test]
+    fn test_evaluate_keep_counts_exploded_chain_as_one_die() {
+        // Arrange — first die 6+1 = 7, second die 5.
+        let mut rng = SequenceRng::new(vec![6, 1, 5]);
+
+        // Act
+        let roll = parse("2d6!kl1")
+            .evaluate(&BTreeMap::new(), &mut rng)
+            .unwrap();
+
+        // Assert
+        assert_eq!(roll.dice_total, 5);
+        let kept: Vec<bool> = roll.dice.iter().map(|d| d.kept).collect();
+        assert_eq!(kept, [false, false, true]);
+    }
+
+    #[test]
+    fn test_evaluate_resolves_stat_references() {
+        // Arrange
+        let stats = BTreeMap::from([("dexterity".to_owned(), 4), ("fatigue".to_owned(), 1)]);
+
+        // Act
+        let roll = parse("1d20+@dexterity-@fatigue")
+            .evaluate(&stats, &mut MockRng)
+            .unwrap();
+
+        // Assert
+        assert_eq!(roll.modifier, 3);
+        assert_eq!(roll.total(), 4);
+    }
+
+    #[test]
+    fn test_evaluate_unknown_stat_returns_validation_error() {
+        let result = parse("1d20+@luck").evaluate(&BTreeMap::new(), &mut MockRng);
+
+        assert!(matches!(result, Err(DomainError::Validation(msg)) if msg.contains("@luck")));
+    }
+
+    #[test]
+    fn test_d20_draws_a_single_die() {
+        // Arrange
+        let mut rng = SequenceRng::new(vec![17]);
+
+        // Act
+        let roll = DiceExpression::d20()
+            .evaluate(&BTreeMap::new(), &mut rng)
+            .unwrap();
+
+        // Assert
+        assert_eq!(roll.dice_total, 17);
+        assert_eq!(roll.natural_d20(), Some(17));
+    }
+
+    #[test]
+    fn test_natural_d20_is_none_without_a_single_kept_d20() {
+        let mut rng = SequenceRng::new(vec![20, 20, 3]);
+
+        let pool = parse("2d20").evaluate(&BTreeMap::new(), &mut rng).unwrap();
+        let other = parse("1d12").evaluate(&BTreeMap::new(), &mut rng).unwrap();
+
+        assert_eq!(pool.natural_d20(), None);
+        assert_eq!(other.natural_d20(), None);
+    }
+
+    // --- outcomes ---
+
+    #[test]
+    fn test_outcomes_lists_every_combination_of_faces() {
+        // Act
+        let rolls = parse("2d6+1").outcomes(&BTreeMap::new()).unwrap();
+
+        // Assert
+        assert_eq!(rolls.len(), 36);
+        assert_eq!(rolls[0].total(), 3);
+        assert_eq!(rolls[35].total(), 13);
+        let sevens = rolls.iter().filter(|r| r.dice_total == 7).count();
+        assert_eq!(sevens, 6);
+    }
+
+    #[test]
+    fn test_outcomes_applies_keep_like_evaluate() {
+        // Arrange — faces 1, 1, 1, 6 are the 6th combination of 4d6.
+        let mut rng = SequenceRng::new(vec![1, 1, 1, 6]);
+        let expression = parse("4d6kh3");
+
+        // Act
+        let rolls = expression.outcomes(&BTreeMap::new()).unwrap();
+
+        // Assert
+        assert_eq!(rolls.len(), 1296);
+        let evaluated = expression.evaluate(&BTreeMap::new(), &mut rng).unwrap();
+        assert_eq!(rolls[5], evaluated);
+    }
+
+    #[test]
+    fn test_outcomes_rejects_exploding_and_oversized_expressions() {
+        for (expression, expected) in [
+            ("1d6!", "exploding dice"),
+            ("1d100+1d100+1d100", "more than 10000 possible rolls"),
+        ] {
+            match parse(expression).outcomes(&BTreeMap::new()) {
+                Err(DomainError::Validation(msg)) => assert!(msg.contains(expected), "{msg}"),
+                other => panic!("expected Validation for '{expression}', got {other:?}"),
+            }
+        }
+    }
+}
@@ -0,0 +1,454 @@
+//! The effect catalogue — what a resolution can produce.
+//!
+//! Every effect is one of a fixed set of typed [`Effect`]s, each with a
+//! payload the engine understands. A campaign that needs something the
+//! catalogue lacks declares a versioned custom effect in its front matter,
+//! with a JSON schema its payloads must satisfy; a [`CustomEffect`] names
+//! that declaration and carries data checked against it.
+//!
+//! On the wire an effect keeps its `{ effect_type, target_id, payload }`
+//! shape, so clients describe typed and custom effects alike.
+
+use otherworlds_core::error::DomainError;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use uuid::Uuid;
+
+/// The `effect_type` of campaign-defined effects.
+pub const CUSTOM_EFFECT_TYPE: &str = "custom";
+
+/// Every `effect_type` the catalogue accepts.
+pub const EFFECT_TYPES: &[&str] = &[
+    "damage",
+    "heal",
+    "apply_status",
+    "set_flag",
+    "grant_item",
+    "award_xp",
+    "shift_disposition",
+    CUSTOM_EFFECT_TYPE,
+];
+
+/// A typed effect, tagged by `effect_type` with its fields under `payload`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "effect_type", content = "payload", rename_all = "snake_case")]
+pub enum Effect {
+    /// The target loses hit points.
+    Damage(Damage),
+    /// The target regains hit points.
+    Heal(Heal),
+    /// The target gains a status condition.
+    ApplyStatus(ApplyStatus),
+    /// A world flag is set.
+    SetFlag(SetFlag),
+    /// An item is added to the target inventory.
+    GrantItem(GrantItem),
+    /// The target character gains experience.
+    AwardXp(AwardXp),
+    /// The target NPC's disposition shifts.
+    ShiftDisposition(ShiftDisposition),
+    /// A campaign-defined effect.
+    Custom(CustomEffect),
+}
+
+/// Payload of [`Effect::Damage`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Damage {
+    /// Hit points lost.
+    pub amount: u32,
+    /// Kind of damage (e.g., "`fire`"), if it matters.
+    #[serde(default)]
+    pub damage_type: Option<String>,
+}
+
+/// Payload of [`Effect::Heal`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Heal {
+    /// Hit points regained.
+    pub amount: u32,
+}
+
+/// Payload of [`Effect::ApplyStatus`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ApplyStatus {
+    /// The status condition (e.g., "`poisoned`").
+    pub status: String,
+    /// Rounds the status lasts; until removed when absent.
+    #[serde(default)]
+    pub duration_rounds: Option<u32>,
+}
+
+/// Payload of [`Effect::SetFlag`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SetFlag {
+    /// The flag name (e.g., "`vault_open`").
+    pub flag: String,
+    /// The flag's new value.
+    pub value: bool,
+}
+
+/// Payload of [`Effect::GrantItem`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct GrantItem {
+    /// The item granted.
+    pub item_id: Uuid,
+}
+
+/// Payload of [`Effect::AwardXp`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct AwardXp {
+    /// Experience points awarded.
+    pub amount: u32,
+}
+
+/// Payload of [`Effect::ShiftDisposition`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ShiftDisposition {
+    /// How far the disposition moves; negative towards hostility.
+    pub delta: i32,
+}
+
+/// Payload of [`Effect::Custom`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CustomEffect {
+    /// The name the campaign declared the effect under.
+    pub name: String,
+    /// The declared version whose schema `data` satisfies. Version 0 marks
+    /// effects recorded before the catalogue existed, which were never
+    /// checked.
+    pub version: u32,
+    /// The effect's data.
+    pub data: Value,
+}
+
+/// A campaign's declaration of one version of a custom effect.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CustomEffectSchema {
+    /// The effect name.
+    pub name: String,
+    /// The declaration version, from 1.
+    pub version: u32,
+    /// The JSON schema the effect's data must satisfy.
+    pub schema: Value,
+}
+
+/// Whether an effect needs a `target_id`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Target {
+    Required,
+    Forbidden,
+    Optional,
+}
+
+impl Effect {
+    /// Parses a wire effect, checking its type is in the catalogue and its
+    /// payload has that type's shape.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::Validation` for an unknown type or a payload
+    /// that does not match it.
+    pub fn parse(effect_type: &str, payload: Value) -> Result<Self, DomainError> {
+        if !EFFECT_TYPES.contains(&effect_type) {
+            return Err(DomainError::Validation(format!(
+                "unknown effect type '{effect_type}' (expected one of: {})",
+                EFFECT_TYPES.join(", ")
+            )));
+        }
+        let tagged = Map::from_iter([
+            ("effect_type".to_owned(), Value::from(effect_type)),
+            ("payload".to_owned(), payload),
+        ]);
+        serde_json::from_value(Value::Object(tagged))
+            .map_err(|e| DomainError::Validation(format!("invalid {effect_type} payload: {e}")))
+    }
+
+    /// Returns the effect's `effect_type`.
+    #[must_use]
+    pub fn effect_type(&self) -> &'static str {
+        match self {
+            Self::Damage(_) => "damage",
+            Self::Heal(_) => "heal",
+            Self::ApplyStatus(_) => "apply_status",
+            Self::SetFlag(_) => "set_flag",
+            Self::GrantItem(_) => "grant_item",
+            Self::AwardXp(_) => "award_xp",
+            Self::ShiftDisposition(_) => "shift_disposition",
+            Self::Custom(_) => CUSTOM_EFFECT_TYPE,
+        }
+    }
+
+    /// Returns the effect's payload as JSON.
+    #[must_use]
+    pub fn payload(&self) -> Value {
+        match serde_json::to_value(self) {
+            Ok(Value::Object(mut tagged)) => tagged.remove("payload").unwrap_or(Value::Null),
+            _ => Value::Null,
+        }
+    }
+
+    /// Checks the effect's values, its target, and — for a custom effect —
+    /// its data against the campaign's declaration.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::Validation` describing the first problem found.
+    pub fn validate(
+        &self,
+        target_id: Option<Uuid>,
+        custom_effects: &[CustomEffectSchema],
+    ) -> Result<(), DomainError> {
+        let effect_type = self.effect_type();
+        match (self.target(), target_id) {
+            (Target::Required, None) => {
+                return Err(DomainError::Validation(format!(
+                    "{effect_type} effect requires a target_id"
+                )));
+            }
+            (Target::Forbidden, Some(_)) => {
+                return Err(DomainError::Validation(format!(
+                    "{effect_type} effect does not take a target_id"
+                )));
+            }
+            _ => {}
+        }
+
+        let problem = match self {
+            Self::Damage(Damage { amount, .. })
+            | Self::Heal(Heal { amount })
+            | Self::AwardXp(AwardXp { amount })
+                if *amount == 0 =>
+            {
+                Some("amount must be positive")
+            }
+            Self::Damage(Damage {
+                damage_type: Some(name),
+                ..
+            })
+            | Self::ApplyStatus(ApplyStatus { status: name, .. })
+            | Self::SetFlag(SetFlag { flag: name, .. })
+                if name.trim().is_empty() =>
+            {
+                Some("names must not be blank")
+            }
+            Self::ApplyStatus(ApplyStatus {
+                duration_rounds: Some(0),
+                ..
+            }) => Some("duration_rounds must be positive"),
+            Self::ShiftDisposition(ShiftDisposition { delta: 0 }) => Some("delta must not be zero"),
+            Self::Custom(custom) => return custom.validate(custom_effects),
+            _ => None,
+        };
+        match problem {
+            Some(problem) => Err(DomainError::Validation(format!(
+                "invalid {effect_type} payload: {problem}"
+            ))),
+            None => Ok(()),
+        }
+    }
+
+    fn target(&self) -> Target {
+        match self {
+            Self::Damage(_)
+            | Self::Heal(_)
+            | Self::ApplyStatus(_)
+            | Self::GrantItem(_)
+            | Self::AwardXp(_)
+            | Self::ShiftDisposition(_) => Target::Required,
+            Self::SetFlag(_) => Target::Forbidden,
+            Self::Custom(_) => Target::Optional,
+        }
+    }
+}
+
+impl CustomEffect {
+    /// Checks `data` against the schema the campaign declared for this
+    /// name and version.
+    fn validate(&self, custom_effects: &[CustomEffectSchema]) -> Result<(), DomainError> {
+        let Some(declared) = custom_effects
+            .iter()
+            .find(|s| s.name == self.name && s.version == self.version)
+        else {
+            return Err(DomainError::Validation(format!(
+                "custom effect '{}' v{} is not declared by the campaign",
+                self.name, self.version
+            )));
+        };
+        let validator = jsonschema::validator_for(&declared.schema).map_err(|e| {
+            DomainError::Validation(format!(
+                "custom effect '{}' v{} has an invalid schema: {e}",
+                self.name, self.version
+            ))
+        })?;
+        let errors: Vec<String> = validator
+            .iter_errors(&self.data)
+            .map(|e| format!("{e} at '{}'", e.instance_path))
+            .collect();
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(DomainError::Validation(format!(
+                "custom effect '{}' v{} data does not match its schema: {}",
+                self.name,
+                self.version,
+                errors.join("; ")
+            )))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn storm_schema() -> CustomEffectSchema {
+        CustomEffectSchema {
+            name: "summon_storm".to_owned(),
+            version: 2,
+            schema: json!({
+                "type": "object",
+                "required": ["intensity"],
+                "properties": { "intensity": { "type": "integer", "minimum": 1 } }
+            }),
+        }
+    }
+
+    fn custom(version: u32, data: Value) -> Effect {
+        Effect::Custom(CustomEffect {
+            name: "summon_storm".to_owned(),
+            version,
+            data,
+        })
+    }
+
+    #[test]
+    fn test_parse_reads_typed_payload() {
+        // Act
+        let effect =
+            Effect::parse("damage", json!({ "amount": 8, "damage_type": "fire" })).unwrap();
+
+        // Assert
+        assert_eq!(
+            effect,
+            Effect::Damage(Damage {
+                amount: 8,
+                damage_type: Some("fire".to_owned()),
+            })
+        );
+        assert_eq!(effect.effect_type(), "damage");
+        assert_eq!(
+            effect.payload(),
+            json!({ "amount": 8, "damage_type": "fire" })
+        );
+    }
+
+    #[test]
+    fn test_parse_rejects_unknown_type_and_malformed_payload() {
+        for (effect_type, payload, expected) in [
+            ("reveal", json!({}), "unknown effect type 'reveal'"),
+            ("heal", json!({ "amount": -1 }), "invalid heal payload"),
+            ("award_xp", json!({}), "missing field `amount`"),
+            (
+                "set_flag",
+                json!({ "flag": "x", "value": true, "extra": 1 }),
+                "unknown field",
+            ),
+        ] {
+            match Effect::parse(effect_type, payload) {
+                Err(DomainError::Validation(msg)) => assert!(msg.contains(expected), "{msg}"),
+                other => panic!("expected Validation for {effect_type}, got {other:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn test_validate_enforces_target_rules() {
+        let target = Some(Uuid::new_v4());
+        let heal = Effect::Heal(Heal { amount: 3 });
+        let flag = Effect::SetFlag(SetFlag {
+            flag: "vault_open".to_owned(),
+            value: true,
+        });
+
+        assert!(heal.validate(target, &[]).is_ok());
+        assert!(heal.validate(None, &[]).is_err());
+        assert!(flag.validate(None, &[]).is_ok());
+        assert!(flag.validate(target, &[]).is_err());
+    }
+
+    #[test]
+    fn test_validate_rejects_empty_values() {
+        let target = Some(Uuid::new_v4());
+        for effect in [
+            Effect::Damage(Damage {
+                amount: 0,
+                damage_type: None,
+            }),
+            Effect::ApplyStatus(ApplyStatus {
+                status: " ".to_owned(),
+                duration_rounds: None,
+            }),
+            Effect::ApplyStatus(ApplyStatus {
+                status: "prone".to_owned(),
+                duration_rounds: Some(0),
+            }),
+            Effect::ShiftDisposition(ShiftDisposition { delta: 0 }),
+        ] {
+            assert!(
+                matches!(
+                    effect.validate(target, &[]),
+                    Err(DomainError::Validation(_))
+                ),
+                "{effect:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_validate_checks_custom_data_against_declared_schema() {
+        let schemas = [storm_schema()];
+
+        assert!(
+            custom(2, json!({ "intensity": 3 }))
+                .validate(None, &schemas)
+                .is_ok()
+        );
+        match custom(2, json!({ "intensity": 0 })).validate(None, &schemas) {
+            Err(DomainError::Validation(msg)) => {
+                assert!(msg.contains("does not match its schema"), "{msg}");
+                assert!(msg.contains("/intensity"), "{msg}");
+            }
+            other => panic!("expected Validation, got {other:?}"),
+        }
+        match custom(1, json!({ "intensity": 3 })).validate(None, &schemas) {
+            Err(DomainError::Validation(msg)) => {
+                assert!(msg.contains("'summon_storm' v1 is not declared"), "{msg}");
+            }
+            other => panic!("expected Validation, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_validate_rejects_invalid_custom_schema() {
+        let schemas = [CustomEffectSchema {
+            schema: json!({ "type": "no-such-type" }),
+            ..storm_schema()
+        }];
+
+        let result = custom(2, json!({})).validate(None, &schemas);
+
+        assert!(
+            matches!(result, Err(DomainError::Validation(msg)) if msg.contains("invalid schema"))
+        );
+    }
+}
@@ -0,0 +1,903 @@
+//! The `Encounter` aggregate — initiative, rounds and turns of a combat.
+//!
+//! Starting an encounter rolls 1d20 + modifier initiative for every
+//! participant and fixes the turn order, highest first. Ties go to the
+//! higher modifier, then to whoever was listed first. Each turn belongs to
+//! one participant; ending it starts the next, and the round advances once
+//! everyone has acted. The acting participant may instead delay (move to
+//! act after someone who has not acted yet this round) or ready an action,
+//! which lets them act out of turn when its trigger occurs. A readied action
+//! lapses when its participant's next turn starts.
+//!
+//! The checks made during a turn are ordinary `Resolution`s; the encounter
+//! only records which participant made them in which round.
+
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+
+use otherworlds_core::aggregate::AggregateRoot;
+use otherworlds_core::clock::Clock;
+use otherworlds_core::error::DomainError;
+use otherworlds_core::event::EventMetadata;
+use otherworlds_core::rng::DeterministicRng;
+use otherworlds_core::snapshot::Snapshottable;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::events::{
+    ACTION_READIED_EVENT_TYPE, ActionReadied, ENCOUNTER_ENDED_EVENT_TYPE,
+    ENCOUNTER_STARTED_EVENT_TYPE, EncounterEnded, EncounterEvent, EncounterEventKind,
+    EncounterStarted, InitiativeRoll, READIED_ACTION_TRIGGERED_EVENT_TYPE,
+    RESOLUTION_LINKED_EVENT_TYPE, ReadiedActionTriggered, ResolutionLinked,
+    TURN_DELAYED_EVENT_TYPE, TURN_STARTED_EVENT_TYPE, TurnDelayed, TurnStarted,
+};
+use super::upcasters::current_schema_version;
+
+/// A participant joining an encounter.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EncounterParticipant {
+    /// The participant (typically a character) identifier.
+    pub participant_id: Uuid,
+    /// Display name of the participant.
+    pub name: String,
+    /// The modifier added to the participant's initiative roll.
+    #[serde(default)]
+    pub initiative_modifier: i32,
+}
+
+/// Encounter lifecycle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) enum EncounterPhase {
+    NotStarted,
+    Active,
+    Ended,
+}
+
+/// A resolution linked to a participant's action.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct LinkedResolution {
+    pub round: u32,
+    pub participant_id: Uuid,
+    pub resolution_id: Uuid,
+}
+
+/// The aggregate root for a combat encounter.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Encounter {
+    /// Aggregate identifier.
+    pub id: Uuid,
+    /// Current version (event count).
+    pub(crate) version: i64,
+    /// Lifecycle phase.
+    pub(crate) phase: EncounterPhase,
+    /// Initiative rolls, in the order participants were listed.
+    pub(crate) initiative: Vec<InitiativeRoll>,
+    /// Participant IDs in the order they act.
+    pub(crate) turn_order: Vec<Uuid>,
+    /// The current round, starting at 1 (0 before the encounter starts).
+    pub(crate) round: u32,
+    /// Index into `turn_order` of the participant whose turn it is.
+    pub(crate) turn_index: usize,
+    /// Readied actions by participant, with their triggers.
+    pub(crate) readied: BTreeMap<Uuid, String>,
+    /// Participants whose readied action fired during the current turn.
+    pub(crate) interrupting: BTreeSet<Uuid>,
+    /// Resolutions linked to participants' actions, in order.
+    pub(crate) resolutions: Vec<LinkedResolution>,
+    /// Why the encounter ended, if it has.
+    pub(crate) end_reason: Option<String>,
+    /// Uncommitted events pending persistence.
+    #[serde(skip)]
+    uncommitted_events: Vec<EncounterEvent>,
+}
+
+impl Encounter {
+    /// Creates a new, not yet started encounter.
+    #[must_use]
+    pub fn new(id: Uuid) -> Self {
+        Self {
+            id,
+            version: 0,
+            phase: EncounterPhase::NotStarted,
+            initiative: Vec::new(),
+            turn_order: Vec::new(),
+            round: 0,
+            turn_index: 0,
+            readied: BTreeMap::new(),
+            interrupting: BTreeSet::new(),
+            resolutions: Vec::new(),
+            end_reason: None,
+            uncommitted_events: Vec::new(),
+        }
+    }
+
+    /// Returns the participant whose turn it is, while the encounter is
+    /// active.
+    #[must_use]
+    pub fn current_participant(&self) -> Option<Uuid> {
+        match self.phase {
+            EncounterPhase::Active => self.turn_order.get(self.turn_index).copied(),
+            EncounterPhase::NotStarted | EncounterPhase::Ended => None,
+        }
+    }
+
+    /// Returns the current phase as a string.
+    #[must_use]
+    pub fn phase_name(&self) -> &'static str {
+        match self.phase {
+            EncounterPhase::NotStarted => "not_started",
+            EncounterPhase::Active => "active",
+            EncounterPhase::Ended => "ended",
+        }
+    }
+
+    /// Returns the next sequence number for a new event.
+    #[allow(clippy::cast_possible_wrap)]
+    fn next_sequence_number(&self) -> i64 {
+        self.version + self.uncommitted_events.len() as i64 + 1
+    }
+
+    fn record(
+        &mut self,
+        event_type: &str,
+        kind: EncounterEventKind,
+        correlation_id: Uuid,
+        clock: &dyn Clock,
+        rng: &mut dyn DeterministicRng,
+    ) {
+        let event = EncounterEvent {
+            metadata: EventMetadata {
+                event_id: rng.next_uuid(),
+                event_type: event_type.to_owned(),
+                aggregate_id: self.id,
+                sequence_number: self.next_sequence_number(),
+                correlation_id,
+                causation_id: correlation_id,
+                occurred_at: clock.now(),
+                schema_version: current_schema_version(event_type),
+            },
+            kind,
+        };
+        self.uncommitted_events.push(event);
+    }
+
+    fn record_turn_started(
+        &mut self,
+        round: u32,
+        participant_id: Uuid,
+        correlation_id: Uuid,
+        clock: &dyn Clock,
+        rng: &mut dyn DeterministicRng,
+    ) {
+        let kind = EncounterEventKind::TurnStarted(TurnStarted {
+            encounter_id: self.id,
+            round,
+            participant_id,
+        });
+        self.record(TURN_STARTED_EVENT_TYPE, kind, correlation_id, clock, rng);
+    }
+
+    /// Returns the acting participant, or an error unless the encounter is
+    /// active.
+    fn acting_participant(&self) -> Result<Uuid, DomainError> {
+        match self.phase {
+            EncounterPhase::NotStarted => Err(DomainError::Validation(
+                "encounter has not started".to_owned(),
+            )),
+            EncounterPhase::Ended => Err(DomainError::Validation("encounter has ended".to_owned())),
+            EncounterPhase::Active => self.current_participant().ok_or_else(|| {
+                DomainError::Validation("encounter has no acting participant".to_owned())
+            }),
+        }
+    }
+
+    /// Returns the round and participant of the turn after the current one.
+    fn next_turn(&self) -> (u32, Uuid) {
+        match self.turn_order.get(self.turn_index + 1) {
+            Some(&next) => (self.round, next),
+            None => (self.round + 1, self.turn_order[0]),
+        }
+    }
+
+    /// Starts the encounter: rolls 1d20 + modifier initiative for each
+    /// participant in the order listed, fixes the turn order and starts the
+    /// first turn of round 1. Produces `EncounterStarted` and `TurnStarted`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::Validation` if the encounter has already
+    /// started, if there are no participants, if a participant is listed
+    /// twice or has a blank name, or if an initiative total overflows.
+    pub fn start(
+        &mut self,
+        participants: &[EncounterParticipant],
+        correlation_id: Uuid,
+        clock: &dyn Clock,
+        rng: &mut dyn DeterministicRng,
+    ) -> Result<(), DomainError> {
+        if self.phase != EncounterPhase::NotStarted {
+            return Err(DomainError::Validation(
+                "encounter has already started".to_owned(),
+            ));
+        }
+        if participants.is_empty() {
+            return Err(DomainError::Validation(
+                "an encounter needs at least one participant".to_owned(),
+            ));
+        }
+        let mut seen = HashSet::new();
+        for participant in participants {
+            if !seen.insert(participant.participant_id) {
+                return Err(DomainError::Validation(format!(
+                    "participant {} is listed more than once",
+                    participant.participant_id
+                )));
+            }
+            if participant.name.trim().is_empty() {
+                return Err(DomainError::Validation(format!(
+                    "participant {} has no name",
+                    participant.participant_id
+                )));
+            }
+        }
+
+        let initiative = participants
+            .iter()
+            .map(|participant| {
+                let natural_roll = rng.next_u32_range(1, 20);
+                let total = i32::try_from(natural_roll)
+                    .ok()
+                    .and_then(|roll| roll.checked_add(participant.initiative_modifier))
+                    .ok_or_else(|| {
+                        DomainError::Validation("initiative total overflows".to_owned())
+                    })?;
+                Ok(InitiativeRoll {
+                    participant_id: participant.participant_id,
+                    name: participant.name.clone(),
+                    modifier: participant.initiative_modifier,
+                    natural_roll,
+                    initiative: total,
+                })
+            })
+            .collect::<Result<Vec<_>, DomainError>>()?;
+
+        // A stable sort keeps listing order among full ties.
+        let mut ranked: Vec<&InitiativeRoll> = initiative.iter().collect();
+        ranked.sort_by_key(|roll| (Reverse(roll.initiative), Reverse(roll.modifier)));
+        let turn_order: Vec<Uuid> = ranked.iter().map(|roll| roll.participant_id).collect();
+        let first = turn_order[0];
+
+        let kind = EncounterEventKind::EncounterStarted(EncounterStarted {
+            encounter_id: self.id,
+            initiative,
+            turn_order,
+        });
+        self.record(
+            ENCOUNTER_STARTED_EVENT_TYPE,
+            kind,
+            correlation_id,
+            clock,
+            rng,
+        );
+        self.record_turn_started(1, first, correlation_id, clock, rng);
+        Ok(())
+    }
+
+    /// Ends the acting participant's turn and starts the next one, moving to
+    /// a new round after the last participant. Produces `TurnStarted`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::Validation` if the encounter is not active.
+    pub fn end_turn(
+        &mut self,
+        correlation_id: Uuid,
+        clock: &dyn Clock,
+        rng: &mut dyn DeterministicRng,
+    ) -> Result<(), DomainError> {
+        self.acting_participant()?;
+        let (round, next) = self.next_turn();
+        self.record_turn_started(round, next, correlation_id, clock, rng);
+        Ok(())
+    }
+
+    /// Delays the acting participant until after `after_participant_id`,
+    /// who must not have acted yet this round. The new position holds for
+    /// the rest of the encounter. Produces `TurnDelayed` and the
+    /// `TurnStarted` of the participant who was next.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::Validation` if the encounter is not active, or
+    /// if `after_participant_id` is the acting participant, is not in the
+    /// encounter, or has already acted this round.
+    pub fn delay_turn(
+        &mut self,
+        after_participant_id: Uuid,
+        correlation_id: Uuid,
+        clock: &dyn Clock,
+        rng: &mut dyn DeterministicRng,
+    ) -> Result<(), DomainError> {
+        let participant_id = self.acting_participant()?;
+        if after_participant_id == participant_id {
+            return Err(DomainError::Validation(
+                "a participant cannot delay until after themselves".to_owned(),
+            ));
+        }
+        let position = self
+            .turn_order
+            .iter()
+            .position(|&id| id == after_participant_id)
+            .ok_or_else(|| {
+                DomainError::Validation(format!(
+                    "participant {after_participant_id} is not in this encounter"
+                ))
+            })?;
+        if position < self.turn_index {
+            return Err(DomainError::Validation(format!(
+                "participant {after_participant_id} has already acted this round"
+            )));
+        }
+
+        // The participant after the acting one has not acted yet, so the
+        // round does not advance.
+        let next = self.turn_order[self.turn_index + 1];
+        let kind = EncounterEventKind::TurnDelayed(TurnDelayed {
+            encounter_id: self.id,
+            round: self.round,
+            participant_id,
+            after_participant_id,
+        });
+        self.record(TURN_DELAYED_EVENT_TYPE, kind, correlation_id, clock, rng);
+        self.record_turn_started(self.round, next, correlation_id, clock, rng);
+        Ok(())
+    }
+
+    /// Readies an action for the acting participant and ends their turn.
+    /// Produces `ActionReadied` and the next `TurnStarted`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::Validation` if the encounter is not active or
+    /// the trigger is blank.
+    pub fn ready_action(
+        &mut self,
+        trigger: &str,
+        correlation_id: Uuid,
+        clock: &dyn Clock,
+        rng: &mut dyn DeterministicRng,
+    ) -> Result<(), DomainError> {
+        let participant_id = self.acting_participant()?;
+        if trigger.trim().is_empty() {
+            return Err(DomainError::Validation(
+                "a readied action needs a trigger".to_owned(),
+            ));
+        }
+        let (round, next) = self.next_turn();
+        let kind = EncounterEventKind::ActionReadied(ActionReadied {
+            encounter_id: self.id,
+            round: self.round,
+            participant_id,
+            trigger: trigger.to_owned(),
+        });
+        self.record(ACTION_READIED_EVENT_TYPE, kind, correlation_id, clock, rng);
+        self.record_turn_started(round, next, correlation_id, clock, rng);
+        Ok(())
+    }
+
+    /// Fires `participant_id`'s readied action, letting them act during the
+    /// current turn. Produces `ReadiedActionTriggered`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::Validation` if the encounter is not active or
+    /// the participant has no readied action.
+    pub fn trigger_readied_action(
+        &mut self,
+        participant_id: Uuid,
+        correlation_id: Uuid,
+        clock: &dyn Clock,
+        rng: &mut dyn DeterministicRng,
+    ) -> Result<(), DomainError> {
+        self.acting_participant()?;
+        if !self.readied.contains_key(&participant_id) {
+            return Err(DomainError::Validation(format!(
+                "participant {participant_id} has no readied action"
+            )));
+        }
+        let kind = EncounterEventKind::ReadiedActionTriggered(ReadiedActionTriggered {
+            encounter_id: self.id,
+            round: self.round,
+            participant_id,
+        });
+        self.record(
+            READIED_ACTION_TRIGGERED_EVENT_TYPE,
+            kind,
+            correlation_id,
+            clock,
+            rng,
+        );
+        Ok(())
+    }
+
+    /// Links a resolution to `participant_id`'s action in the current round.
+    /// Produces `ResolutionLinked`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::Validation` if the encounter is not active, if
+    /// the participant is neither acting nor interrupting with a readied
+    /// action, or if the resolution is already linked.
+    pub fn link_resolution(
+        &mut self,
+        participant_id: Uuid,
+        resolution_id: Uuid,
+        correlation_id: Uuid,
+        clock: &dyn Clock,
+        rng: &mut dyn DeterministicRng,
+    ) -> Result<(), DomainError> {
+        let acting = self.acting_participant()?;
+        if participant_id != acting && !self.interrupting.contains(&participant_id) {
+            return Err(DomainError::Validation(format!(
+                "participant {participant_id} is not acting"
+            )));
+        }
+        if self
+            .resolutions
+            .iter()
+            .any(|linked| linked.resolution_id == resolution_id)
+        {
+            return Err(DomainError::Validation(format!(
+                "resolution {resolution_id} is already linked"
+            )));
+        }
+        let kind = EncounterEventKind::ResolutionLinked(ResolutionLinked {
+            encounter_id: self.id,
+            round: self.round,
+            participant_id,
+            resolution_id,
+        });
+        self.record(
+            RESOLUTION_LINKED_EVENT_TYPE,
+            kind,
+            correlation_id,
+            clock,
+            rng,
+        );
+        Ok(())
+    }
+
+    /// Ends the encounter. Produces `EncounterEnded`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::Validation` if the encounter is not active.
+    pub fn end(
+        &mut self,
+        reason: Option<String>,
+        correlation_id: Uuid,
+        clock: &dyn Clock,
+        rng: &mut dyn DeterministicRng,
+    ) -> Result<(), DomainError> {
+        self.acting_participant()?;
+        let kind = EncounterEventKind::EncounterEnded(EncounterEnded {
+            encounter_id: self.id,
+            round: self.round,
+            reason,
+        });
+        self.record(ENCOUNTER_ENDED_EVENT_TYPE, kind, correlation_id, clock, rng);
+        Ok(())
+    }
+}
+
+impl AggregateRoot for Encounter {
+    type Event = EncounterEvent;
+
+    fn aggregate_id(&self) -> Uuid {
+        self.id
+    }
+
+    fn version(&self) -> i64 {
+        self.version
+    }
+
+    fn apply(&mut self, event: &Self::Event) {
+        match &event.kind {
+            EncounterEventKind::EncounterStarted(payload) => {
+                self.phase = EncounterPhase::Active;
+                self.initiative.clone_from(&payload.initiative);
+                self.turn_order.clone_from(&payload.turn_order);
+            }
+            EncounterEventKind::TurnStarted(payload) => {
+                self.round = payload.round;
+                self.turn_index = self
+                    .turn_order
+                    .iter()
+                    .position(|&id| id == payload.participant_id)
+                    .unwrap_or(0);
+                self.readied.remove(&payload.participant_id);
+                self.interrupting.clear();
+            }
+            EncounterEventKind::TurnDelayed(payload) => {
+                self.turn_order.retain(|&id| id != payload.participant_id);
+                let after = self
+                    .turn_order
+                    .iter()
+                    .position(|&id| id == payload.after_participant_id)
+                    .map_or(self.turn_order.len(), |index| index + 1);
+                self.turn_order.insert(after, payload.participant_id);
+            }
+            EncounterEventKind::ActionReadied(payload) => {
+                self.readied
+                    .insert(payload.participant_id, payload.trigger.clone());
+            }
+            EncounterEventKind::ReadiedActionTriggered(payload) => {
+                self.readied.remove(&payload.participant_id);
+                self.interrupting.insert(payload.participant_id);
+            }
+            EncounterEventKind::ResolutionLinked(payload) => {
+                self.resolutions.push(LinkedResolution {
+                    round: payload.round,
+                    participant_id: payload.participant_id,
+                    resolution_id: payload.resolution_id,
+                });
+            }
+            EncounterEventKind::EncounterEnded(payload) => {
+                self.phase = EncounterPhase::Ended;
+                self.end_reason.clone_from(&payload.reason);
+                self.readied.clear();
+                self.interrupting.clear();
+            }
+        }
+        self.version += 1;
+    }
+
+    fn uncommitted_events(&self) -> &[Self::Event] {
+        &self.uncommitted_events
+    }
+
+    fn clear_uncommitted_events(&mut self) {
+        self.uncommitted_events.clear();
+    }
+}
+
+impl Snapshottable for Encounter {
+    const AGGREGATE_TYPE: &'static str = "rules.encounter";
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+    use otherworlds_core::event::DomainEvent;
+    use otherworlds_test_support::{FixedClock, MockRng, SequenceRng};
+
+    fn fixed_clock() -> FixedClock {
+        FixedClock(Utc.with_ymd_and_hms(2026, 1, 15, 10, 0, 0).unwrap())
+    }
+
+    fn participant(name: &str, initiative_modifier: i32) -> EncounterParticipant {
+        EncounterParticipant {
+            participant_id: Uuid::new_v4(),
+            name: name.to_owned(),
+            initiative_modifier,
+        }
+    }
+
+    /// Applies and clears the uncommitted events.
+    fn commit(encounter: &mut Encounter) {
+        for event in encounter.uncommitted_events().to_vec() {
+            encounter.apply(&event);
+        }
+        encounter.clear_uncommitted_events();
+    }
+
+    /// Starts an encounter whose participants act in the order given.
+    fn started(participants: &[EncounterParticipant]) -> Encounter {
+        let mut encounter = Encounter::new(Uuid::new_v4());
+        // Descending natural rolls keep the listed order.
+        let mut values: Vec<u32> = (0..participants.len())
+            .map(|i| 20 - u32::try_from(i).unwrap())
+            .collect();
+        values.extend(std::iter::repeat_n(7, 8));
+        encounter
+            .start(
+                participants,
+                Uuid::new_v4(),
+                &fixed_clock(),
+                &mut SequenceRng::new(values),
+            )
+            .unwrap();
+        commit(&mut encounter);
+        encounter
+    }
+
+    fn turn_started(encounter: &Encounter) -> &TurnStarted {
+        let event = encounter.uncommitted_events().last().unwrap();
+        match &event.kind {
+            EncounterEventKind::TurnStarted(payload) => payload,
+            other => panic!("expected TurnStarted, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_start_rolls_initiative_and_orders_turns() {
+        // Arrange — the rogue rolls 8+4, the fighter 11+0, the ogre 13-1.
+        let rogue = participant("Rogue", 4);
+        let fighter = participant("Fighter", 0);
+        let ogre = participant("Ogre", -1);
+        let mut encounter = Encounter::new(Uuid::new_v4());
+        let mut values = vec![8, 11, 13];
+        values.extend(std::iter::repeat_n(7, 8));
+
+        // Act
+        encounter
+            .start(
+                &[rogue.clone(), fighter.clone(), ogre.clone()],
+                Uuid::new_v4(),
+                &fixed_clock(),
+                &mut SequenceRng::new(values),
+            )
+            .unwrap();
+
+        // Assert — rogue and ogre tie on 12; the rogue's modifier is higher.
+        let events = encounter.uncommitted_events();
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].event_type(), ENCOUNTER_STARTED_EVENT_TYPE);
+        match &events[0].kind {
+            EncounterEventKind::EncounterStarted(payload) => {
+                assert_eq!(payload.initiative[0].natural_roll, 8);
+                assert_eq!(payload.initiative[0].initiative, 12);
+                assert_eq!(payload.initiative[2].initiative, 12);
+                assert_eq!(
+                    payload.turn_order,
+                    vec![
+                        rogue.participant_id,
+                        ogre.participant_id,
+                        fighter.participant_id
+                    ]
+                );
+            }
+            other => panic!("expected EncounterStarted, got {other:?}"),
+        }
+        let first = turn_started(&encounter);
+        assert_eq!(first.round, 1);
+        assert_eq!(first.participant_id, rogue.participant_id);
+    }
+
+    #[test]
+    fn test_start_rejects_duplicate_participants() {
+        // Arrange
+        let hero = participant("Hero", 0);
+        let mut encounter = Encounter::new(Uuid::new_v4());
+
+        // Act
+        let result = encounter.start(
+            &[hero.clone(), hero],
+            Uuid::new_v4(),
+            &fixed_clock(),
+            &mut MockRng,
+        );
+
+        // Assert
+        assert!(matches!(result, Err(DomainError::Validation(_))));
+    }
+
+    #[test]
+    fn test_start_rejects_empty_encounter_and_restart() {
+        let mut encounter = Encounter::new(Uuid::new_v4());
+        let empty = encounter.start(&[], Uuid::new_v4(), &fixed_clock(), &mut MockRng);
+        assert!(matches!(empty, Err(DomainError::Validation(_))));
+
+        let mut encounter = started(&[participant("Hero", 0)]);
+        let again = encounter.start(
+            &[participant("Hero", 0)],
+            Uuid::new_v4(),
+            &fixed_clock(),
+            &mut MockRng,
+        );
+        assert!(matches!(again, Err(DomainError::Validation(msg)) if msg.contains("already")));
+    }
+
+    #[test]
+    fn test_end_turn_advances_turns_and_rounds() {
+        // Arrange
+        let a = participant("A", 0);
+        let b = participant("B", 0);
+        let mut encounter = started(&[a.clone(), b.clone()]);
+
+        // Act — A ends, then B ends.
+        encounter
+            .end_turn(Uuid::new_v4(), &fixed_clock(), &mut MockRng)
+            .unwrap();
+        let second = turn_started(&encounter).clone();
+        commit(&mut encounter);
+        encounter
+            .end_turn(Uuid::new_v4(), &fixed_clock(), &mut MockRng)
+            .unwrap();
+        let third = turn_started(&encounter).clone();
+        commit(&mut encounter);
+
+        // Assert
+        assert_eq!((second.round, second.participant_id), (1, b.participant_id));
+        assert_eq!((third.round, third.participant_id), (2, a.participant_id));
+        assert_eq!(encounter.current_participant(), Some(a.participant_id));
+    }
+
+    #[test]
+    fn test_delay_turn_moves_participant_after_target() {
+        // Arrange
+        let a = participant("A", 0);
+        let b = participant("B", 0);
+        let c = participant("C", 0);
+        let mut encounter = started(&[a.clone(), b.clone(), c.clone()]);
+
+        // Act — A delays until after C.
+        encounter
+            .delay_turn(
+                c.participant_id,
+                Uuid::new_v4(),
+                &fixed_clock(),
+                &mut MockRng,
+            )
+            .unwrap();
+        let next = turn_started(&encounter).clone();
+        commit(&mut encounter);
+
+        // Assert
+        assert_eq!(next.participant_id, b.participant_id);
+        assert_eq!(next.round, 1);
+        assert_eq!(
+            encounter.turn_order,
+            vec![b.participant_id, c.participant_id, a.participant_id]
+        );
+        assert_eq!(encounter.current_participant(), Some(b.participant_id));
+    }
+
+    #[test]
+    fn test_delay_turn_rejects_participant_who_already_acted() {
+        // Arrange
+        let a = participant("A", 0);
+        let b = participant("B", 0);
+        let mut encounter = started(&[a.clone(), b]);
+        encounter
+            .end_turn(Uuid::new_v4(), &fixed_clock(), &mut MockRng)
+            .unwrap();
+        commit(&mut encounter);
+
+        // Act
+        let result = encounter.delay_turn(
+            a.participant_id,
+            Uuid::new_v4(),
+            &fixed_clock(),
+            &mut MockRng,
+        );
+
+        // Assert
+        assert!(
+            matches!(result, Err(DomainError::Validation(msg)) if msg.contains("already acted"))
+        );
+    }
+
+    #[test]
+    fn test_readied_action_lets_participant_act_out_of_turn() {
+        // Arrange — A readies, then it is B's turn.
+        let a = participant("A", 0);
+        let b = participant("B", 0);
+        let mut encounter = started(&[a.clone(), b.clone()]);
+        encounter
+            .ready_action(
+                "the door opens",
+                Uuid::new_v4(),
+                &fixed_clock(),
+                &mut MockRng,
+            )
+            .unwrap();
+        commit(&mut encounter);
+        let resolution_id = Uuid::new_v4();
+
+        // Act
+        encounter
+            .trigger_readied_action(
+                a.participant_id,
+                Uuid::new_v4(),
+                &fixed_clock(),
+                &mut MockRng,
+            )
+            .unwrap();
+        commit(&mut encounter);
+        encounter
+            .link_resolution(
+                a.participant_id,
+                resolution_id,
+                Uuid::new_v4(),
+                &fixed_clock(),
+                &mut MockRng,
+            )
+            .unwrap();
+        commit(&mut encounter);
+
+        // Assert
+        assert_eq!(encounter.current_participant(), Some(b.participant_id));
+        assert!(encounter.readied.is_empty());
+        assert_eq!(encounter.resolutions.len(), 1);
+        assert_eq!(encounter.resolutions[0].participant_id, a.participant_id);
+        assert_eq!(encounter.resolutions[0].round, 1);
+    }
+
+    #[test]
+    fn test_readied_action_lapses_at_participants_next_turn() {
+        // Arrange
+        let a = participant("A", 0);
+        let b = participant("B", 0);
+        let mut encounter = started(&[a.clone(), b]);
+        encounter
+            .ready_action(
+                "an enemy approaches",
+                Uuid::new_v4(),
+                &fixed_clock(),
+                &mut MockRng,
+            )
+            .unwrap();
+        commit(&mut encounter);
+
+        // Act — B ends their turn without the trigger occurring.
+        encounter
+            .end_turn(Uuid::new_v4(), &fixed_clock(), &mut MockRng)
+            .unwrap();
+        commit(&mut encounter);
+        let result = encounter.trigger_readied_action(
+            a.participant_id,
+            Uuid::new_v4(),
+            &fixed_clock(),
+            &mut MockRng,
+        );
+
+        // Assert
+        assert!(matches!(result, Err(DomainError::Validation(_))));
+    }
+
+    #[test]
+    fn test_link_resolution_rejects_participant_not_acting() {
+        // Arrange
+        let a = participant("A", 0);
+        let b = participant("B", 0);
+        let mut encounter = started(&[a, b.clone()]);
+
+        // Act
+        let result = encounter.link_resolution(
+            b.participant_id,
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            &fixed_clock(),
+            &mut MockRng,
+        );
+
+        // Assert
+        assert!(matches!(result, Err(DomainError::Validation(msg)) if msg.contains("not acting")));
+    }
+
+    #[test]
+    fn test_end_encounter_stops_further_turns() {
+        // Arrange
+        let mut encounter = started(&[participant("A", 0)]);
+
+        // Act
+        encounter
+            .end(
+                Some("fled".to_owned()),
+                Uuid::new_v4(),
+                &fixed_clock(),
+                &mut MockRng,
+            )
+            .unwrap();
+        commit(&mut encounter);
+
+        // Assert
+        assert_eq!(encounter.phase, EncounterPhase::Ended);
+        assert_eq!(encounter.end_reason.as_deref(), Some("fled"));
+        assert_eq!(encounter.current_participant(), None);
+        let result = encounter.end_turn(Uuid::new_v4(), &fixed_clock(), &mut MockRng);
+        assert!(matches!(result, Err(DomainError::Validation(msg)) if msg.contains("ended")));
+    }
+}
@@ -1,26 +1,52 @@
 //! Domain events for the Rules & Resolution context.
 
+use std::collections::BTreeMap;
 use std::fmt;
 
+use otherworlds_core::error::DomainError;
 use otherworlds_core::event::{DomainEvent, EventMetadata};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-/// Five-tier outcome of a d20 check.
+use super::dice::{DiceExpression, DieRoll};
+use super::effects::Effect;
+use super::modifiers::ModifierBreakdown;
+use super::ruleset::RulesetId;
+
+/// Five-tier outcome of a check.
+///
+/// The bands below are the d20 ruleset's; other rulesets map their rolls
+/// onto the same tiers (see [`super::ruleset`]).
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum CheckOutcome {
     /// Natural 1 or catastrophic failure.
+    #[serde(alias = "critical_failure")]
     CriticalFailure,
     /// Total below DC-5.
+    #[serde(alias = "failure")]
     Failure,
     /// Total in [DC-5, DC).
+    #[serde(alias = "partial_success")]
     PartialSuccess,
     /// Total meets or exceeds DC.
+    #[serde(alias = "success")]
     Success,
     /// Natural 20 or total >= DC+10.
+    #[serde(alias = "critical_success")]
     CriticalSuccess,
 }
 
+impl CheckOutcome {
+    /// Every tier, from worst to best.
+    pub const ALL: [Self; 5] = [
+        Self::CriticalFailure,
+        Self::Failure,
+        Self::PartialSuccess,
+        Self::Success,
+        Self::CriticalSuccess,
+    ];
+}
+
 impl fmt::Display for CheckOutcome {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -33,14 +59,92 @@ impl fmt::Display for CheckOutcome {
     }
 }
 
+/// Most times a single check may roll its expression.
+pub const MAX_ROLLS: u32 = 10;
+
+/// How many times a check rolls its expression, and which roll counts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RollMode {
+    /// Roll once.
+    #[default]
+    Normal,
+    /// Roll twice and keep the better roll.
+    Advantage,
+    /// Roll twice and keep the worse roll.
+    Disadvantage,
+    /// Roll `n` times and keep the best roll.
+    KeepBest(u32),
+    /// Roll `n` times and keep the worst roll.
+    KeepWorst(u32),
+}
+
+impl RollMode {
+    /// Returns how many times the expression is rolled.
+    #[must_use]
+    pub fn rolls(self) -> u32 {
+        match self {
+            Self::Normal => 1,
+            Self::Advantage | Self::Disadvantage => 2,
+            Self::KeepBest(n) | Self::KeepWorst(n) => n,
+        }
+    }
+
+    /// Checks that the mode rolls between 1 and `MAX_ROLLS` times.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::Validation` if the roll count is out of range.
+    pub fn validate(self) -> Result<(), DomainError> {
+        if (1..=MAX_ROLLS).contains(&self.rolls()) {
+            Ok(())
+        } else {
+            Err(DomainError::Validation(format!(
+                "roll mode must roll between 1 and {MAX_ROLLS} times"
+            )))
+        }
+    }
+
+    /// Returns whether the mode keeps the highest natural roll, where the
+    /// best roll is the highest when `higher_is_better` and the lowest
+    /// otherwise.
+    #[must_use]
+    pub fn keeps_highest(self, higher_is_better: bool) -> bool {
+        let keep_best = match self {
+            Self::Normal | Self::Advantage | Self::KeepBest(_) => true,
+            Self::Disadvantage | Self::KeepWorst(_) => false,
+        };
+        keep_best == higher_is_better
+    }
+
+    /// Returns the index of the kept roll among `natural_rolls` (see
+    /// [`Self::keeps_highest`]). Ties keep the earliest roll.
+    #[must_use]
+    pub fn select(self, natural_rolls: &[u32], higher_is_better: bool) -> usize {
+        let keeps_highest = self.keeps_highest(higher_is_better);
+        let mut kept = 0;
+        for (index, natural) in natural_rolls.iter().enumerate().skip(1) {
+            let better = if keeps_highest {
+                *natural > natural_rolls[kept]
+            } else {
+                *natural < natural_rolls[kept]
+            };
+            if better {
+                kept = index;
+            }
+        }
+        kept
+    }
+}
+
 /// Determines the outcome of a d20 check.
 ///
+/// `natural_roll` is the face of the d20 that was kept; a natural 1 or 20
+/// rolled and then discarded (e.g., under advantage) has no effect.
+///
 /// - Natural 1: always `CriticalFailure`
 /// - Natural 20: always `CriticalSuccess`
-/// - total >= dc + 10: `CriticalSuccess`
-/// - total >= dc: `Success`
-/// - total >= dc - 5: `PartialSuccess`
-/// - otherwise: `Failure`
+/// - otherwise: see [`determine_total_outcome`]
 #[must_use]
 pub fn determine_outcome(natural_roll: u32, total: i32, dc: i32) -> CheckOutcome {
     if natural_roll == 1 {
@@ -49,6 +153,18 @@ pub fn determine_outcome(natural_roll: u32, total: i32, dc: i32) -> CheckOutcome
     if natural_roll == 20 {
         return CheckOutcome::CriticalSuccess;
     }
+    determine_total_outcome(total, dc)
+}
+
+/// Determines the outcome of a check from its total alone, for rolls with no
+/// natural d20 to honour.
+///
+/// - total >= dc + 10: `CriticalSuccess`
+/// - total >= dc: `Success`
+/// - total >= dc - 5: `PartialSuccess`
+/// - otherwise: `Failure`
+#[must_use]
+pub fn determine_total_outcome(total: i32, dc: i32) -> CheckOutcome {
     if total >= dc + 10 {
         CheckOutcome::CriticalSuccess
     } else if total >= dc {
@@ -60,6 +176,72 @@ pub fn determine_outcome(natural_roll: u32, total: i32, dc: i32) -> CheckOutcome
     }
 }
 
+/// Margin by which an opposed check becomes a critical win or loss.
+pub const OPPOSED_CRITICAL_MARGIN: i32 = 10;
+
+/// One side of an opposed check.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OpposedSide {
+    /// The participant who initiated the contest.
+    Actor,
+    /// The participant resisting it.
+    Opponent,
+}
+
+/// How an opposed check whose totals are equal is settled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TieBreak {
+    /// The opponent wins: a tie leaves the situation as it was.
+    #[default]
+    Defender,
+    /// The actor wins.
+    Initiator,
+    /// The side with the higher modifier wins; the opponent on equal
+    /// modifiers.
+    HigherModifier,
+    /// Neither side wins; the check is a `PartialSuccess`.
+    Standoff,
+}
+
+/// Determines the actor's outcome of an opposed check and its winner.
+///
+/// `margin` is how far the actor beat the opponent (negative when the actor
+/// lost).
+///
+/// - margin >= `OPPOSED_CRITICAL_MARGIN`: `CriticalSuccess`
+/// - margin > 0: `Success`
+/// - margin <= -`OPPOSED_CRITICAL_MARGIN`: `CriticalFailure`
+/// - margin < 0: `Failure`
+/// - margin == 0: settled by `tie_break`
+#[must_use]
+pub fn determine_opposed_outcome(
+    margin: i32,
+    tie_break: TieBreak,
+    actor_modifier: i32,
+    opponent_modifier: i32,
+) -> (CheckOutcome, Option<OpposedSide>) {
+    let actor_wins = (CheckOutcome::Success, Some(OpposedSide::Actor));
+    let opponent_wins = (CheckOutcome::Failure, Some(OpposedSide::Opponent));
+    if margin >= OPPOSED_CRITICAL_MARGIN {
+        (CheckOutcome::CriticalSuccess, Some(OpposedSide::Actor))
+    } else if margin > 0 {
+        actor_wins
+    } else if margin <= -OPPOSED_CRITICAL_MARGIN {
+        (CheckOutcome::CriticalFailure, Some(OpposedSide::Opponent))
+    } else if margin < 0 {
+        opponent_wins
+    } else {
+        match tie_break {
+            TieBreak::Initiator => actor_wins,
+            TieBreak::HigherModifier if actor_modifier > opponent_modifier => actor_wins,
+            TieBreak::Defender | TieBreak::HigherModifier => opponent_wins,
+            TieBreak::Standoff => (CheckOutcome::PartialSuccess, None),
+        }
+    }
+}
+
 /// Emitted when a player declares an intent to act.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct IntentDeclared {
@@ -77,36 +259,144 @@ pub struct IntentDeclared {
     pub difficulty_class: i32,
     /// The modifier applied to the roll.
     pub modifier: i32,
+    /// The dice expression rolled for the check.
+    pub roll_expression: DiceExpression,
+    /// Values of the stats the roll expression references.
+    pub stats: BTreeMap<String, i32>,
+    /// How many times the expression is rolled and which roll counts.
+    pub roll_mode: RollMode,
+    /// The ruleset the check is rolled and judged by.
+    pub ruleset: RulesetId,
+    /// How the modifier was derived from the acting character's sheet, or
+    /// `None` if the client supplied it.
+    pub modifier_breakdown: Option<ModifierBreakdown>,
 }
 
-/// Emitted when a d20 check is resolved.
+/// One roll of a check's expression.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CheckRoll {
+    /// The sum of the roll's kept dice.
+    pub natural_roll: u32,
+    /// Every die drawn for the roll, in the order it was drawn.
+    pub dice: Vec<DieRoll>,
+}
+
+/// Emitted when a check is resolved.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CheckResolved {
     /// The resolution identifier.
     pub resolution_id: Uuid,
     /// The check identifier.
     pub check_id: Uuid,
-    /// The raw d20 result (1-20).
+    /// The natural roll of the kept roll (the raw d20 result for a plain d20
+    /// roll).
     pub natural_roll: u32,
-    /// The modifier applied to the roll.
+    /// The intent's modifier plus the expression's flat modifiers and stats.
     pub modifier: i32,
-    /// The total (`natural_roll` as i32 + modifier).
+    /// The total the ruleset judges (`natural_roll` + modifier, or
+    /// `natural_roll` - modifier when rolling under).
     pub total: i32,
     /// The difficulty class.
     pub difficulty_class: i32,
     /// The five-tier outcome.
     pub outcome: CheckOutcome,
+    /// Every roll of the expression, in the order it was rolled.
+    pub rolls: Vec<CheckRoll>,
+    /// Index of the roll in `rolls` that counts.
+    pub kept_roll: usize,
 }
 
-/// A single campaign-independent effect.
+/// A participant in an opposed check.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OpposedParticipant {
+    /// Optional identifier of the character or NPC rolling.
+    #[serde(default)]
+    pub participant_id: Option<Uuid>,
+    /// The modifier applied to the participant's roll.
+    pub modifier: i32,
+    /// How many times the participant rolls and which roll counts.
+    #[serde(default)]
+    pub roll_mode: RollMode,
+}
+
+/// Emitted when a player declares an intent contested by an opponent.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OpposedIntentDeclared {
+    /// The resolution identifier.
+    pub resolution_id: Uuid,
+    /// The intent identifier.
+    pub intent_id: Uuid,
+    /// The type of action (e.g., "`grapple`", "`stealth`", "`haggle`").
+    pub action_type: String,
+    /// Optional skill being used.
+    pub skill: Option<String>,
+    /// The participant initiating the contest.
+    pub actor: OpposedParticipant,
+    /// The participant resisting it.
+    pub opponent: OpposedParticipant,
+    /// The dice expression both participants roll.
+    pub roll_expression: DiceExpression,
+    /// The ruleset the rolls are made under.
+    pub ruleset: RulesetId,
+    /// How equal totals are settled.
+    pub tie_break: TieBreak,
+}
+
+/// One participant's side of a resolved opposed check.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OpposedRoll {
+    /// The natural roll of the kept roll.
+    pub natural_roll: u32,
+    /// The participant's modifier plus the expression's flat modifiers.
+    pub modifier: i32,
+    /// The total compared against the other side's.
+    pub total: i32,
+    /// Every roll of the expression, in the order it was rolled.
+    pub rolls: Vec<CheckRoll>,
+    /// Index of the roll in `rolls` that counts.
+    pub kept_roll: usize,
+}
+
+/// Emitted when an opposed check is resolved.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OpposedCheckResolved {
+    /// The resolution identifier.
+    pub resolution_id: Uuid,
+    /// The check identifier.
+    pub check_id: Uuid,
+    /// The actor's roll.
+    pub actor: OpposedRoll,
+    /// The opponent's roll.
+    pub opponent: OpposedRoll,
+    /// How far the actor beat the opponent; negative when the actor lost.
+    pub margin: i32,
+    /// The outcome from the actor's point of view.
+    pub outcome: CheckOutcome,
+    /// The winning side, or `None` on a standoff.
+    pub winner: Option<OpposedSide>,
+}
+
+/// A single validated effect, serialized as `effect_type`, `target_id` and
+/// `payload`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ResolvedEffect {
-    /// The type of effect (e.g., "`damage`", "`heal`", "`status_apply`").
-    pub effect_type: String,
     /// Optional target of the effect.
     pub target_id: Option<Uuid>,
-    /// Campaign-specific payload.
-    pub payload: serde_json::Value,
+    /// The typed effect.
+    #[serde(flatten)]
+    pub effect: Effect,
+}
+
+/// Which tier of an outcome table fired for a check.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FiredTier {
+    /// The check's outcome.
+    pub outcome: CheckOutcome,
+    /// The tier whose effects were produced after following fallbacks, or
+    /// `None` if no tier applied.
+    pub branch: Option<CheckOutcome>,
+    /// Strength the effects were produced at, in percent.
+    pub strength_percent: u32,
 }
 
 /// Emitted when effects are produced from a resolution.
@@ -116,6 +406,9 @@ pub struct EffectsProduced {
     pub resolution_id: Uuid,
     /// The produced effects.
     pub effects: Vec<ResolvedEffect>,
+    /// The outcome table tier that selected the effects, or `None` if they
+    /// were produced whatever the outcome.
+    pub tier: Option<FiredTier>,
 }
 
 /// Emitted when a resolution is archived (soft-deleted).
@@ -130,8 +423,12 @@ pub struct ResolutionArchived {
 pub enum RulesEventKind {
     /// A player intent has been declared.
     IntentDeclared(IntentDeclared),
-    /// A d20 check has been resolved.
+    /// A check has been resolved.
     CheckResolved(CheckResolved),
+    /// A player intent contested by an opponent has been declared.
+    OpposedIntentDeclared(OpposedIntentDeclared),
+    /// An opposed check has been resolved.
+    OpposedCheckResolved(OpposedCheckResolved),
     /// Effects have been produced from a resolution.
     EffectsProduced(EffectsProduced),
     /// A resolution has been archived (soft-deleted).
@@ -152,6 +449,8 @@ impl DomainEvent for RulesEvent {
         match &self.kind {
             RulesEventKind::IntentDeclared(_) => "rules.intent_declared",
             RulesEventKind::CheckResolved(_) => "rules.check_resolved",
+            RulesEventKind::OpposedIntentDeclared(_) => "rules.opposed_intent_declared",
+            RulesEventKind::OpposedCheckResolved(_) => "rules.opposed_check_resolved",
             RulesEventKind::EffectsProduced(_) => "rules.effects_produced",
             RulesEventKind::ResolutionArchived(_) => "rules.resolution_archived",
         }
@@ -166,9 +465,182 @@ impl DomainEvent for RulesEvent {
     }
 }
 
+/// A participant's initiative roll, recorded when an encounter starts.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InitiativeRoll {
+    /// The participant (typically a character) identifier.
+    pub participant_id: Uuid,
+    /// Display name of the participant.
+    pub name: String,
+    /// The modifier added to the initiative roll.
+    pub modifier: i32,
+    /// The face the d20 showed.
+    pub natural_roll: u32,
+    /// The natural roll plus the modifier.
+    pub initiative: i32,
+}
+
+/// Emitted when an encounter starts and initiative has been rolled.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EncounterStarted {
+    /// The encounter identifier.
+    pub encounter_id: Uuid,
+    /// Every participant's initiative roll, in the order they were listed.
+    pub initiative: Vec<InitiativeRoll>,
+    /// Participant IDs in the order they act, highest initiative first.
+    pub turn_order: Vec<Uuid>,
+}
+
+/// Emitted when a participant's turn begins.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TurnStarted {
+    /// The encounter identifier.
+    pub encounter_id: Uuid,
+    /// The round the turn belongs to, starting at 1.
+    pub round: u32,
+    /// The participant whose turn it is.
+    pub participant_id: Uuid,
+}
+
+/// Emitted when the acting participant delays, moving to act after another
+/// participant for the rest of the encounter.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TurnDelayed {
+    /// The encounter identifier.
+    pub encounter_id: Uuid,
+    /// The round in which the turn was delayed.
+    pub round: u32,
+    /// The participant who delayed.
+    pub participant_id: Uuid,
+    /// The participant they now act after.
+    pub after_participant_id: Uuid,
+}
+
+/// Emitted when the acting participant readies an action instead of acting.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ActionReadied {
+    /// The encounter identifier.
+    pub encounter_id: Uuid,
+    /// The round in which the action was readied.
+    pub round: u32,
+    /// The participant who readied the action.
+    pub participant_id: Uuid,
+    /// The circumstance the action waits for.
+    pub trigger: String,
+}
+
+/// Emitted when a readied action's trigger occurs, letting its participant
+/// act out of turn.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReadiedActionTriggered {
+    /// The encounter identifier.
+    pub encounter_id: Uuid,
+    /// The round in which the action was triggered.
+    pub round: u32,
+    /// The participant whose readied action fired.
+    pub participant_id: Uuid,
+}
+
+/// Emitted when a resolution (an attack, save or other check) is linked to
+/// a participant's action in the encounter.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResolutionLinked {
+    /// The encounter identifier.
+    pub encounter_id: Uuid,
+    /// The round in which the resolution happened.
+    pub round: u32,
+    /// The participant who acted.
+    pub participant_id: Uuid,
+    /// The linked resolution.
+    pub resolution_id: Uuid,
+}
+
+/// Emitted when an encounter ends.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EncounterEnded {
+    /// The encounter identifier.
+    pub encounter_id: Uuid,
+    /// The round in which the encounter ended.
+    pub round: u32,
+    /// Optional reason (e.g., "`enemies_defeated`", "`fled`").
+    pub reason: Option<String>,
+}
+
+/// Event type identifier for [`EncounterStarted`].
+pub const ENCOUNTER_STARTED_EVENT_TYPE: &str = "rules.encounter_started";
+
+/// Event type identifier for [`TurnStarted`].
+pub const TURN_STARTED_EVENT_TYPE: &str = "rules.turn_started";
+
+/// Event type identifier for [`TurnDelayed`].
+pub const TURN_DELAYED_EVENT_TYPE: &str = "rules.turn_delayed";
+
+/// Event type identifier for [`ActionReadied`].
+pub const ACTION_READIED_EVENT_TYPE: &str = "rules.action_readied";
+
+/// Event type identifier for [`ReadiedActionTriggered`].
+pub const READIED_ACTION_TRIGGERED_EVENT_TYPE: &str = "rules.readied_action_triggered";
+
+/// Event type identifier for [`ResolutionLinked`].
+pub const RESOLUTION_LINKED_EVENT_TYPE: &str = "rules.resolution_linked";
+
+/// Event type identifier for [`EncounterEnded`].
+pub const ENCOUNTER_ENDED_EVENT_TYPE: &str = "rules.encounter_ended";
+
+/// Event payload variants for the `Encounter` aggregate.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum EncounterEventKind {
+    /// An encounter has started.
+    EncounterStarted(EncounterStarted),
+    /// A participant's turn has started.
+    TurnStarted(TurnStarted),
+    /// A participant has delayed their turn.
+    TurnDelayed(TurnDelayed),
+    /// A participant has readied an action.
+    ActionReadied(ActionReadied),
+    /// A readied action has been triggered.
+    ReadiedActionTriggered(ReadiedActionTriggered),
+    /// A resolution has been linked to a participant's action.
+    ResolutionLinked(ResolutionLinked),
+    /// An encounter has ended.
+    EncounterEnded(EncounterEnded),
+}
+
+/// Domain event envelope for the `Encounter` aggregate.
+#[derive(Debug, Clone)]
+pub struct EncounterEvent {
+    /// Event metadata.
+    pub metadata: EventMetadata,
+    /// Event-specific payload.
+    pub kind: EncounterEventKind,
+}
+
+impl DomainEvent for EncounterEvent {
+    fn event_type(&self) -> &'static str {
+        match &self.kind {
+            EncounterEventKind::EncounterStarted(_) => ENCOUNTER_STARTED_EVENT_TYPE,
+            EncounterEventKind::TurnStarted(_) => TURN_STARTED_EVENT_TYPE,
+            EncounterEventKind::TurnDelayed(_) => TURN_DELAYED_EVENT_TYPE,
+            EncounterEventKind::ActionReadied(_) => ACTION_READIED_EVENT_TYPE,
+            EncounterEventKind::ReadiedActionTriggered(_) => READIED_ACTION_TRIGGERED_EVENT_TYPE,
+            EncounterEventKind::ResolutionLinked(_) => RESOLUTION_LINKED_EVENT_TYPE,
+            EncounterEventKind::EncounterEnded(_) => ENCOUNTER_ENDED_EVENT_TYPE,
+        }
+    }
+
+    fn to_payload(&self) -> serde_json::Value {
+        serde_json::to_value(&self.kind).expect("EncounterEventKind serialization is infallible")
+    }
+
+    fn metadata(&self) -> &EventMetadata {
+        &self.metadata
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::effects::{ApplyStatus, Damage};
 
     // --- determine_outcome tests ---
 
@@ -242,6 +714,111 @@ mod tests {
         assert_eq!(determine_outcome(20, 10, 25), CheckOutcome::CriticalSuccess);
     }
 
+    #[test]
+    fn test_total_outcome_ignores_natural_faces() {
+        // A 2d6 total of 20 is not a natural 20.
+        assert_eq!(determine_total_outcome(20, 15), CheckOutcome::Success);
+        assert_eq!(determine_total_outcome(1, 15), CheckOutcome::Failure);
+    }
+
+    // --- determine_opposed_outcome tests ---
+
+    #[test]
+    fn test_opposed_margin_bands() {
+        let outcome = |margin| determine_opposed_outcome(margin, TieBreak::Defender, 0, 0);
+        assert_eq!(
+            outcome(10),
+            (CheckOutcome::CriticalSuccess, Some(OpposedSide::Actor))
+        );
+        assert_eq!(
+            outcome(1),
+            (CheckOutcome::Success, Some(OpposedSide::Actor))
+        );
+        assert_eq!(
+            outcome(-1),
+            (CheckOutcome::Failure, Some(OpposedSide::Opponent))
+        );
+        assert_eq!(
+            outcome(-10),
+            (CheckOutcome::CriticalFailure, Some(OpposedSide::Opponent))
+        );
+    }
+
+    #[test]
+    fn test_opposed_tie_breaks() {
+        assert_eq!(
+            determine_opposed_outcome(0, TieBreak::Defender, 5, 0).1,
+            Some(OpposedSide::Opponent)
+        );
+        assert_eq!(
+            determine_opposed_outcome(0, TieBreak::Initiator, 0, 5).1,
+            Some(OpposedSide::Actor)
+        );
+        assert_eq!(
+            determine_opposed_outcome(0, TieBreak::HigherModifier, 3, 2).1,
+            Some(OpposedSide::Actor)
+        );
+        assert_eq!(
+            determine_opposed_outcome(0, TieBreak::HigherModifier, 2, 2).1,
+            Some(OpposedSide::Opponent)
+        );
+        assert_eq!(
+            determine_opposed_outcome(0, TieBreak::Standoff, 0, 0),
+            (CheckOutcome::PartialSuccess, None)
+        );
+    }
+
+    // --- RollMode tests ---
+
+    #[test]
+    fn test_roll_mode_roll_counts() {
+        assert_eq!(RollMode::Normal.rolls(), 1);
+        assert_eq!(RollMode::Advantage.rolls(), 2);
+        assert_eq!(RollMode::Disadvantage.rolls(), 2);
+        assert_eq!(RollMode::KeepWorst(4).rolls(), 4);
+    }
+
+    #[test]
+    fn test_roll_mode_validate_rejects_out_of_range_counts() {
+        assert!(RollMode::KeepBest(1).validate().is_ok());
+        assert!(RollMode::KeepBest(MAX_ROLLS).validate().is_ok());
+        assert!(RollMode::KeepBest(0).validate().is_err());
+        assert!(RollMode::KeepWorst(MAX_ROLLS + 1).validate().is_err());
+    }
+
+    #[test]
+    fn test_roll_mode_select_keeps_best_or_worst() {
+        assert_eq!(RollMode::Normal.select(&[7], true), 0);
+        assert_eq!(RollMode::Advantage.select(&[1, 15], true), 1);
+        assert_eq!(RollMode::Disadvantage.select(&[20, 4], true), 1);
+        assert_eq!(RollMode::KeepBest(3).select(&[8, 19, 12], true), 1);
+        assert_eq!(RollMode::KeepWorst(3).select(&[8, 19, 2], true), 2);
+    }
+
+    #[test]
+    fn test_roll_mode_select_when_lower_is_better() {
+        assert_eq!(RollMode::Advantage.select(&[40, 15], false), 1);
+        assert_eq!(RollMode::Disadvantage.select(&[40, 15], false), 0);
+    }
+
+    #[test]
+    fn test_roll_mode_select_ties_keep_earliest() {
+        assert_eq!(RollMode::Advantage.select(&[11, 11], true), 0);
+        assert_eq!(RollMode::Disadvantage.select(&[11, 11], true), 0);
+    }
+
+    #[test]
+    fn test_roll_mode_serializes_as_snake_case() {
+        assert_eq!(
+            serde_json::to_value(RollMode::Advantage).unwrap(),
+            serde_json::json!("advantage")
+        );
+        assert_eq!(
+            serde_json::to_value(RollMode::KeepBest(3)).unwrap(),
+            serde_json::json!({ "keep_best": 3 })
+        );
+    }
+
     #[test]
     fn test_check_outcome_serialization_round_trip() {
         let outcome = CheckOutcome::PartialSuccess;
@@ -262,6 +839,11 @@ mod tests {
             target_id: None,
             difficulty_class: 15,
             modifier: 3,
+            roll_expression: "1d20+@wisdom".parse().unwrap(),
+            stats: BTreeMap::from([("wisdom".to_owned(), 2)]),
+            roll_mode: RollMode::KeepBest(3),
+            ruleset: RulesetId::Pbta,
+            modifier_breakdown: None,
         });
         let json = serde_json::to_value(&kind).unwrap();
         let deserialized: RulesEventKind = serde_json::from_value(json).unwrap();
@@ -270,6 +852,10 @@ mod tests {
                 assert_eq!(payload.action_type, "skill_check");
                 assert_eq!(payload.difficulty_class, 15);
                 assert_eq!(payload.modifier, 3);
+                assert_eq!(payload.roll_expression.to_string(), "1d20+@wisdom");
+                assert_eq!(payload.stats["wisdom"], 2);
+                assert_eq!(payload.roll_mode, RollMode::KeepBest(3));
+                assert_eq!(payload.ruleset, RulesetId::Pbta);
             }
             other => panic!("expected IntentDeclared, got {other:?}"),
         }
@@ -285,6 +871,16 @@ mod tests {
             total: 18,
             difficulty_class: 15,
             outcome: CheckOutcome::Success,
+            rolls: vec![CheckRoll {
+                natural_roll: 15,
+                dice: vec![DieRoll {
+                    sides: 20,
+                    value: 15,
+                    kept: true,
+                    exploded: false,
+                }],
+            }],
+            kept_roll: 0,
         });
         let json = serde_json::to_value(&kind).unwrap();
         let deserialized: RulesEventKind = serde_json::from_value(json).unwrap();
@@ -293,6 +889,8 @@ mod tests {
                 assert_eq!(payload.natural_roll, 15);
                 assert_eq!(payload.total, 18);
                 assert_eq!(payload.outcome, CheckOutcome::Success);
+                assert_eq!(payload.rolls.len(), 1);
+                assert_eq!(payload.rolls[0].dice.len(), 1);
             }
             other => panic!("expected CheckResolved, got {other:?}"),
         }
@@ -303,17 +901,20 @@ mod tests {
         let kind = RulesEventKind::EffectsProduced(EffectsProduced {
             resolution_id: Uuid::new_v4(),
             effects: vec![ResolvedEffect {
-                effect_type: "damage".to_owned(),
                 target_id: Some(Uuid::new_v4()),
-                payload: serde_json::json!({ "amount": 8 }),
+                effect: Effect::Damage(Damage {
+                    amount: 8,
+                    damage_type: None,
+                }),
             }],
+            tier: None,
         });
         let json = serde_json::to_value(&kind).unwrap();
         let deserialized: RulesEventKind = serde_json::from_value(json).unwrap();
         match deserialized {
             RulesEventKind::EffectsProduced(payload) => {
                 assert_eq!(payload.effects.len(), 1);
-                assert_eq!(payload.effects[0].effect_type, "damage");
+                assert_eq!(payload.effects[0].effect.effect_type(), "damage");
             }
             other => panic!("expected EffectsProduced, got {other:?}"),
         }
@@ -322,13 +923,17 @@ mod tests {
     #[test]
     fn test_resolved_effect_serialization_round_trip() {
         let effect = ResolvedEffect {
-            effect_type: "status_apply".to_owned(),
             target_id: None,
-            payload: serde_json::json!({ "status": "poisoned", "duration": 3 }),
+            effect: Effect::ApplyStatus(ApplyStatus {
+                status: "poisoned".to_owned(),
+                duration_rounds: Some(3),
+            }),
         };
         let json = serde_json::to_value(&effect).unwrap();
+        assert_eq!(json["effect_type"], "apply_status");
+        assert_eq!(json["payload"]["status"], "poisoned");
         let deserialized: ResolvedEffect = serde_json::from_value(json).unwrap();
-        assert_eq!(deserialized.effect_type, "status_apply");
+        assert_eq!(deserialized, effect);
         assert!(deserialized.target_id.is_none());
     }
 }
@@ -2,4 +2,12 @@
 
 pub mod aggregates;
 pub mod commands;
+pub mod dice;
+pub mod effects;
+pub mod encounter;
 pub mod events;
+pub mod modifiers;
+pub mod odds;
+pub mod outcome_table;
+pub mod ruleset;
+pub mod upcasters;
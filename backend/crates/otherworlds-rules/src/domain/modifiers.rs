@@ -0,0 +1,319 @@
+//! Check modifiers derived from a character sheet.
+//!
+//! Instead of trusting a modifier sent by the client, an intent can name the
+//! acting character. The caller gathers that character's sheet from the
+//! Character and Inventory contexts into an [`ActorSheet`], and
+//! [`derive_modifier`] turns it into a modifier under the intent's ruleset:
+//!
+//! - the governing attribute's modifier — the named attribute itself, or the
+//!   attribute the ruleset assigns to the named skill;
+//! - the ruleset's proficiency bonus, if the character is proficient in the
+//!   skill;
+//! - the bonus of every equipped item keyed by the skill or its attribute.
+//!
+//! Each part is kept in a [`ModifierBreakdown`] recorded on `IntentDeclared`.
+//!
+//! The `@name` terms of a roll expression read the same sheet: each is the
+//! ruleset's modifier for the named attribute ([`sheet_stats`]).
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use otherworlds_core::error::DomainError;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::dice::DiceExpression;
+use super::ruleset::Ruleset;
+
+/// The parts of a character's sheet a check modifier is derived from.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub struct ActorSheet {
+    /// The character identifier.
+    pub character_id: Uuid,
+    /// Attribute scores (e.g., "dexterity" → 16).
+    pub attributes: BTreeMap<String, i32>,
+    /// Skills and attributes the character is proficient in.
+    #[serde(default)]
+    pub proficiencies: BTreeSet<String>,
+    /// Bonuses of the character's equipped items.
+    #[serde(default)]
+    pub equipment: Vec<EquipmentBonus>,
+}
+
+/// The check bonuses of one equipped item.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EquipmentBonus {
+    /// The item identifier.
+    pub item_id: Uuid,
+    /// Bonuses keyed by skill or attribute name.
+    pub bonuses: BTreeMap<String, i32>,
+}
+
+/// Where one part of a derived modifier came from.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "source", rename_all = "snake_case")]
+pub enum ModifierSource {
+    /// The governing attribute's score, converted by the ruleset.
+    Attribute {
+        /// The attribute name.
+        name: String,
+        /// The attribute score on the sheet.
+        score: i32,
+    },
+    /// The ruleset's proficiency bonus.
+    Proficiency {
+        /// The skill or attribute the character is proficient in.
+        skill: String,
+    },
+    /// An equipped item's bonus.
+    Item {
+        /// The item identifier.
+        item_id: Uuid,
+    },
+}
+
+/// One part of a derived modifier.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ModifierComponent {
+    /// Where the part came from.
+    #[serde(flatten)]
+    pub source: ModifierSource,
+    /// The part's contribution to the modifier.
+    pub value: i32,
+}
+
+/// How a check's modifier was derived from the acting character's sheet.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ModifierBreakdown {
+    /// The acting character.
+    pub character_id: Uuid,
+    /// The skill or attribute the check was made with.
+    pub skill: String,
+    /// The attribute governing the check.
+    pub attribute: String,
+    /// Every part of the modifier, in the order it was added.
+    pub components: Vec<ModifierComponent>,
+    /// The sum of the components.
+    pub total: i32,
+}
+
+/// Derives the modifier for a check with `skill` from `sheet` under
+/// `ruleset`.
+///
+/// # Errors
+///
+/// Returns `DomainError::Validation` if `skill` is neither an attribute on
+/// the sheet nor a skill the ruleset assigns an attribute to, if the sheet
+/// lacks the governing attribute, or if the modifier overflows.
+pub fn derive_modifier(
+    sheet: &ActorSheet,
+    skill: &str,
+    ruleset: &dyn Ruleset,
+) -> Result<ModifierBreakdown, DomainError> {
+    let attribute = if sheet.attributes.contains_key(skill) {
+        skill
+    } else {
+        ruleset.governing_attribute(skill).ok_or_else(|| {
+            DomainError::Validation(format!(
+                "'{skill}' is not an attribute of the character or a {} skill",
+                ruleset.id()
+            ))
+        })?
+    };
+    let score = *sheet.attributes.get(attribute).ok_or_else(|| {
+        DomainError::Validation(format!(
+            "character has no '{attribute}' attribute for '{skill}'"
+        ))
+    })?;
+
+    let mut components = vec![ModifierComponent {
+        source: ModifierSource::Attribute {
+            name: attribute.to_owned(),
+            score,
+        },
+        value: ruleset.attribute_modifier(score),
+    }];
+    if sheet.proficiencies.contains(skill) {
+        components.push(ModifierComponent {
+            source: ModifierSource::Proficiency {
+                skill: skill.to_owned(),
+            },
+            value: ruleset.proficiency_bonus(),
+        });
+    }
+    for item in &sheet.equipment {
+        let skill_bonus = item.bonuses.get(skill).copied().unwrap_or(0);
+        let attribute_bonus = if attribute == skill {
+            0
+        } else {
+            item.bonuses.get(attribute).copied().unwrap_or(0)
+        };
+        let value = skill_bonus.saturating_add(attribute_bonus);
+        if value != 0 {
+            components.push(ModifierComponent {
+                source: ModifierSource::Item {
+                    item_id: item.item_id,
+                },
+                value,
+            });
+        }
+    }
+
+    let total = components
+        .iter()
+        .try_fold(0_i32, |sum, c| sum.checked_add(c.value))
+        .ok_or_else(|| DomainError::Validation("derived modifier overflows".into()))?;
+    Ok(ModifierBreakdown {
+        character_id: sheet.character_id,
+        skill: skill.to_owned(),
+        attribute: attribute.to_owned(),
+        components,
+        total,
+    })
+}
+
+/// Returns the value of every stat `expression` references: the ruleset's
+/// modifier for the attribute of that name on `sheet`.
+///
+/// # Errors
+///
+/// Returns `DomainError::Validation` if the sheet lacks a referenced
+/// attribute.
+pub fn sheet_stats(
+    sheet: &ActorSheet,
+    expression: &DiceExpression,
+    ruleset: &dyn Ruleset,
+) -> Result<BTreeMap<String, i32>, DomainError> {
+    expression
+        .stat_names()
+        .map(|name| {
+            let score = sheet.attributes.get(name).ok_or_else(|| {
+                DomainError::Validation(format!("character has no '{name}' attribute for @{name}"))
+            })?;
+            Ok((name.to_owned(), ruleset.attribute_modifier(*score)))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::ruleset::RulesetId;
+
+    fn sheet() -> ActorSheet {
+        ActorSheet {
+            character_id: Uuid::from_u128(1),
+            attributes: BTreeMap::from([("dexterity".to_owned(), 16), ("strength".to_owned(), 9)]),
+            proficiencies: BTreeSet::from(["stealth".to_owned()]),
+            equipment: vec![EquipmentBonus {
+                item_id: Uuid::from_u128(2),
+                bonuses: BTreeMap::from([("stealth".to_owned(), 1), ("dexterity".to_owned(), 1)]),
+            }],
+        }
+    }
+
+    #[test]
+    fn test_d20_skill_adds_attribute_proficiency_and_item_bonuses() {
+        // Act
+        let breakdown = derive_modifier(&sheet(), "stealth", RulesetId::D20.ruleset()).unwrap();
+
+        // Assert — +3 dexterity, +2 proficiency, +2 cloak (stealth and dexterity).
+        assert_eq!(breakdown.attribute, "dexterity");
+        assert_eq!(
+            breakdown.components,
+            vec![
+                ModifierComponent {
+                    source: ModifierSource::Attribute {
+                        name: "dexterity".to_owned(),
+                        score: 16
+                    },
+                    value: 3
+                },
+                ModifierComponent {
+                    source: ModifierSource::Proficiency {
+                        skill: "stealth".to_owned()
+                    },
+                    value: 2
+                },
+                ModifierComponent {
+                    source: ModifierSource::Item {
+                        item_id: Uuid::from_u128(2)
+                    },
+                    value: 2
+                },
+            ]
+        );
+        assert_eq!(breakdown.total, 7);
+    }
+
+    #[test]
+    fn test_d20_attribute_check_rounds_the_modifier_down() {
+        // Act
+        let breakdown = derive_modifier(&sheet(), "strength", RulesetId::D20.ruleset()).unwrap();
+
+        // Assert
+        assert_eq!(breakdown.attribute, "strength");
+        assert_eq!(breakdown.components.len(), 1);
+        assert_eq!(breakdown.total, -1);
+    }
+
+    #[test]
+    fn test_rulesets_without_skills_use_the_score_as_the_modifier() {
+        // Arrange
+        let mut sheet = sheet();
+        sheet.attributes.insert("cool".to_owned(), 2);
+
+        // Act
+        let breakdown = derive_modifier(&sheet, "cool", RulesetId::Pbta.ruleset()).unwrap();
+        let unknown = derive_modifier(&sheet, "stealth", RulesetId::Pbta.ruleset());
+
+        // Assert
+        assert_eq!(breakdown.total, 2);
+        assert!(matches!(unknown, Err(DomainError::Validation(msg)) if msg.contains("stealth")));
+    }
+
+    #[test]
+    fn test_missing_governing_attribute_is_rejected() {
+        // Act
+        let result = derive_modifier(&sheet(), "arcana", RulesetId::D20.ruleset());
+
+        // Assert
+        assert!(
+            matches!(result, Err(DomainError::Validation(msg)) if msg.contains("intelligence"))
+        );
+    }
+
+    #[test]
+    fn test_breakdown_serializes_with_tagged_sources() {
+        // Act
+        let breakdown = derive_modifier(&sheet(), "stealth", RulesetId::D20.ruleset()).unwrap();
+        let json = serde_json::to_value(&breakdown).unwrap();
+
+        // Assert
+        assert_eq!(json["components"][0]["source"], "attribute");
+        assert_eq!(json["components"][0]["name"], "dexterity");
+        assert_eq!(json["components"][2]["source"], "item");
+        assert_eq!(
+            serde_json::from_value::<ModifierBreakdown>(json).unwrap(),
+            breakdown
+        );
+    }
+
+    #[test]
+    fn test_sheet_stats_reads_attribute_modifiers() {
+        // Arrange
+        let expression: DiceExpression = "1d20+@dexterity-@strength".parse().unwrap();
+        let missing: DiceExpression = "1d20+@luck".parse().unwrap();
+
+        // Act
+        let stats = sheet_stats(&sheet(), &expression, RulesetId::D20.ruleset()).unwrap();
+        let unknown = sheet_stats(&sheet(), &missing, RulesetId::D20.ruleset());
+
+        // Assert — dexterity 16 is +3, strength 9 is -1.
+        assert_eq!(
+            stats,
+            BTreeMap::from([("dexterity".to_owned(), 3), ("strength".to_owned(), -1)])
+        );
+        assert!(matches!(unknown, Err(DomainError::Validation(msg)) if msg.contains("@luck")));
+    }
+}
@@ -0,0 +1,205 @@
+//! Odds — the exact chance of each outcome tier before a check is rolled.
+//!
+//! The calculator lists every equally likely roll of the ruleset's default
+//! expression and judges each one with the ruleset's `outcome` — for d20,
+//! [`super::events::determine_outcome`] — exactly as `resolve_check` does,
+//! so a preview can never disagree with a real resolution. Nothing is
+//! rolled: the result is a pure function of its inputs.
+//!
+//! Under advantage and the other roll modes the kept roll is the highest (or
+//! lowest) natural roll. The chance that the kept natural roll is `t` is
+//! `P(X ≤ t)ⁿ − P(X < t)ⁿ`, shared equally by every roll whose natural roll
+//! is `t`, because ties keep the earliest roll and the rolls are
+//! independent. Counts are kept as integers, so the odds are exact.
+
+use std::collections::BTreeMap;
+
+use otherworlds_core::error::DomainError;
+
+use super::events::{CheckOutcome, RollMode};
+use super::ruleset::Ruleset;
+
+/// The exact odds of each outcome tier of a check.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CheckOdds {
+    /// Equally likely ways each tier can come about, in [`CheckOutcome::ALL`]
+    /// order.
+    ways: [u128; 5],
+    /// Equally likely ways the check can be rolled.
+    out_of: u128,
+}
+
+impl CheckOdds {
+    /// Returns the number of equally likely ways `outcome` comes about.
+    #[must_use]
+    pub fn ways(&self, outcome: CheckOutcome) -> u128 {
+        self.ways[tier_index(outcome)]
+    }
+
+    /// Returns the number of equally likely ways the check can be rolled.
+    #[must_use]
+    pub fn out_of(&self) -> u128 {
+        self.out_of
+    }
+
+    /// Returns the probability of `outcome`, between 0 and 1.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn probability(&self, outcome: CheckOutcome) -> f64 {
+        self.ways(outcome) as f64 / self.out_of as f64
+    }
+}
+
+fn tier_index(outcome: CheckOutcome) -> usize {
+    CheckOutcome::ALL
+        .iter()
+        .position(|tier| *tier == outcome)
+        .unwrap_or_default()
+}
+
+/// Calculates the odds of each outcome of a check rolled with `ruleset`'s
+/// default expression against `difficulty_class`.
+///
+/// # Errors
+///
+/// Returns `DomainError::Validation` if `roll_mode` is out of range or a
+/// total overflows.
+pub fn check_odds(
+    ruleset: &dyn Ruleset,
+    difficulty_class: i32,
+    modifier: i32,
+    roll_mode: RollMode,
+) -> Result<CheckOdds, DomainError> {
+    roll_mode.validate()?;
+    let rolls = ruleset.default_roll().outcomes(&BTreeMap::new())?;
+    let rolled = roll_mode.rolls();
+
+    // How many single rolls show each natural roll.
+    let mut naturals: BTreeMap<u32, u128> = BTreeMap::new();
+    for roll in &rolls {
+        *naturals.entry(roll.dice_total).or_default() += 1;
+    }
+    let keeps_highest = roll_mode.keeps_highest(ruleset.higher_is_better());
+    let ordered: Vec<(u32, u128)> = if keeps_highest {
+        naturals.into_iter().collect()
+    } else {
+        naturals.into_iter().rev().collect()
+    };
+
+    // Ways a single roll showing each natural roll ends up kept.
+    let mut kept_ways: BTreeMap<u32, u128> = BTreeMap::new();
+    let mut worse: u128 = 0;
+    for (natural, count) in ordered {
+        let at_most = (worse + count).pow(rolled) - worse.pow(rolled);
+        kept_ways.insert(natural, at_most / count);
+        worse += count;
+    }
+
+    let mut ways = [0; 5];
+    for roll in &rolls {
+        let total = roll
+            .modifier
+            .checked_add(modifier)
+            .and_then(|modifier| ruleset.total(roll.dice_total, modifier))
+            .ok_or_else(|| DomainError::Validation("roll modifier overflows".to_owned()))?;
+        let outcome = ruleset.outcome(roll, total, difficulty_class);
+        ways[tier_index(outcome)] += kept_ways[&roll.dice_total];
+    }
+
+    Ok(CheckOdds {
+        ways,
+        out_of: worse.pow(rolled),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::ruleset::RulesetId;
+
+    /// Counts outcomes by rolling every sequence of `n` rolls and keeping one
+    /// with `RollMode::select`, as a resolution does.
+    fn brute_force(ruleset: &dyn Ruleset, dc: i32, modifier: i32, mode: RollMode) -> [u128; 5] {
+        let rolls = ruleset.default_roll().outcomes(&BTreeMap::new()).unwrap();
+        let mut ways = [0; 5];
+        let mut sequence = vec![0; mode.rolls() as usize];
+        loop {
+            let naturals: Vec<u32> = sequence.iter().map(|i| rolls[*i].dice_total).collect();
+            let kept = &rolls[sequence[mode.select(&naturals, ruleset.higher_is_better())]];
+            let total = ruleset.total(kept.dice_total, modifier).unwrap();
+            ways[tier_index(ruleset.outcome(kept, total, dc))] += 1;
+            let Some(position) = sequence.iter().rposition(|i| *i + 1 < rolls.len()) else {
+                return ways;
+            };
+            sequence[position] += 1;
+            sequence[position + 1..].fill(0);
+        }
+    }
+
+    #[test]
+    fn test_d20_odds_honour_natural_ones_and_twenties() {
+        // Act
+        let odds = check_odds(RulesetId::D20.ruleset(), 15, 3, RollMode::Normal).unwrap();
+
+        // Assert — naturals 2–6 fail, 7–11 partially succeed, 12–19 succeed.
+        assert_eq!(odds.out_of(), 20);
+        assert_eq!(odds.ways(CheckOutcome::CriticalFailure), 1);
+        assert_eq!(odds.ways(CheckOutcome::Failure), 5);
+        assert_eq!(odds.ways(CheckOutcome::PartialSuccess), 5);
+        assert_eq!(odds.ways(CheckOutcome::Success), 8);
+        assert_eq!(odds.ways(CheckOutcome::CriticalSuccess), 1);
+        assert!((odds.probability(CheckOutcome::Success) - 0.4).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_advantage_keeps_the_better_of_two_rolls() {
+        // Act
+        let odds = check_odds(RulesetId::D20.ruleset(), 15, 3, RollMode::Advantage).unwrap();
+
+        // Assert — a natural 20 on either die; a natural 1 on both.
+        assert_eq!(odds.out_of(), 400);
+        assert_eq!(odds.ways(CheckOutcome::CriticalSuccess), 39);
+        assert_eq!(odds.ways(CheckOutcome::CriticalFailure), 1);
+    }
+
+    #[test]
+    fn test_pbta_odds_follow_the_2d6_bands() {
+        // Act
+        let odds = check_odds(RulesetId::Pbta.ruleset(), 0, 0, RollMode::Normal).unwrap();
+
+        // Assert
+        assert_eq!(odds.out_of(), 36);
+        assert_eq!(odds.ways(CheckOutcome::Failure), 15);
+        assert_eq!(odds.ways(CheckOutcome::PartialSuccess), 15);
+        assert_eq!(odds.ways(CheckOutcome::Success), 6);
+    }
+
+    #[test]
+    fn test_odds_match_rolling_every_sequence() {
+        for (ruleset, dc, modifier, mode) in [
+            (RulesetId::D20, 12, 1, RollMode::KeepBest(3)),
+            (RulesetId::D20, 18, -2, RollMode::Disadvantage),
+            (RulesetId::Pbta, 0, 1, RollMode::Advantage),
+            (RulesetId::Pbta, 0, -1, RollMode::KeepWorst(3)),
+            (RulesetId::Percentile, 45, 10, RollMode::Advantage),
+            (RulesetId::Percentile, 60, 0, RollMode::Disadvantage),
+        ] {
+            // Act
+            let ruleset = ruleset.ruleset();
+            let odds = check_odds(ruleset, dc, modifier, mode).unwrap();
+
+            // Assert
+            let expected = brute_force(ruleset, dc, modifier, mode);
+            let ways = CheckOutcome::ALL.map(|tier| odds.ways(tier));
+            assert_eq!(ways, expected, "{} {mode:?}", ruleset.id());
+            assert_eq!(ways.iter().sum::<u128>(), odds.out_of());
+        }
+    }
+
+    #[test]
+    fn test_invalid_roll_mode_returns_validation_error() {
+        let result = check_odds(RulesetId::D20.ruleset(), 10, 0, RollMode::KeepBest(0));
+
+        assert!(matches!(result, Err(DomainError::Validation(_))));
+    }
+}
@@ -0,0 +1,415 @@
+//! Outcome tables — effects keyed by the tier a check lands in.
+//!
+//! Each tier of an [`OutcomeTable`] either lists its own effects or falls
+//! back to another tier at some strength, so "a partial success is a success
+//! at half strength" is `{ "fallback": "success", "strength_percent": 50 }`.
+//! A missing critical tier falls back to its ordinary tier at full strength;
+//! any other missing tier produces nothing.
+//!
+//! Strength scales the magnitude of damage, healing, experience and
+//! disposition shifts, rounding towards zero. An effect scaled to nothing is
+//! dropped; effects without a magnitude are produced as they are.
+
+use otherworlds_core::error::DomainError;
+use serde::{Deserialize, Serialize};
+
+use super::effects::{AwardXp, Damage, Effect, Heal, ShiftDisposition};
+use super::events::{CheckOutcome, FiredTier, ResolvedEffect};
+
+/// Greatest strength a fallback may scale effects to, in percent.
+pub const MAX_STRENGTH_PERCENT: u32 = 1000;
+
+/// Effects keyed by check outcome. `E` is an effect specification in a
+/// command and a validated [`ResolvedEffect`] in the domain.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(bound(deserialize = "E: Deserialize<'de>"))]
+pub struct OutcomeTable<E> {
+    /// Produced on a critical failure.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub critical_failure: Option<OutcomeBranch<E>>,
+    /// Produced on a failure.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub failure: Option<OutcomeBranch<E>>,
+    /// Produced on a partial success.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub partial_success: Option<OutcomeBranch<E>>,
+    /// Produced on a success.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub success: Option<OutcomeBranch<E>>,
+    /// Produced on a critical success.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub critical_success: Option<OutcomeBranch<E>>,
+}
+
+/// One tier of an [`OutcomeTable`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged, bound(deserialize = "E: Deserialize<'de>"))]
+pub enum OutcomeBranch<E> {
+    /// The tier's own effects.
+    Effects(Vec<E>),
+    /// Another tier's effects at a given strength.
+    Fallback(Fallback),
+}
+
+/// A tier that reuses another tier's effects.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Fallback {
+    /// The tier whose effects are used.
+    pub fallback: CheckOutcome,
+    /// Strength the effects are produced at, in percent.
+    #[serde(default = "full_strength")]
+    pub strength_percent: u32,
+}
+
+fn full_strength() -> u32 {
+    100
+}
+
+impl<E> Default for OutcomeTable<E> {
+    fn default() -> Self {
+        Self {
+            critical_failure: None,
+            failure: None,
+            partial_success: None,
+            success: None,
+            critical_success: None,
+        }
+    }
+}
+
+impl<E> OutcomeTable<E> {
+    /// Returns the branch for `tier`, if the table has one.
+    #[must_use]
+    pub fn branch(&self, tier: CheckOutcome) -> Option<&OutcomeBranch<E>> {
+        match tier {
+            CheckOutcome::CriticalFailure => self.critical_failure.as_ref(),
+            CheckOutcome::Failure => self.failure.as_ref(),
+            CheckOutcome::PartialSuccess => self.partial_success.as_ref(),
+            CheckOutcome::Success => self.success.as_ref(),
+            CheckOutcome::CriticalSuccess => self.critical_success.as_ref(),
+        }
+    }
+
+    /// Converts every effect in the table with `f`, which is given the
+    /// effect's tier and its index within the tier. Fallbacks are kept.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error `f` returns.
+    pub fn try_map<T>(
+        self,
+        mut f: impl FnMut(CheckOutcome, usize, E) -> Result<T, DomainError>,
+    ) -> Result<OutcomeTable<T>, DomainError> {
+        let mut branch = |tier: CheckOutcome,
+                          branch: Option<OutcomeBranch<E>>|
+         -> Result<_, DomainError> {
+            Ok(match branch {
+                Some(OutcomeBranch::Effects(effects)) => Some(OutcomeBranch::Effects(
+                    effects
+                        .into_iter()
+                        .enumerate()
+                        .map(|(index, effect)| f(tier, index, effect))
+                        .collect::<Result<_, _>>()?,
+                )),
+                Some(OutcomeBranch::Fallback(fallback)) => Some(OutcomeBranch::Fallback(fallback)),
+                None => None,
+            })
+        };
+        Ok(OutcomeTable {
+            critical_failure: branch(CheckOutcome::CriticalFailure, self.critical_failure)?,
+            failure: branch(CheckOutcome::Failure, self.failure)?,
+            partial_success: branch(CheckOutcome::PartialSuccess, self.partial_success)?,
+            success: branch(CheckOutcome::Success, self.success)?,
+            critical_success: branch(CheckOutcome::CriticalSuccess, self.critical_success)?,
+        })
+    }
+
+    /// Follows fallbacks from `outcome` to the tier whose effects apply,
+    /// returning that tier (`None` when nothing applies) and the strength to
+    /// produce its effects at.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::Validation` if a strength is out of range or
+    /// the fallbacks form a cycle.
+    pub fn follow(&self, outcome: CheckOutcome) -> Result<FiredTier, DomainError> {
+        let mut tier = outcome;
+        let mut strength_percent = full_strength();
+        for _ in 0..=CheckOutcome::ALL.len() {
+            match self.branch(tier) {
+                Some(OutcomeBranch::Effects(_)) => {
+                    return Ok(FiredTier {
+                        outcome,
+                        branch: Some(tier),
+                        strength_percent,
+                    });
+                }
+                Some(OutcomeBranch::Fallback(fallback)) => {
+                    if fallback.strength_percent == 0
+                        || fallback.strength_percent > MAX_STRENGTH_PERCENT
+                    {
+                        return Err(DomainError::Validation(format!(
+                            "{tier} fallback strength_percent must be between 1 and \
+                             {MAX_STRENGTH_PERCENT}"
+                        )));
+                    }
+                    strength_percent = strength_percent * fallback.strength_percent / 100;
+                    tier = fallback.fallback;
+                }
+                None => match implicit_fallback(tier) {
+                    Some(ordinary) => tier = ordinary,
+                    None => {
+                        return Ok(FiredTier {
+                            outcome,
+                            branch: None,
+                            strength_percent,
+                        });
+                    }
+                },
+            }
+        }
+        Err(DomainError::Validation(format!(
+            "{outcome} fallbacks form a cycle"
+        )))
+    }
+
+    /// Checks that every tier's fallbacks resolve.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::Validation` for the first tier that does not.
+    pub fn validate(&self) -> Result<(), DomainError> {
+        CheckOutcome::ALL
+            .into_iter()
+            .try_for_each(|tier| self.follow(tier).map(|_| ()))
+    }
+}
+
+impl OutcomeTable<ResolvedEffect> {
+    /// Selects the effects for `outcome`, following fallbacks and scaling
+    /// the effects to the strength they reach.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::Validation` if the fallbacks do not resolve.
+    pub fn select(
+        &self,
+        outcome: CheckOutcome,
+    ) -> Result<(FiredTier, Vec<ResolvedEffect>), DomainError> {
+        let fired = self.follow(outcome)?;
+        let effects = match fired.branch.and_then(|tier| self.branch(tier)) {
+            Some(OutcomeBranch::Effects(effects)) => effects
+                .iter()
+                .filter_map(|e| {
+                    scale(&e.effect, fired.strength_percent).map(|effect| ResolvedEffect {
+                        target_id: e.target_id,
+                        effect,
+                    })
+                })
+                .collect(),
+            _ => Vec::new(),
+        };
+        Ok((fired, effects))
+    }
+}
+
+/// The tier a missing critical tier falls back to.
+fn implicit_fallback(tier: CheckOutcome) -> Option<CheckOutcome> {
+    match tier {
+        CheckOutcome::CriticalFailure => Some(CheckOutcome::Failure),
+        CheckOutcome::CriticalSuccess => Some(CheckOutcome::Success),
+        _ => None,
+    }
+}
+
+/// Scales an effect's magnitude to `strength_percent`, or returns `None` if
+/// nothing is left of it.
+fn scale(effect: &Effect, strength_percent: u32) -> Option<Effect> {
+    let amount = |amount: u32| {
+        u32::try_from(u64::from(amount) * u64::from(strength_percent) / 100).unwrap_or(u32::MAX)
+    };
+    let scaled = match effect {
+        Effect::Damage(damage) => Effect::Damage(Damage {
+            amount: amount(damage.amount),
+            ..damage.clone()
+        }),
+        Effect::Heal(heal) => Effect::Heal(Heal {
+            amount: amount(heal.amount),
+        }),
+        Effect::AwardXp(xp) => Effect::AwardXp(AwardXp {
+            amount: amount(xp.amount),
+        }),
+        Effect::ShiftDisposition(shift) => Effect::ShiftDisposition(ShiftDisposition {
+            delta: i32::try_from(i64::from(shift.delta) * i64::from(strength_percent) / 100)
+                .unwrap_or(if shift.delta < 0 { i32::MIN } else { i32::MAX }),
+        }),
+        other => return Some(other.clone()),
+    };
+    match scaled {
+        Effect::Damage(Damage { amount: 0, .. })
+        | Effect::Heal(Heal { amount: 0 })
+        | Effect::AwardXp(AwardXp { amount: 0 })
+        | Effect::ShiftDisposition(ShiftDisposition { delta: 0 }) => None,
+        scaled => Some(scaled),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::domain::effects::SetFlag;
+
+    fn damage(amount: u32) -> ResolvedEffect {
+        ResolvedEffect {
+            target_id: Some(Uuid::nil()),
+            effect: Effect::Damage(Damage {
+                amount,
+                damage_type: None,
+            }),
+        }
+    }
+
+    fn flag() -> ResolvedEffect {
+        ResolvedEffect {
+            target_id: None,
+            effect: Effect::SetFlag(SetFlag {
+                flag: "alarm_raised".to_owned(),
+                value: true,
+            }),
+        }
+    }
+
+    fn fallback(tier: CheckOutcome, strength_percent: u32) -> OutcomeBranch<ResolvedEffect> {
+        OutcomeBranch::Fallback(Fallback {
+            fallback: tier,
+            strength_percent,
+        })
+    }
+
+    #[test]
+    fn test_select_uses_the_matching_tier() {
+        // Arrange
+        let table = OutcomeTable {
+            failure: Some(OutcomeBranch::Effects(vec![flag()])),
+            success: Some(OutcomeBranch::Effects(vec![damage(8)])),
+            ..OutcomeTable::default()
+        };
+
+        // Act
+        let (fired, effects) = table.select(CheckOutcome::Failure).unwrap();
+
+        // Assert
+        assert_eq!(fired.branch, Some(CheckOutcome::Failure));
+        assert_eq!(fired.strength_percent, 100);
+        assert_eq!(effects, vec![flag()]);
+    }
+
+    #[test]
+    fn test_select_scales_a_fallback_and_keeps_unscaled_effects() {
+        // Arrange — a partial success is a success at half strength.
+        let table = OutcomeTable {
+            partial_success: Some(fallback(CheckOutcome::Success, 50)),
+            success: Some(OutcomeBranch::Effects(vec![damage(7), flag()])),
+            ..OutcomeTable::default()
+        };
+
+        // Act
+        let (fired, effects) = table.select(CheckOutcome::PartialSuccess).unwrap();
+
+        // Assert
+        assert_eq!(fired.outcome, CheckOutcome::PartialSuccess);
+        assert_eq!(fired.branch, Some(CheckOutcome::Success));
+        assert_eq!(fired.strength_percent, 50);
+        assert_eq!(effects, vec![damage(3), flag()]);
+    }
+
+    #[test]
+    fn test_select_drops_effects_scaled_to_nothing() {
+        let table = OutcomeTable {
+            partial_success: Some(fallback(CheckOutcome::Success, 50)),
+            success: Some(OutcomeBranch::Effects(vec![damage(1)])),
+            ..OutcomeTable::default()
+        };
+
+        let (_, effects) = table.select(CheckOutcome::PartialSuccess).unwrap();
+
+        assert!(effects.is_empty());
+    }
+
+    #[test]
+    fn test_select_falls_back_from_missing_critical_tiers_only() {
+        let table = OutcomeTable {
+            success: Some(OutcomeBranch::Effects(vec![damage(8)])),
+            ..OutcomeTable::default()
+        };
+
+        let (critical, critical_effects) = table.select(CheckOutcome::CriticalSuccess).unwrap();
+        let (partial, partial_effects) = table.select(CheckOutcome::PartialSuccess).unwrap();
+
+        assert_eq!(critical.branch, Some(CheckOutcome::Success));
+        assert_eq!(critical_effects, vec![damage(8)]);
+        assert_eq!(partial.branch, None);
+        assert!(partial_effects.is_empty());
+    }
+
+    #[test]
+    fn test_select_compounds_chained_strengths() {
+        let table = OutcomeTable {
+            critical_success: Some(fallback(CheckOutcome::Success, 200)),
+            success: Some(fallback(CheckOutcome::Failure, 50)),
+            failure: Some(OutcomeBranch::Effects(vec![damage(10)])),
+            ..OutcomeTable::default()
+        };
+
+        let (fired, effects) = table.select(CheckOutcome::CriticalSuccess).unwrap();
+
+        assert_eq!(fired.branch, Some(CheckOutcome::Failure));
+        assert_eq!(fired.strength_percent, 100);
+        assert_eq!(effects, vec![damage(10)]);
+    }
+
+    #[test]
+    fn test_validate_rejects_cycles_and_out_of_range_strengths() {
+        let cycle: OutcomeTable<ResolvedEffect> = OutcomeTable {
+            success: Some(fallback(CheckOutcome::Failure, 100)),
+            failure: Some(fallback(CheckOutcome::Success, 100)),
+            ..OutcomeTable::default()
+        };
+        let zero: OutcomeTable<ResolvedEffect> = OutcomeTable {
+            partial_success: Some(fallback(CheckOutcome::Success, 0)),
+            ..OutcomeTable::default()
+        };
+
+        assert!(matches!(
+            cycle.validate(),
+            Err(DomainError::Validation(msg)) if msg.contains("cycle")
+        ));
+        assert!(matches!(
+            zero.validate(),
+            Err(DomainError::Validation(msg)) if msg.contains("strength_percent")
+        ));
+    }
+
+    #[test]
+    fn test_table_deserializes_effects_and_snake_case_fallbacks() {
+        let json = serde_json::json!({
+            "partial_success": { "fallback": "success", "strength_percent": 50 },
+            "success": [{ "effect_type": "heal", "target_id": null, "payload": { "amount": 4 } }]
+        });
+
+        let table: OutcomeTable<serde_json::Value> = serde_json::from_value(json).unwrap();
+
+        assert_eq!(
+            table.partial_success,
+            Some(OutcomeBranch::Fallback(Fallback {
+                fallback: CheckOutcome::Success,
+                strength_percent: 50,
+            }))
+        );
+        assert!(matches!(table.success, Some(OutcomeBranch::Effects(ref e)) if e.len() == 1));
+        assert!(table.failure.is_none());
+    }
+}
@@ -0,0 +1,417 @@
+//! Rulesets — how a check is rolled and how its roll maps onto an outcome.
+//!
+//! Every resolution records the ruleset it was declared under, so replaying
+//! it later always applies the same rules. Three rulesets are built in:
+//!
+//! - `d20`: roll 1d20 + modifier against the DC; natural 1s and 20s are
+//!   critical (see [`determine_outcome`]).
+//! - `pbta`: roll 2d6 + modifier; 10+ is a strong hit, 7–9 a weak hit and
+//!   6- a miss. Moves have fixed bands, so the DC is ignored.
+//! - `percentile`: roll 1d100 under the DC (the skill rating). Bonuses lower
+//!   the roll, a roll at or under a fifth of the rating is an extreme
+//!   success, and a natural 100 (96+ when the rating is below 50) fumbles.
+//!
+//! A ruleset also decides how a character sheet becomes a modifier (see
+//! [`super::modifiers`]): only `d20` has a skill list and converts scores
+//! (`(score - 10) / 2`, rounded down); the others roll attributes directly
+//! and read the score as the modifier.
+
+use std::fmt;
+use std::str::FromStr;
+
+use otherworlds_core::error::DomainError;
+use otherworlds_core::ruleset::{RULESET_NAMES, ruleset_index};
+use serde::{Deserialize, Serialize};
+
+use super::dice::{DiceExpression, DiceRoll};
+use super::events::{CheckOutcome, determine_outcome, determine_total_outcome};
+
+/// The rules a check is rolled and judged by.
+pub trait Ruleset: Send + Sync {
+    /// Returns the identifier recorded on resolutions using this ruleset.
+    fn id(&self) -> RulesetId;
+
+    /// Returns the expression rolled when an intent names none.
+    fn default_roll(&self) -> DiceExpression;
+
+    /// Returns whether a higher natural roll is the better one, which decides
+    /// the roll kept under advantage and disadvantage.
+    fn higher_is_better(&self) -> bool {
+        true
+    }
+
+    /// Combines the sum of the kept dice with the check's modifier, or
+    /// returns `None` on overflow.
+    fn total(&self, dice_total: u32, modifier: i32) -> Option<i32> {
+        i32::try_from(dice_total).ok()?.checked_add(modifier)
+    }
+
+    /// Maps the kept roll and its total onto the five-tier outcome.
+    fn outcome(&self, roll: &DiceRoll, total: i32, difficulty_class: i32) -> CheckOutcome;
+
+    /// Returns the name the ruleset gives an outcome tier.
+    fn tier_name(&self, outcome: CheckOutcome) -> &'static str;
+
+    /// Returns the attribute governing `skill`, or `None` if the ruleset
+    /// does not know the skill. Rulesets without a skill list roll
+    /// attributes directly.
+    fn governing_attribute(&self, _skill: &str) -> Option<&'static str> {
+        None
+    }
+
+    /// Converts an attribute score on a character sheet into a check
+    /// modifier. By default the sheet stores the modifier itself.
+    fn attribute_modifier(&self, score: i32) -> i32 {
+        score
+    }
+
+    /// Returns the bonus a character proficient in a skill adds to checks
+    /// with it.
+    fn proficiency_bonus(&self) -> i32;
+}
+
+/// Identifies one of the built-in rulesets, in the order of
+/// [`RULESET_NAMES`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RulesetId {
+    /// d20 roll-over against a difficulty class.
+    #[default]
+    D20,
+    /// Powered by the Apocalypse 2d6 moves.
+    Pbta,
+    /// Percentile roll-under against a skill rating.
+    Percentile,
+}
+
+impl RulesetId {
+    /// Every built-in ruleset, in declaration order.
+    pub const ALL: [Self; RULESET_NAMES.len()] = [Self::D20, Self::Pbta, Self::Percentile];
+
+    /// Returns the name used in campaign front matter and on the wire.
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        RULESET_NAMES[self as usize]
+    }
+
+    /// Returns the ruleset implementation.
+    #[must_use]
+    pub fn ruleset(self) -> &'static dyn Ruleset {
+        match self {
+            Self::D20 => &D20Ruleset,
+            Self::Pbta => &PbtaRuleset,
+            Self::Percentile => &PercentileRuleset,
+        }
+    }
+}
+
+impl fmt::Display for RulesetId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for RulesetId {
+    type Err = DomainError;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        ruleset_index(name).map(|index| Self::ALL[index])
+    }
+}
+
+/// d20 roll-over: 1d20 + modifier against the DC.
+#[derive(Debug, Clone, Copy)]
+pub struct D20Ruleset;
+
+impl Ruleset for D20Ruleset {
+    fn id(&self) -> RulesetId {
+        RulesetId::D20
+    }
+
+    fn default_roll(&self) -> DiceExpression {
+        DiceExpression::d20()
+    }
+
+    fn outcome(&self, roll: &DiceRoll, total: i32, difficulty_class: i32) -> CheckOutcome {
+        match roll.natural_d20() {
+            Some(natural) => determine_outcome(natural, total, difficulty_class),
+            None => determine_total_outcome(total, difficulty_class),
+        }
+    }
+
+    fn tier_name(&self, outcome: CheckOutcome) -> &'static str {
+        match outcome {
+            CheckOutcome::CriticalFailure => "critical failure",
+            CheckOutcome::Failure => "failure",
+            CheckOutcome::PartialSuccess => "partial success",
+            CheckOutcome::Success => "success",
+            CheckOutcome::CriticalSuccess => "critical success",
+        }
+    }
+
+    fn governing_attribute(&self, skill: &str) -> Option<&'static str> {
+        D20_SKILLS
+            .iter()
+            .find(|(name, _)| *name == skill)
+            .map(|(_, attribute)| *attribute)
+    }
+
+    fn attribute_modifier(&self, score: i32) -> i32 {
+        (score - 10).div_euclid(2)
+    }
+
+    fn proficiency_bonus(&self) -> i32 {
+        2
+    }
+}
+
+/// The d20 skills and the attribute each is rolled with.
+const D20_SKILLS: &[(&str, &str)] = &[
+    ("acrobatics", "dexterity"),
+    ("animal_handling", "wisdom"),
+    ("arcana", "intelligence"),
+    ("athletics", "strength"),
+    ("deception", "charisma"),
+    ("history", "intelligence"),
+    ("insight", "wisdom"),
+    ("intimidation", "charisma"),
+    ("investigation", "intelligence"),
+    ("medicine", "wisdom"),
+    ("nature", "intelligence"),
+    ("perception", "wisdom"),
+    ("performance", "charisma"),
+    ("persuasion", "charisma"),
+    ("religion", "intelligence"),
+    ("sleight_of_hand", "dexterity"),
+    ("stealth", "dexterity"),
+    ("survival", "wisdom"),
+];
+
+/// Powered by the Apocalypse: 2d6 + modifier read against fixed bands.
+#[derive(Debug, Clone, Copy)]
+pub struct PbtaRuleset;
+
+impl Ruleset for PbtaRuleset {
+    fn id(&self) -> RulesetId {
+        RulesetId::Pbta
+    }
+
+    fn default_roll(&self) -> DiceExpression {
+        "2d6".parse().expect("2d6 is a valid dice expression")
+    }
+
+    fn outcome(&self, _roll: &DiceRoll, total: i32, _difficulty_class: i32) -> CheckOutcome {
+        if total >= 10 {
+            CheckOutcome::Success
+        } else if total >= 7 {
+            CheckOutcome::PartialSuccess
+        } else {
+            CheckOutcome::Failure
+        }
+    }
+
+    fn tier_name(&self, outcome: CheckOutcome) -> &'static str {
+        match outcome {
+            CheckOutcome::CriticalFailure | CheckOutcome::Failure => "miss",
+            CheckOutcome::PartialSuccess => "weak hit",
+            CheckOutcome::Success | CheckOutcome::CriticalSuccess => "strong hit",
+        }
+    }
+
+    /// A move the character is trained in rolls +1.
+    fn proficiency_bonus(&self) -> i32 {
+        1
+    }
+}
+
+/// Percentile roll-under: 1d100, with the DC as the skill rating.
+#[derive(Debug, Clone, Copy)]
+pub struct PercentileRuleset;
+
+impl Ruleset for PercentileRuleset {
+    fn id(&self) -> RulesetId {
+        RulesetId::Percentile
+    }
+
+    fn default_roll(&self) -> DiceExpression {
+        "1d100".parse().expect("1d100 is a valid dice expression")
+    }
+
+    fn higher_is_better(&self) -> bool {
+        false
+    }
+
+    fn total(&self, dice_total: u32, modifier: i32) -> Option<i32> {
+        i32::try_from(dice_total).ok()?.checked_sub(modifier)
+    }
+
+    fn outcome(&self, roll: &DiceRoll, total: i32, difficulty_class: i32) -> CheckOutcome {
+        let natural = roll.dice_total;
+        if natural >= 100 || (natural >= 96 && difficulty_class < 50) {
+            CheckOutcome::CriticalFailure
+        } else if natural == 1 || total <= difficulty_class / 5 {
+            CheckOutcome::CriticalSuccess
+        } else if total <= difficulty_class {
+            CheckOutcome::Success
+        } else {
+            CheckOutcome::Failure
+        }
+    }
+
+    fn tier_name(&self, outcome: CheckOutcome) -> &'static str {
+        match outcome {
+            CheckOutcome::CriticalFailure => "fumble",
+            CheckOutcome::Failure => "failure",
+            CheckOutcome::PartialSuccess => "partial success",
+            CheckOutcome::Success => "success",
+            CheckOutcome::CriticalSuccess => "extreme success",
+        }
+    }
+
+    /// Training takes ten points off the roll.
+    fn proficiency_bonus(&self) -> i32 {
+        10
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::dice::DieRoll;
+
+    fn roll(sides: u32, faces: &[u32]) -> DiceRoll {
+        DiceRoll {
+            dice: faces
+                .iter()
+                .map(|&value| DieRoll {
+                    sides,
+                    value,
+                    kept: true,
+                    exploded: false,
+                })
+                .collect(),
+            dice_total: faces.iter().sum(),
+            modifier: 0,
+        }
+    }
+
+    #[test]
+    fn test_ruleset_ids_round_trip_through_their_names() {
+        for id in RulesetId::ALL {
+            assert_eq!(id.as_str().parse::<RulesetId>().unwrap(), id);
+            assert_eq!(id.ruleset().id(), id);
+            assert_eq!(
+                serde_json::to_value(id).unwrap(),
+                serde_json::json!(id.as_str())
+            );
+        }
+    }
+
+    #[test]
+    fn test_d20_maps_skills_to_attributes_and_scores_to_modifiers() {
+        let d20 = RulesetId::D20.ruleset();
+        assert_eq!(d20.governing_attribute("stealth"), Some("dexterity"));
+        assert_eq!(d20.governing_attribute("basket_weaving"), None);
+        assert_eq!(d20.attribute_modifier(10), 0);
+        assert_eq!(d20.attribute_modifier(15), 2);
+        assert_eq!(d20.attribute_modifier(7), -2);
+        assert_eq!(RulesetId::Pbta.ruleset().attribute_modifier(2), 2);
+        assert_eq!(
+            RulesetId::Pbta.ruleset().governing_attribute("stealth"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_unknown_ruleset_name_is_rejected() {
+        let result = "gurps".parse::<RulesetId>();
+        assert!(matches!(result, Err(DomainError::Validation(msg)) if msg.contains("gurps")));
+    }
+
+    #[test]
+    fn test_default_rolls() {
+        assert_eq!(RulesetId::D20.ruleset().default_roll().to_string(), "1d20");
+        assert_eq!(RulesetId::Pbta.ruleset().default_roll().to_string(), "2d6");
+        assert_eq!(
+            RulesetId::Percentile.ruleset().default_roll().to_string(),
+            "1d100"
+        );
+    }
+
+    #[test]
+    fn test_d20_honours_natural_faces() {
+        let d20 = D20Ruleset;
+        assert_eq!(
+            d20.outcome(&roll(20, &[20]), 25, 30),
+            CheckOutcome::CriticalSuccess
+        );
+        assert_eq!(
+            d20.outcome(&roll(20, &[1]), 30, 10),
+            CheckOutcome::CriticalFailure
+        );
+        assert_eq!(
+            d20.outcome(&roll(6, &[6, 6]), 12, 12),
+            CheckOutcome::Success
+        );
+    }
+
+    #[test]
+    fn test_pbta_bands_ignore_the_difficulty_class() {
+        let pbta = PbtaRuleset;
+        let dice = roll(6, &[3, 4]);
+        assert_eq!(pbta.outcome(&dice, 6, 0), CheckOutcome::Failure);
+        assert_eq!(pbta.outcome(&dice, 7, 0), CheckOutcome::PartialSuccess);
+        assert_eq!(pbta.outcome(&dice, 9, 30), CheckOutcome::PartialSuccess);
+        assert_eq!(pbta.outcome(&dice, 10, 30), CheckOutcome::Success);
+        assert_eq!(pbta.tier_name(CheckOutcome::PartialSuccess), "weak hit");
+    }
+
+    #[test]
+    fn test_percentile_rolls_under_the_rating() {
+        let percentile = PercentileRuleset;
+        assert_eq!(
+            percentile.outcome(&roll(100, &[12]), 12, 60),
+            CheckOutcome::CriticalSuccess
+        );
+        assert_eq!(
+            percentile.outcome(&roll(100, &[60]), 60, 60),
+            CheckOutcome::Success
+        );
+        assert_eq!(
+            percentile.outcome(&roll(100, &[61]), 61, 60),
+            CheckOutcome::Failure
+        );
+        assert_eq!(
+            percentile.outcome(&roll(100, &[1]), 1, 3),
+            CheckOutcome::CriticalSuccess
+        );
+        assert_eq!(
+            percentile.tier_name(CheckOutcome::CriticalSuccess),
+            "extreme success"
+        );
+    }
+
+    #[test]
+    fn test_percentile_fumbles_on_high_rolls() {
+        let percentile = PercentileRuleset;
+        assert_eq!(
+            percentile.outcome(&roll(100, &[100]), 100, 99),
+            CheckOutcome::CriticalFailure
+        );
+        assert_eq!(
+            percentile.outcome(&roll(100, &[97]), 97, 40),
+            CheckOutcome::CriticalFailure
+        );
+        assert_eq!(
+            percentile.outcome(&roll(100, &[97]), 97, 98),
+            CheckOutcome::Success
+        );
+    }
+
+    #[test]
+    fn test_percentile_bonuses_lower_the_total() {
+        assert_eq!(PercentileRuleset.total(55, 10), Some(45));
+        assert_eq!(D20Ruleset.total(15, 3), Some(18));
+        assert!(D20Ruleset.total(u32::MAX, 0).is_none());
+    }
+}
@@ -0,0 +1,307 @@
+//! Upcasters for the Rules & Resolution context.
+//!
+//! Register a step here whenever an event payload changes shape, so streams
+//! written with the previous shape still load.
+
+use std::sync::LazyLock;
+
+use otherworlds_core::error::DomainError;
+use otherworlds_core::upcasting::UpcasterRegistry;
+use serde_json::{Map, Value, json};
+
+use super::effects::CUSTOM_EFFECT_TYPE;
+use super::events::ResolvedEffect;
+
+static REGISTRY: LazyLock<UpcasterRegistry> = LazyLock::new(|| {
+    UpcasterRegistry::new()
+        .register("rules.intent_declared", 1, add_d20_roll_expression)
+        .register("rules.intent_declared", 2, add_normal_roll_mode)
+        .register("rules.intent_declared", 3, add_d20_ruleset)
+        .register("rules.intent_declared", 4, add_no_modifier_breakdown)
+        .register("rules.check_resolved", 1, add_d20_die)
+        .register("rules.check_resolved", 2, wrap_dice_in_single_roll)
+        .register("rules.effects_produced", 1, type_legacy_effects)
+        .register("rules.effects_produced", 2, add_no_fired_tier)
+});
+
+/// Returns the upcaster registry for rules events.
+#[must_use]
+pub fn registry() -> &'static UpcasterRegistry {
+    &REGISTRY
+}
+
+/// Returns the schema version newly produced events of `event_type` carry.
+#[must_use]
+pub fn current_schema_version(event_type: &str) -> i32 {
+    REGISTRY.current_version(event_type)
+}
+
+/// Returns the body of an externally tagged payload.
+fn body<'a>(
+    payload: &'a mut Value,
+    variant: &str,
+) -> Result<&'a mut Map<String, Value>, DomainError> {
+    payload
+        .get_mut(variant)
+        .and_then(Value::as_object_mut)
+        .ok_or_else(|| DomainError::Infrastructure(format!("{variant} payload has no body")))
+}
+
+/// v1 → v2: `IntentDeclared` records the roll expression and the stats it
+/// references. Every intent declared before expressions existed rolled 1d20.
+fn add_d20_roll_expression(mut payload: Value) -> Result<Value, DomainError> {
+    let intent = body(&mut payload, "IntentDeclared")?;
+    intent.insert("roll_expression".into(), json!("1d20"));
+    intent.insert("stats".into(), json!({}));
+    Ok(payload)
+}
+
+/// v1 → v2: `CheckResolved` records every die drawn. A v1 check rolled a
+/// single d20, whose face is its natural roll.
+fn add_d20_die(mut payload: Value) -> Result<Value, DomainError> {
+    let check = body(&mut payload, "CheckResolved")?;
+    let natural_roll = check
+        .get("natural_roll")
+        .and_then(Value::as_u64)
+        .ok_or_else(|| {
+            DomainError::Infrastructure("CheckResolved payload has no valid natural_roll".into())
+        })?;
+    check.insert(
+        "dice".into(),
+        json!([{ "sides": 20, "value": natural_roll, "kept": true, "exploded": false }]),
+    );
+    Ok(payload)
+}
+
+/// v2 → v3: `IntentDeclared` records its roll mode. Earlier intents rolled
+/// once.
+fn add_normal_roll_mode(mut payload: Value) -> Result<Value, DomainError> {
+    let intent = body(&mut payload, "IntentDeclared")?;
+    intent.insert("roll_mode".into(), json!("normal"));
+    Ok(payload)
+}
+
+/// v3 → v4: `IntentDeclared` records its ruleset. Earlier intents were all
+/// judged by the d20 rules.
+fn add_d20_ruleset(mut payload: Value) -> Result<Value, DomainError> {
+    let intent = body(&mut payload, "IntentDeclared")?;
+    intent.insert("ruleset".into(), json!("d20"));
+    Ok(payload)
+}
+
+/// v4 → v5: `IntentDeclared` records how its modifier was derived from a
+/// character sheet. Earlier intents carried client-supplied modifiers.
+fn add_no_modifier_breakdown(mut payload: Value) -> Result<Value, DomainError> {
+    let intent = body(&mut payload, "IntentDeclared")?;
+    intent.insert("modifier_breakdown".into(), Value::Null);
+    Ok(payload)
+}
+
+/// v2 → v3: `CheckResolved` records every roll of its expression and which
+/// one was kept. A v2 check rolled once, so its dice become the only roll.
+fn wrap_dice_in_single_roll(mut payload: Value) -> Result<Value, DomainError> {
+    let check = body(&mut payload, "CheckResolved")?;
+    let natural_roll = check.get("natural_roll").cloned().ok_or_else(|| {
+        DomainError::Infrastructure("CheckResolved payload has no natural_roll".into())
+    })?;
+    let dice = check
+        .remove("dice")
+        .ok_or_else(|| DomainError::Infrastructure("CheckResolved payload has no dice".into()))?;
+    check.insert(
+        "rolls".into(),
+        json!([{ "natural_roll": natural_roll, "dice": dice }]),
+    );
+    check.insert("kept_roll".into(), json!(0));
+    Ok(payload)
+}
+
+/// v1 → v2: `EffectsProduced` carries typed effects. A v1 effect whose type
+/// and payload already fit the catalogue is kept as it is; any other becomes
+/// a version 0 custom effect named after its old type, with its old payload
+/// as data.
+fn type_legacy_effects(mut payload: Value) -> Result<Value, DomainError> {
+    let produced = body(&mut payload, "EffectsProduced")?;
+    let effects = produced
+        .get_mut("effects")
+        .and_then(Value::as_array_mut)
+        .ok_or_else(|| {
+            DomainError::Infrastructure("EffectsProduced payload has no effects".into())
+        })?;
+    for effect in effects {
+        if serde_json::from_value::<ResolvedEffect>(effect.clone()).is_ok() {
+            continue;
+        }
+        let name = effect.get("effect_type").cloned().unwrap_or(Value::Null);
+        let data = effect.get("payload").cloned().unwrap_or(Value::Null);
+        let target_id = effect.get("target_id").cloned().unwrap_or(Value::Null);
+        *effect = json!({
+            "effect_type": CUSTOM_EFFECT_TYPE,
+            "target_id": target_id,
+            "payload": { "name": name, "version": 0, "data": data },
+        });
+    }
+    Ok(payload)
+}
+
+/// v2 → v3: `EffectsProduced` records the outcome table tier that selected
+/// its effects. Earlier effects were produced whatever the outcome.
+fn add_no_fired_tier(mut payload: Value) -> Result<Value, DomainError> {
+    let produced = body(&mut payload, "EffectsProduced")?;
+    produced.insert("tier".into(), Value::Null);
+    Ok(payload)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+    use otherworlds_core::repository::StoredEvent;
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::domain::dice::DiceExpression;
+    use crate::domain::effects::{CustomEffect, Damage, Effect};
+    use crate::domain::events::{RollMode, RulesEventKind};
+    use crate::domain::ruleset::RulesetId;
+
+    fn stored_v1(event_type: &str, payload: Value) -> StoredEvent {
+        StoredEvent {
+            event_id: Uuid::new_v4(),
+            aggregate_id: Uuid::new_v4(),
+            event_type: event_type.to_owned(),
+            payload,
+            sequence_number: 1,
+            correlation_id: Uuid::new_v4(),
+            causation_id: Uuid::new_v4(),
+            occurred_at: Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap(),
+            schema_version: 1,
+        }
+    }
+
+    #[test]
+    fn test_intent_declared_v1_gains_d20_roll_expression_mode_ruleset_and_no_breakdown() {
+        // Arrange
+        let stored = stored_v1(
+            "rules.intent_declared",
+            json!({ "IntentDeclared": {
+                "resolution_id": Uuid::new_v4(),
+                "intent_id": Uuid::new_v4(),
+                "action_type": "attack",
+                "skill": null,
+                "target_id": null,
+                "difficulty_class": 12,
+                "modifier": 1
+            }}),
+        );
+
+        // Act
+        let payload = registry().upcast(&stored).unwrap();
+
+        // Assert
+        match serde_json::from_value::<RulesEventKind>(payload).unwrap() {
+            RulesEventKind::IntentDeclared(intent) => {
+                assert_eq!(intent.roll_expression, DiceExpression::d20());
+                assert!(intent.stats.is_empty());
+                assert_eq!(intent.roll_mode, RollMode::Normal);
+                assert_eq!(intent.ruleset, RulesetId::D20);
+                assert!(intent.modifier_breakdown.is_none());
+            }
+            other => panic!("expected IntentDeclared, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_check_resolved_v1_gains_a_single_d20_roll() {
+        // Arrange
+        let stored = stored_v1(
+            "rules.check_resolved",
+            json!({ "CheckResolved": {
+                "resolution_id": Uuid::new_v4(),
+                "check_id": Uuid::new_v4(),
+                "natural_roll": 14,
+                "modifier": 2,
+                "total": 16,
+                "difficulty_class": 15,
+                "outcome": "Success"
+            }}),
+        );
+
+        // Act
+        let payload = registry().upcast(&stored).unwrap();
+
+        // Assert
+        match serde_json::from_value::<RulesEventKind>(payload).unwrap() {
+            RulesEventKind::CheckResolved(check) => {
+                assert_eq!(check.kept_roll, 0);
+                assert_eq!(check.rolls.len(), 1);
+                assert_eq!(check.rolls[0].natural_roll, 14);
+                let dice = &check.rolls[0].dice;
+                assert_eq!(dice.len(), 1);
+                assert_eq!(dice[0].sides, 20);
+                assert_eq!(dice[0].value, 14);
+                assert!(dice[0].kept);
+            }
+            other => panic!("expected CheckResolved, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_check_resolved_v1_without_natural_roll_is_rejected() {
+        // Act
+        let result = add_d20_die(json!({ "CheckResolved": {} }));
+
+        // Assert
+        assert!(matches!(result, Err(DomainError::Infrastructure(_))));
+    }
+
+    #[test]
+    fn test_check_resolved_v2_without_dice_is_rejected() {
+        // Act
+        let result = wrap_dice_in_single_roll(json!({ "CheckResolved": { "natural_roll": 3 } }));
+
+        // Assert
+        assert!(matches!(result, Err(DomainError::Infrastructure(_))));
+    }
+
+    #[test]
+    fn test_effects_produced_v1_keeps_fitting_effects_wraps_the_rest_and_has_no_tier() {
+        // Arrange
+        let target_id = Uuid::new_v4();
+        let stored = stored_v1(
+            "rules.effects_produced",
+            json!({ "EffectsProduced": {
+                "resolution_id": Uuid::new_v4(),
+                "effects": [
+                    { "effect_type": "damage", "target_id": target_id, "payload": { "amount": 8 } },
+                    { "effect_type": "reveal", "target_id": null, "payload": { "area": "vault" } }
+                ]
+            }}),
+        );
+
+        // Act
+        let payload = registry().upcast(&stored).unwrap();
+
+        // Assert
+        match serde_json::from_value::<RulesEventKind>(payload).unwrap() {
+            RulesEventKind::EffectsProduced(produced) => {
+                assert!(produced.tier.is_none());
+                assert_eq!(produced.effects[0].target_id, Some(target_id));
+                assert_eq!(
+                    produced.effects[0].effect,
+                    Effect::Damage(Damage {
+                        amount: 8,
+                        damage_type: None,
+                    })
+                );
+                assert_eq!(
+                    produced.effects[1].effect,
+                    Effect::Custom(CustomEffect {
+                        name: "reveal".to_owned(),
+                        version: 0,
+                        data: json!({ "area": "vault" }),
+                    })
+                );
+            }
+            other => panic!("expected EffectsProduced, got {other:?}"),
+        }
+    }
+}
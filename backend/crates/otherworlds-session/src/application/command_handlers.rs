@@ -10,15 +10,18 @@ use otherworlds_core::clock::Clock;
 use otherworlds_core::error::DomainError;
 use otherworlds_core::event::DomainEvent;
 use otherworlds_core::repository::{EventRepository, StoredEvent};
-use otherworlds_core::rng::DeterministicRng;
+use otherworlds_core::rng::{DeterministicRng, SeededRng};
+use otherworlds_core::snapshot::{load_with_snapshot, save_snapshot_if_due};
 use tracing::instrument;
 use uuid::Uuid;
 
 use crate::domain::aggregates::CampaignRun;
 use crate::domain::commands::{
-    ArchiveCampaignRun, BranchTimeline, CreateCheckpoint, RegisterAggregate, StartCampaignRun,
+    ArchiveCampaignRun, BranchTimeline, CreateCheckpoint, RecordCommand, RecordRngPosition,
+    RegisterAggregate, StartCampaignRun,
 };
 use crate::domain::events::{SessionEvent, SessionEventKind};
+use crate::domain::upcasters;
 
 /// Result of a successfully handled command.
 #[derive(Debug)]
@@ -40,6 +43,7 @@ fn to_stored_event(event: &SessionEvent) -> StoredEvent {
         correlation_id: meta.correlation_id,
         causation_id: meta.causation_id,
         occurred_at: meta.occurred_at,
+        schema_version: meta.schema_version,
     }
 }
 
@@ -52,12 +56,36 @@ pub fn reconstitute(
     run_id: Uuid,
     existing_events: &[StoredEvent],
 ) -> Result<CampaignRun, DomainError> {
-    let mut run = CampaignRun::new(run_id);
-    for stored in existing_events {
-        let kind: SessionEventKind =
-            serde_json::from_value(stored.payload.clone()).map_err(|e| {
-                DomainError::Infrastructure(format!("event deserialization failed: {e}"))
-            })?;
+    replay(CampaignRun::new(run_id), existing_events)
+}
+
+/// Loads a `CampaignRun`, starting from its latest snapshot when one is
+/// available and replaying only the events recorded after it.
+///
+/// Returns `None` if the aggregate has neither a snapshot nor any events.
+///
+/// # Errors
+///
+/// Returns `DomainError` if loading fails or event deserialization fails.
+pub async fn load(
+    run_id: Uuid,
+    repo: &dyn EventRepository,
+) -> Result<Option<CampaignRun>, DomainError> {
+    let (restored, events) = load_with_snapshot::<CampaignRun>(repo, run_id).await?;
+    match restored {
+        Some(run) => replay(run, &events).map(Some),
+        None if events.is_empty() => Ok(None),
+        None => reconstitute(run_id, &events).map(Some),
+    }
+}
+
+/// Applies stored events on top of an existing `CampaignRun` state.
+fn replay(mut run: CampaignRun, events: &[StoredEvent]) -> Result<CampaignRun, DomainError> {
+    for stored in events {
+        let payload = upcasters::registry().upcast(stored)?;
+        let kind: SessionEventKind = serde_json::from_value(payload).map_err(|e| {
+            DomainError::Infrastructure(format!("event deserialization failed: {e}"))
+        })?;
         let event = SessionEvent {
             metadata: otherworlds_core::event::EventMetadata {
                 event_id: stored.event_id,
@@ -67,6 +95,7 @@ pub fn reconstitute(
                 correlation_id: stored.correlation_id,
                 causation_id: stored.causation_id,
                 occurred_at: stored.occurred_at,
+                schema_version: upcasters::current_schema_version(&stored.event_type),
             },
             kind,
         };
@@ -102,6 +131,7 @@ pub async fn handle_start_campaign_run(
             .map_err(|e| DomainError::Infrastructure(format!("RNG mutex poisoned: {e}")))?;
         run.start_campaign_run(
             command.campaign_id,
+            command.seed,
             command.correlation_id,
             clock,
             &mut *rng_guard,
@@ -116,6 +146,7 @@ pub async fn handle_start_campaign_run(
 
     repo.append_events(run_id, run.version(), &stored_events)
         .await?;
+    save_snapshot_if_due(repo, run, clock).await;
 
     Ok(SessionCommandResult {
         aggregate_id: run_id,
@@ -123,6 +154,118 @@ pub async fn handle_start_campaign_run(
     })
 }
 
+/// Loads a campaign run and returns its RNG, resumed at the last recorded
+/// stream position.
+///
+/// # Errors
+///
+/// Returns `DomainError::AggregateNotFound` if the run does not exist.
+/// Returns `DomainError::Validation` if the run is archived.
+pub async fn open_run_rng(
+    run_id: Uuid,
+    repo: &dyn EventRepository,
+) -> Result<SeededRng, DomainError> {
+    let run = load(run_id, repo)
+        .await?
+        .ok_or(DomainError::AggregateNotFound(run_id))?;
+    if run.archived {
+        return Err(DomainError::Validation("campaign run is archived".into()));
+    }
+    Ok(run.rng())
+}
+
+/// Handles the `RecordCommand` command: reconstitutes the aggregate, appends
+/// the command to the run's command log, and persists the resulting event.
+///
+/// # Errors
+///
+/// Returns `DomainError::AggregateNotFound` if no events exist for the run ID.
+/// Returns `DomainError` if event loading or appending fails.
+#[instrument(skip(clock, rng, repo), fields(run_id = %command.run_id, command_type = %command.command_type, correlation_id = %command.correlation_id))]
+pub async fn handle_record_command(
+    command: &RecordCommand,
+    clock: &dyn Clock,
+    rng: &Mutex<dyn DeterministicRng + Send>,
+    repo: &dyn EventRepository,
+) -> Result<SessionCommandResult, DomainError> {
+    let mut run = load(command.run_id, repo)
+        .await?
+        .ok_or(DomainError::AggregateNotFound(command.run_id))?;
+
+    {
+        let mut rng_guard = rng
+            .lock()
+            .map_err(|e| DomainError::Infrastructure(format!("RNG mutex poisoned: {e}")))?;
+        run.record_command(
+            &command.command_type,
+            command.command.clone(),
+            command.commit,
+            command.correlation_id,
+            clock,
+            &mut *rng_guard,
+        );
+    }
+
+    let stored_events: Vec<StoredEvent> = run
+        .uncommitted_events()
+        .iter()
+        .map(to_stored_event)
+        .collect();
+
+    repo.append_events(command.run_id, run.version(), &stored_events)
+        .await?;
+    save_snapshot_if_due(repo, run, clock).await;
+
+    Ok(SessionCommandResult {
+        aggregate_id: command.run_id,
+        stored_events,
+    })
+}
+
+/// Handles the `RecordRngPosition` command: reconstitutes the aggregate,
+/// records the position `rng` has reached, and persists the resulting event.
+///
+/// `rng` must be the run's own RNG, as returned by `open_run_rng` and then
+/// drawn from by the command being recorded.
+///
+/// # Errors
+///
+/// Returns `DomainError::AggregateNotFound` if no events exist for the run ID.
+/// Returns `DomainError` if event loading or appending fails.
+#[instrument(skip(clock, rng, repo), fields(run_id = %command.run_id, correlation_id = %command.correlation_id))]
+pub async fn handle_record_rng_position(
+    command: &RecordRngPosition,
+    clock: &dyn Clock,
+    rng: &Mutex<SeededRng>,
+    repo: &dyn EventRepository,
+) -> Result<SessionCommandResult, DomainError> {
+    let mut run = load(command.run_id, repo)
+        .await?
+        .ok_or(DomainError::AggregateNotFound(command.run_id))?;
+
+    {
+        let mut rng_guard = rng
+            .lock()
+            .map_err(|e| DomainError::Infrastructure(format!("RNG mutex poisoned: {e}")))?;
+        run.record_rng_position(command.correlation_id, clock, &mut rng_guard);
+    }
+
+    let stored_events: Vec<StoredEvent> = run
+        .uncommitted_events()
+        .iter()
+        .map(to_stored_event)
+        .collect();
+
+    repo.append_events(command.run_id, run.version(), &stored_events)
+        .await?;
+    save_snapshot_if_due(repo, run, clock).await;
+
+    Ok(SessionCommandResult {
+        aggregate_id: command.run_id,
+        stored_events,
+    })
+}
+
 /// Handles the `CreateCheckpoint` command: reconstitutes the aggregate, creates
 /// a checkpoint, and persists the resulting events.
 ///
@@ -136,11 +279,9 @@ pub async fn handle_create_checkpoint(
     rng: &Mutex<dyn DeterministicRng + Send>,
     repo: &dyn EventRepository,
 ) -> Result<SessionCommandResult, DomainError> {
-    let existing_events = repo.load_events(command.run_id).await?;
-    if existing_events.is_empty() {
-        return Err(DomainError::AggregateNotFound(command.run_id));
-    }
-    let mut run = reconstitute(command.run_id, &existing_events)?;
+    let mut run = load(command.run_id, repo)
+        .await?
+        .ok_or(DomainError::AggregateNotFound(command.run_id))?;
     if run.archived {
         return Err(DomainError::Validation("campaign run is archived".into()));
     }
@@ -160,6 +301,7 @@ pub async fn handle_create_checkpoint(
 
     repo.append_events(command.run_id, run.version(), &stored_events)
         .await?;
+    save_snapshot_if_due(repo, run, clock).await;
 
     Ok(SessionCommandResult {
         aggregate_id: command.run_id,
@@ -190,6 +332,8 @@ pub async fn handle_branch_timeline(
     repo: &dyn EventRepository,
 ) -> Result<SessionCommandResult, DomainError> {
     // Load source run events to verify it exists.
+    // Branching needs the full source stream to locate the checkpoint, so
+    // this path always replays from the first event.
     let source_events = repo.load_events(command.source_run_id).await?;
     if source_events.is_empty() {
         return Err(DomainError::AggregateNotFound(command.source_run_id));
@@ -217,8 +361,10 @@ pub async fn handle_branch_timeline(
             if stored.event_type != "session.checkpoint_created" {
                 return false;
             }
-            serde_json::from_value::<SessionEventKind>(stored.payload.clone())
+            upcasters::registry()
+                .upcast(stored)
                 .ok()
+                .and_then(|payload| serde_json::from_value::<SessionEventKind>(payload).ok())
                 .is_some_and(|kind| {
                     matches!(kind, SessionEventKind::CheckpointCreated(cp) if cp.checkpoint_id == command.from_checkpoint_id)
                 })
@@ -229,8 +375,11 @@ pub async fn handle_branch_timeline(
     let events_to_replay: Vec<SessionEvent> = source_events[..=checkpoint_index]
         .iter()
         .map(|stored| {
+            let payload = upcasters::registry()
+                .upcast(stored)
+                .expect("already validated");
             let kind: SessionEventKind =
-                serde_json::from_value(stored.payload.clone()).expect("already validated");
+                serde_json::from_value(payload).expect("already validated");
             SessionEvent {
                 metadata: otherworlds_core::event::EventMetadata {
                     event_id: stored.event_id,
@@ -240,6 +389,7 @@ pub async fn handle_branch_timeline(
                     correlation_id: stored.correlation_id,
                     causation_id: stored.causation_id,
                     occurred_at: stored.occurred_at,
+                    schema_version: upcasters::current_schema_version(&stored.event_type),
                 },
                 kind,
             }
@@ -285,6 +435,7 @@ pub async fn handle_branch_timeline(
 
     repo.append_events(branch_run_id, branch.version(), &stored_events)
         .await?;
+    save_snapshot_if_due(repo, branch, clock).await;
 
     Ok(SessionCommandResult {
         aggregate_id: branch_run_id,
@@ -306,11 +457,9 @@ pub async fn handle_register_aggregate(
     rng: &Mutex<dyn DeterministicRng + Send>,
     repo: &dyn EventRepository,
 ) -> Result<SessionCommandResult, DomainError> {
-    let existing_events = repo.load_events(command.run_id).await?;
-    if existing_events.is_empty() {
-        return Err(DomainError::AggregateNotFound(command.run_id));
-    }
-    let mut run = reconstitute(command.run_id, &existing_events)?;
+    let mut run = load(command.run_id, repo)
+        .await?
+        .ok_or(DomainError::AggregateNotFound(command.run_id))?;
 
     {
         let mut rng_guard = rng
@@ -333,6 +482,7 @@ pub async fn handle_register_aggregate(
 
     repo.append_events(command.run_id, run.version(), &stored_events)
         .await?;
+    save_snapshot_if_due(repo, run, clock).await;
 
     Ok(SessionCommandResult {
         aggregate_id: command.run_id,
@@ -355,11 +505,9 @@ pub async fn handle_archive_campaign_run(
     rng: &Mutex<dyn DeterministicRng + Send>,
     repo: &dyn EventRepository,
 ) -> Result<SessionCommandResult, DomainError> {
-    let existing_events = repo.load_events(command.run_id).await?;
-    if existing_events.is_empty() {
-        return Err(DomainError::AggregateNotFound(command.run_id));
-    }
-    let mut run = reconstitute(command.run_id, &existing_events)?;
+    let mut run = load(command.run_id, repo)
+        .await?
+        .ok_or(DomainError::AggregateNotFound(command.run_id))?;
 
     {
         let mut rng_guard = rng
@@ -376,6 +524,7 @@ pub async fn handle_archive_campaign_run(
 
     repo.append_events(command.run_id, run.version(), &stored_events)
         .await?;
+    save_snapshot_if_due(repo, run, clock).await;
 
     Ok(SessionCommandResult {
         aggregate_id: command.run_id,
@@ -388,19 +537,22 @@ mod tests {
     use chrono::{DateTime, TimeZone, Utc};
     use otherworlds_core::error::DomainError;
     use otherworlds_core::repository::StoredEvent;
-    use otherworlds_core::rng::DeterministicRng;
+    use otherworlds_core::rng::{DeterministicRng, SeededRng};
     use std::sync::{Arc, Mutex};
     use uuid::Uuid;
 
     use crate::application::command_handlers::{
         handle_archive_campaign_run, handle_branch_timeline, handle_create_checkpoint,
-        handle_register_aggregate, handle_start_campaign_run,
+        handle_record_command, handle_record_rng_position, handle_register_aggregate,
+        handle_start_campaign_run, open_run_rng,
     };
     use crate::domain::commands::{
-        ArchiveCampaignRun, BranchTimeline, CreateCheckpoint, RegisterAggregate, StartCampaignRun,
+        ArchiveCampaignRun, BranchTimeline, CreateCheckpoint, RecordCommand, RecordRngPosition,
+        RegisterAggregate, StartCampaignRun,
     };
     use crate::domain::events::{
-        CampaignRunArchived, CampaignRunStarted, CheckpointCreated, SessionEventKind,
+        CampaignRunArchived, CampaignRunStarted, CheckpointCreated, CommitSpan, RngAdvanced,
+        SessionEventKind,
     };
     use otherworlds_test_support::{FixedClock, MockRng, RecordingEventRepository};
 
@@ -417,6 +569,7 @@ mod tests {
         let command = StartCampaignRun {
             correlation_id,
             campaign_id,
+            seed: 42,
         };
 
         // Act
@@ -519,6 +672,7 @@ mod tests {
                 CampaignRunStarted {
                     run_id: aggregate_id,
                     campaign_id: Uuid::new_v4(),
+                    seed: 42,
                 },
             ))
             .unwrap(),
@@ -526,6 +680,7 @@ mod tests {
             correlation_id: Uuid::new_v4(),
             causation_id: Uuid::new_v4(),
             occurred_at: fixed_now,
+            schema_version: 2,
         }
     }
 
@@ -555,6 +710,7 @@ mod tests {
                 correlation_id: Uuid::new_v4(),
                 causation_id: Uuid::new_v4(),
                 occurred_at: fixed_now,
+                schema_version: 1,
             },
         ];
         let repo = RecordingEventRepository::new(Ok(source_events));
@@ -706,6 +862,7 @@ mod tests {
             correlation_id: Uuid::new_v4(),
             causation_id: Uuid::new_v4(),
             occurred_at: fixed_now,
+            schema_version: 1,
         };
         let repo = RecordingEventRepository::new(Ok(vec![existing_event, archived_event]));
 
@@ -748,6 +905,7 @@ mod tests {
             correlation_id: Uuid::new_v4(),
             causation_id: Uuid::new_v4(),
             occurred_at: fixed_now,
+            schema_version: 1,
         };
         let repo = RecordingEventRepository::new(Ok(vec![existing_event, archived_event]));
 
@@ -793,6 +951,7 @@ mod tests {
             correlation_id: Uuid::new_v4(),
             causation_id: Uuid::new_v4(),
             occurred_at: fixed_now,
+            schema_version: 1,
         };
         let repo = RecordingEventRepository::new(Ok(vec![existing_event, archived_event]));
 
@@ -832,6 +991,7 @@ mod tests {
                     CampaignRunStarted {
                         run_id: source_run_id,
                         campaign_id,
+                        seed: 42,
                     },
                 ))
                 .unwrap(),
@@ -839,6 +999,7 @@ mod tests {
                 correlation_id: Uuid::new_v4(),
                 causation_id: Uuid::new_v4(),
                 occurred_at: fixed_now,
+                schema_version: 2,
             },
             StoredEvent {
                 event_id: Uuid::new_v4(),
@@ -855,6 +1016,7 @@ mod tests {
                 correlation_id: Uuid::new_v4(),
                 causation_id: Uuid::new_v4(),
                 occurred_at: fixed_now,
+                schema_version: 1,
             },
             StoredEvent {
                 event_id: Uuid::new_v4(),
@@ -871,6 +1033,7 @@ mod tests {
                 correlation_id: Uuid::new_v4(),
                 causation_id: Uuid::new_v4(),
                 occurred_at: fixed_now,
+                schema_version: 1,
             },
         ]
     }
@@ -1057,4 +1220,122 @@ mod tests {
             other => panic!("expected AggregateNotFound, got {other:?}"),
         }
     }
+
+    #[tokio::test]
+    async fn test_open_run_rng_resumes_at_recorded_position() {
+        // Arrange
+        let run_id = Uuid::new_v4();
+        let fixed_now = Utc.with_ymd_and_hms(2026, 1, 15, 10, 0, 0).unwrap();
+        let mut events = vec![dummy_stored_event(run_id, fixed_now)];
+        events.push(StoredEvent {
+            event_id: Uuid::new_v4(),
+            aggregate_id: run_id,
+            event_type: "session.rng_advanced".to_owned(),
+            payload: serde_json::to_value(SessionEventKind::RngAdvanced(RngAdvanced {
+                run_id,
+                position: 12,
+            }))
+            .unwrap(),
+            sequence_number: 2,
+            correlation_id: Uuid::new_v4(),
+            causation_id: Uuid::new_v4(),
+            occurred_at: fixed_now,
+            schema_version: 1,
+        });
+        let repo = RecordingEventRepository::new(Ok(events));
+
+        // Act
+        let rng = open_run_rng(run_id, &repo).await.unwrap();
+
+        // Assert
+        assert_eq!(rng.seed(), 42);
+        assert_eq!(rng.position(), 12);
+    }
+
+    #[tokio::test]
+    async fn test_open_run_rng_returns_error_when_run_not_found() {
+        // Arrange
+        let run_id = Uuid::new_v4();
+        let repo = RecordingEventRepository::new(Ok(Vec::new()));
+
+        // Act
+        let result = open_run_rng(run_id, &repo).await;
+
+        // Assert
+        match result.unwrap_err() {
+            DomainError::AggregateNotFound(id) => assert_eq!(id, run_id),
+            other => panic!("expected AggregateNotFound, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_handle_record_rng_position_persists_rng_advanced_event() {
+        // Arrange
+        let run_id = Uuid::new_v4();
+        let correlation_id = Uuid::new_v4();
+        let fixed_now = Utc.with_ymd_and_hms(2026, 1, 15, 10, 0, 0).unwrap();
+        let clock = FixedClock(fixed_now);
+        let repo = RecordingEventRepository::new(Ok(vec![dummy_stored_event(run_id, fixed_now)]));
+        let rng = Mutex::new(SeededRng::new(42));
+        rng.lock().unwrap().next_u32_range(1, 20);
+
+        let command = RecordRngPosition {
+            correlation_id,
+            run_id,
+        };
+
+        // Act
+        let result = handle_record_rng_position(&command, &clock, &rng, &repo).await;
+
+        // Assert
+        result.unwrap();
+        let appended = repo.appended_events();
+        let (agg_id, expected_version, events) = &appended[0];
+        assert_eq!(*agg_id, run_id);
+        assert_eq!(*expected_version, 1);
+        assert_eq!(events[0].event_type, "session.rng_advanced");
+        assert_eq!(
+            events[0].payload["RngAdvanced"]["position"],
+            rng.lock().unwrap().position()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_handle_record_command_persists_command_recorded_event() {
+        // Arrange
+        let run_id = Uuid::new_v4();
+        let correlation_id = Uuid::new_v4();
+        let fixed_now = Utc.with_ymd_and_hms(2026, 1, 15, 10, 0, 0).unwrap();
+        let clock = FixedClock(fixed_now);
+        let repo = RecordingEventRepository::new(Ok(vec![dummy_stored_event(run_id, fixed_now)]));
+        let rng = Mutex::new(SeededRng::new(42));
+
+        let command = RecordCommand {
+            correlation_id,
+            run_id,
+            command_type: "character.create_character".to_owned(),
+            command: serde_json::json!({ "CreateCharacter": { "name": "Alaric" } }),
+            commit: CommitSpan { offset: 0, len: 2 },
+        };
+
+        // Act
+        let result = handle_record_command(&command, &clock, &rng, &repo).await;
+
+        // Assert
+        result.unwrap();
+        let appended = repo.appended_events();
+        let (agg_id, expected_version, events) = &appended[0];
+        assert_eq!(*agg_id, run_id);
+        assert_eq!(*expected_version, 1);
+        assert_eq!(events[0].event_type, "session.command_recorded");
+        assert_eq!(events[0].correlation_id, correlation_id);
+        assert_eq!(
+            events[0].payload["CommandRecorded"]["command_type"],
+            "character.create_character"
+        );
+        assert_eq!(
+            events[0].payload["CommandRecorded"]["command"]["CreateCharacter"]["name"],
+            "Alaric"
+        );
+    }
 }
@@ -0,0 +1,135 @@
+//! Read-model projection for the Session & Progress context.
+//!
+//! Maintains one `CampaignRunView` per campaign run and one `CampaignRunSummary`
+//! per non-archived campaign run, rebuilt from the aggregate whenever its
+//! stream changes. Also indexes every aggregate a run currently has
+//! registered by that run, so a command on the aggregate can find it.
+
+use async_trait::async_trait;
+use otherworlds_core::error::DomainError;
+use otherworlds_core::projection::{Projection, ReadModelStore, get_document, put_document};
+use otherworlds_core::repository::EventRepository;
+use uuid::Uuid;
+
+use crate::application::command_handlers;
+use crate::application::query_handlers::{CampaignRunSummary, CampaignRunView, RunAggregateView};
+use crate::domain::aggregates::CampaignRun;
+
+/// Read-model collection holding one `CampaignRunView` per campaign run.
+pub(crate) const CAMPAIGN_RUN_VIEWS: &str = "campaign_run_views";
+
+/// Read-model collection holding one `CampaignRunSummary` per listed campaign run.
+pub(crate) const CAMPAIGN_RUN_SUMMARIES: &str = "campaign_run_summaries";
+
+/// Read-model collection holding one `RunAggregateView` per aggregate a
+/// campaign run has registered.
+pub(crate) const RUN_AGGREGATES: &str = "run_aggregates";
+
+/// Event types used by the Session & Progress context.
+const EVENT_TYPES: &[&str] = &[
+    "session.campaign_run_started",
+    "session.checkpoint_created",
+    "session.timeline_branched",
+    "session.aggregate_registered",
+    "session.rng_advanced",
+    "session.command_recorded",
+    "session.campaign_run_archived",
+];
+
+/// Projects campaign run streams into the campaign run view and summary tables.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CampaignRunProjection;
+
+#[async_trait]
+impl Projection for CampaignRunProjection {
+    fn name(&self) -> &'static str {
+        "session.campaign_runs"
+    }
+
+    // v2: views record the run's seed.
+    // v3: registered aggregates are indexed by run.
+    fn version(&self) -> i32 {
+        3
+    }
+
+    fn event_types(&self) -> &'static [&'static str] {
+        EVENT_TYPES
+    }
+
+    async fn project(
+        &self,
+        aggregate_id: Uuid,
+        repo: &dyn EventRepository,
+        store: &dyn ReadModelStore,
+    ) -> Result<(), DomainError> {
+        let Some(run) = command_handlers::load(aggregate_id, repo).await? else {
+            store.delete(CAMPAIGN_RUN_VIEWS, aggregate_id).await?;
+            return store.delete(CAMPAIGN_RUN_SUMMARIES, aggregate_id).await;
+        };
+
+        index_registered_aggregates(&run, store).await?;
+        put_document(store, CAMPAIGN_RUN_VIEWS, aggregate_id, &view(&run)).await?;
+        if run.archived {
+            store.delete(CAMPAIGN_RUN_SUMMARIES, aggregate_id).await
+        } else {
+            put_document(store, CAMPAIGN_RUN_SUMMARIES, aggregate_id, &summary(&run)).await
+        }
+    }
+
+    async fn reset(&self, store: &dyn ReadModelStore) -> Result<(), DomainError> {
+        store.clear(CAMPAIGN_RUN_VIEWS).await?;
+        store.clear(CAMPAIGN_RUN_SUMMARIES).await?;
+        store.clear(RUN_AGGREGATES).await
+    }
+}
+
+/// Points each aggregate `run` has registered at the run, and drops the
+/// aggregates its previous view listed that it no longer registers (a run
+/// keeps one aggregate per context, so registering a new one replaces the
+/// old).
+async fn index_registered_aggregates(
+    run: &CampaignRun,
+    store: &dyn ReadModelStore,
+) -> Result<(), DomainError> {
+    let previous: Option<CampaignRunView> = get_document(store, CAMPAIGN_RUN_VIEWS, run.id).await?;
+    if let Some(previous) = previous {
+        for aggregate_id in previous.registered_aggregates.values() {
+            if !run
+                .registered_aggregates
+                .values()
+                .any(|id| id == aggregate_id)
+            {
+                store.delete(RUN_AGGREGATES, *aggregate_id).await?;
+            }
+        }
+    }
+    for (context_name, aggregate_id) in &run.registered_aggregates {
+        let registration = RunAggregateView {
+            aggregate_id: *aggregate_id,
+            run_id: run.id,
+            context_name: context_name.clone(),
+        };
+        put_document(store, RUN_AGGREGATES, *aggregate_id, &registration).await?;
+    }
+    Ok(())
+}
+
+fn view(run: &CampaignRun) -> CampaignRunView {
+    CampaignRunView {
+        run_id: run.id,
+        campaign_id: run.campaign_id,
+        seed: run.seed,
+        checkpoint_ids: run.checkpoint_ids.clone(),
+        registered_aggregates: run.registered_aggregates.clone(),
+        version: run.version,
+    }
+}
+
+fn summary(run: &CampaignRun) -> CampaignRunSummary {
+    CampaignRunSummary {
+        run_id: run.id,
+        campaign_id: run.campaign_id,
+        checkpoint_count: run.checkpoint_ids.len(),
+        version: run.version,
+    }
+}
@@ -1,28 +1,35 @@
 //! Query handlers for the Session & Progress context.
 //!
-//! This module contains query handlers that reconstitute aggregates
-//! from stored events and return read-only view DTOs.
+//! This module contains query handlers that bring the campaign run projection
+//! up to date and return read-only view DTOs from its read-model tables.
 
 use std::collections::HashMap;
 
 use otherworlds_core::error::DomainError;
+use otherworlds_core::projection::{
+    ProjectionRunner, ReadModelStore, get_document, list_documents,
+};
 use otherworlds_core::repository::EventRepository;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use crate::application::command_handlers;
+use crate::application::projections::{
+    CAMPAIGN_RUN_SUMMARIES, CAMPAIGN_RUN_VIEWS, CampaignRunProjection, RUN_AGGREGATES,
+};
 
 /// Read-only view of a campaign run aggregate.
 ///
 /// Note: `branch_source` is intentionally omitted from this view.
 /// Branching metadata is internal bookkeeping; consumers that need it
 /// should use a dedicated branching query.
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct CampaignRunView {
     /// The campaign run identifier.
     pub run_id: Uuid,
     /// The campaign this run belongs to.
     pub campaign_id: Option<Uuid>,
+    /// Seed of the run's RNG stream, needed to replay its commands.
+    pub seed: u64,
     /// Checkpoint IDs created during this run.
     pub checkpoint_ids: Vec<Uuid>,
     /// Registered aggregates from other bounded contexts.
@@ -31,17 +38,8 @@ pub struct CampaignRunView {
     pub version: i64,
 }
 
-/// Event types used by the Session & Progress context.
-const EVENT_TYPES: &[&str] = &[
-    "session.campaign_run_started",
-    "session.checkpoint_created",
-    "session.timeline_branched",
-    "session.aggregate_registered",
-    "session.campaign_run_archived",
-];
-
 /// Summary view for listing campaign runs.
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct CampaignRunSummary {
     /// The campaign run identifier.
     pub run_id: Uuid,
@@ -53,57 +51,74 @@ pub struct CampaignRunSummary {
     pub version: i64,
 }
 
-/// Lists all campaign runs.
+/// The campaign run an aggregate of another context is registered with.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct RunAggregateView {
+    /// The registered aggregate.
+    pub aggregate_id: Uuid,
+    /// The campaign run it is registered with.
+    pub run_id: Uuid,
+    /// The context it is registered under.
+    pub context_name: String,
+}
+
+/// Lists all campaign runs that have not been archived.
 ///
 /// # Errors
 ///
-/// Returns `DomainError::Infrastructure` if querying or deserialization fails.
+/// Returns `DomainError::Infrastructure` if catching up the projection or
+/// reading the read model fails.
 pub async fn list_campaign_runs(
     repo: &dyn EventRepository,
+    read_models: &dyn ReadModelStore,
 ) -> Result<Vec<CampaignRunSummary>, DomainError> {
-    let ids = repo.list_aggregate_ids(EVENT_TYPES).await?;
-    let mut summaries = Vec::with_capacity(ids.len());
-    for id in ids {
-        let stored_events = repo.load_events(id).await?;
-        if stored_events.is_empty() {
-            continue;
-        }
-        let run = command_handlers::reconstitute(id, &stored_events)?;
-        if run.archived {
-            continue;
-        }
-        summaries.push(CampaignRunSummary {
-            run_id: id,
-            campaign_id: run.campaign_id,
-            checkpoint_count: run.checkpoint_ids.len(),
-            version: run.version,
-        });
-    }
-    Ok(summaries)
+    ProjectionRunner::default()
+        .catch_up(&CampaignRunProjection, repo, read_models)
+        .await?;
+    list_documents(read_models, CAMPAIGN_RUN_SUMMARIES).await
 }
 
 /// Retrieves a campaign run by its aggregate ID.
 ///
 /// # Errors
 ///
-/// Returns `DomainError::AggregateNotFound` if no events exist for the ID.
-/// Returns `DomainError::Infrastructure` if event deserialization fails.
+/// Returns `DomainError::AggregateNotFound` if the campaign run has no view.
+/// Returns `DomainError::Infrastructure` if catching up the projection or
+/// reading the read model fails.
 pub async fn get_campaign_run_by_id(
     run_id: Uuid,
     repo: &dyn EventRepository,
+    read_models: &dyn ReadModelStore,
 ) -> Result<CampaignRunView, DomainError> {
-    let stored_events = repo.load_events(run_id).await?;
-    if stored_events.is_empty() {
-        return Err(DomainError::AggregateNotFound(run_id));
-    }
-    let run = command_handlers::reconstitute(run_id, &stored_events)?;
-    Ok(CampaignRunView {
-        run_id,
-        campaign_id: run.campaign_id,
-        checkpoint_ids: run.checkpoint_ids.clone(),
-        registered_aggregates: run.registered_aggregates.clone(),
-        version: run.version,
-    })
+    ProjectionRunner::default()
+        .catch_up(&CampaignRunProjection, repo, read_models)
+        .await?;
+    get_document(read_models, CAMPAIGN_RUN_VIEWS, run_id)
+        .await?
+        .ok_or(DomainError::AggregateNotFound(run_id))
+}
+
+/// Finds the campaign run `aggregate_id` is currently registered with.
+///
+/// Returns `None` for aggregates no run has registered.
+///
+/// # Errors
+///
+/// Returns `DomainError::Infrastructure` if catching up the projection or
+/// reading the read model fails.
+pub async fn find_run_of_aggregate(
+    aggregate_id: Uuid,
+    repo: &dyn EventRepository,
+    read_models: &dyn ReadModelStore,
+) -> Result<Option<Uuid>, DomainError> {
+    ProjectionRunner::default()
+        .catch_up(&CampaignRunProjection, repo, read_models)
+        .await?;
+    Ok(
+        get_document::<RunAggregateView>(read_models, RUN_AGGREGATES, aggregate_id)
+            .await?
+            .map(|registration| registration.run_id),
+    )
 }
 
 #[cfg(test)]
@@ -113,9 +128,15 @@ mod tests {
     use otherworlds_core::repository::StoredEvent;
     use uuid::Uuid;
 
-    use crate::application::query_handlers::{get_campaign_run_by_id, list_campaign_runs};
-    use crate::domain::events::{CampaignRunArchived, CampaignRunStarted, SessionEventKind};
-    use otherworlds_test_support::{EmptyEventRepository, RecordingEventRepository};
+    use crate::application::query_handlers::{
+        find_run_of_aggregate, get_campaign_run_by_id, list_campaign_runs,
+    };
+    use crate::domain::events::{
+        AggregateRegistered, CampaignRunArchived, CampaignRunStarted, SessionEventKind,
+    };
+    use otherworlds_test_support::{
+        EmptyEventRepository, InMemoryReadModelStore, RecordingEventRepository,
+    };
 
     #[tokio::test]
     async fn test_get_campaign_run_by_id_returns_view_with_state() {
@@ -132,6 +153,7 @@ mod tests {
                 CampaignRunStarted {
                     run_id,
                     campaign_id,
+                    seed: 42,
                 },
             ))
             .unwrap(),
@@ -139,11 +161,14 @@ mod tests {
             correlation_id: Uuid::new_v4(),
             causation_id: Uuid::new_v4(),
             occurred_at: fixed_now,
+            schema_version: 2,
         }];
         let repo = RecordingEventRepository::new(Ok(events));
 
         // Act
-        let view = get_campaign_run_by_id(run_id, &repo).await.unwrap();
+        let view = get_campaign_run_by_id(run_id, &repo, &InMemoryReadModelStore::new())
+            .await
+            .unwrap();
 
         // Assert
         assert_eq!(view.run_id, run_id);
@@ -159,7 +184,7 @@ mod tests {
         let repo = EmptyEventRepository;
 
         // Act
-        let result = get_campaign_run_by_id(run_id, &repo).await;
+        let result = get_campaign_run_by_id(run_id, &repo, &InMemoryReadModelStore::new()).await;
 
         // Assert
         assert!(result.is_err());
@@ -173,7 +198,9 @@ mod tests {
     async fn test_list_campaign_runs_returns_empty_when_no_aggregates() {
         let repo = EmptyEventRepository;
 
-        let result = list_campaign_runs(&repo).await.unwrap();
+        let result = list_campaign_runs(&repo, &InMemoryReadModelStore::new())
+            .await
+            .unwrap();
 
         assert!(result.is_empty());
     }
@@ -192,6 +219,7 @@ mod tests {
                 CampaignRunStarted {
                     run_id,
                     campaign_id,
+                    seed: 42,
                 },
             ))
             .unwrap(),
@@ -199,10 +227,13 @@ mod tests {
             correlation_id: Uuid::new_v4(),
             causation_id: Uuid::new_v4(),
             occurred_at: fixed_now,
+            schema_version: 2,
         }];
         let repo = RecordingEventRepository::with_aggregate_ids(Ok(events), vec![run_id]);
 
-        let result = list_campaign_runs(&repo).await.unwrap();
+        let result = list_campaign_runs(&repo, &InMemoryReadModelStore::new())
+            .await
+            .unwrap();
 
         assert_eq!(result.len(), 1);
         assert_eq!(result[0].run_id, run_id);
@@ -226,6 +257,7 @@ mod tests {
                     CampaignRunStarted {
                         run_id,
                         campaign_id,
+                        seed: 42,
                     },
                 ))
                 .unwrap(),
@@ -233,6 +265,7 @@ mod tests {
                 correlation_id: Uuid::new_v4(),
                 causation_id: Uuid::new_v4(),
                 occurred_at: fixed_now,
+                schema_version: 2,
             },
             StoredEvent {
                 event_id: Uuid::new_v4(),
@@ -246,12 +279,90 @@ mod tests {
                 correlation_id: Uuid::new_v4(),
                 causation_id: Uuid::new_v4(),
                 occurred_at: fixed_now,
+                schema_version: 1,
             },
         ];
         let repo = RecordingEventRepository::with_aggregate_ids(Ok(events), vec![run_id]);
 
-        let result = list_campaign_runs(&repo).await.unwrap();
+        let result = list_campaign_runs(&repo, &InMemoryReadModelStore::new())
+            .await
+            .unwrap();
 
         assert!(result.is_empty());
     }
+
+    #[tokio::test]
+    async fn test_find_run_of_aggregate_follows_current_registrations() {
+        // Arrange — a run registers one encounter, then replaces it.
+        let run_id = Uuid::new_v4();
+        let first_encounter = Uuid::new_v4();
+        let second_encounter = Uuid::new_v4();
+        let fixed_now = Utc.with_ymd_and_hms(2026, 1, 15, 10, 0, 0).unwrap();
+        let event = |sequence_number: i64,
+                     kind: SessionEventKind,
+                     event_type: &str,
+                     schema_version: i32| StoredEvent {
+            event_id: Uuid::new_v4(),
+            aggregate_id: run_id,
+            event_type: event_type.to_owned(),
+            payload: serde_json::to_value(kind).unwrap(),
+            sequence_number,
+            correlation_id: Uuid::new_v4(),
+            causation_id: Uuid::new_v4(),
+            occurred_at: fixed_now,
+            schema_version,
+        };
+        let registered = |sequence_number: i64, aggregate_id: Uuid| {
+            event(
+                sequence_number,
+                SessionEventKind::AggregateRegistered(AggregateRegistered {
+                    run_id,
+                    context_name: "encounter".to_owned(),
+                    aggregate_id,
+                }),
+                "session.aggregate_registered",
+                1,
+            )
+        };
+        let started = event(
+            1,
+            SessionEventKind::CampaignRunStarted(CampaignRunStarted {
+                run_id,
+                campaign_id: Uuid::new_v4(),
+                seed: 42,
+            }),
+            "session.campaign_run_started",
+            2,
+        );
+        let before = RecordingEventRepository::new(Ok(vec![
+            started.clone(),
+            registered(2, first_encounter),
+        ]));
+        let after = RecordingEventRepository::new(Ok(vec![
+            started,
+            registered(2, first_encounter),
+            registered(3, second_encounter),
+        ]));
+        let store = InMemoryReadModelStore::new();
+
+        // Act
+        let first_before = find_run_of_aggregate(first_encounter, &before, &store)
+            .await
+            .unwrap();
+        let first_after = find_run_of_aggregate(first_encounter, &after, &store)
+            .await
+            .unwrap();
+        let second_after = find_run_of_aggregate(second_encounter, &after, &store)
+            .await
+            .unwrap();
+        let unregistered = find_run_of_aggregate(Uuid::new_v4(), &after, &store)
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(first_before, Some(run_id));
+        assert_eq!(first_after, None);
+        assert_eq!(second_after, Some(run_id));
+        assert_eq!(unregistered, None);
+    }
 }
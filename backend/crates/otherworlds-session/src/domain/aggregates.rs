@@ -4,20 +4,24 @@ use otherworlds_core::aggregate::AggregateRoot;
 use otherworlds_core::clock::Clock;
 use otherworlds_core::error::DomainError;
 use otherworlds_core::event::EventMetadata;
-use otherworlds_core::rng::DeterministicRng;
+use otherworlds_core::rng::{DeterministicRng, SeededRng};
+use otherworlds_core::snapshot::Snapshottable;
+use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 use std::collections::HashMap;
 
 use super::events::{
     AGGREGATE_REGISTERED_EVENT_TYPE, AggregateRegistered, CAMPAIGN_RUN_ARCHIVED_EVENT_TYPE,
-    CAMPAIGN_RUN_STARTED_EVENT_TYPE, CHECKPOINT_CREATED_EVENT_TYPE, CampaignRunArchived,
-    CampaignRunStarted, CheckpointCreated, SessionEvent, SessionEventKind,
+    CAMPAIGN_RUN_STARTED_EVENT_TYPE, CHECKPOINT_CREATED_EVENT_TYPE, COMMAND_RECORDED_EVENT_TYPE,
+    CampaignRunArchived, CampaignRunStarted, CheckpointCreated, CommandRecorded, CommitSpan,
+    RNG_ADVANCED_EVENT_TYPE, RngAdvanced, SessionEvent, SessionEventKind,
     TIMELINE_BRANCHED_EVENT_TYPE, TimelineBranched,
 };
+use super::upcasters::current_schema_version;
 
 /// The aggregate root for a campaign run.
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct CampaignRun {
     /// Aggregate identifier.
     pub id: Uuid,
@@ -33,7 +37,12 @@ pub struct CampaignRun {
     pub(crate) registered_aggregates: HashMap<String, Uuid>,
     /// Whether this campaign run has been archived (soft-deleted).
     pub(crate) archived: bool,
+    /// Seed of the run's RNG stream.
+    pub(crate) seed: u64,
+    /// Words consumed from the run's RNG stream as of the last recorded command.
+    pub(crate) rng_position: u64,
     /// Uncommitted events pending persistence.
+    #[serde(skip)]
     uncommitted_events: Vec<SessionEvent>,
 }
 
@@ -49,6 +58,8 @@ impl CampaignRun {
             branch_source: None,
             registered_aggregates: HashMap::new(),
             archived: false,
+            seed: 0,
+            rng_position: 0,
             uncommitted_events: Vec::new(),
         }
     }
@@ -59,6 +70,18 @@ impl CampaignRun {
         &self.registered_aggregates
     }
 
+    /// Returns whether this campaign run has been archived.
+    #[must_use]
+    pub fn is_archived(&self) -> bool {
+        self.archived
+    }
+
+    /// Returns the run's RNG, resumed at its last recorded stream position.
+    #[must_use]
+    pub fn rng(&self) -> SeededRng {
+        SeededRng::at_position(self.seed, self.rng_position)
+    }
+
     /// Returns the next sequence number for a new event.
     #[allow(clippy::cast_possible_wrap)]
     fn next_sequence_number(&self) -> i64 {
@@ -69,6 +92,7 @@ impl CampaignRun {
     pub fn start_campaign_run(
         &mut self,
         campaign_id: Uuid,
+        seed: u64,
         correlation_id: Uuid,
         clock: &dyn Clock,
         rng: &mut dyn DeterministicRng,
@@ -82,10 +106,77 @@ impl CampaignRun {
                 correlation_id,
                 causation_id: correlation_id,
                 occurred_at: clock.now(),
+                schema_version: current_schema_version(CAMPAIGN_RUN_STARTED_EVENT_TYPE),
             },
             kind: SessionEventKind::CampaignRunStarted(CampaignRunStarted {
                 run_id: self.id,
                 campaign_id,
+                seed,
+            }),
+        };
+
+        self.uncommitted_events.push(event);
+    }
+
+    /// Records a command executed on behalf of this run, producing a
+    /// `CommandRecorded` event.
+    pub fn record_command(
+        &mut self,
+        command_type: &str,
+        command: serde_json::Value,
+        commit: CommitSpan,
+        correlation_id: Uuid,
+        clock: &dyn Clock,
+        rng: &mut dyn DeterministicRng,
+    ) {
+        let event = SessionEvent {
+            metadata: EventMetadata {
+                event_id: rng.next_uuid(),
+                event_type: COMMAND_RECORDED_EVENT_TYPE.to_owned(),
+                aggregate_id: self.id,
+                sequence_number: self.next_sequence_number(),
+                correlation_id,
+                causation_id: correlation_id,
+                occurred_at: clock.now(),
+                schema_version: current_schema_version(COMMAND_RECORDED_EVENT_TYPE),
+            },
+            kind: SessionEventKind::CommandRecorded(CommandRecorded {
+                run_id: self.id,
+                command_type: command_type.to_owned(),
+                command,
+                commit,
+            }),
+        };
+
+        self.uncommitted_events.push(event);
+    }
+
+    /// Records how far `rng` (this run's RNG) has advanced, producing an
+    /// `RngAdvanced` event.
+    ///
+    /// The event ID is drawn from `rng` before its position is read, so the
+    /// recorded position covers every draw the command made.
+    pub fn record_rng_position(
+        &mut self,
+        correlation_id: Uuid,
+        clock: &dyn Clock,
+        rng: &mut SeededRng,
+    ) {
+        let event_id = rng.next_uuid();
+        let event = SessionEvent {
+            metadata: EventMetadata {
+                event_id,
+                event_type: RNG_ADVANCED_EVENT_TYPE.to_owned(),
+                aggregate_id: self.id,
+                sequence_number: self.next_sequence_number(),
+                correlation_id,
+                causation_id: correlation_id,
+                occurred_at: clock.now(),
+                schema_version: current_schema_version(RNG_ADVANCED_EVENT_TYPE),
+            },
+            kind: SessionEventKind::RngAdvanced(RngAdvanced {
+                run_id: self.id,
+                position: rng.position(),
             }),
         };
 
@@ -108,6 +199,7 @@ impl CampaignRun {
                 correlation_id,
                 causation_id: correlation_id,
                 occurred_at: clock.now(),
+                schema_version: current_schema_version(CHECKPOINT_CREATED_EVENT_TYPE),
             },
             kind: SessionEventKind::CheckpointCreated(CheckpointCreated {
                 run_id: self.id,
@@ -149,6 +241,7 @@ impl CampaignRun {
                 correlation_id,
                 causation_id: correlation_id,
                 occurred_at: clock.now(),
+                schema_version: current_schema_version(AGGREGATE_REGISTERED_EVENT_TYPE),
             },
             kind: SessionEventKind::AggregateRegistered(AggregateRegistered {
                 run_id: self.id,
@@ -165,8 +258,10 @@ impl CampaignRun {
     /// to point to the branch. Used during timeline branching to fork the
     /// event stream up to a checkpoint.
     ///
-    /// Only replays `CampaignRunStarted` and `CheckpointCreated` events.
-    /// `TimelineBranched` and `CampaignRunArchived` events are skipped.
+    /// Only replays `CampaignRunStarted`, `CheckpointCreated`, and
+    /// `AggregateRegistered` events; all other events are skipped. The
+    /// branch is given a fresh seed drawn from `rng`, so its rolls diverge
+    /// from the source run's while remaining reproducible.
     pub fn replay_source_events(
         &mut self,
         source_events: &[SessionEvent],
@@ -180,6 +275,7 @@ impl CampaignRun {
                     SessionEventKind::CampaignRunStarted(CampaignRunStarted {
                         run_id: self.id,
                         campaign_id: payload.campaign_id,
+                        seed: rng.next_u64(),
                     })
                 }
                 SessionEventKind::CheckpointCreated(payload) => {
@@ -196,6 +292,8 @@ impl CampaignRun {
                     })
                 }
                 SessionEventKind::TimelineBranched(_)
+                | SessionEventKind::RngAdvanced(_)
+                | SessionEventKind::CommandRecorded(_)
                 | SessionEventKind::CampaignRunArchived(_) => {
                     continue;
                 }
@@ -210,6 +308,7 @@ impl CampaignRun {
                     correlation_id,
                     causation_id: correlation_id,
                     occurred_at: clock.now(),
+                    schema_version: current_schema_version(&source_event.metadata.event_type),
                 },
                 kind,
             };
@@ -236,6 +335,7 @@ impl CampaignRun {
                 correlation_id,
                 causation_id: correlation_id,
                 occurred_at: clock.now(),
+                schema_version: current_schema_version(TIMELINE_BRANCHED_EVENT_TYPE),
             },
             kind: SessionEventKind::TimelineBranched(TimelineBranched {
                 source_run_id,
@@ -272,6 +372,7 @@ impl CampaignRun {
                 correlation_id,
                 causation_id: correlation_id,
                 occurred_at: clock.now(),
+                schema_version: current_schema_version(CAMPAIGN_RUN_ARCHIVED_EVENT_TYPE),
             },
             kind: SessionEventKind::CampaignRunArchived(CampaignRunArchived { run_id: self.id }),
         };
@@ -296,6 +397,8 @@ impl AggregateRoot for CampaignRun {
         match &event.kind {
             SessionEventKind::CampaignRunStarted(payload) => {
                 self.campaign_id = Some(payload.campaign_id);
+                self.seed = payload.seed;
+                self.rng_position = 0;
             }
             SessionEventKind::CheckpointCreated(payload) => {
                 self.checkpoint_ids.push(payload.checkpoint_id);
@@ -307,6 +410,10 @@ impl AggregateRoot for CampaignRun {
                 self.registered_aggregates
                     .insert(payload.context_name.clone(), payload.aggregate_id);
             }
+            SessionEventKind::RngAdvanced(payload) => {
+                self.rng_position = payload.position;
+            }
+            SessionEventKind::CommandRecorded(_) => {}
             SessionEventKind::CampaignRunArchived(_) => {
                 self.archived = true;
             }
@@ -323,6 +430,10 @@ impl AggregateRoot for CampaignRun {
     }
 }
 
+impl Snapshottable for CampaignRun {
+    const AGGREGATE_TYPE: &'static str = "session.campaign_run";
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -343,7 +454,7 @@ mod tests {
         let mut run = CampaignRun::new(run_id);
 
         // Act
-        run.start_campaign_run(campaign_id, correlation_id, &clock, &mut MockRng);
+        run.start_campaign_run(campaign_id, 42, correlation_id, &clock, &mut MockRng);
 
         // Assert
         let events = run.uncommitted_events();
@@ -363,6 +474,7 @@ mod tests {
             SessionEventKind::CampaignRunStarted(payload) => {
                 assert_eq!(payload.run_id, run_id);
                 assert_eq!(payload.campaign_id, campaign_id);
+                assert_eq!(payload.seed, 42);
             }
             other => panic!("expected CampaignRunStarted, got {other:?}"),
         }
@@ -384,10 +496,12 @@ mod tests {
                 correlation_id: Uuid::new_v4(),
                 causation_id: Uuid::new_v4(),
                 occurred_at: fixed_now,
+                schema_version: current_schema_version(CAMPAIGN_RUN_STARTED_EVENT_TYPE),
             },
             kind: SessionEventKind::CampaignRunStarted(CampaignRunStarted {
                 run_id,
                 campaign_id,
+                seed: 42,
             }),
         };
 
@@ -396,6 +510,8 @@ mod tests {
 
         // Assert
         assert_eq!(run.campaign_id, Some(campaign_id));
+        assert_eq!(run.seed, 42);
+        assert_eq!(run.rng_position, 0);
         assert_eq!(run.version, 1);
     }
 
@@ -415,6 +531,7 @@ mod tests {
                 correlation_id: Uuid::new_v4(),
                 causation_id: Uuid::new_v4(),
                 occurred_at: fixed_now,
+                schema_version: current_schema_version(CHECKPOINT_CREATED_EVENT_TYPE),
             },
             kind: SessionEventKind::CheckpointCreated(CheckpointCreated {
                 run_id,
@@ -447,6 +564,7 @@ mod tests {
                 correlation_id: Uuid::new_v4(),
                 causation_id: Uuid::new_v4(),
                 occurred_at: fixed_now,
+                schema_version: current_schema_version(TIMELINE_BRANCHED_EVENT_TYPE),
             },
             kind: SessionEventKind::TimelineBranched(TimelineBranched {
                 source_run_id,
@@ -592,6 +710,7 @@ mod tests {
                 correlation_id: Uuid::new_v4(),
                 causation_id: Uuid::new_v4(),
                 occurred_at: fixed_now,
+                schema_version: current_schema_version(CAMPAIGN_RUN_ARCHIVED_EVENT_TYPE),
             },
             kind: SessionEventKind::CampaignRunArchived(CampaignRunArchived { run_id }),
         };
@@ -662,6 +781,7 @@ mod tests {
                 correlation_id: Uuid::new_v4(),
                 causation_id: Uuid::new_v4(),
                 occurred_at: fixed_now,
+                schema_version: current_schema_version(AGGREGATE_REGISTERED_EVENT_TYPE),
             },
             kind: SessionEventKind::AggregateRegistered(AggregateRegistered {
                 run_id,
@@ -762,4 +882,110 @@ mod tests {
             other => panic!("expected Validation, got {other:?}"),
         }
     }
+
+    #[test]
+    fn test_record_rng_position_records_position_after_event_id_draw() {
+        // Arrange
+        let run_id = Uuid::new_v4();
+        let correlation_id = Uuid::new_v4();
+        let clock = FixedClock(Utc.with_ymd_and_hms(2026, 1, 15, 10, 0, 0).unwrap());
+        let mut run = CampaignRun::new(run_id);
+        let mut rng = SeededRng::new(42);
+        rng.next_u32_range(1, 20);
+
+        // Act
+        run.record_rng_position(correlation_id, &clock, &mut rng);
+
+        // Assert
+        let events = run.uncommitted_events();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].event_type(), "session.rng_advanced");
+        match &events[0].kind {
+            SessionEventKind::RngAdvanced(payload) => {
+                assert_eq!(payload.run_id, run_id);
+                assert_eq!(payload.position, rng.position());
+            }
+            other => panic!("expected RngAdvanced, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_record_command_produces_command_recorded_event() {
+        // Arrange
+        let run_id = Uuid::new_v4();
+        let correlation_id = Uuid::new_v4();
+        let clock = FixedClock(Utc.with_ymd_and_hms(2026, 1, 15, 10, 0, 0).unwrap());
+        let mut run = CampaignRun::new(run_id);
+        let command = serde_json::json!({ "ArchiveCharacter": { "character_id": run_id } });
+
+        // Act
+        run.record_command(
+            "character.archive_character",
+            command.clone(),
+            CommitSpan { offset: 1, len: 3 },
+            correlation_id,
+            &clock,
+            &mut MockRng,
+        );
+
+        // Assert
+        let events = run.uncommitted_events();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].event_type(), "session.command_recorded");
+        assert_eq!(events[0].metadata.correlation_id, correlation_id);
+        match &events[0].kind {
+            SessionEventKind::CommandRecorded(payload) => {
+                assert_eq!(payload.run_id, run_id);
+                assert_eq!(payload.command_type, "character.archive_character");
+                assert_eq!(payload.command, command);
+                assert_eq!(payload.commit, CommitSpan { offset: 1, len: 3 });
+            }
+            other => panic!("expected CommandRecorded, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_rng_resumes_at_recorded_position() {
+        // Arrange
+        let clock = FixedClock(Utc.with_ymd_and_hms(2026, 1, 15, 10, 0, 0).unwrap());
+        let mut run = CampaignRun::new(Uuid::new_v4());
+        run.start_campaign_run(Uuid::new_v4(), 42, Uuid::new_v4(), &clock, &mut MockRng);
+        let mut rng = SeededRng::new(42);
+        rng.next_u32_range(1, 20);
+        run.record_rng_position(Uuid::new_v4(), &clock, &mut rng);
+
+        // Act
+        for event in run.uncommitted_events().to_vec() {
+            run.apply(&event);
+        }
+        run.clear_uncommitted_events();
+
+        // Assert
+        let mut resumed = run.rng();
+        assert_eq!(resumed.seed(), 42);
+        assert_eq!(resumed.next_uuid(), rng.next_uuid());
+    }
+
+    #[test]
+    fn test_replay_source_events_gives_branch_a_fresh_seed() {
+        // Arrange
+        let clock = FixedClock(Utc.with_ymd_and_hms(2026, 1, 15, 10, 0, 0).unwrap());
+        let mut source = CampaignRun::new(Uuid::new_v4());
+        source.start_campaign_run(Uuid::new_v4(), 42, Uuid::new_v4(), &clock, &mut MockRng);
+        let source_events = source.uncommitted_events().to_vec();
+        let mut branch = CampaignRun::new(Uuid::new_v4());
+        let mut rng = SeededRng::new(7);
+        let expected_seed = SeededRng::new(7).next_u64();
+
+        // Act
+        branch.replay_source_events(&source_events, Uuid::new_v4(), &clock, &mut rng);
+
+        // Assert
+        match &branch.uncommitted_events()[0].kind {
+            SessionEventKind::CampaignRunStarted(payload) => {
+                assert_eq!(payload.seed, expected_seed);
+            }
+            other => panic!("expected CampaignRunStarted, got {other:?}"),
+        }
+    }
 }
@@ -1,15 +1,20 @@
 //! Commands for the Session & Progress context.
 
 use otherworlds_core::command::Command;
+use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use super::events::CommitSpan;
+
 /// Command to start a new campaign run.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StartCampaignRun {
     /// The correlation ID for tracing.
     pub correlation_id: Uuid,
     /// The campaign to run.
     pub campaign_id: Uuid,
+    /// Seed of the new run's RNG stream.
+    pub seed: u64,
 }
 
 impl Command for StartCampaignRun {
@@ -23,7 +28,7 @@ impl Command for StartCampaignRun {
 }
 
 /// Command to create a checkpoint.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CreateCheckpoint {
     /// The correlation ID for tracing.
     pub correlation_id: Uuid,
@@ -42,7 +47,7 @@ impl Command for CreateCheckpoint {
 }
 
 /// Command to branch a timeline.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BranchTimeline {
     /// The correlation ID for tracing.
     pub correlation_id: Uuid,
@@ -63,7 +68,7 @@ impl Command for BranchTimeline {
 }
 
 /// Command to register an aggregate from another bounded context with a campaign run.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RegisterAggregate {
     /// The correlation ID for tracing.
     pub correlation_id: Uuid,
@@ -85,8 +90,53 @@ impl Command for RegisterAggregate {
     }
 }
 
+/// Command to record another command in a run's command log.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecordCommand {
+    /// The correlation ID of the command being recorded.
+    pub correlation_id: Uuid,
+    /// The campaign run the command was executed for.
+    pub run_id: Uuid,
+    /// The recorded command's type name.
+    pub command_type: String,
+    /// The serialized command.
+    pub command: serde_json::Value,
+    /// Where the record sits among the events committed with it.
+    pub commit: CommitSpan,
+}
+
+impl Command for RecordCommand {
+    fn command_type(&self) -> &'static str {
+        "session.record_command"
+    }
+
+    fn correlation_id(&self) -> Uuid {
+        self.correlation_id
+    }
+}
+
+/// Command to record how far a run's RNG has advanced after another command
+/// drew from it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecordRngPosition {
+    /// The correlation ID of the command that drew from the RNG.
+    pub correlation_id: Uuid,
+    /// The campaign run whose RNG advanced.
+    pub run_id: Uuid,
+}
+
+impl Command for RecordRngPosition {
+    fn command_type(&self) -> &'static str {
+        "session.record_rng_position"
+    }
+
+    fn correlation_id(&self) -> Uuid {
+        self.correlation_id
+    }
+}
+
 /// Command to archive (soft-delete) a campaign run.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ArchiveCampaignRun {
     /// The correlation ID for tracing.
     pub correlation_id: Uuid,
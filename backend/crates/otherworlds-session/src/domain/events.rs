@@ -11,6 +11,8 @@ pub struct CampaignRunStarted {
     pub run_id: Uuid,
     /// The campaign identifier.
     pub campaign_id: Uuid,
+    /// Seed of the run's RNG stream.
+    pub seed: u64,
 }
 
 /// Emitted when a checkpoint is created.
@@ -44,6 +46,40 @@ pub struct AggregateRegistered {
     pub aggregate_id: Uuid,
 }
 
+/// Emitted after a command consumed randomness from a run's RNG, recording
+/// where its stream now stands.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RngAdvanced {
+    /// The campaign run identifier.
+    pub run_id: Uuid,
+    /// Number of 32-bit words consumed from the run's RNG stream.
+    pub position: u64,
+}
+
+/// Emitted when a command runs on behalf of a campaign run, recording the
+/// command so the run can be replayed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CommandRecorded {
+    /// The campaign run identifier.
+    pub run_id: Uuid,
+    /// The recorded command's type name.
+    pub command_type: String,
+    /// The serialized command.
+    pub command: serde_json::Value,
+    /// Where this event sits among the events committed with it.
+    pub commit: CommitSpan,
+}
+
+/// The span of the global log a recorded command committed, relative to its
+/// `CommandRecorded` event.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CommitSpan {
+    /// Number of events committed ahead of the `CommandRecorded` event.
+    pub offset: usize,
+    /// Number of events in the commit, the `CommandRecorded` event included.
+    pub len: usize,
+}
+
 /// Emitted when a campaign run is archived (soft-deleted).
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CampaignRunArchived {
@@ -63,6 +99,12 @@ pub const TIMELINE_BRANCHED_EVENT_TYPE: &str = "session.timeline_branched";
 /// Event type identifier for [`AggregateRegistered`].
 pub const AGGREGATE_REGISTERED_EVENT_TYPE: &str = "session.aggregate_registered";
 
+/// Event type identifier for [`RngAdvanced`].
+pub const RNG_ADVANCED_EVENT_TYPE: &str = "session.rng_advanced";
+
+/// Event type identifier for [`CommandRecorded`].
+pub const COMMAND_RECORDED_EVENT_TYPE: &str = "session.command_recorded";
+
 /// Event type identifier for [`CampaignRunArchived`].
 pub const CAMPAIGN_RUN_ARCHIVED_EVENT_TYPE: &str = "session.campaign_run_archived";
 
@@ -77,6 +119,10 @@ pub enum SessionEventKind {
     TimelineBranched(TimelineBranched),
     /// An aggregate from another context has been registered with this run.
     AggregateRegistered(AggregateRegistered),
+    /// The run's RNG stream has advanced.
+    RngAdvanced(RngAdvanced),
+    /// A command has been recorded in the run's command log.
+    CommandRecorded(CommandRecorded),
     /// A campaign run has been archived (soft-deleted).
     CampaignRunArchived(CampaignRunArchived),
 }
@@ -97,6 +143,8 @@ impl DomainEvent for SessionEvent {
             SessionEventKind::CheckpointCreated(_) => CHECKPOINT_CREATED_EVENT_TYPE,
             SessionEventKind::TimelineBranched(_) => TIMELINE_BRANCHED_EVENT_TYPE,
             SessionEventKind::AggregateRegistered(_) => AGGREGATE_REGISTERED_EVENT_TYPE,
+            SessionEventKind::RngAdvanced(_) => RNG_ADVANCED_EVENT_TYPE,
+            SessionEventKind::CommandRecorded(_) => COMMAND_RECORDED_EVENT_TYPE,
             SessionEventKind::CampaignRunArchived(_) => CAMPAIGN_RUN_ARCHIVED_EVENT_TYPE,
         }
     }
@@ -3,3 +3,4 @@
 pub mod aggregates;
 pub mod commands;
 pub mod events;
+pub mod upcasters;
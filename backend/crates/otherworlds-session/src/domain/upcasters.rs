@@ -0,0 +1,102 @@
+//! Upcasters for the Session & Progress context.
+//!
+//! Register a step here whenever an event payload changes shape, so streams
+//! written with the previous shape still load.
+
+use std::sync::LazyLock;
+
+use otherworlds_core::error::DomainError;
+use otherworlds_core::upcasting::UpcasterRegistry;
+use serde_json::Value;
+use uuid::Uuid;
+
+use super::events::CAMPAIGN_RUN_STARTED_EVENT_TYPE;
+
+static REGISTRY: LazyLock<UpcasterRegistry> = LazyLock::new(|| {
+    UpcasterRegistry::new().register(CAMPAIGN_RUN_STARTED_EVENT_TYPE, 1, add_run_seed)
+});
+
+/// Returns the upcaster registry for session events.
+#[must_use]
+pub fn registry() -> &'static UpcasterRegistry {
+    &REGISTRY
+}
+
+/// Returns the schema version newly produced events of `event_type` carry.
+#[must_use]
+pub fn current_schema_version(event_type: &str) -> i32 {
+    REGISTRY.current_version(event_type)
+}
+
+/// v1 → v2: `CampaignRunStarted` records the seed of the run's RNG.
+///
+/// Runs started before seeds were recorded never had a per-run stream, so
+/// their history cannot be reproduced either way. They are given a seed
+/// derived from the run ID, so each one still gets a distinct, stable stream
+/// for the commands it receives from now on.
+fn add_run_seed(mut payload: Value) -> Result<Value, DomainError> {
+    let started = payload
+        .get_mut("CampaignRunStarted")
+        .and_then(Value::as_object_mut)
+        .ok_or_else(|| {
+            DomainError::Infrastructure("campaign_run_started payload has no body".into())
+        })?;
+    let run_id = started
+        .get("run_id")
+        .and_then(Value::as_str)
+        .and_then(|id| Uuid::parse_str(id).ok())
+        .ok_or_else(|| {
+            DomainError::Infrastructure("campaign_run_started payload has no valid run_id".into())
+        })?;
+    started.insert("seed".into(), Value::from(run_id.as_u64_pair().0));
+    Ok(payload)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::{TimeZone, Utc};
+    use otherworlds_core::repository::StoredEvent;
+    use serde_json::json;
+
+    use super::*;
+    use crate::domain::events::SessionEventKind;
+
+    #[test]
+    fn test_campaign_run_started_v1_gains_seed_derived_from_run_id() {
+        // Arrange
+        let run_id = Uuid::new_v4();
+        let stored = StoredEvent {
+            event_id: Uuid::new_v4(),
+            aggregate_id: run_id,
+            event_type: CAMPAIGN_RUN_STARTED_EVENT_TYPE.to_owned(),
+            payload: json!({
+                "CampaignRunStarted": { "run_id": run_id, "campaign_id": Uuid::new_v4() }
+            }),
+            sequence_number: 1,
+            correlation_id: Uuid::new_v4(),
+            causation_id: Uuid::new_v4(),
+            occurred_at: Utc.with_ymd_and_hms(2026, 1, 1, 0, 0, 0).unwrap(),
+            schema_version: 1,
+        };
+
+        // Act
+        let payload = registry().upcast(&stored).unwrap();
+
+        // Assert
+        match serde_json::from_value::<SessionEventKind>(payload).unwrap() {
+            SessionEventKind::CampaignRunStarted(started) => {
+                assert_eq!(started.seed, run_id.as_u64_pair().0);
+            }
+            other => panic!("expected CampaignRunStarted, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_campaign_run_started_v1_without_run_id_is_rejected() {
+        // Act
+        let result = add_run_seed(json!({ "CampaignRunStarted": {} }));
+
+        // Assert
+        assert!(matches!(result, Err(DomainError::Infrastructure(_))));
+    }
+}
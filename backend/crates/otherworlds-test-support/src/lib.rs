@@ -1,12 +1,12 @@
 //! Shared test mocks and utilities for the Otherworlds RPG engine.
 
-mod clock;
 mod repository;
 mod rng;
 
-pub use clock::FixedClock;
+pub use otherworlds_core::clock::FixedClock;
+pub use otherworlds_event_store::memory_read_model_store::InMemoryReadModelStore;
 pub use repository::{
     ConflictingEventRepository, EmptyEventRepository, FailingEventRepository,
-    MultiAggregateEventRepository, RecordingEventRepository,
+    InMemorySnapshotStore, MultiAggregateEventRepository, RecordingEventRepository,
 };
 pub use rng::{MockRng, SequenceRng};
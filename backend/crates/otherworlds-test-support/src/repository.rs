@@ -1,12 +1,37 @@
 //! Test repositories — mock `EventRepository` implementations for tests.
 
+use std::collections::HashMap;
 use std::sync::Mutex;
 
 use async_trait::async_trait;
 use otherworlds_core::error::DomainError;
-use otherworlds_core::repository::{EventRepository, StoredEvent};
+use otherworlds_core::repository::{
+    AggregateSnapshot, EventRepository, PositionedEvent, SnapshotPolicy, SnapshotStore, StoredEvent,
+};
 use uuid::Uuid;
 
+/// Reads a slice of an in-memory global log, numbering events from 1 in
+/// slice order.
+fn read_log(
+    log: &[StoredEvent],
+    event_types: Option<&[&str]>,
+    from_position: i64,
+    limit: i64,
+) -> Vec<PositionedEvent> {
+    (1_i64..)
+        .zip(log)
+        .filter(|(position, _)| *position >= from_position)
+        .filter(|(_, event)| {
+            event_types.is_none_or(|types| types.contains(&event.event_type.as_str()))
+        })
+        .take(usize::try_from(limit).unwrap_or(0))
+        .map(|(position, event)| PositionedEvent {
+            position,
+            event: event.clone(),
+        })
+        .collect()
+}
+
 /// An event repository that records all `load_events` and `append_events`
 /// calls. Returns the configured result from `load_events` on every call and
 /// always succeeds on `append_events`.
@@ -71,6 +96,16 @@ impl RecordingEventRepository {
     pub fn appended_events(&self) -> Vec<(Uuid, i64, Vec<StoredEvent>)> {
         self.appended.lock().unwrap().clone()
     }
+
+    /// The configured load result followed by every appended event, in
+    /// append order.
+    fn global_log(&self) -> Vec<StoredEvent> {
+        let mut log = self.load_result.lock().unwrap().clone();
+        for (_, _, events) in self.appended.lock().unwrap().iter() {
+            log.extend(events.iter().cloned());
+        }
+        log
+    }
 }
 
 #[async_trait]
@@ -95,6 +130,28 @@ impl EventRepository for RecordingEventRepository {
     async fn list_aggregate_ids(&self, _event_types: &[&str]) -> Result<Vec<Uuid>, DomainError> {
         Ok(self.aggregate_ids.lock().unwrap().clone())
     }
+
+    async fn read_all_from(
+        &self,
+        from_position: i64,
+        limit: i64,
+    ) -> Result<Vec<PositionedEvent>, DomainError> {
+        Ok(read_log(&self.global_log(), None, from_position, limit))
+    }
+
+    async fn read_by_types_from(
+        &self,
+        event_types: &[&str],
+        from_position: i64,
+        limit: i64,
+    ) -> Result<Vec<PositionedEvent>, DomainError> {
+        Ok(read_log(
+            &self.global_log(),
+            Some(event_types),
+            from_position,
+            limit,
+        ))
+    }
 }
 
 /// An event repository that stores events per aggregate ID, supporting
@@ -103,19 +160,42 @@ impl EventRepository for RecordingEventRepository {
 pub struct MultiAggregateEventRepository {
     events_by_aggregate: Mutex<std::collections::HashMap<Uuid, Vec<StoredEvent>>>,
     appended: Mutex<Vec<(Uuid, i64, Vec<StoredEvent>)>>,
+    log: Mutex<Vec<StoredEvent>>,
+    snapshots: Option<InMemorySnapshotStore>,
 }
 
 impl MultiAggregateEventRepository {
     /// Creates a new multi-aggregate repository with pre-loaded events.
     ///
+    /// Pre-loaded events enter the global log ordered by `occurred_at`, then
+    /// aggregate ID, then sequence number; appended events follow in append
+    /// order.
+    ///
     /// # Panics
     ///
     /// Panics if the internal mutex is poisoned.
     #[must_use]
     pub fn new(events_by_aggregate: std::collections::HashMap<Uuid, Vec<StoredEvent>>) -> Self {
+        let mut log: Vec<StoredEvent> = events_by_aggregate.values().flatten().cloned().collect();
+        log.sort_by_key(|e| (e.occurred_at, e.aggregate_id, e.sequence_number));
         Self {
             events_by_aggregate: Mutex::new(events_by_aggregate),
             appended: Mutex::new(Vec::new()),
+            log: Mutex::new(log),
+            snapshots: None,
+        }
+    }
+
+    /// Creates a new multi-aggregate repository with pre-loaded events and an
+    /// in-memory snapshot store using `policy`.
+    #[must_use]
+    pub fn with_snapshots(
+        events_by_aggregate: std::collections::HashMap<Uuid, Vec<StoredEvent>>,
+        policy: SnapshotPolicy,
+    ) -> Self {
+        Self {
+            snapshots: Some(InMemorySnapshotStore::new(policy)),
+            ..Self::new(events_by_aggregate)
         }
     }
 
@@ -153,6 +233,7 @@ impl EventRepository for MultiAggregateEventRepository {
             .entry(aggregate_id)
             .or_default()
             .extend(events.to_vec());
+        self.log.lock().unwrap().extend(events.iter().cloned());
         Ok(())
     }
 
@@ -160,6 +241,71 @@ impl EventRepository for MultiAggregateEventRepository {
         let guard = self.events_by_aggregate.lock().unwrap();
         Ok(guard.keys().copied().collect())
     }
+
+    async fn read_all_from(
+        &self,
+        from_position: i64,
+        limit: i64,
+    ) -> Result<Vec<PositionedEvent>, DomainError> {
+        let log = self.log.lock().unwrap();
+        Ok(read_log(&log, None, from_position, limit))
+    }
+
+    async fn read_by_types_from(
+        &self,
+        event_types: &[&str],
+        from_position: i64,
+        limit: i64,
+    ) -> Result<Vec<PositionedEvent>, DomainError> {
+        let log = self.log.lock().unwrap();
+        Ok(read_log(&log, Some(event_types), from_position, limit))
+    }
+
+    fn snapshot_store(&self) -> Option<&dyn SnapshotStore> {
+        self.snapshots
+            .as_ref()
+            .map(|store| store as &dyn SnapshotStore)
+    }
+}
+
+/// A snapshot store that keeps the latest snapshot per aggregate in memory.
+#[derive(Debug)]
+pub struct InMemorySnapshotStore {
+    policy: SnapshotPolicy,
+    snapshots: Mutex<HashMap<Uuid, AggregateSnapshot>>,
+}
+
+impl InMemorySnapshotStore {
+    /// Creates an empty snapshot store using `policy`.
+    #[must_use]
+    pub fn new(policy: SnapshotPolicy) -> Self {
+        Self {
+            policy,
+            snapshots: Mutex::new(HashMap::new()),
+        }
+    }
+}
+
+#[async_trait]
+impl SnapshotStore for InMemorySnapshotStore {
+    async fn load_snapshot(
+        &self,
+        aggregate_id: Uuid,
+    ) -> Result<Option<AggregateSnapshot>, DomainError> {
+        Ok(self.snapshots.lock().unwrap().get(&aggregate_id).cloned())
+    }
+
+    async fn save_snapshot(&self, snapshot: &AggregateSnapshot) -> Result<(), DomainError> {
+        self.snapshots
+            .lock()
+            .unwrap()
+            .insert(snapshot.aggregate_id, snapshot.clone());
+        Ok(())
+    }
+
+    fn policy(&self) -> SnapshotPolicy {
+        self.policy
+    }
 }
 
 /// An event repository that always returns an empty event list and silently
@@ -186,6 +332,23 @@ impl EventRepository for EmptyEventRepository {
     async fn list_aggregate_ids(&self, _event_types: &[&str]) -> Result<Vec<Uuid>, DomainError> {
         Ok(vec![])
     }
+
+    async fn read_all_from(
+        &self,
+        _from_position: i64,
+        _limit: i64,
+    ) -> Result<Vec<PositionedEvent>, DomainError> {
+        Ok(vec![])
+    }
+
+    async fn read_by_types_from(
+        &self,
+        _event_types: &[&str],
+        _from_position: i64,
+        _limit: i64,
+    ) -> Result<Vec<PositionedEvent>, DomainError> {
+        Ok(vec![])
+    }
 }
 
 /// An event repository that loads events successfully but always returns a
@@ -243,6 +406,25 @@ impl EventRepository for ConflictingEventRepository {
     async fn list_aggregate_ids(&self, _event_types: &[&str]) -> Result<Vec<Uuid>, DomainError> {
         Ok(vec![])
     }
+
+    async fn read_all_from(
+        &self,
+        from_position: i64,
+        limit: i64,
+    ) -> Result<Vec<PositionedEvent>, DomainError> {
+        let log = self.load_result.lock().unwrap();
+        Ok(read_log(&log, None, from_position, limit))
+    }
+
+    async fn read_by_types_from(
+        &self,
+        event_types: &[&str],
+        from_position: i64,
+        limit: i64,
+    ) -> Result<Vec<PositionedEvent>, DomainError> {
+        let log = self.load_result.lock().unwrap();
+        Ok(read_log(&log, Some(event_types), from_position, limit))
+    }
 }
 
 /// An event repository that always returns an infrastructure error. Useful for
@@ -268,4 +450,20 @@ impl EventRepository for FailingEventRepository {
     async fn list_aggregate_ids(&self, _event_types: &[&str]) -> Result<Vec<Uuid>, DomainError> {
         Err(DomainError::Infrastructure("connection refused".into()))
     }
+    async fn read_all_from(
+        &self,
+        _from_position: i64,
+        _limit: i64,
+    ) -> Result<Vec<PositionedEvent>, DomainError> {
+        Err(DomainError::Infrastructure("connection refused".into()))
+    }
+
+    async fn read_by_types_from(
+        &self,
+        _event_types: &[&str],
+        _from_position: i64,
+        _limit: i64,
+    ) -> Result<Vec<PositionedEvent>, DomainError> {
+        Err(DomainError::Infrastructure("connection refused".into()))
+    }
 }
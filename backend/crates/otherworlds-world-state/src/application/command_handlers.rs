@@ -3,6 +3,7 @@
 //! This module contains application-level command handler functions that
 //! orchestrate domain logic: load aggregate, execute command, persist events.
 
+use std::collections::BTreeSet;
 use std::sync::Mutex;
 
 use otherworlds_core::aggregate::AggregateRoot;
@@ -11,12 +12,18 @@ use otherworlds_core::error::DomainError;
 use otherworlds_core::event::DomainEvent;
 use otherworlds_core::repository::{EventRepository, StoredEvent};
 use otherworlds_core::rng::DeterministicRng;
+use otherworlds_core::snapshot::{load_with_snapshot, save_snapshot_if_due};
 use tracing::instrument;
 use uuid::Uuid;
 
 use crate::domain::aggregates::WorldSnapshot;
-use crate::domain::commands::{ApplyEffect, ArchiveWorldSnapshot, SetFlag, UpdateDisposition};
-use crate::domain::events::{WorldStateEvent, WorldStateEventKind};
+use crate::domain::commands::{
+    AdvanceTime, ApplyEffect, ArchiveWorldSnapshot, AssertFact, JoinFaction, MoveNpc, MoveParty,
+    RetractFact, ScheduleEvent, SeedDispositions, SeedLocations, SetCalendar, SetConnectionLock,
+    SetFlag, UpdateDisposition,
+};
+use crate::domain::events::{FactValue, WorldStateEvent, WorldStateEventKind};
+use crate::domain::upcasters;
 
 fn to_stored_event(event: &WorldStateEvent) -> StoredEvent {
     let meta = event.metadata();
@@ -29,6 +36,7 @@ fn to_stored_event(event: &WorldStateEvent) -> StoredEvent {
         correlation_id: meta.correlation_id,
         causation_id: meta.causation_id,
         occurred_at: meta.occurred_at,
+        schema_version: meta.schema_version,
     }
 }
 
@@ -41,12 +49,39 @@ pub(crate) fn reconstitute(
     world_id: Uuid,
     existing_events: &[StoredEvent],
 ) -> Result<WorldSnapshot, DomainError> {
-    let mut snapshot = WorldSnapshot::new(world_id);
-    for stored in existing_events {
-        let kind: WorldStateEventKind =
-            serde_json::from_value(stored.payload.clone()).map_err(|e| {
-                DomainError::Infrastructure(format!("event deserialization failed: {e}"))
-            })?;
+    replay(WorldSnapshot::new(world_id), existing_events)
+}
+
+/// Loads a `WorldSnapshot`, starting from its latest snapshot when one is
+/// available and replaying only the events recorded after it.
+///
+/// Returns `None` if the aggregate has neither a snapshot nor any events.
+///
+/// # Errors
+///
+/// Returns `DomainError` if loading fails or event deserialization fails.
+pub(crate) async fn load(
+    world_id: Uuid,
+    repo: &dyn EventRepository,
+) -> Result<Option<WorldSnapshot>, DomainError> {
+    let (restored, events) = load_with_snapshot::<WorldSnapshot>(repo, world_id).await?;
+    match restored {
+        Some(snapshot) => replay(snapshot, &events).map(Some),
+        None if events.is_empty() => Ok(None),
+        None => reconstitute(world_id, &events).map(Some),
+    }
+}
+
+/// Applies stored events on top of an existing `WorldSnapshot` state.
+fn replay(
+    mut snapshot: WorldSnapshot,
+    events: &[StoredEvent],
+) -> Result<WorldSnapshot, DomainError> {
+    for stored in events {
+        let payload = upcasters::registry().upcast(stored)?;
+        let kind: WorldStateEventKind = serde_json::from_value(payload).map_err(|e| {
+            DomainError::Infrastructure(format!("event deserialization failed: {e}"))
+        })?;
         let event = WorldStateEvent {
             metadata: otherworlds_core::event::EventMetadata {
                 event_id: stored.event_id,
@@ -56,6 +91,7 @@ pub(crate) fn reconstitute(
                 correlation_id: stored.correlation_id,
                 causation_id: stored.causation_id,
                 occurred_at: stored.occurred_at,
+                schema_version: upcasters::current_schema_version(&stored.event_type),
             },
             kind,
         };
@@ -64,8 +100,7 @@ pub(crate) fn reconstitute(
     Ok(snapshot)
 }
 
-/// Handles the `ApplyEffect` command: reconstitutes the aggregate, applies
-/// the effect, and persists the resulting events.
+/// Handles the `ApplyEffect` command by asserting its fact key as `true`.
 ///
 /// # Errors
 ///
@@ -77,12 +112,617 @@ pub async fn handle_apply_effect(
     rng: &Mutex<dyn DeterministicRng + Send>,
     repo: &dyn EventRepository,
 ) -> Result<Vec<StoredEvent>, DomainError> {
-    if command.fact_key.trim().is_empty() {
+    let assert_fact = AssertFact {
+        correlation_id: command.correlation_id,
+        causation_id: command.causation_id,
+        world_id: command.world_id,
+        key: command.fact_key.clone(),
+        value: FactValue::Bool(true),
+    };
+    handle_assert_fact(&assert_fact, clock, rng, repo).await
+}
+
+/// Handles the `AssertFact` command: reconstitutes the aggregate, asserts
+/// the fact, and persists the resulting events.
+///
+/// # Errors
+///
+/// Returns `DomainError` if validation fails or event persistence fails.
+#[instrument(skip(clock, rng, repo), fields(world_id = %command.world_id, correlation_id = %command.correlation_id))]
+pub async fn handle_assert_fact(
+    command: &AssertFact,
+    clock: &dyn Clock,
+    rng: &Mutex<dyn DeterministicRng + Send>,
+    repo: &dyn EventRepository,
+) -> Result<Vec<StoredEvent>, DomainError> {
+    if command.key.trim().is_empty() {
         return Err(DomainError::Validation("fact key must not be empty".into()));
     }
 
-    let existing_events = repo.load_events(command.world_id).await?;
-    let mut snapshot = reconstitute(command.world_id, &existing_events)?;
+    let mut snapshot = load(command.world_id, repo)
+        .await?
+        .unwrap_or_else(|| WorldSnapshot::new(command.world_id));
+
+    if snapshot.archived {
+        return Err(DomainError::Validation("world snapshot is archived".into()));
+    }
+
+    {
+        let mut rng_guard = rng
+            .lock()
+            .map_err(|e| DomainError::Infrastructure(format!("RNG mutex poisoned: {e}")))?;
+        snapshot.assert_fact(
+            command.key.clone(),
+            command.value.clone(),
+            command.correlation_id,
+            clock,
+            &mut *rng_guard,
+        );
+    }
+
+    let stored_events: Vec<StoredEvent> = snapshot
+        .uncommitted_events()
+        .iter()
+        .map(|event| to_stored_event(event).caused_by(command.causation_id))
+        .collect();
+
+    repo.append_events(command.world_id, snapshot.version, &stored_events)
+        .await?;
+    save_snapshot_if_due(repo, snapshot, clock).await;
+
+    Ok(stored_events)
+}
+
+/// Handles the `RetractFact` command: reconstitutes the aggregate, retracts
+/// the fact, and persists the resulting events.
+///
+/// # Errors
+///
+/// Returns `DomainError::AggregateNotFound` if the world snapshot does not exist.
+/// Returns `DomainError::Validation` if the world snapshot is archived or the
+/// fact is not asserted.
+#[instrument(skip(clock, rng, repo), fields(world_id = %command.world_id, correlation_id = %command.correlation_id))]
+pub async fn handle_retract_fact(
+    command: &RetractFact,
+    clock: &dyn Clock,
+    rng: &Mutex<dyn DeterministicRng + Send>,
+    repo: &dyn EventRepository,
+) -> Result<Vec<StoredEvent>, DomainError> {
+    let mut snapshot = load(command.world_id, repo)
+        .await?
+        .ok_or(DomainError::AggregateNotFound(command.world_id))?;
+
+    if snapshot.archived {
+        return Err(DomainError::Validation("world snapshot is archived".into()));
+    }
+
+    {
+        let mut rng_guard = rng
+            .lock()
+            .map_err(|e| DomainError::Infrastructure(format!("RNG mutex poisoned: {e}")))?;
+        snapshot.retract_fact(
+            command.key.clone(),
+            command.correlation_id,
+            clock,
+            &mut *rng_guard,
+        )?;
+    }
+
+    let stored_events: Vec<StoredEvent> = snapshot
+        .uncommitted_events()
+        .iter()
+        .map(|event| to_stored_event(event).caused_by(command.causation_id))
+        .collect();
+
+    repo.append_events(command.world_id, snapshot.version, &stored_events)
+        .await?;
+    save_snapshot_if_due(repo, snapshot, clock).await;
+
+    Ok(stored_events)
+}
+
+/// Handles the `SetFlag` command: reconstitutes the aggregate, sets the flag,
+/// and persists the resulting events.
+///
+/// # Errors
+///
+/// Returns `DomainError` if validation fails or event persistence fails.
+#[instrument(skip(clock, rng, repo), fields(world_id = %command.world_id, correlation_id = %command.correlation_id))]
+pub async fn handle_set_flag(
+    command: &SetFlag,
+    clock: &dyn Clock,
+    rng: &Mutex<dyn DeterministicRng + Send>,
+    repo: &dyn EventRepository,
+) -> Result<Vec<StoredEvent>, DomainError> {
+    if command.flag_key.trim().is_empty() {
+        return Err(DomainError::Validation("flag key must not be empty".into()));
+    }
+
+    let mut snapshot = load(command.world_id, repo)
+        .await?
+        .unwrap_or_else(|| WorldSnapshot::new(command.world_id));
+
+    if snapshot.archived {
+        return Err(DomainError::Validation("world snapshot is archived".into()));
+    }
+
+    {
+        let mut rng_guard = rng
+            .lock()
+            .map_err(|e| DomainError::Infrastructure(format!("RNG mutex poisoned: {e}")))?;
+        snapshot.set_flag(
+            command.flag_key.clone(),
+            command.value,
+            command.correlation_id,
+            clock,
+            &mut *rng_guard,
+        );
+    }
+
+    let stored_events: Vec<StoredEvent> = snapshot
+        .uncommitted_events()
+        .iter()
+        .map(|event| to_stored_event(event).caused_by(command.causation_id))
+        .collect();
+
+    repo.append_events(command.world_id, snapshot.version, &stored_events)
+        .await?;
+    save_snapshot_if_due(repo, snapshot, clock).await;
+
+    Ok(stored_events)
+}
+
+/// Handles the `UpdateDisposition` command: reconstitutes the aggregate,
+/// shifts the disposition and lets it ripple to the entity's factions, and
+/// persists the resulting events.
+///
+/// # Errors
+///
+/// Returns `DomainError` if event loading or appending fails.
+#[instrument(skip(clock, rng, repo), fields(world_id = %command.world_id, correlation_id = %command.correlation_id))]
+pub async fn handle_update_disposition(
+    command: &UpdateDisposition,
+    clock: &dyn Clock,
+    rng: &Mutex<dyn DeterministicRng + Send>,
+    repo: &dyn EventRepository,
+) -> Result<Vec<StoredEvent>, DomainError> {
+    let mut snapshot = load(command.world_id, repo)
+        .await?
+        .unwrap_or_else(|| WorldSnapshot::new(command.world_id));
+
+    if snapshot.archived {
+        return Err(DomainError::Validation("world snapshot is archived".into()));
+    }
+
+    {
+        let mut rng_guard = rng
+            .lock()
+            .map_err(|e| DomainError::Infrastructure(format!("RNG mutex poisoned: {e}")))?;
+        snapshot.update_disposition(
+            command.entity_id,
+            command.delta,
+            command.correlation_id,
+            clock,
+            &mut *rng_guard,
+        );
+    }
+
+    let stored_events: Vec<StoredEvent> = snapshot
+        .uncommitted_events()
+        .iter()
+        .map(|event| to_stored_event(event).caused_by(command.causation_id))
+        .collect();
+
+    repo.append_events(command.world_id, snapshot.version, &stored_events)
+        .await?;
+    save_snapshot_if_due(repo, snapshot, clock).await;
+
+    Ok(stored_events)
+}
+
+/// Handles the `SeedDispositions` command: reconstitutes the aggregate,
+/// seeds every entity that has no score yet and adds the memberships that do
+/// not exist yet, and persists the resulting events.
+///
+/// # Errors
+///
+/// Returns `DomainError::Validation` if the world snapshot is archived, an
+/// entity is seeded twice, a score is out of range, or an entity is listed
+/// as its own faction.
+#[instrument(skip(clock, rng, repo), fields(world_id = %command.world_id, correlation_id = %command.correlation_id))]
+pub async fn handle_seed_dispositions(
+    command: &SeedDispositions,
+    clock: &dyn Clock,
+    rng: &Mutex<dyn DeterministicRng + Send>,
+    repo: &dyn EventRepository,
+) -> Result<Vec<StoredEvent>, DomainError> {
+    let mut seen = BTreeSet::new();
+    if let Some(seed) = command
+        .seeds
+        .iter()
+        .find(|seed| !seen.insert(seed.entity_id))
+    {
+        return Err(DomainError::Validation(format!(
+            "entity {} is seeded twice",
+            seed.entity_id
+        )));
+    }
+
+    let mut snapshot = load(command.world_id, repo)
+        .await?
+        .unwrap_or_else(|| WorldSnapshot::new(command.world_id));
+
+    if snapshot.archived {
+        return Err(DomainError::Validation("world snapshot is archived".into()));
+    }
+
+    {
+        let mut rng_guard = rng
+            .lock()
+            .map_err(|e| DomainError::Infrastructure(format!("RNG mutex poisoned: {e}")))?;
+        for seed in &command.seeds {
+            if !snapshot.has_disposition(seed.entity_id) {
+                snapshot.seed_disposition(
+                    seed.entity_id,
+                    seed.score,
+                    command.correlation_id,
+                    clock,
+                    &mut *rng_guard,
+                )?;
+            }
+            for faction_id in &seed.factions {
+                if !snapshot.is_member(seed.entity_id, *faction_id) {
+                    snapshot.join_faction(
+                        seed.entity_id,
+                        *faction_id,
+                        command.correlation_id,
+                        clock,
+                        &mut *rng_guard,
+                    )?;
+                }
+            }
+        }
+    }
+
+    let stored_events: Vec<StoredEvent> = snapshot
+        .uncommitted_events()
+        .iter()
+        .map(to_stored_event)
+        .collect();
+    if stored_events.is_empty() {
+        return Ok(stored_events);
+    }
+
+    repo.append_events(command.world_id, snapshot.version, &stored_events)
+        .await?;
+    save_snapshot_if_due(repo, snapshot, clock).await;
+
+    Ok(stored_events)
+}
+
+/// Handles the `JoinFaction` command: reconstitutes the aggregate, adds the
+/// entity to the faction, and persists the resulting events.
+///
+/// # Errors
+///
+/// Returns `DomainError::Validation` if the world snapshot is archived, or
+/// the entity is the faction itself or already belongs to it.
+#[instrument(skip(clock, rng, repo), fields(world_id = %command.world_id, correlation_id = %command.correlation_id))]
+pub async fn handle_join_faction(
+    command: &JoinFaction,
+    clock: &dyn Clock,
+    rng: &Mutex<dyn DeterministicRng + Send>,
+    repo: &dyn EventRepository,
+) -> Result<Vec<StoredEvent>, DomainError> {
+    let mut snapshot = load(command.world_id, repo)
+        .await?
+        .unwrap_or_else(|| WorldSnapshot::new(command.world_id));
+
+    if snapshot.archived {
+        return Err(DomainError::Validation("world snapshot is archived".into()));
+    }
+
+    {
+        let mut rng_guard = rng
+            .lock()
+            .map_err(|e| DomainError::Infrastructure(format!("RNG mutex poisoned: {e}")))?;
+        snapshot.join_faction(
+            command.entity_id,
+            command.faction_id,
+            command.correlation_id,
+            clock,
+            &mut *rng_guard,
+        )?;
+    }
+
+    let stored_events: Vec<StoredEvent> = snapshot
+        .uncommitted_events()
+        .iter()
+        .map(to_stored_event)
+        .collect();
+
+    repo.append_events(command.world_id, snapshot.version, &stored_events)
+        .await?;
+    save_snapshot_if_due(repo, snapshot, clock).await;
+
+    Ok(stored_events)
+}
+
+/// Handles the `SeedLocations` command: reconstitutes the aggregate, adds
+/// the locations and connections that do not exist yet, places the NPCs and
+/// the party that have no location yet, and persists the resulting events.
+///
+/// # Errors
+///
+/// Returns `DomainError::Validation` if the world snapshot is archived, a
+/// location is seeded twice, an NPC is placed twice, or a location or
+/// connection is invalid.
+#[instrument(skip(clock, rng, repo), fields(world_id = %command.world_id, correlation_id = %command.correlation_id))]
+pub async fn handle_seed_locations(
+    command: &SeedLocations,
+    clock: &dyn Clock,
+    rng: &Mutex<dyn DeterministicRng + Send>,
+    repo: &dyn EventRepository,
+) -> Result<Vec<StoredEvent>, DomainError> {
+    let mut seen = BTreeSet::new();
+    if let Some(seed) = command
+        .locations
+        .iter()
+        .find(|seed| !seen.insert(seed.location_id))
+    {
+        return Err(DomainError::Validation(format!(
+            "location {} is seeded twice",
+            seed.location_id
+        )));
+    }
+    let mut placed = BTreeSet::new();
+    if let Some(npc) = command
+        .locations
+        .iter()
+        .flat_map(|seed| &seed.npcs)
+        .find(|npc| !placed.insert(**npc))
+    {
+        return Err(DomainError::Validation(format!(
+            "NPC {npc} is placed twice"
+        )));
+    }
+
+    let mut snapshot = load(command.world_id, repo)
+        .await?
+        .unwrap_or_else(|| WorldSnapshot::new(command.world_id));
+
+    if snapshot.archived {
+        return Err(DomainError::Validation("world snapshot is archived".into()));
+    }
+
+    {
+        let mut rng_guard = rng
+            .lock()
+            .map_err(|e| DomainError::Infrastructure(format!("RNG mutex poisoned: {e}")))?;
+        let correlation_id = command.correlation_id;
+        for seed in &command.locations {
+            if !snapshot.has_location(seed.location_id) {
+                snapshot.add_location(
+                    seed.location_id,
+                    seed.name.clone(),
+                    correlation_id,
+                    clock,
+                    &mut *rng_guard,
+                )?;
+            }
+        }
+        for seed in &command.connections {
+            if !snapshot.are_connected(seed.location_id, seed.other_location_id) {
+                snapshot.connect_locations(
+                    seed.location_id,
+                    seed.other_location_id,
+                    seed.locked,
+                    correlation_id,
+                    clock,
+                    &mut *rng_guard,
+                )?;
+            }
+        }
+        for seed in &command.locations {
+            for npc in &seed.npcs {
+                if snapshot.npc_location(*npc).is_none() {
+                    snapshot.move_npc(
+                        *npc,
+                        seed.location_id,
+                        correlation_id,
+                        clock,
+                        &mut *rng_guard,
+                    )?;
+                }
+            }
+        }
+        if let Some(location_id) = command.party_location
+            && snapshot.party_location().is_none()
+        {
+            snapshot.move_party(location_id, correlation_id, clock, &mut *rng_guard)?;
+        }
+    }
+
+    let stored_events: Vec<StoredEvent> = snapshot
+        .uncommitted_events()
+        .iter()
+        .map(to_stored_event)
+        .collect();
+    if stored_events.is_empty() {
+        return Ok(stored_events);
+    }
+
+    repo.append_events(command.world_id, snapshot.version, &stored_events)
+        .await?;
+    save_snapshot_if_due(repo, snapshot, clock).await;
+
+    Ok(stored_events)
+}
+
+/// Handles the `SetConnectionLock` command: reconstitutes the aggregate,
+/// locks or unlocks the connection, and persists the resulting events.
+///
+/// # Errors
+///
+/// Returns `DomainError::AggregateNotFound` if the world snapshot does not exist.
+/// Returns `DomainError::Validation` if it is archived, the locations are not
+/// connected, or the connection is already in the requested state.
+#[instrument(skip(clock, rng, repo), fields(world_id = %command.world_id, correlation_id = %command.correlation_id))]
+pub async fn handle_set_connection_lock(
+    command: &SetConnectionLock,
+    clock: &dyn Clock,
+    rng: &Mutex<dyn DeterministicRng + Send>,
+    repo: &dyn EventRepository,
+) -> Result<Vec<StoredEvent>, DomainError> {
+    let mut snapshot = load(command.world_id, repo)
+        .await?
+        .ok_or(DomainError::AggregateNotFound(command.world_id))?;
+
+    if snapshot.archived {
+        return Err(DomainError::Validation("world snapshot is archived".into()));
+    }
+
+    {
+        let mut rng_guard = rng
+            .lock()
+            .map_err(|e| DomainError::Infrastructure(format!("RNG mutex poisoned: {e}")))?;
+        snapshot.set_connection_locked(
+            command.location_id,
+            command.other_location_id,
+            command.locked,
+            command.correlation_id,
+            clock,
+            &mut *rng_guard,
+        )?;
+    }
+
+    let stored_events: Vec<StoredEvent> = snapshot
+        .uncommitted_events()
+        .iter()
+        .map(to_stored_event)
+        .collect();
+
+    repo.append_events(command.world_id, snapshot.version, &stored_events)
+        .await?;
+    save_snapshot_if_due(repo, snapshot, clock).await;
+
+    Ok(stored_events)
+}
+
+/// Handles the `MoveParty` command: reconstitutes the aggregate, moves the
+/// party, and persists the resulting events.
+///
+/// # Errors
+///
+/// Returns `DomainError::AggregateNotFound` if the world snapshot does not exist.
+/// Returns `DomainError::Validation` if it is archived or the party cannot
+/// reach the location.
+#[instrument(skip(clock, rng, repo), fields(world_id = %command.world_id, correlation_id = %command.correlation_id))]
+pub async fn handle_move_party(
+    command: &MoveParty,
+    clock: &dyn Clock,
+    rng: &Mutex<dyn DeterministicRng + Send>,
+    repo: &dyn EventRepository,
+) -> Result<Vec<StoredEvent>, DomainError> {
+    let mut snapshot = load(command.world_id, repo)
+        .await?
+        .ok_or(DomainError::AggregateNotFound(command.world_id))?;
+
+    if snapshot.archived {
+        return Err(DomainError::Validation("world snapshot is archived".into()));
+    }
+
+    {
+        let mut rng_guard = rng
+            .lock()
+            .map_err(|e| DomainError::Infrastructure(format!("RNG mutex poisoned: {e}")))?;
+        snapshot.move_party(
+            command.location_id,
+            command.correlation_id,
+            clock,
+            &mut *rng_guard,
+        )?;
+    }
+
+    let stored_events: Vec<StoredEvent> = snapshot
+        .uncommitted_events()
+        .iter()
+        .map(to_stored_event)
+        .collect();
+
+    repo.append_events(command.world_id, snapshot.version, &stored_events)
+        .await?;
+    save_snapshot_if_due(repo, snapshot, clock).await;
+
+    Ok(stored_events)
+}
+
+/// Handles the `MoveNpc` command: reconstitutes the aggregate, moves the
+/// NPC, and persists the resulting events.
+///
+/// # Errors
+///
+/// Returns `DomainError::AggregateNotFound` if the world snapshot does not exist.
+/// Returns `DomainError::Validation` if it is archived, the location does
+/// not exist, or the NPC is already there.
+#[instrument(skip(clock, rng, repo), fields(world_id = %command.world_id, correlation_id = %command.correlation_id))]
+pub async fn handle_move_npc(
+    command: &MoveNpc,
+    clock: &dyn Clock,
+    rng: &Mutex<dyn DeterministicRng + Send>,
+    repo: &dyn EventRepository,
+) -> Result<Vec<StoredEvent>, DomainError> {
+    let mut snapshot = load(command.world_id, repo)
+        .await?
+        .ok_or(DomainError::AggregateNotFound(command.world_id))?;
+
+    if snapshot.archived {
+        return Err(DomainError::Validation("world snapshot is archived".into()));
+    }
+
+    {
+        let mut rng_guard = rng
+            .lock()
+            .map_err(|e| DomainError::Infrastructure(format!("RNG mutex poisoned: {e}")))?;
+        snapshot.move_npc(
+            command.entity_id,
+            command.location_id,
+            command.correlation_id,
+            clock,
+            &mut *rng_guard,
+        )?;
+    }
+
+    let stored_events: Vec<StoredEvent> = snapshot
+        .uncommitted_events()
+        .iter()
+        .map(to_stored_event)
+        .collect();
+
+    repo.append_events(command.world_id, snapshot.version, &stored_events)
+        .await?;
+    save_snapshot_if_due(repo, snapshot, clock).await;
+
+    Ok(stored_events)
+}
+
+/// Handles the `SetCalendar` command: reconstitutes (or creates) the
+/// aggregate, sets its calendar, and persists the resulting events.
+///
+/// # Errors
+///
+/// Returns `DomainError::Validation` if the world snapshot is archived, the
+/// calendar is invalid, or time has advanced or events are scheduled.
+#[instrument(skip(clock, rng, repo), fields(world_id = %command.world_id, correlation_id = %command.correlation_id))]
+pub async fn handle_set_calendar(
+    command: &SetCalendar,
+    clock: &dyn Clock,
+    rng: &Mutex<dyn DeterministicRng + Send>,
+    repo: &dyn EventRepository,
+) -> Result<Vec<StoredEvent>, DomainError> {
+    let mut snapshot = load(command.world_id, repo)
+        .await?
+        .unwrap_or_else(|| WorldSnapshot::new(command.world_id));
 
     if snapshot.archived {
         return Err(DomainError::Validation("world snapshot is archived".into()));
@@ -92,12 +732,12 @@ pub async fn handle_apply_effect(
         let mut rng_guard = rng
             .lock()
             .map_err(|e| DomainError::Infrastructure(format!("RNG mutex poisoned: {e}")))?;
-        snapshot.apply_effect(
-            command.fact_key.clone(),
+        snapshot.set_calendar(
+            command.calendar.clone(),
             command.correlation_id,
             clock,
             &mut *rng_guard,
-        );
+        )?;
     }
 
     let stored_events: Vec<StoredEvent> = snapshot
@@ -108,29 +748,29 @@ pub async fn handle_apply_effect(
 
     repo.append_events(command.world_id, snapshot.version, &stored_events)
         .await?;
+    save_snapshot_if_due(repo, snapshot, clock).await;
 
     Ok(stored_events)
 }
 
-/// Handles the `SetFlag` command: reconstitutes the aggregate, sets the flag,
-/// and persists the resulting events.
+/// Handles the `ScheduleEvent` command: reconstitutes (or creates) the
+/// aggregate, schedules the effect, and persists the resulting events.
 ///
 /// # Errors
 ///
-/// Returns `DomainError` if validation fails or event persistence fails.
+/// Returns `DomainError::Validation` if the world snapshot is archived, the
+/// time is not a time of its calendar or has already been reached, or the
+/// effect is invalid.
 #[instrument(skip(clock, rng, repo), fields(world_id = %command.world_id, correlation_id = %command.correlation_id))]
-pub async fn handle_set_flag(
-    command: &SetFlag,
+pub async fn handle_schedule_event(
+    command: &ScheduleEvent,
     clock: &dyn Clock,
     rng: &Mutex<dyn DeterministicRng + Send>,
     repo: &dyn EventRepository,
 ) -> Result<Vec<StoredEvent>, DomainError> {
-    if command.flag_key.trim().is_empty() {
-        return Err(DomainError::Validation("flag key must not be empty".into()));
-    }
-
-    let existing_events = repo.load_events(command.world_id).await?;
-    let mut snapshot = reconstitute(command.world_id, &existing_events)?;
+    let mut snapshot = load(command.world_id, repo)
+        .await?
+        .unwrap_or_else(|| WorldSnapshot::new(command.world_id));
 
     if snapshot.archived {
         return Err(DomainError::Validation("world snapshot is archived".into()));
@@ -140,13 +780,13 @@ pub async fn handle_set_flag(
         let mut rng_guard = rng
             .lock()
             .map_err(|e| DomainError::Infrastructure(format!("RNG mutex poisoned: {e}")))?;
-        snapshot.set_flag(
-            command.flag_key.clone(),
-            command.value,
+        snapshot.schedule_event(
+            command.at,
+            command.effect.clone(),
             command.correlation_id,
             clock,
             &mut *rng_guard,
-        );
+        )?;
     }
 
     let stored_events: Vec<StoredEvent> = snapshot
@@ -157,25 +797,29 @@ pub async fn handle_set_flag(
 
     repo.append_events(command.world_id, snapshot.version, &stored_events)
         .await?;
+    save_snapshot_if_due(repo, snapshot, clock).await;
 
     Ok(stored_events)
 }
 
-/// Handles the `UpdateDisposition` command: reconstitutes the aggregate,
-/// updates the disposition, and persists the resulting events.
+/// Handles the `AdvanceTime` command: reconstitutes (or creates) the
+/// aggregate, advances its time, fires the scheduled events reached, and
+/// persists the resulting events.
 ///
 /// # Errors
 ///
-/// Returns `DomainError` if event loading or appending fails.
+/// Returns `DomainError::Validation` if the world snapshot is archived or the
+/// advance is zero or too far.
 #[instrument(skip(clock, rng, repo), fields(world_id = %command.world_id, correlation_id = %command.correlation_id))]
-pub async fn handle_update_disposition(
-    command: &UpdateDisposition,
+pub async fn handle_advance_time(
+    command: &AdvanceTime,
     clock: &dyn Clock,
     rng: &Mutex<dyn DeterministicRng + Send>,
     repo: &dyn EventRepository,
 ) -> Result<Vec<StoredEvent>, DomainError> {
-    let existing_events = repo.load_events(command.world_id).await?;
-    let mut snapshot = reconstitute(command.world_id, &existing_events)?;
+    let mut snapshot = load(command.world_id, repo)
+        .await?
+        .unwrap_or_else(|| WorldSnapshot::new(command.world_id));
 
     if snapshot.archived {
         return Err(DomainError::Validation("world snapshot is archived".into()));
@@ -185,12 +829,12 @@ pub async fn handle_update_disposition(
         let mut rng_guard = rng
             .lock()
             .map_err(|e| DomainError::Infrastructure(format!("RNG mutex poisoned: {e}")))?;
-        snapshot.update_disposition(
-            command.entity_id,
+        snapshot.advance_time(
+            command.hours,
             command.correlation_id,
             clock,
             &mut *rng_guard,
-        );
+        )?;
     }
 
     let stored_events: Vec<StoredEvent> = snapshot
@@ -201,6 +845,7 @@ pub async fn handle_update_disposition(
 
     repo.append_events(command.world_id, snapshot.version, &stored_events)
         .await?;
+    save_snapshot_if_due(repo, snapshot, clock).await;
 
     Ok(stored_events)
 }
@@ -219,11 +864,9 @@ pub async fn handle_archive_world_snapshot(
     rng: &Mutex<dyn DeterministicRng + Send>,
     repo: &dyn EventRepository,
 ) -> Result<Vec<StoredEvent>, DomainError> {
-    let existing_events = repo.load_events(command.world_id).await?;
-    if existing_events.is_empty() {
-        return Err(DomainError::AggregateNotFound(command.world_id));
-    }
-    let mut snapshot = reconstitute(command.world_id, &existing_events)?;
+    let mut snapshot = load(command.world_id, repo)
+        .await?
+        .ok_or(DomainError::AggregateNotFound(command.world_id))?;
 
     {
         let mut rng_guard = rng
@@ -240,6 +883,7 @@ pub async fn handle_archive_world_snapshot(
 
     repo.append_events(command.world_id, snapshot.version, &stored_events)
         .await?;
+    save_snapshot_if_due(repo, snapshot, clock).await;
 
     Ok(stored_events)
 }
@@ -257,14 +901,28 @@ mod tests {
     use otherworlds_core::repository::StoredEvent;
 
     use crate::application::command_handlers::{
-        handle_apply_effect, handle_archive_world_snapshot, handle_set_flag,
+        handle_advance_time, handle_apply_effect, handle_archive_world_snapshot,
+        handle_assert_fact, handle_join_faction, handle_move_party, handle_retract_fact,
+        handle_schedule_event, handle_seed_dispositions, handle_seed_locations, handle_set_flag,
         handle_update_disposition,
     };
-    use crate::domain::commands::{ApplyEffect, ArchiveWorldSnapshot, SetFlag, UpdateDisposition};
-    use crate::domain::events::{WorldFactChanged, WorldSnapshotArchived, WorldStateEventKind};
+    use crate::domain::calendar::GameTime;
+    use crate::domain::commands::{
+        AdvanceTime, ApplyEffect, ArchiveWorldSnapshot, AssertFact, ConnectionSeed,
+        DispositionSeed, JoinFaction, LocationSeed, MoveParty, RetractFact, ScheduleEvent,
+        SeedDispositions, SeedLocations, SetFlag, UpdateDisposition,
+    };
+    use crate::domain::events::{
+        DISPOSITION_SEEDED_EVENT_TYPE, DispositionSeeded, EVENT_SCHEDULED_EVENT_TYPE,
+        EventScheduled, FACT_ASSERTED_EVENT_TYPE, FACT_RETRACTED_EVENT_TYPE,
+        FACTION_JOINED_EVENT_TYPE, FactValue, LOCATION_ADDED_EVENT_TYPE,
+        LOCATIONS_CONNECTED_EVENT_TYPE, NPC_MOVED_EVENT_TYPE, PARTY_MOVED_EVENT_TYPE,
+        SCHEDULED_EVENT_FIRED_EVENT_TYPE, ScheduledEffect, TIME_ADVANCED_EVENT_TYPE,
+        WorldSnapshotArchived, WorldStateEventKind,
+    };
 
     #[tokio::test]
-    async fn test_handle_apply_effect_persists_world_fact_changed_event() {
+    async fn test_handle_apply_effect_asserts_fact_key_as_true() {
         // Arrange
         let world_id = Uuid::new_v4();
         let correlation_id = Uuid::new_v4();
@@ -275,6 +933,7 @@ mod tests {
 
         let command = ApplyEffect {
             correlation_id,
+            causation_id: None,
             world_id,
             fact_key: "quest_complete".to_owned(),
         };
@@ -294,7 +953,7 @@ mod tests {
         assert_eq!(events.len(), 1);
 
         let stored = &events[0];
-        assert_eq!(stored.event_type, "world_state.world_fact_changed");
+        assert_eq!(stored.event_type, FACT_ASSERTED_EVENT_TYPE);
         assert_eq!(stored.aggregate_id, world_id);
         assert_eq!(stored.sequence_number, 1);
         assert_eq!(stored.correlation_id, correlation_id);
@@ -303,14 +962,125 @@ mod tests {
 
         let kind: WorldStateEventKind = serde_json::from_value(stored.payload.clone()).unwrap();
         match kind {
-            WorldStateEventKind::WorldFactChanged(payload) => {
+            WorldStateEventKind::FactAsserted(payload) => {
                 assert_eq!(payload.world_id, world_id);
-                assert_eq!(payload.fact_key, "quest_complete");
+                assert_eq!(payload.key, "quest_complete");
+                assert_eq!(payload.value, FactValue::Bool(true));
+            }
+            other => panic!("expected FactAsserted, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_handle_assert_fact_persists_typed_value() {
+        // Arrange
+        let world_id = Uuid::new_v4();
+        let causation_id = Uuid::new_v4();
+        let clock = FixedClock(Utc.with_ymd_and_hms(2026, 1, 15, 10, 0, 0).unwrap());
+        let rng: Arc<Mutex<dyn DeterministicRng + Send>> = Arc::new(Mutex::new(MockRng));
+        let repo = RecordingEventRepository::new(Ok(Vec::new()));
+
+        let command = AssertFact {
+            correlation_id: Uuid::new_v4(),
+            causation_id: Some(causation_id),
+            world_id,
+            key: "harbour_master".to_owned(),
+            value: FactValue::String("Ilsa".to_owned()),
+        };
+
+        // Act
+        let stored_events = handle_assert_fact(&command, &clock, &*rng, &repo)
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(stored_events.len(), 1);
+        assert_eq!(stored_events[0].causation_id, causation_id);
+        let kind: WorldStateEventKind =
+            serde_json::from_value(stored_events[0].payload.clone()).unwrap();
+        match kind {
+            WorldStateEventKind::FactAsserted(payload) => {
+                assert_eq!(payload.key, "harbour_master");
+                assert_eq!(payload.value, FactValue::String("Ilsa".to_owned()));
             }
-            other => panic!("expected WorldFactChanged, got {other:?}"),
+            other => panic!("expected FactAsserted, got {other:?}"),
         }
     }
 
+    #[tokio::test]
+    async fn test_handle_retract_fact_retracts_legacy_fact() {
+        // Arrange
+        let world_id = Uuid::new_v4();
+        let clock = FixedClock(Utc.with_ymd_and_hms(2026, 1, 15, 10, 0, 0).unwrap());
+        let rng: Arc<Mutex<dyn DeterministicRng + Send>> = Arc::new(Mutex::new(MockRng));
+        let mut existing = archived_world_snapshot_events(world_id);
+        existing.truncate(1);
+        let repo = RecordingEventRepository::new(Ok(existing));
+
+        let command = RetractFact {
+            correlation_id: Uuid::new_v4(),
+            causation_id: None,
+            world_id,
+            key: "quest_complete".to_owned(),
+        };
+
+        // Act
+        let stored_events = handle_retract_fact(&command, &clock, &*rng, &repo)
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(stored_events.len(), 1);
+        assert_eq!(stored_events[0].event_type, FACT_RETRACTED_EVENT_TYPE);
+        assert_eq!(stored_events[0].sequence_number, 2);
+    }
+
+    #[tokio::test]
+    async fn test_handle_retract_fact_rejects_unasserted_fact() {
+        // Arrange
+        let world_id = Uuid::new_v4();
+        let clock = FixedClock(Utc.with_ymd_and_hms(2026, 1, 15, 10, 0, 0).unwrap());
+        let rng: Arc<Mutex<dyn DeterministicRng + Send>> = Arc::new(Mutex::new(MockRng));
+        let mut existing = archived_world_snapshot_events(world_id);
+        existing.truncate(1);
+        let repo = RecordingEventRepository::new(Ok(existing));
+
+        let command = RetractFact {
+            correlation_id: Uuid::new_v4(),
+            causation_id: None,
+            world_id,
+            key: "dragon_slain".to_owned(),
+        };
+
+        // Act
+        let result = handle_retract_fact(&command, &clock, &*rng, &repo).await;
+
+        // Assert
+        assert!(matches!(result, Err(DomainError::Validation(_))));
+        assert!(repo.appended_events().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_handle_retract_fact_returns_not_found_when_no_events() {
+        // Arrange
+        let clock = FixedClock(Utc.with_ymd_and_hms(2026, 1, 15, 10, 0, 0).unwrap());
+        let rng: Arc<Mutex<dyn DeterministicRng + Send>> = Arc::new(Mutex::new(MockRng));
+        let repo = RecordingEventRepository::new(Ok(Vec::new()));
+
+        let command = RetractFact {
+            correlation_id: Uuid::new_v4(),
+            causation_id: None,
+            world_id: Uuid::new_v4(),
+            key: "quest_complete".to_owned(),
+        };
+
+        // Act
+        let result = handle_retract_fact(&command, &clock, &*rng, &repo).await;
+
+        // Assert
+        assert!(matches!(result, Err(DomainError::AggregateNotFound(_))));
+    }
+
     #[tokio::test]
     async fn test_handle_set_flag_persists_flag_set_event() {
         // Arrange
@@ -323,6 +1093,7 @@ mod tests {
 
         let command = SetFlag {
             correlation_id,
+            causation_id: None,
             world_id,
             flag_key: "door_unlocked".to_owned(),
             value: true,
@@ -374,8 +1145,10 @@ mod tests {
 
         let command = UpdateDisposition {
             correlation_id,
+            causation_id: None,
             world_id,
             entity_id,
+            delta: 5,
         };
 
         // Act
@@ -405,11 +1178,146 @@ mod tests {
             WorldStateEventKind::DispositionUpdated(payload) => {
                 assert_eq!(payload.world_id, world_id);
                 assert_eq!(payload.entity_id, entity_id);
+                assert_eq!(payload.delta, 5);
             }
             other => panic!("expected DispositionUpdated, got {other:?}"),
         }
     }
 
+    #[tokio::test]
+    async fn test_handle_seed_dispositions_keeps_existing_scores_and_memberships() {
+        // Arrange
+        let world_id = Uuid::new_v4();
+        let innkeeper_id = Uuid::new_v4();
+        let smuggler_id = Uuid::new_v4();
+        let guild_id = Uuid::new_v4();
+        let clock = FixedClock(Utc.with_ymd_and_hms(2026, 1, 15, 10, 0, 0).unwrap());
+        let rng: Arc<Mutex<dyn DeterministicRng + Send>> = Arc::new(Mutex::new(MockRng));
+        let seeded = StoredEvent {
+            event_id: Uuid::new_v4(),
+            aggregate_id: world_id,
+            event_type: DISPOSITION_SEEDED_EVENT_TYPE.to_owned(),
+            payload: serde_json::to_value(WorldStateEventKind::DispositionSeeded(
+                DispositionSeeded {
+                    world_id,
+                    entity_id: innkeeper_id,
+                    score: 40,
+                },
+            ))
+            .unwrap(),
+            sequence_number: 1,
+            correlation_id: Uuid::new_v4(),
+            causation_id: Uuid::new_v4(),
+            occurred_at: clock.0,
+            schema_version: 1,
+        };
+        let repo = RecordingEventRepository::new(Ok(vec![seeded]));
+
+        let command = SeedDispositions {
+            correlation_id: Uuid::new_v4(),
+            world_id,
+            seeds: vec![
+                DispositionSeed {
+                    entity_id: innkeeper_id,
+                    score: -80,
+                    factions: vec![guild_id],
+                },
+                DispositionSeed {
+                    entity_id: smuggler_id,
+                    score: -40,
+                    factions: Vec::new(),
+                },
+            ],
+        };
+
+        // Act
+        let stored_events = handle_seed_dispositions(&command, &clock, &*rng, &repo)
+            .await
+            .unwrap();
+
+        // Assert — the innkeeper keeps 40 and only joins the guild.
+        let event_types: Vec<&str> = stored_events
+            .iter()
+            .map(|stored| stored.event_type.as_str())
+            .collect();
+        assert_eq!(
+            event_types,
+            vec![FACTION_JOINED_EVENT_TYPE, DISPOSITION_SEEDED_EVENT_TYPE]
+        );
+        let kind: WorldStateEventKind =
+            serde_json::from_value(stored_events[1].payload.clone()).unwrap();
+        match kind {
+            WorldStateEventKind::DispositionSeeded(payload) => {
+                assert_eq!(payload.entity_id, smuggler_id);
+                assert_eq!(payload.score, -40);
+            }
+            other => panic!("expected DispositionSeeded, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_handle_seed_dispositions_rejects_entity_seeded_twice() {
+        // Arrange
+        let entity_id = Uuid::new_v4();
+        let clock = FixedClock(Utc.with_ymd_and_hms(2026, 1, 15, 10, 0, 0).unwrap());
+        let rng: Arc<Mutex<dyn DeterministicRng + Send>> = Arc::new(Mutex::new(MockRng));
+        let repo = RecordingEventRepository::new(Ok(Vec::new()));
+        let seed = DispositionSeed {
+            entity_id,
+            score: 0,
+            factions: Vec::new(),
+        };
+
+        let command = SeedDispositions {
+            correlation_id: Uuid::new_v4(),
+            world_id: Uuid::new_v4(),
+            seeds: vec![seed.clone(), seed],
+        };
+
+        // Act
+        let result = handle_seed_dispositions(&command, &clock, &*rng, &repo).await;
+
+        // Assert
+        assert!(matches!(result, Err(DomainError::Validation(m)) if m.contains("seeded twice")));
+        assert!(repo.appended_events().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_handle_join_faction_persists_faction_joined_event() {
+        // Arrange
+        let world_id = Uuid::new_v4();
+        let entity_id = Uuid::new_v4();
+        let faction_id = Uuid::new_v4();
+        let clock = FixedClock(Utc.with_ymd_and_hms(2026, 1, 15, 10, 0, 0).unwrap());
+        let rng: Arc<Mutex<dyn DeterministicRng + Send>> = Arc::new(Mutex::new(MockRng));
+        let repo = RecordingEventRepository::new(Ok(Vec::new()));
+
+        let command = JoinFaction {
+            correlation_id: Uuid::new_v4(),
+            world_id,
+            entity_id,
+            faction_id,
+        };
+
+        // Act
+        let stored_events = handle_join_faction(&command, &clock, &*rng, &repo)
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(stored_events.len(), 1);
+        assert_eq!(stored_events[0].event_type, FACTION_JOINED_EVENT_TYPE);
+        let kind: WorldStateEventKind =
+            serde_json::from_value(stored_events[0].payload.clone()).unwrap();
+        match kind {
+            WorldStateEventKind::FactionJoined(payload) => {
+                assert_eq!(payload.entity_id, entity_id);
+                assert_eq!(payload.faction_id, faction_id);
+            }
+            other => panic!("expected FactionJoined, got {other:?}"),
+        }
+    }
+
     #[tokio::test]
     async fn test_handle_apply_effect_rejects_empty_fact_key() {
         // Arrange
@@ -419,6 +1327,7 @@ mod tests {
 
         let command = ApplyEffect {
             correlation_id: Uuid::new_v4(),
+            causation_id: None,
             world_id: Uuid::new_v4(),
             fact_key: "  ".to_owned(),
         };
@@ -445,6 +1354,7 @@ mod tests {
 
         let command = SetFlag {
             correlation_id: Uuid::new_v4(),
+            causation_id: None,
             world_id: Uuid::new_v4(),
             flag_key: String::new(),
             value: true,
@@ -471,17 +1381,14 @@ mod tests {
                 event_id: Uuid::new_v4(),
                 aggregate_id: world_id,
                 event_type: "world_state.world_fact_changed".to_owned(),
-                payload: serde_json::to_value(WorldStateEventKind::WorldFactChanged(
-                    WorldFactChanged {
-                        world_id,
-                        fact_key: "quest_complete".to_owned(),
-                    },
-                ))
-                .unwrap(),
+                payload: serde_json::json!({
+                    "WorldFactChanged": { "world_id": world_id, "fact_key": "quest_complete" }
+                }),
                 sequence_number: 1,
                 correlation_id: Uuid::new_v4(),
                 causation_id: Uuid::new_v4(),
                 occurred_at: fixed_now,
+                schema_version: 1,
             },
             StoredEvent {
                 event_id: Uuid::new_v4(),
@@ -495,6 +1402,7 @@ mod tests {
                 correlation_id: Uuid::new_v4(),
                 causation_id: Uuid::new_v4(),
                 occurred_at: fixed_now,
+                schema_version: 1,
             },
         ]
     }
@@ -513,17 +1421,14 @@ mod tests {
             event_id: Uuid::new_v4(),
             aggregate_id: world_id,
             event_type: "world_state.world_fact_changed".to_owned(),
-            payload: serde_json::to_value(WorldStateEventKind::WorldFactChanged(
-                WorldFactChanged {
-                    world_id,
-                    fact_key: "quest_complete".to_owned(),
-                },
-            ))
-            .unwrap(),
+            payload: serde_json::json!({
+                "WorldFactChanged": { "world_id": world_id, "fact_key": "quest_complete" }
+            }),
             sequence_number: 1,
             correlation_id: Uuid::new_v4(),
             causation_id: Uuid::new_v4(),
             occurred_at: fixed_now,
+            schema_version: 1,
         }];
         let repo = RecordingEventRepository::new(Ok(existing));
 
@@ -612,6 +1517,7 @@ mod tests {
 
         let command = ApplyEffect {
             correlation_id: Uuid::new_v4(),
+            causation_id: None,
             world_id,
             fact_key: "quest_complete".to_owned(),
         };
@@ -639,6 +1545,7 @@ mod tests {
 
         let command = SetFlag {
             correlation_id: Uuid::new_v4(),
+            causation_id: None,
             world_id,
             flag_key: "door_unlocked".to_owned(),
             value: true,
@@ -667,8 +1574,10 @@ mod tests {
 
         let command = UpdateDisposition {
             correlation_id: Uuid::new_v4(),
+            causation_id: None,
             world_id,
             entity_id: Uuid::new_v4(),
+            delta: -5,
         };
 
         // Act
@@ -683,4 +1592,202 @@ mod tests {
             other => panic!("expected Validation, got {other:?}"),
         }
     }
+
+    #[tokio::test]
+    async fn test_handle_seed_locations_builds_the_graph_and_places_everyone() {
+        // Arrange
+        let world_id = Uuid::new_v4();
+        let market_id = Uuid::new_v4();
+        let dock_id = Uuid::new_v4();
+        let smuggler_id = Uuid::new_v4();
+        let clock = FixedClock(Utc.with_ymd_and_hms(2026, 1, 15, 10, 0, 0).unwrap());
+        let rng: Arc<Mutex<dyn DeterministicRng + Send>> = Arc::new(Mutex::new(MockRng));
+        let repo = RecordingEventRepository::new(Ok(vec![]));
+
+        let command = SeedLocations {
+            correlation_id: Uuid::new_v4(),
+            world_id,
+            locations: vec![
+                LocationSeed {
+                    location_id: market_id,
+                    name: "Market".to_owned(),
+                    npcs: Vec::new(),
+                },
+                LocationSeed {
+                    location_id: dock_id,
+                    name: "Dock".to_owned(),
+                    npcs: vec![smuggler_id],
+                },
+            ],
+            connections: vec![ConnectionSeed {
+                location_id: market_id,
+                other_location_id: dock_id,
+                locked: false,
+            }],
+            party_location: Some(market_id),
+        };
+
+        // Act
+        let stored_events = handle_seed_locations(&command, &clock, &*rng, &repo)
+            .await
+            .unwrap();
+
+        // Assert
+        let event_types: Vec<&str> = stored_events
+            .iter()
+            .map(|stored| stored.event_type.as_str())
+            .collect();
+        assert_eq!(
+            event_types,
+            vec![
+                LOCATION_ADDED_EVENT_TYPE,
+                LOCATION_ADDED_EVENT_TYPE,
+                LOCATIONS_CONNECTED_EVENT_TYPE,
+                NPC_MOVED_EVENT_TYPE,
+                PARTY_MOVED_EVENT_TYPE,
+            ]
+        );
+        assert_eq!(repo.appended_events()[0].2.len(), 5);
+    }
+
+    #[tokio::test]
+    async fn test_handle_seed_locations_rejects_an_npc_placed_twice() {
+        // Arrange
+        let smuggler_id = Uuid::new_v4();
+        let clock = FixedClock(Utc.with_ymd_and_hms(2026, 1, 15, 10, 0, 0).unwrap());
+        let rng: Arc<Mutex<dyn DeterministicRng + Send>> = Arc::new(Mutex::new(MockRng));
+        let repo = RecordingEventRepository::new(Ok(vec![]));
+        let location = |name: &str| LocationSeed {
+            location_id: Uuid::new_v4(),
+            name: name.to_owned(),
+            npcs: vec![smuggler_id],
+        };
+
+        let command = SeedLocations {
+            correlation_id: Uuid::new_v4(),
+            world_id: Uuid::new_v4(),
+            locations: vec![location("Market"), location("Dock")],
+            connections: Vec::new(),
+            party_location: None,
+        };
+
+        // Act
+        let result = handle_seed_locations(&command, &clock, &*rng, &repo).await;
+
+        // Assert
+        assert!(matches!(
+            result,
+            Err(DomainError::Validation(m)) if m.contains("placed twice")
+        ));
+        assert!(repo.appended_events().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_handle_move_party_returns_not_found_for_unknown_world() {
+        // Arrange
+        let world_id = Uuid::new_v4();
+        let clock = FixedClock(Utc.with_ymd_and_hms(2026, 1, 15, 10, 0, 0).unwrap());
+        let rng: Arc<Mutex<dyn DeterministicRng + Send>> = Arc::new(Mutex::new(MockRng));
+        let repo = RecordingEventRepository::new(Ok(vec![]));
+
+        let command = MoveParty {
+            correlation_id: Uuid::new_v4(),
+            world_id,
+            location_id: Uuid::new_v4(),
+        };
+
+        // Act
+        let result = handle_move_party(&command, &clock, &*rng, &repo).await;
+
+        // Assert
+        assert!(matches!(result, Err(DomainError::AggregateNotFound(id)) if id == world_id));
+    }
+
+    #[tokio::test]
+    async fn test_handle_advance_time_fires_recorded_schedule() {
+        // Arrange
+        let world_id = Uuid::new_v4();
+        let schedule_id = Uuid::new_v4();
+        let clock = FixedClock(Utc.with_ymd_and_hms(2026, 1, 15, 10, 0, 0).unwrap());
+        let rng: Arc<Mutex<dyn DeterministicRng + Send>> = Arc::new(Mutex::new(MockRng));
+        let scheduled = StoredEvent {
+            event_id: Uuid::new_v4(),
+            aggregate_id: world_id,
+            event_type: EVENT_SCHEDULED_EVENT_TYPE.to_owned(),
+            payload: serde_json::to_value(WorldStateEventKind::EventScheduled(EventScheduled {
+                world_id,
+                schedule_id,
+                at: 6,
+                effect: ScheduledEffect::SetFlag {
+                    flag_key: "gates_closed".to_owned(),
+                    value: true,
+                },
+            }))
+            .unwrap(),
+            sequence_number: 1,
+            correlation_id: Uuid::new_v4(),
+            causation_id: Uuid::new_v4(),
+            occurred_at: clock.0,
+            schema_version: 1,
+        };
+        let repo = RecordingEventRepository::new(Ok(vec![scheduled]));
+
+        let command = AdvanceTime {
+            correlation_id: Uuid::new_v4(),
+            world_id,
+            hours: 6,
+        };
+
+        // Act
+        let stored_events = handle_advance_time(&command, &clock, &*rng, &repo)
+            .await
+            .unwrap();
+
+        // Assert
+        let event_types: Vec<&str> = stored_events
+            .iter()
+            .map(|stored| stored.event_type.as_str())
+            .collect();
+        assert_eq!(
+            event_types,
+            vec![
+                TIME_ADVANCED_EVENT_TYPE,
+                SCHEDULED_EVENT_FIRED_EVENT_TYPE,
+                "world_state.flag_set",
+            ]
+        );
+        assert_eq!(
+            stored_events[1].payload["ScheduledEventFired"]["schedule_id"],
+            schedule_id.to_string()
+        );
+        assert_eq!(stored_events[0].sequence_number, 2);
+    }
+
+    #[tokio::test]
+    async fn test_handle_schedule_event_rejects_hours_past_the_day() {
+        // Arrange
+        let clock = FixedClock(Utc.with_ymd_and_hms(2026, 1, 15, 10, 0, 0).unwrap());
+        let rng: Arc<Mutex<dyn DeterministicRng + Send>> = Arc::new(Mutex::new(MockRng));
+        let repo = RecordingEventRepository::new(Ok(vec![]));
+
+        let command = ScheduleEvent {
+            correlation_id: Uuid::new_v4(),
+            world_id: Uuid::new_v4(),
+            at: GameTime { day: 2, hour: 24 },
+            effect: ScheduledEffect::SetFlag {
+                flag_key: "gates_closed".to_owned(),
+                value: true,
+            },
+        };
+
+        // Act
+        let result = handle_schedule_event(&command, &clock, &*rng, &repo).await;
+
+        // Assert
+        assert!(matches!(
+            result,
+            Err(DomainError::Validation(m)) if m.contains("24-hour day")
+        ));
+        assert!(repo.appended_events().is_empty());
+    }
 }
@@ -0,0 +1,242 @@
+//! Read-model projections for the World State context.
+//!
+//! Maintains one `WorldSnapshotView` per world snapshot and one
+//! `WorldSnapshotSummary` per non-archived world snapshot, plus one
+//! `WorldLocationsView` per world snapshot, rebuilt from the aggregate
+//! whenever its stream changes.
+
+use async_trait::async_trait;
+use otherworlds_core::error::DomainError;
+use otherworlds_core::projection::{Projection, ReadModelStore, put_document};
+use otherworlds_core::repository::EventRepository;
+use uuid::Uuid;
+
+use crate::application::command_handlers;
+use crate::application::query_handlers::{
+    ConnectionView, DispositionView, LocationView, ScheduledEventView, WorldLocationsView,
+    WorldSnapshotSummary, WorldSnapshotView, WorldTimeView,
+};
+use crate::domain::aggregates::WorldSnapshot;
+use crate::domain::disposition::DispositionBand;
+use crate::domain::events::{
+    CALENDAR_SET_EVENT_TYPE, CONNECTION_LOCKED_EVENT_TYPE, CONNECTION_UNLOCKED_EVENT_TYPE,
+    DISPOSITION_SEEDED_EVENT_TYPE, DISPOSITION_UPDATED_EVENT_TYPE, EVENT_SCHEDULED_EVENT_TYPE,
+    FACT_ASSERTED_EVENT_TYPE, FACT_RETRACTED_EVENT_TYPE, FACTION_JOINED_EVENT_TYPE,
+    LEGACY_FACT_CHANGED_EVENT_TYPE, LOCATION_ADDED_EVENT_TYPE, LOCATIONS_CONNECTED_EVENT_TYPE,
+    NPC_MOVED_EVENT_TYPE, PARTY_MOVED_EVENT_TYPE, SCHEDULED_EVENT_FIRED_EVENT_TYPE,
+    TIME_ADVANCED_EVENT_TYPE,
+};
+
+/// Read-model collection holding one `WorldSnapshotView` per world snapshot.
+pub(crate) const WORLD_SNAPSHOT_VIEWS: &str = "world_snapshot_views";
+
+/// Read-model collection holding one `WorldLocationsView` per world snapshot.
+pub(crate) const WORLD_LOCATION_VIEWS: &str = "world_location_views";
+
+/// Read-model collection holding one `WorldSnapshotSummary` per listed world snapshot.
+pub(crate) const WORLD_SNAPSHOT_SUMMARIES: &str = "world_snapshot_summaries";
+
+/// Event types used by the World State context.
+const EVENT_TYPES: &[&str] = &[
+    FACT_ASSERTED_EVENT_TYPE,
+    FACT_RETRACTED_EVENT_TYPE,
+    LEGACY_FACT_CHANGED_EVENT_TYPE,
+    "world_state.flag_set",
+    DISPOSITION_UPDATED_EVENT_TYPE,
+    DISPOSITION_SEEDED_EVENT_TYPE,
+    FACTION_JOINED_EVENT_TYPE,
+    LOCATION_ADDED_EVENT_TYPE,
+    LOCATIONS_CONNECTED_EVENT_TYPE,
+    CONNECTION_LOCKED_EVENT_TYPE,
+    CONNECTION_UNLOCKED_EVENT_TYPE,
+    PARTY_MOVED_EVENT_TYPE,
+    NPC_MOVED_EVENT_TYPE,
+    CALENDAR_SET_EVENT_TYPE,
+    TIME_ADVANCED_EVENT_TYPE,
+    EVENT_SCHEDULED_EVENT_TYPE,
+    SCHEDULED_EVENT_FIRED_EVENT_TYPE,
+    "world_state.world_snapshot_archived",
+];
+
+/// Projects world snapshot streams into the world snapshot view and summary tables.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct WorldSnapshotProjection;
+
+#[async_trait]
+impl Projection for WorldSnapshotProjection {
+    fn name(&self) -> &'static str {
+        "world_state.world_snapshots"
+    }
+
+    // v2: facts carry values; dispositions, time and schedule are listed.
+    fn version(&self) -> i32 {
+        2
+    }
+
+    fn event_types(&self) -> &'static [&'static str] {
+        EVENT_TYPES
+    }
+
+    async fn project(
+        &self,
+        aggregate_id: Uuid,
+        repo: &dyn EventRepository,
+        store: &dyn ReadModelStore,
+    ) -> Result<(), DomainError> {
+        let Some(snapshot) = command_handlers::load(aggregate_id, repo).await? else {
+            store.delete(WORLD_SNAPSHOT_VIEWS, aggregate_id).await?;
+            return store.delete(WORLD_SNAPSHOT_SUMMARIES, aggregate_id).await;
+        };
+
+        put_document(store, WORLD_SNAPSHOT_VIEWS, aggregate_id, &view(&snapshot)).await?;
+        if snapshot.archived {
+            store.delete(WORLD_SNAPSHOT_SUMMARIES, aggregate_id).await
+        } else {
+            put_document(
+                store,
+                WORLD_SNAPSHOT_SUMMARIES,
+                aggregate_id,
+                &summary(&snapshot),
+            )
+            .await
+        }
+    }
+
+    async fn reset(&self, store: &dyn ReadModelStore) -> Result<(), DomainError> {
+        store.clear(WORLD_SNAPSHOT_VIEWS).await?;
+        store.clear(WORLD_SNAPSHOT_SUMMARIES).await
+    }
+}
+
+fn view(snapshot: &WorldSnapshot) -> WorldSnapshotView {
+    WorldSnapshotView {
+        world_id: snapshot.id,
+        facts: snapshot.facts.clone(),
+        flags: snapshot.flags.clone(),
+        dispositions: snapshot
+            .dispositions
+            .iter()
+            .map(|(entity_id, score)| {
+                let view = DispositionView {
+                    score: *score,
+                    band: DispositionBand::of(*score),
+                };
+                (*entity_id, view)
+            })
+            .collect(),
+        factions: snapshot
+            .factions
+            .iter()
+            .map(|(faction_id, members)| (*faction_id, members.iter().copied().collect()))
+            .collect(),
+        time: time_view(snapshot),
+        schedule: schedule_view(snapshot),
+        version: snapshot.version,
+    }
+}
+
+fn time_view(snapshot: &WorldSnapshot) -> WorldTimeView {
+    let now = snapshot.calendar.time_of(snapshot.elapsed_hours);
+    WorldTimeView {
+        elapsed_hours: snapshot.elapsed_hours,
+        now,
+        date: snapshot.calendar.date_of(now.day),
+        calendar: snapshot.calendar.clone(),
+    }
+}
+
+fn schedule_view(snapshot: &WorldSnapshot) -> Vec<ScheduledEventView> {
+    let mut schedule: Vec<_> = snapshot.schedule.iter().collect();
+    schedule.sort_by_key(|scheduled| scheduled.at);
+    schedule
+        .into_iter()
+        .map(|scheduled| ScheduledEventView {
+            schedule_id: scheduled.schedule_id,
+            at: snapshot.calendar.time_of(scheduled.at),
+            effect: scheduled.effect.clone(),
+        })
+        .collect()
+}
+
+/// Projects world snapshot streams into the world location view table.
+///
+/// Every world has a location graph, empty until locations are added, so
+/// this follows the same events as `WorldSnapshotProjection`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct WorldLocationProjection;
+
+#[async_trait]
+impl Projection for WorldLocationProjection {
+    fn name(&self) -> &'static str {
+        "world_state.world_locations"
+    }
+
+    fn event_types(&self) -> &'static [&'static str] {
+        EVENT_TYPES
+    }
+
+    async fn project(
+        &self,
+        aggregate_id: Uuid,
+        repo: &dyn EventRepository,
+        store: &dyn ReadModelStore,
+    ) -> Result<(), DomainError> {
+        let Some(snapshot) = command_handlers::load(aggregate_id, repo).await? else {
+            return store.delete(WORLD_LOCATION_VIEWS, aggregate_id).await;
+        };
+
+        put_document(
+            store,
+            WORLD_LOCATION_VIEWS,
+            aggregate_id,
+            &locations_view(&snapshot),
+        )
+        .await
+    }
+
+    async fn reset(&self, store: &dyn ReadModelStore) -> Result<(), DomainError> {
+        store.clear(WORLD_LOCATION_VIEWS).await
+    }
+}
+
+fn locations_view(snapshot: &WorldSnapshot) -> WorldLocationsView {
+    let locations = snapshot
+        .locations
+        .iter()
+        .map(|(location_id, name)| LocationView {
+            location_id: *location_id,
+            name: name.clone(),
+            connections: snapshot
+                .connections
+                .get(location_id)
+                .into_iter()
+                .flatten()
+                .map(|(neighbour_id, locked)| ConnectionView {
+                    location_id: *neighbour_id,
+                    locked: *locked,
+                })
+                .collect(),
+            npcs: snapshot
+                .npc_locations
+                .iter()
+                .filter(|(_, at)| *at == location_id)
+                .map(|(entity_id, _)| *entity_id)
+                .collect(),
+        })
+        .collect();
+    WorldLocationsView {
+        world_id: snapshot.id,
+        locations,
+        party_location: snapshot.party_location,
+        version: snapshot.version,
+    }
+}
+
+fn summary(snapshot: &WorldSnapshot) -> WorldSnapshotSummary {
+    WorldSnapshotSummary {
+        world_id: snapshot.id,
+        fact_count: snapshot.facts.len(),
+        flag_count: snapshot.flags.len(),
+        version: snapshot.version,
+    }
+}
@@ -1,42 +1,120 @@
 //! Query handlers for the World State context.
 //!
-//! This module contains query handlers that reconstitute aggregates
-//! from stored events and return read-only view DTOs.
+//! This module contains query handlers that bring the world snapshot and
+//! world location projections up to date and return read-only view DTOs from
+//! their read-model tables.
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 use otherworlds_core::error::DomainError;
+use otherworlds_core::projection::{
+    ProjectionRunner, ReadModelStore, get_document, list_documents,
+};
 use otherworlds_core::repository::EventRepository;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
 use crate::application::command_handlers;
+use crate::application::projections::{
+    WORLD_LOCATION_VIEWS, WORLD_SNAPSHOT_SUMMARIES, WORLD_SNAPSHOT_VIEWS, WorldLocationProjection,
+    WorldSnapshotProjection,
+};
+use crate::domain::calendar::{Calendar, CalendarDate, GameTime};
+use crate::domain::condition::Condition;
+use crate::domain::disposition::DispositionBand;
+use crate::domain::events::{FactValue, ScheduledEffect};
+
+/// An entity's disposition score and the band it falls in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DispositionView {
+    /// The score, between −100 and 100.
+    pub score: i32,
+    /// The named band the score falls in.
+    pub band: DispositionBand,
+}
+
+/// A world's current in-game time.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct WorldTimeView {
+    /// Hours since the first hour of day 1.
+    pub elapsed_hours: u64,
+    /// The current day and hour.
+    pub now: GameTime,
+    /// The current date, when the calendar names months.
+    pub date: Option<CalendarDate>,
+    /// The calendar time is read with.
+    pub calendar: Calendar,
+}
+
+/// An effect waiting for world time to reach it.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ScheduledEventView {
+    /// The scheduled event identifier.
+    pub schedule_id: Uuid,
+    /// When the event is due.
+    pub at: GameTime,
+    /// What happens when it fires.
+    pub effect: ScheduledEffect,
+}
 
 /// Read-only view of a world snapshot aggregate.
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct WorldSnapshotView {
     /// The world snapshot identifier.
     pub world_id: Uuid,
-    /// Fact keys that have been applied.
-    pub facts: Vec<String>,
+    /// World facts by key.
+    pub facts: BTreeMap<String, FactValue>,
     /// Boolean flags set in the world.
     pub flags: HashMap<String, bool>,
-    /// Entity IDs whose dispositions have been updated.
-    pub disposition_entity_ids: Vec<Uuid>,
+    /// Disposition scores by entity, for every entity seeded or shifted.
+    pub dispositions: BTreeMap<Uuid, DispositionView>,
+    /// Members of each faction, by faction entity.
+    pub factions: BTreeMap<Uuid, Vec<Uuid>>,
+    /// The current in-game time.
+    pub time: WorldTimeView,
+    /// Events waiting to fire, in the order they will fire.
+    pub schedule: Vec<ScheduledEventView>,
     /// Current version (event count).
     pub version: i64,
 }
 
-/// Event types used by the World State context.
-const EVENT_TYPES: &[&str] = &[
-    "world_state.world_fact_changed",
-    "world_state.flag_set",
-    "world_state.disposition_updated",
-    "world_state.world_snapshot_archived",
-];
+/// A connection from a location to a neighbouring one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConnectionView {
+    /// The neighbouring location.
+    pub location_id: Uuid,
+    /// Whether the connection is locked.
+    pub locked: bool,
+}
+
+/// A location with its connections and the NPCs there.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct LocationView {
+    /// The location identifier.
+    pub location_id: Uuid,
+    /// The location's display name.
+    pub name: String,
+    /// Connections to neighbouring locations.
+    pub connections: Vec<ConnectionView>,
+    /// The NPCs currently at the location.
+    pub npcs: Vec<Uuid>,
+}
+
+/// Read-only view of a world's location graph.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct WorldLocationsView {
+    /// The world snapshot identifier.
+    pub world_id: Uuid,
+    /// Every location in the world.
+    pub locations: Vec<LocationView>,
+    /// The party's location, once placed.
+    pub party_location: Option<Uuid>,
+    /// Current version (event count).
+    pub version: i64,
+}
 
 /// Summary view for listing world snapshots.
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct WorldSnapshotSummary {
     /// The world snapshot identifier.
     pub world_id: Uuid,
@@ -48,71 +126,102 @@ pub struct WorldSnapshotSummary {
     pub version: i64,
 }
 
-/// Lists all world snapshots.
+/// Lists all world snapshots that have not been archived.
 ///
 /// # Errors
 ///
-/// Returns `DomainError::Infrastructure` if querying or deserialization fails.
+/// Returns `DomainError::Infrastructure` if catching up the projection or
+/// reading the read model fails.
 pub async fn list_world_snapshots(
     repo: &dyn EventRepository,
+    read_models: &dyn ReadModelStore,
 ) -> Result<Vec<WorldSnapshotSummary>, DomainError> {
-    let ids = repo.list_aggregate_ids(EVENT_TYPES).await?;
-    let mut summaries = Vec::with_capacity(ids.len());
-    for id in ids {
-        let stored_events = repo.load_events(id).await?;
-        if stored_events.is_empty() {
-            continue;
-        }
-        let snapshot = command_handlers::reconstitute(id, &stored_events)?;
-        if snapshot.archived {
-            continue;
-        }
-        summaries.push(WorldSnapshotSummary {
-            world_id: id,
-            fact_count: snapshot.facts.len(),
-            flag_count: snapshot.flags.len(),
-            version: snapshot.version,
-        });
-    }
-    Ok(summaries)
+    ProjectionRunner::default()
+        .catch_up(&WorldSnapshotProjection, repo, read_models)
+        .await?;
+    list_documents(read_models, WORLD_SNAPSHOT_SUMMARIES).await
 }
 
 /// Retrieves a world snapshot by its aggregate ID.
 ///
 /// # Errors
 ///
-/// Returns `DomainError::AggregateNotFound` if no events exist for the ID.
-/// Returns `DomainError::Infrastructure` if event deserialization fails.
+/// Returns `DomainError::AggregateNotFound` if the world snapshot has no view.
+/// Returns `DomainError::Infrastructure` if catching up the projection or
+/// reading the read model fails.
 pub async fn get_world_snapshot_by_id(
     world_id: Uuid,
     repo: &dyn EventRepository,
+    read_models: &dyn ReadModelStore,
 ) -> Result<WorldSnapshotView, DomainError> {
-    let stored_events = repo.load_events(world_id).await?;
-    if stored_events.is_empty() {
-        return Err(DomainError::AggregateNotFound(world_id));
+    ProjectionRunner::default()
+        .catch_up(&WorldSnapshotProjection, repo, read_models)
+        .await?;
+    get_document(read_models, WORLD_SNAPSHOT_VIEWS, world_id)
+        .await?
+        .ok_or(DomainError::AggregateNotFound(world_id))
+}
+
+/// Retrieves the location graph of a world snapshot.
+///
+/// # Errors
+///
+/// Returns `DomainError::AggregateNotFound` if the world snapshot has no view.
+/// Returns `DomainError::Infrastructure` if catching up the projection or
+/// reading the read model fails.
+pub async fn get_world_locations(
+    world_id: Uuid,
+    repo: &dyn EventRepository,
+    read_models: &dyn ReadModelStore,
+) -> Result<WorldLocationsView, DomainError> {
+    ProjectionRunner::default()
+        .catch_up(&WorldLocationProjection, repo, read_models)
+        .await?;
+    get_document(read_models, WORLD_LOCATION_VIEWS, world_id)
+        .await?
+        .ok_or(DomainError::AggregateNotFound(world_id))
+}
+
+/// Evaluates a condition against the current state of a world snapshot,
+/// resolving entity names through `entities`.
+///
+/// # Errors
+///
+/// Returns `DomainError::AggregateNotFound` if the world snapshot does not
+/// exist, and `DomainError::Validation` if it is archived, an entity name is
+/// unknown, or a fact is compared with a value of the wrong kind.
+pub async fn evaluate_condition(
+    world_id: Uuid,
+    condition: &Condition,
+    entities: &BTreeMap<String, Uuid>,
+    repo: &dyn EventRepository,
+) -> Result<bool, DomainError> {
+    let snapshot = command_handlers::load(world_id, repo)
+        .await?
+        .ok_or(DomainError::AggregateNotFound(world_id))?;
+    if snapshot.archived {
+        return Err(DomainError::Validation("world snapshot is archived".into()));
     }
-    let snapshot = command_handlers::reconstitute(world_id, &stored_events)?;
-    Ok(WorldSnapshotView {
-        world_id,
-        facts: snapshot.facts.clone(),
-        flags: snapshot.flags.clone(),
-        disposition_entity_ids: snapshot.disposition_entity_ids.clone(),
-        version: snapshot.version,
-    })
+    Ok(condition.evaluate(&snapshot, entities)?)
 }
 
 #[cfg(test)]
 mod tests {
+    use std::collections::BTreeMap;
+
     use chrono::{TimeZone, Utc};
     use otherworlds_core::error::DomainError;
     use otherworlds_core::repository::StoredEvent;
     use uuid::Uuid;
 
-    use crate::application::query_handlers::{get_world_snapshot_by_id, list_world_snapshots};
-    use crate::domain::events::{
-        FlagSet, WorldFactChanged, WorldSnapshotArchived, WorldStateEventKind,
+    use crate::application::query_handlers::{
+        evaluate_condition, get_world_snapshot_by_id, list_world_snapshots,
+    };
+    use crate::domain::condition::Condition;
+    use crate::domain::events::{FactValue, FlagSet, WorldSnapshotArchived, WorldStateEventKind};
+    use otherworlds_test_support::{
+        EmptyEventRepository, InMemoryReadModelStore, RecordingEventRepository,
     };
-    use otherworlds_test_support::{EmptyEventRepository, RecordingEventRepository};
 
     #[tokio::test]
     async fn test_get_world_snapshot_by_id_returns_view_with_state() {
@@ -125,17 +234,14 @@ mod tests {
                 event_id: Uuid::new_v4(),
                 aggregate_id: world_id,
                 event_type: "world_state.world_fact_changed".to_owned(),
-                payload: serde_json::to_value(WorldStateEventKind::WorldFactChanged(
-                    WorldFactChanged {
-                        world_id,
-                        fact_key: "quest_complete".to_owned(),
-                    },
-                ))
-                .unwrap(),
+                payload: serde_json::json!({
+                    "WorldFactChanged": { "world_id": world_id, "fact_key": "quest_complete" }
+                }),
                 sequence_number: 1,
                 correlation_id: Uuid::new_v4(),
                 causation_id: Uuid::new_v4(),
                 occurred_at: fixed_now,
+                schema_version: 1,
             },
             StoredEvent {
                 event_id: Uuid::new_v4(),
@@ -151,18 +257,23 @@ mod tests {
                 correlation_id: Uuid::new_v4(),
                 causation_id: Uuid::new_v4(),
                 occurred_at: fixed_now,
+                schema_version: 1,
             },
         ];
         let repo = RecordingEventRepository::new(Ok(events));
 
         // Act
-        let view = get_world_snapshot_by_id(world_id, &repo).await.unwrap();
+        let view = get_world_snapshot_by_id(world_id, &repo, &InMemoryReadModelStore::new())
+            .await
+            .unwrap();
 
         // Assert
         assert_eq!(view.world_id, world_id);
-        assert_eq!(view.facts, vec!["quest_complete".to_owned()]);
+        assert_eq!(view.facts.len(), 1);
+        assert_eq!(view.facts["quest_complete"], FactValue::Bool(true));
         assert_eq!(view.flags.get("door_unlocked"), Some(&true));
-        assert!(view.disposition_entity_ids.is_empty());
+        assert!(view.dispositions.is_empty());
+        assert!(view.factions.is_empty());
         assert_eq!(view.version, 2);
     }
 
@@ -173,7 +284,8 @@ mod tests {
         let repo = EmptyEventRepository;
 
         // Act
-        let result = get_world_snapshot_by_id(world_id, &repo).await;
+        let result =
+            get_world_snapshot_by_id(world_id, &repo, &InMemoryReadModelStore::new()).await;
 
         // Assert
         assert!(result.is_err());
@@ -187,7 +299,9 @@ mod tests {
     async fn test_list_world_snapshots_returns_empty_when_no_aggregates() {
         let repo = EmptyEventRepository;
 
-        let result = list_world_snapshots(&repo).await.unwrap();
+        let result = list_world_snapshots(&repo, &InMemoryReadModelStore::new())
+            .await
+            .unwrap();
 
         assert!(result.is_empty());
     }
@@ -201,21 +315,20 @@ mod tests {
             event_id: Uuid::new_v4(),
             aggregate_id: world_id,
             event_type: "world_state.world_fact_changed".to_owned(),
-            payload: serde_json::to_value(WorldStateEventKind::WorldFactChanged(
-                WorldFactChanged {
-                    world_id,
-                    fact_key: "quest_complete".to_owned(),
-                },
-            ))
-            .unwrap(),
+            payload: serde_json::json!({
+                "WorldFactChanged": { "world_id": world_id, "fact_key": "quest_complete" }
+            }),
             sequence_number: 1,
             correlation_id: Uuid::new_v4(),
             causation_id: Uuid::new_v4(),
             occurred_at: fixed_now,
+            schema_version: 1,
         }];
         let repo = RecordingEventRepository::with_aggregate_ids(Ok(events), vec![world_id]);
 
-        let result = list_world_snapshots(&repo).await.unwrap();
+        let result = list_world_snapshots(&repo, &InMemoryReadModelStore::new())
+            .await
+            .unwrap();
 
         assert_eq!(result.len(), 1);
         assert_eq!(result[0].world_id, world_id);
@@ -234,17 +347,14 @@ mod tests {
                 event_id: Uuid::new_v4(),
                 aggregate_id: world_id,
                 event_type: "world_state.world_fact_changed".to_owned(),
-                payload: serde_json::to_value(WorldStateEventKind::WorldFactChanged(
-                    WorldFactChanged {
-                        world_id,
-                        fact_key: "quest_complete".to_owned(),
-                    },
-                ))
-                .unwrap(),
+                payload: serde_json::json!({
+                    "WorldFactChanged": { "world_id": world_id, "fact_key": "quest_complete" }
+                }),
                 sequence_number: 1,
                 correlation_id: Uuid::new_v4(),
                 causation_id: Uuid::new_v4(),
                 occurred_at: fixed_now,
+                schema_version: 1,
             },
             StoredEvent {
                 event_id: Uuid::new_v4(),
@@ -258,12 +368,62 @@ mod tests {
                 correlation_id: Uuid::new_v4(),
                 causation_id: Uuid::new_v4(),
                 occurred_at: fixed_now,
+                schema_version: 1,
             },
         ];
         let repo = RecordingEventRepository::with_aggregate_ids(Ok(events), vec![world_id]);
 
-        let result = list_world_snapshots(&repo).await.unwrap();
+        let result = list_world_snapshots(&repo, &InMemoryReadModelStore::new())
+            .await
+            .unwrap();
 
         assert!(result.is_empty());
     }
+
+    #[tokio::test]
+    async fn test_evaluate_condition_reads_the_recorded_world() {
+        // Arrange
+        let world_id = Uuid::new_v4();
+        let fixed_now = Utc.with_ymd_and_hms(2026, 1, 15, 10, 0, 0).unwrap();
+        let events = vec![StoredEvent {
+            event_id: Uuid::new_v4(),
+            aggregate_id: world_id,
+            event_type: "world_state.flag_set".to_owned(),
+            payload: serde_json::to_value(WorldStateEventKind::FlagSet(FlagSet {
+                world_id,
+                flag_key: "door_unlocked".to_owned(),
+                value: true,
+            }))
+            .unwrap(),
+            sequence_number: 1,
+            correlation_id: Uuid::new_v4(),
+            causation_id: Uuid::new_v4(),
+            occurred_at: fixed_now,
+            schema_version: 1,
+        }];
+        let repo = RecordingEventRepository::new(Ok(events));
+        let condition: Condition = "flag door_unlocked and not flag alarm".parse().unwrap();
+
+        // Act
+        let result = evaluate_condition(world_id, &condition, &BTreeMap::new(), &repo).await;
+
+        // Assert
+        assert!(result.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_evaluate_condition_returns_not_found_for_unknown_world() {
+        let world_id = Uuid::new_v4();
+        let condition: Condition = "true".parse().unwrap();
+
+        let result = evaluate_condition(
+            world_id,
+            &condition,
+            &BTreeMap::new(),
+            &EmptyEventRepository,
+        )
+        .await;
+
+        assert!(matches!(result, Err(DomainError::AggregateNotFound(id)) if id == world_id));
+    }
 }
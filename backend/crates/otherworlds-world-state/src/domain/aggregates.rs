@@ -1,35 +1,60 @@
 //! Aggregate roots for the World State context.
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 
 use otherworlds_core::aggregate::AggregateRoot;
 use otherworlds_core::clock::Clock;
 use otherworlds_core::error::DomainError;
 use otherworlds_core::event::EventMetadata;
 use otherworlds_core::rng::DeterministicRng;
+use otherworlds_core::snapshot::Snapshottable;
+use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use super::calendar::{Calendar, GameTime, ScheduledEvent};
+use super::disposition::{faction_ripple, shifted, validate_score};
 use super::events::{
-    DispositionUpdated, FlagSet, WorldFactChanged, WorldSnapshotArchived, WorldStateEvent,
-    WorldStateEventKind,
+    CalendarSet, ConnectionLocked, ConnectionUnlocked, DispositionSeeded, DispositionUpdated,
+    EventScheduled, FactAsserted, FactRetracted, FactValue, FactionJoined, FlagSet, LocationAdded,
+    LocationsConnected, NpcMoved, PartyMoved, ScheduledEffect, ScheduledEventFired, TimeAdvanced,
+    WorldSnapshotArchived, WorldStateEvent, WorldStateEventKind,
 };
+use super::upcasters::current_schema_version;
 
 /// The aggregate root for a world snapshot.
-#[derive(Debug)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct WorldSnapshot {
     /// Aggregate identifier.
     pub id: Uuid,
     /// Current version (event count).
     pub(crate) version: i64,
-    /// Fact keys that have been applied.
-    pub(crate) facts: Vec<String>,
+    /// World facts by key.
+    pub(crate) facts: BTreeMap<String, FactValue>,
     /// Boolean flags set in the world.
     pub(crate) flags: HashMap<String, bool>,
-    /// Entity IDs whose dispositions have been updated.
-    pub(crate) disposition_entity_ids: Vec<Uuid>,
+    /// Disposition scores by entity, for every entity seeded or shifted.
+    pub(crate) dispositions: BTreeMap<Uuid, i32>,
+    /// Members of each faction, by faction entity.
+    pub(crate) factions: BTreeMap<Uuid, BTreeSet<Uuid>>,
+    /// Location names, by location.
+    pub(crate) locations: BTreeMap<Uuid, String>,
+    /// The locations each location connects to, and whether each connection
+    /// is locked. Every connection is listed from both ends.
+    pub(crate) connections: BTreeMap<Uuid, BTreeMap<Uuid, bool>>,
+    /// The party's location, once placed.
+    pub(crate) party_location: Option<Uuid>,
+    /// The location of each placed NPC.
+    pub(crate) npc_locations: BTreeMap<Uuid, Uuid>,
+    /// The calendar world time is read with.
+    pub(crate) calendar: Calendar,
+    /// World time, in hours since the first hour of day 1.
+    pub(crate) elapsed_hours: u64,
+    /// Events waiting to fire, in the order they were scheduled.
+    pub(crate) schedule: Vec<ScheduledEvent>,
     /// Whether this world snapshot has been archived (soft-deleted).
     pub(crate) archived: bool,
     /// Uncommitted events pending persistence.
+    #[serde(skip)]
     uncommitted_events: Vec<WorldStateEvent>,
 }
 
@@ -40,9 +65,17 @@ impl WorldSnapshot {
         Self {
             id,
             version: 0,
-            facts: Vec::new(),
+            facts: BTreeMap::new(),
             flags: HashMap::new(),
-            disposition_entity_ids: Vec::new(),
+            dispositions: BTreeMap::new(),
+            factions: BTreeMap::new(),
+            locations: BTreeMap::new(),
+            connections: BTreeMap::new(),
+            party_location: None,
+            npc_locations: BTreeMap::new(),
+            calendar: Calendar::default(),
+            elapsed_hours: 0,
+            schedule: Vec::new(),
             archived: false,
             uncommitted_events: Vec::new(),
         }
@@ -54,31 +87,58 @@ impl WorldSnapshot {
         self.version + self.uncommitted_events.len() as i64 + 1
     }
 
-    /// Applies an effect to the world state, producing a `WorldFactChanged` event.
-    pub fn apply_effect(
+    /// Asserts a world fact, producing a `FactAsserted` event. The value
+    /// replaces any earlier value of the same key.
+    pub fn assert_fact(
         &mut self,
-        fact_key: String,
+        key: String,
+        value: FactValue,
         correlation_id: Uuid,
         clock: &dyn Clock,
         rng: &mut dyn DeterministicRng,
     ) {
-        let event = WorldStateEvent {
-            metadata: EventMetadata {
-                event_id: rng.next_uuid(),
-                event_type: "world_state.world_fact_changed".to_owned(),
-                aggregate_id: self.id,
-                sequence_number: self.next_sequence_number(),
-                correlation_id,
-                causation_id: correlation_id,
-                occurred_at: clock.now(),
-            },
-            kind: WorldStateEventKind::WorldFactChanged(WorldFactChanged {
+        let event = self.new_event(
+            WorldStateEventKind::FactAsserted(FactAsserted {
                 world_id: self.id,
-                fact_key,
+                key,
+                value,
             }),
-        };
+            correlation_id,
+            clock,
+            rng,
+        );
+        self.uncommitted_events.push(event);
+    }
 
+    /// Retracts a world fact, producing a `FactRetracted` event.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::Validation` if the fact is not asserted.
+    pub fn retract_fact(
+        &mut self,
+        key: String,
+        correlation_id: Uuid,
+        clock: &dyn Clock,
+        rng: &mut dyn DeterministicRng,
+    ) -> Result<(), DomainError> {
+        if !self.facts.contains_key(&key) {
+            return Err(DomainError::Validation(format!(
+                "fact '{key}' is not asserted"
+            )));
+        }
+
+        let event = self.new_event(
+            WorldStateEventKind::FactRetracted(FactRetracted {
+                world_id: self.id,
+                key,
+            }),
+            correlation_id,
+            clock,
+            rng,
+        );
         self.uncommitted_events.push(event);
+        Ok(())
     }
 
     /// Sets a flag in the world state, producing a `FlagSet` event.
@@ -99,6 +159,7 @@ impl WorldSnapshot {
                 correlation_id,
                 causation_id: correlation_id,
                 occurred_at: clock.now(),
+                schema_version: current_schema_version("world_state.flag_set"),
             },
             kind: WorldStateEventKind::FlagSet(FlagSet {
                 world_id: self.id,
@@ -110,31 +171,630 @@ impl WorldSnapshot {
         self.uncommitted_events.push(event);
     }
 
-    /// Updates a disposition in the world state, producing a `DispositionUpdated` event.
-    pub fn update_disposition(
-        &mut self,
-        entity_id: Uuid,
+    /// Returns whether the entity's disposition has been seeded or shifted.
+    #[must_use]
+    pub fn has_disposition(&self, entity_id: Uuid) -> bool {
+        self.dispositions.contains_key(&entity_id)
+    }
+
+    /// Returns whether the entity belongs to the faction.
+    #[must_use]
+    pub fn is_member(&self, entity_id: Uuid, faction_id: Uuid) -> bool {
+        self.factions
+            .get(&faction_id)
+            .is_some_and(|members| members.contains(&entity_id))
+    }
+
+    /// Builds the next event carrying `kind`.
+    fn new_event(
+        &self,
+        kind: WorldStateEventKind,
         correlation_id: Uuid,
         clock: &dyn Clock,
         rng: &mut dyn DeterministicRng,
-    ) {
-        let event = WorldStateEvent {
+    ) -> WorldStateEvent {
+        let event_type = kind.event_type();
+        WorldStateEvent {
             metadata: EventMetadata {
                 event_id: rng.next_uuid(),
-                event_type: "world_state.disposition_updated".to_owned(),
+                event_type: event_type.to_owned(),
                 aggregate_id: self.id,
                 sequence_number: self.next_sequence_number(),
                 correlation_id,
                 causation_id: correlation_id,
                 occurred_at: clock.now(),
+                schema_version: current_schema_version(event_type),
             },
-            kind: WorldStateEventKind::DispositionUpdated(DispositionUpdated {
+            kind,
+        }
+    }
+
+    /// Shifts an entity's disposition by `delta`, producing a
+    /// `DispositionUpdated` event, followed by one for each faction the
+    /// entity belongs to whose rippled share of `delta` is not zero.
+    pub fn update_disposition(
+        &mut self,
+        entity_id: Uuid,
+        delta: i32,
+        correlation_id: Uuid,
+        clock: &dyn Clock,
+        rng: &mut dyn DeterministicRng,
+    ) {
+        let event = self.new_event(
+            WorldStateEventKind::DispositionUpdated(DispositionUpdated {
+                world_id: self.id,
+                entity_id,
+                delta,
+                rippled_from: None,
+            }),
+            correlation_id,
+            clock,
+            rng,
+        );
+        self.uncommitted_events.push(event);
+
+        let ripple = faction_ripple(delta);
+        if ripple == 0 {
+            return;
+        }
+        let factions: Vec<Uuid> = self
+            .factions
+            .iter()
+            .filter(|(_, members)| members.contains(&entity_id))
+            .map(|(faction_id, _)| *faction_id)
+            .collect();
+        for faction_id in factions {
+            let event = self.new_event(
+                WorldStateEventKind::DispositionUpdated(DispositionUpdated {
+                    world_id: self.id,
+                    entity_id: faction_id,
+                    delta: ripple,
+                    rippled_from: Some(entity_id),
+                }),
+                correlation_id,
+                clock,
+                rng,
+            );
+            self.uncommitted_events.push(event);
+        }
+    }
+
+    /// Gives an entity its starting disposition score, producing a
+    /// `DispositionSeeded` event.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::Validation` if the score is out of range.
+    pub fn seed_disposition(
+        &mut self,
+        entity_id: Uuid,
+        score: i32,
+        correlation_id: Uuid,
+        clock: &dyn Clock,
+        rng: &mut dyn DeterministicRng,
+    ) -> Result<(), DomainError> {
+        validate_score(score)?;
+        let event = self.new_event(
+            WorldStateEventKind::DispositionSeeded(DispositionSeeded {
                 world_id: self.id,
                 entity_id,
+                score,
             }),
+            correlation_id,
+            clock,
+            rng,
+        );
+        self.uncommitted_events.push(event);
+        Ok(())
+    }
+
+    /// Adds an entity to a faction, producing a `FactionJoined` event.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::Validation` if the entity is the faction itself
+    /// or already belongs to it.
+    pub fn join_faction(
+        &mut self,
+        entity_id: Uuid,
+        faction_id: Uuid,
+        correlation_id: Uuid,
+        clock: &dyn Clock,
+        rng: &mut dyn DeterministicRng,
+    ) -> Result<(), DomainError> {
+        if entity_id == faction_id {
+            return Err(DomainError::Validation(
+                "an entity cannot join itself as a faction".into(),
+            ));
+        }
+        let already_joined = self.is_member(entity_id, faction_id)
+            || self.uncommitted_events.iter().any(|event| {
+                matches!(&event.kind, WorldStateEventKind::FactionJoined(joined)
+                    if joined.entity_id == entity_id && joined.faction_id == faction_id)
+            });
+        if already_joined {
+            return Err(DomainError::Validation(format!(
+                "entity {entity_id} already belongs to faction {faction_id}"
+            )));
+        }
+
+        let event = self.new_event(
+            WorldStateEventKind::FactionJoined(FactionJoined {
+                world_id: self.id,
+                entity_id,
+                faction_id,
+            }),
+            correlation_id,
+            clock,
+            rng,
+        );
+        self.uncommitted_events.push(event);
+        Ok(())
+    }
+
+    /// Returns whether the location exists, counting locations added by
+    /// events not yet committed.
+    #[must_use]
+    pub fn has_location(&self, location_id: Uuid) -> bool {
+        self.locations.contains_key(&location_id)
+            || self.uncommitted_events.iter().any(|event| {
+                matches!(&event.kind, WorldStateEventKind::LocationAdded(added)
+                    if added.location_id == location_id)
+            })
+    }
+
+    /// Returns whether the two locations are connected, counting
+    /// connections made by events not yet committed.
+    #[must_use]
+    pub fn are_connected(&self, location_id: Uuid, other_location_id: Uuid) -> bool {
+        self.connection_locked(location_id, other_location_id)
+            .is_some()
+            || self.uncommitted_events.iter().any(|event| {
+                matches!(&event.kind, WorldStateEventKind::LocationsConnected(connected)
+                    if (connected.location_id, connected.other_location_id) == (location_id, other_location_id)
+                        || (connected.location_id, connected.other_location_id) == (other_location_id, location_id))
+            })
+    }
+
+    /// Returns whether the connection between two locations is locked, or
+    /// `None` if they are not connected.
+    fn connection_locked(&self, location_id: Uuid, other_location_id: Uuid) -> Option<bool> {
+        self.connections
+            .get(&location_id)
+            .and_then(|neighbours| neighbours.get(&other_location_id))
+            .copied()
+    }
+
+    /// Returns the party's location, if it has been placed.
+    #[must_use]
+    pub fn party_location(&self) -> Option<Uuid> {
+        self.party_location
+    }
+
+    /// Returns the NPC's location, if it has been placed.
+    #[must_use]
+    pub fn npc_location(&self, entity_id: Uuid) -> Option<Uuid> {
+        self.npc_locations.get(&entity_id).copied()
+    }
+
+    fn require_location(&self, location_id: Uuid) -> Result<(), DomainError> {
+        if self.has_location(location_id) {
+            Ok(())
+        } else {
+            Err(DomainError::Validation(format!(
+                "location {location_id} does not exist"
+            )))
+        }
+    }
+
+    /// Adds a location, producing a `LocationAdded` event.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::Validation` if the name is empty or the location
+    /// already exists.
+    pub fn add_location(
+        &mut self,
+        location_id: Uuid,
+        name: String,
+        correlation_id: Uuid,
+        clock: &dyn Clock,
+        rng: &mut dyn DeterministicRng,
+    ) -> Result<(), DomainError> {
+        if name.trim().is_empty() {
+            return Err(DomainError::Validation(
+                "location name must not be empty".into(),
+            ));
+        }
+        if self.has_location(location_id) {
+            return Err(DomainError::Validation(format!(
+                "location {location_id} already exists"
+            )));
+        }
+
+        let event = self.new_event(
+            WorldStateEventKind::LocationAdded(LocationAdded {
+                world_id: self.id,
+                location_id,
+                name,
+            }),
+            correlation_id,
+            clock,
+            rng,
+        );
+        self.uncommitted_events.push(event);
+        Ok(())
+    }
+
+    /// Connects two locations both ways, producing a `LocationsConnected`
+    /// event.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::Validation` if the locations are the same, either
+    /// does not exist, or they are already connected.
+    pub fn connect_locations(
+        &mut self,
+        location_id: Uuid,
+        other_location_id: Uuid,
+        locked: bool,
+        correlation_id: Uuid,
+        clock: &dyn Clock,
+        rng: &mut dyn DeterministicRng,
+    ) -> Result<(), DomainError> {
+        if location_id == other_location_id {
+            return Err(DomainError::Validation(
+                "a location cannot connect to itself".into(),
+            ));
+        }
+        self.require_location(location_id)?;
+        self.require_location(other_location_id)?;
+        if self.are_connected(location_id, other_location_id) {
+            return Err(DomainError::Validation(format!(
+                "locations {location_id} and {other_location_id} are already connected"
+            )));
+        }
+
+        let event = self.new_event(
+            WorldStateEventKind::LocationsConnected(LocationsConnected {
+                world_id: self.id,
+                location_id,
+                other_location_id,
+                locked,
+            }),
+            correlation_id,
+            clock,
+            rng,
+        );
+        self.uncommitted_events.push(event);
+        Ok(())
+    }
+
+    /// Locks or unlocks the connection between two locations, producing a
+    /// `ConnectionLocked` or `ConnectionUnlocked` event.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::Validation` if the locations are not connected or
+    /// the connection is already in the requested state.
+    pub fn set_connection_locked(
+        &mut self,
+        location_id: Uuid,
+        other_location_id: Uuid,
+        locked: bool,
+        correlation_id: Uuid,
+        clock: &dyn Clock,
+        rng: &mut dyn DeterministicRng,
+    ) -> Result<(), DomainError> {
+        let Some(currently_locked) = self.connection_locked(location_id, other_location_id) else {
+            return Err(DomainError::Validation(format!(
+                "locations {location_id} and {other_location_id} are not connected"
+            )));
         };
+        if currently_locked == locked {
+            let state = if locked { "locked" } else { "unlocked" };
+            return Err(DomainError::Validation(format!(
+                "the connection between {location_id} and {other_location_id} is already {state}"
+            )));
+        }
 
+        let kind = if locked {
+            WorldStateEventKind::ConnectionLocked(ConnectionLocked {
+                world_id: self.id,
+                location_id,
+                other_location_id,
+            })
+        } else {
+            WorldStateEventKind::ConnectionUnlocked(ConnectionUnlocked {
+                world_id: self.id,
+                location_id,
+                other_location_id,
+            })
+        };
+        let event = self.new_event(kind, correlation_id, clock, rng);
         self.uncommitted_events.push(event);
+        Ok(())
+    }
+
+    /// Moves the party to a location, producing a `PartyMoved` event. Once
+    /// placed, the party can only move along an unlocked connection.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::Validation` if the location does not exist, the
+    /// party is already there, or the party's location has no unlocked
+    /// connection to it.
+    pub fn move_party(
+        &mut self,
+        to: Uuid,
+        correlation_id: Uuid,
+        clock: &dyn Clock,
+        rng: &mut dyn DeterministicRng,
+    ) -> Result<(), DomainError> {
+        self.require_location(to)?;
+        if let Some(from) = self.party_location {
+            if from == to {
+                return Err(DomainError::Validation(format!(
+                    "the party is already at location {to}"
+                )));
+            }
+            match self.connection_locked(from, to) {
+                None => {
+                    return Err(DomainError::Validation(format!(
+                        "location {to} is not connected to the party's location {from}"
+                    )));
+                }
+                Some(true) => {
+                    return Err(DomainError::Validation(format!(
+                        "the way from {from} to {to} is locked"
+                    )));
+                }
+                Some(false) => {}
+            }
+        }
+
+        let event = self.new_event(
+            WorldStateEventKind::PartyMoved(PartyMoved {
+                world_id: self.id,
+                from: self.party_location,
+                to,
+            }),
+            correlation_id,
+            clock,
+            rng,
+        );
+        self.uncommitted_events.push(event);
+        Ok(())
+    }
+
+    /// Moves an NPC to a location, producing an `NpcMoved` event. NPCs move
+    /// as the story needs them to, so the move need not follow a connection.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::Validation` if the location does not exist or the
+    /// NPC is already there.
+    pub fn move_npc(
+        &mut self,
+        entity_id: Uuid,
+        to: Uuid,
+        correlation_id: Uuid,
+        clock: &dyn Clock,
+        rng: &mut dyn DeterministicRng,
+    ) -> Result<(), DomainError> {
+        self.require_location(to)?;
+        let from = self.npc_location(entity_id);
+        if from == Some(to) {
+            return Err(DomainError::Validation(format!(
+                "NPC {entity_id} is already at location {to}"
+            )));
+        }
+
+        let event = self.new_event(
+            WorldStateEventKind::NpcMoved(NpcMoved {
+                world_id: self.id,
+                entity_id,
+                from,
+                to,
+            }),
+            correlation_id,
+            clock,
+            rng,
+        );
+        self.uncommitted_events.push(event);
+        Ok(())
+    }
+
+    /// Returns world time, in hours since the first hour of day 1.
+    #[must_use]
+    pub fn elapsed_hours(&self) -> u64 {
+        self.elapsed_hours
+    }
+
+    /// Returns the calendar world time is read with.
+    #[must_use]
+    pub fn calendar(&self) -> &Calendar {
+        &self.calendar
+    }
+
+    /// Sets the calendar world time is read with, producing a `CalendarSet`
+    /// event. Schedules are kept in elapsed hours, so the calendar can only
+    /// change while the world is still at its first hour with nothing
+    /// scheduled.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::Validation` if the calendar is invalid, time has
+    /// advanced, or an event has been scheduled.
+    pub fn set_calendar(
+        &mut self,
+        calendar: Calendar,
+        correlation_id: Uuid,
+        clock: &dyn Clock,
+        rng: &mut dyn DeterministicRng,
+    ) -> Result<(), DomainError> {
+        calendar.validate()?;
+        if self.elapsed_hours > 0 || !self.schedule.is_empty() {
+            return Err(DomainError::Validation(
+                "the calendar cannot change once time has advanced or events are scheduled".into(),
+            ));
+        }
+
+        let event = self.new_event(
+            WorldStateEventKind::CalendarSet(CalendarSet {
+                world_id: self.id,
+                calendar,
+            }),
+            correlation_id,
+            clock,
+            rng,
+        );
+        self.uncommitted_events.push(event);
+        Ok(())
+    }
+
+    /// Schedules an effect to fire when world time reaches `at`, producing an
+    /// `EventScheduled` event. Returns the scheduled event's identifier.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::Validation` if `at` is not a time of the calendar
+    /// or has already been reached, a flag key is empty, or an NPC's
+    /// destination does not exist.
+    pub fn schedule_event(
+        &mut self,
+        at: GameTime,
+        effect: ScheduledEffect,
+        correlation_id: Uuid,
+        clock: &dyn Clock,
+        rng: &mut dyn DeterministicRng,
+    ) -> Result<Uuid, DomainError> {
+        let due = self.calendar.elapsed_at(at)?;
+        if due <= self.elapsed_hours {
+            return Err(DomainError::Validation(format!(
+                "day {} hour {} has already been reached",
+                at.day, at.hour
+            )));
+        }
+        match &effect {
+            ScheduledEffect::SetFlag { flag_key, .. } if flag_key.trim().is_empty() => {
+                return Err(DomainError::Validation("flag key must not be empty".into()));
+            }
+            ScheduledEffect::SetFlag { .. } => {}
+            ScheduledEffect::MoveNpc { location_id, .. } => self.require_location(*location_id)?,
+        }
+
+        let schedule_id = rng.next_uuid();
+        let event = self.new_event(
+            WorldStateEventKind::EventScheduled(EventScheduled {
+                world_id: self.id,
+                schedule_id,
+                at: due,
+                effect,
+            }),
+            correlation_id,
+            clock,
+            rng,
+        );
+        self.uncommitted_events.push(event);
+        Ok(schedule_id)
+    }
+
+    /// Advances world time by `hours`, producing a `TimeAdvanced` event.
+    ///
+    /// Every scheduled event due by the new time then fires, soonest first
+    /// and in scheduling order within an hour. Each produces a
+    /// `ScheduledEventFired` event followed by the `FlagSet` or `NpcMoved`
+    /// event of its effect; moving an NPC to where it already is fires
+    /// without moving it.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::Validation` if `hours` is 0 or time would pass
+    /// the last hour that can be counted.
+    pub fn advance_time(
+        &mut self,
+        hours: u64,
+        correlation_id: Uuid,
+        clock: &dyn Clock,
+        rng: &mut dyn DeterministicRng,
+    ) -> Result<(), DomainError> {
+        if hours == 0 {
+            return Err(DomainError::Validation(
+                "time must advance by at least one hour".into(),
+            ));
+        }
+        let from = self.elapsed_hours;
+        let to = from.checked_add(hours).ok_or_else(|| {
+            DomainError::Validation(format!("time cannot advance {hours} hours further"))
+        })?;
+
+        let event = self.new_event(
+            WorldStateEventKind::TimeAdvanced(TimeAdvanced {
+                world_id: self.id,
+                from,
+                to,
+            }),
+            correlation_id,
+            clock,
+            rng,
+        );
+        self.uncommitted_events.push(event);
+
+        let mut due: Vec<ScheduledEvent> = self
+            .schedule
+            .iter()
+            .filter(|scheduled| scheduled.at <= to)
+            .cloned()
+            .collect();
+        due.sort_by_key(|scheduled| scheduled.at);
+        // NPCs moved by events fired earlier in this advance.
+        let mut moved: BTreeMap<Uuid, Uuid> = BTreeMap::new();
+        for scheduled in due {
+            let event = self.new_event(
+                WorldStateEventKind::ScheduledEventFired(ScheduledEventFired {
+                    world_id: self.id,
+                    schedule_id: scheduled.schedule_id,
+                    at: scheduled.at,
+                }),
+                correlation_id,
+                clock,
+                rng,
+            );
+            self.uncommitted_events.push(event);
+
+            let kind = match scheduled.effect {
+                ScheduledEffect::SetFlag { flag_key, value } => {
+                    WorldStateEventKind::FlagSet(FlagSet {
+                        world_id: self.id,
+                        flag_key,
+                        value,
+                    })
+                }
+                ScheduledEffect::MoveNpc {
+                    entity_id,
+                    location_id,
+                } => {
+                    let from = moved
+                        .get(&entity_id)
+                        .copied()
+                        .or_else(|| self.npc_location(entity_id));
+                    if from == Some(location_id) {
+                        continue;
+                    }
+                    moved.insert(entity_id, location_id);
+                    WorldStateEventKind::NpcMoved(NpcMoved {
+                        world_id: self.id,
+                        entity_id,
+                        from,
+                        to: location_id,
+                    })
+                }
+            };
+            let event = self.new_event(kind, correlation_id, clock, rng);
+            self.uncommitted_events.push(event);
+        }
+        Ok(())
     }
 
     /// Archives (soft-deletes) the world snapshot, producing a `WorldSnapshotArchived` event.
@@ -163,6 +823,7 @@ impl WorldSnapshot {
                 correlation_id,
                 causation_id: correlation_id,
                 occurred_at: clock.now(),
+                schema_version: current_schema_version("world_state.world_snapshot_archived"),
             },
             kind: WorldStateEventKind::WorldSnapshotArchived(WorldSnapshotArchived {
                 world_id: self.id,
@@ -174,6 +835,24 @@ impl WorldSnapshot {
     }
 }
 
+impl WorldSnapshot {
+    /// Sets the lock state of an existing connection at both ends.
+    fn set_locked(&mut self, location_id: Uuid, other_location_id: Uuid, locked: bool) {
+        for (from, to) in [
+            (location_id, other_location_id),
+            (other_location_id, location_id),
+        ] {
+            if let Some(state) = self
+                .connections
+                .get_mut(&from)
+                .and_then(|neighbours| neighbours.get_mut(&to))
+            {
+                *state = locked;
+            }
+        }
+    }
+}
+
 impl AggregateRoot for WorldSnapshot {
     type Event = WorldStateEvent;
 
@@ -187,14 +866,71 @@ impl AggregateRoot for WorldSnapshot {
 
     fn apply(&mut self, event: &Self::Event) {
         match &event.kind {
-            WorldStateEventKind::WorldFactChanged(payload) => {
-                self.facts.push(payload.fact_key.clone());
+            WorldStateEventKind::FactAsserted(payload) => {
+                self.facts
+                    .insert(payload.key.clone(), payload.value.clone());
+            }
+            WorldStateEventKind::FactRetracted(payload) => {
+                self.facts.remove(&payload.key);
             }
             WorldStateEventKind::FlagSet(payload) => {
                 self.flags.insert(payload.flag_key.clone(), payload.value);
             }
             WorldStateEventKind::DispositionUpdated(payload) => {
-                self.disposition_entity_ids.push(payload.entity_id);
+                let score = self.dispositions.entry(payload.entity_id).or_default();
+                *score = shifted(*score, payload.delta);
+            }
+            WorldStateEventKind::DispositionSeeded(payload) => {
+                self.dispositions.insert(payload.entity_id, payload.score);
+            }
+            WorldStateEventKind::FactionJoined(payload) => {
+                self.factions
+                    .entry(payload.faction_id)
+                    .or_default()
+                    .insert(payload.entity_id);
+            }
+            WorldStateEventKind::LocationAdded(payload) => {
+                self.locations
+                    .insert(payload.location_id, payload.name.clone());
+            }
+            WorldStateEventKind::LocationsConnected(payload) => {
+                self.connections
+                    .entry(payload.location_id)
+                    .or_default()
+                    .insert(payload.other_location_id, payload.locked);
+                self.connections
+                    .entry(payload.other_location_id)
+                    .or_default()
+                    .insert(payload.location_id, payload.locked);
+            }
+            WorldStateEventKind::ConnectionLocked(payload) => {
+                self.set_locked(payload.location_id, payload.other_location_id, true);
+            }
+            WorldStateEventKind::ConnectionUnlocked(payload) => {
+                self.set_locked(payload.location_id, payload.other_location_id, false);
+            }
+            WorldStateEventKind::PartyMoved(payload) => {
+                self.party_location = Some(payload.to);
+            }
+            WorldStateEventKind::NpcMoved(payload) => {
+                self.npc_locations.insert(payload.entity_id, payload.to);
+            }
+            WorldStateEventKind::CalendarSet(payload) => {
+                self.calendar = payload.calendar.clone();
+            }
+            WorldStateEventKind::TimeAdvanced(payload) => {
+                self.elapsed_hours = payload.to;
+            }
+            WorldStateEventKind::EventScheduled(payload) => {
+                self.schedule.push(ScheduledEvent {
+                    schedule_id: payload.schedule_id,
+                    at: payload.at,
+                    effect: payload.effect.clone(),
+                });
+            }
+            WorldStateEventKind::ScheduledEventFired(payload) => {
+                self.schedule
+                    .retain(|scheduled| scheduled.schedule_id != payload.schedule_id);
             }
             WorldStateEventKind::WorldSnapshotArchived(_) => {
                 self.archived = true;
@@ -212,16 +948,25 @@ impl AggregateRoot for WorldSnapshot {
     }
 }
 
+impl Snapshottable for WorldSnapshot {
+    const AGGREGATE_TYPE: &'static str = "world_state.world_snapshot";
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use chrono::{TimeZone, Utc};
     use otherworlds_core::aggregate::AggregateRoot;
     use otherworlds_core::event::DomainEvent;
+    use otherworlds_core::rng::SeededRng;
     use otherworlds_test_support::{FixedClock, MockRng};
 
+    use crate::domain::events::{
+        DISPOSITION_UPDATED_EVENT_TYPE, FACT_ASSERTED_EVENT_TYPE, FACT_RETRACTED_EVENT_TYPE,
+    };
+
     #[test]
-    fn test_apply_effect_produces_world_fact_changed_event() {
+    fn test_assert_fact_produces_fact_asserted_event() {
         // Arrange
         let world_id = Uuid::new_v4();
         let correlation_id = Uuid::new_v4();
@@ -230,64 +975,172 @@ mod tests {
         let mut snapshot = WorldSnapshot::new(world_id);
 
         // Act
-        snapshot.apply_effect(
-            "quest_complete".to_owned(),
+        snapshot.assert_fact(
+            "gold_owed".to_owned(),
+            FactValue::Number(40),
             correlation_id,
             &clock,
             &mut MockRng,
         );
 
         // Assert
-        let events = snapshot.uncommitted_events();
-        assert_eq!(events.len(), 1);
+        let events = snapshot.uncommitted_events();
+        assert_eq!(events.len(), 1);
+
+        let event = &events[0];
+        assert_eq!(event.event_type(), FACT_ASSERTED_EVENT_TYPE);
+
+        let meta = event.metadata();
+        assert_eq!(meta.aggregate_id, world_id);
+        assert_eq!(meta.sequence_number, 1);
+        assert_eq!(meta.correlation_id, correlation_id);
+        assert_eq!(meta.causation_id, correlation_id);
+        assert_eq!(meta.occurred_at, fixed_now);
+
+        match &event.kind {
+            WorldStateEventKind::FactAsserted(payload) => {
+                assert_eq!(payload.world_id, world_id);
+                assert_eq!(payload.key, "gold_owed");
+                assert_eq!(payload.value, FactValue::Number(40));
+            }
+            other => panic!("expected FactAsserted, got {other:?}"),
+        }
+    }
+
+    fn fact_event(
+        world_id: Uuid,
+        sequence_number: i64,
+        kind: WorldStateEventKind,
+    ) -> WorldStateEvent {
+        let event_type = match &kind {
+            WorldStateEventKind::FactRetracted(_) => FACT_RETRACTED_EVENT_TYPE,
+            _ => FACT_ASSERTED_EVENT_TYPE,
+        };
+        WorldStateEvent {
+            metadata: EventMetadata {
+                event_id: Uuid::new_v4(),
+                event_type: event_type.to_owned(),
+                aggregate_id: world_id,
+                sequence_number,
+                correlation_id: Uuid::new_v4(),
+                causation_id: Uuid::new_v4(),
+                occurred_at: Utc.with_ymd_and_hms(2026, 1, 15, 10, 0, 0).unwrap(),
+                schema_version: current_schema_version(event_type),
+            },
+            kind,
+        }
+    }
+
+    fn asserted(world_id: Uuid, key: &str, value: FactValue) -> WorldStateEventKind {
+        WorldStateEventKind::FactAsserted(FactAsserted {
+            world_id,
+            key: key.to_owned(),
+            value,
+        })
+    }
+
+    #[test]
+    fn test_apply_fact_asserted_replaces_earlier_value() {
+        // Arrange
+        let world_id = Uuid::new_v4();
+        let ruler_id = Uuid::new_v4();
+        let mut snapshot = WorldSnapshot::new(world_id);
+
+        // Act
+        snapshot.apply(&fact_event(
+            world_id,
+            1,
+            asserted(world_id, "ruler", FactValue::String("nobody".to_owned())),
+        ));
+        snapshot.apply(&fact_event(
+            world_id,
+            2,
+            asserted(world_id, "ruler", FactValue::Entity(ruler_id)),
+        ));
+
+        // Assert
+        assert_eq!(snapshot.facts.len(), 1);
+        assert_eq!(snapshot.facts["ruler"], FactValue::Entity(ruler_id));
+        assert_eq!(snapshot.version, 2);
+    }
+
+    #[test]
+    fn test_apply_fact_retracted_removes_fact() {
+        // Arrange
+        let world_id = Uuid::new_v4();
+        let mut snapshot = WorldSnapshot::new(world_id);
+        snapshot.apply(&fact_event(
+            world_id,
+            1,
+            asserted(world_id, "bridge_standing", FactValue::Bool(true)),
+        ));
+
+        // Act
+        snapshot.apply(&fact_event(
+            world_id,
+            2,
+            WorldStateEventKind::FactRetracted(FactRetracted {
+                world_id,
+                key: "bridge_standing".to_owned(),
+            }),
+        ));
+
+        // Assert
+        assert!(snapshot.facts.is_empty());
+        assert_eq!(snapshot.version, 2);
+    }
 
-        let event = &events[0];
-        assert_eq!(event.event_type(), "world_state.world_fact_changed");
+    #[test]
+    fn test_retract_fact_produces_fact_retracted_event() {
+        // Arrange
+        let world_id = Uuid::new_v4();
+        let clock = FixedClock(Utc.with_ymd_and_hms(2026, 1, 15, 10, 0, 0).unwrap());
+        let mut snapshot = WorldSnapshot::new(world_id);
+        snapshot.apply(&fact_event(
+            world_id,
+            1,
+            asserted(world_id, "bridge_standing", FactValue::Bool(true)),
+        ));
 
-        let meta = event.metadata();
-        assert_eq!(meta.aggregate_id, world_id);
-        assert_eq!(meta.sequence_number, 1);
-        assert_eq!(meta.correlation_id, correlation_id);
-        assert_eq!(meta.causation_id, correlation_id);
-        assert_eq!(meta.occurred_at, fixed_now);
+        // Act
+        snapshot
+            .retract_fact(
+                "bridge_standing".to_owned(),
+                Uuid::new_v4(),
+                &clock,
+                &mut MockRng,
+            )
+            .unwrap();
 
+        // Assert
+        let event = &snapshot.uncommitted_events()[0];
+        assert_eq!(event.event_type(), FACT_RETRACTED_EVENT_TYPE);
+        assert_eq!(event.metadata().sequence_number, 2);
         match &event.kind {
-            WorldStateEventKind::WorldFactChanged(payload) => {
-                assert_eq!(payload.world_id, world_id);
-                assert_eq!(payload.fact_key, "quest_complete");
+            WorldStateEventKind::FactRetracted(payload) => {
+                assert_eq!(payload.key, "bridge_standing");
             }
-            other => panic!("expected WorldFactChanged, got {other:?}"),
+            other => panic!("expected FactRetracted, got {other:?}"),
         }
     }
 
     #[test]
-    fn test_apply_world_fact_changed_pushes_fact() {
+    fn test_retract_unasserted_fact_returns_error() {
         // Arrange
-        let world_id = Uuid::new_v4();
-        let fixed_now = Utc.with_ymd_and_hms(2026, 1, 15, 10, 0, 0).unwrap();
-        let mut snapshot = WorldSnapshot::new(world_id);
-        let event = WorldStateEvent {
-            metadata: EventMetadata {
-                event_id: Uuid::new_v4(),
-                event_type: "world_state.world_fact_changed".to_owned(),
-                aggregate_id: world_id,
-                sequence_number: 1,
-                correlation_id: Uuid::new_v4(),
-                causation_id: Uuid::new_v4(),
-                occurred_at: fixed_now,
-            },
-            kind: WorldStateEventKind::WorldFactChanged(WorldFactChanged {
-                world_id,
-                fact_key: "quest_complete".to_owned(),
-            }),
-        };
+        let clock = FixedClock(Utc.with_ymd_and_hms(2026, 1, 15, 10, 0, 0).unwrap());
+        let mut snapshot = WorldSnapshot::new(Uuid::new_v4());
 
         // Act
-        snapshot.apply(&event);
+        let result = snapshot.retract_fact(
+            "dragon_slain".to_owned(),
+            Uuid::new_v4(),
+            &clock,
+            &mut MockRng,
+        );
 
         // Assert
-        assert_eq!(snapshot.facts, vec!["quest_complete".to_owned()]);
-        assert_eq!(snapshot.version, 1);
+        assert!(matches!(result, Err(DomainError::Validation(m)) if m.contains("dragon_slain")));
+        assert!(snapshot.uncommitted_events().is_empty());
     }
 
     #[test]
@@ -305,6 +1158,7 @@ mod tests {
                 correlation_id: Uuid::new_v4(),
                 causation_id: Uuid::new_v4(),
                 occurred_at: fixed_now,
+                schema_version: current_schema_version("world_state.flag_set"),
             },
             kind: WorldStateEventKind::FlagSet(FlagSet {
                 world_id,
@@ -321,35 +1175,186 @@ mod tests {
         assert_eq!(snapshot.version, 1);
     }
 
-    #[test]
-    fn test_apply_disposition_updated_pushes_entity_id() {
-        // Arrange
-        let world_id = Uuid::new_v4();
-        let entity_id = Uuid::new_v4();
-        let fixed_now = Utc.with_ymd_and_hms(2026, 1, 15, 10, 0, 0).unwrap();
-        let mut snapshot = WorldSnapshot::new(world_id);
-        let event = WorldStateEvent {
+    fn event_at(
+        world_id: Uuid,
+        sequence_number: i64,
+        kind: WorldStateEventKind,
+    ) -> WorldStateEvent {
+        let event_type = kind.event_type();
+        WorldStateEvent {
             metadata: EventMetadata {
                 event_id: Uuid::new_v4(),
-                event_type: "world_state.disposition_updated".to_owned(),
+                event_type: event_type.to_owned(),
                 aggregate_id: world_id,
-                sequence_number: 1,
+                sequence_number,
                 correlation_id: Uuid::new_v4(),
                 causation_id: Uuid::new_v4(),
-                occurred_at: fixed_now,
+                occurred_at: Utc.with_ymd_and_hms(2026, 1, 15, 10, 0, 0).unwrap(),
+                schema_version: current_schema_version(event_type),
             },
-            kind: WorldStateEventKind::DispositionUpdated(DispositionUpdated {
+            kind,
+        }
+    }
+
+    fn updated(world_id: Uuid, entity_id: Uuid, delta: i32) -> WorldStateEventKind {
+        WorldStateEventKind::DispositionUpdated(DispositionUpdated {
+            world_id,
+            entity_id,
+            delta,
+            rippled_from: None,
+        })
+    }
+
+    #[test]
+    fn test_apply_disposition_updated_shifts_and_clamps_score() {
+        // Arrange
+        let world_id = Uuid::new_v4();
+        let entity_id = Uuid::new_v4();
+        let mut snapshot = WorldSnapshot::new(world_id);
+        snapshot.apply(&event_at(
+            world_id,
+            1,
+            WorldStateEventKind::DispositionSeeded(DispositionSeeded {
                 world_id,
                 entity_id,
+                score: 70,
             }),
-        };
+        ));
 
         // Act
-        snapshot.apply(&event);
+        snapshot.apply(&event_at(world_id, 2, updated(world_id, entity_id, 45)));
+        snapshot.apply(&event_at(world_id, 3, updated(world_id, entity_id, -30)));
+
+        // Assert — 70 + 45 is held at 100, then 100 − 30.
+        assert_eq!(snapshot.dispositions[&entity_id], 70);
+        assert_eq!(snapshot.version, 3);
+    }
+
+    #[test]
+    fn test_apply_disposition_updated_starts_unknown_entities_at_neutral() {
+        // Arrange
+        let world_id = Uuid::new_v4();
+        let entity_id = Uuid::new_v4();
+        let mut snapshot = WorldSnapshot::new(world_id);
+
+        // Act
+        snapshot.apply(&event_at(world_id, 1, updated(world_id, entity_id, -25)));
 
         // Assert
-        assert_eq!(snapshot.disposition_entity_ids, vec![entity_id]);
-        assert_eq!(snapshot.version, 1);
+        assert_eq!(snapshot.dispositions[&entity_id], -25);
+    }
+
+    #[test]
+    fn test_update_disposition_ripples_half_the_delta_to_each_faction() {
+        // Arrange
+        let world_id = Uuid::new_v4();
+        let guard_id = Uuid::new_v4();
+        let watch_id = Uuid::new_v4();
+        let guild_id = Uuid::new_v4();
+        let clock = FixedClock(Utc.with_ymd_and_hms(2026, 1, 15, 10, 0, 0).unwrap());
+        let mut snapshot = WorldSnapshot::new(world_id);
+        for (sequence_number, faction_id) in [(1, watch_id), (2, guild_id)] {
+            snapshot.apply(&event_at(
+                world_id,
+                sequence_number,
+                WorldStateEventKind::FactionJoined(FactionJoined {
+                    world_id,
+                    entity_id: guard_id,
+                    faction_id,
+                }),
+            ));
+        }
+
+        // Act
+        snapshot.update_disposition(guard_id, -15, Uuid::new_v4(), &clock, &mut MockRng);
+
+        // Assert
+        let events = snapshot.uncommitted_events();
+        assert_eq!(events.len(), 3);
+        let shifts: Vec<(Uuid, i32, Option<Uuid>)> = events
+            .iter()
+            .map(|event| match &event.kind {
+                WorldStateEventKind::DispositionUpdated(payload) => {
+                    (payload.entity_id, payload.delta, payload.rippled_from)
+                }
+                other => panic!("expected DispositionUpdated, got {other:?}"),
+            })
+            .collect();
+        let mut expected = vec![(guard_id, -15, None)];
+        let mut ripples = vec![
+            (watch_id, -7, Some(guard_id)),
+            (guild_id, -7, Some(guard_id)),
+        ];
+        ripples.sort();
+        expected.extend(ripples);
+        assert_eq!(shifts, expected);
+        let sequence_numbers: Vec<i64> = events
+            .iter()
+            .map(|event| event.metadata().sequence_number)
+            .collect();
+        assert_eq!(sequence_numbers, vec![3, 4, 5]);
+    }
+
+    #[test]
+    fn test_update_disposition_does_not_ripple_from_a_faction_to_its_members() {
+        // Arrange
+        let world_id = Uuid::new_v4();
+        let guard_id = Uuid::new_v4();
+        let watch_id = Uuid::new_v4();
+        let clock = FixedClock(Utc.with_ymd_and_hms(2026, 1, 15, 10, 0, 0).unwrap());
+        let mut snapshot = WorldSnapshot::new(world_id);
+        snapshot.apply(&event_at(
+            world_id,
+            1,
+            WorldStateEventKind::FactionJoined(FactionJoined {
+                world_id,
+                entity_id: guard_id,
+                faction_id: watch_id,
+            }),
+        ));
+
+        // Act
+        snapshot.update_disposition(watch_id, 20, Uuid::new_v4(), &clock, &mut MockRng);
+
+        // Assert
+        assert_eq!(snapshot.uncommitted_events().len(), 1);
+    }
+
+    #[test]
+    fn test_seed_disposition_rejects_out_of_range_score() {
+        // Arrange
+        let clock = FixedClock(Utc.with_ymd_and_hms(2026, 1, 15, 10, 0, 0).unwrap());
+        let mut snapshot = WorldSnapshot::new(Uuid::new_v4());
+
+        // Act
+        let result =
+            snapshot.seed_disposition(Uuid::new_v4(), 101, Uuid::new_v4(), &clock, &mut MockRng);
+
+        // Assert
+        assert!(matches!(result, Err(DomainError::Validation(_))));
+        assert!(snapshot.uncommitted_events().is_empty());
+    }
+
+    #[test]
+    fn test_join_faction_rejects_self_and_duplicate_membership() {
+        // Arrange
+        let guard_id = Uuid::new_v4();
+        let watch_id = Uuid::new_v4();
+        let clock = FixedClock(Utc.with_ymd_and_hms(2026, 1, 15, 10, 0, 0).unwrap());
+        let mut snapshot = WorldSnapshot::new(Uuid::new_v4());
+
+        // Act
+        let joined =
+            snapshot.join_faction(guard_id, watch_id, Uuid::new_v4(), &clock, &mut MockRng);
+        let again = snapshot.join_faction(guard_id, watch_id, Uuid::new_v4(), &clock, &mut MockRng);
+        let itself =
+            snapshot.join_faction(watch_id, watch_id, Uuid::new_v4(), &clock, &mut MockRng);
+
+        // Assert
+        assert!(joined.is_ok());
+        assert!(matches!(again, Err(DomainError::Validation(_))));
+        assert!(matches!(itself, Err(DomainError::Validation(_))));
+        assert_eq!(snapshot.uncommitted_events().len(), 1);
     }
 
     #[test]
@@ -480,6 +1485,7 @@ mod tests {
                 correlation_id: Uuid::new_v4(),
                 causation_id: Uuid::new_v4(),
                 occurred_at: fixed_now,
+                schema_version: current_schema_version("world_state.world_snapshot_archived"),
             },
             kind: WorldStateEventKind::WorldSnapshotArchived(
                 super::super::events::WorldSnapshotArchived { world_id },
@@ -517,6 +1523,7 @@ mod tests {
                 correlation_id: Uuid::new_v4(),
                 causation_id: Uuid::new_v4(),
                 occurred_at: fixed_now,
+                schema_version: current_schema_version("world_state.world_snapshot_archived"),
             },
             kind: WorldStateEventKind::WorldSnapshotArchived(
                 super::super::events::WorldSnapshotArchived { world_id },
@@ -542,14 +1549,14 @@ mod tests {
         let mut snapshot = WorldSnapshot::new(world_id);
 
         // Act
-        snapshot.update_disposition(entity_id, correlation_id, &clock, &mut MockRng);
+        snapshot.update_disposition(entity_id, 10, correlation_id, &clock, &mut MockRng);
 
         // Assert
         let events = snapshot.uncommitted_events();
         assert_eq!(events.len(), 1);
 
         let event = &events[0];
-        assert_eq!(event.event_type(), "world_state.disposition_updated");
+        assert_eq!(event.event_type(), DISPOSITION_UPDATED_EVENT_TYPE);
 
         let meta = event.metadata();
         assert_eq!(meta.aggregate_id, world_id);
@@ -562,8 +1569,448 @@ mod tests {
             WorldStateEventKind::DispositionUpdated(payload) => {
                 assert_eq!(payload.world_id, world_id);
                 assert_eq!(payload.entity_id, entity_id);
+                assert_eq!(payload.delta, 10);
+                assert_eq!(payload.rippled_from, None);
             }
             other => panic!("expected DispositionUpdated, got {other:?}"),
         }
     }
+
+    /// Applies the snapshot's uncommitted events, as loading them back from
+    /// the store would.
+    fn commit_pending(snapshot: &mut WorldSnapshot) {
+        let events = snapshot.uncommitted_events().to_vec();
+        snapshot.clear_uncommitted_events();
+        for event in &events {
+            snapshot.apply(event);
+        }
+    }
+
+    /// Returns a world with a market connected to a dock, a vault behind a
+    /// locked door from the market, and an island reachable from nowhere.
+    fn harbour(clock: &FixedClock) -> (WorldSnapshot, [Uuid; 4]) {
+        let ids = [
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+        ];
+        let [market, dock, vault, island] = ids;
+        let mut snapshot = WorldSnapshot::new(Uuid::new_v4());
+        for (id, name) in [
+            (market, "Market"),
+            (dock, "Dock"),
+            (vault, "Vault"),
+            (island, "Island"),
+        ] {
+            snapshot
+                .add_location(id, name.to_owned(), Uuid::new_v4(), clock, &mut MockRng)
+                .unwrap();
+        }
+        snapshot
+            .connect_locations(market, dock, false, Uuid::new_v4(), clock, &mut MockRng)
+            .unwrap();
+        snapshot
+            .connect_locations(market, vault, true, Uuid::new_v4(), clock, &mut MockRng)
+            .unwrap();
+        commit_pending(&mut snapshot);
+        (snapshot, ids)
+    }
+
+    #[test]
+    fn test_add_location_rejects_blank_names_and_duplicates() {
+        // Arrange
+        let clock = FixedClock(Utc.with_ymd_and_hms(2026, 1, 15, 10, 0, 0).unwrap());
+        let mut snapshot = WorldSnapshot::new(Uuid::new_v4());
+        let market = Uuid::new_v4();
+
+        // Act
+        let added = snapshot.add_location(
+            market,
+            "Market".to_owned(),
+            Uuid::new_v4(),
+            &clock,
+            &mut MockRng,
+        );
+        let again = snapshot.add_location(
+            market,
+            "Market".to_owned(),
+            Uuid::new_v4(),
+            &clock,
+            &mut MockRng,
+        );
+        let blank = snapshot.add_location(
+            Uuid::new_v4(),
+            " ".to_owned(),
+            Uuid::new_v4(),
+            &clock,
+            &mut MockRng,
+        );
+
+        // Assert
+        assert!(added.is_ok());
+        assert!(matches!(again, Err(DomainError::Validation(m)) if m.contains("already exists")));
+        assert!(matches!(blank, Err(DomainError::Validation(_))));
+        assert_eq!(snapshot.uncommitted_events().len(), 1);
+    }
+
+    #[test]
+    fn test_connect_locations_requires_two_existing_unconnected_locations() {
+        // Arrange
+        let clock = FixedClock(Utc.with_ymd_and_hms(2026, 1, 15, 10, 0, 0).unwrap());
+        let (mut snapshot, [market, dock, _, _]) = harbour(&clock);
+
+        // Act
+        let itself =
+            snapshot.connect_locations(market, market, false, Uuid::new_v4(), &clock, &mut MockRng);
+        let unknown = snapshot.connect_locations(
+            market,
+            Uuid::new_v4(),
+            false,
+            Uuid::new_v4(),
+            &clock,
+            &mut MockRng,
+        );
+        let reversed =
+            snapshot.connect_locations(dock, market, true, Uuid::new_v4(), &clock, &mut MockRng);
+
+        // Assert
+        assert!(matches!(itself, Err(DomainError::Validation(_))));
+        assert!(matches!(unknown, Err(DomainError::Validation(m)) if m.contains("does not exist")));
+        assert!(
+            matches!(reversed, Err(DomainError::Validation(m)) if m.contains("already connected"))
+        );
+        assert!(snapshot.uncommitted_events().is_empty());
+    }
+
+    #[test]
+    fn test_apply_connection_events_update_both_ends() {
+        // Arrange
+        let clock = FixedClock(Utc.with_ymd_and_hms(2026, 1, 15, 10, 0, 0).unwrap());
+        let (mut snapshot, [market, dock, vault, _]) = harbour(&clock);
+
+        // Act
+        snapshot
+            .set_connection_locked(vault, market, false, Uuid::new_v4(), &clock, &mut MockRng)
+            .unwrap();
+        snapshot
+            .set_connection_locked(market, dock, true, Uuid::new_v4(), &clock, &mut MockRng)
+            .unwrap();
+        commit_pending(&mut snapshot);
+
+        // Assert
+        assert!(!snapshot.connections[&market][&vault]);
+        assert!(!snapshot.connections[&vault][&market]);
+        assert!(snapshot.connections[&market][&dock]);
+        assert!(snapshot.connections[&dock][&market]);
+    }
+
+    #[test]
+    fn test_set_connection_locked_rejects_missing_connections_and_no_change() {
+        // Arrange
+        let clock = FixedClock(Utc.with_ymd_and_hms(2026, 1, 15, 10, 0, 0).unwrap());
+        let (mut snapshot, [market, dock, vault, island]) = harbour(&clock);
+
+        // Act
+        let missing = snapshot.set_connection_locked(
+            market,
+            island,
+            true,
+            Uuid::new_v4(),
+            &clock,
+            &mut MockRng,
+        );
+        let still_locked = snapshot.set_connection_locked(
+            vault,
+            market,
+            true,
+            Uuid::new_v4(),
+            &clock,
+            &mut MockRng,
+        );
+        let still_open = snapshot.set_connection_locked(
+            dock,
+            market,
+            false,
+            Uuid::new_v4(),
+            &clock,
+            &mut MockRng,
+        );
+
+        // Assert
+        assert!(matches!(missing, Err(DomainError::Validation(m)) if m.contains("not connected")));
+        assert!(
+            matches!(still_locked, Err(DomainError::Validation(m)) if m.contains("already locked"))
+        );
+        assert!(
+            matches!(still_open, Err(DomainError::Validation(m)) if m.contains("already unlocked"))
+        );
+    }
+
+    #[test]
+    fn test_move_party_follows_unlocked_connections_once_placed() {
+        // Arrange
+        let clock = FixedClock(Utc.with_ymd_and_hms(2026, 1, 15, 10, 0, 0).unwrap());
+        let (mut snapshot, [market, dock, vault, island]) = harbour(&clock);
+        snapshot
+            .move_party(market, Uuid::new_v4(), &clock, &mut MockRng)
+            .unwrap();
+        commit_pending(&mut snapshot);
+
+        // Act
+        let locked = snapshot.move_party(vault, Uuid::new_v4(), &clock, &mut MockRng);
+        let unconnected = snapshot.move_party(island, Uuid::new_v4(), &clock, &mut MockRng);
+        let staying = snapshot.move_party(market, Uuid::new_v4(), &clock, &mut MockRng);
+        let walked = snapshot.move_party(dock, Uuid::new_v4(), &clock, &mut MockRng);
+
+        // Assert
+        assert!(matches!(locked, Err(DomainError::Validation(m)) if m.contains("locked")));
+        assert!(
+            matches!(unconnected, Err(DomainError::Validation(m)) if m.contains("not connected"))
+        );
+        assert!(matches!(staying, Err(DomainError::Validation(m)) if m.contains("already at")));
+        assert!(walked.is_ok());
+        match &snapshot.uncommitted_events()[0].kind {
+            WorldStateEventKind::PartyMoved(moved) => {
+                assert_eq!(moved.from, Some(market));
+                assert_eq!(moved.to, dock);
+            }
+            other => panic!("expected PartyMoved, got {other:?}"),
+        }
+        commit_pending(&mut snapshot);
+        assert_eq!(snapshot.party_location(), Some(dock));
+    }
+
+    #[test]
+    fn test_move_npc_need_not_follow_connections() {
+        // Arrange
+        let clock = FixedClock(Utc.with_ymd_and_hms(2026, 1, 15, 10, 0, 0).unwrap());
+        let (mut snapshot, [market, _, _, island]) = harbour(&clock);
+        let smuggler = Uuid::new_v4();
+        snapshot
+            .move_npc(smuggler, market, Uuid::new_v4(), &clock, &mut MockRng)
+            .unwrap();
+        commit_pending(&mut snapshot);
+
+        // Act
+        let staying = snapshot.move_npc(smuggler, market, Uuid::new_v4(), &clock, &mut MockRng);
+        let nowhere = snapshot.move_npc(
+            smuggler,
+            Uuid::new_v4(),
+            Uuid::new_v4(),
+            &clock,
+            &mut MockRng,
+        );
+        let sailed = snapshot.move_npc(smuggler, island, Uuid::new_v4(), &clock, &mut MockRng);
+        commit_pending(&mut snapshot);
+
+        // Assert
+        assert!(matches!(staying, Err(DomainError::Validation(_))));
+        assert!(matches!(nowhere, Err(DomainError::Validation(m)) if m.contains("does not exist")));
+        assert!(sailed.is_ok());
+        assert_eq!(snapshot.npc_location(smuggler), Some(island));
+    }
+
+    #[test]
+    fn test_set_calendar_only_before_time_advances() {
+        // Arrange
+        let clock = FixedClock(Utc.with_ymd_and_hms(2026, 1, 15, 10, 0, 0).unwrap());
+        let mut snapshot = WorldSnapshot::new(Uuid::new_v4());
+        let short_days = Calendar {
+            hours_per_day: 12,
+            months: Vec::new(),
+        };
+
+        // Act
+        snapshot
+            .set_calendar(short_days.clone(), Uuid::new_v4(), &clock, &mut MockRng)
+            .unwrap();
+        snapshot
+            .advance_time(30, Uuid::new_v4(), &clock, &mut MockRng)
+            .unwrap();
+        commit_pending(&mut snapshot);
+        let too_late =
+            snapshot.set_calendar(Calendar::default(), Uuid::new_v4(), &clock, &mut MockRng);
+
+        // Assert
+        assert!(matches!(too_late, Err(DomainError::Validation(m)) if m.contains("cannot change")));
+        assert_eq!(snapshot.calendar(), &short_days);
+        assert_eq!(
+            snapshot.calendar().time_of(snapshot.elapsed_hours()),
+            GameTime { day: 3, hour: 6 }
+        );
+    }
+
+    #[test]
+    fn test_schedule_event_rejects_reached_times_and_unknown_destinations() {
+        // Arrange
+        let clock = FixedClock(Utc.with_ymd_and_hms(2026, 1, 15, 10, 0, 0).unwrap());
+        let (mut snapshot, [market, ..]) = harbour(&clock);
+        snapshot
+            .advance_time(5, Uuid::new_v4(), &clock, &mut MockRng)
+            .unwrap();
+        commit_pending(&mut snapshot);
+        let flag = ScheduledEffect::SetFlag {
+            flag_key: "tide_in".to_owned(),
+            value: true,
+        };
+
+        // Act
+        let reached = snapshot.schedule_event(
+            GameTime { day: 1, hour: 5 },
+            flag.clone(),
+            Uuid::new_v4(),
+            &clock,
+            &mut MockRng,
+        );
+        let nowhere = snapshot.schedule_event(
+            GameTime { day: 2, hour: 0 },
+            ScheduledEffect::MoveNpc {
+                entity_id: Uuid::new_v4(),
+                location_id: Uuid::new_v4(),
+            },
+            Uuid::new_v4(),
+            &clock,
+            &mut MockRng,
+        );
+        let later = snapshot.schedule_event(
+            GameTime { day: 1, hour: 6 },
+            ScheduledEffect::MoveNpc {
+                entity_id: Uuid::new_v4(),
+                location_id: market,
+            },
+            Uuid::new_v4(),
+            &clock,
+            &mut MockRng,
+        );
+
+        // Assert
+        assert!(
+            matches!(reached, Err(DomainError::Validation(m)) if m.contains("already been reached"))
+        );
+        assert!(matches!(nowhere, Err(DomainError::Validation(m)) if m.contains("does not exist")));
+        assert!(later.is_ok());
+        assert_eq!(snapshot.uncommitted_events().len(), 1);
+    }
+
+    #[test]
+    fn test_advance_time_fires_due_events_soonest_first() {
+        // Arrange
+        let clock = FixedClock(Utc.with_ymd_and_hms(2026, 1, 15, 10, 0, 0).unwrap());
+        let (mut snapshot, [market, dock, ..]) = harbour(&clock);
+        let mut rng = SeededRng::new(7);
+        let smuggler = Uuid::new_v4();
+        let mut schedule = |day, hour, effect| {
+            snapshot
+                .schedule_event(
+                    GameTime { day, hour },
+                    effect,
+                    Uuid::new_v4(),
+                    &clock,
+                    &mut rng,
+                )
+                .unwrap()
+        };
+        let flag = schedule(
+            1,
+            8,
+            ScheduledEffect::SetFlag {
+                flag_key: "tide_in".to_owned(),
+                value: true,
+            },
+        );
+        let to_dock = schedule(
+            1,
+            3,
+            ScheduledEffect::MoveNpc {
+                entity_id: smuggler,
+                location_id: dock,
+            },
+        );
+        let back_to_market = schedule(
+            1,
+            8,
+            ScheduledEffect::MoveNpc {
+                entity_id: smuggler,
+                location_id: market,
+            },
+        );
+        let tomorrow = schedule(
+            2,
+            0,
+            ScheduledEffect::SetFlag {
+                flag_key: "market_day".to_owned(),
+                value: true,
+            },
+        );
+        commit_pending(&mut snapshot);
+
+        // Act
+        snapshot
+            .advance_time(10, Uuid::new_v4(), &clock, &mut rng)
+            .unwrap();
+
+        // Assert — the move at hour 3 fires first, then the two hour-8 events
+        // in the order they were scheduled.
+        let fired: Vec<String> = snapshot
+            .uncommitted_events()
+            .iter()
+            .map(|event| match &event.kind {
+                WorldStateEventKind::TimeAdvanced(advanced) => {
+                    format!("time {}..{}", advanced.from, advanced.to)
+                }
+                WorldStateEventKind::ScheduledEventFired(fired) if fired.schedule_id == flag => {
+                    "fired flag".to_owned()
+                }
+                WorldStateEventKind::ScheduledEventFired(fired) if fired.schedule_id == to_dock => {
+                    "fired to_dock".to_owned()
+                }
+                WorldStateEventKind::ScheduledEventFired(fired)
+                    if fired.schedule_id == back_to_market =>
+                {
+                    "fired back_to_market".to_owned()
+                }
+                WorldStateEventKind::FlagSet(set) => format!("flag {}", set.flag_key),
+                WorldStateEventKind::NpcMoved(moved) if moved.from.is_none() => {
+                    "npc placed".to_owned()
+                }
+                WorldStateEventKind::NpcMoved(moved) if moved.from == Some(dock) => {
+                    "npc left dock".to_owned()
+                }
+                other => panic!("unexpected event {other:?}"),
+            })
+            .collect();
+        assert_eq!(
+            fired,
+            vec![
+                "time 0..10",
+                "fired to_dock",
+                "npc placed",
+                "fired flag",
+                "flag tide_in",
+                "fired back_to_market",
+                "npc left dock",
+            ]
+        );
+        commit_pending(&mut snapshot);
+        assert_eq!(snapshot.elapsed_hours(), 10);
+        assert_eq!(snapshot.npc_location(smuggler), Some(market));
+        assert_eq!(snapshot.flags.get("tide_in"), Some(&true));
+        let waiting: Vec<Uuid> = snapshot.schedule.iter().map(|e| e.schedule_id).collect();
+        assert_eq!(waiting, vec![tomorrow]);
+    }
+
+    #[test]
+    fn test_advance_time_rejects_zero_hours() {
+        // Arrange
+        let clock = FixedClock(Utc.with_ymd_and_hms(2026, 1, 15, 10, 0, 0).unwrap());
+        let mut snapshot = WorldSnapshot::new(Uuid::new_v4());
+
+        // Act
+        let result = snapshot.advance_time(0, Uuid::new_v4(), &clock, &mut MockRng);
+
+        // Assert
+        assert!(matches!(result, Err(DomainError::Validation(_))));
+        assert!(snapshot.uncommitted_events().is_empty());
+    }
 }
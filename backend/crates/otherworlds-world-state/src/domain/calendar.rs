@@ -0,0 +1,290 @@
+//! In-game time — the world's clock and the calendar that names its hours.
+//!
+//! World time is a count of whole hours since the first hour of day 1; it
+//! starts at 0 and only moves forward. A [`Calendar`] divides those hours into
+//! days and, when it names months, days into months that repeat every year.
+//! The calendar only changes how time is read: events and schedules always
+//! record the elapsed hour, so two worlds with different calendars agree on
+//! what happened when.
+//!
+//! Scheduled events fire in the order of the hour they are due at, and
+//! events due at the same hour fire in the order they were scheduled.
+
+use otherworlds_core::error::DomainError;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use super::events::ScheduledEffect;
+
+/// The hours in a day of the default calendar.
+pub const DEFAULT_HOURS_PER_DAY: u32 = 24;
+
+/// A named month of a calendar.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Month {
+    /// The month's name.
+    pub name: String,
+    /// The number of days in the month.
+    pub days: u32,
+}
+
+/// How a world counts its hours into days, months and years.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Calendar {
+    /// The number of hours in a day.
+    pub hours_per_day: u32,
+    /// The months of a year, in order. A calendar without months counts days
+    /// only.
+    #[serde(default)]
+    pub months: Vec<Month>,
+}
+
+impl Default for Calendar {
+    fn default() -> Self {
+        Self {
+            hours_per_day: DEFAULT_HOURS_PER_DAY,
+            months: Vec::new(),
+        }
+    }
+}
+
+/// A point in world time, as a day and an hour of that day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct GameTime {
+    /// The day, counting from 1.
+    pub day: u64,
+    /// The hour of the day, counting from 0.
+    pub hour: u32,
+}
+
+/// A day named by a calendar's months.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CalendarDate {
+    /// The year, counting from 1.
+    pub year: u64,
+    /// The month's name.
+    pub month: String,
+    /// The day of the month, counting from 1.
+    pub day_of_month: u32,
+}
+
+/// An effect waiting for world time to reach the hour it is due at.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScheduledEvent {
+    /// The scheduled event identifier.
+    pub schedule_id: Uuid,
+    /// The elapsed hour the event is due at.
+    pub at: u64,
+    /// What happens when it fires.
+    pub effect: ScheduledEffect,
+}
+
+impl Calendar {
+    /// Checks that the calendar has hours in a day and that every month has
+    /// a name, a unique one, and at least one day.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::Validation` describing the first problem found.
+    pub fn validate(&self) -> Result<(), DomainError> {
+        if self.hours_per_day == 0 {
+            return Err(DomainError::Validation(
+                "a day must have at least one hour".into(),
+            ));
+        }
+        for (index, month) in self.months.iter().enumerate() {
+            if month.name.trim().is_empty() {
+                return Err(DomainError::Validation(format!(
+                    "month {} must have a name",
+                    index + 1
+                )));
+            }
+            if month.days == 0 {
+                return Err(DomainError::Validation(format!(
+                    "month '{}' must have at least one day",
+                    month.name
+                )));
+            }
+            if self.months[..index].iter().any(|m| m.name == month.name) {
+                return Err(DomainError::Validation(format!(
+                    "month '{}' is named twice",
+                    month.name
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the day and hour `elapsed` hours after the first hour of
+    /// day 1.
+    #[must_use]
+    pub fn time_of(&self, elapsed: u64) -> GameTime {
+        let hours_per_day = u64::from(self.hours_per_day.max(1));
+        // The remainder is below `hours_per_day`, which is a `u32`.
+        #[allow(clippy::cast_possible_truncation)]
+        let hour = (elapsed % hours_per_day) as u32;
+        GameTime {
+            day: elapsed / hours_per_day + 1,
+            hour,
+        }
+    }
+
+    /// Returns the hours elapsed between the first hour of day 1 and `time`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `DomainError::Validation` if the day is 0, the hour is not an
+    /// hour of the day, or the time is too far away to count.
+    pub fn elapsed_at(&self, time: GameTime) -> Result<u64, DomainError> {
+        if time.day == 0 {
+            return Err(DomainError::Validation("days are counted from 1".into()));
+        }
+        if time.hour >= self.hours_per_day {
+            return Err(DomainError::Validation(format!(
+                "hour {} is not an hour of a {}-hour day",
+                time.hour, self.hours_per_day
+            )));
+        }
+        (time.day - 1)
+            .checked_mul(u64::from(self.hours_per_day))
+            .and_then(|hours| hours.checked_add(u64::from(time.hour)))
+            .ok_or_else(|| {
+                DomainError::Validation(format!("day {} is too far away to count", time.day))
+            })
+    }
+
+    /// Returns the date the calendar gives `day`, or `None` when it names no
+    /// months.
+    #[must_use]
+    pub fn date_of(&self, day: u64) -> Option<CalendarDate> {
+        let days_per_year: u64 = self.months.iter().map(|m| u64::from(m.days)).sum();
+        if days_per_year == 0 {
+            return None;
+        }
+        let index = day.saturating_sub(1);
+        let mut day_of_year = index % days_per_year;
+        for month in &self.months {
+            let days = u64::from(month.days);
+            if day_of_year < days {
+                // Below `month.days`, which is a `u32`.
+                #[allow(clippy::cast_possible_truncation)]
+                let day_of_month = day_of_year as u32 + 1;
+                return Some(CalendarDate {
+                    year: index / days_per_year + 1,
+                    month: month.name.clone(),
+                    day_of_month,
+                });
+            }
+            day_of_year -= days;
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn harvest_calendar() -> Calendar {
+        Calendar {
+            hours_per_day: 20,
+            months: vec![
+                Month {
+                    name: "Sowing".to_owned(),
+                    days: 10,
+                },
+                Month {
+                    name: "Harvest".to_owned(),
+                    days: 5,
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn test_time_of_and_elapsed_at_are_inverse() {
+        // Arrange
+        let calendar = harvest_calendar();
+
+        // Act
+        let time = calendar.time_of(45);
+
+        // Assert
+        assert_eq!(time, GameTime { day: 3, hour: 5 });
+        assert_eq!(calendar.elapsed_at(time).unwrap(), 45);
+        assert_eq!(Calendar::default().time_of(0), GameTime { day: 1, hour: 0 });
+    }
+
+    #[test]
+    fn test_elapsed_at_rejects_day_zero_and_hours_past_the_day() {
+        let calendar = harvest_calendar();
+
+        assert!(matches!(
+            calendar.elapsed_at(GameTime { day: 0, hour: 0 }),
+            Err(DomainError::Validation(_))
+        ));
+        assert!(matches!(
+            calendar.elapsed_at(GameTime { day: 1, hour: 20 }),
+            Err(DomainError::Validation(m)) if m.contains("20-hour day")
+        ));
+        assert!(matches!(
+            calendar.elapsed_at(GameTime {
+                day: u64::MAX,
+                hour: 0
+            }),
+            Err(DomainError::Validation(_))
+        ));
+    }
+
+    #[test]
+    fn test_date_of_wraps_months_into_years() {
+        // Arrange
+        let calendar = harvest_calendar();
+        let date = |year, month: &str, day_of_month| CalendarDate {
+            year,
+            month: month.to_owned(),
+            day_of_month,
+        };
+
+        // Act & Assert
+        assert_eq!(calendar.date_of(1), Some(date(1, "Sowing", 1)));
+        assert_eq!(calendar.date_of(11), Some(date(1, "Harvest", 1)));
+        assert_eq!(calendar.date_of(16), Some(date(2, "Sowing", 1)));
+        assert_eq!(Calendar::default().date_of(16), None);
+    }
+
+    #[test]
+    fn test_validate_rejects_empty_days_and_bad_months() {
+        let mut calendar = harvest_calendar();
+        calendar.months[1].name = "Sowing".to_owned();
+        assert!(matches!(
+            calendar.validate(),
+            Err(DomainError::Validation(m)) if m.contains("named twice")
+        ));
+
+        calendar.months[1].name = " ".to_owned();
+        assert!(matches!(
+            calendar.validate(),
+            Err(DomainError::Validation(m)) if m.contains("month 2")
+        ));
+
+        calendar.months[1] = Month {
+            name: "Frost".to_owned(),
+            days: 0,
+        };
+        assert!(matches!(
+            calendar.validate(),
+            Err(DomainError::Validation(m)) if m.contains("Frost")
+        ));
+
+        let no_hours = Calendar {
+            hours_per_day: 0,
+            months: Vec::new(),
+        };
+        assert!(matches!(
+            no_hours.validate(),
+            Err(DomainError::Validation(_))
+        ));
+        assert!(harvest_calendar().validate().is_ok());
+    }
+}
@@ -1,13 +1,22 @@
 //! Commands for the World State context.
 
 use otherworlds_core::command::Command;
+use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-/// Command to apply an effect to the world state.
-#[derive(Debug, Clone)]
+use super::calendar::{Calendar, GameTime};
+use super::events::{FactValue, ScheduledEffect};
+
+/// Command to apply an effect to the world state, asserting its fact key as
+/// `true`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ApplyEffect {
     /// The correlation ID for tracing.
     pub correlation_id: Uuid,
+    /// The event that caused this command, when it was dispatched in
+    /// reaction to another context's event.
+    #[serde(default)]
+    pub causation_id: Option<Uuid>,
     /// The world snapshot identifier.
     pub world_id: Uuid,
     /// The fact key to apply.
@@ -24,11 +33,67 @@ impl Command for ApplyEffect {
     }
 }
 
+/// Command to assert a world fact.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AssertFact {
+    /// The correlation ID for tracing.
+    pub correlation_id: Uuid,
+    /// The event that caused this command, when it was dispatched in
+    /// reaction to another context's event.
+    #[serde(default)]
+    pub causation_id: Option<Uuid>,
+    /// The world snapshot identifier.
+    pub world_id: Uuid,
+    /// The fact key.
+    pub key: String,
+    /// The fact's value.
+    pub value: FactValue,
+}
+
+impl Command for AssertFact {
+    fn command_type(&self) -> &'static str {
+        "world_state.assert_fact"
+    }
+
+    fn correlation_id(&self) -> Uuid {
+        self.correlation_id
+    }
+}
+
+/// Command to retract a world fact.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RetractFact {
+    /// The correlation ID for tracing.
+    pub correlation_id: Uuid,
+    /// The event that caused this command, when it was dispatched in
+    /// reaction to another context's event.
+    #[serde(default)]
+    pub causation_id: Option<Uuid>,
+    /// The world snapshot identifier.
+    pub world_id: Uuid,
+    /// The fact key.
+    pub key: String,
+}
+
+impl Command for RetractFact {
+    fn command_type(&self) -> &'static str {
+        "world_state.retract_fact"
+    }
+
+    fn correlation_id(&self) -> Uuid {
+        self.correlation_id
+    }
+}
+
 /// Command to set a flag in the world state.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SetFlag {
     /// The correlation ID for tracing.
     pub correlation_id: Uuid,
+    /// The event that caused this command, when it was dispatched in
+    /// reaction to another context's event.
+    #[serde(default)]
+    pub causation_id: Option<Uuid>,
     /// The world snapshot identifier.
     pub world_id: Uuid,
     /// The flag key.
@@ -47,15 +112,23 @@ impl Command for SetFlag {
     }
 }
 
-/// Command to update a disposition.
-#[derive(Debug, Clone)]
+/// Command to shift an entity's disposition score.
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UpdateDisposition {
     /// The correlation ID for tracing.
     pub correlation_id: Uuid,
+    /// The event that caused this command, when it was dispatched in
+    /// reaction to another context's event.
+    #[serde(default)]
+    pub causation_id: Option<Uuid>,
     /// The world snapshot identifier.
     pub world_id: Uuid,
     /// The entity whose disposition to update.
     pub entity_id: Uuid,
+    /// The amount to shift the score by. Commands recorded before scores
+    /// existed carry none and shift by 0.
+    #[serde(default)]
+    pub delta: i32,
 }
 
 impl Command for UpdateDisposition {
@@ -68,8 +141,255 @@ impl Command for UpdateDisposition {
     }
 }
 
+/// The starting disposition of one entity.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DispositionSeed {
+    /// The entity to seed.
+    pub entity_id: Uuid,
+    /// The starting score.
+    pub score: i32,
+    /// The factions the entity belongs to.
+    #[serde(default)]
+    pub factions: Vec<Uuid>,
+}
+
+/// Command to give entities their starting dispositions and faction
+/// memberships. Entities that already have a score keep it, and
+/// memberships that already exist are left alone, so seeding twice is
+/// harmless.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SeedDispositions {
+    /// The correlation ID for tracing.
+    pub correlation_id: Uuid,
+    /// The world snapshot identifier.
+    pub world_id: Uuid,
+    /// The entities to seed.
+    pub seeds: Vec<DispositionSeed>,
+}
+
+impl Command for SeedDispositions {
+    fn command_type(&self) -> &'static str {
+        "world_state.seed_dispositions"
+    }
+
+    fn correlation_id(&self) -> Uuid {
+        self.correlation_id
+    }
+}
+
+/// Command to add an entity to a faction.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JoinFaction {
+    /// The correlation ID for tracing.
+    pub correlation_id: Uuid,
+    /// The world snapshot identifier.
+    pub world_id: Uuid,
+    /// The entity joining the faction.
+    pub entity_id: Uuid,
+    /// The faction to join.
+    pub faction_id: Uuid,
+}
+
+impl Command for JoinFaction {
+    fn command_type(&self) -> &'static str {
+        "world_state.join_faction"
+    }
+
+    fn correlation_id(&self) -> Uuid {
+        self.correlation_id
+    }
+}
+
+/// A location to seed, with the NPCs that start there.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LocationSeed {
+    /// The location identifier.
+    pub location_id: Uuid,
+    /// The location's display name.
+    pub name: String,
+    /// The NPCs placed at the location.
+    #[serde(default)]
+    pub npcs: Vec<Uuid>,
+}
+
+/// A connection to seed between two locations.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConnectionSeed {
+    /// One end of the connection.
+    pub location_id: Uuid,
+    /// The other end of the connection.
+    pub other_location_id: Uuid,
+    /// Whether the connection starts locked.
+    #[serde(default)]
+    pub locked: bool,
+}
+
+/// Command to give a world its locations, their connections, and the
+/// starting places of the party and NPCs. Locations and connections that
+/// already exist are left alone, as are the party and NPCs once placed, so
+/// seeding twice is harmless.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SeedLocations {
+    /// The correlation ID for tracing.
+    pub correlation_id: Uuid,
+    /// The world snapshot identifier.
+    pub world_id: Uuid,
+    /// The locations to seed.
+    pub locations: Vec<LocationSeed>,
+    /// The connections to seed.
+    #[serde(default)]
+    pub connections: Vec<ConnectionSeed>,
+    /// Where the party starts, if anywhere.
+    #[serde(default)]
+    pub party_location: Option<Uuid>,
+}
+
+impl Command for SeedLocations {
+    fn command_type(&self) -> &'static str {
+        "world_state.seed_locations"
+    }
+
+    fn correlation_id(&self) -> Uuid {
+        self.correlation_id
+    }
+}
+
+/// Command to lock or unlock the connection between two locations.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SetConnectionLock {
+    /// The correlation ID for tracing.
+    pub correlation_id: Uuid,
+    /// The world snapshot identifier.
+    pub world_id: Uuid,
+    /// One end of the connection.
+    pub location_id: Uuid,
+    /// The other end of the connection.
+    pub other_location_id: Uuid,
+    /// Whether the connection should be locked.
+    pub locked: bool,
+}
+
+impl Command for SetConnectionLock {
+    fn command_type(&self) -> &'static str {
+        "world_state.set_connection_lock"
+    }
+
+    fn correlation_id(&self) -> Uuid {
+        self.correlation_id
+    }
+}
+
+/// Command to move the party to a location.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MoveParty {
+    /// The correlation ID for tracing.
+    pub correlation_id: Uuid,
+    /// The world snapshot identifier.
+    pub world_id: Uuid,
+    /// The location to move to.
+    pub location_id: Uuid,
+}
+
+impl Command for MoveParty {
+    fn command_type(&self) -> &'static str {
+        "world_state.move_party"
+    }
+
+    fn correlation_id(&self) -> Uuid {
+        self.correlation_id
+    }
+}
+
+/// Command to move an NPC to a location.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MoveNpc {
+    /// The correlation ID for tracing.
+    pub correlation_id: Uuid,
+    /// The world snapshot identifier.
+    pub world_id: Uuid,
+    /// The NPC to move.
+    pub entity_id: Uuid,
+    /// The location to move to.
+    pub location_id: Uuid,
+}
+
+impl Command for MoveNpc {
+    fn command_type(&self) -> &'static str {
+        "world_state.move_npc"
+    }
+
+    fn correlation_id(&self) -> Uuid {
+        self.correlation_id
+    }
+}
+
+/// Command to set the calendar a world's time is read with.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SetCalendar {
+    /// The correlation ID for tracing.
+    pub correlation_id: Uuid,
+    /// The world snapshot identifier.
+    pub world_id: Uuid,
+    /// The new calendar.
+    pub calendar: Calendar,
+}
+
+impl Command for SetCalendar {
+    fn command_type(&self) -> &'static str {
+        "world_state.set_calendar"
+    }
+
+    fn correlation_id(&self) -> Uuid {
+        self.correlation_id
+    }
+}
+
+/// Command to advance world time, firing the scheduled events it reaches.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AdvanceTime {
+    /// The correlation ID for tracing.
+    pub correlation_id: Uuid,
+    /// The world snapshot identifier.
+    pub world_id: Uuid,
+    /// The number of hours to advance by.
+    pub hours: u64,
+}
+
+impl Command for AdvanceTime {
+    fn command_type(&self) -> &'static str {
+        "world_state.advance_time"
+    }
+
+    fn correlation_id(&self) -> Uuid {
+        self.correlation_id
+    }
+}
+
+/// Command to schedule an effect for a later time.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScheduleEvent {
+    /// The correlation ID for tracing.
+    pub correlation_id: Uuid,
+    /// The world snapshot identifier.
+    pub world_id: Uuid,
+    /// When the event is due.
+    pub at: GameTime,
+    /// What happens when it fires.
+    pub effect: ScheduledEffect,
+}
+
+impl Command for ScheduleEvent {
+    fn command_type(&self) -> &'static str {
+        "world_state.schedule_event"
+    }
+
+    fn correlation_id(&self) -> Uuid {
+        self.correlation_id
+    }
+}
+
 /// Command to archive (soft-delete) a world snapshot.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ArchiveWorldSnapshot {
     /// The correlation ID for tracing.
     pub correlation_id: Uuid,
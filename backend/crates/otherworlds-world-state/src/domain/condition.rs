@@ -0,0 +1,886 @@
+//! Conditions — questions content and narrative ask of a world's state.
+//!
+//! A condition is written as text such as
+//! `` flag door_open and disposition(guard_captain) >= friendly and fact gold >= 10 ``
+//! and is parsed once into a [`Condition`], which can then be evaluated
+//! against any world snapshot.
+//!
+//! ```text
+//! condition   := or
+//! or          := and ("or" and)*
+//! and         := not ("and" not)*
+//! not         := "not" not | atom
+//! atom        := "(" condition ")" | "true" | "false"
+//!              | "flag" key ["is" ("true" | "false")]
+//!              | "fact" key ("exists" | comparison value)
+//!              | "disposition" "(" entity ")" comparison (band | score)
+//!              | ("day" | "hour") comparison number
+//! comparison  := "==" | "!=" | "<" | "<=" | ">" | ">="
+//! value       := number | "\"" text "\"" | "true" | "false" | uuid
+//! ```
+//!
+//! Keys and entity names are bare words, or wrapped in backticks when they
+//! clash with a keyword. Entities are given by UUID or by a name the caller
+//! resolves to one. Unset flags are false, an entity without a disposition
+//! is neutral (score 0), and a comparison with a fact that is not asserted
+//! does not hold. `day` and `hour` read the world's calendar.
+//!
+//! Conditions arrive from clients, so their length and how deeply
+//! parentheses and `not`s nest are bounded ([`MAX_LENGTH`], [`MAX_NESTING`])
+//! to keep parsing and evaluation off the end of the stack.
+//!
+//! Both sides of `and` and `or` are always evaluated, so a condition that
+//! compares a fact with a value of the wrong type fails the same way
+//! whatever the other side holds.
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
+
+use otherworlds_core::error::DomainError;
+use thiserror::Error;
+use uuid::Uuid;
+
+use super::aggregates::WorldSnapshot;
+use super::disposition::DispositionBand;
+use super::events::FactValue;
+
+/// The most characters a condition may have.
+pub const MAX_LENGTH: usize = 4096;
+
+/// The deepest parentheses and `not`s may nest in a condition.
+pub const MAX_NESTING: usize = 64;
+
+/// Why a condition could not be parsed or evaluated.
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum ConditionError {
+    /// The condition has no text.
+    #[error("condition is empty")]
+    Empty,
+    /// A token appeared where the grammar does not allow it.
+    #[error("unexpected {found} at column {column}; expected {expected}")]
+    Unexpected {
+        /// The 1-based column of the token.
+        column: usize,
+        /// The token, as written.
+        found: String,
+        /// What the grammar allows at that point.
+        expected: &'static str,
+    },
+    /// The condition ended where the grammar expects more.
+    #[error("condition ends early; expected {expected}")]
+    UnexpectedEnd {
+        /// What the grammar expects next.
+        expected: &'static str,
+    },
+    /// A quoted string or key has no closing quote.
+    #[error("unterminated {what} starting at column {column}")]
+    Unterminated {
+        /// The 1-based column of the opening quote.
+        column: usize,
+        /// `"string"` or `"key"`.
+        what: &'static str,
+    },
+    /// A word is not a valid value of the kind the grammar expects.
+    #[error("'{value}' at column {column} is not a valid {what}")]
+    InvalidValue {
+        /// The 1-based column of the word.
+        column: usize,
+        /// The word, as written.
+        value: String,
+        /// The kind of value expected.
+        what: &'static str,
+    },
+    /// An ordering comparison was given a value that has no order.
+    #[error("a {kind} at column {column} can only be compared with == or !=")]
+    Unordered {
+        /// The 1-based column of the value.
+        column: usize,
+        /// The kind of value.
+        kind: &'static str,
+    },
+    /// A fact holds a value of a different kind from the one it is compared with.
+    #[error("fact '{key}' holds a {found}, not a {expected}")]
+    TypeMismatch {
+        /// The fact key.
+        key: String,
+        /// The kind of value the condition compares it with.
+        expected: &'static str,
+        /// The kind of value the fact holds.
+        found: &'static str,
+    },
+    /// An entity name the caller did not resolve to an ID.
+    #[error("unknown entity '{0}'")]
+    UnknownEntity(String),
+    /// The condition is longer than a condition may be.
+    #[error("condition is longer than {limit} characters")]
+    TooLong {
+        /// The most characters a condition may have.
+        limit: usize,
+    },
+    /// Parentheses or `not`s nest deeper than a condition may.
+    #[error("condition nests deeper than {limit} levels")]
+    TooDeep {
+        /// The deepest a condition may nest.
+        limit: usize,
+    },
+}
+
+impl From<ConditionError> for DomainError {
+    fn from(error: ConditionError) -> Self {
+        Self::Validation(error.to_string())
+    }
+}
+
+/// How a comparison relates a world value to the condition's value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Comparison {
+    /// `==`
+    Eq,
+    /// `!=`
+    Ne,
+    /// `<`
+    Lt,
+    /// `<=`
+    Le,
+    /// `>`
+    Gt,
+    /// `>=`
+    Ge,
+}
+
+impl Comparison {
+    /// Returns whether `actual` relates to `expected` this way.
+    #[must_use]
+    pub fn holds<T: Ord + ?Sized>(self, actual: &T, expected: &T) -> bool {
+        match self {
+            Self::Eq => actual == expected,
+            Self::Ne => actual != expected,
+            Self::Lt => actual < expected,
+            Self::Le => actual <= expected,
+            Self::Gt => actual > expected,
+            Self::Ge => actual >= expected,
+        }
+    }
+
+    /// Returns the comparison as written in a condition.
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Eq => "==",
+            Self::Ne => "!=",
+            Self::Lt => "<",
+            Self::Le => "<=",
+            Self::Gt => ">",
+            Self::Ge => ">=",
+        }
+    }
+
+    fn is_equality(self) -> bool {
+        matches!(self, Self::Eq | Self::Ne)
+    }
+}
+
+/// An entity named in a condition.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EntityRef {
+    /// The entity's world ID.
+    Id(Uuid),
+    /// A name the caller resolves to a world ID.
+    Name(String),
+}
+
+/// What a disposition is compared with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DispositionTarget {
+    /// The band the score falls in.
+    Band(DispositionBand),
+    /// The score itself.
+    Score(i32),
+}
+
+/// Which part of the world's time a condition reads.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeUnit {
+    /// The day, counting from 1.
+    Day,
+    /// The hour of the day, counting from 0.
+    Hour,
+}
+
+/// A parsed condition.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Condition {
+    /// `true` or `false`.
+    Literal(bool),
+    /// Holds when the inner condition does not.
+    Not(Box<Condition>),
+    /// Holds when both conditions do.
+    And(Box<Condition>, Box<Condition>),
+    /// Holds when either condition does.
+    Or(Box<Condition>, Box<Condition>),
+    /// Holds when the flag has the value; unset flags are false.
+    Flag {
+        /// The flag key.
+        key: String,
+        /// The value the flag must have.
+        value: bool,
+    },
+    /// Holds when the fact is asserted.
+    FactExists {
+        /// The fact key.
+        key: String,
+    },
+    /// Holds when the fact is asserted and compares with the value.
+    Fact {
+        /// The fact key.
+        key: String,
+        /// How the fact is compared.
+        comparison: Comparison,
+        /// The value it is compared with.
+        value: FactValue,
+    },
+    /// Holds when the entity's disposition compares with the target.
+    Disposition {
+        /// The entity.
+        entity: EntityRef,
+        /// How the disposition is compared.
+        comparison: Comparison,
+        /// The band or score it is compared with.
+        target: DispositionTarget,
+    },
+    /// Holds when the current day or hour compares with the value.
+    Time {
+        /// The part of the time read.
+        unit: TimeUnit,
+        /// How it is compared.
+        comparison: Comparison,
+        /// The value it is compared with.
+        value: u64,
+    },
+}
+
+impl FromStr for Condition {
+    type Err = ConditionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.chars().count() > MAX_LENGTH {
+            return Err(ConditionError::TooLong { limit: MAX_LENGTH });
+        }
+        Parser {
+            tokens: tokenize(s)?,
+            position: 0,
+            depth: 0,
+        }
+        .parse()
+    }
+}
+
+impl Condition {
+    /// Evaluates the condition against `world`, resolving entity names
+    /// through `entities`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ConditionError::UnknownEntity` if an entity name is not in
+    /// `entities`, and `ConditionError::TypeMismatch` if a fact holds a
+    /// value of a different kind from the one it is compared with.
+    pub fn evaluate(
+        &self,
+        world: &WorldSnapshot,
+        entities: &BTreeMap<String, Uuid>,
+    ) -> Result<bool, ConditionError> {
+        Ok(match self {
+            Self::Literal(value) => *value,
+            Self::Not(inner) => !inner.evaluate(world, entities)?,
+            Self::And(left, right) => {
+                let left = left.evaluate(world, entities)?;
+                let right = right.evaluate(world, entities)?;
+                left && right
+            }
+            Self::Or(left, right) => {
+                let left = left.evaluate(world, entities)?;
+                let right = right.evaluate(world, entities)?;
+                left || right
+            }
+            Self::Flag { key, value } => world.flags.get(key).copied().unwrap_or(false) == *value,
+            Self::FactExists { key } => world.facts.contains_key(key),
+            Self::Fact {
+                key,
+                comparison,
+                value,
+            } => match world.facts.get(key) {
+                Some(actual) => compare_fact(key, actual, *comparison, value)?,
+                None => false,
+            },
+            Self::Disposition {
+                entity,
+                comparison,
+                target,
+            } => {
+                let entity_id = match entity {
+                    EntityRef::Id(id) => *id,
+                    EntityRef::Name(name) => *entities
+                        .get(name)
+                        .ok_or_else(|| ConditionError::UnknownEntity(name.clone()))?,
+                };
+                let score = world.dispositions.get(&entity_id).copied().unwrap_or(0);
+                match target {
+                    DispositionTarget::Band(band) => {
+                        comparison.holds(&DispositionBand::of(score), band)
+                    }
+                    DispositionTarget::Score(expected) => comparison.holds(&score, expected),
+                }
+            }
+            Self::Time {
+                unit,
+                comparison,
+                value,
+            } => {
+                let now = world.calendar.time_of(world.elapsed_hours);
+                let actual = match unit {
+                    TimeUnit::Day => now.day,
+                    TimeUnit::Hour => u64::from(now.hour),
+                };
+                comparison.holds(&actual, value)
+            }
+        })
+    }
+}
+
+fn compare_fact(
+    key: &str,
+    actual: &FactValue,
+    comparison: Comparison,
+    expected: &FactValue,
+) -> Result<bool, ConditionError> {
+    match (actual, expected) {
+        (FactValue::Number(actual), FactValue::Number(expected)) => {
+            Ok(comparison.holds(actual, expected))
+        }
+        // Only `==` and `!=` parse with values other than numbers.
+        _ if kind(actual) == kind(expected) => {
+            Ok((actual == expected) == (comparison == Comparison::Eq))
+        }
+        _ => Err(ConditionError::TypeMismatch {
+            key: key.to_owned(),
+            expected: kind(expected),
+            found: kind(actual),
+        }),
+    }
+}
+
+fn kind(value: &FactValue) -> &'static str {
+    match value {
+        FactValue::String(_) => "string",
+        FactValue::Number(_) => "number",
+        FactValue::Bool(_) => "bool",
+        FactValue::Entity(_) => "entity",
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum TokenKind {
+    Word(String),
+    Text(String),
+    Key(String),
+    Open,
+    Close,
+    Compare(Comparison),
+}
+
+#[derive(Debug, Clone)]
+struct Token {
+    kind: TokenKind,
+    column: usize,
+}
+
+impl fmt::Display for Token {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            TokenKind::Word(word) => write!(f, "'{word}'"),
+            TokenKind::Text(text) => write!(f, "\"{text}\""),
+            TokenKind::Key(key) => write!(f, "`{key}`"),
+            TokenKind::Open => f.write_str("'('"),
+            TokenKind::Close => f.write_str("')'"),
+            TokenKind::Compare(comparison) => write!(f, "'{}'", comparison.as_str()),
+        }
+    }
+}
+
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+fn tokenize(source: &str) -> Result<Vec<Token>, ConditionError> {
+    let chars: Vec<char> = source.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        let column = i + 1;
+        if c.is_whitespace() {
+            i += 1;
+            continue;
+        }
+        let kind = match c {
+            '(' => {
+                i += 1;
+                TokenKind::Open
+            }
+            ')' => {
+                i += 1;
+                TokenKind::Close
+            }
+            '"' | '`' => {
+                let what = if c == '"' { "string" } else { "key" };
+                let length = chars[i + 1..]
+                    .iter()
+                    .position(|&d| d == c)
+                    .ok_or(ConditionError::Unterminated { column, what })?;
+                let text = chars[i + 1..=i + length].iter().collect();
+                i += length + 2;
+                if c == '"' {
+                    TokenKind::Text(text)
+                } else {
+                    TokenKind::Key(text)
+                }
+            }
+            '=' | '!' | '<' | '>' => {
+                let (comparison, length) = match (c, chars.get(i + 1)) {
+                    ('=', Some('=')) => (Comparison::Eq, 2),
+                    ('!', Some('=')) => (Comparison::Ne, 2),
+                    ('<', Some('=')) => (Comparison::Le, 2),
+                    ('>', Some('=')) => (Comparison::Ge, 2),
+                    ('<', _) => (Comparison::Lt, 1),
+                    ('>', _) => (Comparison::Gt, 1),
+                    _ => {
+                        return Err(ConditionError::Unexpected {
+                            column,
+                            found: format!("'{c}'"),
+                            expected: "a comparison such as == or >=",
+                        });
+                    }
+                };
+                i += length;
+                TokenKind::Compare(comparison)
+            }
+            _ if is_word_char(c)
+                || (c == '-' && chars.get(i + 1).is_some_and(char::is_ascii_digit)) =>
+            {
+                let start = i;
+                i += 1;
+                while i < chars.len() && (is_word_char(chars[i]) || chars[i] == '-') {
+                    i += 1;
+                }
+                TokenKind::Word(chars[start..i].iter().collect())
+            }
+            _ => {
+                return Err(ConditionError::Unexpected {
+                    column,
+                    found: format!("'{c}'"),
+                    expected: "a condition",
+                });
+            }
+        };
+        tokens.push(Token { kind, column });
+    }
+    Ok(tokens)
+}
+
+/// What an atom may start with, for error messages.
+const EXPECTED_CONDITION: &str =
+    "'flag', 'fact', 'disposition', 'day', 'hour', 'not', 'true', 'false' or '('";
+
+/// A recursive-descent parser over the tokens of a condition.
+struct Parser {
+    tokens: Vec<Token>,
+    position: usize,
+    /// How many parentheses and `not`s enclose the current position.
+    depth: usize,
+}
+
+impl Parser {
+    fn parse(mut self) -> Result<Condition, ConditionError> {
+        if self.tokens.is_empty() {
+            return Err(ConditionError::Empty);
+        }
+        let condition = self.or()?;
+        match self.tokens.get(self.position) {
+            Some(token) => Err(unexpected(token, "'and', 'or' or the end of the condition")),
+            None => Ok(condition),
+        }
+    }
+
+    fn next(&mut self, expected: &'static str) -> Result<Token, ConditionError> {
+        let token = self
+            .tokens
+            .get(self.position)
+            .cloned()
+            .ok_or(ConditionError::UnexpectedEnd { expected })?;
+        self.position += 1;
+        Ok(token)
+    }
+
+    fn eat_word(&mut self, word: &str) -> bool {
+        let found = matches!(
+            self.tokens.get(self.position),
+            Some(Token { kind: TokenKind::Word(w), .. }) if w == word
+        );
+        if found {
+            self.position += 1;
+        }
+        found
+    }
+
+    fn expect(&mut self, kind: &TokenKind, expected: &'static str) -> Result<(), ConditionError> {
+        let token = self.next(expected)?;
+        if token.kind == *kind {
+            Ok(())
+        } else {
+            Err(unexpected(&token, expected))
+        }
+    }
+
+    /// Parses a condition one level deeper, failing past [`MAX_NESTING`].
+    fn nested(
+        &mut self,
+        parse: fn(&mut Self) -> Result<Condition, ConditionError>,
+    ) -> Result<Condition, ConditionError> {
+        if self.depth >= MAX_NESTING {
+            return Err(ConditionError::TooDeep { limit: MAX_NESTING });
+        }
+        self.depth += 1;
+        let condition = parse(self);
+        self.depth -= 1;
+        condition
+    }
+
+    fn or(&mut self) -> Result<Condition, ConditionError> {
+        let mut condition = self.and()?;
+        while self.eat_word("or") {
+            condition = Condition::Or(Box::new(condition), Box::new(self.and()?));
+        }
+        Ok(condition)
+    }
+
+    fn and(&mut self) -> Result<Condition, ConditionError> {
+        let mut condition = self.not()?;
+        while self.eat_word("and") {
+            condition = Condition::And(Box::new(condition), Box::new(self.not()?));
+        }
+        Ok(condition)
+    }
+
+    fn not(&mut self) -> Result<Condition, ConditionError> {
+        if self.eat_word("not") {
+            Ok(Condition::Not(Box::new(self.nested(Self::not)?)))
+        } else {
+            self.atom()
+        }
+    }
+
+    fn atom(&mut self) -> Result<Condition, ConditionError> {
+        let token = self.next(EXPECTED_CONDITION)?;
+        match &token.kind {
+            TokenKind::Open => {
+                let condition = self.nested(Self::or)?;
+                self.expect(&TokenKind::Close, "')'")?;
+                Ok(condition)
+            }
+            TokenKind::Word(word) => match word.as_str() {
+                "true" => Ok(Condition::Literal(true)),
+                "false" => Ok(Condition::Literal(false)),
+                "flag" => self.flag(),
+                "fact" => self.fact(),
+                "disposition" => self.disposition(),
+                "day" => self.time(TimeUnit::Day),
+                "hour" => self.time(TimeUnit::Hour),
+                _ => Err(unexpected(&token, EXPECTED_CONDITION)),
+            },
+            _ => Err(unexpected(&token, EXPECTED_CONDITION)),
+        }
+    }
+
+    fn name(&mut self, expected: &'static str) -> Result<String, ConditionError> {
+        let token = self.next(expected)?;
+        match token.kind {
+            TokenKind::Word(name) | TokenKind::Key(name) => Ok(name),
+            _ => Err(unexpected(&token, expected)),
+        }
+    }
+
+    fn comparison(&mut self) -> Result<Comparison, ConditionError> {
+        const EXPECTED: &str = "a comparison such as == or >=";
+        let token = self.next(EXPECTED)?;
+        match token.kind {
+            TokenKind::Compare(comparison) => Ok(comparison),
+            _ => Err(unexpected(&token, EXPECTED)),
+        }
+    }
+
+    fn flag(&mut self) -> Result<Condition, ConditionError> {
+        let key = self.name("a flag key")?;
+        let value = if self.eat_word("is") {
+            if self.eat_word("true") {
+                true
+            } else if self.eat_word("false") {
+                false
+            } else {
+                let token = self.next("'true' or 'false'")?;
+                return Err(unexpected(&token, "'true' or 'false'"));
+            }
+        } else {
+            true
+        };
+        Ok(Condition::Flag { key, value })
+    }
+
+    fn fact(&mut self) -> Result<Condition, ConditionError> {
+        let key = self.name("a fact key")?;
+        if self.eat_word("exists") {
+            return Ok(Condition::FactExists { key });
+        }
+        let comparison = self.comparison()?;
+        let token = self.next("a value")?;
+        let value = match &token.kind {
+            TokenKind::Text(text) => FactValue::String(text.clone()),
+            TokenKind::Word(word) if word == "true" => FactValue::Bool(true),
+            TokenKind::Word(word) if word == "false" => FactValue::Bool(false),
+            TokenKind::Word(word) => {
+                if let Ok(number) = word.parse() {
+                    FactValue::Number(number)
+                } else if let Ok(id) = Uuid::parse_str(word) {
+                    FactValue::Entity(id)
+                } else {
+                    return Err(ConditionError::InvalidValue {
+                        column: token.column,
+                        value: word.clone(),
+                        what: "number, \"string\", true, false or entity ID",
+                    });
+                }
+            }
+            _ => return Err(unexpected(&token, "a value")),
+        };
+        if !comparison.is_equality() && !matches!(value, FactValue::Number(_)) {
+            return Err(ConditionError::Unordered {
+                column: token.column,
+                kind: kind(&value),
+            });
+        }
+        Ok(Condition::Fact {
+            key,
+            comparison,
+            value,
+        })
+    }
+
+    fn disposition(&mut self) -> Result<Condition, ConditionError> {
+        self.expect(&TokenKind::Open, "'('")?;
+        let name = self.name("an entity")?;
+        let entity = match Uuid::parse_str(&name) {
+            Ok(id) => EntityRef::Id(id),
+            Err(_) => EntityRef::Name(name),
+        };
+        self.expect(&TokenKind::Close, "')'")?;
+        let comparison = self.comparison()?;
+        let token = self.next("a disposition band or score")?;
+        let TokenKind::Word(word) = &token.kind else {
+            return Err(unexpected(&token, "a disposition band or score"));
+        };
+        let target = match word.parse() {
+            Ok(score) => DispositionTarget::Score(score),
+            Err(_) => {
+                DispositionTarget::Band(word.parse().map_err(|_| ConditionError::InvalidValue {
+                    column: token.column,
+                    value: word.clone(),
+                    what: "disposition band or score",
+                })?)
+            }
+        };
+        Ok(Condition::Disposition {
+            entity,
+            comparison,
+            target,
+        })
+    }
+
+    fn time(&mut self, unit: TimeUnit) -> Result<Condition, ConditionError> {
+        let comparison = self.comparison()?;
+        let token = self.next("a whole number")?;
+        let TokenKind::Word(word) = &token.kind else {
+            return Err(unexpected(&token, "a whole number"));
+        };
+        let value = word.parse().map_err(|_| ConditionError::InvalidValue {
+            column: token.column,
+            value: word.clone(),
+            what: "whole number",
+        })?;
+        Ok(Condition::Time {
+            unit,
+            comparison,
+            value,
+        })
+    }
+}
+
+fn unexpected(token: &Token, expected: &'static str) -> ConditionError {
+    ConditionError::Unexpected {
+        column: token.column,
+        found: token.to_string(),
+        expected,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::calendar::Calendar;
+
+    fn world() -> (WorldSnapshot, BTreeMap<String, Uuid>) {
+        let captain_id = Uuid::new_v4();
+        let mut world = WorldSnapshot::new(Uuid::new_v4());
+        world.flags.insert("door_open".to_owned(), true);
+        world.flags.insert("alarm".to_owned(), false);
+        world.facts.insert("gold".to_owned(), FactValue::Number(12));
+        world.facts.insert(
+            "ruler".to_owned(),
+            FactValue::String("Queen Ysolde".to_owned()),
+        );
+        world.dispositions.insert(captain_id, 45);
+        world.calendar = Calendar {
+            hours_per_day: 20,
+            months: Vec::new(),
+        };
+        world.elapsed_hours = 45;
+        let entities = BTreeMap::from([("guard_captain".to_owned(), captain_id)]);
+        (world, entities)
+    }
+
+    fn check(condition: &str) -> Result<bool, ConditionError> {
+        let (world, entities) = world();
+        condition.parse::<Condition>()?.evaluate(&world, &entities)
+    }
+
+    #[test]
+    fn test_evaluate_combines_flags_facts_and_dispositions() {
+        // Arrange
+        let (world, entities) = world();
+        let condition: Condition =
+            "flag `door_open` is true and disposition(guard_captain) >= friendly and fact gold >= 10"
+                .parse()
+                .unwrap();
+
+        // Act
+        let result = condition.evaluate(&world, &entities);
+
+        // Assert
+        assert_eq!(result, Ok(true));
+    }
+
+    #[test]
+    fn test_evaluate_reads_unset_values_as_their_defaults() {
+        assert_eq!(check("flag alarm"), Ok(false));
+        assert_eq!(check("flag never_set is false"), Ok(true));
+        assert_eq!(check("fact debt > 0"), Ok(false));
+        assert_eq!(check("not fact debt exists and fact gold exists"), Ok(true));
+        let stranger = Uuid::new_v4();
+        assert_eq!(
+            check(&format!("disposition({stranger}) == neutral")),
+            Ok(true)
+        );
+        assert_eq!(check(&format!("disposition({stranger}) < 1")), Ok(true));
+    }
+
+    #[test]
+    fn test_evaluate_applies_precedence_and_time() {
+        // `and` binds tighter than `or`; 45 hours of 20-hour days is day 3, hour 5.
+        assert_eq!(check("true or false and false"), Ok(true));
+        assert_eq!(check("(true or false) and false"), Ok(false));
+        assert_eq!(check("day == 3 and hour >= 5 and not hour > 5"), Ok(true));
+        assert_eq!(check("fact ruler == \"Queen Ysolde\""), Ok(true));
+        assert_eq!(check("fact ruler != \"King Alaric\""), Ok(true));
+    }
+
+    #[test]
+    fn test_evaluate_rejects_mismatched_facts_and_unknown_entities() {
+        assert_eq!(
+            check("false and fact gold == \"lots\""),
+            Err(ConditionError::TypeMismatch {
+                key: "gold".to_owned(),
+                expected: "string",
+                found: "number",
+            })
+        );
+        assert_eq!(
+            check("disposition(harbour_master) > hostile"),
+            Err(ConditionError::UnknownEntity("harbour_master".to_owned()))
+        );
+    }
+
+    #[test]
+    fn test_parse_reports_typed_errors() {
+        let parse = |s: &str| s.parse::<Condition>().unwrap_err();
+
+        assert_eq!(parse("  "), ConditionError::Empty);
+        assert_eq!(
+            parse("flag door_open and"),
+            ConditionError::UnexpectedEnd {
+                expected: EXPECTED_CONDITION
+            }
+        );
+        assert!(matches!(
+            parse("flag a flag b"),
+            ConditionError::Unexpected { column: 8, ref found, .. } if found == "'flag'"
+        ));
+        assert_eq!(
+            parse("fact name == \"open"),
+            ConditionError::Unterminated {
+                column: 14,
+                what: "string"
+            }
+        );
+        assert!(matches!(
+            parse("disposition(guard) >= smitten"),
+            ConditionError::InvalidValue { ref value, .. } if value == "smitten"
+        ));
+        assert_eq!(
+            parse("fact ruler < \"M\""),
+            ConditionError::Unordered {
+                column: 14,
+                kind: "string"
+            }
+        );
+        assert!(matches!(
+            parse("fact gold = 10"),
+            ConditionError::Unexpected { column: 11, .. }
+        ));
+    }
+
+    #[test]
+    fn test_parse_bounds_nesting_and_length() {
+        let nested = |depth: usize| {
+            format!("{}true{}", "(".repeat(depth), ")".repeat(depth)).parse::<Condition>()
+        };
+        let negated = |depth: usize| format!("{}true", "not ".repeat(depth)).parse::<Condition>();
+        let too_deep = ConditionError::TooDeep { limit: MAX_NESTING };
+
+        assert!(nested(MAX_NESTING).is_ok());
+        assert_eq!(nested(MAX_NESTING + 1), Err(too_deep.clone()));
+        assert_eq!(nested(1000), Err(too_deep.clone()));
+        assert!(negated(MAX_NESTING).is_ok());
+        assert_eq!(negated(MAX_NESTING + 1), Err(too_deep.clone()));
+        assert_eq!(negated(1000), Err(too_deep));
+        assert_eq!(
+            nested(5000),
+            Err(ConditionError::TooLong { limit: MAX_LENGTH })
+        );
+    }
+
+    #[test]
+    fn test_condition_error_becomes_validation_error() {
+        let error = DomainError::from(ConditionError::UnknownEntity("guard".to_owned()));
+
+        assert!(matches!(error, DomainError::Validation(m) if m.contains("guard")));
+    }
+}
@@ -0,0 +1,53 @@
+//! Dispositions — how an NPC or faction regards the party, as a score.
+//!
+//! A disposition is a whole-number score between [`MIN_SCORE`] and
+//! [`MAX_SCORE`]; entities the world has never scored are neutral at 0.
+//! Every score falls in exactly one named [`DispositionBand`], so content and
+//! conditions can speak of "friendly" without caring about the number. The
+//! scale itself is shared with content through `otherworlds_core::disposition`
+//! and re-exported here.
+//!
+//! When a member of a faction has their disposition shifted, the faction's
+//! own score — its reputation — shifts by [`FACTION_RIPPLE_PERCENT`] of the
+//! same delta, rounded toward zero. The ripple goes one hop: shifting a
+//! faction never moves its members.
+
+pub use otherworlds_core::disposition::{
+    DispositionBand, MAX_SCORE, MIN_SCORE, parse_disposition, validate_score,
+};
+
+/// The share of a member's disposition delta applied to each faction they
+/// belong to, in percent.
+pub const FACTION_RIPPLE_PERCENT: i32 = 50;
+
+/// Returns `score` shifted by `delta`, kept within the valid range.
+#[must_use]
+pub fn shifted(score: i32, delta: i32) -> i32 {
+    score.saturating_add(delta).clamp(MIN_SCORE, MAX_SCORE)
+}
+
+/// Returns the share of a member's `delta` that ripples to their faction.
+#[must_use]
+pub fn faction_ripple(delta: i32) -> i32 {
+    delta.saturating_mul(FACTION_RIPPLE_PERCENT) / 100
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_shifted_clamps_to_the_score_range() {
+        assert_eq!(shifted(90, 25), MAX_SCORE);
+        assert_eq!(shifted(-90, -25), MIN_SCORE);
+        assert_eq!(shifted(10, i32::MAX), MAX_SCORE);
+        assert_eq!(shifted(10, -15), -5);
+    }
+
+    #[test]
+    fn test_faction_ripple_rounds_toward_zero() {
+        assert_eq!(faction_ripple(10), 5);
+        assert_eq!(faction_ripple(-7), -3);
+        assert_eq!(faction_ripple(1), 0);
+    }
+}
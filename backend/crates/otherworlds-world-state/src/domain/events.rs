@@ -4,13 +4,40 @@ use otherworlds_core::event::{DomainEvent, EventMetadata};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-/// Emitted when a world fact changes.
+use super::calendar::Calendar;
+
+/// The value of a world fact.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", content = "value", rename_all = "snake_case")]
+pub enum FactValue {
+    /// A piece of text.
+    String(String),
+    /// A whole number.
+    Number(i64),
+    /// A truth value.
+    Bool(bool),
+    /// A reference to another entity.
+    Entity(Uuid),
+}
+
+/// Emitted when a world fact is asserted, replacing any earlier value.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FactAsserted {
+    /// The world snapshot identifier.
+    pub world_id: Uuid,
+    /// The fact key.
+    pub key: String,
+    /// The fact's value.
+    pub value: FactValue,
+}
+
+/// Emitted when a world fact is retracted.
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct WorldFactChanged {
+pub struct FactRetracted {
     /// The world snapshot identifier.
     pub world_id: Uuid,
-    /// The fact key that changed.
-    pub fact_key: String,
+    /// The fact key.
+    pub key: String,
 }
 
 /// Emitted when a flag is set.
@@ -24,13 +51,175 @@ pub struct FlagSet {
     pub value: bool,
 }
 
-/// Emitted when a disposition is updated.
+/// Emitted when an entity's disposition score is shifted.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DispositionUpdated {
     /// The world snapshot identifier.
     pub world_id: Uuid,
     /// The entity whose disposition changed.
     pub entity_id: Uuid,
+    /// The amount the score was shifted by, before clamping.
+    pub delta: i32,
+    /// The faction member whose shift rippled to this entity, when this
+    /// entity is a faction.
+    pub rippled_from: Option<Uuid>,
+}
+
+/// Emitted when an entity's disposition is given its starting score.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DispositionSeeded {
+    /// The world snapshot identifier.
+    pub world_id: Uuid,
+    /// The entity whose disposition was seeded.
+    pub entity_id: Uuid,
+    /// The starting score.
+    pub score: i32,
+}
+
+/// Emitted when an entity joins a faction.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FactionJoined {
+    /// The world snapshot identifier.
+    pub world_id: Uuid,
+    /// The entity joining the faction.
+    pub entity_id: Uuid,
+    /// The faction joined.
+    pub faction_id: Uuid,
+}
+
+/// Emitted when a location is added to the world.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LocationAdded {
+    /// The world snapshot identifier.
+    pub world_id: Uuid,
+    /// The new location.
+    pub location_id: Uuid,
+    /// The location's display name.
+    pub name: String,
+}
+
+/// Emitted when two locations are connected. Connections run both ways.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LocationsConnected {
+    /// The world snapshot identifier.
+    pub world_id: Uuid,
+    /// One end of the connection.
+    pub location_id: Uuid,
+    /// The other end of the connection.
+    pub other_location_id: Uuid,
+    /// Whether the connection starts locked.
+    pub locked: bool,
+}
+
+/// Emitted when a connection between two locations is locked.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConnectionLocked {
+    /// The world snapshot identifier.
+    pub world_id: Uuid,
+    /// One end of the connection.
+    pub location_id: Uuid,
+    /// The other end of the connection.
+    pub other_location_id: Uuid,
+}
+
+/// Emitted when a connection between two locations is unlocked.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConnectionUnlocked {
+    /// The world snapshot identifier.
+    pub world_id: Uuid,
+    /// One end of the connection.
+    pub location_id: Uuid,
+    /// The other end of the connection.
+    pub other_location_id: Uuid,
+}
+
+/// Emitted when the party moves to a location.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PartyMoved {
+    /// The world snapshot identifier.
+    pub world_id: Uuid,
+    /// The location the party left, or `None` when it is first placed.
+    pub from: Option<Uuid>,
+    /// The location the party arrived at.
+    pub to: Uuid,
+}
+
+/// Emitted when an NPC moves to a location.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NpcMoved {
+    /// The world snapshot identifier.
+    pub world_id: Uuid,
+    /// The NPC that moved.
+    pub entity_id: Uuid,
+    /// The location the NPC left, or `None` when it is first placed.
+    pub from: Option<Uuid>,
+    /// The location the NPC arrived at.
+    pub to: Uuid,
+}
+
+/// What a scheduled event does to the world when it fires.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum ScheduledEffect {
+    /// Sets a flag.
+    SetFlag {
+        /// The flag key.
+        flag_key: String,
+        /// The flag value.
+        value: bool,
+    },
+    /// Moves an NPC to a location.
+    MoveNpc {
+        /// The NPC to move.
+        entity_id: Uuid,
+        /// The location to move to.
+        location_id: Uuid,
+    },
+}
+
+/// Emitted when the world's calendar is set.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CalendarSet {
+    /// The world snapshot identifier.
+    pub world_id: Uuid,
+    /// The new calendar.
+    pub calendar: Calendar,
+}
+
+/// Emitted when world time moves forward.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimeAdvanced {
+    /// The world snapshot identifier.
+    pub world_id: Uuid,
+    /// The elapsed hour time advanced from.
+    pub from: u64,
+    /// The elapsed hour time advanced to.
+    pub to: u64,
+}
+
+/// Emitted when an effect is scheduled for a later hour.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EventScheduled {
+    /// The world snapshot identifier.
+    pub world_id: Uuid,
+    /// The scheduled event identifier.
+    pub schedule_id: Uuid,
+    /// The elapsed hour the event is due at.
+    pub at: u64,
+    /// What happens when it fires.
+    pub effect: ScheduledEffect,
+}
+
+/// Emitted when world time reaches a scheduled event. The events its effect
+/// produced follow it in the same stream.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScheduledEventFired {
+    /// The world snapshot identifier.
+    pub world_id: Uuid,
+    /// The scheduled event identifier.
+    pub schedule_id: Uuid,
+    /// The elapsed hour the event was due at.
+    pub at: u64,
 }
 
 /// Emitted when a world snapshot is archived (soft-deleted).
@@ -40,19 +229,120 @@ pub struct WorldSnapshotArchived {
     pub world_id: Uuid,
 }
 
+/// Event type identifier for [`FactAsserted`].
+pub const FACT_ASSERTED_EVENT_TYPE: &str = "world_state.fact_asserted";
+
+/// Event type identifier for [`FactRetracted`].
+pub const FACT_RETRACTED_EVENT_TYPE: &str = "world_state.fact_retracted";
+
+/// Event type identifier of the fact-only events recorded before facts had
+/// values; they are upcast to [`FactAsserted`] payloads.
+pub const LEGACY_FACT_CHANGED_EVENT_TYPE: &str = "world_state.world_fact_changed";
+
+/// Event type identifier for [`DispositionUpdated`].
+pub const DISPOSITION_UPDATED_EVENT_TYPE: &str = "world_state.disposition_updated";
+
+/// Event type identifier for [`DispositionSeeded`].
+pub const DISPOSITION_SEEDED_EVENT_TYPE: &str = "world_state.disposition_seeded";
+
+/// Event type identifier for [`FactionJoined`].
+pub const FACTION_JOINED_EVENT_TYPE: &str = "world_state.faction_joined";
+
+/// Event type identifier for [`LocationAdded`].
+pub const LOCATION_ADDED_EVENT_TYPE: &str = "world_state.location_added";
+
+/// Event type identifier for [`LocationsConnected`].
+pub const LOCATIONS_CONNECTED_EVENT_TYPE: &str = "world_state.locations_connected";
+
+/// Event type identifier for [`ConnectionLocked`].
+pub const CONNECTION_LOCKED_EVENT_TYPE: &str = "world_state.connection_locked";
+
+/// Event type identifier for [`ConnectionUnlocked`].
+pub const CONNECTION_UNLOCKED_EVENT_TYPE: &str = "world_state.connection_unlocked";
+
+/// Event type identifier for [`PartyMoved`].
+pub const PARTY_MOVED_EVENT_TYPE: &str = "world_state.party_moved";
+
+/// Event type identifier for [`NpcMoved`].
+pub const NPC_MOVED_EVENT_TYPE: &str = "world_state.npc_moved";
+
+/// Event type identifier for [`CalendarSet`].
+pub const CALENDAR_SET_EVENT_TYPE: &str = "world_state.calendar_set";
+
+/// Event type identifier for [`TimeAdvanced`].
+pub const TIME_ADVANCED_EVENT_TYPE: &str = "world_state.time_advanced";
+
+/// Event type identifier for [`EventScheduled`].
+pub const EVENT_SCHEDULED_EVENT_TYPE: &str = "world_state.event_scheduled";
+
+/// Event type identifier for [`ScheduledEventFired`].
+pub const SCHEDULED_EVENT_FIRED_EVENT_TYPE: &str = "world_state.scheduled_event_fired";
+
 /// Event payload variants for the World State context.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum WorldStateEventKind {
-    /// A world fact has changed.
-    WorldFactChanged(WorldFactChanged),
+    /// A world fact has been asserted.
+    FactAsserted(FactAsserted),
+    /// A world fact has been retracted.
+    FactRetracted(FactRetracted),
     /// A flag has been set.
     FlagSet(FlagSet),
     /// A disposition has been updated.
     DispositionUpdated(DispositionUpdated),
+    /// A disposition has been seeded.
+    DispositionSeeded(DispositionSeeded),
+    /// An entity has joined a faction.
+    FactionJoined(FactionJoined),
+    /// A location has been added.
+    LocationAdded(LocationAdded),
+    /// Two locations have been connected.
+    LocationsConnected(LocationsConnected),
+    /// A connection has been locked.
+    ConnectionLocked(ConnectionLocked),
+    /// A connection has been unlocked.
+    ConnectionUnlocked(ConnectionUnlocked),
+    /// The party has moved.
+    PartyMoved(PartyMoved),
+    /// An NPC has moved.
+    NpcMoved(NpcMoved),
+    /// The calendar has been set.
+    CalendarSet(CalendarSet),
+    /// World time has advanced.
+    TimeAdvanced(TimeAdvanced),
+    /// An effect has been scheduled.
+    EventScheduled(EventScheduled),
+    /// A scheduled event has fired.
+    ScheduledEventFired(ScheduledEventFired),
     /// A world snapshot has been archived.
     WorldSnapshotArchived(WorldSnapshotArchived),
 }
 
+impl WorldStateEventKind {
+    /// Returns the event type identifier of this payload.
+    #[must_use]
+    pub fn event_type(&self) -> &'static str {
+        match self {
+            Self::FactAsserted(_) => FACT_ASSERTED_EVENT_TYPE,
+            Self::FactRetracted(_) => FACT_RETRACTED_EVENT_TYPE,
+            Self::FlagSet(_) => "world_state.flag_set",
+            Self::DispositionUpdated(_) => DISPOSITION_UPDATED_EVENT_TYPE,
+            Self::DispositionSeeded(_) => DISPOSITION_SEEDED_EVENT_TYPE,
+            Self::FactionJoined(_) => FACTION_JOINED_EVENT_TYPE,
+            Self::LocationAdded(_) => LOCATION_ADDED_EVENT_TYPE,
+            Self::LocationsConnected(_) => LOCATIONS_CONNECTED_EVENT_TYPE,
+            Self::ConnectionLocked(_) => CONNECTION_LOCKED_EVENT_TYPE,
+            Self::ConnectionUnlocked(_) => CONNECTION_UNLOCKED_EVENT_TYPE,
+            Self::PartyMoved(_) => PARTY_MOVED_EVENT_TYPE,
+            Self::NpcMoved(_) => NPC_MOVED_EVENT_TYPE,
+            Self::CalendarSet(_) => CALENDAR_SET_EVENT_TYPE,
+            Self::TimeAdvanced(_) => TIME_ADVANCED_EVENT_TYPE,
+            Self::EventScheduled(_) => EVENT_SCHEDULED_EVENT_TYPE,
+            Self::ScheduledEventFired(_) => SCHEDULED_EVENT_FIRED_EVENT_TYPE,
+            Self::WorldSnapshotArchived(_) => "world_state.world_snapshot_archived",
+        }
+    }
+}
+
 /// Domain event envelope for the World State context.
 #[derive(Debug, Clone)]
 pub struct WorldStateEvent {
@@ -64,12 +354,7 @@ pub struct WorldStateEvent {
 
 impl DomainEvent for WorldStateEvent {
     fn event_type(&self) -> &'static str {
-        match &self.kind {
-            WorldStateEventKind::WorldFactChanged(_) => "world_state.world_fact_changed",
-            WorldStateEventKind::FlagSet(_) => "world_state.flag_set",
-            WorldStateEventKind::DispositionUpdated(_) => "world_state.disposition_updated",
-            WorldStateEventKind::WorldSnapshotArchived(_) => "world_state.world_snapshot_archived",
-        }
+        self.kind.event_type()
     }
 
     fn to_payload(&self) -> serde_json::Value {
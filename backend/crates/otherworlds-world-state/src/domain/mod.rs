@@ -1,5 +1,9 @@
 //! Domain layer for the World State context.
 
 pub mod aggregates;
+pub mod calendar;
 pub mod commands;
+pub mod condition;
+pub mod disposition;
 pub mod events;
+pub mod upcasters;
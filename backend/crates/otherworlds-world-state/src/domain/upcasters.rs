@@ -0,0 +1,148 @@
+//! Upcasters for the World State context.
+//!
+//! Register a step here whenever an event payload changes shape, so streams
+//! written with the previous shape still load.
+
+use std::sync::LazyLock;
+
+use otherworlds_core::error::DomainError;
+use otherworlds_core::upcasting::UpcasterRegistry;
+use serde_json::{Value, json};
+
+use super::events::{DISPOSITION_UPDATED_EVENT_TYPE, LEGACY_FACT_CHANGED_EVENT_TYPE};
+
+static REGISTRY: LazyLock<UpcasterRegistry> = LazyLock::new(|| {
+    UpcasterRegistry::new()
+        .register(LEGACY_FACT_CHANGED_EVENT_TYPE, 1, assert_legacy_fact)
+        .register(DISPOSITION_UPDATED_EVENT_TYPE, 1, add_disposition_delta)
+});
+
+/// Returns the upcaster registry for world state events.
+#[must_use]
+pub fn registry() -> &'static UpcasterRegistry {
+    &REGISTRY
+}
+
+/// Returns the schema version newly produced events of `event_type` carry.
+#[must_use]
+pub fn current_schema_version(event_type: &str) -> i32 {
+    REGISTRY.current_version(event_type)
+}
+
+/// v1 → v2: a `WorldFactChanged` event recorded only that a fact key held.
+/// It becomes a `FactAsserted` payload asserting the key as `true`.
+fn assert_legacy_fact(mut payload: Value) -> Result<Value, DomainError> {
+    let Some(Value::Object(mut changed)) = payload
+        .as_object_mut()
+        .and_then(|payload| payload.remove("WorldFactChanged"))
+    else {
+        return Err(DomainError::Infrastructure(
+            "WorldFactChanged payload has no body".into(),
+        ));
+    };
+    let world_id = changed.remove("world_id").unwrap_or(Value::Null);
+    let key = changed.remove("fact_key").ok_or_else(|| {
+        DomainError::Infrastructure("WorldFactChanged payload has no fact_key".into())
+    })?;
+    Ok(json!({
+        "FactAsserted": {
+            "world_id": world_id,
+            "key": key,
+            "value": { "type": "bool", "value": true }
+        }
+    }))
+}
+
+/// v1 → v2: a `DispositionUpdated` event recorded only which entity was
+/// touched. It gains a `delta` of 0 and no `rippled_from`, so the entity is
+/// known but its score is unchanged.
+fn add_disposition_delta(mut payload: Value) -> Result<Value, DomainError> {
+    let Some(Value::Object(updated)) = payload
+        .as_object_mut()
+        .and_then(|payload| payload.get_mut("DispositionUpdated"))
+    else {
+        return Err(DomainError::Infrastructure(
+            "DispositionUpdated payload has no body".into(),
+        ));
+    };
+    updated.insert("delta".to_owned(), json!(0));
+    updated.insert("rippled_from".to_owned(), Value::Null);
+    Ok(payload)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Utc;
+    use otherworlds_core::repository::StoredEvent;
+    use uuid::Uuid;
+
+    use super::*;
+    use crate::domain::events::{FactValue, WorldStateEventKind};
+
+    fn stored_v1(world_id: Uuid, event_type: &str, payload: Value) -> StoredEvent {
+        StoredEvent {
+            event_id: Uuid::new_v4(),
+            aggregate_id: world_id,
+            event_type: event_type.to_owned(),
+            payload,
+            sequence_number: 1,
+            correlation_id: Uuid::new_v4(),
+            causation_id: Uuid::new_v4(),
+            occurred_at: Utc::now(),
+            schema_version: 1,
+        }
+    }
+
+    #[test]
+    fn test_legacy_fact_changed_upcasts_to_a_true_fact() {
+        // Arrange
+        let world_id = Uuid::new_v4();
+        let stored = stored_v1(
+            world_id,
+            LEGACY_FACT_CHANGED_EVENT_TYPE,
+            json!({
+                "WorldFactChanged": { "world_id": world_id, "fact_key": "quest_complete" }
+            }),
+        );
+
+        // Act
+        let payload = registry().upcast(&stored).unwrap();
+
+        // Assert
+        let WorldStateEventKind::FactAsserted(asserted) = serde_json::from_value(payload).unwrap()
+        else {
+            panic!("expected FactAsserted");
+        };
+        assert_eq!(asserted.world_id, world_id);
+        assert_eq!(asserted.key, "quest_complete");
+        assert_eq!(asserted.value, FactValue::Bool(true));
+    }
+
+    #[test]
+    fn test_v1_disposition_updated_upcasts_to_a_zero_delta() {
+        // Arrange
+        let world_id = Uuid::new_v4();
+        let entity_id = Uuid::new_v4();
+        let stored = stored_v1(
+            world_id,
+            DISPOSITION_UPDATED_EVENT_TYPE,
+            json!({
+                "DispositionUpdated": { "world_id": world_id, "entity_id": entity_id }
+            }),
+        );
+
+        // Act
+        let payload = registry().upcast(&stored).unwrap();
+
+        // Assert
+        let WorldStateEventKind::DispositionUpdated(updated) =
+            serde_json::from_value(payload).unwrap()
+        else {
+            panic!("expected DispositionUpdated");
+        };
+        assert_eq!(updated.entity_id, entity_id);
+        assert_eq!(updated.delta, 0);
+        assert_eq!(updated.rippled_from, None);
+        assert_eq!(current_schema_version(DISPOSITION_UPDATED_EVENT_TYPE), 2);
+    }
+}
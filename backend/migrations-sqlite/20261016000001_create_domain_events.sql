@@ -0,0 +1,22 @@
+-- SQLite event store schema for single-player offline builds. Mirrors the
+-- PostgreSQL `domain_events` table; UUIDs are stored as 16-byte blobs and
+-- payloads as JSON text.
+CREATE TABLE IF NOT EXISTS domain_events (
+    global_position INTEGER PRIMARY KEY AUTOINCREMENT,
+    event_id        BLOB NOT NULL UNIQUE,
+    aggregate_id    BLOB NOT NULL,
+    event_type      TEXT NOT NULL,
+    payload         TEXT NOT NULL,
+    sequence_number INTEGER NOT NULL,
+    correlation_id  BLOB NOT NULL,
+    causation_id    BLOB NOT NULL,
+    occurred_at     TEXT NOT NULL,
+    schema_version  INTEGER NOT NULL DEFAULT 1,
+    UNIQUE (aggregate_id, sequence_number)
+);
+
+CREATE INDEX IF NOT EXISTS idx_domain_events_event_type
+    ON domain_events (event_type);
+
+CREATE INDEX IF NOT EXISTS idx_domain_events_correlation_id
+    ON domain_events (correlation_id);
@@ -0,0 +1,9 @@
+-- Store the latest serialized state of each aggregate so reconstitution can
+-- skip replaying the full event stream.
+CREATE TABLE IF NOT EXISTS aggregate_snapshots (
+    aggregate_id   UUID PRIMARY KEY,
+    aggregate_type VARCHAR(255) NOT NULL,
+    version        BIGINT NOT NULL,
+    state          JSONB NOT NULL,
+    taken_at       TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
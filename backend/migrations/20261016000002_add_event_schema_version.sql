@@ -0,0 +1,4 @@
+-- Record the payload schema version of each event so upcasters can migrate
+-- older payloads on load. Existing rows were written with version 1.
+ALTER TABLE domain_events
+    ADD COLUMN IF NOT EXISTS schema_version INTEGER NOT NULL DEFAULT 1;
@@ -0,0 +1,29 @@
+-- Add a global, monotonically increasing position to every event so readers
+-- can tail all contexts' events in commit order.
+ALTER TABLE domain_events ADD COLUMN IF NOT EXISTS global_position BIGINT;
+
+-- Backfill existing rows in chronological order.
+UPDATE domain_events AS d
+SET global_position = ordered.position
+FROM (
+    SELECT event_id,
+           ROW_NUMBER() OVER (ORDER BY occurred_at, aggregate_id, sequence_number) AS position
+    FROM domain_events
+) AS ordered
+WHERE d.event_id = ordered.event_id;
+
+CREATE SEQUENCE IF NOT EXISTS domain_events_global_position_seq
+    OWNED BY domain_events.global_position;
+
+SELECT setval(
+    'domain_events_global_position_seq',
+    COALESCE((SELECT MAX(global_position) FROM domain_events), 0) + 1,
+    false
+);
+
+ALTER TABLE domain_events
+    ALTER COLUMN global_position SET DEFAULT nextval('domain_events_global_position_seq'),
+    ALTER COLUMN global_position SET NOT NULL;
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_domain_events_global_position
+    ON domain_events (global_position);
@@ -0,0 +1,12 @@
+-- Read-model tables for combat encounters (Rules & Resolution context).
+CREATE TABLE IF NOT EXISTS encounter_views (
+    id         UUID PRIMARY KEY,
+    document   JSONB NOT NULL,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE TABLE IF NOT EXISTS encounter_summaries (
+    id         UUID PRIMARY KEY,
+    document   JSONB NOT NULL,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
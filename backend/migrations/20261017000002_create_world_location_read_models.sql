@@ -0,0 +1,6 @@
+-- Read-model table for world location graphs (World State context).
+CREATE TABLE IF NOT EXISTS world_location_views (
+    id         UUID PRIMARY KEY,
+    document   JSONB NOT NULL,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
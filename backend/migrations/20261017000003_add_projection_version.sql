@@ -0,0 +1,5 @@
+-- Records the document-shape version each projection was built under, so a
+-- projection whose version changed is rebuilt. Rows written before versions
+-- were tracked were built under version 1.
+ALTER TABLE projection_checkpoints
+    ADD COLUMN IF NOT EXISTS version INTEGER NOT NULL DEFAULT 1;
@@ -0,0 +1,7 @@
+-- Read-model table mapping each aggregate registered with a campaign run to
+-- that run (Session & Progress context).
+CREATE TABLE IF NOT EXISTS run_aggregates (
+    id         UUID PRIMARY KEY,
+    document   JSONB NOT NULL,
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
@@ -10,6 +10,7 @@ import type {
   CharacterView,
   CommandResponse,
   CreateCharacterRequest,
+  GrantProficiencyRequest,
   ModifyAttributeRequest,
 } from '$lib/types';
 
@@ -37,6 +38,12 @@ export async function awardExperience(request: AwardExperienceRequest): Promise<
   return apiPost<CommandResponse>(`${BASE}/award-experience`, request);
 }
 
+export async function grantProficiency(
+  request: GrantProficiencyRequest,
+): Promise<CommandResponse> {
+  return apiPost<CommandResponse>(`${BASE}/grant-proficiency`, request);
+}
+
 export async function archiveCharacter(characterId: string): Promise<CommandResponse> {
   return apiDelete<CommandResponse>(`${BASE}/${characterId}`);
 }
@@ -0,0 +1,60 @@
+/**
+ * Server-side API client for combat encounters (Rules & Resolution context).
+ *
+ * Routes are nested under /api/v1/encounters on the backend.
+ */
+
+import type {
+  CommandResponse,
+  DelayTurnRequest,
+  EncounterSummary,
+  EncounterView,
+  EndEncounterRequest,
+  EndTurnRequest,
+  LinkResolutionRequest,
+  ReadyActionRequest,
+  StartEncounterRequest,
+  TriggerReadiedActionRequest,
+} from '$lib/types';
+
+import { apiGet, apiPost } from './client';
+
+const BASE = '/api/v1/encounters';
+
+export async function listEncounters(): Promise<EncounterSummary[]> {
+  return apiGet<EncounterSummary[]>(BASE);
+}
+
+export async function getEncounter(encounterId: string): Promise<EncounterView> {
+  return apiGet<EncounterView>(`${BASE}/${encounterId}`);
+}
+
+export async function startEncounter(request: StartEncounterRequest): Promise<CommandResponse> {
+  return apiPost<CommandResponse>(`${BASE}/start-encounter`, request);
+}
+
+export async function endTurn(request: EndTurnRequest): Promise<CommandResponse> {
+  return apiPost<CommandResponse>(`${BASE}/end-turn`, request);
+}
+
+export async function delayTurn(request: DelayTurnRequest): Promise<CommandResponse> {
+  return apiPost<CommandResponse>(`${BASE}/delay-turn`, request);
+}
+
+export async function readyAction(request: ReadyActionRequest): Promise<CommandResponse> {
+  return apiPost<CommandResponse>(`${BASE}/ready-action`, request);
+}
+
+export async function triggerReadiedAction(
+  request: TriggerReadiedActionRequest,
+): Promise<CommandResponse> {
+  return apiPost<CommandResponse>(`${BASE}/trigger-readied-action`, request);
+}
+
+export async function linkResolution(request: LinkResolutionRequest): Promise<CommandResponse> {
+  return apiPost<CommandResponse>(`${BASE}/link-resolution`, request);
+}
+
+export async function endEncounter(request: EndEncounterRequest): Promise<CommandResponse> {
+  return apiPost<CommandResponse>(`${BASE}/end-encounter`, request);
+}
@@ -15,6 +15,7 @@ export { handleLoadError, mapApiError } from './errors';
 export * as narrative from './narrative';
 export * as character from './character';
 export * as rules from './rules';
+export * as encounter from './encounter';
 export * as worldState from './world-state';
 export * as inventory from './inventory';
 export * as session from './session';
@@ -5,8 +5,11 @@
  */
 
 import type {
+  CheckOddsQuery,
+  CheckOddsView,
   CommandResponse,
   DeclareIntentRequest,
+  DeclareOpposedIntentRequest,
   ProduceEffectsRequest,
   ResolutionSummary,
   ResolutionView,
@@ -25,10 +28,25 @@ export async function getResolution(resolutionId: string): Promise<ResolutionVie
   return apiGet<ResolutionView>(`${BASE}/${resolutionId}`);
 }
 
+export async function getOdds(query: CheckOddsQuery): Promise<CheckOddsView> {
+  const params = new URLSearchParams({ difficulty_class: String(query.difficulty_class) });
+  if (query.modifier !== undefined) params.set('modifier', String(query.modifier));
+  if (query.ruleset) params.set('ruleset', query.ruleset);
+  if (query.roll_mode) params.set('roll_mode', query.roll_mode);
+  if (query.rolls !== undefined) params.set('rolls', String(query.rolls));
+  return apiGet<CheckOddsView>(`${BASE}/odds?${params}`);
+}
+
 export async function declareIntent(request: DeclareIntentRequest): Promise<CommandResponse> {
   return apiPost<CommandResponse>(`${BASE}/declare-intent`, request);
 }
 
+export async function declareOpposedIntent(
+  request: DeclareOpposedIntentRequest,
+): Promise<CommandResponse> {
+  return apiPost<CommandResponse>(`${BASE}/declare-opposed-intent`, request);
+}
+
 export async function resolveCheck(request: ResolveCheckRequest): Promise<CommandResponse> {
   return apiPost<CommandResponse>(`${BASE}/resolve-check`, request);
 }
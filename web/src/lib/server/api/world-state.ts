@@ -5,10 +5,24 @@
  */
 
 import type {
+  AdvanceTimeRequest,
   ApplyEffectRequest,
+  AssertFactRequest,
   CommandResponse,
+  EvaluateConditionRequest,
+  EvaluateConditionResponse,
+  JoinFactionRequest,
+  MoveNpcRequest,
+  MovePartyRequest,
+  RetractFactRequest,
+  ScheduleEventRequest,
+  SeedFromCampaignRequest,
+  SeedFromCampaignResponse,
+  SetCalendarRequest,
+  SetConnectionLockRequest,
   SetFlagRequest,
   UpdateDispositionRequest,
+  WorldLocationsView,
   WorldSnapshotSummary,
   WorldSnapshotView,
 } from '$lib/types';
@@ -25,10 +39,22 @@ export async function getWorldSnapshot(worldId: string): Promise<WorldSnapshotVi
   return apiGet<WorldSnapshotView>(`${BASE}/${worldId}`);
 }
 
+export async function getWorldLocations(worldId: string): Promise<WorldLocationsView> {
+  return apiGet<WorldLocationsView>(`${BASE}/${worldId}/locations`);
+}
+
 export async function applyEffect(request: ApplyEffectRequest): Promise<CommandResponse> {
   return apiPost<CommandResponse>(`${BASE}/apply-effect`, request);
 }
 
+export async function assertFact(request: AssertFactRequest): Promise<CommandResponse> {
+  return apiPost<CommandResponse>(`${BASE}/assert-fact`, request);
+}
+
+export async function retractFact(request: RetractFactRequest): Promise<CommandResponse> {
+  return apiPost<CommandResponse>(`${BASE}/retract-fact`, request);
+}
+
 export async function setFlag(request: SetFlagRequest): Promise<CommandResponse> {
   return apiPost<CommandResponse>(`${BASE}/set-flag`, request);
 }
@@ -39,6 +65,49 @@ export async function updateDisposition(
   return apiPost<CommandResponse>(`${BASE}/update-disposition`, request);
 }
 
+export async function joinFaction(request: JoinFactionRequest): Promise<CommandResponse> {
+  return apiPost<CommandResponse>(`${BASE}/join-faction`, request);
+}
+
+export async function setConnectionLock(
+  request: SetConnectionLockRequest,
+): Promise<CommandResponse> {
+  return apiPost<CommandResponse>(`${BASE}/set-connection-lock`, request);
+}
+
+export async function moveParty(request: MovePartyRequest): Promise<CommandResponse> {
+  return apiPost<CommandResponse>(`${BASE}/move-party`, request);
+}
+
+export async function moveNpc(request: MoveNpcRequest): Promise<CommandResponse> {
+  return apiPost<CommandResponse>(`${BASE}/move-npc`, request);
+}
+
+export async function setCalendar(request: SetCalendarRequest): Promise<CommandResponse> {
+  return apiPost<CommandResponse>(`${BASE}/set-calendar`, request);
+}
+
+export async function scheduleEvent(request: ScheduleEventRequest): Promise<CommandResponse> {
+  return apiPost<CommandResponse>(`${BASE}/schedule-event`, request);
+}
+
+export async function advanceTime(request: AdvanceTimeRequest): Promise<CommandResponse> {
+  return apiPost<CommandResponse>(`${BASE}/advance-time`, request);
+}
+
+export async function evaluateCondition(
+  worldId: string,
+  request: EvaluateConditionRequest,
+): Promise<EvaluateConditionResponse> {
+  return apiPost<EvaluateConditionResponse>(`${BASE}/${worldId}/evaluate`, request);
+}
+
+export async function seedFromCampaign(
+  request: SeedFromCampaignRequest,
+): Promise<SeedFromCampaignResponse> {
+  return apiPost<SeedFromCampaignResponse>(`${BASE}/seed-from-campaign`, request);
+}
+
 export async function archiveWorldSnapshot(worldId: string): Promise<CommandResponse> {
   return apiDelete<CommandResponse>(`${BASE}/${worldId}`);
 }
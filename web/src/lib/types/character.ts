@@ -30,6 +30,12 @@ export interface AwardExperienceRequest {
   amount: number;
 }
 
+/** Request body for POST /api/v1/characters/grant-proficiency. */
+export interface GrantProficiencyRequest {
+  character_id: UUID;
+  skill: string;
+}
+
 // ---------------------------------------------------------------------------
 // Query / view types
 // ---------------------------------------------------------------------------
@@ -40,6 +46,7 @@ export interface CharacterView {
   name: string | null;
   attributes: Record<string, number>;
   experience: number;
+  proficiencies: string[];
   version: number;
 }
 
@@ -0,0 +1,115 @@
+/**
+ * Types for combat encounters (Rules Engine bounded context).
+ *
+ * Corresponds to Rust structs in:
+ * - otherworlds-api/src/routes/encounter.rs (requests)
+ * - otherworlds-rules/src/application/query_handlers.rs (views)
+ */
+
+import type { UUID } from './common';
+
+// ---------------------------------------------------------------------------
+// Shared types
+// ---------------------------------------------------------------------------
+
+/** The lifecycle phase of an encounter. */
+export type EncounterPhase = 'not_started' | 'active' | 'ended';
+
+/** A participant joining an encounter. */
+export interface EncounterParticipant {
+  participant_id: UUID;
+  name: string;
+  /** Added to the participant's d20 initiative roll; 0 when omitted. */
+  initiative_modifier?: number;
+}
+
+// ---------------------------------------------------------------------------
+// Command / request types
+// ---------------------------------------------------------------------------
+
+/** Request body for POST /api/v1/encounters/start-encounter. */
+export interface StartEncounterRequest {
+  encounter_id: UUID;
+  /** Each participant rolls initiative in the order listed. */
+  participants: EncounterParticipant[];
+}
+
+/** Request body for POST /api/v1/encounters/end-turn. */
+export interface EndTurnRequest {
+  encounter_id: UUID;
+}
+
+/** Request body for POST /api/v1/encounters/delay-turn. */
+export interface DelayTurnRequest {
+  encounter_id: UUID;
+  /** Must not have acted yet this round. */
+  after_participant_id: UUID;
+}
+
+/** Request body for POST /api/v1/encounters/ready-action. */
+export interface ReadyActionRequest {
+  encounter_id: UUID;
+  trigger: string;
+}
+
+/** Request body for POST /api/v1/encounters/trigger-readied-action. */
+export interface TriggerReadiedActionRequest {
+  encounter_id: UUID;
+  participant_id: UUID;
+}
+
+/** Request body for POST /api/v1/encounters/link-resolution. */
+export interface LinkResolutionRequest {
+  encounter_id: UUID;
+  participant_id: UUID;
+  resolution_id: UUID;
+}
+
+/** Request body for POST /api/v1/encounters/end-encounter. */
+export interface EndEncounterRequest {
+  encounter_id: UUID;
+  reason?: string | null;
+}
+
+// ---------------------------------------------------------------------------
+// Query / view types
+// ---------------------------------------------------------------------------
+
+/** A participant's initiative roll. */
+export interface InitiativeView {
+  participant_id: UUID;
+  name: string;
+  modifier: number;
+  natural_roll: number;
+  initiative: number;
+}
+
+/** A resolution linked to a participant's action. */
+export interface EncounterActionView {
+  round: number;
+  participant_id: UUID;
+  resolution_id: UUID;
+}
+
+/** Full read-only view of an encounter (GET /api/v1/encounters/:id). */
+export interface EncounterView {
+  encounter_id: UUID;
+  phase: EncounterPhase;
+  round: number;
+  current_participant_id: UUID | null;
+  initiative: InitiativeView[];
+  turn_order: UUID[];
+  /** Readied action triggers by participant ID. */
+  readied: Record<UUID, string>;
+  actions: EncounterActionView[];
+  end_reason: string | null;
+  version: number;
+}
+
+/** Summary view for listing encounters (GET /api/v1/encounters). */
+export interface EncounterSummary {
+  encounter_id: UUID;
+  phase: EncounterPhase;
+  round: number;
+  version: number;
+}
@@ -9,6 +9,7 @@ export type * from './common';
 export type * from './narrative';
 export type * from './character';
 export type * from './rules';
+export type * from './encounter';
 export type * from './world-state';
 export type * from './inventory';
 export type * from './session';
@@ -28,6 +28,8 @@ export interface RemoveItemRequest {
 export interface EquipItemRequest {
   inventory_id: UUID;
   item_id: UUID;
+  /** Check bonuses keyed by skill or attribute name, e.g. { stealth: 2 }. */
+  bonuses?: Record<string, number>;
 }
 
 // ---------------------------------------------------------------------------
@@ -38,9 +40,16 @@ export interface EquipItemRequest {
 export interface InventoryView {
   inventory_id: UUID;
   items: UUID[];
+  equipped: EquippedItem[];
   version: number;
 }
 
+/** An equipped item and the check bonuses it grants. */
+export interface EquippedItem {
+  item_id: UUID;
+  bonuses: Record<string, number>;
+}
+
 /** Summary view for listing inventories (GET /api/v1/inventory). */
 export interface InventorySummary {
   inventory_id: UUID;
@@ -8,6 +8,35 @@
 
 import type { UUID } from './common';
 
+// ---------------------------------------------------------------------------
+// Shared types
+// ---------------------------------------------------------------------------
+
+/** How many times a check rolls its expression, and which roll counts. */
+export type RollMode =
+  | 'normal'
+  | 'advantage'
+  | 'disadvantage'
+  | { keep_best: number }
+  | { keep_worst: number };
+
+/** The ruleset a check is rolled and judged by. */
+export type RulesetId = 'd20' | 'pbta' | 'percentile';
+
+/** Who wins an opposed check when both totals are equal. */
+export type TieBreak = 'defender' | 'initiator' | 'higher_modifier' | 'standoff';
+
+/** The side that won an opposed check. */
+export type OpposedSide = 'actor' | 'opponent';
+
+/** One side of an opposed check. */
+export interface OpposedParticipant {
+  participant_id?: UUID | null;
+  modifier: number;
+  /** How many times this side rolls and which roll counts; rolled once when omitted. */
+  roll_mode?: RollMode;
+}
+
 // ---------------------------------------------------------------------------
 // Command / request types
 // ---------------------------------------------------------------------------
@@ -20,7 +49,38 @@ export interface DeclareIntentRequest {
   skill: string | null;
   target_id: UUID | null;
   difficulty_class: number;
-  modifier: number;
+  /** Required unless character_id is given, in which case it must be omitted. */
+  modifier?: number;
+  /** The acting character; the modifier is derived from its sheet for `skill`. */
+  character_id?: UUID | null;
+  /** The character's inventory, whose equipped items add their bonuses. */
+  inventory_id?: UUID | null;
+  /**
+   * Dice notation such as "2d6+@strength"; the ruleset's default roll when omitted.
+   * `@name` reads the acting character's sheet, so it needs `character_id`;
+   * with a character, constant terms such as "+2" are rejected.
+   */
+  roll_expression?: string | null;
+  /** How many times to roll and which roll counts; rolled once when omitted. */
+  roll_mode?: RollMode;
+  /** The ruleset judging the check; d20 when omitted. */
+  ruleset?: RulesetId;
+}
+
+/** Request body for POST /api/v1/rules/declare-opposed-intent. */
+export interface DeclareOpposedIntentRequest {
+  resolution_id: UUID;
+  intent_id: UUID;
+  action_type: string;
+  skill: string | null;
+  actor: OpposedParticipant;
+  opponent: OpposedParticipant;
+  /** Dice notation both sides roll; the ruleset's default roll when omitted. */
+  roll_expression?: string | null;
+  /** The ruleset the rolls are made under; d20 when omitted. */
+  ruleset?: RulesetId;
+  /** Who wins a tie; the defender when omitted. */
+  tie_break?: TieBreak;
 }
 
 /** Request body for POST /api/v1/rules/resolve-check. */
@@ -28,17 +88,63 @@ export interface ResolveCheckRequest {
   resolution_id: UUID;
 }
 
-/** A single effect specification within a ProduceEffectsRequest. */
+/** A typed effect from the catalogue, or a campaign-declared custom one. */
+export type Effect =
+  | { effect_type: 'damage'; payload: { amount: number; damage_type?: string | null } }
+  | { effect_type: 'heal'; payload: { amount: number } }
+  | { effect_type: 'apply_status'; payload: { status: string; duration_rounds?: number | null } }
+  | { effect_type: 'set_flag'; payload: { flag: string; value: boolean } }
+  | { effect_type: 'grant_item'; payload: { item_id: UUID } }
+  | { effect_type: 'award_xp'; payload: { amount: number } }
+  | { effect_type: 'shift_disposition'; payload: { delta: number } }
+  | { effect_type: 'custom'; payload: { name: string; version: number; data: unknown } };
+
+/** The `effect_type`s the catalogue accepts. */
+export type EffectType = Effect['effect_type'];
+
+/**
+ * A single effect specification within a ProduceEffectsRequest. The payload
+ * is checked against the effect type on the server.
+ */
 export interface EffectSpecRequest {
   effect_type: string;
   target_id: UUID | null;
   payload: unknown;
 }
 
+/** The tier a check lands in. */
+export type CheckOutcome =
+  | 'critical_failure'
+  | 'failure'
+  | 'partial_success'
+  | 'success'
+  | 'critical_success';
+
+/** A tier that reuses another tier's effects at some strength. */
+export interface OutcomeFallback {
+  fallback: CheckOutcome;
+  /** Percent of the other tier's magnitudes; 100 when absent. */
+  strength_percent?: number;
+}
+
+/** One tier of an outcome table: its own effects or a fallback. */
+export type OutcomeBranch = EffectSpecRequest[] | OutcomeFallback;
+
+/**
+ * Effects keyed by the check's outcome. A missing critical tier falls back to
+ * its ordinary tier; any other missing tier produces nothing.
+ */
+export type OutcomeTable = Partial<Record<CheckOutcome, OutcomeBranch>>;
+
 /** Request body for POST /api/v1/rules/produce-effects. */
 export interface ProduceEffectsRequest {
   resolution_id: UUID;
-  effects: EffectSpecRequest[];
+  /** Effects produced whatever the outcome. */
+  effects?: EffectSpecRequest[];
+  /** Effects keyed by the outcome, instead of `effects`. */
+  outcome_effects?: OutcomeTable | null;
+  /** Campaign whose front-matter custom effects the effects may use. */
+  campaign_id?: UUID | null;
 }
 
 // ---------------------------------------------------------------------------
@@ -53,6 +159,45 @@ export interface IntentView {
   target_id: UUID | null;
   difficulty_class: number;
   modifier: number;
+  roll_expression: string;
+  /** The values the expression's `@name` terms read from the actor's sheet. */
+  stats: Record<string, number>;
+  roll_mode: RollMode;
+  ruleset: RulesetId;
+  /** How the modifier was derived from the acting character's sheet, if it was. */
+  modifier_breakdown: ModifierBreakdown | null;
+}
+
+/** Where one part of a derived modifier came from. */
+export type ModifierSource =
+  | { source: 'attribute'; name: string; score: number }
+  | { source: 'proficiency'; skill: string }
+  | { source: 'item'; item_id: UUID };
+
+/** One part of a derived modifier. */
+export type ModifierComponent = ModifierSource & { value: number };
+
+/** How a check's modifier was derived from a character sheet. */
+export interface ModifierBreakdown {
+  character_id: UUID;
+  skill: string;
+  attribute: string;
+  components: ModifierComponent[];
+  total: number;
+}
+
+/** A single die drawn for a check. */
+export interface DieRollView {
+  sides: number;
+  value: number;
+  kept: boolean;
+  exploded: boolean;
+}
+
+/** One roll of a check's expression. */
+export interface CheckRollView {
+  natural_roll: number;
+  dice: DieRollView[];
 }
 
 /** View of a resolved check within a resolution. */
@@ -63,13 +208,56 @@ export interface CheckResultView {
   total: number;
   difficulty_class: number;
   outcome: string;
+  /** The ruleset's name for the outcome (e.g., "weak hit"). */
+  tier: string;
+  rolls: CheckRollView[];
+  kept_roll: number;
+}
+
+/** View of a declared opposed intent within a resolution. */
+export interface OpposedIntentView {
+  intent_id: UUID;
+  action_type: string;
+  skill: string | null;
+  actor: OpposedParticipant;
+  opponent: OpposedParticipant;
+  roll_expression: string;
+  ruleset: RulesetId;
+  tie_break: TieBreak;
+}
+
+/** One side's roll in a resolved opposed check. */
+export interface OpposedRollView {
+  natural_roll: number;
+  modifier: number;
+  total: number;
+  rolls: CheckRollView[];
+  kept_roll: number;
+}
+
+/** View of a resolved opposed check within a resolution. */
+export interface OpposedCheckView {
+  check_id: UUID;
+  actor: OpposedRollView;
+  opponent: OpposedRollView;
+  /** The actor's lead over the opponent; negative when the opponent leads. */
+  margin: number;
+  outcome: string;
+  /** The ruleset's name for the outcome, from the actor's side. */
+  tier: string;
+  /** Null when a tie ends in a standoff. */
+  winner: OpposedSide | null;
 }
 
 /** View of a produced effect within a resolution. */
-export interface EffectView {
-  effect_type: string;
-  target_id: UUID | null;
-  payload: unknown;
+export type EffectView = Effect & { target_id: UUID | null };
+
+/** The outcome-table tier that produced a resolution's effects. */
+export interface FiredTierView {
+  outcome: CheckOutcome;
+  /** Null when no tier applied and nothing was produced. */
+  branch: CheckOutcome | null;
+  strength_percent: number;
 }
 
 /** Full read-only view of a resolution (GET /api/v1/rules/:id). */
@@ -78,7 +266,11 @@ export interface ResolutionView {
   phase: string;
   intent: IntentView | null;
   check_result: CheckResultView | null;
+  opposed_intent: OpposedIntentView | null;
+  opposed_result: OpposedCheckView | null;
   effects: EffectView[];
+  /** Null unless the effects came from an outcome table. */
+  fired_tier: FiredTierView | null;
   version: number;
 }
 
@@ -88,3 +280,34 @@ export interface ResolutionSummary {
   phase: string;
   version: number;
 }
+
+/** How a previewed check is rolled; `rolls` sets the count for keep_*. */
+export type OddsRollMode = 'normal' | 'advantage' | 'disadvantage' | 'keep_best' | 'keep_worst';
+
+/** Query parameters for GET /api/v1/rules/odds. */
+export interface CheckOddsQuery {
+  difficulty_class: number;
+  modifier?: number;
+  ruleset?: RulesetId;
+  roll_mode?: OddsRollMode;
+  rolls?: number;
+}
+
+/** The chance of one outcome tier. */
+export interface OutcomeOddsView {
+  outcome: CheckOutcome;
+  /** The ruleset's name for the outcome (e.g., "weak hit"). */
+  tier: string;
+  /** Between 0 and 1. */
+  probability: number;
+}
+
+/** Odds of a check before it is rolled (GET /api/v1/rules/odds). */
+export interface CheckOddsView {
+  ruleset: RulesetId;
+  difficulty_class: number;
+  modifier: number;
+  roll_mode: RollMode;
+  /** From worst to best. */
+  outcomes: OutcomeOddsView[];
+}
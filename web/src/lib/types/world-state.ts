@@ -18,6 +18,26 @@ export interface ApplyEffectRequest {
   fact_key: string;
 }
 
+/** The value of a world fact. */
+export type FactValue =
+  | { type: 'string'; value: string }
+  | { type: 'number'; value: number }
+  | { type: 'bool'; value: boolean }
+  | { type: 'entity'; value: UUID };
+
+/** Request body for POST /api/v1/world-state/assert-fact. */
+export interface AssertFactRequest {
+  world_id: UUID;
+  key: string;
+  value: FactValue;
+}
+
+/** Request body for POST /api/v1/world-state/retract-fact. */
+export interface RetractFactRequest {
+  world_id: UUID;
+  key: string;
+}
+
 /** Request body for POST /api/v1/world-state/set-flag. */
 export interface SetFlagRequest {
   world_id: UUID;
@@ -29,18 +49,184 @@ export interface SetFlagRequest {
 export interface UpdateDispositionRequest {
   world_id: UUID;
   entity_id: UUID;
+  /** Amount to shift the score by; negative towards hostility. */
+  delta: number;
+}
+
+/** Request body for POST /api/v1/world-state/join-faction. */
+export interface JoinFactionRequest {
+  world_id: UUID;
+  entity_id: UUID;
+  faction_id: UUID;
+}
+
+/** Request body for POST /api/v1/world-state/set-connection-lock. */
+export interface SetConnectionLockRequest {
+  world_id: UUID;
+  location_id: UUID;
+  other_location_id: UUID;
+  locked: boolean;
+}
+
+/** Request body for POST /api/v1/world-state/move-party. */
+export interface MovePartyRequest {
+  world_id: UUID;
+  location_id: UUID;
+}
+
+/** Request body for POST /api/v1/world-state/move-npc. */
+export interface MoveNpcRequest {
+  world_id: UUID;
+  entity_id: UUID;
+  location_id: UUID;
+}
+
+/** A named month of a calendar. */
+export interface Month {
+  name: string;
+  days: number;
+}
+
+/** How a world counts its hours into days, months and years. */
+export interface Calendar {
+  hours_per_day: number;
+  /** Months of a year, in order; empty when the calendar counts days only. */
+  months: Month[];
+}
+
+/** A point in world time. Days count from 1, hours from 0. */
+export interface GameTime {
+  day: number;
+  hour: number;
+}
+
+/** What a scheduled event does when it fires. */
+export type ScheduledEffect =
+  | { type: 'set_flag'; flag_key: string; value: boolean }
+  | { type: 'move_npc'; entity_id: UUID; location_id: UUID };
+
+/** Request body for POST /api/v1/world-state/set-calendar. */
+export interface SetCalendarRequest {
+  world_id: UUID;
+  calendar: Calendar;
+}
+
+/** Request body for POST /api/v1/world-state/schedule-event. */
+export interface ScheduleEventRequest {
+  world_id: UUID;
+  at: GameTime;
+  effect: ScheduledEffect;
+}
+
+/** Request body for POST /api/v1/world-state/advance-time. */
+export interface AdvanceTimeRequest {
+  world_id: UUID;
+  hours: number;
+}
+
+/** Request body for POST /api/v1/world-state/:id/evaluate. */
+export interface EvaluateConditionRequest {
+  /** A condition such as `flag door_open and fact gold >= 10`. */
+  condition: string;
+  /** Campaign whose NPC IDs and faction names the condition may use as entity names. */
+  campaign_id?: UUID;
+}
+
+/** Response body for POST /api/v1/world-state/:id/evaluate. */
+export interface EvaluateConditionResponse {
+  result: boolean;
+}
+
+/** Request body for POST /api/v1/world-state/seed-from-campaign. */
+export interface SeedFromCampaignRequest {
+  world_id: UUID;
+  campaign_id: UUID;
+}
+
+/** Response body for POST /api/v1/world-state/seed-from-campaign. */
+export interface SeedFromCampaignResponse {
+  event_ids: UUID[];
+  /** World entity IDs by campaign NPC ID. */
+  npc_entity_ids: Record<string, UUID>;
+  /** World entity IDs by campaign faction name. */
+  faction_entity_ids: Record<string, UUID>;
+  /** World location IDs by campaign location ID. */
+  location_entity_ids: Record<string, UUID>;
 }
 
 // ---------------------------------------------------------------------------
 // Query / view types
 // ---------------------------------------------------------------------------
 
+/** Named band a disposition score falls in. */
+export type DispositionBand = 'hostile' | 'unfriendly' | 'neutral' | 'friendly' | 'allied';
+
+/** An entity's disposition score (−100 to 100) and its band. */
+export interface DispositionView {
+  score: number;
+  band: DispositionBand;
+}
+
+/** A day named by a calendar's months. */
+export interface CalendarDate {
+  year: number;
+  month: string;
+  day_of_month: number;
+}
+
+/** A world's current in-game time. */
+export interface WorldTimeView {
+  /** Hours since the first hour of day 1. */
+  elapsed_hours: number;
+  now: GameTime;
+  /** The current date, when the calendar names months. */
+  date: CalendarDate | null;
+  calendar: Calendar;
+}
+
+/** An effect waiting for world time to reach it. */
+export interface ScheduledEventView {
+  schedule_id: UUID;
+  at: GameTime;
+  effect: ScheduledEffect;
+}
+
 /** Full read-only view of a world snapshot (GET /api/v1/world-state/:id). */
 export interface WorldSnapshotView {
   world_id: UUID;
-  facts: string[];
+  facts: Record<string, FactValue>;
   flags: Record<string, boolean>;
-  disposition_entity_ids: UUID[];
+  /** Disposition of every seeded or shifted entity, by entity ID. */
+  dispositions: Record<UUID, DispositionView>;
+  /** Members of each faction, by faction entity ID. */
+  factions: Record<UUID, UUID[]>;
+  time: WorldTimeView;
+  /** Events waiting to fire, in the order they will fire. */
+  schedule: ScheduledEventView[];
+  version: number;
+}
+
+/** A connection from a location to a neighbouring one. */
+export interface ConnectionView {
+  location_id: UUID;
+  locked: boolean;
+}
+
+/** A location with its connections and the NPCs there. */
+export interface LocationView {
+  location_id: UUID;
+  name: string;
+  connections: ConnectionView[];
+  /** Entity IDs of the NPCs currently at the location. */
+  npcs: UUID[];
+}
+
+/** A world's location graph (GET /api/v1/world-state/:id/locations). */
+export interface WorldLocationsView {
+  world_id: UUID;
+  locations: LocationView[];
+  /** The party's location, once placed. */
+  party_location: UUID | null;
   version: number;
 }
 
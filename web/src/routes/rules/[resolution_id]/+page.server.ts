@@ -72,11 +72,21 @@ export const actions: Actions = {
     const formData = await request.formData();
     const effectType = formData.get('effect_type');
     const targetId = formData.get('target_id');
+    const payloadText = formData.get('payload');
 
     if (!effectType || typeof effectType !== 'string' || effectType.trim().length === 0) {
       return fail(400, { action: 'produceEffects', error: 'Effect type is required.' });
     }
 
+    let payload: unknown = {};
+    if (payloadText && typeof payloadText === 'string' && payloadText.trim().length > 0) {
+      try {
+        payload = JSON.parse(payloadText);
+      } catch {
+        return fail(400, { action: 'produceEffects', error: 'Payload must be valid JSON.' });
+      }
+    }
+
     try {
       await produceEffects({
         resolution_id: params.resolution_id,
@@ -84,7 +94,7 @@ export const actions: Actions = {
           {
             effect_type: effectType.trim(),
             target_id: targetId && typeof targetId === 'string' && targetId.trim().length > 0 ? targetId.trim() : null,
-            payload: {},
+            payload,
           },
         ],
       });
@@ -1,18 +1,26 @@
 import type { Actions, PageServerLoad } from './$types';
 import {
   getWorldSnapshot,
+  getWorldLocations,
   applyEffect,
+  moveParty,
+  advanceTime,
+  evaluateCondition,
   setFlag,
   updateDisposition,
   archiveWorldSnapshot,
 } from '$lib/server/api/world-state';
+import { ApiClientError } from '$lib/server/api/client';
 import { handleLoadError } from '$lib/server/api/errors';
 import { fail, redirect } from '@sveltejs/kit';
 
 export const load: PageServerLoad = async ({ params }) => {
   try {
-    const snapshot = await getWorldSnapshot(params.world_id);
-    return { snapshot };
+    const [snapshot, locations] = await Promise.all([
+      getWorldSnapshot(params.world_id),
+      getWorldLocations(params.world_id),
+    ]);
+    return { snapshot, locations };
   } catch (err) {
     handleLoadError(err);
   }
@@ -70,15 +78,22 @@ export const actions: Actions = {
   updateDisposition: async ({ request, params }) => {
     const formData = await request.formData();
     const entityId = formData.get('entity_id');
+    const deltaRaw = formData.get('delta');
 
     if (!entityId || typeof entityId !== 'string' || entityId.trim().length === 0) {
       return fail(400, { action: 'updateDisposition', error: 'Entity ID is required.' });
     }
 
+    const delta = Number(deltaRaw);
+    if (typeof deltaRaw !== 'string' || deltaRaw.trim() === '' || !Number.isInteger(delta)) {
+      return fail(400, { action: 'updateDisposition', error: 'Delta must be a whole number.' });
+    }
+
     try {
       await updateDisposition({
         world_id: params.world_id,
         entity_id: entityId.trim(),
+        delta,
       });
     } catch (err) {
       handleLoadError(err);
@@ -87,6 +102,76 @@ export const actions: Actions = {
     return { action: 'updateDisposition', success: true };
   },
 
+  moveParty: async ({ request, params }) => {
+    const formData = await request.formData();
+    const locationId = formData.get('location_id');
+
+    if (!locationId || typeof locationId !== 'string' || locationId.trim().length === 0) {
+      return fail(400, { action: 'moveParty', error: 'Location is required.' });
+    }
+
+    try {
+      await moveParty({
+        world_id: params.world_id,
+        location_id: locationId.trim(),
+      });
+    } catch (err) {
+      handleLoadError(err);
+    }
+
+    return { action: 'moveParty', success: true };
+  },
+
+  advanceTime: async ({ request, params }) => {
+    const formData = await request.formData();
+    const hoursRaw = formData.get('hours');
+
+    const hours = Number(hoursRaw);
+    if (typeof hoursRaw !== 'string' || !Number.isInteger(hours) || hours < 1) {
+      return fail(400, {
+        action: 'advanceTime',
+        error: 'Hours must be a whole number of at least 1.',
+      });
+    }
+
+    try {
+      await advanceTime({
+        world_id: params.world_id,
+        hours,
+      });
+    } catch (err) {
+      handleLoadError(err);
+    }
+
+    return { action: 'advanceTime', success: true };
+  },
+
+  evaluateCondition: async ({ request, params }) => {
+    const formData = await request.formData();
+    const condition = formData.get('condition');
+
+    if (!condition || typeof condition !== 'string' || condition.trim().length === 0) {
+      return fail(400, { action: 'evaluateCondition', error: 'Condition is required.' });
+    }
+
+    try {
+      const { result } = await evaluateCondition(params.world_id, {
+        condition: condition.trim(),
+      });
+      return {
+        action: 'evaluateCondition',
+        success: true,
+        condition: condition.trim(),
+        result,
+      };
+    } catch (err) {
+      if (err instanceof ApiClientError && err.status === 400) {
+        return fail(400, { action: 'evaluateCondition', error: err.errorResponse.message });
+      }
+      handleLoadError(err);
+    }
+  },
+
   archive: async ({ params }) => {
     try {
       await archiveWorldSnapshot(params.world_id);